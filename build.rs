@@ -0,0 +1,36 @@
+//! Capture build metadata for `swarm --version --verbose`.
+//!
+//! Every value is optional: when git or rustc can't be queried the variable is
+//! simply not set and the binary reports it as unknown.
+
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+
+    if let Some(commit) = command_output("git", &["rev-parse", "--short=12", "HEAD"]) {
+        println!("cargo:rustc-env=SWARM_BUILD_GIT_COMMIT={}", commit);
+    }
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    if let Some(version) = command_output(&rustc, &["--version"]) {
+        println!("cargo:rustc-env=SWARM_BUILD_RUSTC_VERSION={}", version);
+    }
+
+    if let Ok(target) = env::var("TARGET") {
+        println!("cargo:rustc-env=SWARM_BUILD_TARGET={}", target);
+    }
+}
+
+/// Trimmed stdout of a successful command, if any.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let trimmed = stdout.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
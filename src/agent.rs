@@ -1,6 +1,18 @@
 //! Agent naming module with A-Z name/initial mappings.
 //!
-//! Provides deterministic agent names for assignment tracking.
+//! Provides deterministic agent names for assignment tracking. The built-in
+//! roster can be replaced by `.swarm-hug/agents.toml`; see [`Roster`].
+//!
+//! Agents are keyed by a `char` initial throughout the crate. Past Z, agents
+//! use initials from the Unicode private use area, and [`id`] gives the
+//! textual id written to task files, logs, and commit emails (`AA`, `AB`, ...).
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
 
 /// All agent initials in order (A-Z).
 pub const INITIALS: [char; 26] = [
@@ -15,25 +27,369 @@ pub const NAMES: [&str; 26] = [
     "Victor", "Wendy", "Xavier", "Yara", "Zane",
 ];
 
-/// Get agent name from initial.
+/// First private use code point, used as the initial of agent 27 (`AA`).
+const EXTENDED_BASE: u32 = 0xE000;
+/// Last private use code point in the Basic Multilingual Plane.
+const EXTENDED_LAST: u32 = 0xF8FF;
+
+/// Get the initial of the agent at a zero-based roster position.
 ///
 /// # Examples
 /// ```
-/// use swarm::agent::name_from_initial;
-/// assert_eq!(name_from_initial('A'), Some("Aaron"));
-/// assert_eq!(name_from_initial('Z'), Some("Zane"));
-/// assert_eq!(name_from_initial('1'), None);
+/// use swarm::agent::{id, initial_at};
+/// assert_eq!(initial_at(0), Some('A'));
+/// assert_eq!(id(initial_at(26).unwrap()), "AA");
 /// ```
-pub fn name_from_initial(initial: char) -> Option<&'static str> {
+pub fn initial_at(index: usize) -> Option<char> {
+    if index < INITIALS.len() {
+        return Some(INITIALS[index]);
+    }
+    let code = EXTENDED_BASE.checked_add(u32::try_from(index - INITIALS.len()).ok()?)?;
+    if code > EXTENDED_LAST {
+        return None;
+    }
+    char::from_u32(code)
+}
+
+/// Get the zero-based position of an initial (case-insensitive).
+pub fn index_of(initial: char) -> Option<usize> {
     let upper = initial.to_ascii_uppercase();
     if upper.is_ascii_uppercase() {
-        let idx = (upper as u8 - b'A') as usize;
-        Some(NAMES[idx])
+        return Some((upper as u8 - b'A') as usize);
+    }
+    let code = upper as u32;
+    if (EXTENDED_BASE..=EXTENDED_LAST).contains(&code) {
+        Some((code - EXTENDED_BASE) as usize + INITIALS.len())
     } else {
         None
     }
 }
 
+/// Textual id of an agent initial: `A`-`Z`, then `AA`, `AB`, ... `ZZ`, `AAA`.
+///
+/// Characters that are not agent initials (such as `?` for an unknown
+/// completer) are returned unchanged.
+pub fn id(initial: char) -> String {
+    let Some(mut n) = index_of(initial) else {
+        return initial.to_string();
+    };
+    let mut letters = Vec::new();
+    loop {
+        letters.push((b'A' + (n % 26) as u8) as char);
+        if n < 26 {
+            break;
+        }
+        n = n / 26 - 1;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Parse a textual agent id (case-insensitive) back into its initial.
+///
+/// # Examples
+/// ```
+/// use swarm::agent::{id, initial_from_id};
+/// assert_eq!(initial_from_id("b"), Some('B'));
+/// assert_eq!(initial_from_id("AB").map(id), Some("AB".to_string()));
+/// assert_eq!(initial_from_id("A1"), None);
+/// ```
+pub fn initial_from_id(id: &str) -> Option<char> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut n: usize = 0;
+    for c in id.chars() {
+        let digit = (c.to_ascii_uppercase() as u8 - b'A') as usize + 1;
+        n = n.checked_mul(26)?.checked_add(digit)?;
+    }
+    initial_at(n - 1)
+}
+
+/// Default name for the agent at `index`: the built-in names, then
+/// `Aaron2` through `Zane2`, `Aaron3`, and so on.
+fn default_name(index: usize) -> String {
+    let base = NAMES[index % NAMES.len()];
+    match index / NAMES.len() {
+        0 => base.to_string(),
+        round => format!("{}{}", base, round + 1),
+    }
+}
+
+/// The set of agents available to a project, as (initial, name) pairs.
+///
+/// The default roster is the built-in A-Z names. A custom roster is read
+/// from an `agents.toml` with one `INITIAL = "Name"` line per agent:
+///
+/// ```toml
+/// [agents]
+/// B = "Backend"
+/// F = "Frontend"
+/// Q = "QA"
+///
+/// [skills]
+/// F = ["frontend", "ui"]
+/// ```
+///
+/// The default roster grows past Z on demand (see [`get_initials`]); a
+/// custom roster is fixed to the agents it lists. Keys may be any agent id,
+/// so `AA = "Docs"` adds a 27th agent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Roster {
+    agents: Vec<(char, String)>,
+    skills: BTreeMap<char, Vec<String>>,
+    custom: bool,
+}
+
+impl Default for Roster {
+    fn default() -> Self {
+        Self {
+            agents: INITIALS
+                .iter()
+                .copied()
+                .zip(NAMES.map(String::from))
+                .collect(),
+            skills: BTreeMap::new(),
+            custom: false,
+        }
+    }
+}
+
+impl Roster {
+    /// Build a roster from (initial, name) pairs, sorted by initial.
+    ///
+    /// Initials must be unique agent initials; names must be unique
+    /// (case-insensitive) and usable in branch names.
+    pub fn new(entries: Vec<(char, String)>) -> Result<Self, String> {
+        if entries.is_empty() {
+            return Err("agent roster is empty".to_string());
+        }
+        let mut initials = HashSet::new();
+        let mut names = HashSet::new();
+        let mut agents = Vec::with_capacity(entries.len());
+        for (initial, name) in entries {
+            if index_of(initial).is_none() {
+                return Err(format!("agent initial '{}' is not a letter", initial));
+            }
+            let initial = initial.to_ascii_uppercase();
+            if !initials.insert(initial) {
+                return Err(format!("agent '{}' is used more than once", id(initial)));
+            }
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                return Err(format!(
+                    "agent name '{}' for '{}' must be letters, digits, '-' or '_'",
+                    name,
+                    id(initial)
+                ));
+            }
+            if !names.insert(name.to_lowercase()) {
+                return Err(format!("agent name '{}' is used more than once", name));
+            }
+            agents.push((initial, name));
+        }
+        agents.sort_by_key(|&(initial, _)| initial);
+        Ok(Self {
+            agents,
+            skills: BTreeMap::new(),
+            custom: true,
+        })
+    }
+
+    /// Parse the contents of an `agents.toml` roster file.
+    ///
+    /// Agent entries may appear at the top level or under `[agents]`. An
+    /// optional `[skills]` section maps initials to skill lists
+    /// (`F = ["frontend", "ui"]`); with skills but no agents, the built-in
+    /// names are kept. Other sections are ignored.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+        let mut skills = Vec::new();
+        let mut section = "agents";
+        for (lineno, raw) in content.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = match name.trim() {
+                    "agents" => "agents",
+                    "skills" => "skills",
+                    _ => "",
+                };
+                continue;
+            }
+            if section.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected INITIAL = value", lineno + 1))?;
+            let key = key.trim().trim_matches('"');
+            let initial = initial_from_id(key).ok_or_else(|| {
+                format!(
+                    "line {}: agent id '{}' must be letters (A-Z, AA, AB, ...)",
+                    lineno + 1,
+                    key
+                )
+            })?;
+            let value = value.trim();
+            if section == "skills" {
+                let list =
+                    parse_skill_list(value).map_err(|e| format!("line {}: {}", lineno + 1, e))?;
+                skills.push((initial, list));
+                continue;
+            }
+            let name = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .ok_or_else(|| {
+                    format!("line {}: agent name must be a quoted string", lineno + 1)
+                })?;
+            entries.push((initial, name.to_string()));
+        }
+        let mut roster = if entries.is_empty() && !skills.is_empty() {
+            Self::default()
+        } else {
+            Self::new(entries)?
+        };
+        for (initial, list) in skills {
+            if roster.name_from_initial(initial).is_none() {
+                return Err(format!("skills listed for unknown agent '{}'", id(initial)));
+            }
+            roster.skills.insert(initial, list);
+        }
+        Ok(roster)
+    }
+
+    /// Load a roster file, returning `None` when it does not exist.
+    pub fn load(path: &Path) -> Result<Option<Self>, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map(Some)
+                .map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Extend a default roster with generated names up to `count` agents.
+    ///
+    /// Custom rosters are left as written.
+    fn grow_to(&mut self, count: usize) {
+        if self.custom {
+            return;
+        }
+        while self.agents.len() < count {
+            let index = self.agents.len();
+            let Some(initial) = initial_at(index) else {
+                break;
+            };
+            self.agents.push((initial, default_name(index)));
+        }
+    }
+
+    /// The (initial, name) pairs in this roster, ordered by initial.
+    pub fn agents(&self) -> &[(char, String)] {
+        &self.agents
+    }
+
+    /// Skills of an agent (lowercase), empty when none are configured.
+    pub fn skills(&self, initial: char) -> &[String] {
+        self.skills
+            .get(&initial.to_ascii_uppercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Every agent's configured skills, keyed by initial.
+    pub fn skill_map(&self) -> &BTreeMap<char, Vec<String>> {
+        &self.skills
+    }
+
+    /// Get the name for an initial (case-insensitive).
+    pub fn name_from_initial(&self, initial: char) -> Option<&str> {
+        let upper = initial.to_ascii_uppercase();
+        self.agents
+            .iter()
+            .find(|(i, _)| *i == upper)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Get the initial for a name (case-insensitive).
+    pub fn initial_from_name(&self, name: &str) -> Option<char> {
+        self.agents
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|&(initial, _)| initial)
+    }
+}
+
+/// Parse a `["frontend", "ui"]` skill list into lowercase skills.
+fn parse_skill_list(value: &str) -> Result<Vec<String>, String> {
+    let inner = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .ok_or_else(|| "skills must be a list like [\"frontend\", \"ui\"]".to_string())?;
+    let mut skills = Vec::new();
+    for item in inner.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+        let skill = item
+            .strip_prefix('"')
+            .and_then(|i| i.strip_suffix('"'))
+            .map(str::trim)
+            .filter(|i| !i.is_empty())
+            .ok_or_else(|| format!("skill {} must be a non-empty quoted string", item))?;
+        skills.push(skill.to_lowercase());
+    }
+    Ok(skills)
+}
+
+static ROSTER: Lazy<RwLock<Roster>> = Lazy::new(|| RwLock::new(Roster::default()));
+
+/// Replace the process-wide roster used by the lookup functions below.
+pub fn set_roster(roster: Roster) {
+    *ROSTER.write().unwrap() = roster;
+}
+
+/// The effective roster: the custom roster if one was set, else A-Z.
+pub fn roster() -> Roster {
+    ROSTER.read().unwrap().clone()
+}
+
+/// Initials of every agent in the effective roster, in order.
+pub fn initials() -> Vec<char> {
+    ROSTER
+        .read()
+        .unwrap()
+        .agents
+        .iter()
+        .map(|&(i, _)| i)
+        .collect()
+}
+
+/// Skills of an agent in the effective roster.
+pub fn skills(initial: char) -> Vec<String> {
+    ROSTER.read().unwrap().skills(initial).to_vec()
+}
+
+/// Get agent name from initial.
+///
+/// # Examples
+/// ```
+/// use swarm::agent::name_from_initial;
+/// assert_eq!(name_from_initial('A').as_deref(), Some("Aaron"));
+/// assert_eq!(name_from_initial('Z').as_deref(), Some("Zane"));
+/// assert_eq!(name_from_initial('1'), None);
+/// ```
+pub fn name_from_initial(initial: char) -> Option<String> {
+    ROSTER
+        .read()
+        .unwrap()
+        .name_from_initial(initial)
+        .map(str::to_string)
+}
+
 /// Get initial from agent name (case-insensitive).
 ///
 /// # Examples
@@ -45,14 +401,10 @@ pub fn name_from_initial(initial: char) -> Option<&'static str> {
 /// assert_eq!(initial_from_name("Unknown"), None);
 /// ```
 pub fn initial_from_name(name: &str) -> Option<char> {
-    let lower = name.to_lowercase();
-    NAMES
-        .iter()
-        .position(|&n| n.to_lowercase() == lower)
-        .map(|idx| INITIALS[idx])
+    ROSTER.read().unwrap().initial_from_name(name)
 }
 
-/// Get the first N agent names starting from A.
+/// Get the first N agent names from the roster.
 ///
 /// # Examples
 /// ```
@@ -60,11 +412,21 @@ pub fn initial_from_name(name: &str) -> Option<char> {
 /// assert_eq!(get_names(3), vec!["Aaron", "Betty", "Carlos"]);
 /// assert_eq!(get_names(0), Vec::<&str>::new());
 /// ```
-pub fn get_names(count: usize) -> Vec<&'static str> {
-    NAMES.iter().take(count).copied().collect()
+pub fn get_names(count: usize) -> Vec<String> {
+    ROSTER
+        .read()
+        .unwrap()
+        .agents
+        .iter()
+        .take(count)
+        .map(|(_, n)| n.clone())
+        .collect()
 }
 
-/// Get the first N agent initials starting from A.
+/// Get the first N agent initials from the roster.
+///
+/// The default roster grows past Z to supply `count` agents; a custom
+/// roster returns at most the agents it lists.
 ///
 /// # Examples
 /// ```
@@ -72,17 +434,37 @@ pub fn get_names(count: usize) -> Vec<&'static str> {
 /// assert_eq!(get_initials(3), vec!['A', 'B', 'C']);
 /// ```
 pub fn get_initials(count: usize) -> Vec<char> {
-    INITIALS.iter().take(count).copied().collect()
+    if ROSTER.read().unwrap().agents.len() < count {
+        ROSTER.write().unwrap().grow_to(count);
+    }
+    initials().into_iter().take(count).collect()
 }
 
 /// Check if a character is a valid agent initial.
 pub fn is_valid_initial(initial: char) -> bool {
-    initial.to_ascii_uppercase().is_ascii_uppercase()
+    index_of(initial).is_some()
 }
 
 /// Check if a string is a valid agent name.
 pub fn is_valid_name(name: &str) -> bool {
-    NAMES.contains(&name)
+    ROSTER.read().unwrap().agents.iter().any(|(_, n)| n == name)
+}
+
+/// Resolve an agent name or initial (case-insensitive) to its canonical name.
+///
+/// # Examples
+/// ```
+/// use swarm::agent::resolve_name;
+/// assert_eq!(resolve_name("betty").as_deref(), Some("Betty"));
+/// assert_eq!(resolve_name("b").as_deref(), Some("Betty"));
+/// assert_eq!(resolve_name("Unknown"), None);
+/// ```
+pub fn resolve_name(name_or_initial: &str) -> Option<String> {
+    let mut chars = name_or_initial.chars();
+    match (chars.next(), chars.next()) {
+        (Some(initial), None) => name_from_initial(initial),
+        _ => initial_from_name(name_or_initial).and_then(name_from_initial),
+    }
 }
 
 #[cfg(test)]
@@ -91,23 +473,23 @@ mod tests {
 
     #[test]
     fn test_name_from_initial_a() {
-        assert_eq!(name_from_initial('A'), Some("Aaron"));
+        assert_eq!(name_from_initial('A').as_deref(), Some("Aaron"));
     }
 
     #[test]
     fn test_name_from_initial_b() {
-        assert_eq!(name_from_initial('B'), Some("Betty"));
+        assert_eq!(name_from_initial('B').as_deref(), Some("Betty"));
     }
 
     #[test]
     fn test_name_from_initial_z() {
-        assert_eq!(name_from_initial('Z'), Some("Zane"));
+        assert_eq!(name_from_initial('Z').as_deref(), Some("Zane"));
     }
 
     #[test]
     fn test_name_from_initial_lowercase() {
-        assert_eq!(name_from_initial('a'), Some("Aaron"));
-        assert_eq!(name_from_initial('z'), Some("Zane"));
+        assert_eq!(name_from_initial('a').as_deref(), Some("Aaron"));
+        assert_eq!(name_from_initial('z').as_deref(), Some("Zane"));
     }
 
     #[test]
@@ -212,6 +594,16 @@ mod tests {
         assert!(!is_valid_name("aaron"));
     }
 
+    #[test]
+    fn test_resolve_name() {
+        assert_eq!(resolve_name("Aaron").as_deref(), Some("Aaron"));
+        assert_eq!(resolve_name("AARON").as_deref(), Some("Aaron"));
+        assert_eq!(resolve_name("z").as_deref(), Some("Zane"));
+        assert_eq!(resolve_name("1"), None);
+        assert_eq!(resolve_name(""), None);
+        assert_eq!(resolve_name("Aa"), None);
+    }
+
     #[test]
     fn test_all_names_unique() {
         let mut seen = std::collections::HashSet::new();
@@ -224,8 +616,123 @@ mod tests {
     fn test_name_initial_roundtrip() {
         for (i, &name) in NAMES.iter().enumerate() {
             let initial = INITIALS[i];
-            assert_eq!(name_from_initial(initial), Some(name));
+            assert_eq!(name_from_initial(initial).as_deref(), Some(name));
             assert_eq!(initial_from_name(name), Some(initial));
         }
     }
+
+    #[test]
+    fn test_custom_roster_changes_resolved_name() {
+        let roster = Roster::parse(
+            "# team roles\n[agents]\nb = \"Backend\"\nF = \"Frontend\"\n\n[other]\nQ = \"Ignored\"\n",
+        )
+        .unwrap();
+        assert_eq!(roster.name_from_initial('B'), Some("Backend"));
+        assert_eq!(roster.name_from_initial('f'), Some("Frontend"));
+        assert_eq!(roster.name_from_initial('A'), None);
+        assert_eq!(roster.name_from_initial('Q'), None);
+        assert_eq!(roster.initial_from_name("backend"), Some('B'));
+        assert_eq!(
+            roster.agents(),
+            &[('B', "Backend".to_string()), ('F', "Frontend".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_default_roster_matches_builtin_names() {
+        let roster = Roster::default();
+        assert_eq!(roster.agents().len(), 26);
+        assert_eq!(roster.name_from_initial('A'), Some("Aaron"));
+        assert_eq!(roster.initial_from_name("Zane"), Some('Z'));
+    }
+
+    #[test]
+    fn test_roster_rejects_duplicate_and_invalid_entries() {
+        assert!(Roster::parse("A = \"One\"\na = \"Two\"")
+            .unwrap_err()
+            .contains("more than once"));
+        assert!(Roster::parse("A = \"Same\"\nB = \"same\"")
+            .unwrap_err()
+            .contains("more than once"));
+        assert!(Roster::parse("A1 = \"Mixed\"")
+            .unwrap_err()
+            .contains("must be letters"));
+        assert!(Roster::parse("1 = \"Digit\"")
+            .unwrap_err()
+            .contains("must be letters"));
+        assert!(Roster::new(vec![('1', "Digit".to_string())])
+            .unwrap_err()
+            .contains("not a letter"));
+        assert!(Roster::parse("A = \"Two Words\"").is_err());
+        assert!(Roster::parse("A = Unquoted").is_err());
+        assert!(Roster::parse("").unwrap_err().contains("empty"));
+    }
+
+    #[test]
+    fn test_load_missing_roster_is_none() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(Roster::load(&dir.path().join("agents.toml")).unwrap(), None);
+        let path = dir.path().join("present.toml");
+        std::fs::write(&path, "Q = \"QA\"\n").unwrap();
+        let roster = Roster::load(&path).unwrap().unwrap();
+        assert_eq!(roster.name_from_initial('Q'), Some("QA"));
+    }
+
+    #[test]
+    fn test_ids_past_z_round_trip() {
+        assert_eq!(id('A'), "A");
+        assert_eq!(id('z'), "Z");
+        assert_eq!(id('?'), "?");
+        let ids: Vec<String> = (0..60).map(|i| id(initial_at(i).unwrap())).collect();
+        assert_eq!(&ids[25..29], &["Z", "AA", "AB", "AC"]);
+        assert_eq!(ids[51], "AZ");
+        assert_eq!(ids[52], "BA");
+        for (index, text) in ids.iter().enumerate() {
+            let initial = initial_from_id(text).unwrap();
+            assert_eq!(index_of(initial), Some(index), "{}", text);
+            assert!(is_valid_initial(initial));
+        }
+        assert_eq!(id(initial_from_id("zz").unwrap()), "ZZ");
+        assert_eq!(id(initial_from_id("AAA").unwrap()), "AAA");
+        assert_eq!(initial_from_id(""), None);
+    }
+
+    #[test]
+    fn test_default_roster_grows_past_z() {
+        let mut roster = Roster::default();
+        roster.grow_to(30);
+        assert_eq!(roster.agents().len(), 30);
+        let aa = initial_from_id("AA").unwrap();
+        assert_eq!(roster.name_from_initial(aa), Some("Aaron2"));
+        assert_eq!(roster.initial_from_name("dIANA2"), initial_from_id("AD"));
+        let names: std::collections::HashSet<_> = roster.agents().iter().map(|a| &a.1).collect();
+        assert_eq!(names.len(), 30);
+
+        let mut custom = Roster::parse("A = \"Solo\"\nAA = \"Docs\"").unwrap();
+        custom.grow_to(30);
+        assert_eq!(
+            custom.agents(),
+            &[('A', "Solo".to_string()), (aa, "Docs".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_roster_skills_section() {
+        let roster = Roster::parse(
+            "[agents]\nB = \"Backend\"\nF = \"Frontend\"\n\n[skills]\nf = [\"Frontend\", \"ui\"]\n",
+        )
+        .unwrap();
+        assert_eq!(roster.skills('F'), &["frontend", "ui"]);
+        assert!(roster.skills('B').is_empty());
+
+        let defaults = Roster::parse("[skills]\nA = [\"docs\"]\n").unwrap();
+        assert_eq!(defaults.name_from_initial('A'), Some("Aaron"));
+        assert_eq!(defaults.skills('A'), &["docs"]);
+
+        assert!(Roster::parse("B = \"Backend\"\n[skills]\nQ = [\"qa\"]")
+            .unwrap_err()
+            .contains("unknown agent 'Q'"));
+        assert!(Roster::parse("[skills]\nA = \"docs\"").is_err());
+        assert!(Roster::parse("[skills]\nA = [docs]").is_err());
+    }
 }
@@ -0,0 +1,187 @@
+//! Audit trail of sprint lifecycle events mirrored to syslog (`audit.syslog`).
+//!
+//! Call sites report events with [`event`] or [`failure`] in addition to their
+//! normal output. When syslog is disabled (the default) or the platform is not
+//! Unix, both are no-ops. Messages are `key=value` pairs so log pipelines can
+//! parse them without knowing swarm's output format.
+
+use std::sync::Mutex;
+
+/// Syslog facility audit events are logged under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SyslogFacility {
+    #[default]
+    User,
+    Daemon,
+    Local0,
+    Local1,
+    Local2,
+    Local3,
+    Local4,
+    Local5,
+    Local6,
+    Local7,
+}
+
+impl SyslogFacility {
+    /// Parse facility from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "user" => Some(Self::User),
+            "daemon" => Some(Self::Daemon),
+            "local0" => Some(Self::Local0),
+            "local1" => Some(Self::Local1),
+            "local2" => Some(Self::Local2),
+            "local3" => Some(Self::Local3),
+            "local4" => Some(Self::Local4),
+            "local5" => Some(Self::Local5),
+            "local6" => Some(Self::Local6),
+            "local7" => Some(Self::Local7),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Daemon => "daemon",
+            Self::Local0 => "local0",
+            Self::Local1 => "local1",
+            Self::Local2 => "local2",
+            Self::Local3 => "local3",
+            Self::Local4 => "local4",
+            Self::Local5 => "local5",
+            Self::Local6 => "local6",
+            Self::Local7 => "local7",
+        }
+    }
+
+    #[cfg(unix)]
+    fn code(&self) -> libc::c_int {
+        match self {
+            Self::User => libc::LOG_USER,
+            Self::Daemon => libc::LOG_DAEMON,
+            Self::Local0 => libc::LOG_LOCAL0,
+            Self::Local1 => libc::LOG_LOCAL1,
+            Self::Local2 => libc::LOG_LOCAL2,
+            Self::Local3 => libc::LOG_LOCAL3,
+            Self::Local4 => libc::LOG_LOCAL4,
+            Self::Local5 => libc::LOG_LOCAL5,
+            Self::Local6 => libc::LOG_LOCAL6,
+            Self::Local7 => libc::LOG_LOCAL7,
+        }
+    }
+}
+
+static SYSLOG: Mutex<Option<SyslogFacility>> = Mutex::new(None);
+
+/// Enable (or disable with `None`) mirroring audit events to syslog.
+pub fn set_syslog(facility: Option<SyslogFacility>) {
+    #[cfg(unix)]
+    if let Some(facility) = facility {
+        // SAFETY: the ident is a static C string, as openlog requires.
+        unsafe { libc::openlog(c"swarm".as_ptr(), libc::LOG_PID, facility.code()) };
+    }
+    *SYSLOG.lock().unwrap_or_else(|e| e.into_inner()) = facility;
+}
+
+/// Record a lifecycle event such as `sprint_start` or `push`.
+pub fn event(name: &str, fields: &[(&str, &str)]) {
+    send(false, &format_event(name, fields));
+}
+
+/// Record a failed operation such as `push_failed` at error severity.
+pub fn failure(name: &str, fields: &[(&str, &str)]) {
+    send(true, &format_event(name, fields));
+}
+
+#[cfg(unix)]
+fn send(is_failure: bool, message: &str) {
+    let Some(facility) = *SYSLOG.lock().unwrap_or_else(|e| e.into_inner()) else {
+        return;
+    };
+    let Ok(message) = std::ffi::CString::new(message.replace('\0', "")) else {
+        return;
+    };
+    let severity = if is_failure {
+        libc::LOG_ERR
+    } else {
+        libc::LOG_INFO
+    };
+    // SAFETY: "%s" consumes exactly the one NUL-terminated string passed.
+    unsafe { libc::syslog(facility.code() | severity, c"%s".as_ptr(), message.as_ptr()) };
+}
+
+#[cfg(not(unix))]
+fn send(_is_failure: bool, _message: &str) {}
+
+/// Format an event as `event=<name> key=value ...`.
+///
+/// Values that are empty or contain whitespace, `=`, or quotes are quoted.
+pub fn format_event(name: &str, fields: &[(&str, &str)]) -> String {
+    let mut line = format!("event={}", format_value(name));
+    for (key, value) in fields {
+        line.push_str(&format!(" {}={}", key, format_value(value)));
+    }
+    line
+}
+
+fn format_value(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || c == '=' || c == '"');
+    if !needs_quotes {
+        return value.to_string();
+    }
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_event_key_value_pairs() {
+        assert_eq!(
+            format_event("sprint_start", &[("team", "greenfield"), ("sprint", "3")]),
+            "event=sprint_start team=greenfield sprint=3"
+        );
+    }
+
+    #[test]
+    fn test_format_event_quotes_values_with_spaces() {
+        assert_eq!(
+            format_event(
+                "push_failed",
+                &[
+                    ("target", "main"),
+                    ("error", "remote \"origin\" rejected\nx")
+                ]
+            ),
+            "event=push_failed target=main error=\"remote \\\"origin\\\" rejected\\nx\""
+        );
+        assert_eq!(
+            format_event("pr_created", &[("url", "")]),
+            "event=pr_created url=\"\""
+        );
+    }
+
+    #[test]
+    fn test_syslog_facility_parse_round_trip() {
+        for name in ["user", "daemon", "local0", "local7"] {
+            assert_eq!(SyslogFacility::parse(name).unwrap().as_str(), name);
+        }
+        assert_eq!(
+            SyslogFacility::parse("LOCAL3"),
+            Some(SyslogFacility::Local3)
+        );
+        assert_eq!(SyslogFacility::parse("kern"), None);
+    }
+}
@@ -2,14 +2,318 @@
 //!
 //! All communication is appended to CHAT.md with the format:
 //! `YYYY-MM-DD HH:MM:SS | <AgentName> | <message>`
+//!
+//! With the JSONL format (`files.chat_format = "jsonl"`, or any `.jsonl` chat
+//! path) each entry is instead one JSON object per line:
+//! `{"ts": "...", "agent": "...", "message": "...", "kind": "message"}`.
+//! Readers accept both formats, line by line.
+//!
+//! Timestamps use the local timezone and `%Y-%m-%d %H:%M:%S` unless
+//! `files.chat_timezone` / `files.chat_time_format` say otherwise.
 
-use chrono::Local;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use chrono_tz::Tz;
+use std::borrow::Cow;
+use std::fmt;
 use std::fs::{File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
 use std::path::Path;
+use std::sync::Mutex;
+
+use crate::json::{escape_json, parse_json_fields};
 
 const HEARTBEAT_PREFIX: &str = "AGENT_ACTIVITY:";
 
+/// Default strftime format for chat timestamps.
+pub const DEFAULT_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Chat log line format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChatFormat {
+    /// `timestamp | agent | message` lines.
+    #[default]
+    Markdown,
+    /// One JSON object per line.
+    Jsonl,
+}
+
+impl ChatFormat {
+    /// Parse format from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "markdown" | "md" => Some(Self::Markdown),
+            "jsonl" => Some(Self::Jsonl),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Markdown => "markdown",
+            Self::Jsonl => "jsonl",
+        }
+    }
+}
+
+/// What a chat entry records (`kind` in JSONL entries).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChatKind {
+    #[default]
+    Message,
+    /// Periodic "still working" activity line.
+    Heartbeat,
+    /// Sprint plan summary and assignments.
+    Plan,
+    /// End-of-sprint `SPRINT STATUS` lines.
+    Status,
+    /// Merge result for an agent.
+    Merge,
+    /// Banner written when a run starts.
+    Boot,
+}
+
+impl ChatKind {
+    /// Parse kind from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "message" => Some(Self::Message),
+            "heartbeat" => Some(Self::Heartbeat),
+            "plan" => Some(Self::Plan),
+            "status" => Some(Self::Status),
+            "merge" => Some(Self::Merge),
+            "boot" => Some(Self::Boot),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Message => "message",
+            Self::Heartbeat => "heartbeat",
+            Self::Plan => "plan",
+            Self::Status => "status",
+            Self::Merge => "merge",
+            Self::Boot => "boot",
+        }
+    }
+}
+
+/// Severity of a chat message, written as a marker before the message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Level {
+    /// Normal progress (no marker).
+    #[default]
+    Info,
+    /// Something was skipped or needs attention (`[WARN]`).
+    Warn,
+    /// A task, merge, or push failed (`[ERROR]`).
+    Error,
+}
+
+impl Level {
+    /// Parse level from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "info" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+
+    /// Marker prefixed to messages at this level.
+    pub fn marker(&self) -> Option<&'static str> {
+        match self {
+            Self::Info => None,
+            Self::Warn => Some("[WARN]"),
+            Self::Error => Some("[ERROR]"),
+        }
+    }
+
+    /// Split a message (as returned by [`parse_line`]) into its level and text.
+    ///
+    /// # Examples
+    /// ```
+    /// use swarm::chat::Level;
+    /// assert_eq!(Level::of_message("[ERROR] Merge failed"), (Level::Error, "Merge failed"));
+    /// assert_eq!(Level::of_message("Starting: task"), (Level::Info, "Starting: task"));
+    /// ```
+    pub fn of_message(message: &str) -> (Self, &str) {
+        for level in [Self::Warn, Self::Error] {
+            let marker = level.marker().unwrap_or_default();
+            if let Some(rest) = message.strip_prefix(marker) {
+                if let Some(text) = rest.strip_prefix(' ') {
+                    return (level, text);
+                }
+            }
+        }
+        (Self::Info, message)
+    }
+}
+
+/// One parsed chat line, from either format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatEntry {
+    pub timestamp: String,
+    pub agent: String,
+    /// Message text (without the heartbeat prefix for heartbeats).
+    pub message: String,
+    pub kind: ChatKind,
+}
+
+impl ChatEntry {
+    /// Render as a markdown chat line.
+    pub fn to_markdown(&self) -> String {
+        format_message_with_timestamp(&self.timestamp, &self.agent, &self.markdown_message())
+    }
+
+    /// Render as a JSONL chat line.
+    pub fn to_jsonl(&self) -> String {
+        format!(
+            "{{\"ts\": \"{}\", \"agent\": \"{}\", \"message\": \"{}\", \"kind\": \"{}\"}}",
+            escape_json(&self.timestamp),
+            escape_json(&self.agent),
+            escape_json(&self.message),
+            self.kind.as_str()
+        )
+    }
+
+    fn markdown_message(&self) -> String {
+        if self.kind == ChatKind::Heartbeat {
+            format!("{} {}", HEARTBEAT_PREFIX, self.message)
+        } else {
+            self.message.clone()
+        }
+    }
+}
+
+/// Timezone chat timestamps are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChatTimezone {
+    /// The machine's local timezone.
+    #[default]
+    Local,
+    Utc,
+    /// A fixed offset from UTC (`+05:30`).
+    Offset(FixedOffset),
+    /// An IANA timezone (`America/New_York`).
+    Named(Tz),
+}
+
+impl ChatTimezone {
+    /// Parse `local`, `UTC`, a `+HH:MM`/`-HH:MM` offset, or an IANA name.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("local") {
+            Some(Self::Local)
+        } else if s.eq_ignore_ascii_case("utc") || s == "Z" {
+            Some(Self::Utc)
+        } else if s.starts_with(['+', '-']) {
+            parse_offset(s).map(Self::Offset)
+        } else {
+            s.parse::<Tz>().ok().map(Self::Named)
+        }
+    }
+
+    /// Render `now` in this timezone with a strftime `format`.
+    pub fn format(&self, now: DateTime<Utc>, format: &str) -> String {
+        match self {
+            Self::Local => now.with_timezone(&Local).format(format).to_string(),
+            Self::Utc => now.format(format).to_string(),
+            Self::Offset(offset) => now.with_timezone(offset).format(format).to_string(),
+            Self::Named(tz) => now.with_timezone(tz).format(format).to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ChatTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => write!(f, "local"),
+            Self::Utc => write!(f, "UTC"),
+            Self::Offset(offset) => write!(f, "{}", offset),
+            Self::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+/// Parse `+HH:MM`, `+HHMM`, or `+HH` (or the `-` forms).
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.split_at(1) {
+        ("+", rest) => (1, rest),
+        ("-", rest) => (-1, rest),
+        _ => return None,
+    };
+    let digits: String = rest.chars().filter(|&c| c != ':').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) || rest.matches(':').count() > 1 {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Whether `format` is a strftime format chat lines can use.
+///
+/// Rejects `|`, which would break the markdown `time | agent | message` layout.
+pub fn is_valid_time_format(format: &str) -> bool {
+    !format.trim().is_empty()
+        && !format.contains('|')
+        && !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+}
+
+static FORMAT: Mutex<ChatFormat> = Mutex::new(ChatFormat::Markdown);
+
+static TIMESTAMP: Mutex<(ChatTimezone, Cow<'static, str>)> =
+    Mutex::new((ChatTimezone::Local, Cow::Borrowed(DEFAULT_TIME_FORMAT)));
+
+/// Set the format new chat entries are written in (`.jsonl` paths always use JSONL).
+pub fn set_format(format: ChatFormat) {
+    *FORMAT.lock().unwrap_or_else(|e| e.into_inner()) = format;
+}
+
+/// Set the timezone and strftime format of new chat timestamps.
+pub fn set_timestamp_style(timezone: ChatTimezone, time_format: &str) {
+    *TIMESTAMP.lock().unwrap_or_else(|e| e.into_inner()) =
+        (timezone, Cow::Owned(time_format.to_string()));
+}
+
+/// Current time in the configured chat timestamp style.
+fn timestamp_now() -> String {
+    let style = TIMESTAMP.lock().unwrap_or_else(|e| e.into_inner());
+    style.0.format(Utc::now(), &style.1)
+}
+
+/// Format used when writing to `path`.
+fn format_for(path: &Path) -> ChatFormat {
+    if path.extension().is_some_and(|ext| ext == "jsonl") {
+        ChatFormat::Jsonl
+    } else {
+        *FORMAT.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
 /// Format a chat message for CHAT.md.
 ///
 /// # Examples
@@ -20,8 +324,7 @@ const HEARTBEAT_PREFIX: &str = "AGENT_ACTIVITY:";
 /// assert!(msg.contains("Starting task"));
 /// ```
 pub fn format_message(agent_name: &str, message: &str) -> String {
-    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-    format!("{} | {} | {}", timestamp, agent_name, message)
+    format_message_with_timestamp(&timestamp_now(), agent_name, message)
 }
 
 /// Format a chat message with a custom timestamp (for testing).
@@ -31,21 +334,59 @@ pub fn format_message_with_timestamp(timestamp: &str, agent_name: &str, message:
 
 /// Append a message to CHAT.md.
 pub fn write_message<P: AsRef<Path>>(path: P, agent_name: &str, message: &str) -> io::Result<()> {
-    let line = format_message(agent_name, message);
-    append_line(path, &line)
+    write_message_level(path, agent_name, Level::Info, message)
+}
+
+/// Append a message to CHAT.md with a severity marker (none for `Info`).
+pub fn write_message_level<P: AsRef<Path>>(
+    path: P,
+    agent_name: &str,
+    level: Level,
+    message: &str,
+) -> io::Result<()> {
+    match level.marker() {
+        Some(marker) => write_entry(
+            path,
+            agent_name,
+            &format!("{} {}", marker, message),
+            ChatKind::Message,
+        ),
+        None => write_entry(path, agent_name, message, ChatKind::Message),
+    }
 }
 
 /// Append a heartbeat message to CHAT.md.
 pub fn write_heartbeat<P: AsRef<Path>>(path: P, agent_name: &str, message: &str) -> io::Result<()> {
-    let msg = format!("{} {}", HEARTBEAT_PREFIX, message);
-    write_message(path, agent_name, &msg)
+    write_entry(path, agent_name, message, ChatKind::Heartbeat)
+}
+
+/// Append an entry in the chat file's format.
+fn write_entry<P: AsRef<Path>>(
+    path: P,
+    agent_name: &str,
+    message: &str,
+    kind: ChatKind,
+) -> io::Result<()> {
+    let path = path.as_ref();
+    append_line(path, &render_entry(path, agent_name, message, kind))
+}
+
+fn render_entry(path: &Path, agent_name: &str, message: &str, kind: ChatKind) -> String {
+    let entry = ChatEntry {
+        timestamp: timestamp_now(),
+        agent: agent_name.to_string(),
+        message: message.to_string(),
+        kind,
+    };
+    match format_for(path) {
+        ChatFormat::Markdown => entry.to_markdown(),
+        ChatFormat::Jsonl => entry.to_jsonl(),
+    }
 }
 
 /// Check if a chat line is a heartbeat entry.
 pub fn is_heartbeat_line(line: &str) -> bool {
-    parse_line(line)
-        .map(|(_, _, message)| message.trim_start().starts_with(HEARTBEAT_PREFIX))
-        .unwrap_or(false)
+    parse_entry(line).is_some_and(|entry| entry.kind == ChatKind::Heartbeat)
 }
 
 /// Append a raw line to a file.
@@ -54,34 +395,62 @@ fn append_line<P: AsRef<Path>>(path: P, line: &str) -> io::Result<()> {
     writeln!(file, "{}", line)
 }
 
-/// Read recent lines from CHAT.md.
+/// Read recent lines from CHAT.md, with JSONL entries rendered as markdown lines.
 pub fn read_recent<P: AsRef<Path>>(path: P, count: usize) -> io::Result<Vec<String>> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
     let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
 
-    if lines.len() <= count {
-        Ok(lines)
-    } else {
-        Ok(lines[lines.len() - count..].to_vec())
-    }
+    let start = lines.len().saturating_sub(count);
+    Ok(lines[start..]
+        .iter()
+        .map(|line| display_line(line))
+        .collect())
 }
 
-/// Read all messages from a specific agent.
+/// Read all messages from a specific agent (as markdown lines).
 pub fn read_from_agent<P: AsRef<Path>>(path: P, agent_name: &str) -> io::Result<Vec<String>> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
-    let pattern = format!("| {} |", agent_name);
 
     let lines: Vec<String> = reader
         .lines()
         .map_while(Result::ok)
-        .filter(|line| line.contains(&pattern))
+        .filter(|line| parse_entry(line).is_some_and(|entry| entry.agent == agent_name))
+        .map(|line| display_line(&line))
         .collect();
 
     Ok(lines)
 }
 
+/// Read the last `count` lines from one agent, as markdown lines.
+///
+/// `agent` matches case-insensitively, by name or by an A-Z initial
+/// (`"b"` matches Betty).
+pub fn read_filtered<P: AsRef<Path>>(
+    path: P,
+    agent: &str,
+    count: usize,
+) -> io::Result<Vec<String>> {
+    let name = crate::agent::resolve_name(agent).unwrap_or_else(|| agent.to_string());
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+
+    let lines: Vec<String> = reader
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| {
+            parse_entry(line).is_some_and(|entry| entry.agent.eq_ignore_ascii_case(&name))
+        })
+        .collect();
+
+    let start = lines.len().saturating_sub(count);
+    Ok(lines[start..]
+        .iter()
+        .map(|line| display_line(line))
+        .collect())
+}
+
 /// Write a sprint plan summary to CHAT.md.
 pub fn write_sprint_plan<P: AsRef<Path>>(
     path: P,
@@ -93,55 +462,54 @@ pub fn write_sprint_plan<P: AsRef<Path>>(
         sprint_number,
         assignments.len()
     );
-    write_message(&path, "ScrumMaster", &summary)?;
+    write_entry(&path, "ScrumMaster", &summary, ChatKind::Plan)?;
 
     for (initial, description) in assignments {
-        let agent_name = crate::agent::name_from_initial(*initial).unwrap_or("Unknown");
+        let agent_name =
+            crate::agent::name_from_initial(*initial).unwrap_or_else(|| "Unknown".to_string());
         let msg = format!("{} assigned: {}", agent_name, description);
-        write_message(&path, "ScrumMaster", &msg)?;
+        write_entry(&path, "ScrumMaster", &msg, ChatKind::Plan)?;
     }
 
     Ok(())
 }
 
 /// Write a sprint status summary to CHAT.md.
+#[allow(clippy::too_many_arguments)]
 pub fn write_sprint_status<P: AsRef<Path>>(
     path: P,
     team_name: &str,
     sprint_number: usize,
     completed_this_sprint: usize,
     failed_this_sprint: usize,
+    merge_failures: usize,
     remaining_tasks: usize,
     total_tasks: usize,
 ) -> io::Result<()> {
-    let header = format!(
+    let status = |message: &str| write_entry(&path, "ScrumMaster", message, ChatKind::Status);
+    status(&format!(
         "SPRINT STATUS: {} Sprint {} complete",
         team_name, sprint_number
-    );
-    write_message(&path, "ScrumMaster", &header)?;
-    write_message(
-        &path,
-        "ScrumMaster",
-        &format!(
-            "SPRINT STATUS: Completed this sprint: {}",
-            completed_this_sprint
-        ),
-    )?;
-    write_message(
-        &path,
-        "ScrumMaster",
-        &format!("SPRINT STATUS: Failed this sprint: {}", failed_this_sprint),
-    )?;
-    write_message(
-        &path,
-        "ScrumMaster",
-        &format!("SPRINT STATUS: Remaining tasks: {}", remaining_tasks),
-    )?;
-    write_message(
-        &path,
-        "ScrumMaster",
-        &format!("SPRINT STATUS: Total tasks: {}", total_tasks),
-    )?;
+    ))?;
+    status(&format!(
+        "SPRINT STATUS: Completed this sprint: {}",
+        completed_this_sprint
+    ))?;
+    status(&format!(
+        "SPRINT STATUS: Failed this sprint: {}",
+        failed_this_sprint
+    ))?;
+    if merge_failures > 0 {
+        status(&format!(
+            "SPRINT STATUS: Merge failures: {}",
+            merge_failures
+        ))?;
+    }
+    status(&format!(
+        "SPRINT STATUS: Remaining tasks: {}",
+        remaining_tasks
+    ))?;
+    status(&format!("SPRINT STATUS: Total tasks: {}", total_tasks))?;
 
     Ok(())
 }
@@ -150,18 +518,20 @@ pub fn write_sprint_status<P: AsRef<Path>>(
 ///
 /// This clears the chat.md file and writes the "SWARM HUG BOOTING UP" message.
 pub fn write_boot_message<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let path = path.as_ref();
     // Truncate the file (clear all contents)
     let mut file = OpenOptions::new()
         .create(true)
         .write(true)
         .truncate(true)
-        .open(&path)?;
+        .open(path)?;
 
     // Write the boot banner
-    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-    let banner = format!(
-        "{} | ScrumMaster | 🚀🐝 SWARM HUG BOOTING UP 🐝🚀",
-        timestamp
+    let banner = render_entry(
+        path,
+        "ScrumMaster",
+        "🚀🐝 SWARM HUG BOOTING UP 🐝🚀",
+        ChatKind::Boot,
     );
     writeln!(file, "{}", banner)
 }
@@ -175,7 +545,7 @@ pub fn write_merge_status<P: AsRef<Path>>(
 ) -> io::Result<()> {
     let status = if success { "success" } else { "conflict" };
     let msg = format!("Merge {} for {}: {}", status, agent_name, message);
-    write_message(path, "ScrumMaster", &msg)
+    write_entry(path, "ScrumMaster", &msg, ChatKind::Merge)
 }
 
 /// Parse a chat line into (timestamp, agent_name, message).
@@ -193,6 +563,58 @@ pub fn parse_line(line: &str) -> Option<(&str, &str, &str)> {
     Some((timestamp, agent_name, message))
 }
 
+/// Parse a markdown or JSONL chat line.
+///
+/// Markdown lines carry no kind, so they parse as messages (or heartbeats).
+pub fn parse_entry(line: &str) -> Option<ChatEntry> {
+    if line.trim_start().starts_with('{') {
+        return parse_jsonl_entry(line);
+    }
+    let (timestamp, agent, message) = parse_line(line)?;
+    let (message, kind) = match message.trim_start().strip_prefix(HEARTBEAT_PREFIX) {
+        Some(rest) => (rest.trim_start(), ChatKind::Heartbeat),
+        None => (message, ChatKind::Message),
+    };
+    Some(ChatEntry {
+        timestamp: timestamp.to_string(),
+        agent: agent.to_string(),
+        message: message.to_string(),
+        kind,
+    })
+}
+
+/// Render a chat line for display: JSONL entries become markdown lines,
+/// anything else is returned unchanged.
+pub fn display_line(line: &str) -> String {
+    match parse_jsonl_entry(line) {
+        Some(entry) => entry.to_markdown(),
+        None => line.to_string(),
+    }
+}
+
+fn parse_jsonl_entry(line: &str) -> Option<ChatEntry> {
+    let mut entry = ChatEntry {
+        timestamp: String::new(),
+        agent: String::new(),
+        message: String::new(),
+        kind: ChatKind::Message,
+    };
+    let mut has_agent = false;
+    for (key, value) in parse_json_fields(line)? {
+        match key.as_str() {
+            "ts" => entry.timestamp = value,
+            "agent" => {
+                entry.agent = value;
+                has_agent = true;
+            }
+            "message" => entry.message = value,
+            "kind" => entry.kind = ChatKind::parse(&value).unwrap_or_default(),
+            _ => {}
+        }
+    }
+    has_agent.then_some(entry)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,16 +746,28 @@ mod tests {
         let tmp = NamedTempFile::new().unwrap();
         let path = tmp.path();
 
-        write_sprint_status(path, "Alpha", 3, 2, 1, 4, 7).unwrap();
+        write_sprint_status(path, "Alpha", 3, 2, 1, 0, 4, 7).unwrap();
 
         let content = std::fs::read_to_string(path).unwrap();
         assert!(content.contains("SPRINT STATUS: Alpha Sprint 3 complete"));
         assert!(content.contains("SPRINT STATUS: Completed this sprint: 2"));
         assert!(content.contains("SPRINT STATUS: Failed this sprint: 1"));
+        assert!(!content.contains("Merge failures"));
         assert!(content.contains("SPRINT STATUS: Remaining tasks: 4"));
         assert!(content.contains("SPRINT STATUS: Total tasks: 7"));
     }
 
+    #[test]
+    fn test_write_sprint_status_reports_merge_failures() {
+        let tmp = NamedTempFile::new().unwrap();
+        let path = tmp.path();
+
+        write_sprint_status(path, "Alpha", 3, 2, 1, 2, 4, 7).unwrap();
+
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(content.contains("SPRINT STATUS: Merge failures: 2"));
+    }
+
     #[test]
     fn test_write_merge_status_success() {
         let tmp = NamedTempFile::new().unwrap();
@@ -356,6 +790,289 @@ mod tests {
         assert!(content.contains("Merge conflict for Betty"));
     }
 
+    #[test]
+    fn test_read_filtered_keeps_one_agent() {
+        let tmp = NamedTempFile::new().unwrap();
+        let path = tmp.path();
+
+        write_message(path, "Aaron", "First from Aaron").unwrap();
+        write_message(path, "Betty", "First from Betty").unwrap();
+        write_message(path, "Carlos", "First from Carlos").unwrap();
+        write_message(path, "Betty", "Second from Betty").unwrap();
+        write_heartbeat(path, "Betty", "Still working").unwrap();
+        write_message(path, "Aaron", "Mentions Betty | in text").unwrap();
+
+        for agent in ["Betty", "betty", "B", "b"] {
+            let lines = read_filtered(path, agent, 10).unwrap();
+            let messages: Vec<&str> = lines.iter().map(|l| parse_line(l).unwrap().2).collect();
+            assert_eq!(
+                messages,
+                vec![
+                    "First from Betty",
+                    "Second from Betty",
+                    "AGENT_ACTIVITY: Still working"
+                ],
+                "{}",
+                agent
+            );
+        }
+
+        let last = read_filtered(path, "aaron", 1).unwrap();
+        assert_eq!(last.len(), 1);
+        assert!(last[0].ends_with(" | Aaron | Mentions Betty | in text"));
+
+        assert!(read_filtered(path, "Zane", 10).unwrap().is_empty());
+        assert_eq!(read_filtered(path, "scrummaster", 10).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_read_filtered_jsonl() {
+        let tmp = jsonl_file();
+        let path = tmp.path();
+
+        write_message(path, "Aaron", "From Aaron").unwrap();
+        write_message(path, "Betty", "From Betty").unwrap();
+        write_message(path, "Carlos", "From Carlos").unwrap();
+
+        let lines = read_filtered(path, "c", 5).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].ends_with(" | Carlos | From Carlos"));
+    }
+
+    #[test]
+    fn test_write_message_level_markers_parse_back() {
+        let tmp = NamedTempFile::new().unwrap();
+        let path = tmp.path();
+
+        write_message_level(path, "Aaron", Level::Error, "Failed: Task one - boom").unwrap();
+        write_message_level(path, "ScrumMaster", Level::Warn, "PR: skipped (no remote)").unwrap();
+        write_message_level(path, "Betty", Level::Info, "Starting: Task two").unwrap();
+        write_message(path, "Carlos", "[WARN]ing is not a marker").unwrap();
+
+        let content = std::fs::read_to_string(path).unwrap();
+        let parsed: Vec<(&str, Level, &str)> = content
+            .lines()
+            .map(|line| {
+                let (_, agent, message) = parse_line(line).unwrap();
+                let (level, text) = Level::of_message(message);
+                (agent, level, text)
+            })
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                ("Aaron", Level::Error, "Failed: Task one - boom"),
+                ("ScrumMaster", Level::Warn, "PR: skipped (no remote)"),
+                ("Betty", Level::Info, "Starting: Task two"),
+                ("Carlos", Level::Info, "[WARN]ing is not a marker"),
+            ]
+        );
+        assert!(content
+            .lines()
+            .next()
+            .unwrap()
+            .ends_with(" | Aaron | [ERROR] Failed: Task one - boom"));
+    }
+
+    #[test]
+    fn test_write_message_level_jsonl() {
+        let tmp = jsonl_file();
+        let path = tmp.path();
+
+        write_message_level(path, "Aaron", Level::Error, "Merge failed").unwrap();
+
+        let content = std::fs::read_to_string(path).unwrap();
+        let entry = parse_entry(content.lines().next().unwrap()).unwrap();
+        assert_eq!(
+            Level::of_message(&entry.message),
+            (Level::Error, "Merge failed")
+        );
+        let display = display_line(content.lines().next().unwrap());
+        let (_, _, message) = parse_line(&display).unwrap();
+        assert_eq!(Level::of_message(message).0, Level::Error);
+    }
+
+    #[test]
+    fn test_level_parse() {
+        assert_eq!(Level::parse("warning"), Some(Level::Warn));
+        assert_eq!(Level::parse("ERROR"), Some(Level::Error));
+        assert_eq!(Level::parse("debug"), None);
+        for level in [Level::Info, Level::Warn, Level::Error] {
+            assert_eq!(Level::parse(level.as_str()), Some(level));
+        }
+        assert!(Level::Info < Level::Warn && Level::Warn < Level::Error);
+    }
+
+    #[test]
+    fn test_chat_timezone_parse() {
+        assert_eq!(ChatTimezone::parse("local"), Some(ChatTimezone::Local));
+        assert_eq!(ChatTimezone::parse("UTC"), Some(ChatTimezone::Utc));
+        assert_eq!(ChatTimezone::parse("utc"), Some(ChatTimezone::Utc));
+        for (input, secs) in [("+05:30", 19800), ("-0400", -14400), ("+09", 32400)] {
+            assert_eq!(
+                ChatTimezone::parse(input),
+                Some(ChatTimezone::Offset(FixedOffset::east_opt(secs).unwrap())),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            ChatTimezone::parse("America/New_York"),
+            Some(ChatTimezone::Named(chrono_tz::America::New_York))
+        );
+        for bad in ["Mars/Base", "+25:00", "+5:3", "+05:30:00", ""] {
+            assert_eq!(ChatTimezone::parse(bad), None, "{}", bad);
+        }
+
+        for tz in ["local", "UTC", "+05:30", "America/New_York"] {
+            assert_eq!(ChatTimezone::parse(tz).unwrap().to_string(), tz);
+        }
+    }
+
+    #[test]
+    fn test_is_valid_time_format() {
+        assert!(is_valid_time_format(DEFAULT_TIME_FORMAT));
+        assert!(is_valid_time_format("%Y-%m-%dT%H:%M:%S%:z"));
+        assert!(!is_valid_time_format("%H:%M | %Z"));
+        assert!(!is_valid_time_format("%Y-%Q"));
+        assert!(!is_valid_time_format(" "));
+    }
+
+    #[test]
+    fn test_timestamps_in_utc_and_offset_parse_back() {
+        use chrono::TimeZone;
+
+        let now = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        let with_offset = "%Y-%m-%dT%H:%M:%S%:z";
+        let cases = [
+            (
+                ChatTimezone::Utc,
+                DEFAULT_TIME_FORMAT,
+                "2024-01-15 10:30:00",
+            ),
+            (
+                ChatTimezone::parse("-04:00").unwrap(),
+                with_offset,
+                "2024-01-15T06:30:00-04:00",
+            ),
+            (
+                ChatTimezone::parse("America/New_York").unwrap(),
+                "%Y-%m-%d %H:%M:%S %Z",
+                "2024-01-15 05:30:00 EST",
+            ),
+        ];
+
+        for (timezone, format, expected) in cases {
+            let timestamp = timezone.format(now, format);
+            assert_eq!(timestamp, expected);
+
+            let line = format_message_with_timestamp(&timestamp, "Aaron", "Starting task");
+            assert_eq!(
+                parse_line(&line),
+                Some((expected, "Aaron", "Starting task"))
+            );
+
+            let entry = parse_entry(&line).unwrap();
+            assert_eq!(entry.timestamp, expected);
+            assert_eq!(parse_entry(&entry.to_jsonl()), Some(entry));
+        }
+
+        // Offset timestamps identify the same instant as the UTC one
+        let parsed = DateTime::parse_from_str("2024-01-15T06:30:00-04:00", with_offset).unwrap();
+        assert_eq!(parsed.with_timezone(&Utc), now);
+    }
+
+    fn jsonl_file() -> tempfile::NamedTempFile {
+        tempfile::Builder::new()
+            .suffix(".jsonl")
+            .tempfile()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_jsonl_write_then_parse_round_trip() {
+        let tmp = jsonl_file();
+        let path = tmp.path();
+
+        write_message(path, "Aaron", "Fix \"quoted\" | piped\\path\nline two").unwrap();
+        write_sprint_plan(path, 2, &[('B', "Task 1")]).unwrap();
+        write_heartbeat(path, "Betty", "Still working").unwrap();
+        write_merge_status(path, "Aaron", true, "merged").unwrap();
+
+        let content = std::fs::read_to_string(path).unwrap();
+        let entries: Vec<ChatEntry> = content.lines().map(|l| parse_entry(l).unwrap()).collect();
+        assert_eq!(entries.len(), 5);
+        assert!(content.lines().all(|l| l.starts_with("{\"ts\": \"")));
+
+        assert_eq!(entries[0].agent, "Aaron");
+        assert_eq!(entries[0].message, "Fix \"quoted\" | piped\\path\nline two");
+        assert_eq!(entries[0].kind, ChatKind::Message);
+        assert_eq!(entries[1].message, "Sprint 2 plan: 1 task(s) assigned");
+        assert_eq!(entries[1].kind, ChatKind::Plan);
+        assert_eq!(entries[2].message, "Betty assigned: Task 1");
+        assert_eq!(
+            (entries[3].kind, entries[3].message.as_str()),
+            (ChatKind::Heartbeat, "Still working")
+        );
+        assert_eq!(entries[4].kind, ChatKind::Merge);
+
+        // Rendering the parsed entry reproduces the written line
+        for (line, entry) in content.lines().zip(&entries) {
+            assert_eq!(entry.to_jsonl(), line);
+        }
+        assert!(is_heartbeat_line(content.lines().nth(3).unwrap()));
+        assert!(!is_heartbeat_line(content.lines().next().unwrap()));
+    }
+
+    #[test]
+    fn test_jsonl_read_recent_renders_markdown() {
+        let tmp = jsonl_file();
+        let path = tmp.path();
+
+        write_boot_message(path).unwrap();
+        write_message(path, "Aaron", "Starting task").unwrap();
+        write_message(path, "Betty", "Also starting").unwrap();
+
+        let recent = read_recent(path, 2).unwrap();
+        assert_eq!(recent.len(), 2);
+        let (_, agent, message) = parse_line(&recent[0]).unwrap();
+        assert_eq!((agent, message), ("Aaron", "Starting task"));
+
+        let betty = read_from_agent(path, "Betty").unwrap();
+        assert_eq!(betty.len(), 1);
+        assert!(betty[0].ends_with(" | Betty | Also starting"));
+
+        let content = std::fs::read_to_string(path).unwrap();
+        let boot = parse_entry(content.lines().next().unwrap()).unwrap();
+        assert_eq!(boot.kind, ChatKind::Boot);
+    }
+
+    #[test]
+    fn test_parse_entry_accepts_both_formats() {
+        let markdown = "2024-01-15 10:30:00 | Aaron | AGENT_ACTIVITY: busy";
+        let entry = parse_entry(markdown).unwrap();
+        assert_eq!(entry.kind, ChatKind::Heartbeat);
+        assert_eq!(entry.message, "busy");
+        assert_eq!(entry.to_markdown(), markdown);
+        assert_eq!(display_line(markdown), markdown);
+
+        let jsonl = entry.to_jsonl();
+        assert_eq!(
+            jsonl,
+            "{\"ts\": \"2024-01-15 10:30:00\", \"agent\": \"Aaron\", \"message\": \"busy\", \"kind\": \"heartbeat\"}"
+        );
+        assert_eq!(parse_entry(&jsonl), Some(entry));
+        assert_eq!(display_line(&jsonl), markdown);
+
+        assert!(parse_entry("{\"message\": \"no agent\"}").is_none());
+        assert!(parse_entry("{\"agent\": \"Aaron\"").is_none());
+        assert_eq!(
+            parse_entry("{\"agent\": \"A\\u00e9\", \"kind\": \"other\"}")
+                .map(|e| (e.agent, e.kind)),
+            Some(("A\u{e9}".to_string(), ChatKind::Message))
+        );
+    }
+
     #[test]
     fn test_write_boot_message() {
         let tmp = NamedTempFile::new().unwrap();
@@ -26,6 +26,7 @@ pub mod codes {
     pub const BRIGHT_CYAN: &str = "\x1b[96m";
 }
 
+use crate::chat::Level;
 use codes::*;
 
 /// Colors for agent names - deterministic based on agent initial
@@ -44,7 +45,7 @@ const AGENT_COLORS: &[&str] = &[
 
 /// Get a deterministic color for an agent based on their initial.
 pub fn agent_color(initial: char) -> &'static str {
-    let index = (initial.to_ascii_uppercase() as usize).wrapping_sub('A' as usize);
+    let index = crate::agent::index_of(initial).unwrap_or(0);
     AGENT_COLORS[index % AGENT_COLORS.len()]
 }
 
@@ -58,7 +59,14 @@ pub fn agent(name: &str) -> String {
 /// Color an agent name with their initial for display.
 pub fn agent_with_initial(name: &str, initial: char) -> String {
     let color = agent_color(initial);
-    format!("{}{}{}({}){}", BOLD, color, name, initial, RESET)
+    format!(
+        "{}{}{}({}){}",
+        BOLD,
+        color,
+        name,
+        crate::agent::id(initial),
+        RESET
+    )
 }
 
 /// Color a timestamp (dim white).
@@ -134,6 +142,14 @@ pub fn chat_line(line: &str) -> String {
     } else {
         message.to_string()
     };
+    // Highlight the [WARN]/[ERROR] severity marker
+    let colored_message = match Level::of_message(message).0 {
+        Level::Error => {
+            colored_message.replacen("[ERROR]", &format!("{}{}[ERROR]{}", BOLD, RED, RESET), 1)
+        }
+        Level::Warn => colored_message.replacen("[WARN]", &format!("{}[WARN]{}", YELLOW, RESET), 1),
+        Level::Info => colored_message,
+    };
 
     format!(
         "{} | {} | {}",
@@ -251,6 +267,14 @@ mod tests {
         assert!(colored.contains("Carlos"), "Should contain agent name");
     }
 
+    #[test]
+    fn test_chat_line_levels() {
+        let colored = chat_line("2026-01-26 00:01:26 | ScrumMaster | [ERROR] Merge failed");
+        assert!(colored.contains(&format!("{}{}[ERROR]{}", BOLD, RED, RESET)));
+        let colored = chat_line("2026-01-26 00:01:26 | ScrumMaster | [WARN] PR: skipped");
+        assert!(colored.contains(&format!("{}[WARN]{}", YELLOW, RESET)));
+    }
+
     #[test]
     fn test_chat_line_invalid_format() {
         let line = "this is not a valid chat line";
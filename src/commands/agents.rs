@@ -1,12 +1,22 @@
 use swarm::agent;
 use swarm::config::Config;
 
-/// List agent names and initials.
+/// List agent names and initials from the effective roster.
 pub fn cmd_agents(_config: &Config) -> Result<(), String> {
+    let roster = agent::roster();
     println!("Available Agents:");
-    for (i, name) in agent::NAMES.iter().enumerate() {
-        let initial = agent::INITIALS[i];
-        println!("  {} - {}", initial, name);
+    for &(initial, ref name) in roster.agents() {
+        let skills = roster.skills(initial);
+        if skills.is_empty() {
+            println!("  {} - {}", agent::id(initial), name);
+        } else {
+            println!(
+                "  {} - {} ({})",
+                agent::id(initial),
+                name,
+                skills.join(", ")
+            );
+        }
     }
     Ok(())
 }
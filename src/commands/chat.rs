@@ -0,0 +1,67 @@
+use swarm::agent;
+use swarm::chat;
+use swarm::color;
+use swarm::config::{CliArgs, Config};
+
+/// Lines printed when `--last` is not given.
+const DEFAULT_LAST: usize = 20;
+
+/// Chat author for planning, status, and merge lines.
+const SCRUM_MASTER: &str = "ScrumMaster";
+
+/// Print the project's recent chat lines, optionally only those from `--agent`.
+pub fn cmd_chat(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    let count = cli.last.unwrap_or(DEFAULT_LAST);
+    let path = &config.files_chat;
+
+    let lines = match cli.agent.as_deref() {
+        Some(agent) => {
+            check_agent(agent)?;
+            chat::read_filtered(path, agent, count)
+        }
+        None => chat::read_recent(path, count),
+    }
+    .map_err(|e| format!("failed to read chat file {}: {}", path, e))?;
+
+    if lines.is_empty() {
+        match cli.agent.as_deref() {
+            Some(agent) => println!("No chat lines from {} in {}", agent, path),
+            None => println!("No chat lines in {}", path),
+        }
+    }
+    for line in &lines {
+        println!("{}", color::chat_line(line));
+    }
+    Ok(())
+}
+
+/// Reject names that can never match a chat line, listing the valid ones.
+fn check_agent(agent: &str) -> Result<(), String> {
+    if agent::resolve_name(agent).is_some() || agent.eq_ignore_ascii_case(SCRUM_MASTER) {
+        return Ok(());
+    }
+    Err(format!(
+        "unknown agent '{}'\n  Valid agents (name or initial): {}, {}",
+        agent,
+        agent::get_names(usize::MAX).join(", "),
+        SCRUM_MASTER
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_agent_accepts_names_and_initials() {
+        for agent in ["Aaron", "aaron", "A", "z", "scrummaster"] {
+            assert!(check_agent(agent).is_ok(), "{}", agent);
+        }
+
+        let err = check_agent("Bob").unwrap_err();
+        assert!(err.starts_with("unknown agent 'Bob'"), "{}", err);
+        assert!(err.contains("Aaron, Betty"), "{}", err);
+        assert!(err.ends_with("Zane, ScrumMaster"), "{}", err);
+        assert!(check_agent("").is_err());
+    }
+}
@@ -0,0 +1,598 @@
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::git::git_repo_root;
+use crate::project::project_name_for_config;
+use swarm::config::{CliArgs, Config};
+use swarm::task::{TaskList, TaskStatus};
+use swarm::team::{self, Team};
+use swarm::worktree;
+
+/// Everything cleanup would remove for a single team.
+///
+/// Built once and used by both `--dry-run` (print only) and real cleanup
+/// (apply), so the two cannot disagree about scope.
+#[derive(Debug, Default)]
+struct TeamCleanupPlan {
+    worktrees_dir: PathBuf,
+    /// Registered worktrees under the team's worktrees dir (including preserved).
+    worktrees: Vec<PathBuf>,
+    /// Agent branches namespaced to this team.
+    branches: Vec<String>,
+    tasks_path: PathBuf,
+    /// Descriptions of tasks currently assigned to an agent.
+    assigned_tasks: Vec<String>,
+    /// Enumeration failures (reported alongside apply errors).
+    errors: Vec<String>,
+}
+
+/// Result of cleaning up a single team.
+#[derive(Debug, Default)]
+struct TeamCleanupReport {
+    worktrees_removed: usize,
+    branches_deleted: usize,
+    tasks_unassigned: usize,
+    errors: Vec<String>,
+}
+
+/// Remove a project's agent worktrees (including preserved ones), agent
+/// branches, and task assignments. With `--all-teams`, every project is
+/// cleaned and errors are reported together at the end. With `--dry-run`,
+/// only print what would be removed. With `--branches`, only agent branches
+/// that are no longer checked out (e.g. kept via `agents.keep_branches`) are
+/// deleted.
+pub fn cmd_cleanup(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    let repo_root = git_repo_root()?;
+    let teams = if cli.all_teams {
+        team::list_teams()?
+            .into_iter()
+            .filter(|t| !t.name.starts_with('.'))
+            .collect()
+    } else {
+        vec![Team::new(&project_name_for_config(config))]
+    };
+
+    if teams.is_empty() {
+        println!("No projects found.");
+        return Ok(());
+    }
+
+    let plans: Vec<(String, TeamCleanupPlan)> = teams
+        .iter()
+        .map(|t| {
+            let plan = if cli.branches {
+                plan_branch_cleanup(&repo_root, t, &config.branch_prefix)
+            } else {
+                plan_team_cleanup(&repo_root, t, &config.branch_prefix)
+            };
+            (t.name.clone(), plan)
+        })
+        .collect();
+
+    if cli.dry_run {
+        print_dry_run(&repo_root, &plans);
+        return Ok(());
+    }
+
+    // Prompt before deleting anything, unless bypassed or non-interactive (CI).
+    if !cli.yes && io::stdin().is_terminal() && !confirm_cleanup(&plans)? {
+        println!("Cleanup cancelled.");
+        return Ok(());
+    }
+
+    let reports = apply_cleanup_plans(&repo_root, plans);
+    if let Err(e) = worktree::prune_worktrees_in(&repo_root) {
+        eprintln!("warning: {}", e);
+    }
+
+    let mut errors = Vec::new();
+    println!("Cleanup summary:");
+    for (name, report) in &reports {
+        println!(
+            "  {}: removed {} worktree(s), deleted {} branch(es), unassigned {} task(s){}",
+            name,
+            report.worktrees_removed,
+            report.branches_deleted,
+            report.tasks_unassigned,
+            if report.errors.is_empty() {
+                ""
+            } else {
+                " [errors]"
+            }
+        );
+        for e in &report.errors {
+            errors.push(format!("{}: {}", name, e));
+        }
+    }
+
+    if errors.is_empty() {
+        println!("Cleanup complete.");
+        Ok(())
+    } else {
+        Err(format!(
+            "cleanup completed with errors:\n{}",
+            errors.join("\n")
+        ))
+    }
+}
+
+/// Remove one team's worktrees, agent branches, and task assignments.
+///
+/// Returns the problems encountered; used by `swarm team archive`.
+pub(crate) fn cleanup_team(repo_root: &Path, team: &Team, branch_prefix: &str) -> Vec<String> {
+    let mut report =
+        apply_cleanup_plan(repo_root, plan_team_cleanup(repo_root, team, branch_prefix));
+    if let Err(e) = worktree::prune_worktrees_in(repo_root) {
+        report.errors.push(e);
+    }
+    report.errors
+}
+
+fn print_dry_run(repo_root: &Path, plans: &[(String, TeamCleanupPlan)]) {
+    println!("Dry run: nothing will be removed.");
+    for (name, plan) in plans {
+        println!();
+        println!("{}:", name);
+        println!("  Worktrees ({}):", plan.worktrees.len());
+        for path in &plan.worktrees {
+            let display = path.strip_prefix(repo_root).unwrap_or(path);
+            println!("    - {}", display.display());
+        }
+        println!("  Branches ({}):", plan.branches.len());
+        for branch in &plan.branches {
+            println!("    - {}", branch);
+        }
+        println!("  Assignments ({}):", plan.assigned_tasks.len());
+        for task in &plan.assigned_tasks {
+            println!("    - {}", task);
+        }
+        for e in &plan.errors {
+            println!("  warning: {}", e);
+        }
+    }
+}
+
+/// Total worktrees, branches, and assignments across all plans.
+fn cleanup_totals(plans: &[(String, TeamCleanupPlan)]) -> (usize, usize, usize) {
+    plans.iter().fold((0, 0, 0), |(w, b, a), (_, plan)| {
+        (
+            w + plan.worktrees.len(),
+            b + plan.branches.len(),
+            a + plan.assigned_tasks.len(),
+        )
+    })
+}
+
+fn confirm_cleanup(plans: &[(String, TeamCleanupPlan)]) -> Result<bool, String> {
+    let (worktrees, branches, assignments) = cleanup_totals(plans);
+    let names: Vec<&str> = plans.iter().map(|(name, _)| name.as_str()).collect();
+    println!("Cleanup will affect project(s): {}", names.join(", "));
+    println!("  Worktrees to remove:     {}", worktrees);
+    println!("  Branches to delete:      {}", branches);
+    println!("  Assignments to clear:    {}", assignments);
+    println!();
+    println!("Proceed with cleanup? [y/N]");
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| format!("failed to read confirmation: {}", e))?;
+    Ok(is_confirmation(&input))
+}
+
+/// Only an explicit `y`/`yes` confirms; anything else (including empty) declines.
+pub(crate) fn is_confirmation(input: &str) -> bool {
+    let answer = input.trim().to_lowercase();
+    answer == "y" || answer == "yes"
+}
+
+/// Enumerate what cleanup would remove for a team without changing anything.
+fn plan_team_cleanup(repo_root: &Path, team: &Team, branch_prefix: &str) -> TeamCleanupPlan {
+    let team_root = repo_root.join(&team.root);
+    let mut plan = TeamCleanupPlan {
+        worktrees_dir: team_root.join("worktrees"),
+        tasks_path: team_root.join("tasks.md"),
+        ..Default::default()
+    };
+
+    // Worktrees (preserved worktrees live under worktrees/preserved/).
+    match registered_worktrees_under(repo_root, &plan.worktrees_dir) {
+        Ok(paths) => plan.worktrees = paths,
+        Err(e) => plan.errors.push(e),
+    }
+
+    match agent_branches_for_team(repo_root, &team.name, branch_prefix) {
+        Ok(branches) => plan.branches = branches,
+        Err(e) => plan.errors.push(e),
+    }
+
+    if plan.tasks_path.exists() {
+        match fs::read_to_string(&plan.tasks_path) {
+            Ok(content) => {
+                plan.assigned_tasks = TaskList::parse(&content)
+                    .tasks
+                    .into_iter()
+                    .filter(|t| matches!(t.status, TaskStatus::Assigned(_)))
+                    .map(|t| t.to_line())
+                    .collect();
+            }
+            Err(e) => plan.errors.push(format!(
+                "failed to read {}: {}",
+                plan.tasks_path.display(),
+                e
+            )),
+        }
+    }
+
+    plan
+}
+
+/// Enumerate only the team's agent branches that no worktree has checked out.
+///
+/// Worktrees and task assignments are left alone; the worktrees dir is left
+/// unset so applying the plan does not remove it.
+fn plan_branch_cleanup(repo_root: &Path, team: &Team, branch_prefix: &str) -> TeamCleanupPlan {
+    let mut plan = TeamCleanupPlan::default();
+    let checked_out: Vec<String> = match worktree::registered_worktrees_in(repo_root) {
+        Ok(worktrees) => worktrees
+            .into_iter()
+            .filter_map(|(_, branch)| branch)
+            .collect(),
+        Err(e) => {
+            plan.errors.push(e);
+            return plan;
+        }
+    };
+    match agent_branches_for_team(repo_root, &team.name, branch_prefix) {
+        Ok(branches) => {
+            plan.branches = branches
+                .into_iter()
+                .filter(|b| !checked_out.contains(b))
+                .collect();
+        }
+        Err(e) => plan.errors.push(e),
+    }
+    plan
+}
+
+/// Apply each team's plan in turn, continuing past failures.
+fn apply_cleanup_plans(
+    repo_root: &Path,
+    plans: Vec<(String, TeamCleanupPlan)>,
+) -> Vec<(String, TeamCleanupReport)> {
+    plans
+        .into_iter()
+        .map(|(name, plan)| (name, apply_cleanup_plan(repo_root, plan)))
+        .collect()
+}
+
+fn apply_cleanup_plan(repo_root: &Path, plan: TeamCleanupPlan) -> TeamCleanupReport {
+    let mut report = TeamCleanupReport {
+        errors: plan.errors,
+        ..Default::default()
+    };
+
+    for path in &plan.worktrees {
+        match worktree::remove_worktree_in(repo_root, path) {
+            Ok(()) => report.worktrees_removed += 1,
+            Err(e) => report.errors.push(e),
+        }
+    }
+    if plan.worktrees_dir.exists() {
+        if let Err(e) = fs::remove_dir_all(&plan.worktrees_dir) {
+            report.errors.push(format!(
+                "failed to remove {}: {}",
+                plan.worktrees_dir.display(),
+                e
+            ));
+        }
+    }
+
+    for branch in &plan.branches {
+        match worktree::delete_branch_in(repo_root, branch) {
+            Ok(true) => report.branches_deleted += 1,
+            Ok(false) => {}
+            Err(e) => report.errors.push(e),
+        }
+    }
+
+    if !plan.assigned_tasks.is_empty() {
+        match unassign_tasks_file(&plan.tasks_path) {
+            Ok(count) => report.tasks_unassigned = count,
+            Err(e) => report.errors.push(e),
+        }
+    }
+
+    report
+}
+
+/// Registered worktrees located under `dir`.
+pub(crate) fn registered_worktrees_under(
+    repo_root: &Path,
+    dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let dir = normalize_path(dir);
+    Ok(worktree::registered_worktrees_in(repo_root)?
+        .into_iter()
+        .map(|(path, _)| path)
+        .filter(|path| normalize_path(path).starts_with(&dir))
+        .collect())
+}
+
+fn normalize_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Agent branches for a team, named `{branch_prefix}{team}-agent-*`.
+fn agent_branches_for_team(
+    repo_root: &Path,
+    team_name: &str,
+    branch_prefix: &str,
+) -> Result<Vec<String>, String> {
+    let pattern = format!("refs/heads/{}{}-agent-*", branch_prefix, team_name);
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["for-each-ref", "--format=%(refname:short)", &pattern])
+        .output()
+        .map_err(|e| format!("failed to run git for-each-ref: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git for-each-ref failed: {}", stderr.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(ToString::to_string)
+        .collect())
+}
+
+fn unassign_tasks_file(tasks_path: &Path) -> Result<usize, String> {
+    let content = fs::read_to_string(tasks_path)
+        .map_err(|e| format!("failed to read {}: {}", tasks_path.display(), e))?;
+    let mut task_list = TaskList::parse(&content);
+    let count = task_list.unassign_all();
+    if count > 0 {
+        fs::write(tasks_path, task_list.to_string())
+            .map_err(|e| format!("failed to write {}: {}", tasks_path.display(), e))?;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn run_git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(args)
+            .output()
+            .expect("failed to run git command");
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn init_repo(repo: &Path) {
+        run_git(repo, &["init"]);
+        run_git(repo, &["config", "user.name", "Swarm Test"]);
+        run_git(repo, &["config", "user.email", "swarm-test@example.com"]);
+        fs::write(repo.join("README.md"), "init").expect("write README");
+        run_git(repo, &["add", "."]);
+        run_git(repo, &["commit", "-m", "init"]);
+    }
+
+    fn setup_team(repo: &Path, team: &str) {
+        let team_root = repo.join(".swarm-hug").join(team);
+        fs::create_dir_all(team_root.join("worktrees").join("preserved")).unwrap();
+        fs::write(
+            team_root.join("tasks.md"),
+            "# Tasks\n\n- [A] Task one\n- [ ] Task two\n- [x] Task three (B)\n",
+        )
+        .unwrap();
+
+        let agent_branch = format!("{}-agent-aaron-abc123", team);
+        let agent_path = team_root.join("worktrees").join(&agent_branch);
+        run_git(
+            repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                &agent_branch,
+                agent_path.to_str().unwrap(),
+            ],
+        );
+        let preserved_branch = format!("{}-agent-betty-abc123", team);
+        let preserved_path = team_root
+            .join("worktrees")
+            .join("preserved")
+            .join(format!("{}-preserved-1-1", preserved_branch));
+        run_git(
+            repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                &preserved_branch,
+                preserved_path.to_str().unwrap(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_cleanup_team_removes_worktrees_branches_and_assignments() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        init_repo(repo);
+        setup_team(repo, "alpha");
+        setup_team(repo, "beta");
+
+        let plan = plan_team_cleanup(repo, &Team::new("alpha"), "");
+        let report = apply_cleanup_plan(repo, plan);
+        assert!(report.errors.is_empty(), "errors: {:?}", report.errors);
+        assert_eq!(report.worktrees_removed, 2);
+        assert_eq!(report.branches_deleted, 2);
+        assert_eq!(report.tasks_unassigned, 1);
+
+        assert!(!repo.join(".swarm-hug/alpha/worktrees").exists());
+        assert!(agent_branches_for_team(repo, "alpha", "")
+            .unwrap()
+            .is_empty());
+        let tasks = fs::read_to_string(repo.join(".swarm-hug/alpha/tasks.md")).unwrap();
+        assert!(tasks.contains("- [ ] Task one"));
+        assert!(tasks.contains("- [x] Task three (B)"));
+
+        // Other teams are untouched.
+        assert_eq!(agent_branches_for_team(repo, "beta", "").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_plan_team_cleanup_changes_nothing() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        init_repo(repo);
+        setup_team(repo, "alpha");
+        let tasks_before = fs::read_to_string(repo.join(".swarm-hug/alpha/tasks.md")).unwrap();
+
+        let plan = plan_team_cleanup(repo, &Team::new("alpha"), "");
+        assert!(plan.errors.is_empty(), "errors: {:?}", plan.errors);
+        assert_eq!(plan.worktrees.len(), 2);
+        assert!(plan
+            .worktrees
+            .iter()
+            .any(|p| p.to_string_lossy().contains("preserved")));
+        assert_eq!(
+            plan.branches,
+            vec!["alpha-agent-aaron-abc123", "alpha-agent-betty-abc123"]
+        );
+        assert_eq!(plan.assigned_tasks, vec!["- [A] Task one"]);
+
+        // Planning is read-only.
+        assert_eq!(
+            registered_worktrees_under(repo, &plan.worktrees_dir)
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(agent_branches_for_team(repo, "alpha", "").unwrap().len(), 2);
+        let tasks_after = fs::read_to_string(repo.join(".swarm-hug/alpha/tasks.md")).unwrap();
+        assert_eq!(tasks_before, tasks_after);
+    }
+
+    #[test]
+    fn test_branch_cleanup_only_deletes_unchecked_out_branches() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        init_repo(repo);
+        setup_team(repo, "alpha");
+        run_git(repo, &["branch", "alpha-agent-aaron-abc123-sprint-1"]);
+        let tasks_before = fs::read_to_string(repo.join(".swarm-hug/alpha/tasks.md")).unwrap();
+
+        let plan = plan_branch_cleanup(repo, &Team::new("alpha"), "");
+        assert!(plan.errors.is_empty(), "errors: {:?}", plan.errors);
+        assert!(plan.worktrees.is_empty());
+        assert!(plan.assigned_tasks.is_empty());
+        assert_eq!(plan.branches, vec!["alpha-agent-aaron-abc123-sprint-1"]);
+
+        let report = apply_cleanup_plan(repo, plan);
+        assert!(report.errors.is_empty(), "errors: {:?}", report.errors);
+        assert_eq!(report.branches_deleted, 1);
+        assert_eq!(agent_branches_for_team(repo, "alpha", "").unwrap().len(), 2);
+        assert!(repo.join(".swarm-hug/alpha/worktrees").exists());
+        let tasks_after = fs::read_to_string(repo.join(".swarm-hug/alpha/tasks.md")).unwrap();
+        assert_eq!(tasks_before, tasks_after);
+    }
+
+    #[test]
+    fn test_is_confirmation_requires_explicit_yes() {
+        assert!(is_confirmation("y\n"));
+        assert!(is_confirmation("  YES "));
+        assert!(!is_confirmation("\n"));
+        assert!(!is_confirmation("n"));
+        assert!(!is_confirmation("yep"));
+    }
+
+    #[test]
+    fn test_cleanup_totals_sums_all_plans() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        init_repo(repo);
+        setup_team(repo, "alpha");
+        setup_team(repo, "beta");
+
+        let plans: Vec<(String, TeamCleanupPlan)> = ["alpha", "beta"]
+            .iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    plan_team_cleanup(repo, &Team::new(name), ""),
+                )
+            })
+            .collect();
+        assert_eq!(cleanup_totals(&plans), (4, 4, 2));
+    }
+
+    #[test]
+    fn test_cleanup_teams_continues_past_failing_team() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        init_repo(repo);
+        setup_team(repo, "alpha");
+        // A directory where tasks.md should be makes the broken team fail.
+        fs::create_dir_all(repo.join(".swarm-hug/broken/tasks.md")).unwrap();
+
+        let plans = ["broken", "alpha"]
+            .iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    plan_team_cleanup(repo, &Team::new(name), ""),
+                )
+            })
+            .collect();
+        let reports = apply_cleanup_plans(repo, plans);
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].0, "broken");
+        assert_eq!(reports[0].1.errors.len(), 1);
+        assert_eq!(reports[1].0, "alpha");
+        assert!(reports[1].1.errors.is_empty());
+        assert_eq!(reports[1].1.worktrees_removed, 2);
+    }
+
+    #[test]
+    fn test_cleanup_team_uses_branch_prefix() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path();
+        init_repo(repo);
+        setup_team(repo, "alpha");
+        run_git(repo, &["branch", "swarm/alpha-agent-aaron-def456"]);
+        run_git(repo, &["branch", "swarm/alpha-agent-betty-def456"]);
+
+        let plan = plan_team_cleanup(repo, &Team::new("alpha"), "swarm/");
+        assert_eq!(
+            plan.branches,
+            vec![
+                "swarm/alpha-agent-aaron-def456",
+                "swarm/alpha-agent-betty-def456"
+            ]
+        );
+
+        let report = apply_cleanup_plan(repo, plan);
+        assert!(report.errors.is_empty(), "errors: {:?}", report.errors);
+        assert_eq!(report.branches_deleted, 2);
+        assert!(agent_branches_for_team(repo, "alpha", "swarm/")
+            .unwrap()
+            .is_empty());
+    }
+}
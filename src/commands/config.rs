@@ -0,0 +1,71 @@
+use swarm::config::{self, CliArgs, ConfigReport};
+
+/// Check the configuration and print a pass/fail report.
+///
+/// Runs before `Config::load`, so it also reports problems that would
+/// otherwise stop swarm before any command runs.
+pub fn cmd_config_validate(cli: &CliArgs) -> Result<(), String> {
+    let report = config::check_config(cli);
+    print!("{}", format_report(&report));
+    if report.is_ok() {
+        Ok(())
+    } else {
+        Err(format!(
+            "config validation failed: {} problem(s)",
+            report.problems.len()
+        ))
+    }
+}
+
+fn format_report(report: &ConfigReport) -> String {
+    let mut out = if report.files.is_empty() {
+        "Config files: none (using defaults)\n".to_string()
+    } else {
+        format!("Config files: {}\n", report.files.join(", "))
+    };
+    for problem in &report.problems {
+        out.push_str(&format!(
+            "  FAIL {} ({}): {}\n",
+            problem.setting, problem.source, problem.message
+        ));
+    }
+    if report.is_ok() {
+        out.push_str("Config OK\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swarm::config::{ConfigProblem, ConfigSource};
+
+    #[test]
+    fn test_format_report_lists_problems_with_sources() {
+        let report = ConfigReport {
+            files: vec!["swarm.toml".to_string()],
+            problems: vec![
+                ConfigProblem {
+                    setting: "agents.max_count".to_string(),
+                    source: ConfigSource::File("swarm.toml".to_string()),
+                    message: "invalid agents.max_count: -1".to_string(),
+                },
+                ConfigProblem {
+                    setting: "engine.type".to_string(),
+                    source: ConfigSource::Env("SWARM_ENGINE_TYPE"),
+                    message: "invalid engine.type: gpt".to_string(),
+                },
+            ],
+        };
+        assert_eq!(
+            format_report(&report),
+            "Config files: swarm.toml\n\
+             \x20 FAIL agents.max_count (file swarm.toml): invalid agents.max_count: -1\n\
+             \x20 FAIL engine.type (env SWARM_ENGINE_TYPE): invalid engine.type: gpt\n"
+        );
+        assert_eq!(
+            format_report(&ConfigReport::default()),
+            "Config files: none (using defaults)\nConfig OK\n"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::git::{get_short_commit_for_ref_in, git_repo_root};
+use crate::project::project_name_for_config;
+use swarm::config::{CliArgs, Config};
+use swarm::task::{TaskList, TaskStatus};
+use swarm::team::{
+    self, RuntimeStatePaths, SprintHistory, SprintRange, Team, SPRINT_HISTORY_FILE, SWARM_HUG_DIR,
+};
+
+/// Print `git diff --stat` for a sprint's range and the tasks it completed.
+pub fn cmd_diff(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    let team_name = project_name_for_config(config);
+    let (sprint, range) = find_sprint_range(config, &team_name, cli.sprint)?;
+    let repo_root = git_repo_root()?;
+    print!("{}", sprint_report(&repo_root, &team_name, sprint, &range)?);
+    Ok(())
+}
+
+/// Runtime state directories of the configured target branch's run, or of
+/// every run plus the legacy team root.
+fn runtime_roots(config: &Config, team_name: &str) -> Vec<PathBuf> {
+    if let Some(target) = config.target_branch.as_deref() {
+        let paths = RuntimeStatePaths::for_branches(
+            team_name,
+            config.source_branch.as_deref().unwrap_or(""),
+            target,
+        );
+        return vec![paths.root().to_path_buf()];
+    }
+    let team_root = PathBuf::from(SWARM_HUG_DIR).join(team_name);
+    let mut roots = vec![team_root.clone()];
+    if let Ok(entries) = fs::read_dir(team_root.join("runs")) {
+        roots.extend(entries.flatten().map(|entry| entry.path()));
+    }
+    roots
+}
+
+/// The range of sprint `sprint` (or of the last completed sprint), taken from
+/// whichever run recorded it most recently.
+fn find_sprint_range(
+    config: &Config,
+    team_name: &str,
+    sprint: Option<usize>,
+) -> Result<(usize, SprintRange), String> {
+    let mut found: Option<(SystemTime, usize, SprintRange)> = None;
+    for root in runtime_roots(config, team_name) {
+        let (path, candidate) = match sprint {
+            Some(n) => {
+                let path = root.join(team::sprint_range_file(n));
+                let range = SprintRange::load(&path)?.map(|range| (n, range));
+                (path, range)
+            }
+            None => {
+                let path = root.join(SPRINT_HISTORY_FILE);
+                if !path.exists() {
+                    continue;
+                }
+                let history = SprintHistory::load_from(&path)?;
+                let range = history
+                    .last_range
+                    .map(|range| (history.total_sprints, range));
+                (path, range)
+            }
+        };
+        let Some((n, range)) = candidate else {
+            continue;
+        };
+        let modified = fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .unwrap_or(UNIX_EPOCH);
+        if found
+            .as_ref()
+            .is_none_or(|(newest, _, _)| modified > *newest)
+        {
+            found = Some((modified, n, range));
+        }
+    }
+
+    found
+        .map(|(_, n, range)| (n, range))
+        .ok_or_else(|| match sprint {
+            Some(n) => format!("no recorded changes for sprint {} of '{}'", n, team_name),
+            None => format!("no completed sprint recorded for '{}'", team_name),
+        })
+}
+
+/// The diff stat of `range` followed by the tasks completed within it.
+fn sprint_report(
+    repo_root: &Path,
+    team_name: &str,
+    sprint: usize,
+    range: &SprintRange,
+) -> Result<String, String> {
+    let short = |commit: &str| {
+        get_short_commit_for_ref_in(repo_root, commit).unwrap_or_else(|| commit.to_string())
+    };
+    let mut out = format!(
+        "Sprint {} of {} ({} -> {}): {}..{}\n",
+        sprint,
+        team_name,
+        range.source_branch,
+        range.target_branch,
+        short(&range.start_commit),
+        short(&range.end_commit)
+    );
+
+    let stat = git_stdout(
+        repo_root,
+        &["diff", "--stat", &range.start_commit, &range.end_commit],
+    )?;
+    if stat.trim().is_empty() {
+        out.push_str("  (no changes)\n");
+    } else {
+        out.push_str(&stat);
+    }
+
+    let tasks_path = Team::new(team_name).tasks_path();
+    let before = tasks_at(repo_root, &range.start_commit, &tasks_path);
+    let completed: Vec<String> = tasks_at(repo_root, &range.end_commit, &tasks_path)
+        .tasks
+        .into_iter()
+        .filter(|task| matches!(task.status, TaskStatus::Completed(_)))
+        .filter(|task| {
+            !before.tasks.iter().any(|old| {
+                old.description == task.description
+                    && matches!(old.status, TaskStatus::Completed(_))
+            })
+        })
+        .map(|task| task.description)
+        .collect();
+    out.push_str(&format!("Completed tasks ({}):\n", completed.len()));
+    for description in &completed {
+        out.push_str(&format!("  - {}\n", description));
+    }
+    Ok(out)
+}
+
+/// The task list committed at `commit`, or an empty list if it has none.
+fn tasks_at(repo_root: &Path, commit: &str, tasks_path: &Path) -> TaskList {
+    let spec = format!(
+        "{}:{}",
+        commit,
+        tasks_path.to_string_lossy().replace('\\', "/")
+    );
+    let content = git_stdout(repo_root, &["show", &spec]).unwrap_or_default();
+    TaskList::parse(&content)
+}
+
+fn git_stdout(repo_root: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run git {}: {}", args[0], e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git {} failed: {}", args[0], stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::with_temp_cwd;
+
+    fn git(args: &[&str]) -> String {
+        git_stdout(Path::new("."), args).expect("run git")
+    }
+
+    #[test]
+    fn test_diff_lists_changed_files_and_completed_tasks() {
+        with_temp_cwd(|| {
+            git(&["init", "-b", "main"]);
+            git(&["config", "user.name", "Swarm Test"]);
+            git(&["config", "user.email", "swarm-test@example.com"]);
+            fs::create_dir_all(".swarm-hug/greenfield").unwrap();
+            let tasks = ".swarm-hug/greenfield/tasks.md";
+            fs::write(tasks, "# Tasks\n\n- [ ] Build login\n- [ ] Add logout\n").unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-m", "assignments"]);
+            let start = git(&["rev-parse", "HEAD"]).trim().to_string();
+
+            fs::create_dir_all("src").unwrap();
+            fs::write("src/login.rs", "pub fn login() {}\n").unwrap();
+            fs::write(
+                tasks,
+                "# Tasks\n\n- [x] Build login (A)\n- [ ] Add logout\n",
+            )
+            .unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-m", "Aaron: Build login"]);
+            let end = git(&["rev-parse", "HEAD"]).trim().to_string();
+
+            let mut config = Config::default();
+            config.project = Some("greenfield".to_string());
+            config.target_branch = Some("main".to_string());
+            let paths = RuntimeStatePaths::for_branches("greenfield", "main", "main");
+            let range = SprintRange {
+                start_commit: start,
+                end_commit: end,
+                source_branch: "main".to_string(),
+                target_branch: "main".to_string(),
+            };
+            range.save(&paths.sprint_range_path(2)).unwrap();
+            let mut history = SprintHistory::load_from(&paths.sprint_history_path()).unwrap();
+            history.team_name = "greenfield".to_string();
+            history.total_sprints = 2;
+            history.last_range = Some(range.clone());
+            history.save().unwrap();
+
+            assert_eq!(
+                find_sprint_range(&config, "greenfield", None).unwrap(),
+                (2, range.clone())
+            );
+            assert_eq!(
+                find_sprint_range(&config, "greenfield", Some(2)).unwrap(),
+                (2, range.clone())
+            );
+            let err = find_sprint_range(&config, "greenfield", Some(1)).unwrap_err();
+            assert!(err.contains("sprint 1"), "{}", err);
+
+            let report = sprint_report(Path::new("."), "greenfield", 2, &range).unwrap();
+            assert!(report.starts_with("Sprint 2 of greenfield (main -> main): "));
+            assert!(report.contains("src/login.rs"), "{}", report);
+            assert!(report.contains("2 files changed"), "{}", report);
+            assert!(report.contains("Completed tasks (1):\n  - Build login\n"));
+            assert!(!report.contains("Add logout"), "{}", report);
+        });
+    }
+}
@@ -0,0 +1,181 @@
+use std::process::Command;
+
+use swarm::json::parse_json_string;
+
+/// Most issues requested from `gh issue list` (its own default is 30).
+const ISSUE_LIMIT: &str = "1000";
+
+/// An issue from `gh issue list --json number,title,state`.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Issue {
+    number: u64,
+    title: String,
+    state: String,
+}
+
+/// Fetch `repo`'s open issues (optionally only those labeled `label`) with
+/// `gh` and render them as `- [ ] (#N) Title` task lines.
+pub(crate) fn fetch_issue_tasks(repo: &str, label: Option<&str>) -> Result<String, String> {
+    fetch_issue_tasks_with_command("gh", repo, label)
+}
+
+fn fetch_issue_tasks_with_command(
+    gh_command: &str,
+    repo: &str,
+    label: Option<&str>,
+) -> Result<String, String> {
+    let mut args = vec![
+        "issue",
+        "list",
+        "--repo",
+        repo,
+        "--state",
+        "open",
+        "--limit",
+        ISSUE_LIMIT,
+        "--json",
+        "number,title,state",
+    ];
+    if let Some(label) = label {
+        args.extend(["--label", label]);
+    }
+    let output = Command::new(gh_command)
+        .args(&args)
+        .output()
+        .map_err(|e| format!("failed to run {} issue list: {}", gh_command, e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "{} issue list failed: {}",
+            gh_command,
+            stderr.trim()
+        ));
+    }
+    issues_to_tasks(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Convert `gh issue list` JSON to task lines, skipping closed issues. The
+/// issue number becomes the task's `(#N)` id.
+fn issues_to_tasks(json: &str) -> Result<String, String> {
+    let issues = parse_issues(json).ok_or("could not parse gh issue list output")?;
+    Ok(issues
+        .iter()
+        .filter(|issue| issue.state.eq_ignore_ascii_case("open"))
+        .map(|issue| {
+            let title = issue.title.split_whitespace().collect::<Vec<_>>().join(" ");
+            format!("- [ ] (#{}) {}\n", issue.number, title)
+        })
+        .collect())
+}
+
+/// Parse a JSON array of flat issue objects; unknown fields are ignored.
+fn parse_issues(json: &str) -> Option<Vec<Issue>> {
+    let mut rest = json.trim().strip_prefix('[')?.trim_start();
+    let mut issues = Vec::new();
+    while !rest.starts_with(']') {
+        rest = rest.strip_prefix('{')?.trim_start();
+        let mut issue = Issue::default();
+        while !rest.starts_with('}') {
+            let (key, after) = parse_json_string(rest)?;
+            let after = after.trim_start().strip_prefix(':')?.trim_start();
+            let after = if after.starts_with('"') {
+                let (value, after) = parse_json_string(after)?;
+                match key.as_str() {
+                    "title" => issue.title = value,
+                    "state" => issue.state = value,
+                    _ => {}
+                }
+                after
+            } else {
+                let end = after.find([',', '}']).unwrap_or(after.len());
+                if key == "number" {
+                    issue.number = after[..end].trim().parse().ok()?;
+                }
+                &after[end..]
+            };
+            rest = after.trim_start();
+            if let Some(after_comma) = rest.strip_prefix(',') {
+                rest = after_comma.trim_start();
+            } else if !rest.starts_with('}') {
+                return None;
+            }
+        }
+        issues.push(issue);
+        rest = rest[1..].trim_start();
+        if let Some(after_comma) = rest.strip_prefix(',') {
+            rest = after_comma.trim_start();
+        } else if !rest.starts_with(']') {
+            return None;
+        }
+    }
+    Some(issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUES_JSON: &str = r#"[
+  {"number": 42, "state": "OPEN", "title": "Add \"remember me\" to login"},
+  {"number": 7, "state": "CLOSED", "title": "Old bug"},
+  {"number": 3, "state": "OPEN", "title": "Fix  flaky\ntest"}
+]"#;
+
+    #[test]
+    fn test_issues_to_tasks_skips_closed_and_keeps_numbers() {
+        assert_eq!(
+            issues_to_tasks(ISSUES_JSON).unwrap(),
+            "- [ ] (#42) Add \"remember me\" to login\n- [ ] (#3) Fix flaky test\n"
+        );
+        assert_eq!(issues_to_tasks("[]").unwrap(), "");
+        assert!(issues_to_tasks("not json").is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_fetch_issue_tasks_passes_repo_and_label() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let gh_path = temp.path().join("gh");
+        let args_path = temp.path().join("gh-args.txt");
+        fs::write(
+            &gh_path,
+            format!(
+                "#!/bin/sh\nprintf '%s\\n' \"$@\" > \"{}\"\ncat <<'EOF'\n{}\nEOF\n",
+                args_path.display(),
+                ISSUES_JSON
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&gh_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let tasks = fetch_issue_tasks_with_command(
+            gh_path.to_str().unwrap(),
+            "acme/widgets",
+            Some("swarm"),
+        )
+        .unwrap();
+
+        assert!(tasks.starts_with("- [ ] (#42) "), "{}", tasks);
+        let args = fs::read_to_string(&args_path).unwrap();
+        assert_eq!(
+            args.lines().collect::<Vec<_>>(),
+            vec![
+                "issue",
+                "list",
+                "--repo",
+                "acme/widgets",
+                "--state",
+                "open",
+                "--limit",
+                "1000",
+                "--json",
+                "number,title,state",
+                "--label",
+                "swarm",
+            ]
+        );
+    }
+}
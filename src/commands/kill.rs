@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use swarm::config::Config;
+use swarm::process_registry;
+use swarm::team::{self, RuntimeStatePaths};
+
+use crate::project::project_name_for_config;
+
+/// Terminate the engine processes recorded for the project's runs.
+///
+/// With `--target-branch`, only that run's processes are killed; otherwise
+/// every run of the project is swept.
+pub fn cmd_kill(config: &Config) -> Result<(), String> {
+    let team_name = project_name_for_config(config);
+    let mut killed = 0;
+    let mut errors = Vec::new();
+    for path in &pids_files(config, &team_name) {
+        match process_registry::reap_recorded(path) {
+            Ok(processes) => {
+                for process in &processes {
+                    println!("  Killed {} ({})", process.pid, process.name);
+                }
+                killed += processes.len();
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+    println!("Killed {} process(es) for {}.", killed, team_name);
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "kill completed with errors:\n{}",
+            errors.join("\n")
+        ))
+    }
+}
+
+/// PID state files of the configured target branch's run, or of every run.
+pub(crate) fn pids_files(config: &Config, team_name: &str) -> Vec<PathBuf> {
+    match config.target_branch.as_deref() {
+        Some(target) => vec![RuntimeStatePaths::for_branches(
+            team_name,
+            config.source_branch.as_deref().unwrap_or(""),
+            target,
+        )
+        .pids_path()],
+        None => team::run_pids_files(team_name),
+    }
+}
@@ -0,0 +1,107 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::tail::tail_follow_from;
+use swarm::agent;
+use swarm::config::{CliArgs, Config};
+use swarm::log;
+
+/// Lines printed when `--lines` is not given.
+const DEFAULT_LINES: usize = 20;
+
+/// Print the last lines of an agent's log, then keep printing with `--follow`.
+pub fn cmd_logs(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    let agent = cli
+        .agent
+        .as_deref()
+        .ok_or("usage: swarm logs <agent> [--follow] [--lines N]")?;
+    let initial = resolve_agent(agent)?;
+    let path = log::log_file_path(Path::new(&config.files_log_dir), initial);
+
+    let offset = if path.exists() {
+        let count = cli.last.unwrap_or(DEFAULT_LINES);
+        write_tail(&mut io::stdout().lock(), &path, count)?
+    } else if cli.follow {
+        0
+    } else {
+        return Err(format!(
+            "no log for {} yet ({} not found)",
+            agent::name_from_initial(initial)
+                .as_deref()
+                .unwrap_or(agent),
+            path.display()
+        ));
+    };
+
+    if cli.follow {
+        tail_follow_from(&path.to_string_lossy(), true, None, offset, |line| {
+            line.to_string()
+        })?;
+    }
+    Ok(())
+}
+
+/// Resolve a name or agent id (`A`, `AB`) to an initial, listing the valid
+/// names when nothing matches.
+fn resolve_agent(agent: &str) -> Result<char, String> {
+    agent::initial_from_name(agent)
+        .or_else(|| {
+            agent::initial_from_id(agent).filter(|&c| agent::name_from_initial(c).is_some())
+        })
+        .ok_or_else(|| {
+            format!(
+                "unknown agent '{}'\n  Valid agents (name or initial): {}",
+                agent,
+                agent::get_names(usize::MAX).join(", ")
+            )
+        })
+}
+
+/// Write the last `count` lines of `path`, returning the file length so
+/// following can pick up where the tail ended.
+fn write_tail(out: &mut impl Write, path: &Path, count: usize) -> Result<u64, String> {
+    let content =
+        fs::read(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let text = String::from_utf8_lossy(&content);
+    let lines: Vec<&str> = text.lines().collect();
+    for line in &lines[lines.len().saturating_sub(count)..] {
+        writeln!(out, "{}", line).map_err(|e| format!("failed to write output: {}", e))?;
+    }
+    Ok(content.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::with_temp_cwd;
+
+    #[test]
+    fn test_write_tail_prints_last_lines_of_agent_log() {
+        with_temp_cwd(|| {
+            let path = log::log_file_path(Path::new("loop"), resolve_agent("betty").unwrap());
+            fs::create_dir_all("loop").unwrap();
+            let content: String = (1..=30).map(|i| format!("line {}\n", i)).collect();
+            fs::write(&path, &content).unwrap();
+
+            let mut out = Vec::new();
+            let offset = write_tail(&mut out, &path, 3).unwrap();
+
+            assert_eq!(
+                String::from_utf8(out).unwrap(),
+                "line 28\nline 29\nline 30\n"
+            );
+            assert_eq!(offset, content.len() as u64);
+            assert_eq!(path, Path::new("loop/agent-B.log"));
+        });
+    }
+
+    #[test]
+    fn test_resolve_agent_lists_valid_names() {
+        assert_eq!(resolve_agent("Aaron"), Ok('A'));
+        assert_eq!(resolve_agent("c"), Ok('C'));
+        let err = resolve_agent("Bob").unwrap_err();
+        assert!(err.starts_with("unknown agent 'Bob'"), "{}", err);
+        assert!(err.contains("Aaron, Betty"), "{}", err);
+    }
+}
@@ -1,13 +1,38 @@
 pub mod agents;
+pub mod chat;
+pub mod cleanup;
 pub mod cleanup_worktrees;
+pub mod config;
+pub mod diff;
 pub mod init;
+pub mod issues;
+pub mod kill;
+pub mod logs;
 pub mod misc;
 pub mod projects;
+pub mod prune;
+pub mod ps;
 pub mod run;
+pub mod status;
+pub mod team;
+pub mod watch;
+pub mod worktrees;
 
 pub use agents::cmd_agents;
+pub use chat::cmd_chat;
+pub use cleanup::cmd_cleanup;
 pub use cleanup_worktrees::cmd_cleanup_worktrees;
+pub use config::cmd_config_validate;
+pub use diff::cmd_diff;
 pub use init::cmd_init;
+pub use kill::cmd_kill;
+pub use logs::cmd_logs;
 pub use misc::{cmd_customize_prompts, cmd_set_email};
 pub use projects::{cmd_project_init, cmd_projects};
-pub use run::{cmd_run, cmd_run_tui};
+pub use prune::cmd_prune;
+pub use ps::cmd_ps;
+pub use run::{cmd_run, cmd_run_dry, cmd_run_tui};
+pub use status::cmd_status;
+pub use team::{cmd_team_archive, cmd_team_rename};
+pub use watch::cmd_watch;
+pub use worktrees::cmd_worktrees;
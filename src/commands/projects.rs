@@ -1,44 +1,53 @@
 use std::fs;
+use std::path::PathBuf;
 
+use super::issues;
 use swarm::config::{self, Config};
 use swarm::engine;
 use swarm::planning;
-use swarm::team::{self, Team};
+use swarm::task::TaskList;
+use swarm::team::{self, Team, WARNINGS_FILE};
+use swarm::warnings;
 
 /// Task completion counts for a project.
 struct TaskCounts {
     completed: usize,
+    interrupted: usize,
     total: usize,
 }
 
-/// Count completed and total tasks in a tasks.md file.
+/// Count completed, interrupted, and total tasks in a tasks.md file.
 fn count_tasks(team: &Team) -> TaskCounts {
-    let tasks_path = team.tasks_path();
-    let content = match fs::read_to_string(&tasks_path) {
-        Ok(c) => c,
-        Err(_) => {
-            return TaskCounts {
-                completed: 0,
-                total: 0,
-            }
-        }
-    };
-
-    let mut completed = 0;
-    let mut total = 0;
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-        // Match task lines: "- [x]", "- [ ]", "- [A]", etc.
-        if trimmed.starts_with("- [") && trimmed.len() > 4 && trimmed.chars().nth(4) == Some(']') {
-            total += 1;
-            if trimmed.starts_with("- [x]") {
-                completed += 1;
-            }
-        }
+    let tasks = fs::read_to_string(team.tasks_path())
+        .map(|content| TaskList::parse(&content))
+        .unwrap_or_default();
+    TaskCounts {
+        completed: tasks.completed_count(),
+        interrupted: tasks.interrupted_count(),
+        total: tasks.tasks.len(),
+    }
+}
+
+/// Warning count from the most recently written `warnings.json` for a project.
+///
+/// Runtime state lives under `runs/<target>/`, so every target's file is
+/// considered (plus the legacy team root) and the newest one wins.
+fn last_sprint_warnings(team: &Team) -> Option<(usize, PathBuf)> {
+    let mut candidates = vec![team.root.join(WARNINGS_FILE)];
+    if let Ok(entries) = fs::read_dir(team.root.join("runs")) {
+        candidates.extend(entries.flatten().map(|e| e.path().join(WARNINGS_FILE)));
     }
 
-    TaskCounts { completed, total }
+    let newest = candidates
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((modified, path))
+        })
+        .max_by_key(|(modified, _)| *modified)?
+        .1;
+    let count = warnings::load(&newest).ok()?.len();
+    Some((count, newest))
 }
 
 /// List all projects and their task status.
@@ -85,8 +94,25 @@ pub fn cmd_projects(_config: &Config) -> Result<(), String> {
         } else {
             format!(" [{}/{} Tasks Complete]", counts.completed, counts.total)
         };
+        let interrupted_status = if counts.interrupted > 0 {
+            format!(" [Interrupted: {}]", counts.interrupted)
+        } else {
+            String::new()
+        };
 
-        println!("  {}{}", p.name, task_status);
+        let warnings_status = match last_sprint_warnings(p) {
+            Some((count, path)) if count > 0 => format!(
+                " [{} warning(s) last sprint; see {}]",
+                count,
+                path.display()
+            ),
+            _ => String::new(),
+        };
+
+        println!(
+            "  {}{}{}{}",
+            p.name, task_status, interrupted_status, warnings_status
+        );
     }
 
     Ok(())
@@ -99,14 +125,9 @@ pub fn cmd_project_init(config: &Config, cli: &config::CliArgs) -> Result<(), St
         .as_ref()
         .ok_or("Usage: swarm project init <name>")?;
 
-    // Validate project name (alphanumeric and hyphens only)
-    if !project_name
-        .chars()
-        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
-    {
-        return Err(
-            "Project name must contain only letters, numbers, hyphens, and underscores".to_string(),
-        );
+    team::validate_name(project_name)?;
+    if cli.prd_file_arg.is_some() && cli.issues_repo_arg.is_some() {
+        return Err("--with-prd and --with-issues cannot be combined".to_string());
     }
 
     // Initialize root if needed
@@ -141,7 +162,7 @@ pub fn cmd_project_init(config: &Config, cli: &config::CliArgs) -> Result<(), St
         let engine = engine::create_engine(
             config.effective_engine(),
             log_dir.to_str().unwrap_or(""),
-            config.agent_timeout_secs,
+            config.planning_timeout_secs,
         );
 
         println!(
@@ -169,6 +190,17 @@ pub fn cmd_project_init(config: &Config, cli: &config::CliArgs) -> Result<(), St
             eprintln!("  Using default tasks.md instead.");
             println!("  Tasks:     {}", project.tasks_path().display());
         }
+    } else if let Some(ref repo) = cli.issues_repo_arg {
+        println!("\nImporting open issues from {}...", repo);
+        let tasks = issues::fetch_issue_tasks(repo, cli.issue_label.as_deref())?;
+        fs::write(project.tasks_path(), format!("# Tasks\n\n{}", tasks))
+            .map_err(|e| format!("Failed to write tasks.md: {}", e))?;
+        println!(
+            "  Tasks:     {} ({} issues imported)",
+            project.tasks_path().display(),
+            tasks.lines().count()
+        );
+        println!("  Specs:     {}", project.specs_path().display());
     } else {
         println!("  Tasks:     {}", project.tasks_path().display());
         println!("  Specs:     {}", project.specs_path().display());
@@ -189,6 +221,25 @@ mod tests {
     use super::*;
     use crate::testutil::with_temp_cwd;
 
+    #[test]
+    fn test_last_sprint_warnings_reads_runtime_file() {
+        with_temp_cwd(|| {
+            let team = Team::new("warned");
+            team.init().unwrap();
+            assert!(last_sprint_warnings(&team).is_none());
+
+            let paths = team::RuntimeStatePaths::for_branches("warned", "main", "feature");
+            let collector = warnings::WarningCollector::new();
+            collector.record("log", "warning: failed to write log: disk full");
+            collector.record("push", "warning: failed to push");
+            collector.save(&paths.warnings_path()).unwrap();
+
+            let (count, path) = last_sprint_warnings(&team).expect("warnings file");
+            assert_eq!(count, 2);
+            assert_eq!(path, paths.warnings_path());
+        });
+    }
+
     #[test]
     fn test_count_tasks_missing_file() {
         with_temp_cwd(|| {
@@ -277,6 +328,24 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_count_tasks_interrupted() {
+        with_temp_cwd(|| {
+            let team = Team::new("test-project");
+            team.init().unwrap();
+            fs::write(
+                team.tasks_path(),
+                "# Tasks\n\n- [x] Done (A)\n- [ ] Retry {interrupted}\n- [ ] Pending\n",
+            )
+            .unwrap();
+
+            let counts = count_tasks(&team);
+            assert_eq!(counts.interrupted, 1);
+            assert_eq!(counts.completed, 1);
+            assert_eq!(counts.total, 3);
+        });
+    }
+
     #[test]
     fn test_count_tasks_with_sections() {
         with_temp_cwd(|| {
@@ -0,0 +1,35 @@
+use std::path::Path;
+use std::time::Duration;
+
+use crate::output::format_bytes;
+use swarm::config::Config;
+use swarm::worktree;
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Remove preserved worktrees older than `worktree.preserved_max_age_days`
+/// (or `--older-than`) and prune stale git worktree registrations.
+pub fn cmd_prune(config: &Config) -> Result<(), String> {
+    let days = config.worktree_preserved_max_age_days;
+    let max_age = Duration::from_secs(days.saturating_mul(SECS_PER_DAY));
+    let summary = worktree::prune_preserved(Path::new(&config.files_worktrees_dir), max_age)?;
+
+    for path in &summary.removed {
+        println!("  Removed {}", path.display());
+    }
+    println!(
+        "Pruned {} preserved worktree(s) older than {} day(s), reclaimed {}.",
+        summary.removed.len(),
+        days,
+        format_bytes(summary.bytes_reclaimed)
+    );
+
+    if summary.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "prune completed with errors:\n{}",
+            summary.errors.join("\n")
+        ))
+    }
+}
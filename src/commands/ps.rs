@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use swarm::config::Config;
+use swarm::process_registry::{self, RecordedProcess};
+
+use crate::commands::kill::pids_files;
+use crate::output::format_duration;
+use crate::project::project_name_for_config;
+
+/// Longest command line printed before it is cut off.
+const MAX_COMMAND_CHARS: usize = 60;
+
+/// List the engine processes still running for the project's runs.
+///
+/// Entries whose process has exited are left out.
+pub fn cmd_ps(config: &Config) -> Result<(), String> {
+    let team_name = project_name_for_config(config);
+    let mut processes: Vec<RecordedProcess> = Vec::new();
+    for path in &pids_files(config, &team_name) {
+        processes.extend(process_registry::running(path)?);
+    }
+
+    if processes.is_empty() {
+        println!("No running processes for {}.", team_name);
+        return Ok(());
+    }
+    let rows: Vec<[String; 4]> = processes
+        .iter()
+        .map(|p| {
+            [
+                if p.agent.is_empty() {
+                    "-".to_string()
+                } else {
+                    p.agent.clone()
+                },
+                p.pid.to_string(),
+                format_duration(Duration::from_secs(p.elapsed_secs())),
+                truncate_command(if p.command.is_empty() {
+                    &p.name
+                } else {
+                    &p.command
+                }),
+            ]
+        })
+        .collect();
+    let header = ["AGENT", "PID", "ELAPSED", "COMMAND"].map(String::from);
+    let widths: Vec<usize> = (0..3)
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|r| r[i].len())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for row in [&header].into_iter().chain(&rows) {
+        println!(
+            "{:<w0$}  {:>w1$}  {:>w2$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        );
+    }
+    Ok(())
+}
+
+/// Shorten long engine command lines (prompts can be passed as arguments).
+fn truncate_command(command: &str) -> String {
+    if command.chars().count() <= MAX_COMMAND_CHARS {
+        return command.to_string();
+    }
+    let kept: String = command.chars().take(MAX_COMMAND_CHARS - 3).collect();
+    format!("{}...", kept)
+}
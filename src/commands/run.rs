@@ -1,28 +1,44 @@
 use std::env;
+use std::path::{Path, PathBuf};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
 };
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use chrono::Local;
+use swarm::agent;
 use swarm::chat;
 use swarm::color::{self, emoji};
-use swarm::config::Config;
+use swarm::config::{Config, EngineType, PathPlaceholders};
+use swarm::engine::{self, Engine};
+use swarm::events;
+use swarm::info;
+use swarm::process_registry::{self, PROCESS_REGISTRY};
 use swarm::run_hash;
 use swarm::shutdown;
 use swarm::team;
+use swarm::verbosity::Verbosity;
 
-use crate::runner::run_sprint;
+use crate::project::project_name_for_config;
+use crate::runner::{plan_dry_run, run_sprint, DryRunPlan, SprintResult};
 use crate::tail::tail_follow;
 
 /// Run sprints until done or max-sprints reached.
 /// Maximum consecutive sprints where all tasks fail before stopping.
 const MAX_CONSECUTIVE_FAILURES: usize = 3;
 
+/// Poll interval for the shutdown flag while idling in `--watch-tasks` mode.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
 pub fn cmd_run(config: &Config) -> Result<(), String> {
     team::init_root()?;
-    println!(
+    let run_instance = run_hash::generate_run_hash();
+    let config = &resolve_run_paths(config, &run_instance);
+    // With a per-sprint chat path, the boot message and tail use sprint 1's chat.
+    let first_chat = resolve_sprint_paths(config, 1).files_chat;
+    info!(
         "{} {} (max_sprints={}, engine={})...",
         emoji::ROCKET,
         color::label("Running swarm"),
@@ -34,19 +50,44 @@ pub fn cmd_run(config: &Config) -> Result<(), String> {
         color::info(&config.engines_display())
     );
 
+    // Catch missing CLIs or logins before any worktree is created
+    if let Err(e) = preflight_engines(&configured_engines(config)) {
+        println!();
+        print_engine_guidance();
+        return Err(e);
+    }
+
+    // Reap engines left behind by a crashed run, then record this run's
+    if let Some(pids_path) = pids_path_for_config(config) {
+        match process_registry::reap_recorded(&pids_path) {
+            Ok(reaped) if !reaped.is_empty() => info!(
+                "{} Killed {} engine process(es) left by a previous run.",
+                emoji::STOP,
+                reaped.len()
+            ),
+            Ok(_) => {}
+            Err(e) => eprintln!("warning: {}", e),
+        }
+        PROCESS_REGISTRY.set_state_file(Some(pids_path));
+    }
+
     // Clear chat.md and write boot message before the first sprint
     if should_reset_chat() {
-        chat::write_boot_message(&config.files_chat)
+        chat::write_boot_message(&first_chat)
             .map_err(|e| format!("failed to write boot message: {}", e))?;
     }
 
+    if let Some(ref path) = config.event_socket {
+        events::open(Path::new(path))?;
+    }
+
     let mut tail_stop: Option<Arc<AtomicBool>> = None;
     let mut tail_handle: Option<thread::JoinHandle<()>> = None;
 
     // Only tail if SWARM_NO_TAIL is not set (TUI subprocess sets this)
     if !should_skip_tail() {
         let stop = Arc::new(AtomicBool::new(false));
-        let path = config.files_chat.clone();
+        let path = first_chat.clone();
         let stop_clone = Arc::clone(&stop);
         let handle = thread::spawn(move || {
             if let Err(e) = tail_follow(&path, true, Some(stop_clone)) {
@@ -60,14 +101,15 @@ pub fn cmd_run(config: &Config) -> Result<(), String> {
     let mut sprint_number = 0;
     let mut interrupted = false;
     let mut consecutive_failures = 0;
-    let run_instance = run_hash::generate_run_hash();
+    let mut merge_failures = 0;
+    let mut watching_idle = false;
 
     loop {
         sprint_number += 1;
 
         // Check for shutdown request before starting new sprint
         if shutdown::requested() {
-            println!(
+            info!(
                 "{} Shutdown requested, not starting new sprint.",
                 emoji::STOP
             );
@@ -77,16 +119,23 @@ pub fn cmd_run(config: &Config) -> Result<(), String> {
 
         // Check sprint limit
         if config.sprints_max > 0 && sprint_number > config.sprints_max {
-            println!("Reached max sprints ({}), stopping.", config.sprints_max);
+            info!("Reached max sprints ({}), stopping.", config.sprints_max);
             break;
         }
 
-        // Run one sprint (may return early if shutdown requested)
-        let result = run_sprint(config, sprint_number, &run_instance);
+        // Run one sprint (may return early if shutdown requested), re-attempting
+        // infrastructure errors up to sprints.max_retries times.
+        let sprint_config = resolve_sprint_paths(config, sprint_number);
+        let result = run_sprint_with_retries(
+            config.sprints_max_retries,
+            sprint_number,
+            &sprint_config.files_chat,
+            || run_sprint(&sprint_config, sprint_number, &run_instance),
+        );
 
         // Check if we were interrupted during the sprint
         if shutdown::requested() {
-            println!("Sprint interrupted by shutdown request.");
+            info!("Sprint interrupted by shutdown request.");
             interrupted = true;
             // Still process the result to ensure cleanup happened
             if let Err(e) = result {
@@ -95,12 +144,28 @@ pub fn cmd_run(config: &Config) -> Result<(), String> {
             break;
         }
 
-        let sprint_result = result?;
+        let sprint_result = result.inspect_err(|_| events::close())?;
+        merge_failures += sprint_result.merge_failures;
 
         if sprint_result.tasks_assigned == 0 {
-            println!("{} No tasks to assign, sprints complete.", emoji::PARTY);
-            break;
+            if !config.sprints_watch_tasks {
+                println!("{} No tasks to assign, sprints complete.", emoji::PARTY);
+                break;
+            }
+            if !watching_idle {
+                println!(
+                    "{} No tasks to assign, watching for new tasks every {}s (Ctrl+C to stop).",
+                    emoji::HOURGLASS,
+                    config.sprints_watch_interval_secs
+                );
+                watching_idle = true;
+            }
+            // An empty check is not a sprint; reuse the number for the next one.
+            sprint_number -= 1;
+            wait_for_shutdown(Duration::from_secs(config.sprints_watch_interval_secs));
+            continue;
         }
+        watching_idle = false;
 
         // Track consecutive failures (sprints where all tasks failed)
         if sprint_result.all_failed() {
@@ -114,13 +179,7 @@ pub fn cmd_run(config: &Config) -> Result<(), String> {
                     color::failed(&consecutive_failures.to_string())
                 );
                 println!("   This usually indicates a configuration or authentication issue.");
-                println!("   Please check:");
-                println!(
-                    "     - CLI authentication (run 'claude' or 'codex login' to authenticate)"
-                );
-                println!("     - Engine configuration (--engine flag or swarm.toml)");
-                println!("     - File permissions in worktrees directory");
-                println!();
+                print_engine_guidance();
                 println!(
                     "{} Stopping to prevent further failed sprints.",
                     emoji::STOP
@@ -136,6 +195,15 @@ pub fn cmd_run(config: &Config) -> Result<(), String> {
         thread::sleep(Duration::from_millis(100));
     }
 
+    if merge_failures > 0 {
+        println!(
+            "{} {} across all sprints: {}",
+            emoji::WARNING,
+            color::warning("Merge failures"),
+            color::failed(&merge_failures.to_string())
+        );
+    }
+
     if interrupted {
         println!("{} Graceful shutdown complete.", emoji::WAVE);
     }
@@ -146,10 +214,177 @@ pub fn cmd_run(config: &Config) -> Result<(), String> {
     if let Some(handle) = tail_handle {
         let _ = handle.join();
     }
+    events::close();
+
+    Ok(())
+}
+
+/// Location of `pids.json` for the configured team and target branch.
+fn pids_path_for_config(config: &Config) -> Option<PathBuf> {
+    let target_branch = config.target_branch.as_deref()?;
+    let team_name = project_name_for_config(config);
+    Some(
+        team::RuntimeStatePaths::for_branches(
+            &team_name,
+            config.source_branch.as_deref().unwrap_or(""),
+            target_branch,
+        )
+        .pids_path(),
+    )
+}
+
+/// Print the checklist for engine configuration and authentication problems.
+fn print_engine_guidance() {
+    println!("   Please check:");
+    println!("     - CLI authentication (run 'claude' or 'codex login' to authenticate)");
+    println!("     - Engine configuration (--engine flag or swarm.toml)");
+    println!("     - File permissions in worktrees directory");
+    println!();
+}
+
+/// One engine per distinct configured engine type (just the stub in stub mode).
+fn configured_engines(config: &Config) -> Vec<Arc<dyn Engine>> {
+    let mut types: Vec<EngineType> = Vec::new();
+    if config.engine_stub_mode {
+        types.push(EngineType::Stub);
+    } else {
+        for engine_type in &config.engine_types {
+            if !types.contains(engine_type) {
+                types.push(engine_type.clone());
+            }
+        }
+    }
+    types
+        .into_iter()
+        .map(|engine_type| {
+            engine::create_engine(
+                engine_type,
+                &config.files_log_dir,
+                config.agent_timeout_secs,
+            )
+        })
+        .collect()
+}
+
+/// Run every engine's preflight probe, failing on the first that does not pass.
+fn preflight_engines(engines: &[Arc<dyn Engine>]) -> Result<(), String> {
+    for engine in engines {
+        engine.preflight().map_err(|e| {
+            format!(
+                "{} engine preflight failed: {}",
+                engine.engine_type().as_str(),
+                e
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Run a sprint, retrying when it errors (git worktree failures, merge-agent
+/// crashes, ...) rather than reporting task outcomes.
+///
+/// Each attempt starts by cleaning up the sprint's feature and agent worktrees,
+/// so a retry re-runs the same sprint number from a clean slate. Tasks that
+/// merely fail come back as `Ok` and are left to the consecutive-failure check.
+fn run_sprint_with_retries<F>(
+    max_retries: usize,
+    sprint_number: usize,
+    chat_file: &str,
+    mut attempt: F,
+) -> Result<SprintResult, String>
+where
+    F: FnMut() -> Result<SprintResult, String>,
+{
+    let mut retries = 0;
+    loop {
+        match attempt() {
+            Err(e) if retries < max_retries && !shutdown::requested() => {
+                retries += 1;
+                let message = format!(
+                    "Sprint {} failed: {}; retrying ({}/{})",
+                    sprint_number, e, retries, max_retries
+                );
+                eprintln!("{} {}", emoji::WARNING, message);
+                if let Err(e) =
+                    chat::write_message_level(chat_file, "ScrumMaster", chat::Level::Warn, &message)
+                {
+                    eprintln!("warning: failed to write sprint retry to chat: {}", e);
+                }
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Sleep for `duration`, returning early if shutdown is requested.
+fn wait_for_shutdown(duration: Duration) {
+    let deadline = Instant::now() + duration;
+    while !shutdown::requested() {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        thread::sleep(SHUTDOWN_POLL_INTERVAL.min(deadline - now));
+    }
+}
+
+/// Resolve `{run}`, `{team}`, and `{timestamp}` in the chat and log paths.
+fn resolve_run_paths(config: &Config, run: &str) -> Config {
+    let team = project_name_for_config(config);
+    let timestamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
+    config.with_path_placeholders(&PathPlaceholders {
+        run: Some(run),
+        team: Some(&team),
+        timestamp: Some(&timestamp),
+        ..Default::default()
+    })
+}
+
+/// Resolve `{sprint}` in the chat and log paths for one sprint.
+fn resolve_sprint_paths(config: &Config, sprint_number: usize) -> Config {
+    config.with_path_placeholders(&PathPlaceholders {
+        sprint: Some(sprint_number),
+        ..Default::default()
+    })
+}
 
+/// Print the next sprint's assignments and the branches and worktrees it would
+/// create, without calling an engine or changing git or `.swarm-hug/`.
+pub fn cmd_run_dry(config: &Config) -> Result<(), String> {
+    let run_instance = run_hash::generate_run_hash();
+    let config = resolve_run_paths(config, &run_instance);
+    let plan = plan_dry_run(&config, &run_instance)?;
+    print!("{}", format_dry_run(&plan));
     Ok(())
 }
 
+fn format_dry_run(plan: &DryRunPlan) -> String {
+    let mut out = String::from("Dry run: no engines, worktrees, or git changes.\n");
+    out.push_str(&format!("{} sprint {}:\n", plan.team, plan.sprint));
+    if plan.agents.is_empty() {
+        out.push_str("  No tasks to assign.\n");
+        return out;
+    }
+    out.push_str(&format!(
+        "  Sprint branch: {} ({})\n",
+        plan.sprint_branch,
+        plan.sprint_worktree.display()
+    ));
+    for agent in &plan.agents {
+        out.push_str(&format!(
+            "  {} ({}): {} -> {}\n",
+            agent::name_from_initial(agent.initial).unwrap_or_else(|| "?".to_string()),
+            agent::id(agent.initial),
+            agent.branch,
+            agent.worktree.display()
+        ));
+        for task in &agent.tasks {
+            out.push_str(&format!("    - {}\n", task));
+        }
+    }
+    out
+}
+
 /// Run sprints with TUI interface.
 ///
 /// Runs the sprint as a subprocess to avoid stdout corruption of the TUI.
@@ -157,16 +392,21 @@ pub fn cmd_run_tui(config: &Config) -> Result<(), String> {
     use swarm::tui::run_tui_with_subprocess;
 
     team::init_root()?;
+    // Resolve run placeholders once so the TUI and the subprocess share paths.
+    let config = &resolve_run_paths(config, &run_hash::generate_run_hash());
+    let sprint_paths = resolve_sprint_paths(config, 1);
+    let chat_file = sprint_paths.files_chat;
 
     // Clear chat.md before the TUI starts so we preserve the full session history in one run.
     if should_reset_chat() {
-        chat::write_boot_message(&config.files_chat)
+        chat::write_boot_message(&chat_file)
             .map_err(|e| format!("failed to write boot message: {}", e))?;
     }
 
     let args = build_tui_subprocess_args(config);
 
-    run_tui_with_subprocess(&config.files_chat, args, true).map_err(|e| format!("TUI error: {}", e))
+    run_tui_with_subprocess(&chat_file, &sprint_paths.files_log_dir, args, true)
+        .map_err(|e| format!("TUI error: {}", e))
 }
 
 /// Build command-line args to re-run swarm as a --no-tui subprocess.
@@ -174,13 +414,17 @@ pub fn cmd_run_tui(config: &Config) -> Result<(), String> {
 /// SWARM_NO_TAIL env var is set by run_tui_with_subprocess to disable tailing.
 fn build_tui_subprocess_args(config: &Config) -> Vec<String> {
     let mut args: Vec<String> = Vec::new();
-    args.push("run".to_string());
+    args.push(if config.resume { "resume" } else { "run" }.to_string());
     args.push("--no-tui".to_string());
 
     if let Some(ref project) = config.project {
         args.push("--project".to_string());
         args.push(project.clone());
     }
+    if let Some(ref profile) = config.profile {
+        args.push("--profile".to_string());
+        args.push(profile.clone());
+    }
     if let Some(ref source_branch) = config.source_branch {
         args.push("--source-branch".to_string());
         args.push(source_branch.clone());
@@ -199,11 +443,62 @@ fn build_tui_subprocess_args(config: &Config) -> Vec<String> {
     args.push(config.agents_tasks_per_agent.to_string());
     args.push("--agent-timeout".to_string());
     args.push(config.agent_timeout_secs.to_string());
+    args.push("--merge-timeout".to_string());
+    args.push(config.merge_timeout_secs.to_string());
+    args.push("--merge-retries".to_string());
+    args.push(config.merge_retries.to_string());
+    args.push("--planning-timeout".to_string());
+    args.push(config.planning_timeout_secs.to_string());
+    args.push("--shutdown-grace".to_string());
+    args.push(config.shutdown_grace_secs.to_string());
+    args.push("--max-concurrency".to_string());
+    args.push(config.engine_max_concurrency.to_string());
     args.push("--engine".to_string());
     args.push(config.engines_display());
     if config.engine_stub_mode {
         args.push("--stub".to_string());
     }
+    if config.engine_stub_fail_rate > 0.0 {
+        args.push("--fail-rate".to_string());
+        args.push(config.engine_stub_fail_rate.to_string());
+    }
+    if let Some(seed) = config.seed {
+        args.push("--seed".to_string());
+        args.push(seed.to_string());
+    }
+    args.push("--engine-retries".to_string());
+    args.push(config.engine_retries.to_string());
+    if !config.sprints_create_pr {
+        args.push("--no-pr".to_string());
+    }
+    if config.sprints_watch_tasks {
+        args.push("--watch-tasks".to_string());
+    }
+    if config.sprints_ordered_merge {
+        args.push("--ordered-merge".to_string());
+    }
+    args.push("--chat-file".to_string());
+    args.push(config.files_chat.clone());
+    args.push("--chat-format".to_string());
+    args.push(config.files_chat_format.as_str().to_string());
+    args.push("--log-dir".to_string());
+    args.push(config.files_log_dir.clone());
+    args.push("--log-format".to_string());
+    args.push(config.files_log_format.as_str().to_string());
+    if config.verbosity == Verbosity::Quiet {
+        args.push("--quiet".to_string());
+    }
+    for _ in 0..config.verbosity.count() {
+        args.push("--verbose".to_string());
+    }
+    if let Some(ref dir) = config.dump_prompts_dir {
+        args.push("--dump-prompts".to_string());
+        args.push(dir.clone());
+    }
+    if let Some(ref path) = config.event_socket {
+        args.push("--event-socket".to_string());
+        args.push(path.clone());
+    }
 
     args
 }
@@ -218,9 +513,20 @@ fn should_skip_tail() -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{build_tui_subprocess_args, should_reset_chat};
-    use std::sync::Mutex;
-    use swarm::config::Config;
+    use super::{
+        build_tui_subprocess_args, configured_engines, format_dry_run, preflight_engines,
+        resolve_run_paths, resolve_sprint_paths, run_sprint_with_retries, should_reset_chat,
+        wait_for_shutdown,
+    };
+    use crate::runner::{plan_dry_run, SprintResult};
+    use crate::testutil::with_temp_cwd;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use swarm::config::{Config, EngineType, NoAgentsBehavior};
+    use swarm::engine::{Engine, EngineResult, StubEngine};
+    use swarm::run_hash;
+    use swarm::verbosity::Verbosity;
 
     static ENV_LOCK: Mutex<()> = Mutex::new(());
 
@@ -249,6 +555,14 @@ mod tests {
         args.iter().any(|a| a == flag)
     }
 
+    #[test]
+    fn tui_args_rerun_resume_for_resumed_runs() {
+        let mut config = Config::default();
+        assert_eq!(build_tui_subprocess_args(&config)[0], "run");
+        config.resume = true;
+        assert_eq!(build_tui_subprocess_args(&config)[0], "resume");
+    }
+
     #[test]
     fn tui_args_pass_source_branch_when_set() {
         let mut config = Config::default();
@@ -401,15 +715,29 @@ mod tests {
     fn tui_args_include_all_standard_flags() {
         let mut config = Config::default();
         config.project = Some("my-proj".to_string());
+        config.profile = Some("ci".to_string());
+        config.files_chat_format = swarm::chat::ChatFormat::Jsonl;
+        config.files_log_format = swarm::log::LogFormat::Json;
         config.sprints_max = 5;
         config.agents_max_count = 4;
         config.agents_tasks_per_agent = 3;
         config.agent_timeout_secs = 1800;
+        config.merge_timeout_secs = 5400;
+        config.merge_retries = 3;
+        config.planning_timeout_secs = 900;
+        config.shutdown_grace_secs = 15;
+        config.engine_max_concurrency = 2;
         config.engine_stub_mode = true;
 
         let args = build_tui_subprocess_args(&config);
 
         assert_eq!(flag_value(&args, "--project"), Some("my-proj".to_string()));
+        assert_eq!(flag_value(&args, "--profile"), Some("ci".to_string()));
+        assert_eq!(
+            flag_value(&args, "--chat-format"),
+            Some("jsonl".to_string())
+        );
+        assert_eq!(flag_value(&args, "--log-format"), Some("json".to_string()));
         assert_eq!(flag_value(&args, "--max-sprints"), Some("5".to_string()));
         assert_eq!(flag_value(&args, "--max-agents"), Some("4".to_string()));
         assert_eq!(
@@ -420,6 +748,398 @@ mod tests {
             flag_value(&args, "--agent-timeout"),
             Some("1800".to_string())
         );
+        assert_eq!(
+            flag_value(&args, "--merge-timeout"),
+            Some("5400".to_string())
+        );
+        assert_eq!(flag_value(&args, "--merge-retries"), Some("3".to_string()));
+        assert_eq!(
+            flag_value(&args, "--planning-timeout"),
+            Some("900".to_string())
+        );
+        assert_eq!(
+            flag_value(&args, "--shutdown-grace"),
+            Some("15".to_string())
+        );
+        assert_eq!(
+            flag_value(&args, "--max-concurrency"),
+            Some("2".to_string())
+        );
         assert!(has_flag(&args, "--stub"));
     }
+
+    #[test]
+    fn tui_args_forward_verbosity() {
+        let mut config = Config::default();
+        let args = build_tui_subprocess_args(&config);
+        assert!(!has_flag(&args, "--verbose"));
+        assert!(!has_flag(&args, "--quiet"));
+
+        config.verbosity = Verbosity::Debug;
+        let args = build_tui_subprocess_args(&config);
+        assert_eq!(args.iter().filter(|a| *a == "--verbose").count(), 2);
+
+        config.verbosity = Verbosity::Quiet;
+        let args = build_tui_subprocess_args(&config);
+        assert!(has_flag(&args, "--quiet"));
+        assert!(!has_flag(&args, "--verbose"));
+    }
+
+    #[test]
+    fn tui_args_forward_stub_fail_rate_and_seed() {
+        let mut config = Config::default();
+        let args = build_tui_subprocess_args(&config);
+        assert!(!has_flag(&args, "--fail-rate"));
+        assert!(!has_flag(&args, "--seed"));
+
+        config.engine_stub_fail_rate = 0.25;
+        config.seed = Some(42);
+        let args = build_tui_subprocess_args(&config);
+        assert_eq!(flag_value(&args, "--fail-rate"), Some("0.25".to_string()));
+        assert_eq!(flag_value(&args, "--seed"), Some("42".to_string()));
+    }
+
+    #[test]
+    fn tui_args_forward_engine_retries() {
+        let mut config = Config::default();
+        config.engine_retries = 5;
+        let args = build_tui_subprocess_args(&config);
+        assert_eq!(flag_value(&args, "--engine-retries"), Some("5".to_string()));
+    }
+
+    #[test]
+    fn tui_args_forward_no_pr() {
+        let mut config = Config::default();
+        assert!(!has_flag(&build_tui_subprocess_args(&config), "--no-pr"));
+
+        config.sprints_create_pr = false;
+        assert!(has_flag(&build_tui_subprocess_args(&config), "--no-pr"));
+    }
+
+    #[test]
+    fn tui_args_forward_watch_tasks() {
+        let mut config = Config::default();
+        assert!(!has_flag(
+            &build_tui_subprocess_args(&config),
+            "--watch-tasks"
+        ));
+
+        config.sprints_watch_tasks = true;
+        assert!(has_flag(
+            &build_tui_subprocess_args(&config),
+            "--watch-tasks"
+        ));
+    }
+
+    #[test]
+    fn tui_args_forward_ordered_merge() {
+        let mut config = Config::default();
+        assert!(!has_flag(
+            &build_tui_subprocess_args(&config),
+            "--ordered-merge"
+        ));
+
+        config.sprints_ordered_merge = true;
+        assert!(has_flag(
+            &build_tui_subprocess_args(&config),
+            "--ordered-merge"
+        ));
+    }
+
+    #[test]
+    fn wait_for_shutdown_returns_after_duration() {
+        let start = std::time::Instant::now();
+        wait_for_shutdown(std::time::Duration::from_millis(50));
+        assert!(start.elapsed() >= std::time::Duration::from_millis(50));
+    }
+
+    #[test]
+    fn tui_args_forward_event_socket() {
+        let mut config = Config::default();
+        assert!(!has_flag(
+            &build_tui_subprocess_args(&config),
+            "--event-socket"
+        ));
+
+        config.event_socket = Some("/tmp/swarm.sock".to_string());
+        let args = build_tui_subprocess_args(&config);
+        assert_eq!(
+            flag_value(&args, "--event-socket"),
+            Some("/tmp/swarm.sock".to_string())
+        );
+    }
+
+    #[test]
+    fn tui_args_forward_dump_prompts_dir() {
+        let mut config = Config::default();
+        assert!(!has_flag(
+            &build_tui_subprocess_args(&config),
+            "--dump-prompts"
+        ));
+
+        config.dump_prompts_dir = Some("/tmp/prompts".to_string());
+        let args = build_tui_subprocess_args(&config);
+        assert_eq!(
+            flag_value(&args, "--dump-prompts"),
+            Some("/tmp/prompts".to_string())
+        );
+    }
+
+    #[test]
+    fn tui_args_forward_resolved_chat_and_log_paths() {
+        let mut config = Config::default();
+        config.files_chat = "artifacts/{run}/chat.md".to_string();
+        let config = resolve_run_paths(&config, "abc123");
+        let args = build_tui_subprocess_args(&config);
+        assert_eq!(
+            flag_value(&args, "--chat-file"),
+            Some("artifacts/abc123/chat.md".to_string())
+        );
+        assert_eq!(flag_value(&args, "--log-dir"), Some(config.files_log_dir));
+    }
+
+    #[test]
+    fn run_placeholder_expands_to_run_hash() {
+        let run_instance = run_hash::generate_run_hash();
+        let mut config = Config::default();
+        config.files_chat = "ci/{run}/chat.md".to_string();
+        config.files_log_dir = "ci/{run}/sprint-{sprint}".to_string();
+
+        let run_config = resolve_run_paths(&config, &run_instance);
+        assert_eq!(
+            run_config.files_chat,
+            format!("ci/{}/chat.md", run_instance)
+        );
+        assert_eq!(
+            run_config.files_log_dir,
+            format!("ci/{}/sprint-{{sprint}}", run_instance)
+        );
+
+        let sprint_config = resolve_sprint_paths(&run_config, 2);
+        assert_eq!(
+            sprint_config.files_log_dir,
+            format!("ci/{}/sprint-2", run_instance)
+        );
+    }
+
+    #[test]
+    fn paths_without_placeholders_are_unchanged() {
+        let config = Config::default();
+        let resolved = resolve_sprint_paths(&resolve_run_paths(&config, "abc123"), 1);
+        assert_eq!(resolved.files_chat, config.files_chat);
+        assert_eq!(resolved.files_log_dir, config.files_log_dir);
+    }
+
+    fn sprint_result(completed: usize) -> SprintResult {
+        SprintResult {
+            tasks_assigned: completed,
+            tasks_completed: completed,
+            tasks_failed: 0,
+            merge_failures: 0,
+        }
+    }
+
+    #[test]
+    fn sprint_retry_recovers_from_infrastructure_error() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let chat = temp.path().join("chat.md");
+        let mut attempts = 0;
+
+        let result = run_sprint_with_retries(1, 3, chat.to_str().unwrap(), || {
+            attempts += 1;
+            if attempts == 1 {
+                Err("failed to create worktree".to_string())
+            } else {
+                Ok(sprint_result(2))
+            }
+        });
+
+        assert_eq!(attempts, 2);
+        assert_eq!(result.unwrap().tasks_completed, 2);
+        let chat_content = fs::read_to_string(&chat).unwrap();
+        assert!(chat_content.contains("Sprint 3 failed: failed to create worktree; retrying (1/1)"));
+    }
+
+    #[test]
+    fn sprint_retry_gives_up_after_max_retries() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let chat = temp.path().join("chat.md");
+        let mut attempts = 0;
+
+        let result = run_sprint_with_retries(2, 1, chat.to_str().unwrap(), || {
+            attempts += 1;
+            Err(format!("merge agent crashed ({})", attempts))
+        });
+
+        assert_eq!(attempts, 3);
+        assert_eq!(result.unwrap_err(), "merge agent crashed (3)");
+    }
+
+    #[test]
+    fn sprint_retry_disabled_by_default() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let chat = temp.path().join("chat.md");
+        let mut attempts = 0;
+
+        let result = run_sprint_with_retries(
+            Config::default().sprints_max_retries,
+            1,
+            chat.to_str().unwrap(),
+            || {
+                attempts += 1;
+                Err("boom".to_string())
+            },
+        );
+
+        assert_eq!(attempts, 1);
+        assert!(result.is_err());
+        assert!(!chat.exists());
+    }
+
+    /// Engine whose preflight always fails, as with a logged-out CLI.
+    struct LoggedOutEngine;
+
+    impl Engine for LoggedOutEngine {
+        fn execute(
+            &self,
+            _agent_name: &str,
+            _task_description: &str,
+            _working_dir: &Path,
+            _turn_number: usize,
+            _team_dir: Option<&str>,
+        ) -> EngineResult {
+            EngineResult::failure("not logged in", 1)
+        }
+
+        fn engine_type(&self) -> EngineType {
+            EngineType::Codex
+        }
+
+        fn preflight(&self) -> Result<(), String> {
+            Err("Not logged in".to_string())
+        }
+    }
+
+    #[test]
+    fn preflight_passes_for_stub_engine() {
+        let stub: Arc<dyn Engine> = Arc::new(StubEngine::new("loop"));
+        assert_eq!(preflight_engines(&[stub]), Ok(()));
+
+        let mut config = Config::default();
+        config.engine_stub_mode = true;
+        let engines = configured_engines(&config);
+        assert_eq!(engines.len(), 1);
+        assert_eq!(engines[0].engine_type(), EngineType::Stub);
+        assert_eq!(preflight_engines(&engines), Ok(()));
+    }
+
+    #[test]
+    fn preflight_reports_first_failing_engine() {
+        let engines: Vec<Arc<dyn Engine>> =
+            vec![Arc::new(StubEngine::new("loop")), Arc::new(LoggedOutEngine)];
+        assert_eq!(
+            preflight_engines(&engines),
+            Err("codex engine preflight failed: Not logged in".to_string())
+        );
+    }
+
+    #[test]
+    fn configured_engines_dedupes_weighted_types() {
+        let mut config = Config::default();
+        config.engine_types = vec![EngineType::Codex, EngineType::Codex, EngineType::Claude];
+        let types: Vec<EngineType> = configured_engines(&config)
+            .iter()
+            .map(|engine| engine.engine_type())
+            .collect();
+        assert_eq!(types, vec![EngineType::Codex, EngineType::Claude]);
+    }
+
+    /// Every file under `dir` with its contents, for before/after comparisons.
+    fn snapshot_dir(dir: &Path) -> Vec<(String, Vec<u8>)> {
+        let mut files = Vec::new();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(&dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else {
+                    files.push((path.display().to_string(), fs::read(&path).unwrap()));
+                }
+            }
+        }
+        files.sort();
+        files
+    }
+
+    fn git_output(args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .output()
+            .expect("run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    }
+
+    #[test]
+    fn dry_run_prints_assignments_and_leaves_state_untouched() {
+        with_temp_cwd(|| {
+            git_output(&["init", "-b", "main"]);
+            git_output(&["config", "user.name", "Swarm Test"]);
+            git_output(&["config", "user.email", "swarm-test@example.com"]);
+            fs::create_dir_all(".swarm-hug/greenfield").unwrap();
+            fs::write(
+                ".swarm-hug/greenfield/tasks.md",
+                "# Tasks\n\n- [ ] Build login\n- [ ] Add logout\n- [x] Set up repo (A)\n",
+            )
+            .unwrap();
+            git_output(&["add", "."]);
+            git_output(&["commit", "-m", "init"]);
+
+            let mut config = Config::default();
+            config.project = Some("greenfield".to_string());
+            config.source_branch = Some("main".to_string());
+            config.target_branch = Some("main".to_string());
+            config.files_tasks = ".swarm-hug/greenfield/tasks.md".to_string();
+            config.files_worktrees_dir = ".swarm-hug/greenfield/worktrees".to_string();
+            config.agents_tasks_per_agent = 1;
+            config.run_id = Some("abc123".to_string());
+
+            let state_before = snapshot_dir(Path::new(".swarm-hug"));
+            let refs_before = git_output(&["for-each-ref"]);
+            let worktrees_before = git_output(&["worktree", "list"]);
+
+            let plan = plan_dry_run(&config, "abc123").expect("dry run plan");
+            let output = format_dry_run(&plan);
+
+            assert!(output.contains("greenfield sprint 1:"), "{}", output);
+            assert!(
+                output.contains("Sprint branch: greenfield-sprint-1-abc123"),
+                "{}",
+                output
+            );
+            assert!(
+                output.contains("Aaron (A): greenfield-agent-aaron-abc123 -> "),
+                "{}",
+                output
+            );
+            assert!(output.contains("    - Build login"), "{}", output);
+            assert!(output.contains("Betty (B): greenfield-agent-betty-abc123"));
+            assert!(output.contains("    - Add logout"), "{}", output);
+            assert!(!output.contains("Set up repo"), "{}", output);
+
+            assert_eq!(snapshot_dir(Path::new(".swarm-hug")), state_before);
+            assert_eq!(git_output(&["for-each-ref"]), refs_before);
+            assert_eq!(git_output(&["worktree", "list"]), worktrees_before);
+            assert!(git_output(&["status", "--porcelain"]).is_empty());
+
+            config.agents_max_count = 0;
+            config.agents_on_no_agents = NoAgentsBehavior::Skip;
+            let plan = plan_dry_run(&config, "abc123").expect("skipped dry run plan");
+            assert!(plan.agents.is_empty());
+            config.agents_on_no_agents = NoAgentsBehavior::Error;
+            let err = plan_dry_run(&config, "abc123").expect_err("no agents should fail");
+            assert!(err.contains("no agents available"), "{}", err);
+        });
+    }
 }
@@ -0,0 +1,94 @@
+use std::path::{Path, PathBuf};
+
+use swarm::chat;
+use swarm::color::{self, emoji};
+use swarm::config::{CliArgs, Config};
+use swarm::json::json_string;
+use swarm::task::TaskList;
+use swarm::team::RuntimeStatePaths;
+
+use super::watch::status_line;
+use crate::project::project_name_for_config;
+use crate::runner::{runtime_task_file_paths, task_file_paths};
+
+/// Chat lines shown when `--last` is not given.
+const DEFAULT_RECENT_CHAT: usize = 5;
+
+/// Print task counts and the most recent chat lines.
+///
+/// With `--json` the same data is printed as one object with a stable
+/// schema: `{unassigned, assigned, completed, cancelled, interrupted,
+/// assignable, total, recent_chat}`.
+pub fn cmd_status(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    let tasks = TaskList::load_files(&status_task_paths(config))?;
+    let count = cli.last.unwrap_or(DEFAULT_RECENT_CHAT);
+    let recent_chat = chat::read_recent(&config.files_chat, count).unwrap_or_default();
+
+    if cli.json {
+        println!("{}", status_json(&tasks, &recent_chat));
+        return Ok(());
+    }
+
+    println!("{} {}", emoji::TASK, color::label(&status_line(&tasks)));
+    for line in &recent_chat {
+        println!("{}", color::chat_line(line));
+    }
+    Ok(())
+}
+
+/// The live task files of the run for `--target-branch` when one has
+/// started, otherwise the configured tasks files.
+fn status_task_paths(config: &Config) -> Vec<PathBuf> {
+    if let Some(target) = config.target_branch.as_deref() {
+        let runtime_paths = RuntimeStatePaths::for_branches(
+            &project_name_for_config(config),
+            config.source_branch.as_deref().unwrap_or(""),
+            target,
+        );
+        if runtime_paths.tasks_path().exists() {
+            return runtime_task_file_paths(&runtime_paths, config);
+        }
+    }
+    task_file_paths(Path::new(&config.files_tasks), Path::new(""), config)
+}
+
+fn status_json(tasks: &TaskList, recent_chat: &[String]) -> String {
+    let chat: Vec<String> = recent_chat.iter().map(|line| json_string(line)).collect();
+    format!(
+        "{{\"unassigned\":{},\"assigned\":{},\"completed\":{},\"cancelled\":{},\"interrupted\":{},\"assignable\":{},\"total\":{},\"recent_chat\":[{}]}}",
+        tasks.unassigned_count(),
+        tasks.assigned_count(),
+        tasks.completed_count(),
+        tasks.cancelled_count(),
+        tasks.interrupted_count(),
+        tasks.assignable_count(),
+        tasks.tasks.len(),
+        chat.join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_json_reports_task_counts_and_chat() {
+        let tasks = TaskList::parse(
+            "# Tasks\n\n\
+             - [ ] (#1) Add login\n\
+             - [ ] (#2) Add logout (blocked by #1)\n\
+             - [A] (#3) Fix crash {interrupted}\n\
+             - [x] (#4) Write docs (B)\n\
+             - [x] (#5) Add CI (A)\n\
+             - [-] (#6) Drop IE support\n",
+        );
+        let chat = vec!["**ScrumMaster**: Sprint 1 plan: \"1 task\"".to_string()];
+
+        assert_eq!(
+            status_json(&tasks, &chat),
+            "{\"unassigned\":2,\"assigned\":1,\"completed\":2,\"cancelled\":1,\"interrupted\":1,\
+             \"assignable\":1,\"total\":6,\"recent_chat\":[\"**ScrumMaster**: Sprint 1 plan: \\\"1 task\\\"\"]}"
+        );
+        assert!(status_json(&TaskList::default(), &[]).ends_with("\"total\":0,\"recent_chat\":[]}"));
+    }
+}
@@ -0,0 +1,373 @@
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::commands::cleanup::{cleanup_team, is_confirmation, registered_worktrees_under};
+use crate::git::git_repo_root;
+use swarm::config::{CliArgs, Config};
+use swarm::team::{self, Team};
+use swarm::worktree::PRESERVED_DIR;
+
+/// Clean up a project's worktrees, agent branches, and assignments, then move
+/// `.swarm-hug/<name>/` to `.swarm-hug/.archive/<name>/`. With `--delete`, the
+/// directory is removed instead, after confirmation.
+pub fn cmd_team_archive(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    let name = cli
+        .project_arg
+        .as_deref()
+        .ok_or("usage: swarm team archive <name> [--delete]")?;
+    let team = Team::new(name);
+    if name.starts_with('.') || !team.exists() {
+        return Err(format!("project '{}' not found", name));
+    }
+
+    if cli.delete && !cli.yes {
+        if !io::stdin().is_terminal() {
+            return Err(format!(
+                "refusing to delete project '{}' without confirmation; pass --yes",
+                name
+            ));
+        }
+        println!(
+            "Permanently delete {} and its worktrees and agent branches? [y/N]",
+            team.root.display()
+        );
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .map_err(|e| format!("failed to read confirmation: {}", e))?;
+        if !is_confirmation(&input) {
+            println!("Delete cancelled.");
+            return Ok(());
+        }
+    }
+
+    let repo_root = git_repo_root()?;
+    for e in cleanup_team(&repo_root, &team, &config.branch_prefix) {
+        eprintln!("warning: {}", e);
+    }
+
+    if cli.delete {
+        team.delete()?;
+        println!("Deleted project '{}'.", name);
+    } else {
+        let dest = team.archive()?;
+        println!("Archived project '{}' to {}.", name, dest.display());
+    }
+    Ok(())
+}
+
+/// Rename a project: move `.swarm-hug/<old>/` to `.swarm-hug/<new>/`, update
+/// the team name in its sprint history and team state, and rename its agent
+/// and sprint branches and their worktrees to match.
+pub fn cmd_team_rename(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    const USAGE: &str = "usage: swarm team rename <old> <new>";
+    let old_name = cli.project_arg.as_deref().ok_or(USAGE)?;
+    let new_name = cli.new_name_arg.as_deref().ok_or(USAGE)?;
+    let old = Team::new(old_name);
+    if old_name.starts_with('.') || !old.exists() {
+        return Err(format!("project '{}' not found", old_name));
+    }
+    team::validate_name(new_name)?;
+    if Team::new(new_name).root.exists() {
+        return Err(format!("project '{}' already exists", new_name));
+    }
+
+    let repo_root = git_repo_root()?;
+    let prefix = &config.branch_prefix;
+    let old_worktrees_dir = repo_root.join(old.worktrees_dir());
+    let worktrees = registered_worktrees_under(&repo_root, &old_worktrees_dir)?;
+    let branches = team_branches(&repo_root, old_name, prefix)?;
+
+    let renamed = old.rename(new_name, prefix)?;
+    let new_worktrees_dir = repo_root.join(renamed.worktrees_dir());
+
+    let mut warnings = Vec::new();
+    let mut moved = Vec::new();
+    for path in &worktrees {
+        let Ok(rel) = path.strip_prefix(&old_worktrees_dir) else {
+            continue;
+        };
+        let from = new_worktrees_dir.join(rel);
+        let to = match renamed_worktree(rel, prefix, old_name, new_name) {
+            Some(rel) => new_worktrees_dir.join(rel),
+            None => from.clone(),
+        };
+        if from != to {
+            if let Err(e) = fs::rename(&from, &to) {
+                warnings.push(format!(
+                    "failed to move {} to {}: {}",
+                    from.display(),
+                    to.display(),
+                    e
+                ));
+                moved.push(from);
+                continue;
+            }
+        }
+        moved.push(to);
+    }
+    if !moved.is_empty() {
+        if let Err(e) = run_git_in(&repo_root, "worktree", "repair", &moved) {
+            warnings.push(e);
+        }
+    }
+
+    for branch in &branches {
+        let Some(new_branch) = team::renamed_branch(branch, prefix, old_name, new_name) else {
+            continue;
+        };
+        if let Err(e) = run_git_in(&repo_root, "branch", "-m", &[branch, &new_branch]) {
+            warnings.push(e);
+        }
+    }
+
+    for w in &warnings {
+        eprintln!("warning: {}", w);
+    }
+    println!(
+        "Renamed project '{}' to '{}' ({} branches, {} worktrees).",
+        old_name,
+        new_name,
+        branches.len(),
+        moved.len()
+    );
+    Ok(())
+}
+
+/// The renamed location of a worktree (relative to the worktrees dir) whose
+/// directory is named after one of the team's branches.
+fn renamed_worktree(rel: &Path, prefix: &str, old: &str, new: &str) -> Option<PathBuf> {
+    let rel = rel.to_str()?;
+    if let Some(renamed) = team::renamed_branch(rel, prefix, old, new) {
+        return Some(PathBuf::from(renamed));
+    }
+    let preserved = rel.strip_prefix(PRESERVED_DIR)?.strip_prefix('/')?;
+    team::renamed_branch(preserved, prefix, old, new).map(|r| Path::new(PRESERVED_DIR).join(r))
+}
+
+/// The team's local agent and sprint branches.
+fn team_branches(repo_root: &Path, team_name: &str, prefix: &str) -> Result<Vec<String>, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["for-each-ref", "--format=%(refname:short)"])
+        .arg(format!("refs/heads/{}{}-agent-*", prefix, team_name))
+        .arg(format!("refs/heads/{}{}-sprint-*", prefix, team_name))
+        .output()
+        .map_err(|e| format!("failed to run git for-each-ref: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git for-each-ref failed: {}", stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(ToString::to_string)
+        .collect())
+}
+
+fn run_git_in<S: AsRef<std::ffi::OsStr>>(
+    repo_root: &Path,
+    command: &str,
+    flag: &str,
+    args: &[S],
+) -> Result<(), String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args([command, flag])
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run git {} {}: {}", command, flag, e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!(
+            "git {} {} failed: {}",
+            command,
+            flag,
+            stderr.trim()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::with_temp_cwd;
+    use std::path::Path;
+    use std::process::Command;
+    use swarm::team;
+
+    fn run_git(args: &[&str]) {
+        let output = Command::new("git").args(args).output().expect("run git");
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn setup_repo_with_team(name: &str) -> Team {
+        run_git(&["init"]);
+        run_git(&["config", "user.name", "Swarm Test"]);
+        run_git(&["config", "user.email", "swarm-test@example.com"]);
+        std::fs::write("README.md", "init").unwrap();
+        run_git(&["add", "."]);
+        run_git(&["commit", "-m", "init"]);
+        team::init_root().unwrap();
+        let team = Team::new(name);
+        team.init().unwrap();
+        std::fs::write(team.tasks_path(), "# Tasks\n\n- [A] Task one\n").unwrap();
+        run_git(&["branch", &format!("{}-agent-aaron-abc123", name)]);
+        team
+    }
+
+    fn archive_cli(name: &str) -> CliArgs {
+        CliArgs {
+            project_arg: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_team_archive_cleans_up_and_moves_directory() {
+        with_temp_cwd(|| {
+            let team = setup_repo_with_team("payments");
+            Team::new("search").init().unwrap();
+
+            cmd_team_archive(&Config::default(), &archive_cli("payments")).unwrap();
+
+            assert!(!team.exists());
+            let archived = Path::new(".swarm-hug/.archive/payments");
+            let tasks = std::fs::read_to_string(archived.join("tasks.md")).unwrap();
+            assert!(tasks.contains("- [ ] Task one"), "{}", tasks);
+            let branches = Command::new("git")
+                .args(["branch", "--list", "payments-agent-*"])
+                .output()
+                .unwrap();
+            assert!(String::from_utf8_lossy(&branches.stdout).trim().is_empty());
+            let names: Vec<String> = team::list_teams()
+                .unwrap()
+                .into_iter()
+                .map(|t| t.name)
+                .collect();
+            assert_eq!(names, vec!["search"]);
+        });
+    }
+
+    #[test]
+    fn test_team_archive_delete_with_yes_removes_directory() {
+        with_temp_cwd(|| {
+            let team = setup_repo_with_team("payments");
+            let cli = CliArgs {
+                delete: true,
+                yes: true,
+                ..archive_cli("payments")
+            };
+
+            cmd_team_archive(&Config::default(), &cli).unwrap();
+
+            assert!(!team.exists());
+            assert!(!team.archive_path().exists());
+        });
+    }
+
+    #[test]
+    fn test_team_archive_rejects_unknown_project() {
+        with_temp_cwd(|| {
+            team::init_root().unwrap();
+            let err = cmd_team_archive(&Config::default(), &archive_cli("missing")).unwrap_err();
+            assert!(err.contains("not found"), "{}", err);
+            let err = cmd_team_archive(&Config::default(), &archive_cli(".archive")).unwrap_err();
+            assert!(err.contains("not found"), "{}", err);
+        });
+    }
+
+    #[test]
+    fn test_team_rename_preserves_history_and_renames_branches() {
+        with_temp_cwd(|| {
+            let team = setup_repo_with_team("payments");
+            let mut history = team::SprintHistory::load_from(&team.sprint_history_path()).unwrap();
+            history.team_name = "payments".to_string();
+            history.increment();
+            history.increment();
+            history.increment();
+            history.save().unwrap();
+            let branch = "payments-agent-aaron-abc123";
+            let worktree = team.worktrees_dir().join("payments-agent-betty-abc123");
+            run_git(&[
+                "worktree",
+                "add",
+                "-b",
+                "payments-agent-betty-abc123",
+                worktree.to_str().unwrap(),
+            ]);
+
+            let cli = CliArgs {
+                new_name_arg: Some("billing".to_string()),
+                ..archive_cli("payments")
+            };
+            cmd_team_rename(&Config::default(), &cli).unwrap();
+
+            assert!(!team.exists());
+            let renamed = Team::new("billing");
+            let history = team::SprintHistory::load_from(&renamed.sprint_history_path()).unwrap();
+            assert_eq!(history.team_name, "billing");
+            assert_eq!(history.total_sprints, 3);
+            let tasks = std::fs::read_to_string(renamed.tasks_path()).unwrap();
+            assert!(tasks.contains("Task one"), "{}", tasks);
+
+            let branches = Command::new("git")
+                .args(["branch", "--list", "--format=%(refname:short)"])
+                .output()
+                .unwrap();
+            let branches = String::from_utf8_lossy(&branches.stdout);
+            assert!(!branches.contains(branch), "{}", branches);
+            assert!(
+                branches.contains("billing-agent-aaron-abc123"),
+                "{}",
+                branches
+            );
+            assert!(
+                branches.contains("billing-agent-betty-abc123"),
+                "{}",
+                branches
+            );
+
+            let moved = renamed.worktrees_dir().join("billing-agent-betty-abc123");
+            assert!(moved.join("README.md").exists());
+            let head = Command::new("git")
+                .arg("-C")
+                .arg(&moved)
+                .args(["rev-parse", "--abbrev-ref", "HEAD"])
+                .output()
+                .unwrap();
+            assert_eq!(
+                String::from_utf8_lossy(&head.stdout).trim(),
+                "billing-agent-betty-abc123"
+            );
+        });
+    }
+
+    #[test]
+    fn test_team_rename_refuses_existing_project() {
+        with_temp_cwd(|| {
+            let team = setup_repo_with_team("payments");
+            Team::new("billing").init().unwrap();
+            let cli = CliArgs {
+                new_name_arg: Some("billing".to_string()),
+                ..archive_cli("payments")
+            };
+
+            let err = cmd_team_rename(&Config::default(), &cli).unwrap_err();
+            assert!(err.contains("already exists"), "{}", err);
+            assert!(team.exists());
+        });
+    }
+}
@@ -0,0 +1,154 @@
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use swarm::agent;
+use swarm::color::{self, emoji};
+use swarm::config::Config;
+use swarm::shutdown;
+use swarm::task::{TaskList, TaskStatus};
+use swarm::team::RuntimeStatePaths;
+
+use crate::project::project_name_for_config;
+use crate::tail::tail_follow;
+
+/// How often the task status line is refreshed.
+const STATUS_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Poll interval for the shutdown flag between status refreshes.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Attach to a team read-only: follow chat and refresh task status.
+///
+/// Never plans, assigns, or runs engines, so it is safe to run alongside an
+/// active `swarm run` (or with no run at all). Stops on Ctrl+C.
+pub fn cmd_watch(config: &Config) -> Result<(), String> {
+    println!(
+        "{} {} {} (Ctrl+C to stop)",
+        emoji::LINK,
+        color::label("Watching"),
+        color::info(&config.files_chat)
+    );
+
+    // A run for a target branch keeps its live task list under runs/<target>/.
+    let tasks_path = match config.target_branch.as_deref() {
+        Some(target) => RuntimeStatePaths::for_branches(
+            &project_name_for_config(config),
+            config.source_branch.as_deref().unwrap_or(""),
+            target,
+        )
+        .tasks_path(),
+        None => config.files_tasks.clone().into(),
+    };
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let path = config.files_chat.clone();
+    let stop_clone = Arc::clone(&stop);
+    let tail_handle = thread::spawn(move || {
+        if let Err(e) = tail_follow(&path, true, Some(stop_clone)) {
+            eprintln!("warning: tail stopped: {}", e);
+        }
+    });
+
+    let mut last_status: Option<String> = None;
+    let mut last_refresh: Option<Instant> = None;
+    while !shutdown::requested() {
+        if last_refresh.is_none_or(|t| t.elapsed() >= STATUS_REFRESH_INTERVAL) {
+            let status = fs::read_to_string(&tasks_path)
+                .map(|content| status_line(&TaskList::parse(&content)))
+                .unwrap_or_else(|_| "no tasks file yet".to_string());
+            if last_status.as_deref() != Some(status.as_str()) {
+                println!("{} {}", emoji::TASK, color::label(&status));
+                last_status = Some(status);
+            }
+            last_refresh = Some(Instant::now());
+        }
+        thread::sleep(SHUTDOWN_POLL_INTERVAL);
+    }
+
+    stop.store(true, Ordering::SeqCst);
+    let _ = tail_handle.join();
+    println!("{} Stopped watching.", emoji::STOP);
+    Ok(())
+}
+
+/// One-line task summary for the observer's periodic status refresh.
+pub(super) fn status_line(tasks: &TaskList) -> String {
+    let mut agents: Vec<char> = tasks
+        .tasks
+        .iter()
+        .filter_map(|t| match t.status {
+            TaskStatus::Assigned(initial) => Some(initial),
+            _ => None,
+        })
+        .collect();
+    agents.sort_unstable();
+    agents.dedup();
+
+    let mut line = format!(
+        "Status: {}/{} completed, {} in progress, {} unassigned",
+        tasks.completed_count(),
+        tasks.tasks.len(),
+        tasks.assigned_count(),
+        tasks.unassigned_count()
+    );
+    let cancelled = tasks.cancelled_count();
+    if cancelled > 0 {
+        line.push_str(&format!(", {} cancelled", cancelled));
+    }
+    let interrupted = tasks.interrupted_count();
+    if interrupted > 0 {
+        line.push_str(&format!(", {} interrupted", interrupted));
+    }
+    if !agents.is_empty() {
+        let initials: String = agents.into_iter().map(agent::id).collect();
+        line.push_str(&format!(" (agents: {})", initials));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::status_line;
+    use swarm::task::TaskList;
+
+    #[test]
+    fn test_status_line_counts_tasks_and_active_agents() {
+        let tasks = TaskList::parse(
+            "# Tasks\n- [x] Done (A)\n- [B] Working\n- [A] Also working\n- [B] More\n- [ ] Todo\n",
+        );
+        assert_eq!(
+            status_line(&tasks),
+            "Status: 1/5 completed, 3 in progress, 1 unassigned (agents: AB)"
+        );
+    }
+
+    #[test]
+    fn test_status_line_shows_cancelled_tasks() {
+        let tasks = TaskList::parse("- [x] Done (A)\n- [-] Dropped\n- [ ] Todo\n");
+        assert_eq!(
+            status_line(&tasks),
+            "Status: 1/3 completed, 0 in progress, 1 unassigned, 1 cancelled"
+        );
+    }
+
+    #[test]
+    fn test_status_line_shows_interrupted_tasks() {
+        let tasks = TaskList::parse("- [ ] Retry {interrupted}\n- [ ] Todo\n");
+        assert_eq!(
+            status_line(&tasks),
+            "Status: 0/2 completed, 0 in progress, 2 unassigned, 1 interrupted"
+        );
+    }
+
+    #[test]
+    fn test_status_line_without_active_agents() {
+        let tasks = TaskList::parse("- [ ] One\n- [ ] Two\n");
+        assert_eq!(
+            status_line(&tasks),
+            "Status: 0/2 completed, 0 in progress, 2 unassigned"
+        );
+    }
+}
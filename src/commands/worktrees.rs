@@ -0,0 +1,45 @@
+use std::path::Path;
+
+use crate::output::format_bytes;
+use swarm::config::{CliArgs, Config};
+use swarm::worktree;
+
+/// List the project's worktrees. With `--size`, print each worktree's disk
+/// usage and the total.
+pub fn cmd_worktrees(config: &Config, cli: &CliArgs) -> Result<(), String> {
+    let worktrees_dir = Path::new(&config.files_worktrees_dir);
+
+    if !cli.size {
+        let dirs = worktree::worktree_dirs(worktrees_dir);
+        if dirs.is_empty() {
+            println!("No worktrees in {}.", worktrees_dir.display());
+        }
+        for (name, _) in dirs {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    let usage = worktree::disk_usage(worktrees_dir);
+    if usage.is_empty() {
+        println!("No worktrees in {}.", worktrees_dir.display());
+        return Ok(());
+    }
+    let width = usage.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    for (name, bytes) in &usage {
+        println!(
+            "{:<width$}  {:>10}",
+            name,
+            format_bytes(*bytes),
+            width = width
+        );
+    }
+    let total: u64 = usage.iter().map(|(_, bytes)| bytes).sum();
+    println!(
+        "{:<width$}  {:>10}",
+        "Total",
+        format_bytes(total),
+        width = width
+    );
+    Ok(())
+}
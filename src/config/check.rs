@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use std::env as std_env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::cli::CliArgs;
+use super::toml::{apply_key, toml_entries};
+use super::types::{Config, ConfigError, EngineType, DEFAULT_CONFIG_FILES};
+use super::{env, yaml};
+use crate::team::Team;
+
+/// Co-author email written by `swarm set-email`.
+const EMAIL_FILE: &str = ".swarm-hug/email.txt";
+
+/// Where a config value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Built-in default.
+    Default,
+    /// A config file (swarm.toml, swarm.yaml, a team config.toml, or email.txt).
+    File(String),
+    /// A `[profile.<name>]` table selected with `--profile` or `SWARM_PROFILE`.
+    Profile(String),
+    /// An environment variable.
+    Env(&'static str),
+    /// A command-line flag.
+    Cli(&'static str),
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::File(path) => write!(f, "file {}", path),
+            Self::Profile(name) => write!(f, "profile {}", name),
+            Self::Env(var) => write!(f, "env {}", var),
+            Self::Cli(flag) => write!(f, "CLI {}", flag),
+        }
+    }
+}
+
+/// One configuration problem found by [`check_config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// Config key the problem concerns (e.g., `agents.max_count`).
+    pub setting: String,
+    /// Source of the offending value.
+    pub source: ConfigSource,
+    /// What is wrong.
+    pub message: String,
+}
+
+/// Result of [`check_config`].
+#[derive(Debug, Default)]
+pub struct ConfigReport {
+    /// Config files that were read, in load order.
+    pub files: Vec<String>,
+    /// Every problem found; empty when the config is valid.
+    pub problems: Vec<ConfigProblem>,
+}
+
+impl ConfigReport {
+    /// Whether no problems were found.
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    fn problem(&mut self, setting: &str, source: ConfigSource, err: ConfigError) {
+        let message = match err {
+            ConfigError::Io(msg) | ConfigError::Parse(msg) | ConfigError::Validation(msg) => msg,
+        };
+        self.problems.push(ConfigProblem {
+            setting: setting.to_string(),
+            source,
+            message,
+        });
+    }
+}
+
+/// A setting that can be given by file, env var, and (optionally) a CLI flag.
+struct Setting {
+    key: &'static str,
+    env: &'static str,
+    flag: Option<&'static str>,
+    cli_set: fn(&CliArgs) -> bool,
+}
+
+const SETTINGS: &[Setting] = &[
+    Setting {
+        key: "agents.max_count",
+        env: "SWARM_AGENTS_MAX_COUNT",
+        flag: Some("--max-agents"),
+        cli_set: |cli| cli.max_agents.is_some(),
+    },
+    Setting {
+        key: "agents.tasks_per_agent",
+        env: "SWARM_AGENTS_TASKS_PER_AGENT",
+        flag: Some("--tasks-per-agent"),
+        cli_set: |cli| cli.tasks_per_agent.is_some(),
+    },
+    Setting {
+        key: "engine.type",
+        env: "SWARM_ENGINE_TYPE",
+        flag: Some("--engine"),
+        cli_set: |cli| cli.engine.is_some(),
+    },
+    Setting {
+        key: "engine.stub_fail_rate",
+        env: "SWARM_ENGINE_STUB_FAIL_RATE",
+        flag: Some("--fail-rate"),
+        cli_set: |cli| cli.fail_rate.is_some(),
+    },
+    Setting {
+        key: "files.tasks",
+        env: "SWARM_FILES_TASKS",
+        flag: Some("--tasks-file"),
+        cli_set: |cli| !cli.tasks_file.is_empty(),
+    },
+    Setting {
+        key: "files.chat",
+        env: "SWARM_FILES_CHAT",
+        flag: Some("--chat-file"),
+        cli_set: |cli| cli.chat_file.is_some(),
+    },
+    Setting {
+        key: "files.chat_timezone",
+        env: "SWARM_FILES_CHAT_TIMEZONE",
+        flag: None,
+        cli_set: |_| false,
+    },
+    Setting {
+        key: "files.chat_time_format",
+        env: "SWARM_FILES_CHAT_TIME_FORMAT",
+        flag: None,
+        cli_set: |_| false,
+    },
+    Setting {
+        key: "files.log_dir",
+        env: "SWARM_FILES_LOG_DIR",
+        flag: Some("--log-dir"),
+        cli_set: |cli| cli.log_dir.is_some(),
+    },
+    Setting {
+        key: "files.log_format",
+        env: "SWARM_FILES_LOG_FORMAT",
+        flag: Some("--log-format"),
+        cli_set: |cli| cli.log_format.is_some(),
+    },
+    Setting {
+        key: "files.log_max_rotations",
+        env: "SWARM_FILES_LOG_MAX_ROTATIONS",
+        flag: None,
+        cli_set: |_| false,
+    },
+    Setting {
+        key: "sprints.run_hash_len",
+        env: "SWARM_RUN_HASH_LEN",
+        flag: None,
+        cli_set: |_| false,
+    },
+    Setting {
+        key: "sprints.branch_prefix",
+        env: "SWARM_BRANCH_PREFIX",
+        flag: None,
+        cli_set: |_| false,
+    },
+];
+
+/// Load configuration like [`Config::load`], collecting every problem instead
+/// of stopping at the first one.
+///
+/// Unlike `Config::load`, an unreadable or invalid config file is reported
+/// rather than skipped, and env vars or flags that would be silently ignored
+/// (e.g., an unknown engine in `SWARM_ENGINE_TYPE`) are flagged.
+pub fn check_config(cli: &CliArgs) -> ConfigReport {
+    let mut report = ConfigReport::default();
+    let mut config = Config::default();
+    // Source of each key set by a file or profile
+    let mut set_by: HashMap<String, ConfigSource> = HashMap::new();
+
+    let root_file = match &cli.config {
+        Some(path) => Some(PathBuf::from(path)),
+        None => DEFAULT_CONFIG_FILES
+            .iter()
+            .map(PathBuf::from)
+            .find(|path| path.exists()),
+    };
+    let team_file = cli
+        .project
+        .as_deref()
+        .map(|team| Team::new(team).config_path())
+        .filter(|path| path.exists());
+    for path in root_file.iter().chain(team_file.iter()) {
+        apply_file(&mut config, path, &mut set_by, &mut report);
+    }
+
+    let profile = match &cli.profile {
+        Some(name) => Some((name.clone(), ConfigSource::Cli("--profile"))),
+        None => std_env::var("SWARM_PROFILE")
+            .ok()
+            .map(|name| (name, ConfigSource::Env("SWARM_PROFILE"))),
+    };
+    if let Some((name, source)) = profile.filter(|(name, _)| !name.is_empty()) {
+        match config.profiles.get(&name).cloned() {
+            Some(settings) => {
+                for (key, value) in settings {
+                    // Values were checked when the file was read
+                    let _ = apply_key(&mut config, &key, &value);
+                    set_by.insert(key, ConfigSource::Profile(name.clone()));
+                }
+            }
+            None => report.problem(
+                "profile",
+                source,
+                ConfigError::Validation(format!("unknown profile '{}'", name)),
+            ),
+        }
+    }
+
+    // Env vars and flags that Config::load would silently ignore
+    for setting in SETTINGS {
+        if let Ok(value) = std_env::var(setting.env) {
+            if let Err(err) = apply_key(&mut Config::default(), setting.key, &value) {
+                report.problem(setting.key, ConfigSource::Env(setting.env), err);
+            }
+        }
+    }
+    if let Some(engine) = &cli.engine {
+        if EngineType::parse_list(engine).is_none() {
+            report.problem(
+                "engine.type",
+                ConfigSource::Cli("--engine"),
+                ConfigError::Validation(format!("unknown engine: {}", engine)),
+            );
+        }
+    }
+
+    env::apply_env(&mut config);
+    config.apply_cli(cli);
+    if config.engine_stub_mode {
+        config.engine_types = vec![EngineType::Stub];
+    }
+    config.resolve_command_engines();
+    if let Some(project) = config.project.clone() {
+        config.apply_project_paths(&project, cli);
+    }
+
+    let source_of = |key: &str| -> ConfigSource {
+        if let Some(setting) = SETTINGS.iter().find(|setting| setting.key == key) {
+            if let Some(flag) = setting.flag.filter(|_| (setting.cli_set)(cli)) {
+                return ConfigSource::Cli(flag);
+            }
+            if std_env::var(setting.env).is_ok() {
+                return ConfigSource::Env(setting.env);
+            }
+        }
+        set_by.get(key).cloned().unwrap_or(ConfigSource::Default)
+    };
+
+    for (key, value) in [
+        ("agents.max_count", config.agents_max_count),
+        ("agents.tasks_per_agent", config.agents_tasks_per_agent),
+    ] {
+        if value == 0 {
+            report.problem(
+                key,
+                source_of(key),
+                ConfigError::Validation(format!("{} must be greater than 0", key)),
+            );
+        }
+    }
+    for (key, value) in [
+        ("files.tasks", &config.files_tasks),
+        ("files.chat", &config.files_chat),
+        ("files.log_dir", &config.files_log_dir),
+    ] {
+        if value.trim().is_empty() {
+            report.problem(
+                key,
+                source_of(key),
+                ConfigError::Validation(format!("{} must not be empty", key)),
+            );
+        }
+    }
+    for (key, err) in config.validation_errors() {
+        report.problem(key, source_of(key), err);
+    }
+
+    if let Ok(email) = fs::read_to_string(EMAIL_FILE) {
+        let email = email.trim();
+        if !is_valid_email(email) {
+            report.problem(
+                "email",
+                ConfigSource::File(EMAIL_FILE.to_string()),
+                ConfigError::Validation(format!("invalid co-author email '{}'", email)),
+            );
+        }
+    }
+
+    report
+}
+
+/// Apply each key in a TOML/YAML file, reporting every bad value.
+fn apply_file(
+    config: &mut Config,
+    path: &Path,
+    set_by: &mut HashMap<String, ConfigSource>,
+    report: &mut ConfigReport,
+) {
+    let source = ConfigSource::File(path.display().to_string());
+    let entries = fs::read_to_string(path)
+        .map_err(|e| ConfigError::Io(format!("failed to read {}: {}", path.display(), e)))
+        .and_then(|content| {
+            if yaml::is_yaml_path(path) {
+                yaml::flatten(&content)
+            } else {
+                Ok(toml_entries(&content))
+            }
+        });
+    let entries = match entries {
+        Ok(entries) => entries,
+        Err(err) => {
+            report.problem("file", source, err);
+            return;
+        }
+    };
+
+    report.files.push(path.display().to_string());
+    for (key, value) in entries {
+        match apply_key(config, &key, &value) {
+            Ok(()) => {
+                set_by.insert(key, source.clone());
+            }
+            Err(err) => report.problem(&key, source.clone(), err),
+        }
+    }
+}
+
+/// Basic `local@domain.tld` check.
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.chars().any(char::is_whitespace)
+}
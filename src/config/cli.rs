@@ -1,3 +1,5 @@
+use crate::run_hash::sanitize_run_id;
+
 /// CLI arguments parsed from command line.
 #[derive(Debug, Default)]
 pub struct CliArgs {
@@ -5,26 +7,96 @@ pub struct CliArgs {
     pub command: Option<Command>,
     /// Path to config file.
     pub config: Option<String>,
+    /// Config profile (`[profile.<name>]`) to overlay on the config file.
+    pub profile: Option<String>,
     /// Maximum number of agents.
     pub max_agents: Option<usize>,
     /// Tasks per agent per sprint.
     pub tasks_per_agent: Option<usize>,
     /// Agent timeout in seconds.
     pub agent_timeout: Option<u64>,
-    /// Path to tasks file.
-    pub tasks_file: Option<String>,
+    /// Merge-agent timeout in seconds.
+    pub merge_timeout: Option<u64>,
+    /// Merge-agent re-attempts after a failed sprint merge.
+    pub merge_retries: Option<usize>,
+    /// Planning/review timeout in seconds.
+    pub planning_timeout: Option<u64>,
+    /// Seconds agents get to finish after shutdown is requested.
+    pub shutdown_grace: Option<u64>,
+    /// Seconds between "still working" heartbeat chat messages.
+    pub heartbeat_interval: Option<u64>,
+    /// Maximum engine executions running at once.
+    pub max_concurrency: Option<usize>,
+    /// Paths to tasks files (`--tasks-file` may be repeated).
+    pub tasks_file: Vec<String>,
     /// Path to chat file.
     pub chat_file: Option<String>,
+    /// Chat log format (`markdown` or `jsonl`).
+    pub chat_format: Option<String>,
     /// Path to log directory.
     pub log_dir: Option<String>,
+    /// Agent log format (`text` or `json`).
+    pub log_format: Option<String>,
     /// Engine type.
     pub engine: Option<String>,
     /// Enable stub mode.
     pub stub: bool,
+    /// Fraction of stub engine executions that fail (chaos testing).
+    pub fail_rate: Option<f64>,
+    /// Seed for reproducible engine selection and stub failures.
+    pub seed: Option<u64>,
+    /// Retries for transiently failed engine executions.
+    pub engine_retries: Option<u32>,
     /// Maximum sprints to run.
     pub max_sprints: Option<usize>,
     /// Disable TUI mode (use plain text output).
     pub no_tui: bool,
+    /// Push the target branch without opening a pull request.
+    pub no_pr: bool,
+    /// Forge to open the pull request on (`github` or `gitlab`).
+    pub forge: Option<String>,
+    /// Keep running when no tasks are assignable, polling for new ones.
+    pub watch_tasks: bool,
+    /// Merge agent branches in task dependency order.
+    pub ordered_merge: bool,
+    /// Apply cleanup to every project instead of only the current one.
+    pub all_teams: bool,
+    /// List what cleanup would remove without deleting anything; print the
+    /// sprint plan without running it for `run`.
+    pub dry_run: bool,
+    /// Skip the cleanup confirmation prompt.
+    pub yes: bool,
+    /// Limit cleanup to agent branches not checked out in a worktree.
+    pub branches: bool,
+    /// Delete the project in `team archive` instead of archiving it.
+    pub delete: bool,
+    /// Minimum age in days of preserved worktrees removed by `prune`.
+    pub older_than: Option<u64>,
+    /// Show disk usage in `worktrees`.
+    pub size: bool,
+    /// Print `status` as a JSON object.
+    pub json: bool,
+    /// Only show chat lines from this agent (name or initial) in `chat`;
+    /// the agent whose log `logs` shows.
+    pub agent: Option<String>,
+    /// Number of lines shown by `chat` and `logs`.
+    pub last: Option<usize>,
+    /// Keep printing lines appended to the log in `logs`.
+    pub follow: bool,
+    /// Sprint whose changes `diff` shows (default: the last completed sprint).
+    pub sprint: Option<usize>,
+    /// Number of `-v`/`--verbose` flags given.
+    pub verbose: u8,
+    /// Suppress progress output.
+    pub quiet: bool,
+    /// Directory to write generated engine prompts to.
+    pub dump_prompts: Option<String>,
+    /// Unix socket path to stream sprint events to.
+    pub event_socket: Option<String>,
+    /// URL agent events are POSTed to.
+    pub webhook_url: Option<String>,
+    /// Fixed run ID used as the branch run hash.
+    pub run_id: Option<String>,
     /// Show help.
     pub help: bool,
     /// Show version.
@@ -41,8 +113,14 @@ pub struct CliArgs {
     pub project_arg: Option<String>,
     /// Email for set-email command (positional arg).
     pub email_arg: Option<String>,
+    /// New project name for `team rename <old> <new>`.
+    pub new_name_arg: Option<String>,
     /// Path to PRD file for project init --with-prd.
     pub prd_file_arg: Option<String>,
+    /// GitHub repo whose open issues seed tasks for project init --with-issues.
+    pub issues_repo_arg: Option<String>,
+    /// Only import issues with this label (--with-issues).
+    pub issue_label: Option<String>,
     /// Unrecognized command, if provided.
     pub unknown_command: Option<String>,
     /// Parse-time validation error from malformed CLI flags.
@@ -68,6 +146,36 @@ pub enum Command {
     SetEmail,
     /// Interactive cleanup for git worktrees.
     CleanupWorktrees,
+    /// Remove a project's worktrees, agent branches, and task assignments.
+    Cleanup,
+    /// Remove old preserved worktrees and stale worktree registrations.
+    Prune,
+    /// List a project's worktrees, optionally with disk usage.
+    Worktrees,
+    /// Show task counts and recent chat, optionally as JSON.
+    Status,
+    /// Read-only observer: follow chat and task status without running sprints.
+    Watch,
+    /// Check the loaded configuration and report every problem.
+    ConfigValidate,
+    /// Print a JSON Schema for swarm.toml.
+    ConfigSchema,
+    /// Print recent chat lines, optionally from one agent.
+    Chat,
+    /// Terminate the engine processes recorded for a project's runs.
+    Kill,
+    /// List the engine processes still running for a project's runs.
+    Ps,
+    /// Clean up a project and move it to `.swarm-hug/.archive/`.
+    TeamArchive,
+    /// Rename a project along with its branches and worktrees.
+    TeamRename,
+    /// Print or follow one agent's log file.
+    Logs,
+    /// Continue an interrupted sprint, or start a run if none is in progress.
+    Resume,
+    /// Show the files a sprint changed and the tasks it completed.
+    Diff,
 }
 
 impl Command {
@@ -82,6 +190,19 @@ impl Command {
             "customize-prompts" => Some(Self::CustomizePrompts),
             "set-email" => Some(Self::SetEmail),
             "cleanup-worktrees" => Some(Self::CleanupWorktrees),
+            "cleanup" => Some(Self::Cleanup),
+            "prune" => Some(Self::Prune),
+            "worktrees" => Some(Self::Worktrees),
+            "status" => Some(Self::Status),
+            "watch" => Some(Self::Watch),
+            "config" => Some(Self::ConfigValidate),
+            "chat" => Some(Self::Chat),
+            "kill" => Some(Self::Kill),
+            "ps" => Some(Self::Ps),
+            "team" => Some(Self::TeamArchive),
+            "logs" => Some(Self::Logs),
+            "resume" => Some(Self::Resume),
+            "diff" => Some(Self::Diff),
             _ => None,
         }
     }
@@ -103,6 +224,7 @@ where
             "-h" | "--help" => cli.help = true,
             "-V" | "--version" => cli.version = true,
             "-c" | "--config" => cli.config = args.next(),
+            "--profile" => cli.profile = args.next(),
             "-p" | "--project" => cli.project = args.next(),
             "--source-branch" => {
                 cli.source_branch = take_flag_value(&mut args, &mut cli, "--source-branch");
@@ -114,14 +236,70 @@ where
             "--max-agents" => cli.max_agents = args.next().and_then(|s| s.parse().ok()),
             "--tasks-per-agent" => cli.tasks_per_agent = args.next().and_then(|s| s.parse().ok()),
             "--agent-timeout" => cli.agent_timeout = args.next().and_then(|s| s.parse().ok()),
-            "--tasks-file" => cli.tasks_file = args.next(),
+            "--merge-timeout" => cli.merge_timeout = args.next().and_then(|s| s.parse().ok()),
+            "--merge-retries" => cli.merge_retries = args.next().and_then(|s| s.parse().ok()),
+            "--planning-timeout" => cli.planning_timeout = args.next().and_then(|s| s.parse().ok()),
+            "--shutdown-grace" => cli.shutdown_grace = args.next().and_then(|s| s.parse().ok()),
+            "--heartbeat-interval" => {
+                cli.heartbeat_interval = args.next().and_then(|s| s.parse().ok())
+            }
+            "--max-concurrency" => cli.max_concurrency = args.next().and_then(|s| s.parse().ok()),
+            "--tasks-file" => cli.tasks_file.extend(args.next()),
             "--chat-file" => cli.chat_file = args.next(),
+            "--chat-format" => cli.chat_format = args.next(),
             "--log-dir" => cli.log_dir = args.next(),
+            "--log-format" => cli.log_format = args.next(),
             "--engine" => cli.engine = args.next(),
             "--stub" => cli.stub = true,
+            "--fail-rate" => cli.fail_rate = args.next().and_then(|s| s.parse().ok()),
+            "--seed" => cli.seed = args.next().and_then(|s| s.parse().ok()),
+            "--engine-retries" => cli.engine_retries = args.next().and_then(|s| s.parse().ok()),
             "--max-sprints" => cli.max_sprints = args.next().and_then(|s| s.parse().ok()),
             "--no-tui" => cli.no_tui = true,
+            "--no-pr" => cli.no_pr = true,
+            "--forge" => cli.forge = args.next(),
+            "--watch-tasks" => cli.watch_tasks = true,
+            "--ordered-merge" => cli.ordered_merge = true,
+            "--all-teams" => cli.all_teams = true,
+            "--dry-run" => cli.dry_run = true,
+            "-y" | "--yes" => cli.yes = true,
+            "--branches" => cli.branches = true,
+            "--delete" => cli.delete = true,
+            "--older-than" => cli.older_than = args.next().and_then(|s| s.parse().ok()),
+            "--size" => cli.size = true,
+            "--json" => cli.json = true,
+            "--agent" => cli.agent = args.next(),
+            "--last" | "--lines" => cli.last = args.next().and_then(|s| s.parse().ok()),
+            "-f" | "--follow" => cli.follow = true,
+            "--sprint" => cli.sprint = args.next().and_then(|s| s.parse().ok()),
+            "-v" | "--verbose" => cli.verbose = cli.verbose.saturating_add(1),
+            _ if arg.len() > 2 && arg.starts_with('-') && arg[1..].bytes().all(|b| b == b'v') => {
+                cli.verbose = cli.verbose.saturating_add((arg.len() - 1) as u8);
+            }
+            "-q" | "--quiet" => cli.quiet = true,
+            "--dump-prompts" => {
+                cli.dump_prompts = take_flag_value(&mut args, &mut cli, "--dump-prompts");
+            }
+            "--event-socket" => {
+                cli.event_socket = take_flag_value(&mut args, &mut cli, "--event-socket");
+            }
+            "--run-id" => {
+                cli.run_id = take_flag_value(&mut args, &mut cli, "--run-id");
+                if let Some(id) = cli.run_id.as_deref() {
+                    if sanitize_run_id(id).is_none() && cli.parse_error.is_none() {
+                        cli.parse_error = Some(format!(
+                            "--run-id '{}' has no letters or digits.\n  Example: swarm run --run-id nightly-42",
+                            id
+                        ));
+                    }
+                }
+            }
+            "--webhook-url" => {
+                cli.webhook_url = take_flag_value(&mut args, &mut cli, "--webhook-url");
+            }
             "--with-prd" => cli.prd_file_arg = args.next(),
+            "--with-issues" => cli.issues_repo_arg = args.next(),
+            "--label" => cli.issue_label = args.next(),
             _ if !arg.starts_with('-')
                 && cli.command.is_none()
                 && cli.unknown_command.is_none() =>
@@ -141,6 +319,51 @@ where
                             }
                         }
                     }
+                    // "config" takes a subcommand: validate or schema
+                    if cli.command == Some(Command::ConfigValidate) {
+                        match args.next() {
+                            Some(sub) if sub == "validate" => {}
+                            Some(sub) if sub == "schema" => {
+                                cli.command = Some(Command::ConfigSchema)
+                            }
+                            Some(sub) => cli.unknown_command = Some(format!("config {}", sub)),
+                            None => cli.unknown_command = Some("config".to_string()),
+                        }
+                    }
+                    // "team" takes a subcommand: init <name>, archive <name>, or
+                    // rename <old> <new>
+                    if cli.command == Some(Command::TeamArchive) {
+                        match args.next() {
+                            Some(sub) if sub == "init" => {
+                                cli.command = Some(Command::ProjectInit);
+                                if args.peek().is_some_and(|next| !next.starts_with('-')) {
+                                    cli.project_arg = args.next();
+                                }
+                            }
+                            Some(sub) if sub == "archive" => {
+                                if args.peek().is_some_and(|next| !next.starts_with('-')) {
+                                    cli.project_arg = args.next();
+                                }
+                            }
+                            Some(sub) if sub == "rename" => {
+                                cli.command = Some(Command::TeamRename);
+                                if args.peek().is_some_and(|next| !next.starts_with('-')) {
+                                    cli.project_arg = args.next();
+                                }
+                                if args.peek().is_some_and(|next| !next.starts_with('-')) {
+                                    cli.new_name_arg = args.next();
+                                }
+                            }
+                            Some(sub) => cli.unknown_command = Some(format!("team {}", sub)),
+                            None => cli.unknown_command = Some("team".to_string()),
+                        }
+                    }
+                    // For "logs <agent>", capture the agent name or initial
+                    if cli.command == Some(Command::Logs)
+                        && args.peek().is_some_and(|next| !next.starts_with('-'))
+                    {
+                        cli.agent = args.next();
+                    }
                     // For "set-email <email>", capture the email argument
                     if cli.command == Some(Command::SetEmail) {
                         if let Some(next) = args.peek() {
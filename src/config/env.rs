@@ -1,6 +1,13 @@
 use std::env;
 
-use super::types::{Config, EngineType};
+use super::types::{
+    parse_tier_limits, AgentTaskOrder, Config, EngineType, Forge, NoAgentsBehavior,
+};
+use crate::audit::SyslogFacility;
+use crate::chat::{self, ChatFormat, ChatTimezone};
+use crate::log::LogFormat;
+use crate::run_hash::sanitize_run_id;
+use crate::task::CompletionStyle;
 
 pub(super) fn apply_env(config: &mut Config) {
     if let Ok(val) = env::var("SWARM_AGENTS_MAX_COUNT") {
@@ -13,11 +20,64 @@ pub(super) fn apply_env(config: &mut Config) {
             config.agents_tasks_per_agent = n;
         }
     }
+    if let Ok(val) = env::var("SWARM_AGENTS_POINTS_PER_AGENT") {
+        if let Ok(n) = val.parse() {
+            config.agents_points_per_agent = n;
+        }
+    }
     if let Ok(val) = env::var("SWARM_AGENT_TIMEOUT") {
         if let Ok(n) = val.parse() {
             config.agent_timeout_secs = n;
         }
     }
+    if let Ok(val) = env::var("SWARM_MERGE_TIMEOUT") {
+        if let Ok(n) = val.parse() {
+            config.merge_timeout_secs = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_MERGE_RETRIES") {
+        if let Ok(n) = val.parse() {
+            config.merge_retries = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_PLANNING_TIMEOUT") {
+        if let Ok(n) = val.parse() {
+            config.planning_timeout_secs = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_SHUTDOWN_GRACE") {
+        if let Ok(n) = val.parse() {
+            config.shutdown_grace_secs = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_HEARTBEAT_INTERVAL") {
+        if let Ok(n) = val.parse() {
+            config.heartbeat_interval_secs = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_AGENT_TASK_ORDER") {
+        if let Some(order) = AgentTaskOrder::parse(&val) {
+            config.agent_task_order = order;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_AGENTS_ON_NO_AGENTS") {
+        if let Some(behavior) = NoAgentsBehavior::parse(&val) {
+            config.agents_on_no_agents = behavior;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_AGENTS_NO_AGENTS_WAIT_TIMEOUT") {
+        if let Ok(n) = val.parse() {
+            config.agents_no_agents_wait_secs = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_AGENTS_TASKS_PER_PRIORITY") {
+        if let Some(limits) = parse_tier_limits(&val) {
+            config.agents_tasks_per_priority = limits;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_AGENTS_KEEP_BRANCHES") {
+        config.agents_keep_branches = val == "true" || val == "1";
+    }
     if let Ok(val) = env::var("SWARM_FILES_TASKS") {
         config.files_tasks = val;
     }
@@ -27,6 +87,41 @@ pub(super) fn apply_env(config: &mut Config) {
     if let Ok(val) = env::var("SWARM_FILES_LOG_DIR") {
         config.files_log_dir = val;
     }
+    if let Ok(val) = env::var("SWARM_FILES_TASKS_COMPLETION_STYLE") {
+        if let Some(style) = CompletionStyle::parse(&val) {
+            config.files_tasks_completion_style = style;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_FILES_CHAT_FORMAT") {
+        if let Some(format) = ChatFormat::parse(&val) {
+            config.files_chat_format = format;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_FILES_CHAT_TIMEZONE") {
+        if let Some(timezone) = ChatTimezone::parse(&val) {
+            config.files_chat_timezone = timezone;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_FILES_CHAT_TIME_FORMAT") {
+        if chat::is_valid_time_format(&val) {
+            config.files_chat_time_format = val;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_FILES_LOG_FORMAT") {
+        if let Some(format) = LogFormat::parse(&val) {
+            config.files_log_format = format;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_FILES_LOG_MAX_ROTATIONS") {
+        if let Ok(n) = val.parse() {
+            config.files_log_max_rotations = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_FILES_LOG_OUTPUT_MAX_BYTES") {
+        if let Ok(n) = val.parse() {
+            config.files_log_output_max_bytes = Some(n);
+        }
+    }
     if let Ok(val) = env::var("SWARM_ENGINE_TYPE") {
         if let Some(engines) = EngineType::parse_list(&val) {
             config.engine_types = engines;
@@ -35,9 +130,115 @@ pub(super) fn apply_env(config: &mut Config) {
     if let Ok(val) = env::var("SWARM_ENGINE_STUB_MODE") {
         config.engine_stub_mode = val == "true" || val == "1";
     }
+    if let Ok(val) = env::var("SWARM_ENGINE_MAX_RPM") {
+        if let Ok(n) = val.parse() {
+            config.engine_max_rpm = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_ENGINE_MAX_CONCURRENCY") {
+        if let Ok(n) = val.parse() {
+            config.engine_max_concurrency = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_ENGINE_STUB_FAIL_RATE") {
+        if let Ok(rate) = val.parse() {
+            config.engine_stub_fail_rate = rate;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_ENGINE_SEED").or_else(|_| env::var("SWARM_ENGINE_STUB_SEED")) {
+        if let Ok(seed) = val.parse() {
+            config.seed = Some(seed);
+        }
+    }
+    if let Ok(val) = env::var("SWARM_ENGINE_RETRIES") {
+        if let Ok(n) = val.parse() {
+            config.engine_retries = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_ENGINE_RETRY_DELAY") {
+        if let Ok(n) = val.parse() {
+            config.engine_retry_delay_secs = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_ENGINE_OLLAMA_URL") {
+        config.engine_ollama_url = val;
+    }
     if let Ok(val) = env::var("SWARM_SPRINTS_MAX") {
         if let Ok(n) = val.parse() {
             config.sprints_max = n;
         }
     }
+    if let Ok(val) = env::var("SWARM_SPRINTS_TRACK_INTERRUPTED") {
+        config.sprints_track_interrupted = val == "true" || val == "1";
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_FETCH_BEFORE_MERGE") {
+        config.sprints_fetch_before_merge = val == "true" || val == "1";
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_CREATE_PR") {
+        config.sprints_create_pr = val == "true" || val == "1";
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_FORGE") {
+        if let Some(forge) = Forge::parse(&val) {
+            config.sprints_forge = Some(forge);
+        }
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_FAST_PATH_MERGE") {
+        config.sprints_fast_path_merge = val == "true" || val == "1";
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_ORDERED_MERGE") {
+        config.sprints_ordered_merge = val == "true" || val == "1";
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_WATCH_TASKS") {
+        config.sprints_watch_tasks = val == "true" || val == "1";
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_WATCH_INTERVAL") {
+        if let Ok(n) = val.parse() {
+            config.sprints_watch_interval_secs = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_SPRINTS_MAX_RETRIES") {
+        if let Ok(n) = val.parse() {
+            config.sprints_max_retries = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_RUN_HASH_LEN") {
+        if let Ok(n) = val.parse() {
+            config.run_hash_len = n;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_BRANCH_PREFIX") {
+        config.branch_prefix = val;
+    }
+    if let Ok(val) = env::var("SWARM_WORKTREE_SPARSE_PATHS") {
+        config.worktree_sparse_paths = val
+            .split(',')
+            .map(|path| path.trim().to_string())
+            .filter(|path| !path.is_empty())
+            .collect();
+    }
+    if let Ok(val) = env::var("SWARM_WORKTREE_PRESERVED_MAX_AGE_DAYS") {
+        if let Ok(days) = val.parse() {
+            config.worktree_preserved_max_age_days = days;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_AUDIT_SYSLOG") {
+        config.audit_syslog = val == "true" || val == "1";
+    }
+    if let Ok(val) = env::var("SWARM_AUDIT_SYSLOG_FACILITY") {
+        if let Some(facility) = SyslogFacility::parse(&val) {
+            config.audit_syslog_facility = facility;
+        }
+    }
+    if let Ok(val) = env::var("SWARM_RUN_ID") {
+        config.run_id = sanitize_run_id(&val).map(|_| val);
+    }
+    if let Ok(val) = env::var("SWARM_WEBHOOK_URL") {
+        config.webhook_url = (!val.is_empty()).then_some(val);
+    }
+    if let Ok(val) = env::var("SWARM_SPRINT_WEBHOOK_URL") {
+        config.sprint_webhook_url = (!val.is_empty()).then_some(val);
+    }
+    if let Ok(val) = env::var("SWARM_SLACK_WEBHOOK_URL") {
+        config.slack_webhook_url = (!val.is_empty()).then_some(val);
+    }
 }
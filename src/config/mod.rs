@@ -1,15 +1,25 @@
 //! Configuration loading for swarm.
 //!
-//! Supports swarm.toml, CLI flags, and environment variables.
+//! Supports swarm.toml (or swarm.yaml / swarm.yml), CLI flags, and environment variables.
 //! Precedence (highest to lowest): CLI flags > env vars > config file > defaults.
 
+mod check;
 mod cli;
 mod env;
+mod paths;
+mod schema;
 mod toml;
 mod types;
+mod yaml;
 
+pub use check::{check_config, ConfigProblem, ConfigReport, ConfigSource};
 pub use cli::{parse_args, CliArgs, Command};
-pub use types::{Config, ConfigError, EngineType, DEFAULT_AGENT_TIMEOUT_SECS};
+pub use paths::{expand_path_placeholders, PathPlaceholders};
+pub use schema::json_schema;
+pub use types::{
+    AgentTaskOrder, Config, ConfigError, EngineType, Forge, NoAgentsBehavior,
+    DEFAULT_AGENT_TIMEOUT_SECS, DEFAULT_NO_AGENTS_WAIT_SECS, DEFAULT_SHUTDOWN_GRACE_SECS,
+};
 
 #[cfg(test)]
 mod tests;
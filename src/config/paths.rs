@@ -0,0 +1,48 @@
+//! Per-run placeholders in `files.chat` and `files.log_dir`.
+//!
+//! `{run}`, `{team}`, and `{timestamp}` are resolved once when a run starts;
+//! `{sprint}` is resolved again for every sprint. Paths without placeholders
+//! are returned unchanged.
+
+use super::types::Config;
+
+/// Values substituted into path placeholders; `None` leaves a placeholder as-is.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PathPlaceholders<'a> {
+    /// Run identifier shared by every sprint of a `swarm run` (`{run}`).
+    pub run: Option<&'a str>,
+    /// Project/team name (`{team}`).
+    pub team: Option<&'a str>,
+    /// Sprint number (`{sprint}`).
+    pub sprint: Option<usize>,
+    /// Run start time, e.g. `20260101-120000` (`{timestamp}`).
+    pub timestamp: Option<&'a str>,
+}
+
+/// Replace known placeholders in `path`; unknown `{...}` text is kept.
+pub fn expand_path_placeholders(path: &str, values: &PathPlaceholders) -> String {
+    let sprint = values.sprint.map(|n| n.to_string());
+    let replacements = [
+        ("{run}", values.run),
+        ("{team}", values.team),
+        ("{sprint}", sprint.as_deref()),
+        ("{timestamp}", values.timestamp),
+    ];
+    let mut expanded = path.to_string();
+    for (placeholder, value) in replacements {
+        if let Some(value) = value {
+            expanded = expanded.replace(placeholder, value);
+        }
+    }
+    expanded
+}
+
+impl Config {
+    /// Copy of this config with placeholders in the chat and log paths resolved.
+    pub fn with_path_placeholders(&self, values: &PathPlaceholders) -> Config {
+        let mut config = self.clone();
+        config.files_chat = expand_path_placeholders(&self.files_chat, values);
+        config.files_log_dir = expand_path_placeholders(&self.files_log_dir, values);
+        config
+    }
+}
@@ -0,0 +1,563 @@
+use super::types::Config;
+use crate::json::json_string;
+use crate::run_hash::{MAX_HASH_LEN, MIN_HASH_LEN};
+
+/// Engine names accepted in `engine.type` (besides `openrouter_<model>` and
+/// `ollama:<model>`).
+const ENGINE_NAMES: &[&str] = &["claude", "codex", "gemini", "stub", "command"];
+
+/// JSON type of a config setting.
+enum Kind {
+    /// Non-negative integer.
+    Integer,
+    /// Integer within an inclusive range.
+    IntegerRange(usize, usize),
+    /// Number within an inclusive range.
+    NumberRange(f64, f64),
+    Boolean,
+    String,
+    /// One of a fixed set of strings.
+    Enum(&'static [&'static str]),
+    StringArray,
+    /// Comma-separated engine list (`claude,codex`).
+    Engines,
+    /// `P<n>` tier → tasks-per-agent table.
+    TierLimits,
+}
+
+/// One swarm.toml setting and the `Config` field it sets.
+struct Field {
+    /// Dotted TOML key (`agents.max_count`).
+    key: &'static str,
+    /// `Config` field name.
+    field: &'static str,
+    kind: Kind,
+    description: &'static str,
+    /// Default as a JSON literal, or `None` when it has no fixed default.
+    default: fn(&Config) -> Option<String>,
+}
+
+const FIELDS: &[Field] = &[
+    Field {
+        key: "agents.max_count",
+        field: "agents_max_count",
+        kind: Kind::Integer,
+        description: "Maximum number of agents that may be spawned",
+        default: |c| Some(c.agents_max_count.to_string()),
+    },
+    Field {
+        key: "agents.tasks_per_agent",
+        field: "agents_tasks_per_agent",
+        kind: Kind::Integer,
+        description: "Tasks assigned per agent per sprint",
+        default: |c| Some(c.agents_tasks_per_agent.to_string()),
+    },
+    Field {
+        key: "agents.tasks_per_priority",
+        field: "agents_tasks_per_priority",
+        kind: Kind::TierLimits,
+        description: "tasks_per_agent overrides for (P<n>) tagged tasks",
+        default: |_| Some("{}".to_string()),
+    },
+    Field {
+        key: "agents.points_per_agent",
+        field: "agents_points_per_agent",
+        kind: Kind::Integer,
+        description: "Story-point budget per agent for (3pts)-estimated tasks (0 = count only)",
+        default: |c| Some(c.agents_points_per_agent.to_string()),
+    },
+    Field {
+        key: "agents.timeout",
+        field: "agent_timeout_secs",
+        kind: Kind::Integer,
+        description: "Agent execution timeout in seconds",
+        default: |c| Some(c.agent_timeout_secs.to_string()),
+    },
+    Field {
+        key: "agents.merge_timeout",
+        field: "merge_timeout_secs",
+        kind: Kind::Integer,
+        description: "Merge-agent timeout in seconds",
+        default: |c| Some(c.merge_timeout_secs.to_string()),
+    },
+    Field {
+        key: "agents.merge_retries",
+        field: "merge_retries",
+        kind: Kind::Integer,
+        description: "Merge-agent re-attempts when the sprint merge fails verification",
+        default: |c| Some(c.merge_retries.to_string()),
+    },
+    Field {
+        key: "agents.planning_timeout",
+        field: "planning_timeout_secs",
+        kind: Kind::Integer,
+        description: "Planning, review, and PRD conversion timeout in seconds",
+        default: |c| Some(c.planning_timeout_secs.to_string()),
+    },
+    Field {
+        key: "agents.shutdown_grace",
+        field: "shutdown_grace_secs",
+        kind: Kind::Integer,
+        description: "Seconds agents get to finish after Ctrl+C before being force-killed (0 = wait indefinitely)",
+        default: |c| Some(c.shutdown_grace_secs.to_string()),
+    },
+    Field {
+        key: "agents.heartbeat_interval",
+        field: "heartbeat_interval_secs",
+        kind: Kind::Integer,
+        description: "Seconds between \"still working\" chat messages while an agent runs a task (0 = disabled)",
+        default: |c| Some(c.heartbeat_interval_secs.to_string()),
+    },
+    Field {
+        key: "agents.task_order",
+        field: "agent_task_order",
+        kind: Kind::Enum(&["priority", "assignment"]),
+        description: "Order an agent works through its assigned tasks",
+        default: |c| Some(json_string(c.agent_task_order.as_str())),
+    },
+    Field {
+        key: "agents.on_no_agents",
+        field: "agents_on_no_agents",
+        kind: Kind::Enum(&["skip", "wait", "error"]),
+        description: "What a sprint does when no agents are available",
+        default: |c| Some(json_string(c.agents_on_no_agents.as_str())),
+    },
+    Field {
+        key: "agents.no_agents_wait_timeout",
+        field: "agents_no_agents_wait_secs",
+        kind: Kind::Integer,
+        description: "Seconds to wait for a free agent when on_no_agents = \"wait\"",
+        default: |c| Some(c.agents_no_agents_wait_secs.to_string()),
+    },
+    Field {
+        key: "agents.keep_branches",
+        field: "agents_keep_branches",
+        kind: Kind::Boolean,
+        description: "Keep agent branches after each sprint",
+        default: |c| Some(c.agents_keep_branches.to_string()),
+    },
+    Field {
+        key: "files.tasks",
+        field: "files_tasks",
+        kind: Kind::String,
+        description: "Path to the tasks file",
+        default: |c| Some(json_string(&c.files_tasks)),
+    },
+    Field {
+        key: "files.chat",
+        field: "files_chat",
+        kind: Kind::String,
+        description: "Path to the chat file; accepts {run}, {team}, {sprint}, {timestamp}",
+        default: |c| Some(json_string(&c.files_chat)),
+    },
+    Field {
+        key: "files.log_dir",
+        field: "files_log_dir",
+        kind: Kind::String,
+        description: "Log directory; accepts {run}, {team}, {sprint}, {timestamp}",
+        default: |c| Some(json_string(&c.files_log_dir)),
+    },
+    Field {
+        key: "files.chat_format",
+        field: "files_chat_format",
+        kind: Kind::Enum(&["markdown", "jsonl"]),
+        description: "Chat log format: markdown (time | agent | message) or jsonl",
+        default: |c| Some(json_string(c.files_chat_format.as_str())),
+    },
+    Field {
+        key: "files.chat_timezone",
+        field: "files_chat_timezone",
+        kind: Kind::String,
+        description: "Chat timestamp timezone: local, UTC, an offset (+05:30), or an IANA name",
+        default: |c| Some(json_string(&c.files_chat_timezone.to_string())),
+    },
+    Field {
+        key: "files.chat_time_format",
+        field: "files_chat_time_format",
+        kind: Kind::String,
+        description: "strftime format of chat timestamps (must not contain |)",
+        default: |c| Some(json_string(&c.files_chat_time_format)),
+    },
+    Field {
+        key: "files.log_format",
+        field: "files_log_format",
+        kind: Kind::Enum(&["text", "json"]),
+        description: "Agent log format: text (time | agent | message) or json",
+        default: |c| Some(json_string(c.files_log_format.as_str())),
+    },
+    Field {
+        key: "files.log_max_rotations",
+        field: "files_log_max_rotations",
+        kind: Kind::Integer,
+        description: "Gzip archives kept per rotated log (0 = discard rotated content)",
+        default: |c| Some(c.files_log_max_rotations.to_string()),
+    },
+    Field {
+        key: "files.log_output_max_bytes",
+        field: "files_log_output_max_bytes",
+        kind: Kind::Integer,
+        description: "Engine output kept per log entry (0 = no truncation)",
+        default: |_| None,
+    },
+    Field {
+        key: "files.tasks_completion_style",
+        field: "files_tasks_completion_style",
+        kind: Kind::Enum(&["suffix", "prefix"]),
+        description: "Completed task layout: suffix (- [x] task (A)) or prefix (- [x] (A) task)",
+        default: |c| Some(json_string(c.files_tasks_completion_style.as_str())),
+    },
+    Field {
+        key: "engine.type",
+        field: "engine_types",
+        kind: Kind::Engines,
+        description: "Engine(s), comma-separated for load balancing (e.g., claude,claude,codex)",
+        default: |c| Some(json_string(&c.engines_display())),
+    },
+    Field {
+        key: "engine.stub_mode",
+        field: "engine_stub_mode",
+        kind: Kind::Boolean,
+        description: "Use the stub engine for every task",
+        default: |c| Some(c.engine_stub_mode.to_string()),
+    },
+    Field {
+        key: "engine.max_rpm",
+        field: "engine_max_rpm",
+        kind: Kind::Integer,
+        description: "Engine calls per minute (0 = unlimited)",
+        default: |c| Some(c.engine_max_rpm.to_string()),
+    },
+    Field {
+        key: "engine.max_concurrency",
+        field: "engine_max_concurrency",
+        kind: Kind::Integer,
+        description: "Engine calls running at once (default: CPU count, 0 = unlimited)",
+        default: |_| None,
+    },
+    Field {
+        key: "engine.stub_fail_rate",
+        field: "engine_stub_fail_rate",
+        kind: Kind::NumberRange(0.0, 1.0),
+        description: "Fraction of stub tasks that fail (chaos testing)",
+        default: |c| Some(format!("{:?}", c.engine_stub_fail_rate)),
+    },
+    Field {
+        key: "engine.seed",
+        field: "seed",
+        kind: Kind::Integer,
+        description: "Seed for reproducible engine selection and stub failures",
+        default: |_| None,
+    },
+    Field {
+        key: "engine.retries",
+        field: "engine_retries",
+        kind: Kind::Integer,
+        description: "Retries for transient failures (rate limits, network errors)",
+        default: |c| Some(c.engine_retries.to_string()),
+    },
+    Field {
+        key: "engine.retry_delay",
+        field: "engine_retry_delay_secs",
+        kind: Kind::Integer,
+        description: "Seconds before the first retry, doubling each time",
+        default: |c| Some(c.engine_retry_delay_secs.to_string()),
+    },
+    Field {
+        key: "engine.ollama_url",
+        field: "engine_ollama_url",
+        kind: Kind::String,
+        description: "Ollama server for ollama:<model> engines",
+        default: |c| Some(json_string(&c.engine_ollama_url)),
+    },
+    Field {
+        key: "engine.command.program",
+        field: "engine_command_program",
+        kind: Kind::String,
+        description: "Program run by the command engine",
+        default: |_| None,
+    },
+    Field {
+        key: "engine.command.args",
+        field: "engine_command_args",
+        kind: Kind::StringArray,
+        description: "Command engine arguments; {task}, {agent}, and {dir} are substituted",
+        default: |_| Some("[]".to_string()),
+    },
+    Field {
+        key: "sprints.max",
+        field: "sprints_max",
+        kind: Kind::Integer,
+        description: "Maximum sprints to run (0 = unlimited)",
+        default: |c| Some(c.sprints_max.to_string()),
+    },
+    Field {
+        key: "sprints.track_interrupted",
+        field: "sprints_track_interrupted",
+        kind: Kind::Boolean,
+        description: "Mark tasks left assigned by an interrupted sprint with {interrupted}",
+        default: |c| Some(c.sprints_track_interrupted.to_string()),
+    },
+    Field {
+        key: "sprints.fetch_before_merge",
+        field: "sprints_fetch_before_merge",
+        kind: Kind::Boolean,
+        description: "Fetch and fast-forward the target branch before merging",
+        default: |c| Some(c.sprints_fetch_before_merge.to_string()),
+    },
+    Field {
+        key: "sprints.create_pr",
+        field: "sprints_create_pr",
+        kind: Kind::Boolean,
+        description: "Open a pull request after pushing the target branch",
+        default: |c| Some(c.sprints_create_pr.to_string()),
+    },
+    Field {
+        key: "sprints.forge",
+        field: "sprints_forge",
+        kind: Kind::Enum(&["github", "gitlab"]),
+        description: "Forge to open the pull request on (detected from the origin remote when unset)",
+        default: |_| None,
+    },
+    Field {
+        key: "sprints.max_retries",
+        field: "sprints_max_retries",
+        kind: Kind::Integer,
+        description: "Re-attempts of a sprint after git/merge infrastructure errors",
+        default: |c| Some(c.sprints_max_retries.to_string()),
+    },
+    Field {
+        key: "sprints.fast_path_merge",
+        field: "sprints_fast_path_merge",
+        kind: Kind::Boolean,
+        description: "Skip the merge agent when a single agent's sprint merges cleanly",
+        default: |c| Some(c.sprints_fast_path_merge.to_string()),
+    },
+    Field {
+        key: "sprints.ordered_merge",
+        field: "sprints_ordered_merge",
+        kind: Kind::Boolean,
+        description: "Merge agent branches in task dependency order within a sprint",
+        default: |c| Some(c.sprints_ordered_merge.to_string()),
+    },
+    Field {
+        key: "sprints.watch_tasks",
+        field: "sprints_watch_tasks",
+        kind: Kind::Boolean,
+        description: "Idle and re-check tasks instead of exiting when none are assignable",
+        default: |c| Some(c.sprints_watch_tasks.to_string()),
+    },
+    Field {
+        key: "sprints.watch_interval",
+        field: "sprints_watch_interval_secs",
+        kind: Kind::Integer,
+        description: "Seconds between task re-checks while watching",
+        default: |c| Some(c.sprints_watch_interval_secs.to_string()),
+    },
+    Field {
+        key: "sprints.run_hash_len",
+        field: "run_hash_len",
+        kind: Kind::IntegerRange(MIN_HASH_LEN, MAX_HASH_LEN),
+        description: "Branch hash suffix length; affects new runs only",
+        default: |c| Some(c.run_hash_len.to_string()),
+    },
+    Field {
+        key: "sprints.branch_prefix",
+        field: "branch_prefix",
+        kind: Kind::String,
+        description: "Prefix for sprint and agent branch names (e.g., swarm/)",
+        default: |c| Some(json_string(&c.branch_prefix)),
+    },
+    Field {
+        key: "worktree.sparse_paths",
+        field: "worktree_sparse_paths",
+        kind: Kind::StringArray,
+        description: "Directories agent worktrees check out via sparse-checkout",
+        default: |_| Some("[]".to_string()),
+    },
+    Field {
+        key: "worktree.preserved_max_age_days",
+        field: "worktree_preserved_max_age_days",
+        kind: Kind::Integer,
+        description: "Days after which `swarm prune` deletes preserved worktrees",
+        default: |c| Some(c.worktree_preserved_max_age_days.to_string()),
+    },
+    Field {
+        key: "audit.syslog",
+        field: "audit_syslog",
+        kind: Kind::Boolean,
+        description: "Mirror sprint, merge, push, and PR events to syslog (Unix)",
+        default: |c| Some(c.audit_syslog.to_string()),
+    },
+    Field {
+        key: "audit.syslog_facility",
+        field: "audit_syslog_facility",
+        kind: Kind::Enum(&[
+            "user", "daemon", "local0", "local1", "local2", "local3", "local4", "local5", "local6",
+            "local7",
+        ]),
+        description: "Syslog facility used when audit.syslog is enabled",
+        default: |c| Some(json_string(c.audit_syslog_facility.as_str())),
+    },
+    Field {
+        key: "webhook.url",
+        field: "webhook_url",
+        kind: Kind::String,
+        description: "http:// URL agent start, heartbeat, and completion events are POSTed to",
+        default: |_| None,
+    },
+    Field {
+        key: "webhook.sprint_url",
+        field: "sprint_webhook_url",
+        kind: Kind::String,
+        description: "http:// URL a JSON summary of each finished sprint is POSTed to",
+        default: |_| None,
+    },
+    Field {
+        key: "slack.webhook_url",
+        field: "slack_webhook_url",
+        kind: Kind::String,
+        description: "Slack incoming webhook a summary is posted to after each sprint",
+        default: |_| None,
+    },
+];
+
+/// Render a JSON Schema (draft 2020-12) for swarm.toml.
+///
+/// Sections are nested objects (`agents.max_count` is `agents` → `max_count`);
+/// each setting records its `Config` field in `x-config-field`. `[profile.<name>]`
+/// tables accept the same sections.
+pub fn json_schema() -> String {
+    let defaults = Config::default();
+    let sections = render_sections(&defaults, "", 2);
+    let profile_sections = render_sections(&defaults, "", 4);
+    format!(
+        "{{\n  \"$schema\": \"https://json-schema.org/draft/2020-12/schema\",\n  \"title\": \"swarm.toml\",\n  \"description\": \"swarm configuration (swarm.toml, swarm.yaml, or .swarm-hug/<team>/config.toml)\",\n  \"type\": \"object\",\n  \"additionalProperties\": false,\n  \"properties\": {{\n{sections},\n    \"profile\": {{\n      \"description\": \"Named overrides selected with --profile <name> or SWARM_PROFILE\",\n      \"type\": \"object\",\n      \"additionalProperties\": {{\n        \"type\": \"object\",\n        \"additionalProperties\": false,\n        \"properties\": {{\n{profile_sections}\n        }}\n      }}\n    }}\n  }}\n}}\n",
+        sections = sections,
+        profile_sections = profile_sections,
+    )
+}
+
+/// Render the object properties under `prefix` (e.g., `engine.`), indented by
+/// `depth` levels.
+fn render_sections(defaults: &Config, prefix: &str, depth: usize) -> String {
+    let pad = "  ".repeat(depth);
+    let mut names: Vec<&str> = Vec::new();
+    for field in FIELDS.iter().filter(|f| f.key.starts_with(prefix)) {
+        let name = field.key[prefix.len()..]
+            .split('.')
+            .next()
+            .unwrap_or_default();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut entries = Vec::new();
+    for name in names {
+        let key = format!("{}{}", prefix, name);
+        if let Some(field) = FIELDS.iter().find(|f| f.key == key) {
+            entries.push(render_field(field, defaults, &pad));
+        } else {
+            entries.push(format!(
+                "{pad}\"{name}\": {{\n{pad}  \"type\": \"object\",\n{pad}  \"additionalProperties\": false,\n{pad}  \"properties\": {{\n{inner}\n{pad}  }}\n{pad}}}",
+                pad = pad,
+                name = name,
+                inner = render_sections(defaults, &format!("{}.", key), depth + 2),
+            ));
+        }
+    }
+    entries.join(",\n")
+}
+
+fn render_field(field: &Field, defaults: &Config, pad: &str) -> String {
+    let name = field.key.rsplit('.').next().unwrap_or(field.key);
+    let mut props = vec![format!(
+        "\"description\": {}",
+        json_string(field.description)
+    )];
+    match &field.kind {
+        Kind::Integer => {
+            props.push("\"type\": \"integer\"".to_string());
+            props.push("\"minimum\": 0".to_string());
+        }
+        Kind::IntegerRange(min, max) => {
+            props.push("\"type\": \"integer\"".to_string());
+            props.push(format!("\"minimum\": {}", min));
+            props.push(format!("\"maximum\": {}", max));
+        }
+        Kind::NumberRange(min, max) => {
+            props.push("\"type\": \"number\"".to_string());
+            props.push(format!("\"minimum\": {:?}", min));
+            props.push(format!("\"maximum\": {:?}", max));
+        }
+        Kind::Boolean => props.push("\"type\": \"boolean\"".to_string()),
+        Kind::String => props.push("\"type\": \"string\"".to_string()),
+        Kind::Enum(values) => {
+            props.push("\"type\": \"string\"".to_string());
+            props.push(format!("\"enum\": {}", json_string_array(values)));
+        }
+        Kind::StringArray => {
+            props.push("\"type\": \"array\"".to_string());
+            props.push("\"items\": { \"type\": \"string\" }".to_string());
+        }
+        Kind::Engines => {
+            let engine = format!("({}|openrouter_.+|ollama:.+)", ENGINE_NAMES.join("|"));
+            props.push("\"type\": \"string\"".to_string());
+            props.push(format!(
+                "\"pattern\": {}",
+                json_string(&format!("^{engine}(,{engine})*$", engine = engine))
+            ));
+            let mut examples: Vec<&str> = ENGINE_NAMES.to_vec();
+            examples.extend(["openrouter_<model>", "ollama:<model>"]);
+            props.push(format!("\"x-engines\": {}", json_string_array(&examples)));
+        }
+        Kind::TierLimits => {
+            props.push("\"type\": \"object\"".to_string());
+            props.push(
+                "\"patternProperties\": { \"^[Pp][0-9]+$\": { \"type\": \"integer\", \"minimum\": 0 } }"
+                    .to_string(),
+            );
+            props.push("\"additionalProperties\": false".to_string());
+        }
+    }
+    if let Some(default) = (field.default)(defaults) {
+        props.push(format!("\"default\": {}", default));
+    }
+    props.push(format!("\"x-config-field\": {}", json_string(field.field)));
+
+    let inner_pad = format!("{}  ", pad);
+    format!(
+        "{pad}\"{name}\": {{\n{inner_pad}{props}\n{pad}}}",
+        pad = pad,
+        name = name,
+        inner_pad = inner_pad,
+        props = props.join(&format!(",\n{}", inner_pad)),
+    )
+}
+
+fn json_string_array(values: &[&str]) -> String {
+    let items: Vec<String> = values.iter().map(|v| json_string(v)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+#[cfg(test)]
+pub(super) fn schema_keys() -> impl Iterator<Item = &'static str> {
+    FIELDS.iter().map(|field| field.key)
+}
+
+#[cfg(test)]
+pub(super) fn schema_engine_names() -> &'static [&'static str] {
+    ENGINE_NAMES
+}
+
+#[cfg(test)]
+pub(super) fn schema_enum_values(key: &str) -> &'static [&'static str] {
+    match FIELDS
+        .iter()
+        .find(|field| field.key == key)
+        .map(|f| &f.kind)
+    {
+        Some(Kind::Enum(values)) => values,
+        _ => &[],
+    }
+}
@@ -1,6 +1,9 @@
-use super::types::detect_target_branch_in;
+use super::types::{default_max_concurrency, detect_target_branch_in};
 use super::*;
+use crate::audit::SyslogFacility;
+use crate::task::CompletionStyle;
 use crate::testutil::{EnvVarGuard, ENV_LOCK};
+use crate::verbosity::Verbosity;
 use std::fs;
 use std::path::Path;
 use std::process::Command as ProcessCommand;
@@ -81,6 +84,13 @@ fn test_engine_type_parse() {
     assert_eq!(EngineType::parse("claude"), Some(EngineType::Claude));
     assert_eq!(EngineType::parse("CLAUDE"), Some(EngineType::Claude));
     assert_eq!(EngineType::parse("codex"), Some(EngineType::Codex));
+    assert_eq!(EngineType::parse("gemini"), Some(EngineType::Gemini));
+    assert_eq!(
+        EngineType::parse("ollama:llama3:8b"),
+        Some(EngineType::Ollama {
+            model: "llama3:8b".to_string()
+        })
+    );
     assert_eq!(
         EngineType::parse("openrouter_moonshotai/kimi-k2.5"),
         Some(EngineType::OpenRouter {
@@ -100,6 +110,13 @@ fn test_engine_type_parse() {
         })
     );
     assert_eq!(EngineType::parse("stub"), Some(EngineType::Stub));
+    assert_eq!(
+        EngineType::parse("command"),
+        Some(EngineType::Command {
+            program: String::new(),
+            args: Vec::new()
+        })
+    );
     assert_eq!(EngineType::parse("unknown"), None);
 }
 
@@ -107,6 +124,7 @@ fn test_engine_type_parse() {
 fn test_engine_type_as_str() {
     assert_eq!(EngineType::Claude.as_str(), "claude");
     assert_eq!(EngineType::Codex.as_str(), "codex");
+    assert_eq!(EngineType::Gemini.as_str(), "gemini");
     assert_eq!(EngineType::Stub.as_str(), "stub");
     assert_eq!(
         EngineType::OpenRouter {
@@ -351,14 +369,44 @@ fn test_config_default() {
     let config = Config::default();
     assert_eq!(config.agents_max_count, 3);
     assert_eq!(config.agents_tasks_per_agent, 2);
+    assert_eq!(config.agents_points_per_agent, 8);
     assert_eq!(config.agent_timeout_secs, DEFAULT_AGENT_TIMEOUT_SECS);
+    assert_eq!(config.merge_timeout_secs, DEFAULT_AGENT_TIMEOUT_SECS);
+    assert_eq!(config.merge_retries, 1);
+    assert_eq!(config.planning_timeout_secs, DEFAULT_AGENT_TIMEOUT_SECS);
+    assert_eq!(config.agent_task_order, AgentTaskOrder::Priority);
+    assert!(!config.agents_keep_branches);
     assert_eq!(config.files_tasks, ".swarm-hug/default/tasks.md");
     assert_eq!(config.files_chat, ".swarm-hug/default/chat.md");
     assert_eq!(config.files_log_dir, ".swarm-hug/default/loop");
+    assert_eq!(config.files_log_output_max_bytes, None);
+    assert_eq!(config.files_tasks_completion_style, CompletionStyle::Suffix);
     assert_eq!(config.files_worktrees_dir, ".swarm-hug/default/worktrees");
     assert_eq!(config.engine_types, vec![EngineType::Claude]);
     assert!(!config.engine_stub_mode);
+    assert_eq!(config.engine_max_rpm, 0);
+    assert_eq!(config.engine_max_concurrency, default_max_concurrency());
+    assert!(config.engine_max_concurrency >= 1);
+    assert_eq!(config.engine_stub_fail_rate, 0.0);
+    assert_eq!(config.seed, None);
+    assert_eq!(config.engine_ollama_url, "http://localhost:11434");
+    assert_eq!(config.engine_retries, 2);
+    assert_eq!(config.engine_retry_delay_secs, 5);
     assert_eq!(config.sprints_max, 0);
+    assert!(!config.sprints_track_interrupted);
+    assert!(!config.sprints_fetch_before_merge);
+    assert!(config.sprints_create_pr);
+    assert_eq!(config.sprints_max_retries, 0);
+    assert!(!config.sprints_fast_path_merge);
+    assert!(!config.sprints_ordered_merge);
+    assert!(!config.sprints_watch_tasks);
+    assert_eq!(config.sprints_watch_interval_secs, 30);
+    assert_eq!(config.run_hash_len, 6);
+    assert_eq!(config.branch_prefix, "");
+    assert!(config.worktree_sparse_paths.is_empty());
+    assert_eq!(config.worktree_preserved_max_age_days, 7);
+    assert!(!config.audit_syslog);
+    assert_eq!(config.audit_syslog_facility, SyslogFacility::User);
     assert_eq!(config.source_branch, None);
     assert_eq!(config.target_branch, None);
     assert!(!config.target_branch_explicit);
@@ -370,28 +418,99 @@ fn test_config_parse_toml() {
 [agents]
 max_count = 8
 tasks_per_agent = 3
+points_per_agent = 13
+merge_timeout = 7200
+merge_retries = 3
+planning_timeout = 600
+task_order = "assignment"
+keep_branches = true
 
 [files]
 tasks = "MY_TASKS.md"
 chat = "MY_CHAT.md"
 log_dir = "logs"
+log_output_max_bytes = 0
+tasks_completion_style = "prefix"
 
 [engine]
 type = "codex"
 stub_mode = true
+max_rpm = 30
+max_concurrency = 2
+stub_fail_rate = 0.25
+seed = 7
+retries = 4
+retry_delay = 1
+ollama_url = "http://gpu-box:11434"
 
 [sprints]
 max = 5
+track_interrupted = true
+fetch_before_merge = true
+create_pr = false
+forge = "gitlab"
+max_retries = 2
+fast_path_merge = true
+ordered_merge = true
+watch_tasks = true
+watch_interval = 5
+branch_prefix = "swarm/"
+
+[worktree]
+sparse_paths = ["services/auth", "libs/common"]
+preserved_max_age_days = 3
+
+[audit]
+syslog = true
+syslog_facility = "local3"
 "#;
     let config = Config::parse_toml(toml).unwrap();
     assert_eq!(config.agents_max_count, 8);
     assert_eq!(config.agents_tasks_per_agent, 3);
+    assert_eq!(config.agents_points_per_agent, 13);
+    assert_eq!(config.merge_timeout_secs, 7200);
+    assert_eq!(config.merge_retries, 3);
+    assert_eq!(config.planning_timeout_secs, 600);
+    assert_eq!(config.agent_task_order, AgentTaskOrder::Assignment);
+    assert!(config.agents_keep_branches);
     assert_eq!(config.files_tasks, "MY_TASKS.md");
     assert_eq!(config.files_chat, "MY_CHAT.md");
     assert_eq!(config.files_log_dir, "logs");
+    assert_eq!(config.files_log_output_max_bytes, Some(0));
+    assert_eq!(config.files_tasks_completion_style, CompletionStyle::Prefix);
     assert_eq!(config.engine_types, vec![EngineType::Codex]);
     assert!(config.engine_stub_mode);
+    assert_eq!(config.engine_max_rpm, 30);
+    assert_eq!(config.engine_max_concurrency, 2);
+    assert_eq!(config.engine_stub_fail_rate, 0.25);
+    assert_eq!(config.seed, Some(7));
+    assert_eq!(config.engine_ollama_url, "http://gpu-box:11434");
+    assert_eq!(config.engine_retries, 4);
+    assert_eq!(config.engine_retry_delay_secs, 1);
     assert_eq!(config.sprints_max, 5);
+    assert!(config.sprints_track_interrupted);
+    assert!(config.sprints_fetch_before_merge);
+    assert!(!config.sprints_create_pr);
+    assert_eq!(config.sprints_forge, Some(Forge::GitLab));
+    assert_eq!(config.sprints_max_retries, 2);
+    assert!(config.sprints_fast_path_merge);
+    assert!(config.sprints_ordered_merge);
+    assert!(config.sprints_watch_tasks);
+    assert_eq!(config.sprints_watch_interval_secs, 5);
+    assert_eq!(config.branch_prefix, "swarm/");
+    assert_eq!(
+        config.worktree_sparse_paths,
+        vec!["services/auth", "libs/common"]
+    );
+    assert_eq!(config.worktree_preserved_max_age_days, 3);
+    assert!(config.audit_syslog);
+    assert_eq!(config.audit_syslog_facility, SyslogFacility::Local3);
+}
+
+#[test]
+fn test_config_parse_toml_accepts_legacy_stub_seed() {
+    let config = Config::parse_toml("[engine]\nstub_seed = 11\n").unwrap();
+    assert_eq!(config.seed, Some(11));
 }
 
 #[test]
@@ -463,6 +582,25 @@ fn test_parse_args_target_branch() {
     assert_eq!(cli.target_branch, Some("develop".to_string()));
 }
 
+#[test]
+fn test_parse_args_repeated_tasks_file() {
+    let args = vec![
+        "swarm".to_string(),
+        "--tasks-file".to_string(),
+        "features.md".to_string(),
+        "--tasks-file".to_string(),
+        "bugs.md".to_string(),
+        "run".to_string(),
+    ];
+    let cli = parse_args(args);
+    assert_eq!(cli.tasks_file, vec!["features.md", "bugs.md"]);
+
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.files_tasks, "features.md");
+    assert_eq!(config.files_tasks_extra, vec!["bugs.md".to_string()]);
+}
+
 #[test]
 fn test_parse_args_source_branch_missing_value_before_flag() {
     let args = vec![
@@ -589,6 +727,176 @@ fn test_config_apply_cli_ignores_flag_like_target_branch_value() {
     assert!(!config.target_branch_explicit);
 }
 
+#[test]
+fn test_parse_args_verbosity_flags() {
+    let args = ["swarm", "-v", "run", "--verbose"].map(String::from);
+    assert_eq!(parse_args(args).verbose, 2);
+
+    let args = ["swarm", "-vvv"].map(String::from);
+    assert_eq!(parse_args(args).verbose, 3);
+
+    let args = ["swarm", "--quiet"].map(String::from);
+    assert!(parse_args(args).quiet);
+}
+
+#[test]
+fn test_config_apply_cli_verbosity() {
+    let mut config = Config::default();
+    assert_eq!(config.verbosity, Verbosity::Normal);
+
+    config.apply_cli(&CliArgs {
+        verbose: 2,
+        ..Default::default()
+    });
+    assert_eq!(config.verbosity, Verbosity::Debug);
+
+    config.apply_cli(&CliArgs {
+        verbose: 1,
+        quiet: true,
+        ..Default::default()
+    });
+    assert_eq!(config.verbosity, Verbosity::Quiet);
+}
+
+#[test]
+fn test_parse_args_dump_prompts() {
+    let cli = parse_args(
+        ["swarm", "--dump-prompts", "debug/prompts", "run"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.dump_prompts.as_deref(), Some("debug/prompts"));
+    assert_eq!(cli.command, Some(Command::Run));
+
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.dump_prompts_dir.as_deref(), Some("debug/prompts"));
+}
+
+#[test]
+fn test_parse_args_event_socket() {
+    let cli = parse_args(
+        ["swarm", "run", "--event-socket", "/tmp/swarm.sock"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.event_socket.as_deref(), Some("/tmp/swarm.sock"));
+
+    let mut config = Config::default();
+    assert_eq!(config.event_socket, None);
+    config.apply_cli(&cli);
+    assert_eq!(config.event_socket.as_deref(), Some("/tmp/swarm.sock"));
+}
+
+#[test]
+fn test_parse_args_no_pr_disables_pr_creation() {
+    let cli = parse_args(["swarm", "run", "--no-pr"].iter().map(|s| s.to_string()));
+    assert!(cli.no_pr);
+
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert!(!config.sprints_create_pr);
+}
+
+#[test]
+fn test_parse_args_forge_overrides_detection() {
+    let mut config = Config::default();
+    assert_eq!(config.sprints_forge, None);
+
+    let cli = parse_args(
+        ["swarm", "run", "--forge", "gitlab"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.forge.as_deref(), Some("gitlab"));
+    config.apply_cli(&cli);
+    assert_eq!(config.sprints_forge, Some(Forge::GitLab));
+
+    assert!(Config::parse_toml("[sprints]\nforge = \"bitbucket\"\n").is_err());
+}
+
+#[test]
+fn test_parse_args_watch_tasks_enables_watching() {
+    let cli = parse_args(
+        ["swarm", "run", "--watch-tasks"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert!(cli.watch_tasks);
+
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert!(config.sprints_watch_tasks);
+}
+
+#[test]
+fn test_parse_args_ordered_merge() {
+    let cli = parse_args(
+        ["swarm", "run", "--ordered-merge"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert!(cli.ordered_merge);
+
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert!(config.sprints_ordered_merge);
+}
+
+#[test]
+fn test_engine_retries_flag() {
+    let cli = parse_args(
+        ["swarm", "--engine-retries", "0", "agents"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.engine_retries, Some(0));
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.engine_retries, 0);
+}
+
+#[test]
+fn test_merge_retries_flag() {
+    let cli = parse_args(
+        ["swarm", "run", "--merge-retries", "0"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.merge_retries, Some(0));
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.merge_retries, 0);
+}
+
+#[test]
+fn test_stub_fail_rate_flags_and_validation() {
+    let cli = parse_args(
+        [
+            "swarm",
+            "--stub",
+            "--fail-rate",
+            "0.3",
+            "--seed",
+            "9",
+            "agents",
+        ]
+        .iter()
+        .map(|s| s.to_string()),
+    );
+    let config = Config::load(&cli).unwrap();
+    assert_eq!(config.engine_stub_fail_rate, 0.3);
+    assert_eq!(config.seed, Some(9));
+
+    let cli = parse_args(
+        ["swarm", "--fail-rate", "1.5", "agents"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    let err = Config::load(&cli).unwrap_err().to_string();
+    assert!(err.contains("stub_fail_rate must be between 0.0 and 1.0"));
+}
+
 #[test]
 fn test_parse_args_help() {
     let args = vec!["swarm".to_string(), "--help".to_string()];
@@ -615,11 +923,13 @@ fn test_command_parse() {
     assert_eq!(Command::parse("run"), Some(Command::Run));
     assert_eq!(Command::parse("sprint"), None); // sprint command removed
     assert_eq!(Command::parse("plan"), None); // plan command removed
-    assert_eq!(Command::parse("status"), None); // status command removed
+    assert_eq!(Command::parse("status"), Some(Command::Status));
     assert_eq!(Command::parse("agents"), Some(Command::Agents));
-    assert_eq!(Command::parse("worktrees"), None); // worktrees command removed
+    assert_eq!(Command::parse("worktrees"), Some(Command::Worktrees));
     assert_eq!(Command::parse("worktrees-branch"), None); // worktrees-branch command removed
-    assert_eq!(Command::parse("cleanup"), None); // cleanup command removed
+    assert_eq!(Command::parse("cleanup"), Some(Command::Cleanup));
+    assert_eq!(Command::parse("prune"), Some(Command::Prune));
+    assert_eq!(Command::parse("watch"), Some(Command::Watch));
     assert_eq!(Command::parse("projects"), Some(Command::Projects));
     assert_eq!(Command::parse("project"), Some(Command::ProjectInit));
     assert_eq!(
@@ -631,9 +941,153 @@ fn test_command_parse() {
         Some(Command::CleanupWorktrees)
     );
     assert_eq!(Command::parse("set-email"), Some(Command::SetEmail));
+    assert_eq!(Command::parse("kill"), Some(Command::Kill));
+    assert_eq!(Command::parse("ps"), Some(Command::Ps));
     assert_eq!(Command::parse("unknown"), None);
 }
 
+#[test]
+fn test_parse_args_cleanup_all_teams() {
+    let args = vec![
+        "swarm".to_string(),
+        "cleanup".to_string(),
+        "--all-teams".to_string(),
+        "--dry-run".to_string(),
+        "--yes".to_string(),
+    ];
+    let cli = parse_args(args);
+    assert_eq!(cli.command, Some(Command::Cleanup));
+    assert!(cli.all_teams);
+    assert!(cli.dry_run);
+    assert!(cli.yes);
+}
+
+#[test]
+fn test_parse_args_chat_agent_and_last() {
+    let cli = parse_args(
+        ["swarm", "chat", "--agent", "Aaron", "--last", "5"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::Chat));
+    assert_eq!(cli.agent.as_deref(), Some("Aaron"));
+    assert_eq!(cli.last, Some(5));
+
+    let cli = parse_args(["swarm", "chat"].iter().map(|s| s.to_string()));
+    assert_eq!(cli.command, Some(Command::Chat));
+    assert_eq!(cli.agent, None);
+    assert_eq!(cli.last, None);
+}
+
+#[test]
+fn test_parse_args_prune_older_than() {
+    let cli = parse_args(
+        ["swarm", "prune", "--older-than", "30"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::Prune));
+    assert_eq!(cli.older_than, Some(30));
+    assert!(!cli.size);
+
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.worktree_preserved_max_age_days, 30);
+}
+
+#[test]
+fn test_parse_args_team_archive() {
+    let cli = parse_args(
+        ["swarm", "team", "archive", "payments", "--delete", "-y"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::TeamArchive));
+    assert_eq!(cli.project_arg.as_deref(), Some("payments"));
+    assert!(cli.delete);
+    assert!(cli.yes);
+
+    let cli = parse_args(["swarm", "team", "merge"].iter().map(|s| s.to_string()));
+    assert_eq!(cli.unknown_command.as_deref(), Some("team merge"));
+    let cli = parse_args(["swarm", "team", "archive"].iter().map(|s| s.to_string()));
+    assert_eq!(cli.command, Some(Command::TeamArchive));
+    assert_eq!(cli.project_arg, None);
+}
+
+#[test]
+fn test_parse_args_team_rename() {
+    let cli = parse_args(
+        ["swarm", "team", "rename", "payments", "billing"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::TeamRename));
+    assert_eq!(cli.project_arg.as_deref(), Some("payments"));
+    assert_eq!(cli.new_name_arg.as_deref(), Some("billing"));
+
+    let cli = parse_args(
+        ["swarm", "team", "rename", "payments"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::TeamRename));
+    assert_eq!(cli.new_name_arg, None);
+}
+
+#[test]
+fn test_parse_args_logs() {
+    let cli = parse_args(
+        ["swarm", "logs", "Aaron", "--follow", "--lines", "50"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::Logs));
+    assert_eq!(cli.agent.as_deref(), Some("Aaron"));
+    assert!(cli.follow);
+    assert_eq!(cli.last, Some(50));
+
+    let cli = parse_args(["swarm", "logs", "-f"].iter().map(|s| s.to_string()));
+    assert_eq!(cli.agent, None);
+    assert!(cli.follow);
+}
+
+#[test]
+fn test_resume_command_sets_resume() {
+    let cli = parse_args(
+        [
+            "swarm",
+            "resume",
+            "--source-branch",
+            "main",
+            "--target-branch",
+            "feature-1",
+        ]
+        .iter()
+        .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::Resume));
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert!(config.resume);
+
+    let cli = parse_args(["swarm", "run"].iter().map(|s| s.to_string()));
+    config.apply_cli(&cli);
+    assert!(!config.resume);
+}
+
+#[test]
+fn test_parse_args_diff_sprint() {
+    let cli = parse_args(
+        ["swarm", "diff", "--sprint", "3"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::Diff));
+    assert_eq!(cli.sprint, Some(3));
+    let cli = parse_args(["swarm", "diff"].iter().map(|s| s.to_string()));
+    assert_eq!(cli.sprint, None);
+}
+
 #[test]
 fn test_parse_args_unknown_command() {
     let args = vec!["swarm".to_string(), "sprint".to_string()];
@@ -733,6 +1187,41 @@ fn test_project_path_resolution() {
     );
 }
 
+#[test]
+fn test_config_parse_toml_tasks_per_priority() {
+    let toml = r#"
+[agents]
+tasks_per_agent = 2
+
+[agents.tasks_per_priority]
+P0 = 1
+p3 = 3
+
+[files]
+tasks = "T.md"
+"#;
+    let config = Config::parse_toml(toml).unwrap();
+    assert_eq!(config.agents_tasks_per_agent, 2);
+    assert_eq!(
+        config.agents_tasks_per_priority,
+        std::collections::BTreeMap::from([(0, 1), (3, 3)])
+    );
+    assert_eq!(config.files_tasks, "T.md");
+
+    let bad = "[agents.tasks_per_priority]\nhigh = 1\n";
+    assert!(Config::parse_toml(bad).is_err());
+}
+
+#[test]
+fn test_parse_tier_limits() {
+    assert_eq!(
+        super::types::parse_tier_limits("P0=1, P3=3"),
+        Some(std::collections::BTreeMap::from([(0, 1), (3, 3)]))
+    );
+    assert_eq!(super::types::parse_tier_limits("P0"), None);
+    assert_eq!(super::types::parse_tier_limits("X1=2"), None);
+}
+
 #[test]
 fn test_default_toml() {
     let toml = Config::default_toml();
@@ -758,6 +1247,1103 @@ fn test_config_load_with_cli_precedence() {
     assert_eq!(config.effective_engine(), EngineType::Stub);
 }
 
+/// TOML used by the YAML equivalence tests; `EQUIVALENT_YAML` spells the same settings.
+const EQUIVALENT_TOML: &str = r#"
+[agents]
+max_count = 8
+tasks_per_agent = 3
+merge_timeout = 7200
+task_order = "assignment"
+keep_branches = true
+
+[agents.tasks_per_priority]
+P0 = 1
+
+[files]
+tasks = "MY_TASKS.md"
+tasks_completion_style = "prefix"
+
+[engine]
+type = "codex,claude"
+stub_fail_rate = 0.25
+seed = 7
+ollama_url = "http://gpu-box:11434"
+
+[engine.command]
+program = "./scripts/agent.sh"
+args = ["--task", "{task}", "--note", "say \"hi\", ok"]
+
+[sprints]
+max = 5
+ordered_merge = true
+branch_prefix = "swarm/"
+
+[worktree]
+sparse_paths = ["services/auth", "libs/common"]
+
+[audit]
+syslog_facility = "local3"
+"#;
+
+const EQUIVALENT_YAML: &str = r#"
+# Same settings as EQUIVALENT_TOML
+agents:
+  max_count: 8
+  tasks_per_agent: 3
+  merge_timeout: 7200
+  task_order: assignment
+  keep_branches: true
+  tasks_per_priority:
+    P0: 1
+
+files:
+  tasks: "MY_TASKS.md"
+  tasks_completion_style: 'prefix'  # quoted either way
+
+engine:
+  type: codex,claude
+  stub_fail_rate: 0.25
+  seed: 7
+  ollama_url: http://gpu-box:11434
+  command:
+    program: ./scripts/agent.sh
+    args: ["--task", "{task}", "--note", 'say "hi", ok']
+
+sprints:
+  max: 5
+  ordered_merge: true
+  branch_prefix: swarm/
+
+worktree:
+  sparse_paths:
+    - services/auth
+    - 'libs/common'
+
+audit:
+  syslog_facility: local3
+"#;
+
+#[test]
+fn test_config_parse_yaml_matches_toml() {
+    let from_toml = Config::parse_toml(EQUIVALENT_TOML).unwrap();
+    let from_yaml = Config::parse_yaml(EQUIVALENT_YAML).unwrap();
+    assert_eq!(from_yaml, from_toml);
+    assert_ne!(from_yaml, Config::default());
+    assert_eq!(
+        from_yaml.engine_command_args,
+        vec!["--task", "{task}", "--note", "say \"hi\", ok"]
+    );
+    assert_eq!(
+        from_yaml.worktree_sparse_paths,
+        vec!["services/auth", "libs/common"]
+    );
+}
+
+#[test]
+fn test_config_parse_yaml_errors_name_the_format() {
+    let err = Config::parse_yaml(
+        "agents:
+  max_count: lots
+",
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "config parse error: invalid agents.max_count: lots (in YAML config)"
+    );
+    let err = Config::parse_toml(
+        "[agents]
+max_count = lots
+",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("(in TOML config)"), "{}", err);
+
+    let err = Config::parse_yaml(
+        "agents:
+	max_count: 2
+",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("YAML line 2"), "{}", err);
+    let err = Config::parse_yaml(
+        "- orphan
+",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("YAML line 1"), "{}", err);
+}
+
+#[test]
+fn test_config_load_yaml_precedence_matches_toml() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset = EnvVarGuard::unset("SWARM_AGENTS_MAX_COUNT");
+    let temp = TempDir::new().expect("temp dir");
+    let toml_path = temp.path().join("swarm.toml");
+    let yaml_path = temp.path().join("swarm.yml");
+    fs::write(&toml_path, EQUIVALENT_TOML).expect("write swarm.toml");
+    fs::write(&yaml_path, EQUIVALENT_YAML).expect("write swarm.yml");
+
+    let load = |path: &Path, max_agents: Option<usize>| {
+        let cli = CliArgs {
+            config: Some(path.to_string_lossy().to_string()),
+            max_agents,
+            command: Some(Command::Init),
+            ..Default::default()
+        };
+        Config::load(&cli).expect("config load")
+    };
+
+    // File overrides defaults
+    assert_eq!(load(&yaml_path, None), load(&toml_path, None));
+    assert_eq!(load(&yaml_path, None).agents_max_count, 8);
+
+    // Env overrides the file
+    let _env = EnvVarGuard::set("SWARM_AGENTS_MAX_COUNT", "5");
+    assert_eq!(load(&yaml_path, None), load(&toml_path, None));
+    assert_eq!(load(&yaml_path, None).agents_max_count, 5);
+
+    // CLI overrides env
+    assert_eq!(load(&yaml_path, Some(2)), load(&toml_path, Some(2)));
+    assert_eq!(load(&yaml_path, Some(2)).agents_max_count, 2);
+}
+
+#[test]
+fn test_config_load_detects_swarm_yaml() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    crate::testutil::with_temp_cwd(|| {
+        let cli = CliArgs {
+            command: Some(Command::Init),
+            ..Default::default()
+        };
+        fs::write(
+            "swarm.yaml",
+            "sprints:
+  max: 9
+",
+        )
+        .expect("write swarm.yaml");
+        assert_eq!(Config::load(&cli).unwrap().sprints_max, 9);
+
+        // swarm.toml wins when both exist
+        fs::write(
+            "swarm.toml",
+            "[sprints]
+max = 4
+",
+        )
+        .expect("write swarm.toml");
+        assert_eq!(Config::load(&cli).unwrap().sprints_max, 4);
+    });
+}
+
+const PROFILE_TOML: &str = r#"
+[agents]
+max_count = 3
+timeout = 3600
+
+[engine]
+type = "claude"
+
+[profile.ci]
+engine.type = "codex"
+agents.max_count = 8
+agents.timeout = 1800
+
+[profile.local.agents]
+max_count = 2
+"#;
+
+#[test]
+fn test_config_parse_toml_profiles() {
+    let config = Config::parse_toml(PROFILE_TOML).unwrap();
+    // Profiles are recorded, not applied
+    assert_eq!(config.agents_max_count, 3);
+    assert_eq!(config.engine_types, vec![EngineType::Claude]);
+    assert_eq!(
+        config.profiles["ci"],
+        vec![
+            ("engine.type".to_string(), "\"codex\"".to_string()),
+            ("agents.max_count".to_string(), "8".to_string()),
+            ("agents.timeout".to_string(), "1800".to_string()),
+        ]
+    );
+    assert_eq!(
+        config.profiles["local"],
+        vec![("agents.max_count".to_string(), "2".to_string())]
+    );
+
+    let err = Config::parse_toml("[profile.ci]\nagents.max_count = lots\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "config parse error: profile 'ci': invalid agents.max_count: lots (in TOML config)"
+    );
+}
+
+#[test]
+fn test_config_load_profile_precedence() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset_profile = EnvVarGuard::unset("SWARM_PROFILE");
+    let _unset_agents = EnvVarGuard::unset("SWARM_AGENTS_MAX_COUNT");
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().join("swarm.toml");
+    fs::write(&path, PROFILE_TOML).expect("write swarm.toml");
+
+    let load = |profile: Option<&str>, max_agents: Option<usize>| {
+        let cli = CliArgs {
+            config: Some(path.to_string_lossy().to_string()),
+            profile: profile.map(str::to_string),
+            max_agents,
+            command: Some(Command::Init),
+            ..Default::default()
+        };
+        Config::load(&cli)
+    };
+
+    let base = load(None, None).unwrap();
+    assert_eq!(base.profile, None);
+    assert_eq!(base.agents_max_count, 3);
+
+    // Profile overrides base values and leaves the rest alone
+    let ci = load(Some("ci"), None).unwrap();
+    assert_eq!(ci.profile.as_deref(), Some("ci"));
+    assert_eq!(ci.agents_max_count, 8);
+    assert_eq!(ci.agent_timeout_secs, 1800);
+    assert_eq!(ci.engine_types, vec![EngineType::Codex]);
+    assert_eq!(ci.agents_tasks_per_agent, base.agents_tasks_per_agent);
+
+    // Explicit CLI flags still win over the profile
+    let ci = load(Some("ci"), Some(5)).unwrap();
+    assert_eq!(ci.agents_max_count, 5);
+    assert_eq!(ci.engine_types, vec![EngineType::Codex]);
+
+    // SWARM_PROFILE selects a profile; --profile takes precedence over it
+    let _env = EnvVarGuard::set("SWARM_PROFILE", "local");
+    assert_eq!(load(None, None).unwrap().agents_max_count, 2);
+    assert_eq!(load(Some("ci"), None).unwrap().agents_max_count, 8);
+}
+
+#[test]
+fn test_config_load_unknown_profile_errors() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset = EnvVarGuard::unset("SWARM_PROFILE");
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().join("swarm.toml");
+    fs::write(&path, PROFILE_TOML).expect("write swarm.toml");
+
+    let cli = CliArgs {
+        config: Some(path.to_string_lossy().to_string()),
+        profile: Some("staging".to_string()),
+        command: Some(Command::Init),
+        ..Default::default()
+    };
+    let err = Config::load(&cli).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "config validation error: unknown profile 'staging' (available: ci, local)"
+    );
+}
+
+#[test]
+fn test_chat_format_config_sources() {
+    use crate::chat::ChatFormat;
+
+    assert_eq!(Config::default().files_chat_format, ChatFormat::Markdown);
+    let config = Config::parse_toml("[files]\nchat_format = \"jsonl\"\n").unwrap();
+    assert_eq!(config.files_chat_format, ChatFormat::Jsonl);
+    assert!(Config::parse_toml("[files]\nchat_format = \"xml\"\n").is_err());
+
+    let cli = parse_args(
+        ["swarm", "run", "--chat-format", "jsonl"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.chat_format.as_deref(), Some("jsonl"));
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.files_chat_format, ChatFormat::Jsonl);
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_FILES_CHAT_FORMAT", "jsonl");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.files_chat_format, ChatFormat::Jsonl);
+}
+
+#[test]
+fn test_log_format_config_sources() {
+    use crate::log::LogFormat;
+
+    assert_eq!(Config::default().files_log_format, LogFormat::Text);
+    let config = Config::parse_toml("[files]\nlog_format = \"json\"\n").unwrap();
+    assert_eq!(config.files_log_format, LogFormat::Json);
+    assert!(Config::parse_toml("[files]\nlog_format = \"xml\"\n").is_err());
+
+    let cli = parse_args(
+        ["swarm", "run", "--log-format", "json"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.log_format.as_deref(), Some("json"));
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.files_log_format, LogFormat::Json);
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_FILES_LOG_FORMAT", "json");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.files_log_format, LogFormat::Json);
+}
+
+#[test]
+fn test_shutdown_grace_config() {
+    assert_eq!(
+        Config::default().shutdown_grace_secs,
+        DEFAULT_SHUTDOWN_GRACE_SECS
+    );
+    let config = Config::parse_toml("[agents]\nshutdown_grace = 5\n").unwrap();
+    assert_eq!(config.shutdown_grace_secs, 5);
+    assert!(Config::parse_toml("[agents]\nshutdown_grace = -1\n").is_err());
+
+    let cli = parse_args(vec!["swarm".into(), "--shutdown-grace".into(), "0".into()]);
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.shutdown_grace_secs, 0);
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_SHUTDOWN_GRACE", "90");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.shutdown_grace_secs, 90);
+}
+
+#[test]
+fn test_heartbeat_interval_config() {
+    assert_eq!(Config::default().heartbeat_interval_secs, 300);
+    let config = Config::parse_toml("[agents]\nheartbeat_interval = 0\n").unwrap();
+    assert_eq!(config.heartbeat_interval_secs, 0);
+    assert!(Config::parse_toml("[agents]\nheartbeat_interval = \"soon\"\n").is_err());
+
+    let cli = parse_args(vec![
+        "swarm".into(),
+        "--heartbeat-interval".into(),
+        "30".into(),
+    ]);
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.heartbeat_interval_secs, 30);
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_HEARTBEAT_INTERVAL", "120");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.heartbeat_interval_secs, 120);
+}
+
+#[test]
+fn test_webhook_url_config() {
+    assert_eq!(Config::default().webhook_url, None);
+    let config = Config::parse_toml("[webhook]\nurl = \"http://localhost:9000/hook\"\n").unwrap();
+    assert_eq!(
+        config.webhook_url.as_deref(),
+        Some("http://localhost:9000/hook")
+    );
+
+    let cli = parse_args(vec![
+        "swarm".into(),
+        "--webhook-url".into(),
+        "http://ops/swarm".into(),
+    ]);
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.webhook_url.as_deref(), Some("http://ops/swarm"));
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_WEBHOOK_URL", "http://env/hook");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.webhook_url.as_deref(), Some("http://env/hook"));
+
+    let config =
+        Config::parse_toml("[webhook]\nsprint_url = \"http://localhost:9000/sprints\"\n").unwrap();
+    assert_eq!(config.webhook_url, None);
+    assert_eq!(
+        config.sprint_webhook_url.as_deref(),
+        Some("http://localhost:9000/sprints")
+    );
+}
+
+#[test]
+fn test_slack_webhook_url_config() {
+    assert_eq!(Config::default().slack_webhook_url, None);
+    let config =
+        Config::parse_toml("[slack]\nwebhook_url = \"https://hooks.slack.com/services/T/B/X\"\n")
+            .unwrap();
+    assert_eq!(
+        config.slack_webhook_url.as_deref(),
+        Some("https://hooks.slack.com/services/T/B/X")
+    );
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_SLACK_WEBHOOK_URL", "http://env/slack");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(
+        config.slack_webhook_url.as_deref(),
+        Some("http://env/slack")
+    );
+}
+
+#[test]
+fn test_run_id_config() {
+    let cli = parse_args(vec!["swarm".into(), "--run-id".into(), "nightly-42".into()]);
+    assert_eq!(cli.parse_error, None);
+    let mut config = Config::default();
+    config.apply_cli(&cli);
+    assert_eq!(config.run_id.as_deref(), Some("nightly-42"));
+
+    let cli = parse_args(vec!["swarm".into(), "--run-id".into(), "///".into()]);
+    assert!(cli
+        .parse_error
+        .as_deref()
+        .unwrap()
+        .starts_with("--run-id '///' has no letters or digits"));
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_RUN_ID", "ci-7");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.run_id.as_deref(), Some("ci-7"));
+}
+
+#[test]
+fn test_log_max_rotations_config() {
+    assert_eq!(
+        Config::default().files_log_max_rotations,
+        crate::log::DEFAULT_MAX_ROTATIONS
+    );
+    let config = Config::parse_toml("[files]\nlog_max_rotations = 0\n").unwrap();
+    assert_eq!(config.files_log_max_rotations, 0);
+    assert!(Config::parse_toml("[files]\nlog_max_rotations = \"many\"\n").is_err());
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvVarGuard::set("SWARM_FILES_LOG_MAX_ROTATIONS", "9");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.files_log_max_rotations, 9);
+}
+
+#[test]
+fn test_chat_timestamp_config() {
+    use crate::chat::{ChatTimezone, DEFAULT_TIME_FORMAT};
+
+    let config = Config::default();
+    assert_eq!(config.files_chat_timezone, ChatTimezone::Local);
+    assert_eq!(config.files_chat_time_format, DEFAULT_TIME_FORMAT);
+
+    let config = Config::parse_toml(
+        "[files]\nchat_timezone = \"America/New_York\"\nchat_time_format = \"%H:%M %Z\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        config.files_chat_timezone,
+        ChatTimezone::parse("America/New_York").unwrap()
+    );
+    assert_eq!(config.files_chat_time_format, "%H:%M %Z");
+
+    let err = Config::parse_toml("[files]\nchat_timezone = \"Mars/Base\"\n").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid files.chat_timezone: Mars/Base"));
+    let err = Config::parse_toml("[files]\nchat_time_format = \"%H | %M\"\n").unwrap_err();
+    assert!(err.to_string().contains("invalid files.chat_time_format"));
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _tz = EnvVarGuard::set("SWARM_FILES_CHAT_TIMEZONE", "UTC");
+    let _format = EnvVarGuard::set("SWARM_FILES_CHAT_TIME_FORMAT", "%H:%M");
+    let mut config = Config::default();
+    super::env::apply_env(&mut config);
+    assert_eq!(config.files_chat_timezone, ChatTimezone::Utc);
+    assert_eq!(config.files_chat_time_format, "%H:%M");
+}
+
+#[test]
+fn test_profile_flag() {
+    let cli = parse_args(
+        ["swarm", "run", "--profile", "ci"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.profile.as_deref(), Some("ci"));
+}
+
+#[test]
+fn test_config_load_team_config_overrides() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset_tasks = EnvVarGuard::unset("SWARM_AGENTS_TASKS_PER_AGENT");
+    let _unset_engine = EnvVarGuard::unset("SWARM_ENGINE_TYPE");
+    let _unset_profile = EnvVarGuard::unset("SWARM_PROFILE");
+    crate::testutil::with_temp_cwd(|| {
+        fs::write(
+            "swarm.toml",
+            "[agents]\nmax_count = 6\ntasks_per_agent = 2\n\n[engine]\ntype = \"claude\"\n",
+        )
+        .expect("write swarm.toml");
+        fs::create_dir_all(".swarm-hug/payments").expect("create team dir");
+        fs::write(
+            ".swarm-hug/payments/config.toml",
+            "[agents]\ntasks_per_agent = 4\n\n[engine]\ntype = \"codex\"\n",
+        )
+        .expect("write team config");
+
+        let load = |team: &str, tasks_per_agent: Option<usize>| {
+            let cli = CliArgs {
+                project: Some(team.to_string()),
+                tasks_per_agent,
+                command: Some(Command::Init),
+                ..Default::default()
+            };
+            Config::load(&cli).expect("config load")
+        };
+
+        // The team file overrides only the keys it sets
+        let payments = load("payments", None);
+        assert_eq!(payments.agents_tasks_per_agent, 4);
+        assert_eq!(payments.engine_types, vec![EngineType::Codex]);
+        assert_eq!(payments.agents_max_count, 6);
+
+        // Other teams keep the root config
+        let auth = load("auth", None);
+        assert_eq!(auth.agents_tasks_per_agent, 2);
+        assert_eq!(auth.engine_types, vec![EngineType::Claude]);
+
+        // CLI flags still win
+        assert_eq!(load("payments", Some(1)).agents_tasks_per_agent, 1);
+
+        fs::write(
+            ".swarm-hug/payments/config.toml",
+            "[agents]\ntasks_per_agent = many\n",
+        )
+        .expect("write team config");
+        let cli = CliArgs {
+            project: Some("payments".to_string()),
+            command: Some(Command::Init),
+            ..Default::default()
+        };
+        let err = Config::load(&cli).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(".swarm-hug/payments/config.toml: invalid agents.tasks_per_agent"),
+            "{}",
+            err
+        );
+    });
+}
+
+#[test]
+fn test_config_parse_toml_inline_comments() {
+    let config = Config::parse_toml(
+        "[agents]\nmax_count = 7  # agents\n\n[files]\ntasks = \"a#b.md\" # quoted # kept\n",
+    )
+    .unwrap();
+    assert_eq!(config.agents_max_count, 7);
+    assert_eq!(config.files_tasks, "a#b.md");
+
+    // The file `swarm init` writes loads back as the defaults
+    assert_eq!(
+        Config::parse_toml(&Config::default_toml()).unwrap(),
+        Config::default()
+    );
+}
+
+#[test]
+fn test_check_config_reports_every_problem_with_source() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset_profile = EnvVarGuard::unset("SWARM_PROFILE");
+    let _unset_tasks = EnvVarGuard::unset("SWARM_AGENTS_TASKS_PER_AGENT");
+    let _unset_chat = EnvVarGuard::unset("SWARM_FILES_CHAT");
+    let _unset_log = EnvVarGuard::unset("SWARM_FILES_LOG_DIR");
+    let _unset_hash = EnvVarGuard::unset("SWARM_RUN_HASH_LEN");
+    let _engine = EnvVarGuard::set("SWARM_ENGINE_TYPE", "gpt");
+    crate::testutil::with_temp_cwd(|| {
+        fs::write(
+            "swarm.toml",
+            r#"
+[agents]
+max_count = -1
+tasks_per_agent = 0
+
+[engine]
+type = "claude,bogus"
+
+[files]
+log_dir = ""
+
+[sprints]
+run_hash_len = 2
+"#,
+        )
+        .expect("write swarm.toml");
+        fs::create_dir_all(".swarm-hug").expect("create .swarm-hug");
+        fs::write(".swarm-hug/email.txt", "not-an-email\n").expect("write email.txt");
+
+        let cli = CliArgs {
+            chat_file: Some(String::new()),
+            command: Some(Command::ConfigValidate),
+            ..Default::default()
+        };
+        let report = check_config(&cli);
+        let file = || ConfigSource::File("swarm.toml".to_string());
+        let problems: Vec<(&str, ConfigSource, &str)> = report
+            .problems
+            .iter()
+            .map(|p| (p.setting.as_str(), p.source.clone(), p.message.as_str()))
+            .collect();
+
+        assert_eq!(report.files, vec!["swarm.toml"]);
+        assert_eq!(
+            problems,
+            vec![
+                ("agents.max_count", file(), "invalid agents.max_count: -1"),
+                ("engine.type", file(), "invalid engine.type: claude,bogus"),
+                (
+                    "engine.type",
+                    ConfigSource::Env("SWARM_ENGINE_TYPE"),
+                    "invalid engine.type: gpt"
+                ),
+                (
+                    "agents.tasks_per_agent",
+                    file(),
+                    "agents.tasks_per_agent must be greater than 0"
+                ),
+                (
+                    "files.chat",
+                    ConfigSource::Cli("--chat-file"),
+                    "files.chat must not be empty"
+                ),
+                ("files.log_dir", file(), "files.log_dir must not be empty"),
+                (
+                    "sprints.run_hash_len",
+                    file(),
+                    "run_hash_len must be between 4 and 20 (got 2)"
+                ),
+                (
+                    "email",
+                    ConfigSource::File(".swarm-hug/email.txt".to_string()),
+                    "invalid co-author email 'not-an-email'"
+                ),
+            ]
+        );
+        assert!(!report.is_ok());
+    });
+}
+
+#[test]
+fn test_check_config_passes_valid_config() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset_engine = EnvVarGuard::unset("SWARM_ENGINE_TYPE");
+    let _unset_profile = EnvVarGuard::unset("SWARM_PROFILE");
+    crate::testutil::with_temp_cwd(|| {
+        let cli = CliArgs {
+            command: Some(Command::ConfigValidate),
+            ..Default::default()
+        };
+        let report = check_config(&cli);
+        assert!(report.files.is_empty());
+        assert!(report.is_ok(), "{:?}", report.problems);
+
+        fs::write("swarm.toml", Config::default_toml()).expect("write swarm.toml");
+        let cli = CliArgs {
+            profile: Some("ci".to_string()),
+            engine: Some("codex".to_string()),
+            command: Some(Command::ConfigValidate),
+            ..Default::default()
+        };
+        let report = check_config(&cli);
+        assert_eq!(report.files, vec!["swarm.toml"]);
+        assert_eq!(
+            report.problems,
+            vec![ConfigProblem {
+                setting: "profile".to_string(),
+                source: ConfigSource::Cli("--profile"),
+                message: "unknown profile 'ci'".to_string(),
+            }]
+        );
+    });
+}
+
+#[test]
+fn test_config_validate_command_parsing() {
+    let parse = |args: &[&str]| parse_args(args.iter().map(|s| s.to_string()));
+    let cli = parse(&["swarm", "config", "validate", "--max-agents", "2"]);
+    assert_eq!(cli.command, Some(Command::ConfigValidate));
+    assert_eq!(cli.max_agents, Some(2));
+    assert_eq!(cli.unknown_command, None);
+    assert_eq!(
+        parse(&["swarm", "config", "schema"]).command,
+        Some(Command::ConfigSchema)
+    );
+
+    assert_eq!(
+        parse(&["swarm", "config", "show"])
+            .unknown_command
+            .as_deref(),
+        Some("config show")
+    );
+    assert_eq!(
+        parse(&["swarm", "config"]).unknown_command.as_deref(),
+        Some("config")
+    );
+}
+
+/// Minimal JSON syntax check: returns the rest of `input` after one value.
+fn skip_json_value(input: &str) -> Result<&str, String> {
+    let input = input.trim_start();
+    let rest = match input.chars().next() {
+        Some('{') => {
+            let mut rest = input[1..].trim_start();
+            if let Some(after) = rest.strip_prefix('}') {
+                return Ok(after);
+            }
+            loop {
+                rest = skip_json_value(rest)?.trim_start();
+                rest = rest.strip_prefix(':').ok_or("expected ':'")?;
+                rest = skip_json_value(rest)?.trim_start();
+                if let Some(after) = rest.strip_prefix(',') {
+                    rest = after.trim_start();
+                } else {
+                    break rest.strip_prefix('}').ok_or("expected '}'")?;
+                }
+            }
+        }
+        Some('[') => {
+            let mut rest = input[1..].trim_start();
+            if let Some(after) = rest.strip_prefix(']') {
+                return Ok(after);
+            }
+            loop {
+                rest = skip_json_value(rest)?.trim_start();
+                if let Some(after) = rest.strip_prefix(',') {
+                    rest = after;
+                } else {
+                    break rest.strip_prefix(']').ok_or("expected ']'")?;
+                }
+            }
+        }
+        Some('"') => {
+            let mut chars = input[1..].char_indices();
+            loop {
+                match chars.next().ok_or("unterminated string")? {
+                    (_, '\\') => {
+                        chars.next();
+                    }
+                    (idx, '"') => break &input[idx + 2..],
+                    (_, c) if c.is_control() => return Err("control char in string".into()),
+                    _ => {}
+                }
+            }
+        }
+        _ => {
+            let end = input
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')))
+                .unwrap_or(input.len());
+            let literal = &input[..end];
+            if !matches!(literal, "true" | "false" | "null") && literal.parse::<f64>().is_err() {
+                return Err(format!("invalid literal: {:?}", literal));
+            }
+            &input[end..]
+        }
+    };
+    Ok(rest)
+}
+
+#[test]
+fn test_json_schema_is_valid_json_with_config_fields() {
+    let schema = json_schema();
+    let rest = skip_json_value(&schema).expect("schema should be valid JSON");
+    assert!(rest.trim().is_empty(), "trailing content: {}", rest);
+
+    assert!(schema.contains("\"engine\": {"));
+    assert!(schema.contains("\"x-config-field\": \"agents_max_count\""));
+    assert!(schema.contains("\"x-config-field\": \"engine_types\""));
+    assert!(schema.contains("\"default\": 3,"));
+    assert!(schema.contains("\"enum\": [\"priority\", \"assignment\"]"));
+    assert!(schema.contains("\"profile\": {"));
+}
+
+#[test]
+fn test_json_schema_matches_config_keys() {
+    // Every key the TOML loader accepts is described, and vice versa
+    let toml_source = include_str!("toml.rs");
+    let schema_keys: Vec<&str> = schema::schema_keys().collect();
+    let aliases = ["engine.stub_seed"];
+    for line in toml_source.lines() {
+        let Some(arm) = line.trim().strip_suffix(" => {") else {
+            continue;
+        };
+        for key in arm.split(" | ") {
+            let Some(key) = key.strip_prefix('"').and_then(|k| k.strip_suffix('"')) else {
+                continue;
+            };
+            assert!(
+                schema_keys.contains(&key) || aliases.contains(&key),
+                "{} is missing from the schema",
+                key
+            );
+        }
+    }
+    for key in &schema_keys {
+        assert!(
+            toml_source.contains(&format!("\"{}", key)),
+            "{} is not a config key",
+            key
+        );
+    }
+
+    for name in schema::schema_engine_names() {
+        assert!(EngineType::parse(name).is_some(), "{}", name);
+    }
+    for value in schema::schema_enum_values("agents.task_order") {
+        assert!(AgentTaskOrder::parse(value).is_some(), "{}", value);
+    }
+    for value in schema::schema_enum_values("agents.on_no_agents") {
+        assert!(NoAgentsBehavior::parse(value).is_some(), "{}", value);
+    }
+    for value in schema::schema_enum_values("sprints.forge") {
+        assert!(Forge::parse(value).is_some(), "{}", value);
+    }
+    for value in schema::schema_enum_values("files.log_format") {
+        assert!(crate::log::LogFormat::parse(value).is_some(), "{}", value);
+    }
+    for value in schema::schema_enum_values("files.chat_format") {
+        assert!(crate::chat::ChatFormat::parse(value).is_some(), "{}", value);
+    }
+    for value in schema::schema_enum_values("files.tasks_completion_style") {
+        assert!(CompletionStyle::parse(value).is_some(), "{}", value);
+    }
+    for value in schema::schema_enum_values("audit.syslog_facility") {
+        assert!(SyslogFacility::parse(value).is_some(), "{}", value);
+    }
+    assert_eq!(
+        schema::schema_enum_values("audit.syslog_facility").len(),
+        10
+    );
+}
+
+#[test]
+fn test_config_load_timeout_precedence() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let temp = TempDir::new().expect("temp dir");
+    let toml_path = temp.path().join("swarm.toml");
+    fs::write(
+        &toml_path,
+        "[agents]\ntimeout = 100\nmerge_timeout = 200\nplanning_timeout = 300\n",
+    )
+    .expect("write swarm.toml");
+
+    let cases = [
+        ("SWARM_AGENT_TIMEOUT", "--agent-timeout"),
+        ("SWARM_MERGE_TIMEOUT", "--merge-timeout"),
+        ("SWARM_PLANNING_TIMEOUT", "--planning-timeout"),
+    ];
+    let timeouts = |config: &Config| {
+        [
+            config.agent_timeout_secs,
+            config.merge_timeout_secs,
+            config.planning_timeout_secs,
+        ]
+    };
+    let _unset: Vec<_> = cases
+        .iter()
+        .map(|(var, _)| EnvVarGuard::unset(var))
+        .collect();
+
+    // Defaults apply without a config file
+    let mut cli = CliArgs {
+        config: Some(
+            temp.path()
+                .join("missing.toml")
+                .to_string_lossy()
+                .to_string(),
+        ),
+        command: Some(Command::Init),
+        ..Default::default()
+    };
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(timeouts(&config), [DEFAULT_AGENT_TIMEOUT_SECS; 3]);
+
+    // TOML overrides defaults
+    cli.config = Some(toml_path.to_string_lossy().to_string());
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(timeouts(&config), [100, 200, 300]);
+
+    // Env overrides TOML
+    let _env: Vec<_> = cases
+        .iter()
+        .enumerate()
+        .map(|(i, (var, _))| EnvVarGuard::set(var, &(400 + i).to_string()))
+        .collect();
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(timeouts(&config), [400, 401, 402]);
+
+    // CLI overrides env
+    let mut argv = vec!["swarm".to_string()];
+    for (i, (_, flag)) in cases.iter().enumerate() {
+        argv.push(flag.to_string());
+        argv.push((500 + i).to_string());
+    }
+    let parsed = parse_args(argv);
+    cli.agent_timeout = parsed.agent_timeout;
+    cli.merge_timeout = parsed.merge_timeout;
+    cli.planning_timeout = parsed.planning_timeout;
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(timeouts(&config), [500, 501, 502]);
+}
+
+#[test]
+fn test_config_load_rejects_out_of_range_run_hash_len() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let cli = CliArgs {
+        command: Some(Command::Init),
+        ..Default::default()
+    };
+
+    let _len = EnvVarGuard::set("SWARM_RUN_HASH_LEN", "3");
+    let err = Config::load(&cli).expect_err("hash length below minimum");
+    assert!(err.to_string().contains("run_hash_len"));
+
+    let _len = EnvVarGuard::set("SWARM_RUN_HASH_LEN", "12");
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(config.run_hash_len, 12);
+}
+
+#[test]
+fn test_config_load_validates_branch_prefix() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let cli = CliArgs {
+        command: Some(Command::Init),
+        ..Default::default()
+    };
+
+    for bad in [
+        "/swarm",
+        "swarm..x/",
+        "swarm//",
+        "swarm/.x",
+        "bad prefix/",
+        "a~b",
+        "x.lock/",
+    ] {
+        let _prefix = EnvVarGuard::set("SWARM_BRANCH_PREFIX", bad);
+        let err = Config::load(&cli).expect_err("invalid branch prefix");
+        assert!(err.to_string().contains("branch_prefix"), "{}", bad);
+    }
+
+    let _prefix = EnvVarGuard::set("SWARM_BRANCH_PREFIX", "swarm/bots-");
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(config.branch_prefix, "swarm/bots-");
+}
+
+#[test]
+fn test_config_load_rejects_escaping_extra_tasks_files() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    for bad in ["/tmp/bugs.md", "../bugs.md", "docs/../../bugs.md"] {
+        let cli = CliArgs {
+            command: Some(Command::Init),
+            tasks_file: vec!["tasks.md".to_string(), bad.to_string()],
+            ..Default::default()
+        };
+        let err = Config::load(&cli).expect_err("escaping tasks file");
+        assert!(err.to_string().contains("--tasks-file"), "{}", bad);
+    }
+
+    let cli = CliArgs {
+        command: Some(Command::Init),
+        tasks_file: vec!["tasks.md".to_string(), "./docs/bugs.md".to_string()],
+        ..Default::default()
+    };
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(config.files_tasks_extra, vec!["./docs/bugs.md".to_string()]);
+}
+
+#[test]
+fn test_config_parse_toml_engine_command() {
+    let toml = r#"
+[engine]
+type = "command"
+
+[engine.command]
+program = "./scripts/agent.sh"
+args = ["--task", "{task}", "--note", "say \"hi\", ok", "{agent}@{dir}"]
+"#;
+    let config = Config::parse_toml(toml).unwrap();
+    assert_eq!(config.engine_command_program, "./scripts/agent.sh");
+    assert_eq!(
+        config.engine_command_args,
+        vec![
+            "--task",
+            "{task}",
+            "--note",
+            "say \"hi\", ok",
+            "{agent}@{dir}"
+        ]
+    );
+
+    let config = Config::parse_toml("[engine.command]\nargs = []\n").unwrap();
+    assert!(config.engine_command_args.is_empty());
+
+    for bad in [
+        r#"args = "{task}""#,
+        r#"args = ["{task}" "x"]"#,
+        r#"args = ["open"#,
+    ] {
+        let toml = format!("[engine.command]\n{}\n", bad);
+        assert!(Config::parse_toml(&toml).is_err(), "accepted {}", bad);
+    }
+}
+
+#[test]
+fn test_config_load_command_engine_uses_engine_command_section() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset = EnvVarGuard::unset("SWARM_ENGINE_TYPE");
+    let temp = TempDir::new().expect("temp dir");
+    let toml_path = temp.path().join("swarm.toml");
+    fs::write(
+        &toml_path,
+        "[engine.command]\nprogram = \"my-agent\"\nargs = [\"{task}\"]\n",
+    )
+    .expect("write swarm.toml");
+
+    let mut cli = CliArgs {
+        config: Some(toml_path.to_string_lossy().to_string()),
+        engine: Some("command,claude".to_string()),
+        command: Some(Command::Init),
+        ..Default::default()
+    };
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(
+        config.engine_types,
+        vec![
+            EngineType::Command {
+                program: "my-agent".to_string(),
+                args: vec!["{task}".to_string()],
+            },
+            EngineType::Claude,
+        ]
+    );
+    assert_eq!(config.engines_display(), "command,claude");
+
+    cli.config = Some(
+        temp.path()
+            .join("missing.toml")
+            .to_string_lossy()
+            .to_string(),
+    );
+    let err = Config::load(&cli).expect_err("command engine without program");
+    assert!(err.to_string().contains("[engine.command]"));
+}
+
 #[test]
 fn test_config_load_openrouter_requires_api_key() {
     let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
@@ -786,6 +2372,44 @@ fn test_config_load_openrouter_requires_model() {
     assert!(err.to_string().contains("requires a model"));
 }
 
+#[test]
+fn test_config_load_ollama_engine() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _unset = EnvVarGuard::unset("SWARM_ENGINE_OLLAMA_URL");
+    let cli = parse_args(vec![
+        "swarm".to_string(),
+        "--engine".to_string(),
+        "ollama:llama3".to_string(),
+        "init".to_string(),
+    ]);
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(
+        config.engine_types,
+        vec![EngineType::Ollama {
+            model: "llama3".to_string()
+        }]
+    );
+    assert_eq!(config.engines_display(), "ollama:llama3");
+    assert_eq!(config.engine_ollama_url, "http://localhost:11434");
+
+    let _url = EnvVarGuard::set("SWARM_ENGINE_OLLAMA_URL", "http://gpu-box:11434");
+    let config = Config::load(&cli).expect("config load");
+    assert_eq!(config.engine_ollama_url, "http://gpu-box:11434");
+}
+
+#[test]
+fn test_config_load_ollama_requires_model() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let cli = CliArgs {
+        engine: Some("ollama".to_string()),
+        command: Some(Command::Init),
+        ..Default::default()
+    };
+
+    let err = Config::load(&cli).expect_err("expected model error");
+    assert!(err.to_string().contains("ollama engine requires a model"));
+}
+
 #[test]
 fn test_config_load_openrouter_with_api_key() {
     let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
@@ -884,6 +2508,28 @@ fn test_parse_args_with_prd_before_project_name() {
     assert_eq!(cli.prd_file_arg, Some("prd.md".to_string()));
 }
 
+#[test]
+fn test_parse_args_team_init_with_issues() {
+    let cli = parse_args(
+        [
+            "swarm",
+            "team",
+            "init",
+            "myproject",
+            "--with-issues",
+            "acme/widgets",
+            "--label",
+            "swarm",
+        ]
+        .iter()
+        .map(|s| s.to_string()),
+    );
+    assert_eq!(cli.command, Some(Command::ProjectInit));
+    assert_eq!(cli.project_arg.as_deref(), Some("myproject"));
+    assert_eq!(cli.issues_repo_arg.as_deref(), Some("acme/widgets"));
+    assert_eq!(cli.issue_label.as_deref(), Some("swarm"));
+}
+
 #[test]
 fn test_parse_args_with_prd_no_value() {
     // If --with-prd is at the end with no value, prd_file_arg should be None
@@ -1085,3 +2731,21 @@ fn test_resolve_branches_target_only_errors() {
         msg
     );
 }
+
+#[test]
+fn test_expand_path_placeholders() {
+    let values = PathPlaceholders {
+        run: Some("abc123"),
+        team: Some("greenfield"),
+        sprint: Some(3),
+        timestamp: Some("20260101-120000"),
+    };
+    assert_eq!(
+        expand_path_placeholders("out/{team}/{run}-{timestamp}/s{sprint}/{other}", &values),
+        "out/greenfield/abc123-20260101-120000/s3/{other}"
+    );
+    assert_eq!(
+        expand_path_placeholders("logs/{sprint}", &PathPlaceholders::default()),
+        "logs/{sprint}"
+    );
+}
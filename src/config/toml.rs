@@ -1,15 +1,43 @@
 use std::fs;
 use std::path::Path;
 
-use super::types::{Config, ConfigError, EngineType};
+use super::types::{
+    parse_priority_tier, AgentTaskOrder, Config, ConfigError, EngineType, Forge, NoAgentsBehavior,
+};
+use crate::audit::SyslogFacility;
+use crate::chat::{self, ChatFormat, ChatTimezone};
+use crate::log::LogFormat;
+use crate::task::CompletionStyle;
 
 pub(super) fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Config, ConfigError> {
-    let content = fs::read_to_string(&path).map_err(|e| ConfigError::Io(e.to_string()))?;
+    let path = path.as_ref();
+    let content = fs::read_to_string(path).map_err(|e| {
+        ConfigError::Io(format!(
+            "failed to read TOML config {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
     Config::parse_toml(&content)
 }
 
 pub(super) fn parse_toml(content: &str) -> Result<Config, ConfigError> {
     let mut config = Config::default();
+    apply_toml(&mut config, content)?;
+    Ok(config)
+}
+
+/// Apply the settings in TOML `content` on top of `config`.
+pub(super) fn apply_toml(config: &mut Config, content: &str) -> Result<(), ConfigError> {
+    for (key, value) in toml_entries(content) {
+        apply_key(config, &key, &value).map_err(|e| e.in_format("TOML"))?;
+    }
+    Ok(())
+}
+
+/// Flatten TOML `content` into `(section.key, value)` pairs in file order.
+pub(super) fn toml_entries(content: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
     let mut current_section = String::new();
 
     for line in content.lines() {
@@ -32,51 +60,346 @@ pub(super) fn parse_toml(content: &str) -> Result<Config, ConfigError> {
                 format!("{}.{}", current_section, key)
             };
 
-            match full_key.as_str() {
-                "agents.max_count" => {
-                    config.agents_max_count = value.parse().map_err(|_| {
-                        ConfigError::Parse(format!("invalid agents.max_count: {}", value))
-                    })?;
-                }
-                "agents.tasks_per_agent" => {
-                    config.agents_tasks_per_agent = value.parse().map_err(|_| {
-                        ConfigError::Parse(format!("invalid agents.tasks_per_agent: {}", value))
-                    })?;
-                }
-                "agents.timeout" => {
-                    config.agent_timeout_secs = value.parse().map_err(|_| {
-                        ConfigError::Parse(format!("invalid agents.timeout: {}", value))
-                    })?;
-                }
-                "files.tasks" => {
-                    config.files_tasks = value.trim_matches('"').to_string();
-                }
-                "files.chat" => {
-                    config.files_chat = value.trim_matches('"').to_string();
-                }
-                "files.log_dir" => {
-                    config.files_log_dir = value.trim_matches('"').to_string();
-                }
-                "engine.type" => {
-                    let engine_str = value.trim_matches('"');
-                    config.engine_types = EngineType::parse_list(engine_str).ok_or_else(|| {
-                        ConfigError::Parse(format!("invalid engine.type: {}", engine_str))
-                    })?;
-                }
-                "engine.stub_mode" => {
-                    config.engine_stub_mode = value == "true";
-                }
-                "sprints.max" => {
-                    config.sprints_max = value.parse().map_err(|_| {
-                        ConfigError::Parse(format!("invalid sprints.max: {}", value))
+            entries.push((full_key, value.to_string()));
+        }
+    }
+
+    entries
+}
+
+/// Apply one `section.key` setting to `config`.
+///
+/// `value` uses TOML syntax (quoted strings, `[..]` arrays); the YAML loader
+/// converts its scalars to the same form so both formats share this mapping.
+pub(super) fn apply_key(config: &mut Config, key: &str, value: &str) -> Result<(), ConfigError> {
+    match key {
+        "agents.max_count" => {
+            config.agents_max_count = value
+                .parse()
+                .map_err(|_| ConfigError::Parse(format!("invalid agents.max_count: {}", value)))?;
+        }
+        "agents.tasks_per_agent" => {
+            config.agents_tasks_per_agent = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.tasks_per_agent: {}", value))
+            })?;
+        }
+        "agents.points_per_agent" => {
+            config.agents_points_per_agent = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.points_per_agent: {}", value))
+            })?;
+        }
+        "agents.timeout" => {
+            config.agent_timeout_secs = value
+                .parse()
+                .map_err(|_| ConfigError::Parse(format!("invalid agents.timeout: {}", value)))?;
+        }
+        "agents.merge_timeout" => {
+            config.merge_timeout_secs = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.merge_timeout: {}", value))
+            })?;
+        }
+        "agents.merge_retries" => {
+            config.merge_retries = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.merge_retries: {}", value))
+            })?;
+        }
+        "agents.planning_timeout" => {
+            config.planning_timeout_secs = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.planning_timeout: {}", value))
+            })?;
+        }
+        "agents.shutdown_grace" => {
+            config.shutdown_grace_secs = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.shutdown_grace: {}", value))
+            })?;
+        }
+        "agents.heartbeat_interval" => {
+            config.heartbeat_interval_secs = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.heartbeat_interval: {}", value))
+            })?;
+        }
+        "agents.task_order" => {
+            let order_str = value.trim_matches('"');
+            config.agent_task_order = AgentTaskOrder::parse(order_str).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid agents.task_order: {}", order_str))
+            })?;
+        }
+        "agents.on_no_agents" => {
+            let behavior_str = value.trim_matches('"');
+            config.agents_on_no_agents =
+                NoAgentsBehavior::parse(behavior_str).ok_or_else(|| {
+                    ConfigError::Parse(format!("invalid agents.on_no_agents: {}", behavior_str))
+                })?;
+        }
+        "agents.keep_branches" => {
+            config.agents_keep_branches = value == "true";
+        }
+        "agents.no_agents_wait_timeout" => {
+            config.agents_no_agents_wait_secs = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid agents.no_agents_wait_timeout: {}", value))
+            })?;
+        }
+        "files.tasks" => {
+            config.files_tasks = value.trim_matches('"').to_string();
+        }
+        "files.chat" => {
+            config.files_chat = value.trim_matches('"').to_string();
+        }
+        "files.log_dir" => {
+            config.files_log_dir = value.trim_matches('"').to_string();
+        }
+        "files.tasks_completion_style" => {
+            let style_str = value.trim_matches('"');
+            config.files_tasks_completion_style =
+                CompletionStyle::parse(style_str).ok_or_else(|| {
+                    ConfigError::Parse(format!(
+                        "invalid files.tasks_completion_style: {}",
+                        style_str
+                    ))
+                })?;
+        }
+        "files.chat_format" => {
+            let format_str = value.trim_matches('"');
+            config.files_chat_format = ChatFormat::parse(format_str).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid files.chat_format: {}", format_str))
+            })?;
+        }
+        "files.chat_timezone" => {
+            let timezone_str = value.trim_matches('"');
+            config.files_chat_timezone = ChatTimezone::parse(timezone_str).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid files.chat_timezone: {}", timezone_str))
+            })?;
+        }
+        "files.chat_time_format" => {
+            let format_str = value.trim_matches('"');
+            if !chat::is_valid_time_format(format_str) {
+                return Err(ConfigError::Parse(format!(
+                    "invalid files.chat_time_format: {}",
+                    format_str
+                )));
+            }
+            config.files_chat_time_format = format_str.to_string();
+        }
+        "files.log_format" => {
+            let format_str = value.trim_matches('"');
+            config.files_log_format = LogFormat::parse(format_str).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid files.log_format: {}", format_str))
+            })?;
+        }
+        "files.log_max_rotations" => {
+            config.files_log_max_rotations = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid files.log_max_rotations: {}", value))
+            })?;
+        }
+        "files.log_output_max_bytes" => {
+            config.files_log_output_max_bytes = Some(value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid files.log_output_max_bytes: {}", value))
+            })?);
+        }
+        "engine.type" => {
+            let engine_str = value.trim_matches('"');
+            config.engine_types = EngineType::parse_list(engine_str).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid engine.type: {}", engine_str))
+            })?;
+        }
+        "engine.stub_mode" => {
+            config.engine_stub_mode = value == "true";
+        }
+        "engine.max_rpm" => {
+            config.engine_max_rpm = value
+                .parse()
+                .map_err(|_| ConfigError::Parse(format!("invalid engine.max_rpm: {}", value)))?;
+        }
+        "engine.max_concurrency" => {
+            config.engine_max_concurrency = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid engine.max_concurrency: {}", value))
+            })?;
+        }
+        "engine.stub_fail_rate" => {
+            config.engine_stub_fail_rate = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid engine.stub_fail_rate: {}", value))
+            })?;
+        }
+        "engine.seed" | "engine.stub_seed" => {
+            config.seed = Some(
+                value
+                    .parse()
+                    .map_err(|_| ConfigError::Parse(format!("invalid {}: {}", key, value)))?,
+            );
+        }
+        "engine.retries" => {
+            config.engine_retries = value
+                .parse()
+                .map_err(|_| ConfigError::Parse(format!("invalid engine.retries: {}", value)))?;
+        }
+        "engine.retry_delay" => {
+            config.engine_retry_delay_secs = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid engine.retry_delay: {}", value))
+            })?;
+        }
+        "engine.ollama_url" => {
+            config.engine_ollama_url = value.trim_matches('"').to_string();
+        }
+        "engine.command.program" => {
+            config.engine_command_program = value.trim_matches('"').to_string();
+        }
+        "engine.command.args" => {
+            config.engine_command_args = parse_string_array(value).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid engine.command.args: {}", value))
+            })?;
+        }
+        "sprints.max" => {
+            config.sprints_max = value
+                .parse()
+                .map_err(|_| ConfigError::Parse(format!("invalid sprints.max: {}", value)))?;
+        }
+        "sprints.track_interrupted" => {
+            config.sprints_track_interrupted = value == "true";
+        }
+        "sprints.fetch_before_merge" => {
+            config.sprints_fetch_before_merge = value == "true";
+        }
+        "sprints.create_pr" => {
+            config.sprints_create_pr = value == "true";
+        }
+        "sprints.forge" => {
+            let forge_str = value.trim_matches('"');
+            config.sprints_forge = Some(Forge::parse(forge_str).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid sprints.forge: {}", forge_str))
+            })?);
+        }
+        "sprints.fast_path_merge" => {
+            config.sprints_fast_path_merge = value == "true";
+        }
+        "sprints.ordered_merge" => {
+            config.sprints_ordered_merge = value == "true";
+        }
+        "sprints.watch_tasks" => {
+            config.sprints_watch_tasks = value == "true";
+        }
+        "sprints.watch_interval" => {
+            config.sprints_watch_interval_secs = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid sprints.watch_interval: {}", value))
+            })?;
+        }
+        "sprints.max_retries" => {
+            config.sprints_max_retries = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid sprints.max_retries: {}", value))
+            })?;
+        }
+        "sprints.branch_prefix" => {
+            config.branch_prefix = value.trim_matches('"').to_string();
+        }
+        "worktree.sparse_paths" => {
+            config.worktree_sparse_paths = parse_string_array(value).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid worktree.sparse_paths: {}", value))
+            })?;
+        }
+        "worktree.preserved_max_age_days" => {
+            config.worktree_preserved_max_age_days = value.parse().map_err(|_| {
+                ConfigError::Parse(format!(
+                    "invalid worktree.preserved_max_age_days: {}",
+                    value
+                ))
+            })?;
+        }
+        "sprints.run_hash_len" => {
+            config.run_hash_len = value.parse().map_err(|_| {
+                ConfigError::Parse(format!("invalid sprints.run_hash_len: {}", value))
+            })?;
+        }
+        "audit.syslog" => {
+            config.audit_syslog = value == "true";
+        }
+        "audit.syslog_facility" => {
+            let facility_str = value.trim_matches('"');
+            config.audit_syslog_facility =
+                SyslogFacility::parse(facility_str).ok_or_else(|| {
+                    ConfigError::Parse(format!("invalid audit.syslog_facility: {}", facility_str))
+                })?;
+        }
+        "webhook.url" => {
+            let url = value.trim_matches('"');
+            config.webhook_url = (!url.is_empty()).then(|| url.to_string());
+        }
+        "webhook.sprint_url" => {
+            let url = value.trim_matches('"');
+            config.sprint_webhook_url = (!url.is_empty()).then(|| url.to_string());
+        }
+        "slack.webhook_url" => {
+            let url = value.trim_matches('"');
+            config.slack_webhook_url = (!url.is_empty()).then(|| url.to_string());
+        }
+        profile_key if profile_key.starts_with("profile.") => {
+            let (name, setting) =
+                profile_key["profile.".len()..]
+                    .split_once('.')
+                    .ok_or_else(|| {
+                        ConfigError::Parse(format!("invalid profile key: {}", profile_key))
                     })?;
-                }
-                _ => {} // Ignore unknown keys
+            if setting.starts_with("profile.") {
+                return Err(ConfigError::Parse(format!(
+                    "profiles cannot be nested: {}",
+                    profile_key
+                )));
             }
+            // Validate now so a bad value is reported even if the profile is unused
+            apply_key(&mut Config::default(), setting, value).map_err(|e| match e {
+                ConfigError::Parse(msg) => {
+                    ConfigError::Parse(format!("profile '{}': {}", name, msg))
+                }
+                other => other,
+            })?;
+            config
+                .profiles
+                .entry(name.to_string())
+                .or_default()
+                .push((setting.to_string(), value.to_string()));
+        }
+        tier_key if tier_key.starts_with("agents.tasks_per_priority.") => {
+            let tier_name = &tier_key["agents.tasks_per_priority.".len()..];
+            let tier = parse_priority_tier(tier_name).ok_or_else(|| {
+                ConfigError::Parse(format!("invalid priority tier: {}", tier_name))
+            })?;
+            let limit = value
+                .parse()
+                .map_err(|_| ConfigError::Parse(format!("invalid {}: {}", tier_key, value)))?;
+            config.agents_tasks_per_priority.insert(tier, limit);
         }
+        _ => {} // Ignore unknown keys
     }
+    Ok(())
+}
 
-    Ok(config)
+/// Parse a single-line TOML array of strings like `["-v", "{task}"]`.
+fn parse_string_array(value: &str) -> Option<Vec<String>> {
+    let mut rest = value.strip_prefix('[')?.strip_suffix(']')?.trim();
+    let mut items = Vec::new();
+    while !rest.is_empty() {
+        let mut chars = rest.strip_prefix('"')?.char_indices();
+        let mut item = String::new();
+        let end = loop {
+            let (idx, ch) = chars.next()?;
+            match ch {
+                '"' => break idx,
+                '\\' => match chars.next()?.1 {
+                    'n' => item.push('\n'),
+                    't' => item.push('\t'),
+                    other => item.push(other),
+                },
+                _ => item.push(ch),
+            }
+        };
+        items.push(item);
+        // Skip the closing quote, then an optional comma
+        rest = rest[end + 2..].trim_start();
+        if let Some(after_comma) = rest.strip_prefix(',') {
+            rest = after_comma.trim_start();
+        } else if !rest.is_empty() {
+            return None;
+        }
+    }
+    Some(items)
 }
 
 /// Parse a TOML line into key-value pair.
@@ -86,5 +409,21 @@ fn parse_toml_line(line: &str) -> Option<(&str, &str)> {
     if parts.len() != 2 {
         return None;
     }
-    Some((parts[0].trim(), parts[1].trim()))
+    Some((parts[0].trim(), strip_inline_comment(parts[1].trim())))
+}
+
+/// Drop a trailing `# comment` that is outside a quoted string.
+fn strip_inline_comment(value: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (idx, ch) in value.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return value[..idx].trim_end(),
+            _ => {}
+        }
+    }
+    value
 }
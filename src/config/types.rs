@@ -1,10 +1,21 @@
+use std::collections::BTreeMap;
 use std::env as std_env;
-use std::path::Path;
+use std::fs;
+use std::path::{Component, Path};
 #[cfg(test)]
 use std::process::Command;
 
 use super::cli::{CliArgs, Command as CliCommand};
-use super::{env, toml};
+use super::{env, toml, yaml};
+use crate::audit::SyslogFacility;
+use crate::chat::{self, ChatFormat, ChatTimezone};
+use crate::engine::DEFAULT_OLLAMA_URL;
+use crate::heartbeat::DEFAULT_HEARTBEAT_INTERVAL_SECS;
+use crate::log::{self, LogFormat};
+use crate::run_hash::{DEFAULT_HASH_LEN, MAX_HASH_LEN, MIN_HASH_LEN};
+use crate::task::CompletionStyle;
+use crate::team::Team;
+use crate::verbosity::Verbosity;
 
 /// Engine type for agent execution.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -14,8 +25,14 @@ pub enum EngineType {
     Claude,
     /// Codex CLI engine.
     Codex,
+    /// Gemini CLI engine.
+    Gemini,
+    /// Local model served by Ollama over HTTP.
+    Ollama { model: String },
     /// OpenRouter via Claude CLI (Anthropic-compatible).
     OpenRouter { model: String },
+    /// Arbitrary program configured under `[engine.command]`.
+    Command { program: String, args: Vec<String> },
     /// Stubbed engine for tests (no network).
     Stub,
 }
@@ -28,11 +45,25 @@ impl EngineType {
         match lower.as_str() {
             "claude" => Some(Self::Claude),
             "codex" => Some(Self::Codex),
+            "gemini" => Some(Self::Gemini),
             "stub" => Some(Self::Stub),
+            "command" => Some(Self::Command {
+                program: String::new(),
+                args: Vec::new(),
+            }),
             "openrouter" => Some(Self::OpenRouter {
                 model: String::new(),
             }),
+            "ollama" => Some(Self::Ollama {
+                model: String::new(),
+            }),
             _ => {
+                if lower.starts_with("ollama:") {
+                    let (_, model) = trimmed.split_once(':')?;
+                    return Some(Self::Ollama {
+                        model: model.trim().to_string(),
+                    });
+                }
                 if lower.starts_with("openrouter_") {
                     if let Some((prefix, model)) = trimmed.split_once('_') {
                         if prefix.eq_ignore_ascii_case("openrouter") {
@@ -52,7 +83,10 @@ impl EngineType {
         match self {
             Self::Claude => "claude".to_string(),
             Self::Codex => "codex".to_string(),
+            Self::Gemini => "gemini".to_string(),
             Self::Stub => "stub".to_string(),
+            // Program and args come from swarm.toml, so they aren't encoded here
+            Self::Command { .. } => "command".to_string(),
             Self::OpenRouter { model } => {
                 if model.trim().is_empty() {
                     "openrouter".to_string()
@@ -60,6 +94,13 @@ impl EngineType {
                     format!("openrouter_{}", model)
                 }
             }
+            Self::Ollama { model } => {
+                if model.trim().is_empty() {
+                    "ollama".to_string()
+                } else {
+                    format!("ollama:{}", model)
+                }
+            }
         }
     }
 
@@ -89,24 +130,208 @@ impl EngineType {
     }
 }
 
+/// Order in which an agent works through its assigned tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AgentTaskOrder {
+    /// Run `(P<n>)`-tagged tasks most urgent first; untagged tasks keep
+    /// assignment order after tagged ones. No-op when no task is tagged.
+    #[default]
+    Priority,
+    /// Run tasks strictly in assignment order.
+    Assignment,
+}
+
+impl AgentTaskOrder {
+    /// Parse task order from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "priority" => Some(Self::Priority),
+            "assignment" => Some(Self::Assignment),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Priority => "priority",
+            Self::Assignment => "assignment",
+        }
+    }
+}
+
+/// What a sprint does when no agents are available to take tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoAgentsBehavior {
+    /// End the sprint quietly with nothing assigned.
+    #[default]
+    Skip,
+    /// Poll until an agent busy in another run of the project frees up,
+    /// failing after the wait timeout.
+    Wait,
+    /// Fail the sprint with a descriptive error.
+    Error,
+}
+
+impl NoAgentsBehavior {
+    /// Parse behavior from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "skip" => Some(Self::Skip),
+            "wait" => Some(Self::Wait),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::Wait => "wait",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Code host the sprint pull request is opened on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forge {
+    /// GitHub, via `gh pr create`.
+    GitHub,
+    /// GitLab, via `glab mr create`.
+    GitLab,
+}
+
+impl Forge {
+    /// Parse forge from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "github" => Some(Self::GitHub),
+            "gitlab" => Some(Self::GitLab),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::GitHub => "github",
+            Self::GitLab => "gitlab",
+        }
+    }
+}
+
+/// Parse a priority tier name like `P0` (case-insensitive) into its number.
+pub(super) fn parse_priority_tier(s: &str) -> Option<u8> {
+    let s = s.trim().trim_matches('"');
+    let digits = s.strip_prefix('P').or_else(|| s.strip_prefix('p'))?;
+    digits.parse().ok()
+}
+
+/// Parse a tier map like `P0=1,P3=3` into tier → tasks-per-agent.
+pub(super) fn parse_tier_limits(s: &str) -> Option<BTreeMap<u8, usize>> {
+    let mut limits = BTreeMap::new();
+    for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (tier, limit) = entry.split_once('=')?;
+        limits.insert(parse_priority_tier(tier)?, limit.trim().parse().ok()?);
+    }
+    Some(limits)
+}
+
+/// Default time to wait for a free agent when `on_no_agents = "wait"` (5 minutes).
+pub const DEFAULT_NO_AGENTS_WAIT_SECS: u64 = 300;
+
+/// Default time agents get to finish after Ctrl+C before being force-killed.
+pub const DEFAULT_SHUTDOWN_GRACE_SECS: u64 = 60;
+
+/// Config files looked for in the working directory when `--config` isn't given, in order.
+pub(super) const DEFAULT_CONFIG_FILES: [&str; 3] = ["swarm.toml", "swarm.yaml", "swarm.yml"];
+
+/// Default story points per agent per sprint for estimated tasks.
+pub const DEFAULT_POINTS_PER_AGENT: u32 = 8;
+
+/// Default seconds before the first retry of a transient engine failure.
+pub const DEFAULT_ENGINE_RETRY_DELAY_SECS: u64 = 5;
+
+/// Default seconds between task re-checks in `--watch-tasks` mode.
+pub const DEFAULT_WATCH_INTERVAL_SECS: u64 = 30;
+
 /// Default agent timeout in seconds (60 minutes).
 pub const DEFAULT_AGENT_TIMEOUT_SECS: u64 = 3600;
 
+/// Default age in days after which `swarm prune` removes preserved worktrees.
+pub const DEFAULT_PRESERVED_MAX_AGE_DAYS: u64 = 7;
+
+/// Default cap on simultaneous engine executions: the number of CPUs.
+pub fn default_max_concurrency() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
 /// Swarm configuration.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Config {
     /// Maximum number of agents that may be spawned.
     pub agents_max_count: usize,
     /// Number of tasks to assign per agent per sprint.
     pub agents_tasks_per_agent: usize,
+    /// Tasks-per-agent overrides keyed by `(P<n>)` priority tier.
+    /// Untagged tasks and unlisted tiers use `agents_tasks_per_agent`.
+    pub agents_tasks_per_priority: BTreeMap<u8, usize>,
+    /// Story points one agent should take per sprint when tasks carry
+    /// `(<n>pts)` estimates (0 = size the team by task count only).
+    pub agents_points_per_agent: u32,
     /// Agent execution timeout in seconds.
     pub agent_timeout_secs: u64,
+    /// Merge-agent execution timeout in seconds (sprint merges and conflict resolution).
+    pub merge_timeout_secs: u64,
+    /// Merge-agent re-attempts when the sprint merge fails verification (0 = none).
+    pub merge_retries: usize,
+    /// Timeout in seconds for planning, sprint review, PR text, and PRD conversion.
+    pub planning_timeout_secs: u64,
+    /// Seconds agents get to finish their current task after shutdown is
+    /// requested before their processes are force-killed (0 = wait indefinitely).
+    pub shutdown_grace_secs: u64,
+    /// Seconds between "still working" chat messages while an agent runs a
+    /// task (0 = no heartbeats).
+    pub heartbeat_interval_secs: u64,
+    /// Order in which each agent works through its assigned tasks.
+    pub agent_task_order: AgentTaskOrder,
+    /// Behavior when no agents are available for a sprint.
+    pub agents_on_no_agents: NoAgentsBehavior,
+    /// Maximum seconds to wait for a free agent when behavior is `wait`.
+    pub agents_no_agents_wait_secs: u64,
+    /// Keep agent branches after post-sprint cleanup, renamed with the sprint
+    /// number, instead of deleting them.
+    pub agents_keep_branches: bool,
     /// Path to TASKS.md file.
     pub files_tasks: String,
-    /// Path to CHAT.md file.
+    /// Extra tasks files from a repeated `--tasks-file`, merged with
+    /// `files_tasks` into one pool; completions are written back to the
+    /// file each task came from.
+    pub files_tasks_extra: Vec<String>,
+    /// Path to CHAT.md file. May contain `{run}`, `{team}`, `{sprint}`, and
+    /// `{timestamp}` placeholders (see [`PathPlaceholders`](super::PathPlaceholders)).
     pub files_chat: String,
-    /// Path to log directory.
+    /// Path to log directory. Accepts the same placeholders as `files_chat`.
     pub files_log_dir: String,
+    /// Bytes of engine output kept per log entry (0 = no truncation).
+    /// `None` keeps the built-in limits (500 for agents, 1000 for the merge agent).
+    pub files_log_output_max_bytes: Option<usize>,
+    /// Agent log line format (text or JSON).
+    pub files_log_format: LogFormat,
+    /// Compressed archives kept per log on rotation (0 = discard rotated content).
+    pub files_log_max_rotations: usize,
+    /// How completed tasks are written to the tasks file.
+    pub files_tasks_completion_style: CompletionStyle,
+    /// Chat log line format (markdown or JSONL).
+    pub files_chat_format: ChatFormat,
+    /// Timezone chat timestamps are written in.
+    pub files_chat_timezone: ChatTimezone,
+    /// strftime format of chat timestamps.
+    pub files_chat_time_format: String,
     /// Path to worktrees directory.
     pub files_worktrees_dir: String,
     /// Engine types for agent execution (supports weighted random selection).
@@ -114,8 +339,70 @@ pub struct Config {
     pub engine_types: Vec<EngineType>,
     /// Enable stub mode for testing (overrides engine_types to Stub).
     pub engine_stub_mode: bool,
+    /// Maximum engine executions started per minute (0 means unlimited).
+    pub engine_max_rpm: u32,
+    /// Maximum engine executions running at once (0 means unlimited).
+    pub engine_max_concurrency: usize,
+    /// Fraction of stub engine executions that fail, 0.0-1.0 (chaos testing).
+    pub engine_stub_fail_rate: f64,
+    /// Seed that makes engine selection and stub failures reproducible
+    /// (random when unset).
+    pub seed: Option<u64>,
+    /// Times a transiently failed engine execution is retried.
+    pub engine_retries: u32,
+    /// Delay before the first retry in seconds; doubles for each further retry.
+    pub engine_retry_delay_secs: u64,
+    /// Base URL of the Ollama server used by `ollama:<model>` engines.
+    pub engine_ollama_url: String,
+    /// Program run by the `command` engine (`[engine.command] program`).
+    pub engine_command_program: String,
+    /// Arguments for the `command` engine; `{task}`, `{agent}`, and `{dir}`
+    /// are substituted per task.
+    pub engine_command_args: Vec<String>,
     /// Maximum sprints to run (0 means unlimited).
     pub sprints_max: usize,
+    /// Annotate tasks left assigned by an interrupted sprint with `{interrupted}`
+    /// instead of silently unassigning them.
+    pub sprints_track_interrupted: bool,
+    /// Fetch the target branch from `origin` and fast-forward it before merging.
+    pub sprints_fetch_before_merge: bool,
+    /// Open a pull request after the target branch is pushed.
+    pub sprints_create_pr: bool,
+    /// Forge to open the pull request on; detected from the `origin` remote
+    /// host when unset.
+    pub sprints_forge: Option<Forge>,
+    /// Re-attempts of a sprint that errors for infrastructure reasons (0 = none).
+    pub sprints_max_retries: usize,
+    /// Merge clean single-agent sprints with git instead of the merge agent.
+    pub sprints_fast_path_merge: bool,
+    /// Hold each task's merge until the sprint tasks it is blocked by have merged.
+    pub sprints_ordered_merge: bool,
+    /// Idle and re-check tasks instead of exiting when none are assignable.
+    pub sprints_watch_tasks: bool,
+    /// Seconds between task re-checks while `sprints_watch_tasks` is idle.
+    pub sprints_watch_interval_secs: u64,
+    /// Length of the run hash suffix on sprint/agent branch names (4-20).
+    pub run_hash_len: usize,
+    /// Prefix prepended to sprint and agent branch names (empty by default).
+    pub branch_prefix: String,
+    /// Directories agent worktrees check out via sparse-checkout (empty = everything).
+    pub worktree_sparse_paths: Vec<String>,
+    /// Days after which `swarm prune` removes preserved worktrees.
+    pub worktree_preserved_max_age_days: u64,
+    /// Mirror sprint lifecycle events to syslog (Unix only).
+    pub audit_syslog: bool,
+    /// Syslog facility used when `audit_syslog` is enabled.
+    pub audit_syslog_facility: SyslogFacility,
+    /// URL agent start, heartbeat, and completion events are POSTed to.
+    pub webhook_url: Option<String>,
+    /// URL a JSON summary of each finished sprint is POSTed to.
+    pub sprint_webhook_url: Option<String>,
+    /// Slack incoming webhook a summary is posted to after each sprint.
+    pub slack_webhook_url: Option<String>,
+    /// Settings from `[profile.<name>]` tables as `(key, value)` pairs, in file order.
+    pub profiles: BTreeMap<String, Vec<(String, String)>>,
+    /// Profile overlaid on the config file (`--profile` or `SWARM_PROFILE`).
+    pub profile: Option<String>,
     /// Project name for multi-project mode.
     pub project: Option<String>,
     /// Source branch to fork/branch from.
@@ -124,6 +411,18 @@ pub struct Config {
     pub target_branch: Option<String>,
     /// Whether `--target-branch` was explicitly provided by CLI.
     pub target_branch_explicit: bool,
+    /// Console output level (`-q`, default, `-v`, `-vv`).
+    pub verbosity: Verbosity,
+    /// Directory generated engine prompts are dumped to (`--dump-prompts`).
+    pub dump_prompts_dir: Option<String>,
+    /// Unix socket sprint events are streamed to (`--event-socket`).
+    pub event_socket: Option<String>,
+    /// Fixed run ID used as the branch run hash (`--run-id`).
+    pub run_id: Option<String>,
+    /// Continue an interrupted sprint instead of planning a new one (`swarm resume`).
+    pub resume: bool,
+    /// Print the next sprint's plan without running it (`run --dry-run`).
+    pub dry_run: bool,
 }
 
 impl Default for Config {
@@ -131,18 +430,72 @@ impl Default for Config {
         Self {
             agents_max_count: 3,
             agents_tasks_per_agent: 2,
+            agents_tasks_per_priority: BTreeMap::new(),
+            agents_points_per_agent: DEFAULT_POINTS_PER_AGENT,
             agent_timeout_secs: DEFAULT_AGENT_TIMEOUT_SECS,
+            merge_timeout_secs: DEFAULT_AGENT_TIMEOUT_SECS,
+            merge_retries: 1,
+            planning_timeout_secs: DEFAULT_AGENT_TIMEOUT_SECS,
+            shutdown_grace_secs: DEFAULT_SHUTDOWN_GRACE_SECS,
+            heartbeat_interval_secs: DEFAULT_HEARTBEAT_INTERVAL_SECS,
+            agent_task_order: AgentTaskOrder::default(),
+            agents_on_no_agents: NoAgentsBehavior::default(),
+            agents_no_agents_wait_secs: DEFAULT_NO_AGENTS_WAIT_SECS,
+            agents_keep_branches: false,
             files_tasks: ".swarm-hug/default/tasks.md".to_string(),
+            files_tasks_extra: Vec::new(),
             files_chat: ".swarm-hug/default/chat.md".to_string(),
             files_log_dir: ".swarm-hug/default/loop".to_string(),
+            files_log_output_max_bytes: None,
+            files_tasks_completion_style: CompletionStyle::default(),
+            files_chat_format: ChatFormat::default(),
+            files_log_format: LogFormat::default(),
+            files_log_max_rotations: log::DEFAULT_MAX_ROTATIONS,
+            files_chat_timezone: ChatTimezone::default(),
+            files_chat_time_format: chat::DEFAULT_TIME_FORMAT.to_string(),
             files_worktrees_dir: ".swarm-hug/default/worktrees".to_string(),
             engine_types: vec![EngineType::Claude],
             engine_stub_mode: false,
+            engine_max_rpm: 0,
+            engine_max_concurrency: default_max_concurrency(),
+            engine_stub_fail_rate: 0.0,
+            seed: None,
+            engine_retries: 2,
+            engine_retry_delay_secs: DEFAULT_ENGINE_RETRY_DELAY_SECS,
+            engine_ollama_url: DEFAULT_OLLAMA_URL.to_string(),
+            engine_command_program: String::new(),
+            engine_command_args: Vec::new(),
             sprints_max: 0,
+            sprints_track_interrupted: false,
+            sprints_fetch_before_merge: false,
+            sprints_create_pr: true,
+            sprints_forge: None,
+            sprints_max_retries: 0,
+            sprints_fast_path_merge: false,
+            sprints_ordered_merge: false,
+            sprints_watch_tasks: false,
+            sprints_watch_interval_secs: DEFAULT_WATCH_INTERVAL_SECS,
+            run_hash_len: DEFAULT_HASH_LEN,
+            branch_prefix: String::new(),
+            worktree_sparse_paths: Vec::new(),
+            worktree_preserved_max_age_days: DEFAULT_PRESERVED_MAX_AGE_DAYS,
+            audit_syslog: false,
+            webhook_url: None,
+            sprint_webhook_url: None,
+            slack_webhook_url: None,
+            audit_syslog_facility: SyslogFacility::default(),
+            profiles: BTreeMap::new(),
+            profile: None,
             project: None,
             source_branch: None,
             target_branch: None,
             target_branch_explicit: false,
+            verbosity: Verbosity::default(),
+            dump_prompts_dir: None,
+            event_socket: None,
+            run_id: None,
+            resume: false,
+            dry_run: false,
         }
     }
 }
@@ -150,7 +503,7 @@ impl Default for Config {
 impl Config {
     /// Load configuration from all sources with proper precedence.
     ///
-    /// Precedence: CLI args > env vars > config file > defaults.
+    /// Precedence: CLI args > env vars > profile > team config > config file > defaults.
     ///
     /// When a team is specified via `--team`, paths are resolved relative to
     /// `.swarm-hug/<team>/` unless explicitly overridden.
@@ -166,12 +519,30 @@ impl Config {
             if let Ok(file_config) = Self::load_from_file(path) {
                 config.merge_from(&file_config);
             }
-        } else if Path::new("swarm.toml").exists() {
-            if let Ok(file_config) = Self::load_from_file("swarm.toml") {
+        } else if let Some(path) = DEFAULT_CONFIG_FILES
+            .iter()
+            .find(|name| Path::new(name).exists())
+        {
+            if let Ok(file_config) = Self::load_from_file(path) {
                 config.merge_from(&file_config);
             }
         }
 
+        // Team overrides from .swarm-hug/<team>/config.toml
+        if let Some(ref team) = cli_args.project {
+            config.apply_team_config(team)?;
+        }
+
+        // Overlay the selected profile on the file settings
+        let profile = cli_args
+            .profile
+            .clone()
+            .or_else(|| std_env::var("SWARM_PROFILE").ok())
+            .filter(|name| !name.is_empty());
+        if let Some(name) = profile {
+            config.apply_profile(&name)?;
+        }
+
         // Apply environment variables
         config.apply_env();
 
@@ -182,6 +553,7 @@ impl Config {
         if config.engine_stub_mode {
             config.engine_types = vec![EngineType::Stub];
         }
+        config.resolve_command_engines();
 
         // Apply project-based path resolution if project is set and paths weren't explicitly overridden
         if config.project.is_some() {
@@ -197,13 +569,55 @@ impl Config {
         Ok(config)
     }
 
+    /// Overlay `.swarm-hug/<team>/config.toml` (when present) on the loaded config.
+    ///
+    /// Only the keys the team file sets are changed.
+    fn apply_team_config(&mut self, team: &str) -> Result<(), ConfigError> {
+        let path = Team::new(team).config_path();
+        if !path.exists() {
+            return Ok(());
+        }
+        let content = fs::read_to_string(&path).map_err(|e| {
+            ConfigError::Io(format!(
+                "failed to read team config {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        toml::apply_toml(self, &content).map_err(|e| match e {
+            ConfigError::Parse(msg) => ConfigError::Parse(format!("{}: {}", path.display(), msg)),
+            other => other,
+        })
+    }
+
+    /// Apply the settings of the `[profile.<name>]` table.
+    fn apply_profile(&mut self, name: &str) -> Result<(), ConfigError> {
+        let Some(settings) = self.profiles.get(name).cloned() else {
+            let known = self.profiles.keys().cloned().collect::<Vec<_>>();
+            return Err(ConfigError::Validation(if known.is_empty() {
+                format!("unknown profile '{}': config defines no profiles", name)
+            } else {
+                format!(
+                    "unknown profile '{}' (available: {})",
+                    name,
+                    known.join(", ")
+                )
+            }));
+        };
+        for (key, value) in &settings {
+            toml::apply_key(self, key, value)?;
+        }
+        self.profile = Some(name.to_string());
+        Ok(())
+    }
+
     /// Apply project-based path defaults.
     /// Only applies if the path wasn't explicitly set via CLI.
-    fn apply_project_paths(&mut self, project_name: &str, cli_args: &CliArgs) {
+    pub(super) fn apply_project_paths(&mut self, project_name: &str, cli_args: &CliArgs) {
         let project_root = format!(".swarm-hug/{}", project_name);
 
         // Only override if not explicitly set
-        if cli_args.tasks_file.is_none() {
+        if cli_args.tasks_file.is_empty() {
             self.files_tasks = format!("{}/tasks.md", project_root);
         }
         if cli_args.chat_file.is_none() {
@@ -216,9 +630,13 @@ impl Config {
         self.files_worktrees_dir = format!("{}/worktrees", project_root);
     }
 
-    /// Load configuration from a TOML file.
+    /// Load configuration from a TOML file, or YAML for `.yaml`/`.yml` paths.
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
-        toml::load_from_file(path)
+        if yaml::is_yaml_path(path.as_ref()) {
+            yaml::load_from_file(path)
+        } else {
+            toml::load_from_file(path)
+        }
     }
 
     /// Parse TOML content into configuration.
@@ -226,6 +644,11 @@ impl Config {
         toml::parse_toml(content)
     }
 
+    /// Parse YAML content into configuration (same keys as TOML).
+    pub(super) fn parse_yaml(content: &str) -> Result<Self, ConfigError> {
+        yaml::parse_yaml(content)
+    }
+
     /// Apply environment variables.
     fn apply_env(&mut self) {
         env::apply_env(self);
@@ -242,15 +665,43 @@ impl Config {
         if let Some(n) = args.agent_timeout {
             self.agent_timeout_secs = n;
         }
-        if let Some(ref path) = args.tasks_file {
-            self.files_tasks = path.clone();
+        if let Some(n) = args.merge_timeout {
+            self.merge_timeout_secs = n;
+        }
+        if let Some(n) = args.merge_retries {
+            self.merge_retries = n;
+        }
+        if let Some(n) = args.planning_timeout {
+            self.planning_timeout_secs = n;
+        }
+        if let Some(n) = args.shutdown_grace {
+            self.shutdown_grace_secs = n;
+        }
+        if let Some(n) = args.heartbeat_interval {
+            self.heartbeat_interval_secs = n;
+        }
+        if let Some(n) = args.max_concurrency {
+            self.engine_max_concurrency = n;
+        }
+        if let Some(days) = args.older_than {
+            self.worktree_preserved_max_age_days = days;
+        }
+        if let Some((first, extra)) = args.tasks_file.split_first() {
+            self.files_tasks = first.clone();
+            self.files_tasks_extra = extra.to_vec();
         }
         if let Some(ref path) = args.chat_file {
             self.files_chat = path.clone();
         }
+        if let Some(format) = args.chat_format.as_deref().and_then(ChatFormat::parse) {
+            self.files_chat_format = format;
+        }
         if let Some(ref path) = args.log_dir {
             self.files_log_dir = path.clone();
         }
+        if let Some(format) = args.log_format.as_deref().and_then(LogFormat::parse) {
+            self.files_log_format = format;
+        }
         if let Some(ref engine) = args.engine {
             if let Some(engines) = EngineType::parse_list(engine) {
                 self.engine_types = engines;
@@ -259,9 +710,51 @@ impl Config {
         if args.stub {
             self.engine_stub_mode = true;
         }
+        if let Some(rate) = args.fail_rate {
+            self.engine_stub_fail_rate = rate;
+        }
+        if let Some(seed) = args.seed {
+            self.seed = Some(seed);
+        }
+        if let Some(n) = args.engine_retries {
+            self.engine_retries = n;
+        }
         if let Some(n) = args.max_sprints {
             self.sprints_max = n;
         }
+        if args.no_pr {
+            self.sprints_create_pr = false;
+        }
+        if let Some(forge) = args.forge.as_deref().and_then(Forge::parse) {
+            self.sprints_forge = Some(forge);
+        }
+        if args.watch_tasks {
+            self.sprints_watch_tasks = true;
+        }
+        if args.ordered_merge {
+            self.sprints_ordered_merge = true;
+        }
+        if args.dry_run {
+            self.dry_run = true;
+        }
+        if args.quiet {
+            self.verbosity = Verbosity::Quiet;
+        } else if args.verbose > 0 {
+            self.verbosity = Verbosity::from_count(args.verbose);
+        }
+        if let Some(ref dir) = args.dump_prompts {
+            self.dump_prompts_dir = Some(dir.clone());
+        }
+        self.resume = args.command == Some(CliCommand::Resume);
+        if let Some(ref id) = args.run_id {
+            self.run_id = Some(id.clone());
+        }
+        if let Some(ref url) = args.webhook_url {
+            self.webhook_url = Some(url.clone());
+        }
+        if let Some(ref path) = args.event_socket {
+            self.event_socket = Some(path.clone());
+        }
         if let Some(ref project) = args.project {
             self.project = Some(project.clone());
         }
@@ -281,7 +774,7 @@ impl Config {
 
     fn resolve_run_branches(&mut self, cli_args: &CliArgs) -> Result<(), ConfigError> {
         let command = cli_args.command.clone().unwrap_or(CliCommand::Run);
-        if command != CliCommand::Run {
+        if !matches!(command, CliCommand::Run | CliCommand::Resume) {
             return Ok(());
         }
 
@@ -315,13 +808,60 @@ impl Config {
     fn merge_from(&mut self, other: &Self) {
         self.agents_max_count = other.agents_max_count;
         self.agents_tasks_per_agent = other.agents_tasks_per_agent;
+        self.agents_tasks_per_priority = other.agents_tasks_per_priority.clone();
+        self.agents_points_per_agent = other.agents_points_per_agent;
         self.agent_timeout_secs = other.agent_timeout_secs;
+        self.merge_timeout_secs = other.merge_timeout_secs;
+        self.merge_retries = other.merge_retries;
+        self.planning_timeout_secs = other.planning_timeout_secs;
+        self.shutdown_grace_secs = other.shutdown_grace_secs;
+        self.heartbeat_interval_secs = other.heartbeat_interval_secs;
+        self.agent_task_order = other.agent_task_order;
+        self.agents_on_no_agents = other.agents_on_no_agents;
+        self.agents_no_agents_wait_secs = other.agents_no_agents_wait_secs;
+        self.agents_keep_branches = other.agents_keep_branches;
         self.files_tasks = other.files_tasks.clone();
+        self.files_tasks_extra = other.files_tasks_extra.clone();
         self.files_chat = other.files_chat.clone();
         self.files_log_dir = other.files_log_dir.clone();
+        self.files_log_output_max_bytes = other.files_log_output_max_bytes;
+        self.files_log_format = other.files_log_format;
+        self.files_log_max_rotations = other.files_log_max_rotations;
+        self.files_tasks_completion_style = other.files_tasks_completion_style;
+        self.files_chat_format = other.files_chat_format;
+        self.files_chat_timezone = other.files_chat_timezone;
+        self.files_chat_time_format = other.files_chat_time_format.clone();
         self.engine_types = other.engine_types.clone();
         self.engine_stub_mode = other.engine_stub_mode;
+        self.engine_max_rpm = other.engine_max_rpm;
+        self.engine_max_concurrency = other.engine_max_concurrency;
+        self.engine_stub_fail_rate = other.engine_stub_fail_rate;
+        self.seed = other.seed;
+        self.engine_retries = other.engine_retries;
+        self.engine_retry_delay_secs = other.engine_retry_delay_secs;
+        self.engine_ollama_url = other.engine_ollama_url.clone();
+        self.engine_command_program = other.engine_command_program.clone();
+        self.engine_command_args = other.engine_command_args.clone();
         self.sprints_max = other.sprints_max;
+        self.sprints_track_interrupted = other.sprints_track_interrupted;
+        self.sprints_fetch_before_merge = other.sprints_fetch_before_merge;
+        self.sprints_create_pr = other.sprints_create_pr;
+        self.sprints_forge = other.sprints_forge;
+        self.sprints_max_retries = other.sprints_max_retries;
+        self.sprints_fast_path_merge = other.sprints_fast_path_merge;
+        self.sprints_ordered_merge = other.sprints_ordered_merge;
+        self.sprints_watch_tasks = other.sprints_watch_tasks;
+        self.sprints_watch_interval_secs = other.sprints_watch_interval_secs;
+        self.run_hash_len = other.run_hash_len;
+        self.branch_prefix = other.branch_prefix.clone();
+        self.worktree_sparse_paths = other.worktree_sparse_paths.clone();
+        self.worktree_preserved_max_age_days = other.worktree_preserved_max_age_days;
+        self.audit_syslog = other.audit_syslog;
+        self.audit_syslog_facility = other.audit_syslog_facility;
+        self.webhook_url = other.webhook_url.clone();
+        self.sprint_webhook_url = other.sprint_webhook_url.clone();
+        self.slack_webhook_url = other.slack_webhook_url.clone();
+        self.profiles = other.profiles.clone();
         self.source_branch = other.source_branch.clone();
         self.target_branch = other.target_branch.clone();
         self.target_branch_explicit = other.target_branch_explicit;
@@ -335,22 +875,115 @@ impl Config {
 [agents]
 max_count = 3
 tasks_per_agent = 2
-timeout = {}  # seconds (60 minutes)
+points_per_agent = {}  # story-point budget per agent for (3pts)-estimated tasks (0 = count only)
+timeout = {timeout}  # seconds (60 minutes)
+merge_timeout = {timeout}  # seconds, for merge-agent runs
+merge_retries = 1  # merge-agent re-attempts when the sprint merge fails verification
+planning_timeout = {timeout}  # seconds, for planning, review, and PRD conversion
+shutdown_grace = {}  # seconds agents get to finish after Ctrl+C before being killed (0 = wait)
+heartbeat_interval = {}  # seconds between "still working" chat messages (0 = disabled)
+task_order = "priority"  # "priority" or "assignment"
+on_no_agents = "skip"  # "skip", "wait", or "error"
+no_agents_wait_timeout = {}  # seconds, used with on_no_agents = "wait"
+keep_branches = false  # keep agent branches after each sprint (remove with `swarm cleanup --branches`)
+
+# Per-priority tasks_per_agent overrides for (P<n>) tagged tasks
+# [agents.tasks_per_priority]
+# P0 = 1
+# P3 = 3
 
 [files]
 tasks = ".swarm-hug/default/tasks.md"
+# chat and log_dir accept {{run}}, {{team}}, {{sprint}}, and {{timestamp}} placeholders
 chat = ".swarm-hug/default/chat.md"
 log_dir = ".swarm-hug/default/loop"
+# log_output_max_bytes = 0  # engine output kept per log entry (0 = no truncation)
+# Agent log format: "text" (time | agent | message) or "json" (one JSON object per line)
+log_format = "text"
+log_max_rotations = 5  # gzip archives kept per rotated log (agent-A.log.1.gz, ...)
+# Completed task layout: "suffix" (- [x] task (A)) or "prefix" (- [x] (A) task)
+tasks_completion_style = "suffix"
+# Chat log format: "markdown" (time | agent | message) or "jsonl" (one JSON object per line)
+chat_format = "markdown"
+# Chat timestamps: "local", "UTC", an offset ("+05:30"), or an IANA zone ("America/New_York")
+chat_timezone = "local"
+chat_time_format = "%Y-%m-%d %H:%M:%S"  # strftime format
 
 [engine]
 type = "claude"
 stub_mode = false
+max_rpm = 0  # engine calls per minute (0 = unlimited)
+# max_concurrency = 4  # engine calls running at once (default: CPU count, 0 = unlimited)
+stub_fail_rate = 0.0  # fraction of stub tasks that fail (chaos testing)
+# seed = 42  # make engine selection and stub failures reproducible
+retries = 2  # retries for transient failures (rate limits, network errors)
+retry_delay = {}  # seconds before the first retry, doubling each time
+# Ollama server for ollama:<model> engines
+# ollama_url = "http://localhost:11434"
+
+# Program for the "command" engine; {{task}}, {{agent}}, and {{dir}} in args
+# are replaced per task
+# [engine.command]
+# program = "./scripts/my-agent.sh"
+# args = ["--task", "{{task}}", "--agent", "{{agent}}", "--dir", "{{dir}}"]
 
 [sprints]
 max = 0
+track_interrupted = false
+fetch_before_merge = false  # fetch and fast-forward the target branch before merging
+# Open a pull request after pushing the target branch
+create_pr = true
+# Where to open it: "github" (gh) or "gitlab" (glab); detected from the origin
+# remote host when unset
+# forge = "github"
+max_retries = 0  # re-attempts of a sprint after git/merge infrastructure errors
+# Skip the merge agent when a single agent's sprint merges into the target cleanly
+fast_path_merge = false
+# Merge agent branches in task dependency order ((blocked by #N)) within a sprint
+ordered_merge = false
+watch_tasks = false  # idle and re-check tasks.md instead of exiting when none are assignable
+watch_interval = {}  # seconds between re-checks while watching
+run_hash_len = {}  # branch hash suffix length (4-20); affects new runs only
+# Prefix for sprint and agent branch names, e.g. "swarm/"
+# branch_prefix = ""
+
+# Check out only these directories in agent worktrees (sparse-checkout for
+# large monorepos); files at the repository root are always included
+# [worktree]
+# sparse_paths = ["services/auth"]
+# Age in days after which `swarm prune` deletes worktrees preserved after merge failures
+# preserved_max_age_days = 7
+
+[audit]
+syslog = false  # mirror sprint, merge, push, and PR events to syslog (Unix)
+# Facility: "user", "daemon", or "local0".."local7"
+syslog_facility = "user"
+
+# POST agent start/heartbeat/completion events as JSON (https:// URLs use curl)
+# [webhook]
+# url = "http://localhost:8080/swarm"
+# POST a JSON summary (counts, merge status, PR URL) after each sprint
+# sprint_url = "http://localhost:8080/swarm/sprints"
+
+# Post a summary (counts and PR URL) to Slack when a sprint finishes
+# [slack]
+# webhook_url = "https://hooks.slack.com/services/T000/B000/XXXX"
+
+# Named overrides selected with --profile <name> (or SWARM_PROFILE)
+# [profile.ci]
+# engine.type = "codex"
+# agents.max_count = 8
+# agents.timeout = 1800
 
 "#,
-            DEFAULT_AGENT_TIMEOUT_SECS
+            DEFAULT_POINTS_PER_AGENT,
+            DEFAULT_SHUTDOWN_GRACE_SECS,
+            DEFAULT_HEARTBEAT_INTERVAL_SECS,
+            DEFAULT_NO_AGENTS_WAIT_SECS,
+            DEFAULT_ENGINE_RETRY_DELAY_SECS,
+            DEFAULT_WATCH_INTERVAL_SECS,
+            DEFAULT_HASH_LEN,
+            timeout = DEFAULT_AGENT_TIMEOUT_SECS,
         )
     }
 
@@ -399,7 +1032,88 @@ max = 0
     }
 
     fn validate(&self) -> Result<(), ConfigError> {
-        self.validate_openrouter()
+        match self.validation_errors().into_iter().next() {
+            Some((_, err)) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Every failed validation check, keyed by the config setting it concerns.
+    pub(super) fn validation_errors(&self) -> Vec<(&'static str, ConfigError)> {
+        [
+            ("sprints.run_hash_len", self.validate_run_hash_len()),
+            ("sprints.branch_prefix", self.validate_branch_prefix()),
+            ("files.tasks", self.validate_tasks_extra()),
+            ("engine.stub_fail_rate", self.validate_stub_fail_rate()),
+            ("engine.type", self.validate_openrouter()),
+            ("engine.type", self.validate_ollama()),
+            ("engine.command.program", self.validate_command()),
+        ]
+        .into_iter()
+        .filter_map(|(key, result)| result.err().map(|err| (key, err)))
+        .collect()
+    }
+
+    fn validate_stub_fail_rate(&self) -> Result<(), ConfigError> {
+        if (0.0..=1.0).contains(&self.engine_stub_fail_rate) {
+            Ok(())
+        } else {
+            Err(ConfigError::Validation(format!(
+                "stub_fail_rate must be between 0.0 and 1.0 (got {})",
+                self.engine_stub_fail_rate
+            )))
+        }
+    }
+
+    fn validate_run_hash_len(&self) -> Result<(), ConfigError> {
+        if (MIN_HASH_LEN..=MAX_HASH_LEN).contains(&self.run_hash_len) {
+            Ok(())
+        } else {
+            Err(ConfigError::Validation(format!(
+                "run_hash_len must be between {} and {} (got {})",
+                MIN_HASH_LEN, MAX_HASH_LEN, self.run_hash_len
+            )))
+        }
+    }
+
+    /// Reject prefixes that cannot start a git ref name (see `git check-ref-format`).
+    fn validate_branch_prefix(&self) -> Result<(), ConfigError> {
+        let prefix = &self.branch_prefix;
+        let legal_chars = prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+        let legal = legal_chars
+            && !prefix.starts_with(['/', '.', '-'])
+            && !prefix.contains("..")
+            && !prefix.contains("//")
+            && !prefix.contains("/.")
+            && !prefix.contains(".lock/");
+        if legal {
+            Ok(())
+        } else {
+            Err(ConfigError::Validation(format!(
+                "branch_prefix '{}' is not a valid git branch name prefix",
+                prefix
+            )))
+        }
+    }
+
+    /// Extra `--tasks-file` paths are resolved under a run's runtime namespace,
+    /// so they must stay relative to the repository.
+    fn validate_tasks_extra(&self) -> Result<(), ConfigError> {
+        for extra in &self.files_tasks_extra {
+            let path = Path::new(extra);
+            let escapes = path
+                .components()
+                .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir));
+            if extra.is_empty() || escapes {
+                return Err(ConfigError::Validation(format!(
+                    "--tasks-file '{}' must be a relative path inside the repository",
+                    extra
+                )));
+            }
+        }
+        Ok(())
     }
 
     fn validate_openrouter(&self) -> Result<(), ConfigError> {
@@ -427,6 +1141,43 @@ max = 0
             Ok(())
         }
     }
+
+    /// Fill `command` engines with the `[engine.command]` program and args.
+    pub(super) fn resolve_command_engines(&mut self) {
+        for engine in &mut self.engine_types {
+            if let EngineType::Command { program, args } = engine {
+                if program.is_empty() {
+                    program.clone_from(&self.engine_command_program);
+                    args.clone_from(&self.engine_command_args);
+                }
+            }
+        }
+    }
+
+    fn validate_command(&self) -> Result<(), ConfigError> {
+        let missing_program = self.engine_types.iter().any(
+            |engine| matches!(engine, EngineType::Command { program, .. } if program.trim().is_empty()),
+        );
+        if missing_program {
+            return Err(ConfigError::Validation(
+                "command engine requires a program under [engine.command] in swarm.toml"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_ollama(&self) -> Result<(), ConfigError> {
+        let missing_model = self.engine_types.iter().any(
+            |engine| matches!(engine, EngineType::Ollama { model } if model.trim().is_empty()),
+        );
+        if missing_model {
+            return Err(ConfigError::Validation(
+                "ollama engine requires a model (e.g., ollama:llama3)".to_string(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -494,6 +1245,16 @@ impl std::fmt::Display for ConfigError {
     }
 }
 
+impl ConfigError {
+    /// Name the config file format in a parse error.
+    pub(super) fn in_format(self, format: &str) -> Self {
+        match self {
+            Self::Parse(msg) => Self::Parse(format!("{} (in {} config)", msg, format)),
+            other => other,
+        }
+    }
+}
+
 impl std::error::Error for ConfigError {}
 
 #[cfg(test)]
@@ -0,0 +1,229 @@
+use std::fs;
+use std::path::Path;
+
+use super::toml::apply_key;
+use super::types::{Config, ConfigError};
+
+/// Whether `path` names a YAML config file (`.yaml` or `.yml`).
+pub(super) fn is_yaml_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"))
+}
+
+pub(super) fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Config, ConfigError> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path).map_err(|e| {
+        ConfigError::Io(format!(
+            "failed to read YAML config {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    Config::parse_yaml(&content)
+}
+
+/// Parse YAML config with the same keys as swarm.toml.
+///
+/// Sections are nested mappings (`agents:` then indented `max_count: 4`), so
+/// `engine: {command: {program: ..}}` maps to TOML's `[engine.command]`.
+/// Supports the subset swarm.toml needs: mappings, scalars, comments, and
+/// string lists in block (`- item`) or flow (`[a, b]`) style.
+pub(super) fn parse_yaml(content: &str) -> Result<Config, ConfigError> {
+    let mut config = Config::default();
+    for (key, value) in flatten(content)? {
+        apply_key(&mut config, &key, &value).map_err(|e| e.in_format("YAML"))?;
+    }
+    Ok(config)
+}
+
+/// A key whose value is on the following lines: a nested mapping or a list.
+struct OpenKey {
+    key: String,
+    indent: usize,
+    items: Vec<String>,
+}
+
+/// Flatten nested mappings into dotted `section.key` entries with TOML-style values.
+pub(super) fn flatten(content: &str) -> Result<Vec<(String, String)>, ConfigError> {
+    let mut entries = Vec::new();
+    // Enclosing mapping keys as (indent, name)
+    let mut parents: Vec<(usize, String)> = Vec::new();
+    let mut open: Option<OpenKey> = None;
+
+    for (idx, raw) in content.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = strip_comment(raw);
+        let text = line.trim();
+        if text.is_empty() || text == "---" {
+            continue;
+        }
+        let leading = &line[..line.len() - line.trim_start().len()];
+        if leading.contains('\t') {
+            return Err(yaml_error(line_no, "tabs are not allowed in indentation"));
+        }
+        let indent = leading.len();
+
+        if let Some(item) = text
+            .strip_prefix('-')
+            .filter(|rest| rest.is_empty() || rest.starts_with(' '))
+        {
+            match open.as_mut() {
+                Some(list) if indent >= list.indent => {
+                    list.items.push(quote_scalar(item.trim(), line_no)?);
+                }
+                _ => return Err(yaml_error(line_no, "list item without a key")),
+            }
+            continue;
+        }
+
+        if let Some(list) = open.take() {
+            if !list.items.is_empty() {
+                entries.push((list.key, format!("[{}]", list.items.join(", "))));
+            }
+        }
+        while parents.last().is_some_and(|(i, _)| *i >= indent) {
+            parents.pop();
+        }
+
+        let (key, value) = split_key_value(text).ok_or_else(|| {
+            yaml_error(line_no, &format!("expected `key: value`, got `{}`", text))
+        })?;
+        let full_key = parents
+            .iter()
+            .map(|(_, name)| name.as_str())
+            .chain([key])
+            .collect::<Vec<_>>()
+            .join(".");
+
+        if value.is_empty() {
+            parents.push((indent, key.to_string()));
+            open = Some(OpenKey {
+                key: full_key,
+                indent,
+                items: Vec::new(),
+            });
+        } else if let Some(value) = scalar_value(value, line_no)? {
+            entries.push((full_key, value));
+        }
+    }
+
+    if let Some(list) = open {
+        if !list.items.is_empty() {
+            entries.push((list.key, format!("[{}]", list.items.join(", "))));
+        }
+    }
+    Ok(entries)
+}
+
+/// Split `key: value` (or `key:`) into its unquoted key and raw value.
+fn split_key_value(text: &str) -> Option<(&str, &str)> {
+    let colon = text
+        .char_indices()
+        .find(|&(i, ch)| ch == ':' && text[i + 1..].chars().next().is_none_or(|c| c == ' '))?
+        .0;
+    let key = text[..colon].trim().trim_matches(|c| c == '"' || c == '\'');
+    if key.is_empty() {
+        return None;
+    }
+    Some((key, text[colon + 1..].trim()))
+}
+
+/// Convert a YAML scalar to the TOML form `apply_key` expects; `None` for null.
+fn scalar_value(value: &str, line_no: usize) -> Result<Option<String>, ConfigError> {
+    if value == "~" || value == "null" {
+        return Ok(None);
+    }
+    if let Some(inner) = value.strip_prefix('[') {
+        let inner = inner
+            .strip_suffix(']')
+            .ok_or_else(|| yaml_error(line_no, "unterminated list"))?;
+        let items = split_flow_items(inner)
+            .into_iter()
+            .map(|item| quote_scalar(item, line_no))
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(Some(format!("[{}]", items.join(", "))));
+    }
+    if value.starts_with('{') {
+        return Err(yaml_error(
+            line_no,
+            "flow mappings ({...}) are not supported",
+        ));
+    }
+    if value.starts_with('|') || value.starts_with('>') {
+        return Err(yaml_error(
+            line_no,
+            "block scalars (| and >) are not supported",
+        ));
+    }
+    if value.starts_with('\'') {
+        return quote_scalar(value, line_no).map(Some);
+    }
+    Ok(Some(value.to_string()))
+}
+
+/// Render a scalar as a double-quoted TOML string.
+fn quote_scalar(value: &str, line_no: usize) -> Result<String, ConfigError> {
+    if value.starts_with('"') {
+        if value.len() < 2 || !value.ends_with('"') {
+            return Err(yaml_error(line_no, "unterminated string"));
+        }
+        return Ok(value.to_string());
+    }
+    let text = if let Some(inner) = value.strip_prefix('\'') {
+        inner
+            .strip_suffix('\'')
+            .ok_or_else(|| yaml_error(line_no, "unterminated string"))?
+            .replace("''", "'")
+    } else {
+        value.to_string()
+    };
+    Ok(format!(
+        "\"{}\"",
+        text.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+/// Split the inside of a flow list on commas outside quotes.
+fn split_flow_items(inner: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (i, ch) in inner.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(q), _) if ch == q => quote = None,
+            (None, ',') => {
+                items.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(inner[start..].trim());
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+/// Drop a `#` comment that starts the line or follows whitespace, outside quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    let mut prev = ' ';
+    for (i, ch) in line.char_indices() {
+        match (quote, ch) {
+            // Quotes only open a string at the start of a scalar, not in `don't`
+            (None, '"' | '\'') if prev.is_whitespace() || matches!(prev, '[' | ',') => {
+                quote = Some(ch)
+            }
+            (Some(q), _) if ch == q => quote = None,
+            (None, '#') if prev.is_whitespace() => return &line[..i],
+            _ => {}
+        }
+        prev = ch;
+    }
+    line
+}
+
+fn yaml_error(line_no: usize, message: &str) -> ConfigError {
+    ConfigError::Parse(format!("YAML line {}: {}", line_no, message))
+}
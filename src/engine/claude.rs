@@ -6,12 +6,17 @@ use std::thread;
 use std::time::Duration;
 
 use crate::config::EngineType;
+use crate::json::json_string_field;
 use crate::process::kill_process_tree;
 use crate::process_group::spawn_in_new_process_group;
-use crate::process_registry::PROCESS_REGISTRY;
+use crate::process_registry::{self, PROCESS_REGISTRY};
 use crate::shutdown;
 
-use super::util::{build_agent_prompt, output_to_result, resolve_cli_path, WAIT_LOG_INTERVAL_SECS};
+use super::usage::parse_claude_usage;
+use super::util::{
+    build_agent_prompt, drain_pipe, resolve_cli_path, run_preflight_probe, stream_lines,
+    WAIT_LOG_INTERVAL_SECS,
+};
 use super::{Engine, EngineResult};
 
 #[derive(Debug, Clone)]
@@ -77,12 +82,31 @@ impl Default for ClaudeEngine {
 
 impl Engine for ClaudeEngine {
     fn execute(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        working_dir: &Path,
+        turn_number: usize,
+        team_dir: Option<&str>,
+    ) -> EngineResult {
+        self.execute_streaming(
+            agent_name,
+            task_description,
+            working_dir,
+            turn_number,
+            team_dir,
+            &mut |_| {},
+        )
+    }
+
+    fn execute_streaming(
         &self,
         agent_name: &str,
         task_description: &str,
         working_dir: &Path,
         _turn_number: usize,
         team_dir: Option<&str>,
+        on_line: &mut dyn FnMut(&str),
     ) -> EngineResult {
         // For valid agents, wrap in agent prompt; otherwise use raw prompt
         let prompt = match build_agent_prompt(agent_name, task_description, team_dir) {
@@ -96,6 +120,9 @@ impl Engine for ClaudeEngine {
         let mut cmd = Command::new(&self.cli_path);
         cmd.arg("--dangerously-skip-permissions")
             .arg("--print")
+            .arg("--output-format")
+            .arg("stream-json") // One JSON event per line, ending with the result and usage
+            .arg("--verbose") // Required by stream-json in print mode
             .arg("-p")
             .arg("-") // Read prompt from stdin
             .current_dir(working_dir)
@@ -120,7 +147,11 @@ impl Engine for ClaudeEngine {
             Err(e) => return EngineResult::failure(format!("failed to spawn claude: {}", e), 1),
         };
         let pid = child.id();
-        PROCESS_REGISTRY.register(pid);
+        PROCESS_REGISTRY.register_agent(pid, agent_name, &process_registry::command_line(&cmd));
+
+        // Start reading before writing the prompt so output never backs up
+        let (lines, stdout_handle) = stream_lines(child.stdout.take());
+        let stderr_handle = drain_pipe(child.stderr.take());
 
         // Write prompt to stdin
         if let Some(mut stdin) = child.stdin.take() {
@@ -136,20 +167,17 @@ impl Engine for ClaudeEngine {
             None
         };
 
-        // Wait for completion, logging periodically
+        // Wait for completion, forwarding output and logging periodically
         loop {
+            lines.try_iter().for_each(|line| on_line(line.as_str()));
             match child.try_wait() {
-                Ok(Some(_status)) => match child.wait_with_output() {
-                    Ok(output) => {
-                        let result = output_to_result(output);
-                        PROCESS_REGISTRY.unregister(pid);
-                        return result;
-                    }
-                    Err(e) => {
-                        PROCESS_REGISTRY.unregister(pid);
-                        return EngineResult::failure(format!("failed to get output: {}", e), 1);
-                    }
-                },
+                Ok(Some(status)) => {
+                    let stdout = stdout_handle.join().unwrap_or_default();
+                    let stderr = stderr_handle.join().unwrap_or_default();
+                    lines.try_iter().for_each(|line| on_line(line.as_str()));
+                    PROCESS_REGISTRY.unregister(pid);
+                    return claude_result(stdout, stderr, status.code().unwrap_or(1));
+                }
                 Ok(None) => {
                     // Process still running
                     let elapsed = start.elapsed();
@@ -211,6 +239,14 @@ impl Engine for ClaudeEngine {
             None => EngineType::Claude,
         }
     }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    fn preflight(&self) -> Result<(), String> {
+        run_preflight_probe(&self.cli_path, &["--version"])
+    }
 }
 
 impl ClaudeEngine {
@@ -257,9 +293,47 @@ impl ClaudeEngine {
     }
 }
 
+/// Convert claude's `stream-json` output into an engine result.
+///
+/// The final `result` event's text becomes the output and its `usage` is
+/// recorded. Output without a result event falls back to plain-text handling.
+fn claude_result(stdout: String, stderr: String, exit_code: i32) -> EngineResult {
+    let result_event = stdout
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| line.starts_with('{') && line.contains("\"type\":\"result\""));
+    let Some((event, text)) =
+        result_event.and_then(|event| Some((event, json_string_field(event, "result")?)))
+    else {
+        return if exit_code == 0 {
+            EngineResult::success(stdout)
+        } else {
+            EngineResult::failure(stderr, exit_code)
+        };
+    };
+
+    let result = if exit_code == 0 {
+        EngineResult::success(text)
+    } else {
+        let stderr = stderr.trim();
+        let error = if stderr.is_empty() {
+            text
+        } else {
+            stderr.to_string()
+        };
+        EngineResult::failure(error, exit_code)
+    };
+    match parse_claude_usage(event) {
+        Some(usage) => result.with_usage(usage),
+        None => result,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::TokenUsage;
     #[cfg(unix)]
     use crate::testutil::{EnvVarGuard, ENV_LOCK};
     #[cfg(unix)]
@@ -390,6 +464,92 @@ mod tests {
         assert_eq!(std::env::var("ANTHROPIC_MODEL").ok(), before_model);
     }
 
+    fn result_of(code: i32, stdout: &str, stderr: &str) -> EngineResult {
+        claude_result(stdout.to_string(), stderr.to_string(), code)
+    }
+
+    #[test]
+    fn test_claude_result_parses_stream_result_event_and_usage() {
+        let stdout = concat!(
+            r#"{"type":"system","subtype":"init","session_id":"abc","tools":["Bash"]}"#,
+            "\n",
+            r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Working"}]}}"#,
+            "\n",
+            r#"{"type":"result","subtype":"success","is_error":false,"duration_ms":1200,"result":"Done.\nSWARM_SUMMARY: added tests","session_id":"abc","total_cost_usd":0.0123,"usage":{"input_tokens":8,"cache_creation_input_tokens":1000,"cache_read_input_tokens":2000,"output_tokens":150}}"#,
+            "\n",
+        );
+        let result = result_of(0, stdout, "");
+
+        assert!(result.success);
+        assert_eq!(result.output, "Done.\nSWARM_SUMMARY: added tests");
+        assert_eq!(result.summary().as_deref(), Some("added tests"));
+        assert_eq!(result.usage, Some(TokenUsage::new(3008, 150)));
+    }
+
+    #[test]
+    fn test_claude_result_reports_json_errors() {
+        let stdout = r#"{"type":"result","subtype":"error_during_execution","is_error":true,"result":"API Error: 529 Overloaded","usage":{"input_tokens":5,"output_tokens":0}}"#;
+        let result = result_of(1, stdout, "");
+
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 1);
+        assert_eq!(result.error.as_deref(), Some("API Error: 529 Overloaded"));
+        assert_eq!(result.usage, Some(TokenUsage::new(5, 0)));
+    }
+
+    #[test]
+    fn test_claude_result_falls_back_to_plain_text() {
+        let result = result_of(0, "plain output\n", "");
+        assert!(result.success);
+        assert_eq!(result.output, "plain output\n");
+        assert_eq!(result.usage, None);
+
+        let result = result_of(2, "", "boom");
+        assert!(!result.success);
+        assert_eq!(result.error.as_deref(), Some("boom"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_claude_engine_streams_lines_as_they_arrive() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+        use std::time::Instant;
+
+        let _guard = crate::shutdown::test_lock();
+        crate::shutdown::reset();
+
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let script_path = temp.path().join("fake-claude.sh");
+        fs::write(
+            &script_path,
+            "#!/bin/sh\ncat >/dev/null\necho first\nsleep 1\necho second\n",
+        )
+        .expect("write script");
+        let mut perms = fs::metadata(&script_path).expect("metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).expect("chmod");
+
+        let engine = ClaudeEngine::with_path(script_path.to_string_lossy().to_string());
+        let mut seen: Vec<(String, Instant)> = Vec::new();
+        let result =
+            engine.execute_streaming("ScrumMaster", "stream", temp.path(), 0, None, &mut |line| {
+                seen.push((line.to_string(), Instant::now()))
+            });
+
+        assert!(result.success, "unexpected result: {:?}", result);
+        assert_eq!(result.output, "first\nsecond\n");
+        let lines: Vec<&str> = seen.iter().map(|(line, _)| line.as_str()).collect();
+        assert_eq!(lines, vec!["first", "second"]);
+        // "first" must be delivered while the script is still sleeping
+        let gap = seen[1].1.duration_since(seen[0].1);
+        assert!(
+            gap >= Duration::from_millis(500),
+            "lines arrived together: {:?}",
+            gap
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_claude_engine_shutdown_requested() {
@@ -2,16 +2,20 @@ use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 
 use crate::config::EngineType;
 use crate::process::kill_process_tree;
 use crate::process_group::spawn_in_new_process_group;
-use crate::process_registry::PROCESS_REGISTRY;
+use crate::process_registry::{self, PROCESS_REGISTRY};
 use crate::shutdown;
 
-use super::util::{build_agent_prompt, resolve_cli_path, WAIT_LOG_INTERVAL_SECS};
+use super::usage::parse_codex_usage;
+use super::util::{
+    build_agent_prompt, resolve_cli_path, run_preflight_probe, WAIT_LOG_INTERVAL_SECS,
+};
 use super::{Engine, EngineResult};
 
 /// Codex CLI engine.
@@ -59,12 +63,31 @@ impl Default for CodexEngine {
 
 impl Engine for CodexEngine {
     fn execute(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        working_dir: &Path,
+        turn_number: usize,
+        team_dir: Option<&str>,
+    ) -> EngineResult {
+        self.execute_streaming(
+            agent_name,
+            task_description,
+            working_dir,
+            turn_number,
+            team_dir,
+            &mut |_| {},
+        )
+    }
+
+    fn execute_streaming(
         &self,
         agent_name: &str,
         task_description: &str,
         working_dir: &Path,
         _turn_number: usize,
         team_dir: Option<&str>,
+        on_line: &mut dyn FnMut(&str),
     ) -> EngineResult {
         // For valid agents, wrap in agent prompt; otherwise use raw prompt
         let prompt = match build_agent_prompt(agent_name, task_description, team_dir) {
@@ -114,7 +137,7 @@ impl Engine for CodexEngine {
             Err(e) => return EngineResult::failure(format!("failed to spawn codex: {}", e), 1),
         };
         let pid = child.id();
-        PROCESS_REGISTRY.register(pid);
+        PROCESS_REGISTRY.register_agent(pid, agent_name, &process_registry::command_line(&cmd));
 
         // Write prompt to stdin
         if let Some(mut stdin) = child.stdin.take() {
@@ -125,7 +148,9 @@ impl Engine for CodexEngine {
         let stdout = child.stdout.take();
         let stderr = child.stderr.take();
 
-        // Spawn thread to stream stdout to both debug file and buffer
+        // Spawn thread to stream stdout to the debug file, the buffer, and
+        // the caller's line callback
+        let (line_tx, lines) = mpsc::channel::<String>();
         let stdout_handle = thread::spawn(move || {
             let mut output = String::new();
             if let Some(stdout) = stdout {
@@ -142,6 +167,7 @@ impl Engine for CodexEngine {
                             // Accumulate for result
                             output.push_str(&line);
                             output.push('\n');
+                            let _ = line_tx.send(line);
                         }
                         Err(_) => break,
                     }
@@ -177,22 +203,29 @@ impl Engine for CodexEngine {
             None
         };
 
-        // Wait for completion, logging periodically
+        // Wait for completion, forwarding output and logging periodically
         loop {
+            lines.try_iter().for_each(|line| on_line(line.as_str()));
             match child.try_wait() {
                 Ok(Some(status)) => {
                     // Process exited, collect output from threads
                     let stdout_output = stdout_handle.join().unwrap_or_default();
                     let stderr_output = stderr_handle.join().unwrap_or_default();
+                    lines.try_iter().for_each(|line| on_line(line.as_str()));
                     let exit_code = status.code().unwrap_or(1);
                     let _ = child.wait();
                     PROCESS_REGISTRY.unregister(pid);
 
+                    let usage = parse_codex_usage(&stdout_output);
                     let result = if status.success() {
                         EngineResult::success(stdout_output)
                     } else {
                         EngineResult::failure(stderr_output, exit_code)
                     };
+                    let result = match usage {
+                        Some(usage) => result.with_usage(usage),
+                        None => result,
+                    };
                     PROCESS_REGISTRY.unregister(pid);
                     return result;
                 }
@@ -255,6 +288,15 @@ impl Engine for CodexEngine {
     fn engine_type(&self) -> EngineType {
         EngineType::Codex
     }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    /// `codex login status` exits nonzero when no credentials are stored.
+    fn preflight(&self) -> Result<(), String> {
+        run_preflight_probe(&self.cli_path, &["login", "status"])
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,316 @@
+//! Engine that runs an arbitrary program configured under `[engine.command]`.
+//!
+//! Each argument has `{task}`, `{agent}`, and `{dir}` replaced with the task
+//! description, agent name, and worktree path. Exit status 0 is success;
+//! stdout becomes the output and stderr the error on failure.
+
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use crate::config::EngineType;
+use crate::process::kill_process_tree;
+use crate::process_group::spawn_in_new_process_group;
+use crate::process_registry::{self, PROCESS_REGISTRY};
+use crate::shutdown;
+
+use super::util::{drain_pipe, resolve_cli_path, WAIT_LOG_INTERVAL_SECS};
+use super::{Engine, EngineResult};
+
+/// Generic shell-command engine.
+pub struct CommandEngine {
+    /// Program to run (a path or a name on PATH).
+    program: String,
+    /// Argument templates with `{task}`, `{agent}`, `{dir}` placeholders.
+    args: Vec<String>,
+    /// Timeout in seconds (0 = no timeout).
+    timeout_secs: u64,
+}
+
+impl CommandEngine {
+    /// Create a command engine with no timeout.
+    pub fn new(program: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            program: program.into(),
+            args,
+            timeout_secs: 0,
+        }
+    }
+
+    /// Create with timeout.
+    pub fn with_timeout(program: impl Into<String>, args: Vec<String>, timeout_secs: u64) -> Self {
+        Self {
+            timeout_secs,
+            ..Self::new(program, args)
+        }
+    }
+
+    /// Arguments with placeholders substituted for one task.
+    fn render_args(&self, agent_name: &str, task_description: &str, dir: &Path) -> Vec<String> {
+        let dir = dir.to_string_lossy();
+        self.args
+            .iter()
+            .map(|arg| {
+                arg.replace("{task}", task_description)
+                    .replace("{agent}", agent_name)
+                    .replace("{dir}", &dir)
+            })
+            .collect()
+    }
+}
+
+impl Engine for CommandEngine {
+    fn execute(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        working_dir: &Path,
+        _turn_number: usize,
+        _team_dir: Option<&str>,
+    ) -> EngineResult {
+        let mut cmd = Command::new(&self.program);
+        cmd.args(self.render_args(agent_name, task_description, working_dir))
+            .current_dir(working_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = match spawn_in_new_process_group(&mut cmd) {
+            Ok(c) => c,
+            Err(e) => {
+                return EngineResult::failure(format!("failed to spawn {}: {}", self.program, e), 1)
+            }
+        };
+        let pid = child.id();
+        PROCESS_REGISTRY.register_agent(pid, agent_name, &process_registry::command_line(&cmd));
+
+        // Drain pipes on threads so chatty programs can't block on a full pipe
+        let stdout_handle = drain_pipe(child.stdout.take());
+        let stderr_handle = drain_pipe(child.stderr.take());
+
+        let result = self.wait(agent_name, &mut child, pid);
+        let stdout = stdout_handle.join().unwrap_or_default();
+        let stderr = stderr_handle.join().unwrap_or_default();
+        PROCESS_REGISTRY.unregister(pid);
+
+        match result {
+            Ok(0) => EngineResult::success(stdout),
+            Ok(code) => {
+                let error = if stderr.trim().is_empty() {
+                    format!("{} exited with {}", self.program, code)
+                } else {
+                    stderr
+                };
+                EngineResult::failure(error, code)
+            }
+            Err(result) => result,
+        }
+    }
+
+    fn engine_type(&self) -> EngineType {
+        EngineType::Command {
+            program: self.program.clone(),
+            args: self.args.clone(),
+        }
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    fn preflight(&self) -> Result<(), String> {
+        if Path::new(&self.program).is_file() || resolve_cli_path(&self.program).is_some() {
+            Ok(())
+        } else {
+            Err(format!(
+                "command engine program not found: {}",
+                self.program
+            ))
+        }
+    }
+}
+
+impl CommandEngine {
+    /// Wait for exit, honoring shutdown and the timeout. Returns the exit code.
+    fn wait(&self, agent_name: &str, child: &mut Child, pid: u32) -> Result<i32, EngineResult> {
+        let start = std::time::Instant::now();
+        let log_interval = Duration::from_secs(WAIT_LOG_INTERVAL_SECS);
+        let mut next_log = log_interval;
+        let timeout = if self.timeout_secs > 0 {
+            Some(Duration::from_secs(self.timeout_secs))
+        } else {
+            None
+        };
+
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) => return Ok(status.code().unwrap_or(1)),
+                Ok(None) => {
+                    let elapsed = start.elapsed();
+
+                    if shutdown::requested() {
+                        kill_process_tree(pid);
+                        let _ = child.wait();
+                        return Err(EngineResult::failure("Shutdown requested", 130));
+                    }
+
+                    if let Some(timeout_duration) = timeout {
+                        if elapsed >= timeout_duration {
+                            kill_process_tree(pid);
+                            let _ = child.wait();
+                            let mins = elapsed.as_secs() / 60;
+                            return Err(EngineResult::failure(
+                                format!("agent timed out after {} minutes (pid {})", mins, pid),
+                                124, // Standard timeout exit code
+                            ));
+                        }
+                    }
+
+                    if elapsed >= next_log {
+                        eprintln!(
+                            "[{}] Still executing... ({} min elapsed, pid {})",
+                            agent_name,
+                            elapsed.as_secs() / 60,
+                            pid
+                        );
+                        next_log += log_interval;
+                    }
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => {
+                    let _ = child.wait();
+                    return Err(EngineResult::failure(
+                        format!("failed to wait for {}: {}", self.program, e),
+                        1,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> CommandEngine {
+        CommandEngine::new("sh", vec!["-c".to_string(), script.to_string()])
+    }
+
+    #[test]
+    fn test_command_engine_type() {
+        let engine = CommandEngine::new("my-agent", vec!["{task}".to_string()]);
+        assert_eq!(
+            engine.engine_type(),
+            EngineType::Command {
+                program: "my-agent".to_string(),
+                args: vec!["{task}".to_string()],
+            }
+        );
+        assert_eq!(engine.engine_type().as_str(), "command");
+    }
+
+    #[test]
+    fn test_command_engine_render_args() {
+        let engine = CommandEngine::new(
+            "my-agent",
+            vec![
+                "--task={task}".to_string(),
+                "{agent}".to_string(),
+                "{dir}/out".to_string(),
+            ],
+        );
+        assert_eq!(
+            engine.render_args("Aaron", "Fix bug", Path::new("/tmp/wt")),
+            vec!["--task=Fix bug", "Aaron", "/tmp/wt/out"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_engine_true_and_false_map_to_success_and_failure() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+
+        let result = CommandEngine::new("true", Vec::new()).execute(
+            "Aaron",
+            "task",
+            Path::new("."),
+            1,
+            None,
+        );
+        assert!(result.success, "unexpected result: {:?}", result);
+        assert_eq!(result.exit_code, 0);
+
+        let result = CommandEngine::new("false", Vec::new()).execute(
+            "Aaron",
+            "task",
+            Path::new("."),
+            1,
+            None,
+        );
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 1);
+        assert_eq!(result.error.as_deref(), Some("false exited with 1"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_engine_captures_output_and_substitutes_placeholders() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+        let temp = tempfile::TempDir::new().unwrap();
+
+        let engine = CommandEngine::new(
+            "sh",
+            vec![
+                "-c".to_string(),
+                "echo \"$1 did $2 in $(pwd)\"; echo warn >&2".to_string(),
+                "sh".to_string(),
+                "{agent}".to_string(),
+                "{task}".to_string(),
+            ],
+        );
+        let result = engine.execute("Aaron", "Write docs", temp.path(), 1, None);
+        assert!(result.success, "unexpected result: {:?}", result);
+        let dir = temp.path().canonicalize().unwrap();
+        assert_eq!(
+            result.output.trim(),
+            format!("Aaron did Write docs in {}", dir.display())
+        );
+
+        let result = sh("echo partial; echo 'disk full' >&2; exit 3").execute(
+            "Aaron",
+            "task",
+            Path::new("."),
+            1,
+            None,
+        );
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 3);
+        assert_eq!(result.error.as_deref().map(str::trim), Some("disk full"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_engine_times_out() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+
+        let engine = CommandEngine::with_timeout("sleep", vec!["5".to_string()], 1);
+        let result = engine.execute("Aaron", "task", Path::new("."), 1, None);
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 124, "unexpected result: {:?}", result);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_engine_preflight_checks_program() {
+        assert!(CommandEngine::new("sh", Vec::new()).preflight().is_ok());
+        let err = CommandEngine::new("swarm-missing-agent", Vec::new())
+            .preflight()
+            .unwrap_err();
+        assert!(err.contains("swarm-missing-agent"));
+    }
+}
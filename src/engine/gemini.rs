@@ -0,0 +1,234 @@
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use crate::config::EngineType;
+use crate::process::kill_process_tree;
+use crate::process_group::spawn_in_new_process_group;
+use crate::process_registry::{self, PROCESS_REGISTRY};
+use crate::shutdown;
+
+use super::util::{
+    build_agent_prompt, output_to_result, resolve_cli_path, run_preflight_probe,
+    WAIT_LOG_INTERVAL_SECS,
+};
+use super::{Engine, EngineResult};
+
+/// Gemini CLI engine.
+pub struct GeminiEngine {
+    /// Path to gemini CLI binary.
+    cli_path: String,
+    /// Timeout in seconds (0 = no timeout).
+    timeout_secs: u64,
+}
+
+impl GeminiEngine {
+    /// Create a new Gemini engine with default timeout.
+    /// Resolves the full path to gemini using `which` for better portability.
+    pub fn new() -> Self {
+        let cli_path = resolve_cli_path("gemini").unwrap_or_else(|| "gemini".to_string());
+        Self {
+            cli_path,
+            timeout_secs: 0,
+        }
+    }
+
+    /// Create with custom CLI path.
+    pub fn with_path(cli_path: impl Into<String>) -> Self {
+        Self {
+            cli_path: cli_path.into(),
+            timeout_secs: 0,
+        }
+    }
+
+    /// Create with timeout.
+    pub fn with_timeout(timeout_secs: u64) -> Self {
+        let cli_path = resolve_cli_path("gemini").unwrap_or_else(|| "gemini".to_string());
+        Self {
+            cli_path,
+            timeout_secs,
+        }
+    }
+}
+
+impl Default for GeminiEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Engine for GeminiEngine {
+    fn execute(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        working_dir: &Path,
+        _turn_number: usize,
+        team_dir: Option<&str>,
+    ) -> EngineResult {
+        // For valid agents, wrap in agent prompt; otherwise use raw prompt
+        let prompt = match build_agent_prompt(agent_name, task_description, team_dir) {
+            Ok(Some(p)) => p,
+            Ok(None) => task_description.to_string(), // Non-agent (e.g., ScrumMaster)
+            Err(e) => return EngineResult::failure(e, 1),
+        };
+
+        // Gemini runs non-interactively when the prompt arrives on stdin;
+        // --yolo auto-approves tool calls like Claude's skip-permissions flag
+        let mut cmd = Command::new(&self.cli_path);
+        cmd.arg("--yolo")
+            .current_dir(working_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = match spawn_in_new_process_group(&mut cmd) {
+            Ok(c) => c,
+            Err(e) => return EngineResult::failure(format!("failed to spawn gemini: {}", e), 1),
+        };
+        let pid = child.id();
+        PROCESS_REGISTRY.register_agent(pid, agent_name, &process_registry::command_line(&cmd));
+
+        // Write prompt to stdin, then close it so gemini starts
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(prompt.as_bytes());
+        }
+
+        let start = std::time::Instant::now();
+        let log_interval = Duration::from_secs(WAIT_LOG_INTERVAL_SECS);
+        let mut next_log = log_interval;
+        let timeout = if self.timeout_secs > 0 {
+            Some(Duration::from_secs(self.timeout_secs))
+        } else {
+            None
+        };
+
+        // Wait for completion, logging periodically
+        loop {
+            match child.try_wait() {
+                Ok(Some(_status)) => match child.wait_with_output() {
+                    Ok(output) => {
+                        let result = output_to_result(output);
+                        PROCESS_REGISTRY.unregister(pid);
+                        return result;
+                    }
+                    Err(e) => {
+                        PROCESS_REGISTRY.unregister(pid);
+                        return EngineResult::failure(format!("failed to get output: {}", e), 1);
+                    }
+                },
+                Ok(None) => {
+                    let elapsed = start.elapsed();
+
+                    if shutdown::requested() {
+                        kill_process_tree(pid);
+                        let _ = child.wait();
+                        PROCESS_REGISTRY.unregister(pid);
+                        return EngineResult::failure("Shutdown requested", 130);
+                    }
+
+                    // Check for timeout
+                    if let Some(timeout_duration) = timeout {
+                        if elapsed >= timeout_duration {
+                            kill_process_tree(pid);
+                            let _ = child.wait();
+                            PROCESS_REGISTRY.unregister(pid);
+                            let mins = elapsed.as_secs() / 60;
+                            return EngineResult::failure(
+                                format!("agent timed out after {} minutes (pid {})", mins, pid),
+                                124, // Standard timeout exit code
+                            );
+                        }
+                    }
+
+                    if elapsed >= next_log {
+                        let mins = elapsed.as_secs() / 60;
+                        let timeout_msg = if let Some(t) = timeout {
+                            format!(
+                                ", timeout in {} min",
+                                (t.as_secs() - elapsed.as_secs()) / 60
+                            )
+                        } else {
+                            String::new()
+                        };
+                        eprintln!(
+                            "[{}] Still executing... ({} min elapsed, pid {}{})",
+                            agent_name, mins, pid, timeout_msg
+                        );
+                        next_log += log_interval;
+                    }
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => {
+                    let _ = child.wait();
+                    PROCESS_REGISTRY.unregister(pid);
+                    return EngineResult::failure(format!("failed to wait for gemini: {}", e), 1);
+                }
+            }
+        }
+    }
+
+    fn engine_type(&self) -> EngineType {
+        EngineType::Gemini
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    fn preflight(&self) -> Result<(), String> {
+        run_preflight_probe(&self.cli_path, &["--version"])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gemini_engine_type() {
+        let engine = GeminiEngine::new();
+        assert_eq!(engine.engine_type(), EngineType::Gemini);
+    }
+
+    #[test]
+    fn test_gemini_engine_with_timeout() {
+        let engine = GeminiEngine::with_timeout(1800);
+        assert_eq!(engine.timeout_secs, 1800);
+        assert_eq!(engine.engine_type(), EngineType::Gemini);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_gemini_engine_times_out() {
+        use std::fs;
+        use std::fs::File;
+        use std::os::unix::fs::PermissionsExt;
+
+        use tempfile::TempDir;
+
+        let _guard = crate::shutdown::test_lock();
+        crate::shutdown::reset();
+
+        let temp = TempDir::new().expect("temp dir");
+        let script_path = temp.path().join("fake-gemini.sh");
+        let mut file = File::create(&script_path).expect("create script");
+        writeln!(file, "#!/bin/sh").expect("write shebang");
+        writeln!(file, "cat >/dev/null").expect("write stdin drain");
+        writeln!(file, "sleep 5").expect("write sleep");
+        drop(file);
+
+        let mut perms = fs::metadata(&script_path).expect("metadata").permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).expect("chmod");
+
+        let mut engine = GeminiEngine::with_path(script_path.to_string_lossy().to_string());
+        engine.timeout_secs = 1;
+        let result = engine.execute("ScrumMaster", "test timeout", temp.path(), 0, None);
+
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 124, "unexpected result: {:?}", result);
+    }
+}
@@ -3,28 +3,49 @@
 //! Supports multiple backends:
 //! - `claude`: Claude CLI
 //! - `codex`: Codex CLI
+//! - `command`: Arbitrary program from `[engine.command]`
+//! - `gemini`: Gemini CLI
+//! - `ollama:<model>`: Local model via the Ollama HTTP API
 //! - `openrouter_<model>`: Claude CLI via OpenRouter
 //! - `stub`: Deterministic stub for tests (no network)
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::path::Path;
 use std::sync::Arc;
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
 use crate::config::EngineType;
 
 mod claude;
 mod codex;
+mod command;
+mod gemini;
+mod ollama;
+mod retry;
 mod stub;
+mod usage;
 mod util;
 
 pub use claude::ClaudeEngine;
 pub use codex::CodexEngine;
+pub use command::CommandEngine;
+pub use gemini::GeminiEngine;
+pub use ollama::{set_ollama_url, OllamaEngine, DEFAULT_OLLAMA_URL};
+pub use retry::{is_transient_failure, RetryingEngine};
 pub use stub::StubEngine;
+pub use usage::TokenUsage;
 
 /// Get the configured co-author line for commit messages.
 pub(crate) fn coauthor_line() -> String {
     util::generate_coauthor_line()
 }
 
+/// Line prefix agents use to report a human-facing summary of their work.
+pub const SUMMARY_SENTINEL: &str = "SWARM_SUMMARY:";
+
 /// Result of engine execution.
 #[derive(Debug)]
 pub struct EngineResult {
@@ -36,6 +57,8 @@ pub struct EngineResult {
     pub error: Option<String>,
     /// Exit code (0 for stub success).
     pub exit_code: i32,
+    /// Tokens consumed, when the engine reports them.
+    pub usage: Option<TokenUsage>,
 }
 
 impl EngineResult {
@@ -46,9 +69,29 @@ impl EngineResult {
             output: output.into(),
             error: None,
             exit_code: 0,
+            usage: None,
         }
     }
 
+    /// Attach token usage to this result.
+    pub fn with_usage(mut self, usage: TokenUsage) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    /// The agent's `SWARM_SUMMARY: ...` note, if the output contains one.
+    ///
+    /// The last non-empty summary line wins.
+    pub fn summary(&self) -> Option<String> {
+        self.output
+            .lines()
+            .rev()
+            .filter_map(|line| line.trim().strip_prefix(SUMMARY_SENTINEL))
+            .map(str::trim)
+            .find(|summary| !summary.is_empty())
+            .map(ToString::to_string)
+    }
+
     /// Create a failed result.
     pub fn failure(error: impl Into<String>, exit_code: i32) -> Self {
         Self {
@@ -56,6 +99,7 @@ impl EngineResult {
             output: String::new(),
             error: Some(error.into()),
             exit_code,
+            usage: None,
         }
     }
 }
@@ -79,8 +123,45 @@ pub trait Engine: Send + Sync {
         team_dir: Option<&str>,
     ) -> EngineResult;
 
+    /// Like [`Engine::execute`], but passes each stdout line to `on_line` as
+    /// the engine produces it, so long tasks can be followed in the agent log.
+    ///
+    /// The default runs `execute` and replays its output once it finishes.
+    fn execute_streaming(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        working_dir: &Path,
+        turn_number: usize,
+        team_dir: Option<&str>,
+        on_line: &mut dyn FnMut(&str),
+    ) -> EngineResult {
+        let result = self.execute(
+            agent_name,
+            task_description,
+            working_dir,
+            turn_number,
+            team_dir,
+        );
+        for line in result.output.lines() {
+            on_line(line);
+        }
+        result
+    }
+
     /// Get the engine type.
     fn engine_type(&self) -> EngineType;
+
+    /// Execution timeout in seconds (0 = no timeout).
+    fn timeout_secs(&self) -> u64 {
+        0
+    }
+
+    /// Cheap check that the backend is installed and authenticated, run once
+    /// before the first sprint. Engines without a probe always pass.
+    fn preflight(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// Create an engine from config.
@@ -93,9 +174,14 @@ pub fn create_engine(
     match engine_type {
         EngineType::Claude => Arc::new(ClaudeEngine::with_timeout(timeout_secs)),
         EngineType::Codex => Arc::new(CodexEngine::with_timeout(timeout_secs)),
+        EngineType::Gemini => Arc::new(GeminiEngine::with_timeout(timeout_secs)),
         EngineType::OpenRouter { model } => {
             Arc::new(ClaudeEngine::with_timeout(timeout_secs).with_openrouter_model(model))
         }
+        EngineType::Ollama { model } => Arc::new(OllamaEngine::with_timeout(model, timeout_secs)),
+        EngineType::Command { program, args } => {
+            Arc::new(CommandEngine::with_timeout(program, args, timeout_secs))
+        }
         EngineType::Stub => Arc::new(StubEngine::new(output_dir)),
     }
 }
@@ -151,6 +237,15 @@ pub fn create_random_engine(
 /// assert_eq!(selected, EngineType::Stub);
 /// ```
 pub fn select_engine_type(engine_types: &[EngineType], stub_mode: bool) -> EngineType {
+    select_engine_type_with_rng(engine_types, stub_mode, &mut rand::thread_rng())
+}
+
+/// Like [`select_engine_type`], drawing from `rng` so seeded runs repeat.
+pub fn select_engine_type_with_rng<R: Rng + ?Sized>(
+    engine_types: &[EngineType],
+    stub_mode: bool,
+    rng: &mut R,
+) -> EngineType {
     if stub_mode {
         EngineType::Stub
     } else if engine_types.is_empty() {
@@ -159,10 +254,22 @@ pub fn select_engine_type(engine_types: &[EngineType], stub_mode: bool) -> Engin
         engine_types[0].clone()
     } else {
         use rand::seq::SliceRandom;
-        engine_types
-            .choose(&mut rand::thread_rng())
-            .cloned()
-            .unwrap()
+        engine_types.choose(rng).cloned().unwrap()
+    }
+}
+
+/// Random source for one agent's engine selections in a sprint.
+///
+/// With a seed, the stream depends only on `(seed, agent, sprint)`, so
+/// identical runs select identical engines regardless of thread scheduling.
+pub fn selection_rng(seed: Option<u64>, agent_initial: char, sprint_number: usize) -> StdRng {
+    match seed {
+        Some(seed) => {
+            let mut hasher = DefaultHasher::new();
+            (seed, agent_initial, sprint_number).hash(&mut hasher);
+            StdRng::seed_from_u64(hasher.finish())
+        }
+        None => StdRng::from_entropy(),
     }
 }
 
@@ -188,6 +295,15 @@ mod tests {
         assert_eq!(result.exit_code, 1);
     }
 
+    #[test]
+    fn test_engine_result_summary() {
+        let result = EngineResult::success(
+            "working...\nSWARM_SUMMARY: first\n  SWARM_SUMMARY: Added retry logic  \nSWARM_SUMMARY:\n",
+        );
+        assert_eq!(result.summary().as_deref(), Some("Added retry logic"));
+        assert_eq!(EngineResult::success("no summary").summary(), None);
+    }
+
     #[test]
     fn test_create_engine_stub() {
         let engine = create_engine(EngineType::Stub, "loop", 0);
@@ -206,6 +322,42 @@ mod tests {
         assert_eq!(engine.engine_type(), EngineType::Codex);
     }
 
+    #[test]
+    fn test_create_engine_gemini() {
+        let engine = create_engine(EngineType::Gemini, "loop", 3600);
+        assert_eq!(engine.engine_type(), EngineType::Gemini);
+        assert_eq!(engine.timeout_secs(), 3600);
+    }
+
+    #[test]
+    fn test_create_engine_command() {
+        let engine_type = EngineType::Command {
+            program: "./agent.sh".to_string(),
+            args: vec!["{task}".to_string()],
+        };
+        let engine = create_engine(engine_type.clone(), "loop", 900);
+        assert_eq!(engine.engine_type(), engine_type);
+        assert_eq!(engine.timeout_secs(), 900);
+    }
+
+    #[test]
+    fn test_create_engine_ollama() {
+        let engine = create_engine(
+            EngineType::Ollama {
+                model: "llama3".to_string(),
+            },
+            "loop",
+            3600,
+        );
+        assert_eq!(
+            engine.engine_type(),
+            EngineType::Ollama {
+                model: "llama3".to_string()
+            }
+        );
+        assert_eq!(engine.timeout_secs(), 3600);
+    }
+
     #[test]
     fn test_create_engine_openrouter() {
         let engine = create_engine(
@@ -230,6 +382,24 @@ mod tests {
         assert_eq!(select_engine_type(&types, true), EngineType::Stub);
     }
 
+    #[test]
+    fn test_seeded_select_engine_type_sequences_match() {
+        let types = vec![EngineType::Claude, EngineType::Codex, EngineType::Gemini];
+        let sequence = |seed| {
+            let mut rng = selection_rng(Some(seed), 'A', 1);
+            (0..32)
+                .map(|_| select_engine_type_with_rng(&types, false, &mut rng))
+                .collect::<Vec<_>>()
+        };
+        let first = sequence(42);
+        assert_eq!(first, sequence(42));
+        assert!(
+            first.iter().any(|t| *t != first[0]),
+            "expected a mix of engines"
+        );
+        assert_ne!(first, sequence(43));
+    }
+
     #[test]
     fn test_select_engine_type_empty_list() {
         // Empty list defaults to Claude
@@ -282,6 +452,19 @@ mod tests {
         assert_eq!(selected_type, EngineType::Codex);
     }
 
+    #[test]
+    fn test_select_engine_type_includes_gemini() {
+        assert_eq!(
+            select_engine_type(&[EngineType::Gemini], false),
+            EngineType::Gemini
+        );
+        let types = vec![EngineType::Claude, EngineType::Gemini];
+        for _ in 0..20 {
+            let selected = select_engine_type(&types, false);
+            assert!(selected == EngineType::Claude || selected == EngineType::Gemini);
+        }
+    }
+
     #[test]
     fn test_create_random_engine_returns_matching_type() {
         // Verify the returned engine type matches the selected type
@@ -0,0 +1,348 @@
+//! Ollama engine for running agents against a locally hosted model.
+//!
+//! Prompts are sent to `<endpoint>/api/generate` over plain HTTP with
+//! streaming disabled, and the model's `response` text becomes the engine
+//! output. The model only returns text: unlike the CLI engines it cannot edit
+//! files or run commands in the worktree.
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::config::EngineType;
+use crate::http::{self, Endpoint};
+use crate::json::{escape_json, json_string_field};
+use crate::shutdown;
+
+use super::usage::parse_ollama_usage;
+use super::util::{build_agent_prompt, WAIT_LOG_INTERVAL_SECS};
+use super::TokenUsage;
+use super::{Engine, EngineResult};
+
+/// Default Ollama endpoint (`engine.ollama_url`).
+pub const DEFAULT_OLLAMA_URL: &str = "http://localhost:11434";
+
+/// How long a single socket operation may block before shutdown and timeout
+/// are re-checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Connect and preflight timeout for the Ollama server.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+static OLLAMA_URL: Mutex<Option<String>> = Mutex::new(None);
+
+/// Set the endpoint new Ollama engines connect to (`None` restores the default).
+pub fn set_ollama_url(url: Option<String>) {
+    *OLLAMA_URL.lock().unwrap_or_else(|e| e.into_inner()) = url;
+}
+
+fn configured_url() -> String {
+    OLLAMA_URL
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_else(|| DEFAULT_OLLAMA_URL.to_string())
+}
+
+/// Ollama HTTP engine.
+pub struct OllamaEngine {
+    /// Model name passed to Ollama (e.g., "llama3").
+    model: String,
+    /// Base URL of the Ollama server.
+    url: String,
+    /// Timeout in seconds (0 = no timeout).
+    timeout_secs: u64,
+}
+
+impl OllamaEngine {
+    /// Create an engine for `model` using the configured endpoint.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            url: configured_url(),
+            timeout_secs: 0,
+        }
+    }
+
+    /// Create with timeout.
+    pub fn with_timeout(model: impl Into<String>, timeout_secs: u64) -> Self {
+        Self {
+            timeout_secs,
+            ..Self::new(model)
+        }
+    }
+
+    /// Use a specific endpoint instead of the configured one.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Connect to the endpoint, returning the stream and the parsed endpoint.
+    fn connect(&self) -> Result<(TcpStream, Endpoint), String> {
+        let endpoint =
+            Endpoint::parse(&self.url).map_err(|e| format!("invalid ollama endpoint: {}", e))?;
+        let stream = endpoint
+            .connect(CONNECT_TIMEOUT)
+            .map_err(|e| format!("failed to connect to ollama at {}: {}", self.url, e))?;
+        Ok((stream, endpoint))
+    }
+
+    fn generate(
+        &self,
+        agent_name: &str,
+        prompt: &str,
+    ) -> Result<(String, Option<TokenUsage>), EngineResult> {
+        let (mut stream, endpoint) = self.connect().map_err(|e| EngineResult::failure(e, 1))?;
+
+        let body = format!(
+            "{{\"model\": \"{}\", \"prompt\": \"{}\", \"stream\": false}}",
+            escape_json(&self.model),
+            escape_json(prompt)
+        );
+        let request = endpoint.request("POST", "/api/generate", Some(&body));
+        stream.write_all(request.as_bytes()).map_err(|e| {
+            EngineResult::failure(format!("failed to send ollama request: {}", e), 1)
+        })?;
+
+        let raw = self.read_response(agent_name, &mut stream)?;
+        parse_generate_response(&raw).map_err(|e| EngineResult::failure(e, 1))
+    }
+
+    /// Read the whole response, checking for shutdown and timeout between reads.
+    fn read_response(
+        &self,
+        agent_name: &str,
+        stream: &mut TcpStream,
+    ) -> Result<Vec<u8>, EngineResult> {
+        let _ = stream.set_read_timeout(Some(POLL_INTERVAL));
+        let start = Instant::now();
+        let log_interval = Duration::from_secs(WAIT_LOG_INTERVAL_SECS);
+        let mut next_log = log_interval;
+        let timeout = if self.timeout_secs > 0 {
+            Some(Duration::from_secs(self.timeout_secs))
+        } else {
+            None
+        };
+
+        let mut raw = Vec::new();
+        let mut buf = [0u8; 8192];
+        loop {
+            match stream.read(&mut buf) {
+                Ok(0) => return Ok(raw),
+                Ok(n) => raw.extend_from_slice(&buf[..n]),
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    return Err(EngineResult::failure(
+                        format!("failed to read ollama response: {}", e),
+                        1,
+                    ))
+                }
+            }
+
+            let elapsed = start.elapsed();
+            if shutdown::requested() {
+                return Err(EngineResult::failure("Shutdown requested", 130));
+            }
+            if let Some(timeout_duration) = timeout {
+                if elapsed >= timeout_duration {
+                    let mins = elapsed.as_secs() / 60;
+                    return Err(EngineResult::failure(
+                        format!("agent timed out after {} minutes (ollama)", mins),
+                        124, // Standard timeout exit code
+                    ));
+                }
+            }
+            if elapsed >= next_log {
+                eprintln!(
+                    "[{}] Still executing... ({} min elapsed, ollama {})",
+                    agent_name,
+                    elapsed.as_secs() / 60,
+                    self.model
+                );
+                next_log += log_interval;
+            }
+        }
+    }
+}
+
+impl Engine for OllamaEngine {
+    fn execute(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        _working_dir: &Path,
+        _turn_number: usize,
+        team_dir: Option<&str>,
+    ) -> EngineResult {
+        if self.model.trim().is_empty() {
+            return EngineResult::failure(
+                "ollama engine requires a model (e.g., ollama:llama3)",
+                1,
+            );
+        }
+
+        // For valid agents, wrap in agent prompt; otherwise use raw prompt
+        let prompt = match build_agent_prompt(agent_name, task_description, team_dir) {
+            Ok(Some(p)) => p,
+            Ok(None) => task_description.to_string(), // Non-agent (e.g., ScrumMaster)
+            Err(e) => return EngineResult::failure(e, 1),
+        };
+
+        match self.generate(agent_name, &prompt) {
+            Ok((output, usage)) => {
+                let result = EngineResult::success(output);
+                match usage {
+                    Some(usage) => result.with_usage(usage),
+                    None => result,
+                }
+            }
+            Err(result) => result,
+        }
+    }
+
+    fn engine_type(&self) -> EngineType {
+        EngineType::Ollama {
+            model: self.model.clone(),
+        }
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        self.timeout_secs
+    }
+
+    /// Check the server answers `GET /api/version`.
+    fn preflight(&self) -> Result<(), String> {
+        if self.model.trim().is_empty() {
+            return Err("ollama engine requires a model (e.g., ollama:llama3)".to_string());
+        }
+        let (mut stream, endpoint) = self.connect()?;
+        let _ = stream.set_read_timeout(Some(CONNECT_TIMEOUT));
+        let mut raw = Vec::new();
+        stream
+            .write_all(endpoint.request("GET", "/api/version", None).as_bytes())
+            .and_then(|_| stream.read_to_end(&mut raw))
+            .map_err(|e| format!("ollama at {} did not respond: {}", self.url, e))?;
+        let response = http::parse_response(&raw)
+            .map_err(|e| format!("ollama at {} answered: {}", self.url, e))?;
+        if response.status == 200 {
+            Ok(())
+        } else {
+            Err(format!(
+                "ollama at {} answered HTTP {}",
+                self.url, response.status
+            ))
+        }
+    }
+}
+
+/// Extract the generated text and token counts from a raw `/api/generate`
+/// HTTP response.
+fn parse_generate_response(raw: &[u8]) -> Result<(String, Option<TokenUsage>), String> {
+    let http::HttpResponse { status, body } =
+        http::parse_response(raw).map_err(|e| format!("ollama: {}", e))?;
+    if status != 200 {
+        let detail = json_string_field(&body, "error").unwrap_or_else(|| body.trim().to_string());
+        return Err(format!("ollama returned HTTP {}: {}", status, detail));
+    }
+    let response = json_string_field(&body, "response")
+        .ok_or_else(|| "ollama response has no \"response\" field".to_string())?;
+    Ok((response, parse_ollama_usage(&body)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn test_ollama_engine_type() {
+        let engine = OllamaEngine::with_timeout("llama3", 1800);
+        assert_eq!(engine.timeout_secs, 1800);
+        assert_eq!(
+            engine.engine_type(),
+            EngineType::Ollama {
+                model: "llama3".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_generate_response_plain_and_chunked() {
+        let plain = b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"model\":\"llama3\",\"response\":\"Hi \\\"there\\\"\\n\\u00e9\",\"done\":true}";
+        assert_eq!(
+            parse_generate_response(plain).unwrap(),
+            ("Hi \"there\"\n\u{e9}".to_string(), None)
+        );
+
+        let chunked = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\n{\"res\r\n10\r\nponse\":\"ok\"}    \r\n0\r\n\r\n";
+        assert_eq!(parse_generate_response(chunked).unwrap().0, "ok");
+
+        let error = b"HTTP/1.1 404 Not Found\r\n\r\n{\"error\":\"model 'nope' not found\"}";
+        assert_eq!(
+            parse_generate_response(error).unwrap_err(),
+            "ollama returned HTTP 404: model 'nope' not found"
+        );
+    }
+
+    #[test]
+    fn test_ollama_engine_generates_via_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            while !String::from_utf8_lossy(&request).contains("\"stream\": false}") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n{\"response\":\"done\",\"done\":true,\"prompt_eval_count\":26,\"eval_count\":298}")
+                .unwrap();
+            String::from_utf8_lossy(&request).to_string()
+        });
+
+        let engine = OllamaEngine::new("llama3").with_url(format!("http://127.0.0.1:{}", port));
+        let result = engine.execute("ScrumMaster", "plan the sprint", Path::new("."), 0, None);
+        assert!(result.success, "unexpected result: {:?}", result);
+        assert_eq!(result.output, "done");
+        assert_eq!(result.usage, Some(TokenUsage::new(26, 298)));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /api/generate HTTP/1.1\r\n"));
+        assert!(request.contains("\"model\": \"llama3\""));
+        assert!(request.contains("\"prompt\": \"plan the sprint\""));
+    }
+
+    #[test]
+    fn test_ollama_engine_connection_failure() {
+        // Bind then drop to get a port nothing listens on
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let engine = OllamaEngine::new("llama3").with_url(format!("http://127.0.0.1:{}", port));
+        let result = engine.execute("ScrumMaster", "hello", Path::new("."), 0, None);
+        assert!(!result.success);
+        assert!(result
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("failed to connect to ollama at http://127.0.0.1:"));
+    }
+
+    #[test]
+    fn test_ollama_engine_requires_model() {
+        let result = OllamaEngine::new("").execute("ScrumMaster", "hi", Path::new("."), 0, None);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("ollama engine requires a model (e.g., ollama:llama3)")
+        );
+    }
+}
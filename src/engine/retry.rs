@@ -0,0 +1,327 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config::EngineType;
+use crate::shutdown;
+
+use super::{Engine, EngineResult};
+
+/// Error fragments that mark a failure as permanent (checked first).
+const PERMANENT_MARKERS: &[&str] = &[
+    "unauthorized",
+    "authentication",
+    "invalid api key",
+    "invalid x-api-key",
+    "api key",
+    "permission denied",
+    "forbidden",
+    "401",
+    "403",
+];
+
+/// Error fragments that mark a failure as transient and worth retrying.
+const TRANSIENT_MARKERS: &[&str] = &[
+    "rate limit",
+    "rate_limit",
+    "too many requests",
+    "429",
+    "overloaded",
+    "529",
+    "502",
+    "503",
+    "504",
+    "service unavailable",
+    "bad gateway",
+    "temporarily unavailable",
+    "connection reset",
+    "connection refused",
+    "connection closed",
+    "network",
+    "timed out",
+    "econnreset",
+    "etimedout",
+    "try again",
+];
+
+/// Exit codes that are never retried: agent timeout (124) and shutdown (130).
+const NON_RETRYABLE_EXIT_CODES: &[i32] = &[124, 130];
+
+/// How often the backoff sleep re-checks for shutdown.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Engine wrapper that retries transient failures with exponential backoff.
+///
+/// Retry `n` waits `base_delay * 2^(n-1)`. Successes and permanent failures
+/// (auth errors, timeouts, shutdown) are returned immediately.
+pub struct RetryingEngine {
+    inner: Arc<dyn Engine>,
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl RetryingEngine {
+    /// Wrap `inner`, retrying up to `max_retries` times after the first attempt.
+    pub fn new(inner: Arc<dyn Engine>, max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            inner,
+            max_retries,
+            base_delay,
+        }
+    }
+
+    /// Run `attempt` until it succeeds, fails permanently, or retries run out.
+    fn run(&self, agent_name: &str, mut attempt: impl FnMut() -> EngineResult) -> EngineResult {
+        let mut retry = 0;
+        loop {
+            let result = attempt();
+            if result.success || retry >= self.max_retries || !is_transient_failure(&result) {
+                return result;
+            }
+
+            retry += 1;
+            let delay = self.backoff(retry);
+            eprintln!(
+                "[{}] Transient engine failure, retry {}/{} in {}s: {}",
+                agent_name,
+                retry,
+                self.max_retries,
+                delay.as_secs_f32(),
+                result.error.as_deref().unwrap_or("").trim()
+            );
+            if !sleep_unless_shutdown(delay) {
+                return result;
+            }
+        }
+    }
+
+    /// Delay before retry number `retry` (1-based).
+    fn backoff(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+    }
+}
+
+impl Engine for RetryingEngine {
+    fn execute(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        working_dir: &Path,
+        turn_number: usize,
+        team_dir: Option<&str>,
+    ) -> EngineResult {
+        self.run(agent_name, || {
+            self.inner.execute(
+                agent_name,
+                task_description,
+                working_dir,
+                turn_number,
+                team_dir,
+            )
+        })
+    }
+
+    fn execute_streaming(
+        &self,
+        agent_name: &str,
+        task_description: &str,
+        working_dir: &Path,
+        turn_number: usize,
+        team_dir: Option<&str>,
+        on_line: &mut dyn FnMut(&str),
+    ) -> EngineResult {
+        self.run(agent_name, || {
+            self.inner.execute_streaming(
+                agent_name,
+                task_description,
+                working_dir,
+                turn_number,
+                team_dir,
+                on_line,
+            )
+        })
+    }
+
+    fn engine_type(&self) -> EngineType {
+        self.inner.engine_type()
+    }
+
+    fn timeout_secs(&self) -> u64 {
+        self.inner.timeout_secs()
+    }
+
+    fn preflight(&self) -> Result<(), String> {
+        self.inner.preflight()
+    }
+}
+
+/// Whether a failed result looks like a rate limit or network blip.
+pub fn is_transient_failure(result: &EngineResult) -> bool {
+    if result.success || NON_RETRYABLE_EXIT_CODES.contains(&result.exit_code) {
+        return false;
+    }
+    let error = result.error.as_deref().unwrap_or("").to_lowercase();
+    if PERMANENT_MARKERS
+        .iter()
+        .any(|marker| error.contains(marker))
+    {
+        return false;
+    }
+    TRANSIENT_MARKERS
+        .iter()
+        .any(|marker| error.contains(marker))
+}
+
+/// Sleep for `delay`; returns false if shutdown was requested meanwhile.
+fn sleep_unless_shutdown(delay: Duration) -> bool {
+    let deadline = Instant::now() + delay;
+    loop {
+        if shutdown::requested() {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        thread::sleep(SHUTDOWN_POLL_INTERVAL.min(deadline - now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Stub that fails with `error` for the first `failures` calls, then succeeds.
+    struct FlakyEngine {
+        failures: usize,
+        error: &'static str,
+        calls: AtomicUsize,
+    }
+
+    impl FlakyEngine {
+        fn new(failures: usize, error: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                failures,
+                error,
+                calls: AtomicUsize::new(0),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Engine for FlakyEngine {
+        fn execute(
+            &self,
+            _agent_name: &str,
+            _task_description: &str,
+            _working_dir: &Path,
+            _turn_number: usize,
+            _team_dir: Option<&str>,
+        ) -> EngineResult {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call < self.failures {
+                EngineResult::failure(self.error, 1)
+            } else {
+                EngineResult::success("done")
+            }
+        }
+
+        fn engine_type(&self) -> EngineType {
+            EngineType::Stub
+        }
+    }
+
+    fn run(engine: &RetryingEngine) -> EngineResult {
+        engine.execute("Aaron", "task", Path::new("."), 1, None)
+    }
+
+    #[test]
+    fn test_retrying_engine_recovers_after_transient_failures() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+        let flaky = FlakyEngine::new(2, "API Error: 429 rate limit exceeded");
+        let engine = RetryingEngine::new(flaky.clone(), 2, Duration::from_millis(1));
+
+        let result = run(&engine);
+        assert!(result.success, "unexpected result: {:?}", result);
+        assert_eq!(result.output, "done");
+        assert_eq!(flaky.calls(), 3);
+    }
+
+    #[test]
+    fn test_retrying_engine_streams_successful_attempt() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+        let flaky = FlakyEngine::new(1, "503 Service Unavailable");
+        let engine = RetryingEngine::new(flaky.clone(), 2, Duration::from_millis(1));
+
+        let mut lines = Vec::new();
+        let result =
+            engine.execute_streaming("Aaron", "task", Path::new("."), 1, None, &mut |line| {
+                lines.push(line.to_string())
+            });
+        assert!(result.success);
+        assert_eq!(lines, vec!["done"]);
+        assert_eq!(flaky.calls(), 2);
+    }
+
+    #[test]
+    fn test_retrying_engine_gives_up_after_max_retries() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+        let flaky = FlakyEngine::new(2, "Overloaded");
+        let engine = RetryingEngine::new(flaky.clone(), 1, Duration::from_millis(1));
+
+        let result = run(&engine);
+        assert!(!result.success);
+        assert_eq!(result.error.as_deref(), Some("Overloaded"));
+        assert_eq!(flaky.calls(), 2);
+    }
+
+    #[test]
+    fn test_retrying_engine_does_not_retry_auth_errors() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+        let flaky = FlakyEngine::new(2, "401 Unauthorized: invalid API key");
+        let engine = RetryingEngine::new(flaky.clone(), 2, Duration::from_millis(1));
+
+        assert!(!run(&engine).success);
+        assert_eq!(flaky.calls(), 1);
+    }
+
+    #[test]
+    fn test_is_transient_failure() {
+        assert!(is_transient_failure(&EngineResult::failure(
+            "connection reset by peer",
+            1
+        )));
+        assert!(!is_transient_failure(&EngineResult::failure(
+            "compile error in src/lib.rs",
+            1
+        )));
+        // Agent timeouts and shutdowns are final even if the text matches
+        assert!(!is_transient_failure(&EngineResult::failure(
+            "agent timed out after 60 minutes",
+            124
+        )));
+        assert!(!is_transient_failure(&EngineResult::failure(
+            "Shutdown requested",
+            130
+        )));
+        assert!(!is_transient_failure(&EngineResult::success("ok")));
+    }
+
+    #[test]
+    fn test_backoff_doubles_each_retry() {
+        let engine = RetryingEngine::new(FlakyEngine::new(0, ""), 3, Duration::from_secs(5));
+        assert_eq!(engine.backoff(1), Duration::from_secs(5));
+        assert_eq!(engine.backoff(2), Duration::from_secs(10));
+        assert_eq!(engine.backoff(3), Duration::from_secs(20));
+    }
+}
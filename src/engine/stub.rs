@@ -1,10 +1,33 @@
+use std::collections::hash_map::DefaultHasher;
 use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
 use std::io::Write;
 use std::path::Path;
 
 use crate::config::EngineType;
+use crate::json::parse_json_string;
 
-use super::{Engine, EngineResult};
+use super::{Engine, EngineResult, TokenUsage};
+
+/// Canned result for tasks whose description contains a script pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ScriptedResponse {
+    success: bool,
+    output: String,
+    exit_code: i32,
+}
+
+impl ScriptedResponse {
+    fn to_result(&self) -> EngineResult {
+        EngineResult {
+            success: self.success,
+            output: self.output.clone(),
+            error: (!self.success).then(|| self.output.clone()),
+            exit_code: self.exit_code,
+            usage: Some(TokenUsage::default()),
+        }
+    }
+}
 
 /// Stub engine for testing.
 ///
@@ -12,6 +35,12 @@ use super::{Engine, EngineResult};
 pub struct StubEngine {
     /// Directory to write stub output files.
     output_dir: String,
+    /// Fraction of executions that fail (0.0 = never, 1.0 = always).
+    fail_rate: f64,
+    /// Seed that makes the fail decisions reproducible.
+    seed: Option<u64>,
+    /// Scripted responses as (pattern, response), in file order.
+    script: Vec<(String, ScriptedResponse)>,
 }
 
 impl StubEngine {
@@ -19,7 +48,62 @@ impl StubEngine {
     pub fn new(output_dir: impl Into<String>) -> Self {
         Self {
             output_dir: output_dir.into(),
+            fail_rate: 0.0,
+            seed: None,
+            script: Vec::new(),
+        }
+    }
+
+    /// Fail `fail_rate` of executions for chaos testing.
+    ///
+    /// With a seed, each (agent, task, turn) always gets the same outcome;
+    /// without one, failures are picked at random.
+    pub fn with_fail_rate(mut self, fail_rate: f64, seed: Option<u64>) -> Self {
+        self.fail_rate = fail_rate.clamp(0.0, 1.0);
+        self.seed = seed;
+        self
+    }
+
+    /// Answer tasks from a JSON fixture instead of always succeeding.
+    ///
+    /// The fixture maps description substrings to responses:
+    /// `{ "pattern": { "success": false, "output": "...", "exit_code": 2 } }`.
+    /// The first pattern (in file order) contained in the task description
+    /// wins; unmatched tasks keep the default stub behavior. `success`
+    /// defaults to true, `output` to empty, and `exit_code` to 0 on success
+    /// or 1 on failure.
+    pub fn with_script(mut self, path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read stub script {}: {}", path.display(), e))?;
+        self.script = parse_script(&content)
+            .map_err(|e| format!("invalid stub script {}: {}", path.display(), e))?;
+        Ok(self)
+    }
+
+    /// Scripted response for a task description, if any pattern matches.
+    fn scripted_response(&self, task_description: &str) -> Option<&ScriptedResponse> {
+        self.script
+            .iter()
+            .find(|(pattern, _)| task_description.contains(pattern.as_str()))
+            .map(|(_, response)| response)
+    }
+
+    /// Decide whether this execution should simulate a failure.
+    fn should_fail(&self, agent_name: &str, task_description: &str, turn_number: usize) -> bool {
+        if self.fail_rate <= 0.0 {
+            return false;
         }
+        let roll = match self.seed {
+            Some(seed) => {
+                let mut hasher = DefaultHasher::new();
+                (seed, agent_name, task_description, turn_number).hash(&mut hasher);
+                // Top 53 bits give a uniform fraction in [0, 1).
+                (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+            }
+            None => rand::random::<f64>(),
+        };
+        roll < self.fail_rate
     }
 
     /// Get the output file path for a given turn and agent.
@@ -40,6 +124,17 @@ impl Engine for StubEngine {
         turn_number: usize,
         _team_dir: Option<&str>,
     ) -> EngineResult {
+        if let Some(response) = self.scripted_response(task_description) {
+            return response.to_result();
+        }
+
+        if self.should_fail(agent_name, task_description, turn_number) {
+            return EngineResult::failure(
+                format!("stub: simulated failure (fail_rate={})", self.fail_rate),
+                1,
+            );
+        }
+
         // Get agent initial from name
         let initial = crate::agent::initial_from_name(agent_name).unwrap_or('?');
 
@@ -66,7 +161,7 @@ impl Engine for StubEngine {
             }
         }
 
-        EngineResult::success(content)
+        EngineResult::success(content).with_usage(TokenUsage::default())
     }
 
     fn engine_type(&self) -> EngineType {
@@ -74,11 +169,143 @@ impl Engine for StubEngine {
     }
 }
 
+/// Parse a stub script: an object of pattern -> response objects.
+fn parse_script(content: &str) -> Result<Vec<(String, ScriptedResponse)>, String> {
+    let mut cursor = JsonCursor::new(content);
+    let mut script = Vec::new();
+    cursor.parse_object(|cursor, pattern| {
+        let mut success = true;
+        let mut output = String::new();
+        let mut exit_code = None;
+        cursor.parse_object(|cursor, field| {
+            match field.as_str() {
+                "success" => success = cursor.parse_bool()?,
+                "output" => output = cursor.parse_string()?,
+                "exit_code" => exit_code = Some(cursor.parse_i32()?),
+                other => return Err(format!("unknown field \"{}\" for \"{}\"", other, pattern)),
+            }
+            Ok(())
+        })?;
+        let exit_code = exit_code.unwrap_or(if success { 0 } else { 1 });
+        script.push((
+            pattern,
+            ScriptedResponse {
+                success,
+                output,
+                exit_code,
+            },
+        ));
+        Ok(())
+    })?;
+    cursor.skip_whitespace();
+    if cursor.rest().is_empty() {
+        Ok(script)
+    } else {
+        Err("trailing content after script object".to_string())
+    }
+}
+
+/// Minimal JSON reader covering the subset used by stub scripts.
+struct JsonCursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> JsonCursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.rest().starts_with(ch) {
+            self.pos += ch.len_utf8();
+            Ok(())
+        } else {
+            Err(format!("expected '{}' at byte {}", ch, self.pos))
+        }
+    }
+
+    /// Parse `{ "key": value, ... }`, calling `on_entry` to consume each value.
+    fn parse_object<F>(&mut self, mut on_entry: F) -> Result<(), String>
+    where
+        F: FnMut(&mut Self, String) -> Result<(), String>,
+    {
+        self.expect('{')?;
+        self.skip_whitespace();
+        if self.rest().starts_with('}') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            let key = self.parse_string()?;
+            self.expect(':')?;
+            on_entry(self, key)?;
+            self.skip_whitespace();
+            match self.rest().chars().next() {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => return Err(format!("expected ',' or '}}' at byte {}", self.pos)),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.skip_whitespace();
+        let (value, rest) = parse_json_string(self.rest())
+            .ok_or_else(|| format!("invalid or unterminated string at byte {}", self.pos))?;
+        self.pos = self.input.len() - rest.len();
+        Ok(value)
+    }
+
+    fn parse_bool(&mut self) -> Result<bool, String> {
+        self.skip_whitespace();
+        for (literal, value) in [("true", true), ("false", false)] {
+            if self.rest().starts_with(literal) {
+                self.pos += literal.len();
+                return Ok(value);
+            }
+        }
+        Err(format!("expected true or false at byte {}", self.pos))
+    }
+
+    fn parse_i32(&mut self) -> Result<i32, String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && c == '-')))
+            .map_or(rest.len(), |(i, _)| i);
+        let value = rest[..len]
+            .parse()
+            .map_err(|_| format!("expected integer at byte {}", self.pos))?;
+        self.pos += len;
+        Ok(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_stub_engine_preflight_passes() {
+        assert_eq!(StubEngine::new("loop").preflight(), Ok(()));
+    }
+
     #[test]
     fn test_stub_engine_execute() {
         let tmp_dir = TempDir::new().unwrap();
@@ -88,6 +315,7 @@ mod tests {
         let result = engine.execute("Aaron", "Write tests", tmp_dir.path(), 1, None);
 
         assert!(result.success);
+        assert_eq!(result.usage, Some(TokenUsage::default()));
         assert!(result.output.contains("OK"));
         assert!(result.output.contains("Aaron"));
         assert!(result.output.contains("Write tests"));
@@ -100,6 +328,27 @@ mod tests {
         assert!(content.contains("OK"));
     }
 
+    #[test]
+    fn test_stub_engine_streams_output_lines() {
+        let tmp_dir = TempDir::new().unwrap();
+        let output_dir = tmp_dir.path().join("loop");
+        let engine = StubEngine::new(output_dir.to_str().unwrap());
+
+        let mut lines = Vec::new();
+        let result = engine.execute_streaming(
+            "Aaron",
+            "Write tests",
+            tmp_dir.path(),
+            1,
+            None,
+            &mut |line| lines.push(line.to_string()),
+        );
+
+        assert!(result.success);
+        assert_eq!(lines, result.output.lines().collect::<Vec<_>>());
+        assert!(lines.contains(&"Task: Write tests".to_string()));
+    }
+
     #[test]
     fn test_stub_engine_deterministic() {
         let tmp_dir = TempDir::new().unwrap();
@@ -114,6 +363,131 @@ mod tests {
         assert_eq!(result1.output, result2.output);
     }
 
+    #[test]
+    fn test_stub_engine_fail_rate_one_fails_every_task() {
+        let tmp_dir = TempDir::new().unwrap();
+        let output_dir = tmp_dir.path().join("loop");
+        let engine = StubEngine::new(output_dir.to_str().unwrap()).with_fail_rate(1.0, Some(7));
+
+        for (agent, task) in [
+            ("Aaron", "Task 1"),
+            ("Betty", "Task 2"),
+            ("Carlos", "Task 3"),
+        ] {
+            let result = engine.execute(agent, task, tmp_dir.path(), 1, None);
+            assert!(!result.success);
+            assert_eq!(result.exit_code, 1);
+            assert!(result.error.unwrap().contains("simulated failure"));
+        }
+        assert!(!output_dir.exists());
+    }
+
+    #[test]
+    fn test_stub_engine_fail_rate_is_reproducible_with_seed() {
+        let tmp_dir = TempDir::new().unwrap();
+        let output_dir = tmp_dir.path().join("loop");
+        let outcomes = |seed| {
+            let engine =
+                StubEngine::new(output_dir.to_str().unwrap()).with_fail_rate(0.5, Some(seed));
+            (0..40)
+                .map(|i| {
+                    engine
+                        .execute("Aaron", &format!("Task {}", i), tmp_dir.path(), 1, None)
+                        .success
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = outcomes(42);
+        assert_eq!(first, outcomes(42));
+        assert!(first.iter().any(|ok| *ok));
+        assert!(first.iter().any(|ok| !*ok));
+    }
+
+    fn write_script(dir: &Path, content: &str) -> std::path::PathBuf {
+        let path = dir.join("script.json");
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_stub_engine_script_fails_one_pattern_and_succeeds_another() {
+        let tmp_dir = TempDir::new().unwrap();
+        let output_dir = tmp_dir.path().join("loop");
+        let script = write_script(
+            tmp_dir.path(),
+            r#"{
+                "Deploy": { "success": false, "output": "deploy \"exploded\"", "exit_code": 3 },
+                "Write docs": { "success": true, "output": "docs written" }
+            }"#,
+        );
+        let engine = StubEngine::new(output_dir.to_str().unwrap())
+            .with_script(&script)
+            .unwrap();
+
+        let failed = engine.execute("Aaron", "Deploy to staging", tmp_dir.path(), 1, None);
+        assert!(!failed.success);
+        assert_eq!(failed.exit_code, 3);
+        assert_eq!(failed.error.as_deref(), Some("deploy \"exploded\""));
+
+        let scripted = engine.execute("Betty", "Write docs for API", tmp_dir.path(), 1, None);
+        assert!(scripted.success);
+        assert_eq!(scripted.exit_code, 0);
+        assert_eq!(scripted.output, "docs written");
+
+        // Unmatched tasks fall back to the default stub output
+        let fallback = engine.execute("Carlos", "Refactor parser", tmp_dir.path(), 1, None);
+        assert!(fallback.success);
+        assert!(fallback.output.contains("Refactor parser"));
+        assert!(output_dir.join("turn1-agentC.md").exists());
+        assert!(!output_dir.join("turn1-agentA.md").exists());
+    }
+
+    #[test]
+    fn test_stub_engine_script_first_matching_pattern_wins() {
+        let tmp_dir = TempDir::new().unwrap();
+        let script = write_script(
+            tmp_dir.path(),
+            r#"{"Task 1": {"success": false}, "Task": {"output": "generic"}}"#,
+        );
+        let engine = StubEngine::new("loop").with_script(&script).unwrap();
+
+        let result = engine.execute("Aaron", "Task 1", tmp_dir.path(), 1, None);
+        assert!(!result.success);
+        assert_eq!(result.exit_code, 1);
+
+        let result = engine.execute("Aaron", "Task 2", tmp_dir.path(), 1, None);
+        assert!(result.success);
+        assert_eq!(result.output, "generic");
+    }
+
+    #[test]
+    fn test_stub_engine_script_rejects_invalid_fixtures() {
+        let tmp_dir = TempDir::new().unwrap();
+        let missing = StubEngine::new("loop").with_script(tmp_dir.path().join("nope.json"));
+        assert!(missing
+            .err()
+            .unwrap()
+            .contains("failed to read stub script"));
+
+        for bad in [
+            r#"{"Deploy": {"success": "no"}}"#,
+            r#"{"Deploy": {"sucess": false}}"#,
+            r#"{"Deploy": {"success": false}"#,
+            r#"["Deploy"]"#,
+        ] {
+            let script = write_script(tmp_dir.path(), bad);
+            let err = StubEngine::new("loop").with_script(&script).err();
+            assert!(
+                err.as_deref()
+                    .is_some_and(|e| e.contains("invalid stub script")),
+                "accepted {}: {:?}",
+                bad,
+                err
+            );
+        }
+    }
+
     #[test]
     fn test_stub_engine_type() {
         let engine = StubEngine::new("loop");
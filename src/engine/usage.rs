@@ -0,0 +1,123 @@
+//! Token usage reported by engine executions.
+
+use std::ops::AddAssign;
+
+use crate::json::json_u64_field;
+
+/// Tokens consumed by one or more engine executions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    /// Tokens sent to the model, including cached prompt tokens.
+    pub prompt_tokens: u64,
+    /// Tokens generated by the model.
+    pub completion_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Create a usage record.
+    pub fn new(prompt_tokens: u64, completion_tokens: u64) -> Self {
+        Self {
+            prompt_tokens,
+            completion_tokens,
+        }
+    }
+
+    /// Prompt plus completion tokens.
+    pub fn total(&self) -> u64 {
+        self.prompt_tokens + self.completion_tokens
+    }
+}
+
+impl AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+    }
+}
+
+/// Usage from the `usage` object of claude's `--output-format json` result.
+///
+/// Cache writes and reads are billed as input, so they count as prompt tokens.
+pub(super) fn parse_claude_usage(json: &str) -> Option<TokenUsage> {
+    let usage = &json[json.find("\"usage\"")?..];
+    let completion = json_u64_field(usage, "output_tokens")?;
+    let prompt = [
+        "input_tokens",
+        "cache_creation_input_tokens",
+        "cache_read_input_tokens",
+    ]
+    .iter()
+    .filter_map(|key| json_u64_field(usage, key))
+    .sum();
+    Some(TokenUsage::new(prompt, completion))
+}
+
+/// Usage summed over the `turn.completed` events of `codex exec --json`.
+pub(super) fn parse_codex_usage(jsonl: &str) -> Option<TokenUsage> {
+    jsonl
+        .lines()
+        .filter(|line| line.contains("\"turn.completed\""))
+        .filter_map(|line| {
+            let usage = &line[line.find("\"usage\"")?..];
+            Some(TokenUsage::new(
+                json_u64_field(usage, "input_tokens")?,
+                json_u64_field(usage, "output_tokens")?,
+            ))
+        })
+        .reduce(|mut total, usage| {
+            total += usage;
+            total
+        })
+}
+
+/// Usage from an Ollama `/api/generate` response body.
+pub(super) fn parse_ollama_usage(body: &str) -> Option<TokenUsage> {
+    Some(TokenUsage::new(
+        json_u64_field(body, "prompt_eval_count")?,
+        json_u64_field(body, "eval_count")?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_claude_usage_counts_cache_tokens_as_prompt() {
+        let json = r#"{"type":"result","subtype":"success","is_error":false,"result":"done","usage":{"input_tokens":12,"cache_creation_input_tokens":300,"cache_read_input_tokens":4000,"output_tokens":250,"service_tier":"standard"},"total_cost_usd":0.05}"#;
+        assert_eq!(parse_claude_usage(json), Some(TokenUsage::new(4312, 250)));
+    }
+
+    #[test]
+    fn test_parse_claude_usage_without_usage_object() {
+        assert_eq!(parse_claude_usage(r#"{"result":"done"}"#), None);
+        assert_eq!(parse_claude_usage("plain text output"), None);
+    }
+
+    #[test]
+    fn test_parse_codex_usage_sums_turns() {
+        let jsonl = concat!(
+            "{\"type\":\"thread.started\",\"thread_id\":\"t1\"}\n",
+            "{\"type\":\"turn.completed\",\"usage\":{\"input_tokens\":100,\"cached_input_tokens\":80,\"output_tokens\":10}}\n",
+            "{\"type\":\"item.completed\",\"item\":{\"type\":\"agent_message\",\"text\":\"ok\"}}\n",
+            "{\"type\":\"turn.completed\",\"usage\":{\"input_tokens\":50,\"cached_input_tokens\":0,\"output_tokens\":5}}\n",
+        );
+        assert_eq!(parse_codex_usage(jsonl), Some(TokenUsage::new(150, 15)));
+        assert_eq!(parse_codex_usage("plain text"), None);
+    }
+
+    #[test]
+    fn test_parse_ollama_usage() {
+        let body = r#"{"response":"hi","done":true,"prompt_eval_count":26,"eval_count":298}"#;
+        assert_eq!(parse_ollama_usage(body), Some(TokenUsage::new(26, 298)));
+        assert_eq!(parse_ollama_usage(r#"{"response":"hi"}"#), None);
+    }
+
+    #[test]
+    fn test_token_usage_add_assign_and_total() {
+        let mut usage = TokenUsage::new(10, 2);
+        usage += TokenUsage::new(5, 3);
+        assert_eq!(usage, TokenUsage::new(15, 5));
+        assert_eq!(usage.total(), 20);
+    }
+}
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 use std::fs;
-use std::process::{Command, Output};
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Output, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 use crate::prompt;
 
@@ -12,6 +16,9 @@ const EMAIL_FILE_PATH: &str = ".swarm-hug/email.txt";
 /// Interval for "still waiting" log messages (5 minutes).
 pub(super) const WAIT_LOG_INTERVAL_SECS: u64 = 300;
 
+/// How long a preflight probe may run before it counts as failed.
+const PREFLIGHT_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Read the co-author email from .swarm-hug/email.txt if it exists.
 pub(super) fn read_coauthor_email() -> Option<String> {
     fs::read_to_string(EMAIL_FILE_PATH)
@@ -65,7 +72,7 @@ pub(super) fn build_agent_prompt(
 ) -> Result<Option<String>, String> {
     // Only use agent prompt for valid agents (those with A-Z initials)
     let agent_initial = match crate::agent::initial_from_name(agent_name) {
-        Some(c) => c.to_string(),
+        Some(c) => crate::agent::id(c),
         None => return Ok(None), // Not a valid agent, use raw prompt
     };
 
@@ -90,6 +97,104 @@ pub(super) fn build_agent_prompt(
     prompt::load_and_render("agent", &vars).map(Some)
 }
 
+/// Run a cheap CLI probe (e.g. `claude --version`) for `Engine::preflight`.
+///
+/// Succeeds when the command exits 0 within the preflight timeout; otherwise
+/// the error includes the command's stderr (or stdout) for the user.
+pub(super) fn run_preflight_probe(cli_path: &str, args: &[&str]) -> Result<(), String> {
+    let command_line = format!("{} {}", cli_path, args.join(" "));
+    let mut child = Command::new(cli_path)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run `{}`: {}", command_line, e))?;
+
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if start.elapsed() >= PREFLIGHT_TIMEOUT => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "`{}` did not finish within {}s",
+                    command_line,
+                    PREFLIGHT_TIMEOUT.as_secs()
+                ));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(50)),
+            Err(e) => return Err(format!("failed to wait for `{}`: {}", command_line, e)),
+        }
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to read `{}` output: {}", command_line, e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    let detail = if stderr.is_empty() {
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    } else {
+        stderr
+    };
+    Err(format!(
+        "`{}` exited with {}{}",
+        command_line,
+        output.status.code().unwrap_or(1),
+        if detail.is_empty() {
+            String::new()
+        } else {
+            format!(": {}", detail)
+        }
+    ))
+}
+
+/// Read a child pipe on a background thread, forwarding each line (without
+/// its newline) over the returned channel as it arrives.
+///
+/// The thread returns the full output once the pipe closes.
+pub(super) fn stream_lines<R: Read + Send + 'static>(
+    pipe: Option<R>,
+) -> (Receiver<String>, JoinHandle<String>) {
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let mut output = String::new();
+        let Some(pipe) = pipe else {
+            return output;
+        };
+        let mut reader = BufReader::new(pipe);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buf);
+                    output.push_str(&line);
+                    let _ = tx.send(line.trim_end_matches(['\n', '\r']).to_string());
+                }
+            }
+        }
+        output
+    });
+    (rx, handle)
+}
+
+/// Read a child pipe to the end on a background thread.
+pub(super) fn drain_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).to_string()
+    })
+}
+
 /// Convert process output to engine result.
 pub(super) fn output_to_result(output: Output) -> EngineResult {
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
@@ -108,6 +213,21 @@ mod tests {
     use super::*;
     use crate::testutil::with_temp_cwd;
 
+    #[cfg(unix)]
+    #[test]
+    fn test_run_preflight_probe_reports_exit_and_stderr() {
+        assert_eq!(run_preflight_probe("sh", &["-c", "exit 0"]), Ok(()));
+        assert_eq!(
+            run_preflight_probe("sh", &["-c", "echo 'Not logged in' >&2; exit 1"]),
+            Err(
+                "`sh -c echo 'Not logged in' >&2; exit 1` exited with 1: Not logged in".to_string()
+            )
+        );
+        assert!(run_preflight_probe("swarm-missing-cli", &["--version"])
+            .unwrap_err()
+            .starts_with("failed to run `swarm-missing-cli --version`"));
+    }
+
     #[test]
     fn test_build_agent_prompt_valid_agent() {
         // Valid agent should return Some(prompt)
@@ -0,0 +1,215 @@
+//! Live sprint events over a Unix domain socket (`--event-socket <path>`).
+//!
+//! The runner calls [`emit`] as agents move through their lifecycle and as the
+//! sprint reaches merge/push milestones. Every connected subscriber receives
+//! each event as one JSON object per line:
+//!
+//! ```text
+//! {"event": "task_completed", "ts": "2026-01-01T12:00:00+00:00", "agent": "Aaron", "task": "Fix auth"}
+//! ```
+//!
+//! All field values are strings. Events and their fields:
+//!
+//! | event              | fields                                  |
+//! |--------------------|-----------------------------------------|
+//! | `sprint_started`   | `team`, `sprint`, `run`, `target`       |
+//! | `task_started`     | `agent`, `initial`, `task`              |
+//! | `task_completed`   | `agent`, `initial`, `task`              |
+//! | `task_failed`      | `agent`, `initial`, `task`, `error`     |
+//! | `agent_state`      | `agent`, `initial`, `from`, `to`        |
+//! | `sprint_completed` | `team`, `sprint`, `completed`, `failed` |
+//! | `merge_started`    | `source`, `target`, `mode`              |
+//! | `merge_completed`  | `source`, `target`, `mode`              |
+//! | `merge_failed`     | `source`, `target`, `mode`, `error`     |
+//! | `push_completed`   | `target`                                |
+//! | `push_failed`      | `target`, `error`                       |
+//! | `pr_created`       | `url`                                   |
+//!
+//! Subscribers may connect at any time and only see later events. A subscriber
+//! that cannot take an event immediately is disconnected so the sprint never
+//! blocks. When no socket is open, or on non-Unix platforms, [`emit`] is a no-op.
+
+use std::path::Path;
+#[cfg(unix)]
+use std::sync::Mutex;
+
+use crate::json::escape_json;
+
+#[cfg(unix)]
+static SOCKET: Mutex<Option<unix::EventSocket>> = Mutex::new(None);
+
+/// Create the event socket at `path`, replacing a stale socket file.
+#[cfg(unix)]
+pub fn open(path: &Path) -> Result<(), String> {
+    let socket = unix::EventSocket::bind(path)?;
+    *SOCKET.lock().unwrap_or_else(|e| e.into_inner()) = Some(socket);
+    Ok(())
+}
+
+/// Event sockets need Unix; elsewhere this is a no-op.
+#[cfg(not(unix))]
+pub fn open(_path: &Path) -> Result<(), String> {
+    Ok(())
+}
+
+/// Disconnect all subscribers and remove the socket file.
+pub fn close() {
+    #[cfg(unix)]
+    SOCKET.lock().unwrap_or_else(|e| e.into_inner()).take();
+}
+
+/// Send an event to every connected subscriber.
+pub fn emit(event: &str, fields: &[(&str, &str)]) {
+    #[cfg(unix)]
+    if let Some(socket) = SOCKET.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        let ts = chrono::Local::now().to_rfc3339();
+        socket.broadcast(&format_event(event, &ts, fields));
+    }
+    #[cfg(not(unix))]
+    let _ = (event, fields);
+}
+
+/// Format an event as a single JSON line (without the trailing newline).
+pub fn format_event(event: &str, ts: &str, fields: &[(&str, &str)]) -> String {
+    let mut line = format!(
+        "{{\"event\": \"{}\", \"ts\": \"{}\"",
+        escape_json(event),
+        escape_json(ts)
+    );
+    for (key, value) in fields {
+        line.push_str(&format!(
+            ", \"{}\": \"{}\"",
+            escape_json(key),
+            escape_json(value)
+        ));
+    }
+    line.push('}');
+    line
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::fs;
+    use std::io::{ErrorKind, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::{Path, PathBuf};
+
+    /// Listening socket plus the subscribers accepted so far.
+    pub(super) struct EventSocket {
+        path: PathBuf,
+        listener: UnixListener,
+        subscribers: Vec<UnixStream>,
+    }
+
+    impl EventSocket {
+        pub(super) fn bind(path: &Path) -> Result<Self, String> {
+            if path.exists() {
+                fs::remove_file(path)
+                    .map_err(|e| format!("failed to remove {}: {}", path.display(), e))?;
+            }
+            let listener = UnixListener::bind(path)
+                .map_err(|e| format!("failed to bind {}: {}", path.display(), e))?;
+            listener
+                .set_nonblocking(true)
+                .map_err(|e| format!("failed to configure {}: {}", path.display(), e))?;
+            Ok(Self {
+                path: path.to_path_buf(),
+                listener,
+                subscribers: Vec::new(),
+            })
+        }
+
+        /// Accept pending subscribers, then write `line` to each of them.
+        pub(super) fn broadcast(&mut self, line: &str) {
+            self.accept_pending();
+            let payload = format!("{}\n", line);
+            self.subscribers
+                .retain_mut(|stream| stream.write_all(payload.as_bytes()).is_ok());
+        }
+
+        #[cfg(test)]
+        pub(super) fn subscriber_count(&self) -> usize {
+            self.subscribers.len()
+        }
+
+        fn accept_pending(&mut self) {
+            loop {
+                match self.listener.accept() {
+                    Ok((stream, _)) => {
+                        // Non-blocking writes turn a full buffer into a disconnect.
+                        if stream.set_nonblocking(true).is_ok() {
+                            self.subscribers.push(stream);
+                        }
+                    }
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+
+    impl Drop for EventSocket {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_event_escapes_values() {
+        assert_eq!(
+            format_event(
+                "task_failed",
+                "2026-01-01T00:00:00+00:00",
+                &[("agent", "Aaron"), ("error", "bad \"quote\"\nline")]
+            ),
+            "{\"event\": \"task_failed\", \"ts\": \"2026-01-01T00:00:00+00:00\", \
+             \"agent\": \"Aaron\", \"error\": \"bad \\\"quote\\\"\\nline\"}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_event_socket_broadcasts_lines_to_subscribers() {
+        use std::io::{BufRead, BufReader};
+        use std::os::unix::net::UnixStream;
+        use tempfile::TempDir;
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.sock");
+        let mut socket = unix::EventSocket::bind(&path).unwrap();
+
+        let first = UnixStream::connect(&path).unwrap();
+        let second = UnixStream::connect(&path).unwrap();
+        socket.broadcast("{\"event\": \"pr_created\"}");
+
+        for stream in [first, second] {
+            let mut line = String::new();
+            BufReader::new(stream).read_line(&mut line).unwrap();
+            assert_eq!(line, "{\"event\": \"pr_created\"}\n");
+        }
+
+        drop(socket);
+        assert!(!path.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_event_socket_drops_disconnected_subscribers() {
+        use std::os::unix::net::UnixStream;
+        use tempfile::TempDir;
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.sock");
+        let mut socket = unix::EventSocket::bind(&path).unwrap();
+
+        drop(UnixStream::connect(&path).unwrap());
+        socket.broadcast("{}");
+        socket.broadcast("{}");
+        assert_eq!(socket.subscriber_count(), 0);
+    }
+}
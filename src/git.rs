@@ -2,6 +2,8 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process;
 
+use swarm::config::Forge;
+
 pub(crate) fn git_repo_root() -> Result<PathBuf, String> {
     let output = process::Command::new("git")
         .args(["rev-parse", "--show-toplevel"])
@@ -251,19 +253,18 @@ pub(crate) fn commit_files_in_worktree_on_branch(
 ///
 /// # Arguments
 /// * `sprint_branch` - Sprint/feature branch name to commit on
-/// * `tasks_file` - Path to the team's tasks.md file
+/// * `tasks_files` - Paths to the team's tasks files
 /// * `team_name` - Formatted team name for commit message (e.g., "Greenfield")
 /// * `sprint_number` - The historical sprint number for this team
 pub(crate) fn commit_task_assignments(
     worktree_root: &Path,
     sprint_branch: &str,
-    tasks_file: &str,
+    tasks_files: &[&str],
     team_name: &str,
     sprint_number: usize,
 ) -> Result<(), String> {
     let commit_msg = format!("{} Sprint {}: task assignments", team_name, sprint_number);
-    if commit_files_in_worktree_on_branch(worktree_root, sprint_branch, &[tasks_file], &commit_msg)?
-    {
+    if commit_files_in_worktree_on_branch(worktree_root, sprint_branch, tasks_files, &commit_msg)? {
         println!("  Committed task assignments to git.");
     }
     Ok(())
@@ -273,19 +274,18 @@ pub(crate) fn commit_task_assignments(
 ///
 /// # Arguments
 /// * `sprint_branch` - Sprint/feature branch name to commit on
-/// * `tasks_file` - Path to the team's tasks.md file
+/// * `tasks_files` - Paths to the team's tasks files
 /// * `team_name` - Formatted team name for commit message (e.g., "Greenfield")
 /// * `sprint_number` - The historical sprint number for this team
 pub(crate) fn commit_sprint_completion(
     worktree_root: &Path,
     sprint_branch: &str,
-    tasks_file: &str,
+    tasks_files: &[&str],
     team_name: &str,
     sprint_number: usize,
 ) -> Result<(), String> {
     let commit_msg = format!("{} Sprint {}: completed", team_name, sprint_number);
-    if commit_files_in_worktree_on_branch(worktree_root, sprint_branch, &[tasks_file], &commit_msg)?
-    {
+    if commit_files_in_worktree_on_branch(worktree_root, sprint_branch, tasks_files, &commit_msg)? {
         println!("  Committed sprint completion to git.");
     }
     Ok(())
@@ -421,6 +421,142 @@ pub(crate) fn push_branch_to_remote(repo_dir: &Path, target_branch: &str) -> Pus
     }
 }
 
+/// Remote fetched by `fetch_and_fast_forward_target`.
+const FETCH_REMOTE: &str = "origin";
+
+/// Outcome of syncing the local target branch with its remote before a merge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FetchTargetResult {
+    /// The local branch already contains the remote tip.
+    UpToDate,
+    /// The local branch was fast-forwarded (short hashes).
+    FastForwarded { from: String, to: String },
+}
+
+/// Fetch `origin/<target_branch>` and fast-forward the local target branch.
+///
+/// Errors when the fetch fails or the local branch has diverged from the remote.
+pub(crate) fn fetch_and_fast_forward_target(
+    repo_dir: &Path,
+    target_branch: &str,
+) -> Result<FetchTargetResult, String> {
+    fetch_and_fast_forward_target_with(repo_dir, target_branch, fetch_remote_branch)
+}
+
+fn fetch_remote_branch(repo_dir: &Path, remote: &str, branch: &str) -> Result<(), String> {
+    let refspec = format!("+refs/heads/{0}:refs/remotes/{1}/{0}", branch, remote);
+    let output = process::Command::new("git")
+        .arg("-C")
+        .arg(repo_dir)
+        .args(["fetch", remote, refspec.as_str()])
+        .output()
+        .map_err(|e| format!("failed to run git fetch: {}", e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!(
+            "git fetch {} {} failed: {}",
+            remote,
+            branch,
+            stderr.trim()
+        ))
+    }
+}
+
+fn fetch_and_fast_forward_target_with<F>(
+    repo_dir: &Path,
+    target_branch: &str,
+    fetch: F,
+) -> Result<FetchTargetResult, String>
+where
+    F: FnOnce(&Path, &str, &str) -> Result<(), String>,
+{
+    let branch = target_branch.trim();
+    if branch.is_empty() {
+        return Err("target branch name is empty".to_string());
+    }
+
+    fetch(repo_dir, FETCH_REMOTE, branch)?;
+
+    let local_ref = format!("refs/heads/{}", branch);
+    let remote_ref = format!("refs/remotes/{}/{}", FETCH_REMOTE, branch);
+    let local = rev_parse_in(repo_dir, &local_ref)?;
+    let remote = rev_parse_in(repo_dir, &remote_ref)?;
+
+    if local == remote || is_ancestor_in(repo_dir, &remote, &local)? {
+        return Ok(FetchTargetResult::UpToDate);
+    }
+    if !is_ancestor_in(repo_dir, &local, &remote)? {
+        return Err(format!(
+            "local {} has diverged from {}/{}; cannot fast-forward",
+            branch, FETCH_REMOTE, branch
+        ));
+    }
+
+    // A checked-out branch must be advanced through its worktree so the
+    // working tree follows; otherwise the ref can be moved directly.
+    let output = match swarm::worktree::find_target_branch_worktree_in(repo_dir, branch)? {
+        Some(worktree) => process::Command::new("git")
+            .arg("-C")
+            .arg(&worktree)
+            .args(["merge", "--ff-only", remote.as_str()])
+            .output(),
+        None => process::Command::new("git")
+            .arg("-C")
+            .arg(repo_dir)
+            .args([
+                "update-ref",
+                local_ref.as_str(),
+                remote.as_str(),
+                local.as_str(),
+            ])
+            .output(),
+    }
+    .map_err(|e| format!("failed to run git: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "failed to fast-forward {}: {}",
+            branch,
+            stderr.trim()
+        ));
+    }
+
+    Ok(FetchTargetResult::FastForwarded {
+        from: get_short_commit_for_ref_in(repo_dir, &local).unwrap_or(local),
+        to: get_short_commit_for_ref_in(repo_dir, &remote).unwrap_or(remote),
+    })
+}
+
+fn rev_parse_in(repo_dir: &Path, git_ref: &str) -> Result<String, String> {
+    let output = process::Command::new("git")
+        .arg("-C")
+        .arg(repo_dir)
+        .args(["rev-parse", "--verify", git_ref])
+        .output()
+        .map_err(|e| format!("failed to run git rev-parse: {}", e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(format!("unknown git ref: {}", git_ref))
+    }
+}
+
+pub(crate) fn is_ancestor_in(
+    repo_dir: &Path,
+    ancestor: &str,
+    descendant: &str,
+) -> Result<bool, String> {
+    let status = process::Command::new("git")
+        .arg("-C")
+        .arg(repo_dir)
+        .args(["merge-base", "--is-ancestor", ancestor, descendant])
+        .status()
+        .map_err(|e| format!("failed to run git merge-base: {}", e))?;
+    Ok(status.success())
+}
+
 /// Get a one-line commit log between two refs (`source..target`) for PR metadata generation.
 pub(crate) fn get_commit_log_between(
     repo_dir: &Path,
@@ -449,7 +585,7 @@ pub(crate) fn get_commit_log_between(
     }
 }
 
-/// Result of attempting to create a pull request with GitHub CLI.
+/// Result of attempting to create a pull request with the forge's CLI.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[allow(dead_code)]
 pub(crate) enum PullRequestCreateResult {
@@ -457,16 +593,16 @@ pub(crate) enum PullRequestCreateResult {
     Created {
         /// PR URL parsed from stdout (usually first non-empty line).
         url: Option<String>,
-        /// Raw stdout from `gh pr create`.
+        /// Raw stdout from `gh pr create` / `glab mr create`.
         stdout: String,
-        /// Raw stderr from `gh pr create`.
+        /// Raw stderr from `gh pr create` / `glab mr create`.
         stderr: String,
     },
     /// Pull request command ran but failed.
     Failed {
-        /// Raw stdout from `gh pr create`.
+        /// Raw stdout from `gh pr create` / `glab mr create`.
         stdout: String,
-        /// Raw stderr from `gh pr create`.
+        /// Raw stderr from `gh pr create` / `glab mr create`.
         stderr: String,
         /// Process exit code if available.
         exit_code: Option<i32>,
@@ -478,15 +614,99 @@ pub(crate) enum PullRequestCreateResult {
     },
 }
 
+/// The first URL line of `stdout` (glab prints a summary before it), or
+/// else its first non-empty line.
 #[allow(dead_code)]
 fn extract_pull_request_url(stdout: &str) -> Option<String> {
-    stdout
+    let mut lines = stdout
         .lines()
         .map(str::trim)
-        .find(|line| !line.is_empty())
+        .filter(|line| !line.is_empty());
+    let first = lines.clone().next();
+    lines
+        .find(|line| line.starts_with("https://") || line.starts_with("http://"))
+        .or(first)
         .map(ToString::to_string)
 }
 
+/// Host of a git remote URL (`https://host/...`, `ssh://git@host:22/...`, or
+/// scp-like `git@host:owner/repo.git`).
+fn remote_url_host(url: &str) -> &str {
+    let rest = url.trim();
+    let rest = rest.split_once("://").map_or(rest, |(_, rest)| rest);
+    let rest = rest.split_once('@').map_or(rest, |(_, rest)| rest);
+    rest.split([':', '/']).next().unwrap_or("")
+}
+
+/// GitLab when the remote host mentions gitlab (gitlab.com or a self-hosted
+/// `gitlab.example.com`), GitHub otherwise.
+fn forge_for_remote_url(url: &str) -> Forge {
+    if remote_url_host(url).to_lowercase().contains("gitlab") {
+        Forge::GitLab
+    } else {
+        Forge::GitHub
+    }
+}
+
+/// Forge of the `origin` remote, defaulting to GitHub when it has none.
+pub(crate) fn detect_forge(repo_dir: &Path) -> Forge {
+    process::Command::new("git")
+        .arg("-C")
+        .arg(repo_dir)
+        .args(["remote", "get-url", "origin"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| forge_for_remote_url(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or(Forge::GitHub)
+}
+
+/// CLI that opens pull/merge requests on `forge`.
+fn forge_cli(forge: Forge) -> &'static str {
+    match forge {
+        Forge::GitHub => "gh",
+        Forge::GitLab => "glab",
+    }
+}
+
+/// Arguments for the forge CLI that open a request merging `target_branch`
+/// into `source_branch`.
+fn pull_request_create_args<'a>(
+    forge: Forge,
+    title: &'a str,
+    body: &'a str,
+    source_branch: &'a str,
+    target_branch: &'a str,
+) -> Vec<&'a str> {
+    match forge {
+        Forge::GitHub => vec![
+            "pr",
+            "create",
+            "--title",
+            title,
+            "--body",
+            body,
+            "--base",
+            source_branch,
+            "--head",
+            target_branch,
+        ],
+        Forge::GitLab => vec![
+            "mr",
+            "create",
+            "--title",
+            title,
+            "--description",
+            body,
+            "--target-branch",
+            source_branch,
+            "--source-branch",
+            target_branch,
+            "--yes",
+        ],
+    }
+}
+
 fn gh_probe_command_for_platform(is_windows: bool) -> &'static str {
     if is_windows {
         "where"
@@ -501,15 +721,16 @@ fn gh_probe_command() -> &'static str {
 
 #[cfg_attr(not(test), allow(dead_code))]
 fn create_pull_request_with_commands(
+    forge: Forge,
     title: &str,
     body: &str,
     source_branch: &str,
     target_branch: &str,
     probe_command: &str,
-    gh_command: &str,
+    cli_command: &str,
 ) -> PullRequestCreateResult {
     let probe_output = process::Command::new(probe_command)
-        .arg(gh_command)
+        .arg(cli_command)
         .output();
 
     match probe_output {
@@ -517,33 +738,21 @@ fn create_pull_request_with_commands(
         Ok(output) => {
             let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
             let reason = if stderr.is_empty() {
-                format!("'{}' not found on PATH", gh_command)
+                format!("'{}' not found on PATH", cli_command)
             } else {
-                format!("'{}' not found on PATH: {}", gh_command, stderr)
+                format!("'{}' not found on PATH: {}", cli_command, stderr)
             };
             return PullRequestCreateResult::Skipped { reason };
         }
         Err(e) => {
             return PullRequestCreateResult::Skipped {
-                reason: format!("failed to check for '{}': {}", gh_command, e),
+                reason: format!("failed to check for '{}': {}", cli_command, e),
             };
         }
     }
 
-    let output = process::Command::new(gh_command)
-        .args([
-            "pr",
-            "create",
-            "--title",
-            title,
-            "--body",
-            body,
-            "--base",
-            source_branch,
-            "--head",
-            target_branch,
-        ])
-        .output();
+    let args = pull_request_create_args(forge, title, body, source_branch, target_branch);
+    let output = process::Command::new(cli_command).args(&args).output();
 
     match output {
         Ok(output) => {
@@ -565,7 +774,7 @@ fn create_pull_request_with_commands(
         }
         Err(e) => PullRequestCreateResult::Failed {
             stdout: String::new(),
-            stderr: format!("failed to run gh pr create: {}", e),
+            stderr: format!("failed to run {} {} create: {}", cli_command, args[0], e),
             exit_code: None,
         },
     }
@@ -573,18 +782,20 @@ fn create_pull_request_with_commands(
 
 #[allow(dead_code)]
 pub(crate) fn create_pull_request(
+    forge: Forge,
     title: &str,
     body: &str,
     source_branch: &str,
     target_branch: &str,
 ) -> PullRequestCreateResult {
     create_pull_request_with_commands(
+        forge,
         title,
         body,
         source_branch,
         target_branch,
         gh_probe_command(),
-        "gh",
+        forge_cli(forge),
     )
 }
 pub(crate) const MIN_GIT_VERSION: (u32, u32, u32) = (2, 48, 0);
@@ -658,8 +869,9 @@ fn version_lt(current: (u32, u32, u32), min: (u32, u32, u32)) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
-        create_pull_request_with_commands, ensure_branch_checked_out, get_commit_log_between,
-        get_short_commit_for_ref_in, gh_probe_command_for_platform, push_branch_to_remote,
+        create_pull_request_with_commands, detect_forge, ensure_branch_checked_out,
+        fetch_and_fast_forward_target_with, get_commit_log_between, get_short_commit_for_ref_in,
+        gh_probe_command_for_platform, push_branch_to_remote, FetchTargetResult, Forge,
         PullRequestCreateResult,
     };
     use std::fs;
@@ -846,6 +1058,98 @@ mod tests {
         assert_eq!(result.error.as_deref(), Some("target branch name is empty"));
     }
 
+    fn init_fetch_test_repo(repo_dir: &Path) -> String {
+        run_git(repo_dir, &["init", "-b", "main"]);
+        run_git(repo_dir, &["config", "user.name", "Swarm Test"]);
+        run_git(
+            repo_dir,
+            &["config", "user.email", "swarm-test@example.com"],
+        );
+        fs::write(repo_dir.join("README.md"), "hello").expect("write file");
+        run_git(repo_dir, &["add", "."]);
+        run_git(repo_dir, &["commit", "-m", "init"]);
+        run_git(repo_dir, &["rev-parse", "HEAD"]).trim().to_string()
+    }
+
+    /// Create a commit on top of `parent` without touching any branch.
+    fn commit_tree_on(repo_dir: &Path, parent: &str, message: &str) -> String {
+        run_git(
+            repo_dir,
+            &["commit-tree", "HEAD^{tree}", "-p", parent, "-m", message],
+        )
+        .trim()
+        .to_string()
+    }
+
+    #[test]
+    fn test_fetch_and_fast_forward_target_updates_merge_base() {
+        let temp = TempDir::new().expect("temp dir");
+        let repo_dir = temp.path();
+        let base = init_fetch_test_repo(repo_dir);
+        run_git(repo_dir, &["branch", "feature"]);
+        let upstream = commit_tree_on(repo_dir, &base, "upstream change");
+
+        // Fake fetch hook: pretend origin/main moved to `upstream`.
+        let result = fetch_and_fast_forward_target_with(repo_dir, "main", |dir, remote, branch| {
+            assert_eq!((remote, branch), ("origin", "main"));
+            run_git(
+                dir,
+                &["update-ref", "refs/remotes/origin/main", upstream.as_str()],
+            );
+            Ok(())
+        })
+        .expect("fast-forward should succeed");
+
+        assert!(matches!(result, FetchTargetResult::FastForwarded { .. }));
+        assert_eq!(run_git(repo_dir, &["rev-parse", "main"]).trim(), upstream);
+        assert_eq!(
+            run_git(repo_dir, &["merge-base", "main", "feature"]).trim(),
+            base
+        );
+        run_git(repo_dir, &["checkout", "feature"]);
+        run_git(repo_dir, &["merge", "--ff-only", "main"]);
+        assert_eq!(
+            run_git(repo_dir, &["merge-base", "main", "feature"]).trim(),
+            upstream
+        );
+    }
+
+    #[test]
+    fn test_fetch_and_fast_forward_target_up_to_date_and_diverged() {
+        let temp = TempDir::new().expect("temp dir");
+        let repo_dir = temp.path();
+        let base = init_fetch_test_repo(repo_dir);
+
+        let up_to_date = fetch_and_fast_forward_target_with(repo_dir, "main", |dir, _, _| {
+            run_git(
+                dir,
+                &["update-ref", "refs/remotes/origin/main", base.as_str()],
+            );
+            Ok(())
+        });
+        assert_eq!(up_to_date, Ok(FetchTargetResult::UpToDate));
+
+        let remote_only = commit_tree_on(repo_dir, &base, "remote only");
+        fs::write(repo_dir.join("README.md"), "local").expect("write file");
+        run_git(repo_dir, &["commit", "-am", "local only"]);
+        let diverged = fetch_and_fast_forward_target_with(repo_dir, "main", |dir, _, _| {
+            run_git(
+                dir,
+                &[
+                    "update-ref",
+                    "refs/remotes/origin/main",
+                    remote_only.as_str(),
+                ],
+            );
+            Ok(())
+        });
+        assert!(diverged.unwrap_err().contains("diverged"));
+
+        let fetch_failed =
+            fetch_and_fast_forward_target_with(repo_dir, "main", |_, _, _| Err("offline".into()));
+        assert_eq!(fetch_failed, Err("offline".to_string()));
+    }
+
     #[test]
     fn test_get_commit_log_between_returns_oneline_log() {
         let temp = TempDir::new().expect("temp dir");
@@ -934,6 +1238,7 @@ mod tests {
         );
 
         let result = create_pull_request_with_commands(
+            Forge::GitHub,
             "Add sprint automation",
             "Generated body",
             "source-branch",
@@ -998,6 +1303,7 @@ mod tests {
         );
 
         let result = create_pull_request_with_commands(
+            Forge::GitHub,
             "title",
             "body",
             "source",
@@ -1036,6 +1342,7 @@ mod tests {
         );
 
         let result = create_pull_request_with_commands(
+            Forge::GitHub,
             "title",
             "body",
             "source",
@@ -1071,6 +1378,7 @@ mod tests {
         );
 
         let result = create_pull_request_with_commands(
+            Forge::GitHub,
             "title",
             "body",
             "source",
@@ -1092,4 +1400,127 @@ mod tests {
             other => panic!("expected Failed, got {:?}", other),
         }
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_merge_request_builds_expected_glab_command() {
+        let temp = TempDir::new().expect("temp dir");
+        let which_path = temp.path().join("which-glab");
+        let glab_path = temp.path().join("glab");
+        let args_path = temp.path().join("glab-args.txt");
+
+        write_executable_script(&which_path, "#!/bin/sh\necho \"$1\"\n");
+        write_executable_script(
+            &glab_path,
+            &format!(
+                "#!/bin/sh\nprintf '%s\\n' \"$@\" > \"{}\"\necho \"Creating merge request for target-branch into source-branch\"\necho\necho \"https://gitlab.com/example/repo/-/merge_requests/7\"\n",
+                args_path.display()
+            ),
+        );
+
+        let result = create_pull_request_with_commands(
+            Forge::GitLab,
+            "Add sprint automation",
+            "Generated body",
+            "source-branch",
+            "target-branch",
+            which_path.to_str().expect("which path"),
+            glab_path.to_str().expect("glab path"),
+        );
+
+        match result {
+            PullRequestCreateResult::Created { url, .. } => {
+                assert_eq!(
+                    url,
+                    Some("https://gitlab.com/example/repo/-/merge_requests/7".to_string())
+                );
+            }
+            other => panic!("expected Created, got {:?}", other),
+        }
+
+        let args_file = fs::read_to_string(&args_path).expect("read glab args");
+        let args: Vec<&str> = args_file.lines().collect();
+        assert_eq!(
+            args,
+            vec![
+                "mr",
+                "create",
+                "--title",
+                "Add sprint automation",
+                "--description",
+                "Generated body",
+                "--target-branch",
+                "source-branch",
+                "--source-branch",
+                "target-branch",
+                "--yes",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_create_merge_request_skips_when_glab_missing() {
+        let temp = TempDir::new().expect("temp dir");
+        let which_path = temp.path().join("which-missing-glab");
+        let glab_path = temp.path().join("glab");
+        let marker_path = temp.path().join("glab-called.txt");
+
+        write_executable_script(&which_path, "#!/bin/sh\nexit 1\n");
+        write_executable_script(
+            &glab_path,
+            &format!("#!/bin/sh\necho called > \"{}\"\n", marker_path.display()),
+        );
+
+        let result = create_pull_request_with_commands(
+            Forge::GitLab,
+            "title",
+            "body",
+            "source",
+            "target",
+            which_path.to_str().expect("which path"),
+            glab_path.to_str().expect("glab path"),
+        );
+
+        match result {
+            PullRequestCreateResult::Skipped { reason } => {
+                assert!(
+                    reason.contains("not found on PATH") && reason.contains("glab"),
+                    "unexpected skip reason: {}",
+                    reason
+                );
+            }
+            other => panic!("expected Skipped, got {:?}", other),
+        }
+        assert!(!marker_path.exists(), "glab should not have been executed");
+    }
+
+    #[test]
+    fn test_detect_forge_from_origin_host() {
+        use super::forge_for_remote_url;
+
+        for url in [
+            "git@gitlab.com:group/repo.git",
+            "https://gitlab.example.com/group/sub/repo.git",
+            "ssh://git@GitLab.internal:2222/group/repo.git",
+        ] {
+            assert_eq!(forge_for_remote_url(url), Forge::GitLab, "{}", url);
+        }
+        for url in [
+            "git@github.com:owner/repo.git",
+            "https://github.com/owner/gitlab-tools.git",
+            "/srv/git/repo.git",
+        ] {
+            assert_eq!(forge_for_remote_url(url), Forge::GitHub, "{}", url);
+        }
+
+        let temp = TempDir::new().expect("temp dir");
+        run_git(temp.path(), &["init"]);
+        assert_eq!(detect_forge(temp.path()), Forge::GitHub);
+        run_git(
+            temp.path(),
+            &["remote", "add", "origin", "git@gitlab.com:group/repo.git"],
+        );
+        assert_eq!(detect_forge(temp.path()), Forge::GitLab);
+    }
 }
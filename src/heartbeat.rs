@@ -1,6 +1,7 @@
 //! Heartbeat logging for long-running agent tasks.
 //!
-//! Emits periodic "agent activity" messages to chat while a task is running.
+//! Emits periodic "agent activity" messages to chat (and the webhook, if
+//! configured) while a task is running.
 
 use std::path::Path;
 use std::sync::{
@@ -11,8 +12,10 @@ use std::thread;
 use std::time::{Duration, Instant};
 
 use crate::chat;
+use crate::webhook::Webhook;
 
-const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 300;
+/// Default seconds between heartbeat messages.
+pub const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 300;
 
 /// Default heartbeat interval (5 minutes).
 pub fn default_interval() -> Duration {
@@ -26,12 +29,13 @@ pub struct HeartbeatGuard {
 }
 
 impl HeartbeatGuard {
-    /// Start a heartbeat logger for a running task.
+    /// Start a heartbeat logger for a running task, also sent to `webhook`.
     pub fn start<P: AsRef<Path>>(
         path: P,
         agent_name: &str,
         task_description: &str,
         interval: Duration,
+        webhook: Option<Webhook>,
     ) -> Self {
         if interval.is_zero() {
             return Self {
@@ -62,6 +66,9 @@ impl HeartbeatGuard {
                     if let Err(e) = chat::write_heartbeat(&chat_path, &agent_name, &msg) {
                         eprintln!("warning: failed to write heartbeat: {}", e);
                     }
+                    if let Some(webhook) = &webhook {
+                        webhook.notify(&agent_name, "heartbeat", &task_description);
+                    }
                     next_log += interval;
                 }
 
@@ -128,12 +135,48 @@ mod tests {
         assert_eq!(default_interval(), Duration::from_secs(300));
     }
 
+    #[test]
+    fn zero_interval_writes_no_heartbeats() {
+        let tmp = NamedTempFile::new().unwrap();
+
+        let guard = HeartbeatGuard::start(tmp.path(), "Aaron", "Test task", Duration::ZERO, None);
+        assert!(
+            guard.handle.is_none(),
+            "no heartbeat thread should be spawned"
+        );
+        thread::sleep(Duration::from_millis(200));
+        guard.stop();
+
+        let content = fs::read_to_string(tmp.path()).unwrap();
+        assert!(!content.lines().any(chat::is_heartbeat_line));
+    }
+
+    #[test]
+    fn nonzero_interval_writes_heartbeat_within_window() {
+        let tmp = NamedTempFile::new().unwrap();
+        let interval = Duration::from_millis(50);
+
+        let guard = HeartbeatGuard::start(tmp.path(), "Aaron", "Test task", interval, None);
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let mut seen = false;
+        while !seen && Instant::now() < deadline {
+            thread::sleep(interval);
+            seen = fs::read_to_string(tmp.path())
+                .unwrap()
+                .lines()
+                .any(chat::is_heartbeat_line);
+        }
+        guard.stop();
+
+        assert!(seen, "expected a heartbeat within the window");
+    }
+
     #[test]
     fn heartbeat_logs_and_stops_on_drop() {
         let tmp = NamedTempFile::new().unwrap();
         let interval = Duration::from_millis(100);
 
-        let guard = HeartbeatGuard::start(tmp.path(), "Aaron", "Test task", interval);
+        let guard = HeartbeatGuard::start(tmp.path(), "Aaron", "Test task", interval, None);
 
         thread::sleep(interval * 4);
         drop(guard);
@@ -0,0 +1,291 @@
+//! Minimal HTTP/1.1 client over a raw `TcpStream`.
+//!
+//! Shared by the Ollama engine and the webhooks: URL parsing, connecting,
+//! request formatting, and response parsing (status line, chunked bodies)
+//! live here so fixes apply to every caller. Only plain `http://` is spoken
+//! over the socket; [`post_json`] hands `https://` URLs to `curl`.
+
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Host, port, and path prefix of an `http://` URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Endpoint {
+    pub host: String,
+    pub port: u16,
+    /// Path without a trailing slash (empty for the root).
+    pub base_path: String,
+}
+
+impl Endpoint {
+    /// Parse `http://host[:port][/path]`.
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let rest = url
+            .trim()
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("'{}' must start with http://", url))?;
+        let (authority, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], rest[idx..].trim_end_matches('/')),
+            None => (rest, ""),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse()
+                    .map_err(|_| format!("invalid port in '{}'", url))?,
+            ),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(format!("missing host in '{}'", url));
+        }
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            base_path: path.to_string(),
+        })
+    }
+
+    /// Open a connection, giving up after `timeout`.
+    pub fn connect(&self, timeout: Duration) -> io::Result<TcpStream> {
+        let addr = (self.host.as_str(), self.port)
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::from(ErrorKind::NotFound))?;
+        TcpStream::connect_timeout(&addr, timeout)
+    }
+
+    /// Format a request for `path` (appended to the base path); a body is
+    /// sent as JSON.
+    pub fn request(&self, method: &str, path: &str, body: Option<&str>) -> String {
+        let target = format!("{}{}", self.base_path, path);
+        let target = if target.is_empty() { "/" } else { &target };
+        match body {
+            Some(body) => format!(
+                "{} {} HTTP/1.1\r\nHost: {}:{}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                method,
+                target,
+                self.host,
+                self.port,
+                body.len(),
+                body
+            ),
+            None => format!(
+                "{} {} HTTP/1.1\r\nHost: {}:{}\r\nConnection: close\r\n\r\n",
+                method, target, self.host, self.port
+            ),
+        }
+    }
+}
+
+/// Status and decoded body of an HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Send one request to `url` + `path` and read the whole response. `timeout`
+/// bounds connecting and each read or write.
+pub(crate) fn send(
+    url: &str,
+    method: &str,
+    path: &str,
+    body: Option<&str>,
+    timeout: Duration,
+) -> Result<HttpResponse, String> {
+    let endpoint = Endpoint::parse(url)?;
+    let mut stream = endpoint
+        .connect(timeout)
+        .map_err(|e| format!("connect: {}", e))?;
+    let _ = stream.set_read_timeout(Some(timeout));
+    let _ = stream.set_write_timeout(Some(timeout));
+    stream
+        .write_all(endpoint.request(method, path, body).as_bytes())
+        .map_err(|e| format!("send: {}", e))?;
+    let mut raw = Vec::new();
+    stream
+        .read_to_end(&mut raw)
+        .map_err(|e| format!("read: {}", e))?;
+    parse_response(&raw)
+}
+
+/// POST `body` as JSON to `url`. `https://` URLs are sent with `curl`; plain
+/// `http://` goes over the socket client.
+pub(crate) fn post_json(url: &str, body: &str, timeout: Duration) -> Result<HttpResponse, String> {
+    let url = url.trim();
+    if url.starts_with("https://") {
+        post_with_curl(url, body, timeout)
+    } else {
+        send(url, "POST", "", Some(body), timeout)
+    }
+}
+
+fn post_with_curl(url: &str, body: &str, timeout: Duration) -> Result<HttpResponse, String> {
+    let max_time = timeout.as_secs().max(1).to_string();
+    let mut child = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--max-time",
+            &max_time,
+            "--header",
+            "Content-Type: application/json",
+            "--data-binary",
+            "@-",
+            "--write-out",
+            "\n%{http_code}",
+            url,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run curl: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(body.as_bytes())
+            .map_err(|e| format!("failed to send request body to curl: {}", e))?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to wait for curl: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("curl failed: {}", stderr.trim()));
+    }
+    parse_curl_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Split `curl --write-out "\n%{http_code}"` output into body and status.
+fn parse_curl_output(stdout: &str) -> Result<HttpResponse, String> {
+    let (body, code) = stdout.rsplit_once('\n').unwrap_or(("", stdout));
+    let status = code
+        .trim()
+        .parse::<u16>()
+        .map_err(|_| format!("curl reported no HTTP status: '{}'", code.trim()))?;
+    Ok(HttpResponse {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// Parse a raw HTTP response, decoding a chunked body.
+pub(crate) fn parse_response(raw: &[u8]) -> Result<HttpResponse, String> {
+    let header_end = find_bytes(raw, b"\r\n\r\n").unwrap_or(raw.len());
+    let head = String::from_utf8_lossy(&raw[..header_end]);
+    let mut head_lines = head.lines();
+    let status = head_lines
+        .next()
+        .filter(|line| line.starts_with("HTTP/"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| "malformed HTTP response".to_string())?;
+    let chunked = head_lines.any(|line| {
+        line.to_ascii_lowercase()
+            .replace(' ', "")
+            .starts_with("transfer-encoding:chunked")
+    });
+    let body = raw.get(header_end + 4..).unwrap_or_default();
+    let body = if chunked {
+        decode_chunked(body)?
+    } else {
+        body.to_vec()
+    };
+    Ok(HttpResponse {
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn decode_chunked(mut rest: &[u8]) -> Result<Vec<u8>, String> {
+    let truncated = || "truncated chunked HTTP response".to_string();
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = find_bytes(rest, b"\r\n").ok_or_else(truncated)?;
+        let size_line = String::from_utf8_lossy(&rest[..line_end]);
+        let size_hex = size_line.split(';').next().unwrap_or("").trim();
+        let size = usize::from_str_radix(size_hex, 16)
+            .map_err(|_| format!("invalid chunk size '{}'", size_hex))?;
+        if size == 0 {
+            return Ok(decoded);
+        }
+        let after = &rest[line_end + 2..];
+        decoded.extend_from_slice(after.get(..size).ok_or_else(truncated)?);
+        rest = &after[size..];
+        rest = rest.strip_prefix(b"\r\n").unwrap_or(rest);
+    }
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_parse() {
+        assert_eq!(
+            Endpoint::parse("http://localhost:11434").unwrap(),
+            Endpoint {
+                host: "localhost".to_string(),
+                port: 11434,
+                base_path: String::new()
+            }
+        );
+        let endpoint = Endpoint::parse("http://gpu-box/ollama/").unwrap();
+        assert_eq!(
+            (endpoint.port, endpoint.base_path.as_str()),
+            (80, "/ollama")
+        );
+        assert!(Endpoint::parse("https://localhost:11434").is_err());
+        assert!(Endpoint::parse("http://:11434").is_err());
+    }
+
+    #[test]
+    fn test_parse_response_plain_and_chunked() {
+        assert_eq!(
+            parse_response(b"HTTP/1.1 404 Not Found\r\nContent-Length: 4\r\n\r\nnope").unwrap(),
+            HttpResponse {
+                status: 404,
+                body: "nope".to_string()
+            }
+        );
+        let chunked =
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n";
+        assert_eq!(parse_response(chunked).unwrap().body, "abcde");
+        assert_eq!(
+            parse_response(b"HTTP/1.1 204 No Content\r\n")
+                .unwrap()
+                .status,
+            204
+        );
+        assert!(parse_response(b"garbage").is_err());
+    }
+
+    #[test]
+    fn test_parse_curl_output() {
+        assert_eq!(
+            parse_curl_output("ok\n200").unwrap(),
+            HttpResponse {
+                status: 200,
+                body: "ok".to_string()
+            }
+        );
+        assert_eq!(parse_curl_output("\n404").unwrap().status, 404);
+        assert_eq!(parse_curl_output("204").unwrap().body, "");
+        assert!(parse_curl_output("").is_err());
+    }
+}
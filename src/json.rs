@@ -0,0 +1,173 @@
+//! Helpers shared by the hand-written JSON readers and writers.
+
+/// Escape `s` for use inside a JSON string literal.
+pub fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// `s` as a quoted JSON string literal.
+pub fn json_string(s: &str) -> String {
+    format!("\"{}\"", escape_json(s))
+}
+
+/// Parse a JSON string literal at the start of `input`, returning its value
+/// and the text after the closing quote.
+pub fn parse_json_string(input: &str) -> Option<(String, &str)> {
+    let body = input.strip_prefix('"')?;
+    let mut chars = body.char_indices();
+    let mut out = String::new();
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '"' => return Some((out, &body[idx + 1..])),
+            '\\' => match chars.next()?.1 {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'b' => out.push('\u{8}'),
+                'f' => out.push('\u{c}'),
+                'u' => {
+                    let high = parse_hex4(&mut chars)?;
+                    let code = if (0xD800..0xDC00).contains(&high) {
+                        // Surrogate pair: expect a following \uXXXX low half
+                        if chars.next()?.1 != '\\' || chars.next()?.1 != 'u' {
+                            return None;
+                        }
+                        let low = parse_hex4(&mut chars)?;
+                        0x10000 + ((high - 0xD800) << 10) + (low.checked_sub(0xDC00)?)
+                    } else {
+                        high
+                    };
+                    out.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                }
+                other => out.push(other),
+            },
+            _ => out.push(ch),
+        }
+    }
+    None
+}
+
+/// Parse a one-line JSON object whose values are all strings into its
+/// key/value pairs, in order.
+pub fn parse_json_fields(line: &str) -> Option<Vec<(String, String)>> {
+    let mut rest = line.trim().strip_prefix('{')?.trim_start();
+    let mut fields = Vec::new();
+    while !rest.starts_with('}') {
+        let (key, after) = parse_json_string(rest)?;
+        let after = after.trim_start().strip_prefix(':')?.trim_start();
+        let (value, after) = parse_json_string(after)?;
+        fields.push((key, value));
+        rest = after.trim_start();
+        if let Some(after_comma) = rest.strip_prefix(',') {
+            rest = after_comma.trim_start();
+        } else if !rest.starts_with('}') {
+            return None;
+        }
+    }
+    Some(fields)
+}
+
+/// Text following `"key":` for the first occurrence of `key` used as a key
+/// in a flat JSON object (occurrences as a string value, like
+/// `"type": "result"`, are skipped).
+pub fn json_field<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("\"{}\"", key);
+    let mut search_start = 0;
+    while let Some(relative) = json[search_start..].find(&pattern) {
+        let after_key = &json[search_start + relative + pattern.len()..];
+        if let Some(value) = after_key.trim_start().strip_prefix(':') {
+            return Some(value.trim_start());
+        }
+        search_start += relative + pattern.len();
+    }
+    None
+}
+
+/// Value of the string field `key` in a flat JSON object.
+pub fn json_string_field(json: &str, key: &str) -> Option<String> {
+    parse_json_string(json_field(json, key)?).map(|(value, _)| value)
+}
+
+/// Value of the unsigned integer field `key` in a flat JSON object.
+pub fn json_u64_field(json: &str, key: &str) -> Option<u64> {
+    let value = json_field(json, key)?;
+    let end = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    value[..end].parse().ok()
+}
+
+fn parse_hex4(chars: &mut std::str::CharIndices<'_>) -> Option<u32> {
+    let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+    if hex.len() != 4 {
+        return None;
+    }
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_json() {
+        assert_eq!(
+            escape_json("say \"hi\"\\\n\t\u{1}"),
+            "say \\\"hi\\\"\\\\\\n\\t\\u0001"
+        );
+        assert_eq!(json_string("a\"b"), "\"a\\\"b\"");
+    }
+
+    #[test]
+    fn test_parse_json_string_returns_rest() {
+        assert_eq!(
+            parse_json_string(r#""a\"b\n\u00e9\ud83d\ude00", "next""#),
+            Some(("a\"b\né😀".to_string(), r#", "next""#))
+        );
+        assert_eq!(parse_json_string("\"unterminated"), None);
+        assert_eq!(parse_json_string("plain"), None);
+        assert_eq!(
+            parse_json_string(&json_string("round \"trip\"\t")),
+            Some(("round \"trip\"\t".to_string(), ""))
+        );
+    }
+
+    #[test]
+    fn test_parse_json_fields_in_order() {
+        assert_eq!(
+            parse_json_fields(r#"{"b": "2", "a":"x\"y"}"#),
+            Some(vec![
+                ("b".to_string(), "2".to_string()),
+                ("a".to_string(), "x\"y".to_string())
+            ])
+        );
+        assert_eq!(parse_json_fields("{}"), Some(Vec::new()));
+        assert_eq!(parse_json_fields(r#"{"n": 1}"#), None);
+        assert_eq!(parse_json_fields("not json"), None);
+    }
+
+    #[test]
+    fn test_json_field_skips_string_values() {
+        let json = r#"{"type": "result", "result" : "done \u00e9", "tokens":42}"#;
+        assert_eq!(
+            json_string_field(json, "result"),
+            Some("done é".to_string())
+        );
+        assert_eq!(json_string_field(json, "type"), Some("result".to_string()));
+        assert_eq!(json_u64_field(json, "tokens"), Some(42));
+        assert_eq!(json_field(json, "missing"), None);
+        assert_eq!(json_u64_field(json, "type"), None);
+    }
+}
@@ -14,25 +14,36 @@
 //! - `.swarm-hug/<team>/runs/<target>/` - Runtime-local sprint state (ignored by git)
 
 pub mod agent;
+pub mod audit;
 pub mod chat;
 pub mod color;
 pub mod config;
 pub mod engine;
+pub mod events;
 pub mod heartbeat;
+pub mod http;
+pub mod json;
 pub mod lifecycle;
 pub mod log;
 pub mod merge_agent;
+pub mod pause;
 pub mod planning;
 pub mod process;
 pub mod process_group;
 pub mod process_registry;
 pub mod prompt;
+pub mod prompt_dump;
+pub mod rate_limit;
 pub mod run_context;
 pub mod run_hash;
 pub mod shutdown;
+pub mod slack;
 pub mod task;
 pub mod team;
 #[doc(hidden)]
 pub mod testutil;
 pub mod tui;
+pub mod verbosity;
+pub mod warnings;
+pub mod webhook;
 pub mod worktree;
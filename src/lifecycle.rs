@@ -5,12 +5,22 @@
 //! - Working: Agent is actively executing the task
 //! - Done: Agent completed the task (success or failure)
 //! - Terminated: Agent has been cleaned up
+//!
+//! Every transition is timestamped, so a sprint's history can be exported with
+//! [`LifecycleTracker::to_json`]. Hooks added with
+//! [`LifecycleTracker::on_transition`] see each transition as it happens.
 
 use std::collections::HashMap;
 use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::json::escape_json;
 
 /// Agent lifecycle state.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AgentState {
     /// Task assigned, agent not yet started.
     Assigned,
@@ -33,6 +43,19 @@ impl fmt::Display for AgentState {
     }
 }
 
+/// One state an agent entered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transition {
+    /// State entered.
+    pub state: AgentState,
+    /// Task the agent was on.
+    pub task: String,
+    /// When the state was entered.
+    pub at: DateTime<Utc>,
+    /// Task outcome, for `Done`.
+    pub success: Option<bool>,
+}
+
 /// Agent execution context.
 #[derive(Debug, Clone)]
 pub struct AgentContext {
@@ -50,6 +73,8 @@ pub struct AgentContext {
     pub success: Option<bool>,
     /// Error message if failed.
     pub error: Option<String>,
+    /// States entered, oldest first, across all of the agent's tasks.
+    pub history: Vec<Transition>,
 }
 
 impl AgentContext {
@@ -63,40 +88,74 @@ impl AgentContext {
             worktree_path: worktree_path.to_string(),
             success: None,
             error: None,
+            history: vec![Transition {
+                state: AgentState::Assigned,
+                task: task.to_string(),
+                at: Utc::now(),
+                success: None,
+            }],
         }
     }
 
+    /// Enter `state`, recording the transition.
+    fn enter(&mut self, state: AgentState) {
+        self.state = state;
+        self.history.push(Transition {
+            state,
+            task: self.task.clone(),
+            at: Utc::now(),
+            success: if state == AgentState::Done {
+                self.success
+            } else {
+                None
+            },
+        });
+    }
+
     /// Transition to Working state.
     pub fn start(&mut self) {
         if self.state == AgentState::Assigned {
-            self.state = AgentState::Working;
+            self.enter(AgentState::Working);
         }
     }
 
     /// Transition to Done state with success.
     pub fn complete(&mut self) {
         if self.state == AgentState::Working {
-            self.state = AgentState::Done;
             self.success = Some(true);
+            self.enter(AgentState::Done);
         }
     }
 
     /// Transition to Done state with failure.
     pub fn fail(&mut self, error: &str) {
         if self.state == AgentState::Working {
-            self.state = AgentState::Done;
             self.success = Some(false);
             self.error = Some(error.to_string());
+            self.enter(AgentState::Done);
         }
     }
 
     /// Transition to Terminated state.
     pub fn terminate(&mut self) {
         if self.state == AgentState::Done {
-            self.state = AgentState::Terminated;
+            self.enter(AgentState::Terminated);
         }
     }
 
+    /// Time spent in each state, summed across tasks.
+    ///
+    /// A state counts from its transition until the next one, so the current
+    /// state is not included until the agent leaves it.
+    pub fn state_durations(&self) -> HashMap<AgentState, Duration> {
+        let mut durations = HashMap::new();
+        for pair in self.history.windows(2) {
+            let elapsed = (pair[1].at - pair[0].at).to_std().unwrap_or_default();
+            *durations.entry(pair[0].state).or_default() += elapsed;
+        }
+        durations
+    }
+
     /// Check if agent is in a terminal state.
     pub fn is_finished(&self) -> bool {
         matches!(self.state, AgentState::Done | AgentState::Terminated)
@@ -108,11 +167,25 @@ impl AgentContext {
     }
 }
 
+/// Callback for a state change: agent initial, previous state, new state.
+pub type TransitionHook = Box<dyn Fn(char, AgentState, AgentState) + Send>;
+
 /// Tracks lifecycle state for all agents in a sprint.
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct LifecycleTracker {
     /// Agent contexts by initial.
     agents: HashMap<char, AgentContext>,
+    /// Called after each start, complete, fail, and terminate transition.
+    hooks: Vec<TransitionHook>,
+}
+
+impl fmt::Debug for LifecycleTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LifecycleTracker")
+            .field("agents", &self.agents)
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
 }
 
 impl LifecycleTracker {
@@ -122,11 +195,32 @@ impl LifecycleTracker {
     }
 
     /// Register an agent with a task.
+    ///
+    /// An agent that has not started keeps its assignment and only changes
+    /// task; one that has moves on to a fresh context that keeps its history.
     pub fn register(&mut self, initial: char, name: &str, task: &str, worktree_path: &str) {
-        self.agents.insert(
-            initial,
-            AgentContext::new(initial, name, task, worktree_path),
-        );
+        match self.agents.get_mut(&initial) {
+            Some(ctx) if ctx.state == AgentState::Assigned => {
+                ctx.task = task.to_string();
+                ctx.worktree_path = worktree_path.to_string();
+                if let Some(last) = ctx.history.last_mut() {
+                    last.task = task.to_string();
+                }
+            }
+            Some(ctx) => {
+                let mut next = AgentContext::new(initial, name, task, worktree_path);
+                let mut history = std::mem::take(&mut ctx.history);
+                history.append(&mut next.history);
+                next.history = history;
+                *ctx = next;
+            }
+            None => {
+                self.agents.insert(
+                    initial,
+                    AgentContext::new(initial, name, task, worktree_path),
+                );
+            }
+        }
     }
 
     /// Get agent context.
@@ -139,43 +233,78 @@ impl LifecycleTracker {
         self.agents.get_mut(&initial)
     }
 
+    /// Call `hook` after every start, complete, fail, and terminate transition.
+    ///
+    /// Hooks run on the agent's thread while the tracker is borrowed, so they
+    /// should be quick. A panicking hook is caught and logged; the transition
+    /// still happens.
+    pub fn on_transition(&mut self, hook: TransitionHook) {
+        self.hooks.push(hook);
+    }
+
+    /// Apply `transition` to an agent, then run the hooks if its state changed.
+    fn transition(&mut self, initial: char, transition: impl FnOnce(&mut AgentContext)) {
+        let Some(ctx) = self.agents.get_mut(&initial) else {
+            return;
+        };
+        let from = ctx.state;
+        transition(ctx);
+        let to = ctx.state;
+        if from == to {
+            return;
+        }
+        for hook in &self.hooks {
+            if panic::catch_unwind(AssertUnwindSafe(|| hook(initial, from, to))).is_err() {
+                eprintln!(
+                    "warning: lifecycle hook panicked on {} {} -> {}",
+                    initial, from, to
+                );
+            }
+        }
+    }
+
     /// Start an agent's work.
     pub fn start(&mut self, initial: char) {
-        if let Some(ctx) = self.agents.get_mut(&initial) {
-            ctx.start();
-        }
+        self.transition(initial, AgentContext::start);
     }
 
     /// Mark an agent as completed.
     pub fn complete(&mut self, initial: char) {
-        if let Some(ctx) = self.agents.get_mut(&initial) {
-            ctx.complete();
-        }
+        self.transition(initial, AgentContext::complete);
     }
 
     /// Mark an agent as failed.
     pub fn fail(&mut self, initial: char, error: &str) {
-        if let Some(ctx) = self.agents.get_mut(&initial) {
-            ctx.fail(error);
-        }
+        self.transition(initial, |ctx| ctx.fail(error));
     }
 
     /// Terminate an agent.
     pub fn terminate(&mut self, initial: char) {
-        if let Some(ctx) = self.agents.get_mut(&initial) {
-            ctx.terminate();
-        }
+        self.transition(initial, AgentContext::terminate);
     }
 
     /// Terminate all done agents.
     pub fn terminate_all_done(&mut self) {
-        for ctx in self.agents.values_mut() {
-            if ctx.state == AgentState::Done {
-                ctx.terminate();
-            }
+        let done: Vec<char> = self
+            .agents
+            .values()
+            .filter(|ctx| ctx.state == AgentState::Done)
+            .map(|ctx| ctx.initial)
+            .collect();
+        for initial in done {
+            self.terminate(initial);
         }
     }
 
+    /// Time an agent spent in each state (see [`AgentContext::state_durations`]);
+    /// empty for an unknown agent.
+    pub fn state_durations(&self, initial: char) -> HashMap<AgentState, Duration> {
+        self.agents
+            .get(&initial)
+            .map(AgentContext::state_durations)
+            .unwrap_or_default()
+    }
+
     /// Get all agents.
     pub fn all(&self) -> impl Iterator<Item = &AgentContext> {
         self.agents.values()
@@ -225,6 +354,110 @@ impl LifecycleTracker {
             .filter(|ctx| ctx.success == Some(false))
             .count()
     }
+
+    /// Render each agent's transition history as JSON, ordered by initial.
+    ///
+    /// Each transition carries `duration_secs`, the time until the agent's
+    /// next transition (`null` for the latest), and each agent sums those in
+    /// `secs_in_state`. `status` is the outcome of the agent's latest task:
+    /// `success`, `failed`, or `running`.
+    pub fn to_json(&self) -> String {
+        let mut agents: Vec<&AgentContext> = self.agents.values().collect();
+        agents.sort_by_key(|ctx| ctx.initial);
+        let entries: Vec<String> = agents.iter().map(|ctx| agent_json(ctx)).collect();
+        if entries.is_empty() {
+            "{\n  \"agents\": []\n}\n".to_string()
+        } else {
+            format!("{{\n  \"agents\": [\n{}\n  ]\n}}\n", entries.join(",\n"))
+        }
+    }
+
+    /// Update from one agent log message (the text after `time | name | `).
+    ///
+    /// Lets another process (the TUI) follow agent states from
+    /// `agent-<initial>.log`: `Assigned task:` registers the agent and each
+    /// `State: X -> Y` line applies the matching transition.
+    pub fn observe_log(&mut self, initial: char, name: &str, message: &str) {
+        if let Some(task) = message.strip_prefix("Assigned task: ") {
+            let task = task
+                .rsplit_once(" [engine: ")
+                .map_or(task, |(task, _)| task);
+            self.register(initial, name, task, "");
+            return;
+        }
+        match message.strip_prefix("State: ") {
+            Some("ASSIGNED -> WORKING") => self.start(initial),
+            Some("WORKING -> DONE (success)") => self.complete(initial),
+            Some("DONE -> TERMINATED") => self.terminate(initial),
+            Some(transition) => {
+                if let Some(error) = transition
+                    .strip_prefix("WORKING -> DONE (failed: ")
+                    .and_then(|rest| rest.strip_suffix(')'))
+                {
+                    self.fail(initial, error);
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// One agent's object in [`LifecycleTracker::to_json`].
+fn agent_json(ctx: &AgentContext) -> String {
+    let status = match ctx.success {
+        Some(true) if ctx.is_finished() => "success",
+        Some(false) if ctx.is_finished() => "failed",
+        _ => "running",
+    };
+    let error = ctx
+        .error
+        .as_deref()
+        .filter(|_| status == "failed")
+        .map_or("null".to_string(), |e| format!("\"{}\"", escape_json(e)));
+
+    let mut transitions = Vec::new();
+    for (i, t) in ctx.history.iter().enumerate() {
+        let duration = ctx
+            .history
+            .get(i + 1)
+            .map(|next| (next.at - t.at).to_std().unwrap_or_default().as_secs_f64());
+        let success = t
+            .success
+            .map_or(String::new(), |ok| format!(", \"success\": {}", ok));
+        transitions.push(format!(
+            "        {{\"state\": \"{}\", \"task\": \"{}\", \"at\": \"{}\", \"duration_secs\": {}{}}}",
+            t.state,
+            escape_json(&t.task),
+            t.at.to_rfc3339_opts(SecondsFormat::Millis, true),
+            duration.map_or("null".to_string(), |secs| format!("{:.3}", secs)),
+            success
+        ));
+    }
+    let durations = ctx.state_durations();
+    let secs_in_state = [
+        AgentState::Assigned,
+        AgentState::Working,
+        AgentState::Done,
+        AgentState::Terminated,
+    ]
+    .iter()
+    .map(|state| {
+        let secs = durations.get(state).map_or(0.0, Duration::as_secs_f64);
+        format!("\"{}\": {:.3}", state, secs)
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+
+    format!(
+        "    {{\n      \"initial\": \"{}\",\n      \"name\": \"{}\",\n      \"state\": \"{}\",\n      \"status\": \"{}\",\n      \"error\": {},\n      \"secs_in_state\": {{{}}},\n      \"transitions\": [\n{}\n      ]\n    }}",
+        crate::agent::id(ctx.initial),
+        escape_json(&ctx.name),
+        ctx.state,
+        status,
+        error,
+        secs_in_state,
+        transitions.join(",\n")
+    )
 }
 
 #[cfg(test)]
@@ -322,4 +555,198 @@ mod tests {
         assert_eq!(assigned.len(), 1);
         assert_eq!(assigned[0].initial, 'B');
     }
+
+    #[test]
+    fn test_tracker_observe_log() {
+        let mut tracker = LifecycleTracker::new();
+        tracker.observe_log('A', "Aaron", "Working directory: /tmp/wt");
+        assert!(tracker.get('A').is_none());
+
+        tracker.observe_log('A', "Aaron", "Assigned task: Write docs [engine: claude]");
+        let ctx = tracker.get('A').unwrap();
+        assert_eq!(
+            (ctx.state, ctx.task.as_str()),
+            (AgentState::Assigned, "Write docs")
+        );
+
+        tracker.observe_log('A', "Aaron", "State: ASSIGNED -> WORKING");
+        assert_eq!(tracker.get('A').unwrap().state, AgentState::Working);
+        tracker.observe_log('A', "Aaron", "State: WORKING -> DONE (failed: timed out)");
+        let ctx = tracker.get('A').unwrap();
+        assert_eq!(ctx.state, AgentState::Done);
+        assert_eq!(ctx.error.as_deref(), Some("timed out"));
+        tracker.observe_log('A', "Aaron", "State: DONE -> TERMINATED");
+        assert_eq!(tracker.get('A').unwrap().state, AgentState::Terminated);
+
+        // The next task starts a fresh context
+        tracker.observe_log('A', "Aaron", "Assigned task: Fix tests [engine: codex]");
+        tracker.observe_log('A', "Aaron", "State: ASSIGNED -> WORKING");
+        tracker.observe_log('A', "Aaron", "State: WORKING -> DONE (success)");
+        let ctx = tracker.get('A').unwrap();
+        assert_eq!(ctx.task, "Fix tests");
+        assert!(ctx.succeeded());
+    }
+
+    #[test]
+    fn test_tracker_to_json() {
+        let mut tracker = LifecycleTracker::new();
+        tracker.register('B', "Betty", "Fix \"bug\"", "/wt/b");
+        tracker.register('A', "Aaron", "Write tests", "/wt/a");
+        tracker.start('A');
+        tracker.complete('A');
+        tracker.terminate('A');
+        tracker.start('B');
+        tracker.fail('B', "timed out");
+
+        // Pin timestamps: one second per step for Aaron, two for Betty
+        let base = DateTime::parse_from_rfc3339("2026-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        for (initial, step) in [('A', 1), ('B', 2)] {
+            let ctx = tracker.get_mut(initial).unwrap();
+            for (i, t) in ctx.history.iter_mut().enumerate() {
+                t.at = base + chrono::Duration::seconds(step * i as i64);
+            }
+        }
+
+        let json = tracker.to_json();
+        let aaron = json.find("\"name\": \"Aaron\"").unwrap();
+        let betty = json.find("\"name\": \"Betty\"").unwrap();
+        assert!(aaron < betty, "{}", json);
+        let (aaron, betty) = json.split_at(betty);
+
+        assert!(aaron.contains("\"state\": \"terminated\""), "{}", aaron);
+        assert!(aaron.contains("\"status\": \"success\""), "{}", aaron);
+        assert!(aaron.contains("\"error\": null"), "{}", aaron);
+        assert!(aaron.contains(
+            "{\"state\": \"assigned\", \"task\": \"Write tests\", \"at\": \"2026-01-02T03:04:05.000Z\", \"duration_secs\": 1.000}"
+        ), "{}", aaron);
+        assert!(aaron.contains(
+            "{\"state\": \"done\", \"task\": \"Write tests\", \"at\": \"2026-01-02T03:04:07.000Z\", \"duration_secs\": 1.000, \"success\": true}"
+        ), "{}", aaron);
+        assert!(aaron.contains("\"duration_secs\": null}"), "{}", aaron);
+        assert!(aaron.contains(
+            "\"secs_in_state\": {\"assigned\": 1.000, \"working\": 1.000, \"done\": 1.000, \"terminated\": 0.000}"
+        ), "{}", aaron);
+
+        assert!(betty.contains("\"state\": \"done\""), "{}", betty);
+        assert!(betty.contains("\"status\": \"failed\""), "{}", betty);
+        assert!(betty.contains("\"error\": \"timed out\""), "{}", betty);
+        assert!(betty.contains("\"task\": \"Fix \\\"bug\\\"\""), "{}", betty);
+        assert!(betty.contains("\"success\": false"), "{}", betty);
+        assert!(betty.contains(
+            "\"secs_in_state\": {\"assigned\": 2.000, \"working\": 2.000, \"done\": 0.000, \"terminated\": 0.000}"
+        ), "{}", betty);
+
+        assert_eq!(
+            LifecycleTracker::new().to_json(),
+            "{\n  \"agents\": []\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_register_keeps_history_across_tasks() {
+        let mut tracker = LifecycleTracker::new();
+        tracker.register('A', "Aaron", "Task one", "/wt/a");
+        // Re-registering before the agent starts only changes the task
+        tracker.register('A', "Aaron", "Task one (retitled)", "/wt/a");
+        assert_eq!(tracker.get('A').unwrap().history.len(), 1);
+
+        tracker.start('A');
+        tracker.complete('A');
+        tracker.terminate('A');
+        tracker.register('A', "Aaron", "Task two", "/wt/a");
+        tracker.start('A');
+
+        let ctx = tracker.get('A').unwrap();
+        assert_eq!(ctx.state, AgentState::Working);
+        let steps: Vec<(AgentState, &str)> = ctx
+            .history
+            .iter()
+            .map(|t| (t.state, t.task.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (AgentState::Assigned, "Task one (retitled)"),
+                (AgentState::Working, "Task one (retitled)"),
+                (AgentState::Done, "Task one (retitled)"),
+                (AgentState::Terminated, "Task one (retitled)"),
+                (AgentState::Assigned, "Task two"),
+                (AgentState::Working, "Task two"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_state_durations_time_working() {
+        let mut tracker = LifecycleTracker::new();
+        tracker.register('A', "Aaron", "Task A", "/wt/a");
+        tracker.start('A');
+        std::thread::sleep(Duration::from_millis(20));
+        tracker.complete('A');
+
+        let durations = tracker.state_durations('A');
+        assert!(durations[&AgentState::Working] >= Duration::from_millis(20));
+        assert!(durations.contains_key(&AgentState::Assigned));
+        // Done is still open
+        assert!(!durations.contains_key(&AgentState::Done));
+
+        // A second task adds to the same totals
+        tracker.terminate('A');
+        tracker.register('A', "Aaron", "Task B", "/wt/a");
+        tracker.start('A');
+        std::thread::sleep(Duration::from_millis(20));
+        tracker.fail('A', "error");
+        let durations = tracker.state_durations('A');
+        assert!(durations[&AgentState::Working] >= Duration::from_millis(40));
+        assert!(durations.contains_key(&AgentState::Done));
+
+        assert!(tracker.state_durations('Z').is_empty());
+    }
+
+    #[test]
+    fn test_transition_hooks_fire_in_order() {
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut tracker = LifecycleTracker::new();
+        let sink = Arc::clone(&seen);
+        tracker.on_transition(Box::new(move |initial, from, to| {
+            sink.lock().unwrap().push((initial, from, to));
+        }));
+        // A panicking hook neither stops the transition nor later hooks
+        tracker.on_transition(Box::new(|_, _, _| panic!("hook failed")));
+        let after_panic = Arc::new(Mutex::new(0));
+        let count = Arc::clone(&after_panic);
+        tracker.on_transition(Box::new(move |_, _, _| *count.lock().unwrap() += 1));
+
+        tracker.register('A', "Aaron", "Task A", "/wt/a");
+        tracker.register('B', "Betty", "Task B", "/wt/b");
+        tracker.start('A');
+        tracker.complete('A');
+        tracker.start('B');
+        tracker.fail('B', "error");
+        // Invalid transitions do not fire
+        tracker.complete('B');
+        tracker.start('Z');
+        tracker.terminate_all_done();
+
+        let mut seen = seen.lock().unwrap().clone();
+        // terminate_all_done visits agents in any order
+        seen[4..].sort_by_key(|(initial, _, _)| *initial);
+        assert_eq!(
+            seen,
+            vec![
+                ('A', AgentState::Assigned, AgentState::Working),
+                ('A', AgentState::Working, AgentState::Done),
+                ('B', AgentState::Assigned, AgentState::Working),
+                ('B', AgentState::Working, AgentState::Done),
+                ('A', AgentState::Done, AgentState::Terminated),
+                ('B', AgentState::Done, AgentState::Terminated),
+            ]
+        );
+        assert_eq!(*after_panic.lock().unwrap(), 6);
+        assert_eq!(tracker.counts(), (0, 0, 0, 2));
+    }
 }
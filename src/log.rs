@@ -1,17 +1,203 @@
 //! Per-agent logging with rotation.
 //!
 //! Provides file-based logging for agents with automatic rotation when
-//! log files exceed a configurable line limit.
+//! log files exceed a configurable line limit. Rotated content is kept as
+//! gzip archives (`agent-A.log.1.gz` newest, up to `files.log_max_rotations`).
+//!
+//! Entries are `timestamp | name | message` text lines, or with
+//! `--log-format json` one `{ts, agent, initial, level, message}` JSON object
+//! per line. Either way each entry is one line, so rotation by line count
+//! works the same.
 
 use std::fs::{self, File, OpenOptions};
-use std::io::{self, BufRead, BufReader, Write};
+use std::io::{self, BufRead, BufReader, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
 use chrono::Local;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::chat::{self, Level};
+use crate::json::{escape_json, parse_json_fields};
 
 /// Default maximum number of lines before rotation.
 pub const DEFAULT_MAX_LINES: usize = 1000;
 
+/// Default number of compressed archives kept per log.
+pub const DEFAULT_MAX_ROTATIONS: usize = 5;
+
+/// Timestamp format of log entries.
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Log entry line format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// `timestamp | name | message` lines.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+impl LogFormat {
+    /// Parse format from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+        }
+    }
+}
+
+static FORMAT: Mutex<LogFormat> = Mutex::new(LogFormat::Text);
+
+/// Set the format of loggers created after this call.
+pub fn set_format(format: LogFormat) {
+    *FORMAT.lock().unwrap_or_else(|e| e.into_inner()) = format;
+}
+
+fn default_format() -> LogFormat {
+    *FORMAT.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+static MAX_ROTATIONS: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_ROTATIONS);
+
+/// Set the number of archives kept by loggers created after this call.
+pub fn set_max_rotations(max_rotations: usize) {
+    MAX_ROTATIONS.store(max_rotations, Ordering::SeqCst);
+}
+
+fn default_max_rotations() -> usize {
+    MAX_ROTATIONS.load(Ordering::SeqCst)
+}
+
+/// One parsed log line, from either format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub timestamp: String,
+    /// Agent or logger name.
+    pub agent: String,
+    /// Agent initial; `None` for named loggers and text lines.
+    pub initial: Option<char>,
+    pub level: Level,
+    /// Message text, without a level marker.
+    pub message: String,
+}
+
+impl LogEntry {
+    fn now(name: &str, initial: Option<char>, level: Level, message: &str) -> Self {
+        Self {
+            timestamp: Local::now().format(TIMESTAMP_FORMAT).to_string(),
+            agent: name.to_string(),
+            initial,
+            level,
+            message: message.to_string(),
+        }
+    }
+
+    /// Render as a text log line, with a `[WARN]`/`[ERROR]` marker above info.
+    pub fn to_text(&self) -> String {
+        match self.level.marker() {
+            Some(marker) => format!(
+                "{} | {} | {} {}",
+                self.timestamp, self.agent, marker, self.message
+            ),
+            None => format!("{} | {} | {}", self.timestamp, self.agent, self.message),
+        }
+    }
+
+    /// Render as a JSON log line (`initial` is left out for named loggers).
+    pub fn to_json(&self) -> String {
+        let initial = self.initial.map_or(String::new(), |c| {
+            format!(", \"initial\": \"{}\"", crate::agent::id(c))
+        });
+        format!(
+            "{{\"ts\": \"{}\", \"agent\": \"{}\"{}, \"level\": \"{}\", \"message\": \"{}\"}}",
+            escape_json(&self.timestamp),
+            escape_json(&self.agent),
+            initial,
+            self.level.as_str(),
+            escape_json(&self.message)
+        )
+    }
+
+    fn render(&self, format: LogFormat) -> String {
+        match format {
+            LogFormat::Text => self.to_text(),
+            LogFormat::Json => self.to_json(),
+        }
+    }
+}
+
+/// Parse a log line in either format; `None` for session separators and
+/// continuation lines of multi-line text messages.
+pub fn parse_line(line: &str) -> Option<LogEntry> {
+    if line.trim_start().starts_with('{') {
+        let mut entry = LogEntry {
+            timestamp: String::new(),
+            agent: String::new(),
+            initial: None,
+            level: Level::Info,
+            message: String::new(),
+        };
+        let mut has_agent = false;
+        for (key, value) in parse_json_fields(line)? {
+            match key.as_str() {
+                "ts" => entry.timestamp = value,
+                "agent" => {
+                    entry.agent = value;
+                    has_agent = true;
+                }
+                "initial" => entry.initial = crate::agent::initial_from_id(&value),
+                "level" => entry.level = Level::parse(&value).unwrap_or_default(),
+                "message" => entry.message = value,
+                _ => {}
+            }
+        }
+        return has_agent.then_some(entry);
+    }
+    let (timestamp, agent, message) = chat::parse_line(line)?;
+    let (level, message) = Level::of_message(message);
+    Some(LogEntry {
+        timestamp: timestamp.to_string(),
+        agent: agent.to_string(),
+        initial: None,
+        level,
+        message: message.to_string(),
+    })
+}
+
+/// Render a log line for display: JSON entries become text lines, anything
+/// else is returned unchanged.
+pub fn display_line(line: &str) -> String {
+    match parse_line(line) {
+        Some(entry) if line.trim_start().starts_with('{') => entry.to_text(),
+        _ => line.to_string(),
+    }
+}
+
+/// Append `entry` to `path` in `format`, creating the directory if needed.
+fn append_entry(path: &Path, entry: &LogEntry, format: LogFormat) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(format!("{}\n", entry.render(format)).as_bytes())?;
+    file.flush()
+}
+
 /// A logger for a specific agent.
 pub struct AgentLogger {
     /// Path to the log file.
@@ -22,6 +208,10 @@ pub struct AgentLogger {
     pub initial: char,
     /// Agent name (for logging context).
     pub name: String,
+    /// Entry format (defaults to the one set with [`set_format`]).
+    pub format: LogFormat,
+    /// Compressed archives kept on rotation (0 discards rotated content).
+    pub max_rotations: usize,
 }
 
 /// A named logger for non-agent processes (e.g., merge agent).
@@ -32,6 +222,10 @@ pub struct NamedLogger {
     pub max_lines: usize,
     /// Logger name (for logging context).
     pub name: String,
+    /// Entry format (defaults to the one set with [`set_format`]).
+    pub format: LogFormat,
+    /// Compressed archives kept on rotation (0 discards rotated content).
+    pub max_rotations: usize,
 }
 
 impl AgentLogger {
@@ -48,6 +242,8 @@ impl AgentLogger {
             max_lines: DEFAULT_MAX_LINES,
             initial,
             name: name.to_string(),
+            format: default_format(),
+            max_rotations: default_max_rotations(),
         }
     }
 
@@ -57,22 +253,30 @@ impl AgentLogger {
         self
     }
 
+    /// Create a logger writing entries in `format`.
+    pub fn with_format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Create a logger keeping `max_rotations` compressed archives.
+    pub fn with_max_rotations(mut self, max_rotations: usize) -> Self {
+        self.max_rotations = max_rotations;
+        self
+    }
+
     /// Write a log entry.
     ///
-    /// Format: `YYYY-MM-DD HH:MM:SS | <AgentName> | <message>`
+    /// Format: `YYYY-MM-DD HH:MM:SS | <AgentName> | <message>`, or a JSON
+    /// object with `LogFormat::Json`.
     pub fn log(&self, message: &str) -> io::Result<()> {
-        self.ensure_dir()?;
-
-        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-        let line = format!("{} | {} | {}\n", timestamp, self.name, message);
-
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.path)?;
+        self.log_level(Level::Info, message)
+    }
 
-        file.write_all(line.as_bytes())?;
-        file.flush()?;
+    /// Write a log entry at `level` (marked `[WARN]`/`[ERROR]` in text logs).
+    pub fn log_level(&self, level: Level, message: &str) -> io::Result<()> {
+        let entry = LogEntry::now(&self.name, Some(self.initial), level, message);
+        append_entry(&self.path, &entry, self.format)?;
 
         // Check if rotation is needed
         self.rotate_if_needed()?;
@@ -80,16 +284,22 @@ impl AgentLogger {
         Ok(())
     }
 
-    /// Write a separator for a new run/session.
+    /// Write a separator for a new run/session (a `Session started` entry in
+    /// JSON logs).
     pub fn log_session_start(&self) -> io::Result<()> {
+        if self.format == LogFormat::Json {
+            return self.log("Session started");
+        }
         self.ensure_dir()?;
 
-        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+        let timestamp = Local::now().format(TIMESTAMP_FORMAT);
         let separator = format!(
             "\n======================================================================\n\
              === Agent {} ({}) - Session Started at {} ===\n\
              ======================================================================\n\n",
-            self.name, self.initial, timestamp
+            self.name,
+            crate::agent::id(self.initial),
+            timestamp
         );
 
         let mut file = OpenOptions::new()
@@ -122,7 +332,7 @@ impl AgentLogger {
             return Ok(());
         }
 
-        rotate_log(&self.path)?;
+        rotate_log(&self.path, self.max_rotations)?;
         Ok(())
     }
 
@@ -166,6 +376,8 @@ impl NamedLogger {
             path,
             max_lines: DEFAULT_MAX_LINES,
             name: name.to_string(),
+            format: default_format(),
+            max_rotations: default_max_rotations(),
         }
     }
 
@@ -175,35 +387,31 @@ impl NamedLogger {
         self
     }
 
+    /// Create a logger writing entries in `format`.
+    pub fn with_format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Create a logger keeping `max_rotations` compressed archives.
+    pub fn with_max_rotations(mut self, max_rotations: usize) -> Self {
+        self.max_rotations = max_rotations;
+        self
+    }
+
     /// Write a log entry.
     ///
-    /// Format: `YYYY-MM-DD HH:MM:SS | <Name> | <message>`
+    /// Format: `YYYY-MM-DD HH:MM:SS | <Name> | <message>`, or a JSON object
+    /// (without `initial`) with `LogFormat::Json`.
     pub fn log(&self, message: &str) -> io::Result<()> {
-        self.ensure_dir()?;
-
-        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-        let line = format!("{} | {} | {}\n", timestamp, self.name, message);
-
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.path)?;
-
-        file.write_all(line.as_bytes())?;
-        file.flush()?;
+        let entry = LogEntry::now(&self.name, None, Level::Info, message);
+        append_entry(&self.path, &entry, self.format)?;
 
         self.rotate_if_needed()?;
 
         Ok(())
     }
 
-    fn ensure_dir(&self) -> io::Result<()> {
-        if let Some(parent) = self.path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        Ok(())
-    }
-
     fn rotate_if_needed(&self) -> io::Result<()> {
         if !self.path.exists() {
             return Ok(());
@@ -214,14 +422,14 @@ impl NamedLogger {
             return Ok(());
         }
 
-        rotate_log(&self.path)?;
+        rotate_log(&self.path, self.max_rotations)?;
         Ok(())
     }
 }
 
 /// Get the log file path for an agent.
 pub fn log_file_path(log_dir: &Path, initial: char) -> PathBuf {
-    log_dir.join(format!("agent-{}.log", initial))
+    log_dir.join(format!("agent-{}.log", crate::agent::id(initial)))
 }
 
 /// Count lines in a file.
@@ -231,33 +439,66 @@ pub fn count_lines(path: &Path) -> io::Result<usize> {
     Ok(reader.lines().count())
 }
 
+/// Path of a log's `generation`-th compressed archive (`agent-A.log.1.gz`
+/// is the newest).
+pub fn rotated_path(path: &Path, generation: usize) -> PathBuf {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("log");
+    path.with_file_name(format!("{}.{}.gz", name, generation))
+}
+
+/// Read a log's `generation`-th archive, decompressed.
+pub fn read_rotated(path: &Path, generation: usize) -> io::Result<String> {
+    let mut content = String::new();
+    GzDecoder::new(File::open(rotated_path(path, generation))?).read_to_string(&mut content)?;
+    Ok(content)
+}
+
 /// Rotate a log file.
 ///
-/// Creates a timestamped backup and clears the original file.
-pub fn rotate_log(path: &Path) -> io::Result<()> {
+/// Compresses its content to `<log>.1.gz`, shifting older archives up one
+/// generation and dropping those beyond `max_rotations`, then clears the
+/// log. With `max_rotations` 0 the content is discarded.
+pub fn rotate_log(path: &Path, max_rotations: usize) -> io::Result<()> {
     if !path.exists() {
         return Ok(());
     }
 
-    let timestamp = Local::now().format("%Y%m%d-%H%M%S");
-    let backup_name = format!(
-        "{}.{}.bak",
-        path.file_name().and_then(|n| n.to_str()).unwrap_or("log"),
-        timestamp
-    );
-    let backup_path = path.with_file_name(backup_name);
+    // Drop the oldest generation (and any left from a higher limit)
+    let mut generation = max_rotations.max(1);
+    while rotated_path(path, generation).exists() {
+        fs::remove_file(rotated_path(path, generation))?;
+        generation += 1;
+    }
 
-    // Move current log to backup
-    fs::rename(path, &backup_path)?;
+    if max_rotations > 0 {
+        for generation in (1..max_rotations).rev() {
+            let archive = rotated_path(path, generation);
+            if archive.exists() {
+                fs::rename(&archive, rotated_path(path, generation + 1))?;
+            }
+        }
+        let content = fs::read(path)?;
+        let mut encoder =
+            GzEncoder::new(File::create(rotated_path(path, 1))?, Compression::default());
+        encoder.write_all(&content)?;
+        encoder.finish()?;
+    }
 
-    // Create empty new log file
+    // Start the log fresh
     File::create(path)?;
 
     Ok(())
 }
 
-/// Rotate all log files in a directory that exceed the max line count.
-pub fn rotate_logs_in_dir(log_dir: &Path, max_lines: usize) -> io::Result<()> {
+/// Rotate all log files in a directory that exceed the max line count,
+/// keeping `max_rotations` compressed archives of each.
+///
+/// Text and JSON logs both hold one entry per line, so either rotates the same.
+pub fn rotate_logs_in_dir(
+    log_dir: &Path,
+    max_lines: usize,
+    max_rotations: usize,
+) -> io::Result<()> {
     if !log_dir.exists() {
         return Ok(());
     }
@@ -269,7 +510,7 @@ pub fn rotate_logs_in_dir(log_dir: &Path, max_lines: usize) -> io::Result<()> {
         if path.extension().and_then(|e| e.to_str()) == Some("log") {
             let line_count = count_lines(&path)?;
             if line_count > max_lines {
-                rotate_log(&path)?;
+                rotate_log(&path, max_rotations)?;
             }
         }
     }
@@ -368,6 +609,30 @@ mod tests {
         fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn test_extended_initial_uses_agent_id() {
+        let dir = temp_dir();
+        let initial = crate::agent::initial_from_id("AB").unwrap();
+        let logger = AgentLogger::new(&dir, initial, "Abner");
+
+        logger.log_session_start().unwrap();
+        let content = fs::read_to_string(&logger.path).unwrap();
+        assert!(
+            content.contains("=== Agent Abner (AB) - Session"),
+            "{}",
+            content
+        );
+
+        let json =
+            AgentLogger::new(&dir.join("json"), initial, "Abner").with_format(LogFormat::Json);
+        json.log("Working").unwrap();
+        let line = json.read_all().unwrap().remove(0);
+        assert!(line.contains("\"initial\": \"AB\""), "{}", line);
+        assert_eq!(parse_line(&line).unwrap().initial, Some(initial));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_count_lines() {
         let dir = temp_dir();
@@ -388,21 +653,16 @@ mod tests {
         let path = dir.join("test.log");
 
         fs::write(&path, "line1\nline2\nline3\n").unwrap();
-        rotate_log(&path).unwrap();
+        rotate_log(&path, 2).unwrap();
 
         // Original file should be empty/recreated
         assert_eq!(fs::read_to_string(&path).unwrap(), "");
 
-        // Backup file should exist
-        let backups: Vec<_> = fs::read_dir(&dir)
-            .unwrap()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.path().to_string_lossy().contains(".bak"))
-            .collect();
-        assert_eq!(backups.len(), 1);
-
-        let backup_content = fs::read_to_string(backups[0].path()).unwrap();
-        assert_eq!(backup_content, "line1\nline2\nline3\n");
+        // The overflow is kept compressed
+        let archive = rotated_path(&path, 1);
+        assert_eq!(archive, dir.join("test.log.1.gz"));
+        assert_ne!(fs::read(&archive).unwrap(), b"line1\nline2\nline3\n");
+        assert_eq!(read_rotated(&path, 1).unwrap(), "line1\nline2\nline3\n");
 
         fs::remove_dir_all(&dir).ok();
     }
@@ -413,7 +673,8 @@ mod tests {
         let path = dir.join("nonexistent.log");
 
         // Should not fail on nonexistent file
-        rotate_log(&path).unwrap();
+        rotate_log(&path, 2).unwrap();
+        assert!(!rotated_path(&path, 1).exists());
 
         fs::remove_dir_all(&dir).ok();
     }
@@ -432,13 +693,9 @@ mod tests {
         let line_count = logger.line_count().unwrap();
         assert!(line_count <= 5, "Expected <= 5 lines, got {}", line_count);
 
-        // A backup should exist
-        let backups: Vec<_> = fs::read_dir(&dir)
-            .unwrap()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.path().to_string_lossy().contains(".bak"))
-            .collect();
-        assert!(!backups.is_empty(), "Expected backup file to exist");
+        // The rotated lines should be archived
+        let archived = read_rotated(&logger.path, 1).unwrap();
+        assert!(archived.contains("Line 0"), "{}", archived);
 
         fs::remove_dir_all(&dir).ok();
     }
@@ -486,7 +743,7 @@ mod tests {
         fs::write(&other, "Not a log file").unwrap();
 
         // Rotate with max 10 lines
-        rotate_logs_in_dir(&dir, 10).unwrap();
+        rotate_logs_in_dir(&dir, 10, 3).unwrap();
 
         // log1 should be rotated (had 20 lines)
         assert_eq!(fs::read_to_string(&log1).unwrap(), "");
@@ -497,16 +754,9 @@ mod tests {
         // other.txt should be unchanged
         assert_eq!(fs::read_to_string(&other).unwrap(), "Not a log file");
 
-        // Backup for log1 should exist
-        let backups: Vec<_> = fs::read_dir(&dir)
-            .unwrap()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.path().to_string_lossy().contains("agent-A.log")
-                    && e.path().to_string_lossy().contains(".bak")
-            })
-            .collect();
-        assert_eq!(backups.len(), 1);
+        // log1's overflow should be archived; log2 has no archive
+        assert_eq!(read_rotated(&log1, 1).unwrap(), content1);
+        assert!(!rotated_path(&log2, 1).exists());
 
         fs::remove_dir_all(&dir).ok();
     }
@@ -524,4 +774,128 @@ mod tests {
 
         fs::remove_dir_all(&dir).ok();
     }
+
+    /// Check that `line` is one JSON object of string values and return them.
+    fn json_fields(line: &str) -> Vec<(String, String)> {
+        assert!(line.starts_with('{') && line.ends_with('}'), "{}", line);
+        parse_json_fields(line).unwrap_or_else(|| panic!("invalid JSON: {}", line))
+    }
+
+    #[test]
+    fn test_json_log_lines() {
+        let dir = temp_dir();
+        let logger = AgentLogger::new(&dir, 'A', "Aaron").with_format(LogFormat::Json);
+
+        logger.log_session_start().unwrap();
+        logger.log("Engine output:\nline \"one\"\tdone").unwrap();
+        logger.log_level(Level::Error, "Task failed").unwrap();
+
+        let lines = logger.read_all().unwrap();
+        assert_eq!(lines.len(), 3, "{:?}", lines);
+        let keys: Vec<String> = json_fields(&lines[1]).into_iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, ["ts", "agent", "initial", "level", "message"]);
+
+        let entry = parse_line(&lines[0]).unwrap();
+        assert_eq!(entry.message, "Session started");
+        let entry = parse_line(&lines[1]).unwrap();
+        assert_eq!(
+            (entry.agent.as_str(), entry.initial, entry.level),
+            ("Aaron", Some('A'), Level::Info)
+        );
+        assert_eq!(entry.message, "Engine output:\nline \"one\"\tdone");
+        let entry = parse_line(&lines[2]).unwrap();
+        assert_eq!(entry.level, Level::Error);
+
+        // Named loggers leave out the initial
+        let merge =
+            NamedLogger::new(&dir, "MergeAgent", "merge-agent.log").with_format(LogFormat::Json);
+        merge.log("Merging").unwrap();
+        let line = fs::read_to_string(&merge.path).unwrap();
+        let fields = json_fields(line.trim_end());
+        assert!(fields.iter().all(|(k, _)| k != "initial"), "{}", line);
+        assert_eq!(parse_line(line.trim_end()).unwrap().initial, None);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_display_line_renders_json_as_text() {
+        let json = "{\"ts\": \"2026-01-01 12:00:00\", \"agent\": \"Aaron\", \"initial\": \"A\", \"level\": \"warn\", \"message\": \"Retrying\"}";
+        assert_eq!(
+            display_line(json),
+            "2026-01-01 12:00:00 | Aaron | [WARN] Retrying"
+        );
+        let text = "2026-01-01 12:00:00 | Aaron | State: ASSIGNED -> WORKING";
+        assert_eq!(display_line(text), text);
+        assert_eq!(display_line("=== Agent Aaron ==="), "=== Agent Aaron ===");
+
+        // Text lines parse too, with the level taken from the marker
+        let entry = parse_line("2026-01-01 12:00:00 | Aaron | [ERROR] Boom").unwrap();
+        assert_eq!(
+            (entry.level, entry.message.as_str()),
+            (Level::Error, "Boom")
+        );
+    }
+
+    #[test]
+    fn test_json_log_rotation() {
+        let dir = temp_dir();
+        let logger = AgentLogger::new(&dir, 'A', "Aaron")
+            .with_format(LogFormat::Json)
+            .with_max_lines(5);
+
+        // Multi-line messages stay one line each
+        for i in 0..10 {
+            logger.log(&format!("Line {}\nmore", i)).unwrap();
+        }
+        let line_count = logger.line_count().unwrap();
+        assert!(line_count <= 5, "Expected <= 5 lines, got {}", line_count);
+        for line in logger.read_all().unwrap() {
+            json_fields(&line);
+        }
+
+        // Directory rotation counts JSON entries like text lines
+        let other = AgentLogger::new(&dir, 'B', "Betty").with_format(LogFormat::Json);
+        for i in 0..4 {
+            other.log(&format!("Entry {}", i)).unwrap();
+        }
+        rotate_logs_in_dir(&dir, 3, 1).unwrap();
+        assert_eq!(other.line_count().unwrap(), 0);
+        let archived = read_rotated(&other.path, 1).unwrap();
+        assert_eq!(archived.lines().count(), 4);
+        for line in archived.lines() {
+            json_fields(line);
+        }
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_rotate_log_keeps_max_rotations_generations() {
+        let dir = temp_dir();
+        let path = dir.join("agent-A.log");
+
+        for round in 1..=4 {
+            fs::write(&path, format!("round {}\n", round)).unwrap();
+            rotate_log(&path, 2).unwrap();
+        }
+        // Newest first; older generations were dropped
+        assert_eq!(read_rotated(&path, 1).unwrap(), "round 4\n");
+        assert_eq!(read_rotated(&path, 2).unwrap(), "round 3\n");
+        assert!(!rotated_path(&path, 3).exists());
+
+        // Lowering the limit drops the extra generations
+        fs::write(&path, "round 5\n").unwrap();
+        rotate_log(&path, 1).unwrap();
+        assert_eq!(read_rotated(&path, 1).unwrap(), "round 5\n");
+        assert!(!rotated_path(&path, 2).exists());
+
+        // With no rotations kept the content is discarded
+        fs::write(&path, "round 6\n").unwrap();
+        rotate_log(&path, 0).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "");
+        assert!(!rotated_path(&path, 1).exists());
+
+        fs::remove_dir_all(&dir).ok();
+    }
 }
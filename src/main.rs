@@ -1,8 +1,9 @@
 use std::env;
+use std::path::Path;
 use std::process;
 
 use swarm::config::{self, Command, Config};
-use swarm::shutdown;
+use swarm::{agent, audit, chat, engine, log, pause, prompt_dump, shutdown, team, verbosity};
 
 mod commands;
 mod git;
@@ -14,12 +15,12 @@ mod tail;
 mod testutil;
 
 use commands::{
-    cmd_agents, cmd_cleanup_worktrees, cmd_customize_prompts, cmd_init, cmd_project_init,
-    cmd_projects, cmd_run, cmd_run_tui, cmd_set_email,
+    cmd_agents, cmd_chat, cmd_cleanup, cmd_cleanup_worktrees, cmd_config_validate,
+    cmd_customize_prompts, cmd_diff, cmd_init, cmd_kill, cmd_logs, cmd_project_init, cmd_projects,
+    cmd_prune, cmd_ps, cmd_run, cmd_run_dry, cmd_run_tui, cmd_set_email, cmd_status,
+    cmd_team_archive, cmd_team_rename, cmd_watch, cmd_worktrees,
 };
 
-const VERSION: &str = env!("CARGO_PKG_VERSION");
-
 fn main() {
     let args: Vec<String> = env::args().collect();
     let cli = config::parse_args(args);
@@ -30,7 +31,7 @@ fn main() {
     }
 
     if cli.version {
-        println!("swarm {}", VERSION);
+        output::print_version(cli.verbose > 0);
         return;
     }
 
@@ -39,6 +40,19 @@ fn main() {
         process::exit(1);
     }
 
+    // Validation reports config problems itself instead of failing on the first
+    if cli.command == Some(Command::ConfigValidate) {
+        if let Err(e) = cmd_config_validate(&cli) {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+    if cli.command == Some(Command::ConfigSchema) {
+        print!("{}", config::json_schema());
+        return;
+    }
+
     if let Err(e) = git::ensure_min_git_version() {
         eprintln!("error: {}", e);
         process::exit(1);
@@ -51,12 +65,29 @@ fn main() {
             process::exit(1);
         }
     };
+    verbosity::set(config.verbosity);
+    match agent::Roster::load(&Path::new(team::SWARM_HUG_DIR).join(team::ROSTER_FILE)) {
+        Ok(Some(roster)) => agent::set_roster(roster),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+    prompt_dump::set_dir(config.dump_prompts_dir.as_ref().map(Into::into));
+    audit::set_syslog(config.audit_syslog.then_some(config.audit_syslog_facility));
+    engine::set_ollama_url(Some(config.engine_ollama_url.clone()));
+    chat::set_format(config.files_chat_format);
+    log::set_format(config.files_log_format);
+    log::set_max_rotations(config.files_log_max_rotations);
+    chat::set_timestamp_style(config.files_chat_timezone, &config.files_chat_time_format);
+    pause::set_file(env::var_os(pause::PAUSE_FILE_ENV).map(Into::into));
 
     // Default command is Run if none specified
     let command = cli.command.clone().unwrap_or(Command::Run);
 
-    // Register Ctrl+C handler for commands that run sprints
-    if matches!(command, Command::Run) {
+    // Register Ctrl+C/SIGTERM handlers for commands that run sprints or follow chat
+    if matches!(command, Command::Run | Command::Resume | Command::Watch) {
         if let Err(e) = shutdown::register_handler() {
             eprintln!("warning: {}", e);
         }
@@ -64,8 +95,10 @@ fn main() {
 
     let result = match command {
         Command::Init => cmd_init(&config),
-        Command::Run => {
-            if cli.no_tui {
+        Command::Run | Command::Resume => {
+            if config.dry_run {
+                cmd_run_dry(&config)
+            } else if cli.no_tui {
                 cmd_run(&config)
             } else {
                 cmd_run_tui(&config)
@@ -77,6 +110,23 @@ fn main() {
         Command::CustomizePrompts => cmd_customize_prompts(),
         Command::SetEmail => cmd_set_email(&cli),
         Command::CleanupWorktrees => cmd_cleanup_worktrees(&config),
+        Command::Cleanup => cmd_cleanup(&config, &cli),
+        Command::Prune => cmd_prune(&config),
+        Command::Worktrees => cmd_worktrees(&config, &cli),
+        Command::Status => cmd_status(&config, &cli),
+        Command::Watch => cmd_watch(&config),
+        Command::Chat => cmd_chat(&config, &cli),
+        Command::Logs => cmd_logs(&config, &cli),
+        Command::Diff => cmd_diff(&config, &cli),
+        Command::Kill => cmd_kill(&config),
+        Command::Ps => cmd_ps(&config),
+        Command::TeamArchive => cmd_team_archive(&config, &cli),
+        Command::TeamRename => cmd_team_rename(&config, &cli),
+        Command::ConfigValidate => cmd_config_validate(&cli),
+        Command::ConfigSchema => {
+            print!("{}", config::json_schema());
+            Ok(())
+        }
     };
 
     if let Err(e) = result {
@@ -5,6 +5,7 @@ use std::process::Command;
 use crate::config::EngineType;
 use crate::engine::{self, Engine, EngineResult};
 use crate::prompt;
+use crate::prompt_dump;
 use crate::worktree;
 
 /// Generate the merge agent prompt for feature-to-target branch merges.
@@ -23,7 +24,9 @@ pub fn generate_merge_agent_prompt(
     vars.insert("target_worktree_path", target_worktree);
     vars.insert("co_author", engine::coauthor_line());
 
-    prompt::load_and_render("merge_agent", &vars)
+    let rendered = prompt::load_and_render("merge_agent", &vars)?;
+    prompt_dump::dump("merge_agent", &rendered);
+    Ok(rendered)
 }
 
 /// Run the merge agent to merge a feature branch into the target branch.
@@ -115,59 +118,84 @@ pub fn ensure_feature_merged(
     }
 }
 
-/// Verify the merge, retrying `run_merge_agent` once on initial verification failure.
+/// Verify the merge, re-running the merge agent up to `max_retries` times.
 ///
 /// 1. Calls `ensure_feature_merged` to check if the feature branch is already merged.
-/// 2. If verification fails, re-runs `run_merge_agent` exactly once.
-/// 3. Calls `ensure_feature_merged` a second time.
-/// 4. If the second verification also fails, returns a fatal error with no further retries.
+/// 2. If verification fails, re-prepares the workspace (`cleanup_paths`, see
+///    `prepare_merge_workspace`), re-runs `run_merge_agent`, and verifies again.
+/// 3. After `max_retries` failed re-attempts (0 disables retrying), returns an
+///    error carrying the failure from every attempt.
 pub fn run_merge_agent_with_retry(
     engine: &dyn Engine,
     feature_branch: &str,
     target_branch: &str,
     repo_root: &Path,
+    cleanup_paths: &[PathBuf],
+    max_retries: usize,
 ) -> Result<(), String> {
     verify_with_retry(
         || ensure_feature_merged(engine, feature_branch, target_branch, repo_root),
-        || run_merge_agent(engine, feature_branch, target_branch, repo_root),
+        || {
+            prepare_merge_workspace(repo_root, cleanup_paths)
+                .map_err(|e| format!("retry prepare failed: {}", e))?;
+            run_merge_agent(engine, feature_branch, target_branch, repo_root)
+        },
+        max_retries,
     )
 }
 
-/// Core retry loop: verify, and if verification fails, run the merge agent once
-/// then re-verify. Returns `Ok(())` on success, or a fatal error after the
-/// second verification failure.
+/// Core retry loop: verify, and while verification fails and retries remain,
+/// run the merge agent again then re-verify.
+///
+/// A merge agent that reports failure uses up a retry; an execution error
+/// (the agent could not be run at all) ends retrying immediately.
 ///
 /// Extracted for testability — the public API is `run_merge_agent_with_retry`.
-fn verify_with_retry<V, R>(mut verify: V, retry: R) -> Result<(), String>
+fn verify_with_retry<V, R>(mut verify: V, mut retry: R, max_retries: usize) -> Result<(), String>
 where
     V: FnMut() -> Result<(), String>,
-    R: FnOnce() -> Result<EngineResult, String>,
+    R: FnMut() -> Result<EngineResult, String>,
 {
-    // First verification attempt
-    match verify() {
+    let first_err = match verify() {
         Ok(()) => return Ok(()),
-        Err(first_err) => {
-            // Retry: re-run the merge agent once
-            let retry_result = retry()?;
-            if !retry_result.success {
-                let detail = retry_result
-                    .error
-                    .unwrap_or_else(|| "merge agent retry failed".to_string());
+        Err(e) => e,
+    };
+    if max_retries == 0 {
+        return Err(format!("merge verification failed: {}", first_err));
+    }
+
+    let mut failures = vec![format!("attempt 1: {}", first_err)];
+    for attempt in 2..=max_retries + 1 {
+        let retry_result = match retry() {
+            Ok(result) => result,
+            Err(e) => {
+                failures.push(format!("attempt {}: {}", attempt, e));
                 return Err(format!(
-                    "merge agent retry failed after initial verification error '{}': {}",
-                    first_err, detail
+                    "merge agent retry could not run ({})",
+                    failures.join("; ")
                 ));
             }
-
-            // Second verification attempt — fatal on failure
-            verify().map_err(|second_err| {
-                format!(
-                    "merge verification failed after retry (initial: '{}', retry: '{}')",
-                    first_err, second_err
-                )
-            })
+        };
+        if !retry_result.success {
+            let detail = retry_result
+                .error
+                .unwrap_or_else(|| "unknown error".to_string());
+            failures.push(format!(
+                "attempt {}: merge agent retry failed: {}",
+                attempt, detail
+            ));
+            continue;
+        }
+        match verify() {
+            Ok(()) => return Ok(()),
+            Err(e) => failures.push(format!("attempt {}: {}", attempt, e)),
         }
     }
+
+    Err(format!(
+        "merge verification failed after retry ({})",
+        failures.join("; ")
+    ))
 }
 
 /// Prepare the main repo working tree for a merge by cleaning known paths.
@@ -715,6 +743,7 @@ mod tests {
                 retry_called.set(true);
                 Ok(EngineResult::success("should not run"))
             },
+            1,
         );
 
         assert!(result.is_ok());
@@ -745,6 +774,7 @@ mod tests {
                 retry_called.set(true);
                 Ok(EngineResult::success("merge agent retry output"))
             },
+            1,
         );
 
         assert!(result.is_ok());
@@ -772,6 +802,7 @@ mod tests {
                 retry_count.set(n + 1);
                 Ok(EngineResult::success("retry succeeded but merge still bad"))
             },
+            1,
         );
 
         assert!(result.is_err());
@@ -815,6 +846,7 @@ mod tests {
                 retry_count.set(retry_count.get() + 1);
                 Ok(EngineResult::success("retry ran"))
             },
+            1,
         );
 
         assert_eq!(
@@ -841,6 +873,7 @@ mod tests {
                 Err("initial verification failed".to_string())
             },
             || Ok(EngineResult::failure("engine crashed", 1)),
+            1,
         );
 
         assert!(result.is_err());
@@ -865,7 +898,7 @@ mod tests {
     #[test]
     fn test_verify_with_retry_retry_execution_error_propagates() {
         // If the retry function returns Err (execution error, not engine
-        // failure), it should propagate directly.
+        // failure), retrying stops and the error is reported with prior context.
         let verify_count = Cell::new(0u32);
 
         let result = verify_with_retry(
@@ -874,11 +907,13 @@ mod tests {
                 Err("initial verification failed".to_string())
             },
             || Err("failed to spawn merge agent".to_string()),
+            1,
         );
 
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert_eq!(err, "failed to spawn merge agent");
+        assert!(err.contains("failed to spawn merge agent"), "got: {}", err);
+        assert!(err.contains("initial verification failed"), "got: {}", err);
         assert_eq!(
             verify_count.get(),
             1,
@@ -886,6 +921,74 @@ mod tests {
         );
     }
 
+    /// Runs `verify_with_retry` with `max_retries` against a merge agent that
+    /// fails its first `failing_runs` re-attempts; returns (result, agent runs).
+    fn run_flaky_retries(max_retries: usize, failing_runs: u32) -> (Result<(), String>, u32) {
+        let runs = Cell::new(0u32);
+        let merged = Cell::new(false);
+        let result = verify_with_retry(
+            || {
+                if merged.get() {
+                    Ok(())
+                } else {
+                    Err(format!("not merged after {} retries", runs.get()))
+                }
+            },
+            || {
+                runs.set(runs.get() + 1);
+                if runs.get() <= failing_runs {
+                    Ok(EngineResult::failure(
+                        format!("flaky merge {}", runs.get()),
+                        1,
+                    ))
+                } else {
+                    merged.set(true);
+                    Ok(EngineResult::success("merged"))
+                }
+            },
+            max_retries,
+        );
+        (result, runs.get())
+    }
+
+    #[test]
+    fn test_verify_with_retry_recovers_after_k_failed_attempts() {
+        // Two flaky runs, then a good one: needs all three retries
+        let (result, runs) = run_flaky_retries(3, 2);
+        assert!(result.is_ok(), "got: {:?}", result);
+        assert_eq!(runs, 3);
+    }
+
+    #[test]
+    fn test_verify_with_retry_allows_exactly_n_plus_one_attempts() {
+        // The initial merge plus N re-attempts; a fix on attempt N+2 is never reached
+        let (result, runs) = run_flaky_retries(3, 3);
+        assert_eq!(runs, 3, "merge agent should re-run exactly N times");
+        let err = result.unwrap_err();
+        for detail in [
+            "attempt 1: not merged after 0 retries",
+            "attempt 2: merge agent retry failed: flaky merge 1",
+            "attempt 3: merge agent retry failed: flaky merge 2",
+            "attempt 4: merge agent retry failed: flaky merge 3",
+        ] {
+            assert!(err.contains(detail), "missing '{}' in: {}", detail, err);
+        }
+        assert!(!err.contains("attempt 5"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_verify_with_retry_zero_retries_disables_retry() {
+        let (result, runs) = run_flaky_retries(0, 0);
+        assert_eq!(
+            runs, 0,
+            "merge agent should not re-run with retries disabled"
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            "merge verification failed: not merged after 0 retries"
+        );
+    }
+
     #[test]
     fn test_run_merge_agent_with_retry_stub_already_merged() {
         // Integration test: when the feature is already merged, no retry
@@ -899,7 +1002,7 @@ mod tests {
             assert!(is_merged("feature-retry", "master"));
 
             let engine = StubEngine::new("loop");
-            run_merge_agent_with_retry(&engine, "feature-retry", "master", Path::new("."))
+            run_merge_agent_with_retry(&engine, "feature-retry", "master", Path::new("."), &[], 1)
                 .expect("already merged should succeed without retry");
         });
     }
@@ -934,8 +1037,15 @@ mod tests {
             commit_on_branch("feature-stub-retry", "stub-retry.txt");
 
             let engine = StubEngine::new("loop");
-            run_merge_agent_with_retry(&engine, "feature-stub-retry", "master", Path::new("."))
-                .expect("stub should merge and verify on first attempt");
+            run_merge_agent_with_retry(
+                &engine,
+                "feature-stub-retry",
+                "master",
+                Path::new("."),
+                &[],
+                1,
+            )
+            .expect("stub should merge and verify on first attempt");
 
             assert!(is_merged("feature-stub-retry", "master"));
         });
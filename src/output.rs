@@ -2,30 +2,51 @@ use std::time::Duration;
 
 use swarm::color::{self, emoji};
 use swarm::config;
+use swarm::engine::TokenUsage;
 
-/// Print a banner for starting a sprint.
-pub(crate) fn print_sprint_start_banner(team_name: &str, sprint_number: usize) {
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Engines this build can run (`--engine` values).
+const ENGINES: &[&str] = &[
+    "claude",
+    "codex",
+    "command",
+    "gemini",
+    "ollama",
+    "openrouter",
+    "stub",
+];
+
+/// Print a banner for starting a sprint from base commit `base_sha`.
+pub(crate) fn print_sprint_start_banner(team_name: &str, sprint_number: usize, base_sha: &str) {
     println!();
     println!(
-        "=== {} {}: {} Sprint {} ===",
+        "=== {} {}: {} Sprint {} (base {}) ===",
         emoji::ROCKET,
         color::label("STARTING SPRINT"),
         color::info(team_name),
-        color::number(sprint_number)
+        color::number(sprint_number),
+        color::info(base_sha)
     );
     println!();
 }
 
 /// Print a team status banner after sprint completion.
+///
+/// `state_times` is the average time per task spent working and spent waiting
+/// (queued before starting, or merging after finishing).
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn print_team_status_banner(
     team_name: &str,
     sprint_number: usize,
     completed_this_sprint: usize,
     failed_this_sprint: usize,
+    merge_failures: usize,
     remaining_tasks: usize,
     total_tasks: usize,
     task_durations: &[Duration],
+    state_times: Option<(Duration, Duration)>,
+    token_usage: Option<TokenUsage>,
     max_sprints: usize,
     agent_count: usize,
 ) {
@@ -55,6 +76,12 @@ pub(crate) fn print_team_status_banner(
         color::failed("Failed this sprint"),
         color::number(failed_this_sprint)
     );
+    println!(
+        "  {} {}: {}",
+        emoji::CROSS,
+        color::failed("Merge failures"),
+        color::number(merge_failures)
+    );
     println!(
         "  {} Remaining tasks: {}",
         emoji::TASK,
@@ -65,6 +92,15 @@ pub(crate) fn print_team_status_banner(
         emoji::PACKAGE,
         color::number(total_tasks)
     );
+    if let Some(usage) = token_usage {
+        println!(
+            "  {} Tokens: {} ({} prompt, {} completion)",
+            emoji::BRAIN,
+            color::number(usage.total()),
+            color::number(usage.prompt_tokens),
+            color::number(usage.completion_tokens)
+        );
+    }
     println!();
 
     // Calculate timing stats
@@ -82,6 +118,16 @@ pub(crate) fn print_team_status_banner(
             "     Avg task duration: {}",
             color::info(&format_duration(avg_duration))
         );
+        if let Some((working, waiting)) = state_times {
+            println!(
+                "     Avg working time: {}",
+                color::info(&format_duration(working))
+            );
+            println!(
+                "     Avg queue/merge time: {}",
+                color::info(&format_duration(waiting))
+            );
+        }
 
         // Estimate time remaining (accounting for parallel agents)
         if remaining_tasks > 0 && agent_count > 0 {
@@ -123,37 +169,104 @@ USAGE:
 COMMANDS:
     init                  Initialize a new swarm repo (creates .swarm-hug/)
     run                   Run sprints until done or max-sprints reached (default)
+    resume                Continue an interrupted sprint without re-planning (else like run)
     agents                List agent names and initials
     projects              List all projects and their assigned agents
-    project init <name>   Initialize a new project
+    project init <name>   Initialize a new project (also: team init <name>)
                           Use --with-prd <file> to auto-generate tasks from a PRD
+                          Use --with-issues <owner/repo> [--label L] to import open
+                          GitHub issues as tasks (needs gh)
     customize-prompts     Copy prompts to .swarm-hug/prompts/ for customization
     cleanup-worktrees     Interactive cleanup of git worktrees
+    cleanup               Remove a project's worktrees, agent branches, and assignments
+                          Use --all-teams to clean every project, --dry-run to preview
+                          Use --branches to only delete kept agent branches
+    prune                 Remove preserved worktrees older than --older-than days
+                          and stale git worktree registrations
+    worktrees             List a project's worktrees; add --size for disk usage
+    status                Show task counts and recent chat; --json prints one object
+                          {{unassigned, assigned, completed, cancelled, interrupted,
+                          assignable, total, recent_chat}}
     set-email <email>     Set co-author email for commits
+    config validate       Check config (file, env, flags) and list every problem
+    config schema         Print a JSON Schema for swarm.toml (editor completion, CI checks)
+    watch                 Follow a project's chat and task status read-only
+                          Use --target-branch to follow that run's task list
+    chat                  Print recent chat lines; --agent <NAME> keeps one agent's
+                          lines (name or initial), --last <N> sets the count [default: 20]
+    diff                  Show a sprint's `git diff --stat` and completed tasks
+                          Use --sprint <N> for an earlier sprint [default: the last]
+    logs <agent>          Print the end of an agent's log (name or initial); --lines <N>
+                          sets the count [default: 20], -f/--follow keeps printing
+    kill                  Terminate engine processes left by a project's runs
+                          Use --target-branch to limit it to that run
+    ps                    List running engine processes (agent, PID, elapsed, command)
+                          Use --target-branch to limit it to that run
+    team archive <name>   Clean up a project and move it to .swarm-hug/.archive/
+                          Use --delete to remove it entirely (asks first; -y skips)
+    team rename <old> <new>
+                          Rename a project, its sprint history, branches, and worktrees
 
 OPTIONS:
     -h, --help                Show this help message
-    -V, --version             Show version
-    -c, --config <PATH>       Path to config file [default: swarm.toml]
+    -V, --version             Show version (add --verbose for build details)
+    -c, --config <PATH>       Path to config file (.toml, .yaml, or .yml) [default: swarm.toml]
     -p, --project <NAME>      Project to operate on
+    --profile <NAME>          Overlay [profile.<NAME>] from the config file (env: SWARM_PROFILE)
     --source-branch <NAME>    Branch to fork/branch from. Required for `run`.
     --target-branch <NAME>    Branch to merge results into. Required for `run`.
     --max-agents <N>          Maximum number of agents to spawn [default: {max_agents}]
     --tasks-per-agent <N>     Tasks to assign per agent per sprint [default: {tasks_per_agent}]
     --agent-timeout <SECS>    Agent execution timeout in seconds [default: {timeout}]
-    --tasks-file <PATH>       Path to tasks file
+    --merge-timeout <SECS>    Merge-agent timeout in seconds [default: {timeout}]
+    --merge-retries <N>       Merge-agent re-attempts after a failed sprint merge (0 = none) [default: 1]
+    --planning-timeout <SECS> Planning, review, and PRD conversion timeout [default: {timeout}]
+    --shutdown-grace <SECS>   Time agents get to finish after Ctrl+C before being killed (0 = wait) [default: 60]
+    --heartbeat-interval <SECS> Seconds between "still working" chat messages (0 = off) [default: 300]
+    --max-concurrency <N>     Engine executions running at once (0 = unlimited) [default: CPU count]
+    --tasks-file <PATH>       Path to tasks file (repeat to merge several files)
     --chat-file <PATH>        Path to chat file
+    --chat-format <FORMAT>    Chat log format: markdown or jsonl [default: markdown]
     --log-dir <PATH>          Path to log directory
-    --engine <TYPE>           Engine type(s): claude, codex, stub, openrouter_<model> [default: claude]
+    --log-format <FORMAT>     Agent log format: text or json [default: text]
+    --engine <TYPE>           Engine type(s): claude, codex, gemini, stub, openrouter_<model>, ollama:<model>, command [default: claude]
                               Comma-separated for load balancing (e.g., claude,claude,codex)
     --stub                    Enable stub mode for testing
+    --fail-rate <RATE>        Fraction of stub tasks that fail, 0.0-1.0 [default: 0.0]
+    --seed <N>                Seed for reproducible engine selection and --fail-rate failures
+    --run-id <ID>             Use ID as the branch run hash so reruns reuse branch names
+    --engine-retries <N>      Retries for rate-limit/network engine failures [default: 2]
     --max-sprints <N>         Maximum sprints to run (0 = unlimited) [default: 0]
     --no-tui                  Disable TUI mode (use plain text output)
+    --no-pr                   Push the target branch without opening a pull request
+    --forge <FORGE>           Open the pull request on github (gh) or gitlab (glab)
+                              [default: detected from the origin remote]
+    --watch-tasks             Keep running when no tasks are assignable, re-checking
+                              tasks every sprints.watch_interval seconds until Ctrl+C
+    --ordered-merge           Merge a task's branch only after the sprint tasks it is
+                              blocked by have merged
+    -v, --verbose             Print more detail; repeat (-vv) for untruncated engine output
+    -q, --quiet               Only print errors and the final summary
+    --dump-prompts <DIR>      Write generated planning/review/merge/PR prompts to DIR
+    --event-socket <PATH>     Stream JSON sprint events to subscribers of a Unix socket
+    --webhook-url <URL>       POST agent start/heartbeat/completion events as JSON to URL
+    --all-teams               Apply `cleanup` to every project
+    --dry-run                 List what `cleanup` would remove without deleting; with `run`,
+                              print the sprint plan without engines, worktrees, or git changes
+    -y, --yes                 Skip the `cleanup` and `team archive --delete` confirmation
+    --branches                Limit `cleanup` to agent branches
+    --older-than <DAYS>       Minimum age of preserved worktrees `prune` removes [default: 7]
+    --size                    Show per-worktree disk usage and a total in `worktrees`
+    --agent <NAME>            Limit `chat` to one agent's lines (name or initial)
+    --last <N>                Number of lines `chat` prints [default: 20]; chat lines in `status` [default: 5]
+    --json                    Print `status` as a JSON object
 
 EXAMPLES:
     swarm init                        Initialize .swarm-hug/ structure
     swarm project init myproject      Create a new project
     swarm projects                    List all projects
+    swarm -p myproject watch          Observe a project without running sprints
+    swarm -p myproject chat --agent A Show Aaron's recent chat lines
     swarm -p myproject run --source-branch main --target-branch feature/myproject
                                    Run sprints for a project"#,
         max_agents = 3,
@@ -162,8 +275,62 @@ EXAMPLES:
     );
 }
 
+/// Print the version line, plus build metadata when `verbose` is set.
+pub(crate) fn print_version(verbose: bool) {
+    println!("swarm {}", VERSION);
+    if verbose {
+        for line in version_details(BuildInfo::current()) {
+            println!("{}", line);
+        }
+    }
+}
+
+/// Build metadata captured by `build.rs`; any of it may be missing.
+#[derive(Debug, Clone, Copy)]
+struct BuildInfo {
+    git_commit: Option<&'static str>,
+    rustc_version: Option<&'static str>,
+    target: Option<&'static str>,
+}
+
+impl BuildInfo {
+    fn current() -> Self {
+        Self {
+            git_commit: option_env!("SWARM_BUILD_GIT_COMMIT"),
+            rustc_version: option_env!("SWARM_BUILD_RUSTC_VERSION"),
+            target: option_env!("SWARM_BUILD_TARGET"),
+        }
+    }
+}
+
+/// Detail lines for `--version --verbose`.
+fn version_details(info: BuildInfo) -> Vec<String> {
+    let unknown = |value: Option<&str>| value.unwrap_or("unknown").to_string();
+    vec![
+        format!("commit:  {}", unknown(info.git_commit)),
+        format!("rustc:   {}", unknown(info.rustc_version)),
+        format!("target:  {}", unknown(info.target)),
+        format!("engines: {}", ENGINES.join(", ")),
+    ]
+}
+
+/// Format a byte count in human-readable form, e.g. `512 B` or `3.2 MB`.
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
 /// Format a duration in human-readable form.
-fn format_duration(d: Duration) -> String {
+pub(crate) fn format_duration(d: Duration) -> String {
     let total_secs = d.as_secs();
     let hours = total_secs / 3600;
     let minutes = (total_secs % 3600) / 60;
@@ -180,9 +347,48 @@ fn format_duration(d: Duration) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::format_duration;
+    use super::{format_bytes, format_duration, version_details, BuildInfo};
     use std::time::Duration;
 
+    #[test]
+    fn test_version_details_with_metadata() {
+        let info = BuildInfo {
+            git_commit: Some("0123456789ab"),
+            rustc_version: Some("rustc 1.80.0"),
+            target: Some("x86_64-unknown-linux-gnu"),
+        };
+        assert_eq!(
+            version_details(info),
+            vec![
+                "commit:  0123456789ab",
+                "rustc:   rustc 1.80.0",
+                "target:  x86_64-unknown-linux-gnu",
+                "engines: claude, codex, command, gemini, ollama, openrouter, stub",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_version_details_without_metadata() {
+        let info = BuildInfo {
+            git_commit: None,
+            rustc_version: None,
+            target: None,
+        };
+        let details = version_details(info);
+        assert_eq!(details[0], "commit:  unknown");
+        assert_eq!(details[1], "rustc:   unknown");
+        assert_eq!(details[2], "target:  unknown");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GB");
+    }
+
     #[test]
     fn test_format_duration_seconds_only() {
         let d = Duration::from_secs(45);
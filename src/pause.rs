@@ -0,0 +1,92 @@
+//! Pausing agents between tasks.
+//!
+//! The TUI runs sprints in a subprocess, so the pause flag is a file: the TUI
+//! creates it to pause and removes it to resume, and passes its path to the
+//! subprocess in [`PAUSE_FILE_ENV`]. Agents check the flag before starting
+//! each task; a paused agent finishes its current task, then waits.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::shutdown;
+
+/// Env var naming the pause file of a TUI-driven run.
+pub const PAUSE_FILE_ENV: &str = "SWARM_PAUSE_FILE";
+
+/// How often a paused agent re-checks the flag.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Pause file checked by [`requested`]; `None` when the run cannot be paused.
+static PAUSE_FILE: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+#[cfg(test)]
+static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+#[cfg(test)]
+pub(crate) fn test_lock() -> std::sync::MutexGuard<'static, ()> {
+    TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Set the pause file checked by [`requested`] (from [`PAUSE_FILE_ENV`] at startup).
+pub fn set_file(path: Option<PathBuf>) {
+    *PAUSE_FILE.lock().unwrap_or_else(|e| e.into_inner()) = path;
+}
+
+/// Whether agents should hold their next task.
+pub fn requested() -> bool {
+    PAUSE_FILE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_deref()
+        .is_some_and(Path::exists)
+}
+
+/// Pause (create `path`) or resume (remove it).
+pub fn set_paused(path: &Path, paused: bool) -> io::Result<()> {
+    if paused {
+        fs::write(path, b"")
+    } else {
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Block while paused. Returns early if shutdown is requested.
+pub fn wait_until_resumed() {
+    while requested() && !shutdown::requested() {
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pause_file_toggles_requested() {
+        let _guard = test_lock();
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("pause");
+
+        set_file(None);
+        assert!(!requested());
+
+        set_file(Some(path.clone()));
+        assert!(!requested());
+        set_paused(&path, true).unwrap();
+        assert!(requested());
+        set_paused(&path, false).unwrap();
+        assert!(!requested());
+        // Resuming twice is fine
+        set_paused(&path, false).unwrap();
+
+        set_file(None);
+    }
+}
@@ -6,11 +6,12 @@ use crate::agent;
 use crate::config::EngineType;
 use crate::engine::Engine;
 use crate::prompt;
+use crate::prompt_dump;
 use crate::task::TaskList;
 
 use super::parse::{
-    ceil_char_boundary, find_matching_brace, floor_char_boundary, parse_assignments_json,
-    parse_number_at,
+    ceil_char_boundary, find_matching_brace, floor_char_boundary, parse_agent_id_at,
+    parse_assignments_json, parse_number_at,
 };
 
 /// Result of LLM planning operations.
@@ -60,17 +61,11 @@ pub fn generate_scrum_master_prompt(
     agent_initials: &[char],
     tasks_per_agent: usize,
 ) -> Result<Option<String>, String> {
+    // Most urgent tier first, so the listing order is the priority order.
     let unassigned: Vec<(usize, &str)> = task_list
-        .tasks
-        .iter()
-        .enumerate()
-        .filter_map(|(idx, t)| {
-            if task_list.is_task_assignable(idx) {
-                Some((idx + 1, t.description.as_str())) // 1-indexed line numbers
-            } else {
-                None
-            }
-        })
+        .assignable_indices_by_priority()
+        .into_iter()
+        .map(|idx| (idx + 1, task_list.tasks[idx].description.as_str())) // 1-indexed line numbers
         .collect();
 
     if unassigned.is_empty() {
@@ -81,14 +76,7 @@ pub fn generate_scrum_master_prompt(
     let total_tasks = num_agents * tasks_per_agent;
     let to_assign = unassigned.len().min(total_tasks);
 
-    // Build agent list with names
-    let agent_list: String = agent_initials
-        .iter()
-        .map(|&initial| {
-            let name = agent::name_from_initial(initial).unwrap_or("Unknown");
-            format!("  - {} ({})\n", initial, name)
-        })
-        .collect();
+    let agent_list = format_agent_list(agent_initials, &agent::roster());
 
     // Build unassigned task list
     let task_list_str: String = unassigned
@@ -105,9 +93,31 @@ pub fn generate_scrum_master_prompt(
     vars.insert("task_list", task_list_str);
 
     let rendered = prompt::load_and_render("scrum_master", &vars)?;
+    prompt_dump::dump("scrum_master", &rendered);
     Ok(Some(rendered))
 }
 
+/// Agent lines for the scrum master prompt, with any roster skills.
+fn format_agent_list(agent_initials: &[char], roster: &agent::Roster) -> String {
+    agent_initials
+        .iter()
+        .map(|&initial| {
+            let name = roster.name_from_initial(initial).unwrap_or("Unknown");
+            let skills = roster.skills(initial);
+            if skills.is_empty() {
+                format!("  - {} ({})\n", agent::id(initial), name)
+            } else {
+                format!(
+                    "  - {} ({}) - skills: {}\n",
+                    agent::id(initial),
+                    name,
+                    skills.join(", ")
+                )
+            }
+        })
+        .collect()
+}
+
 /// Parse LLM response to extract task assignments.
 ///
 /// Handles various response formats:
@@ -163,22 +173,20 @@ pub fn parse_llm_assignments(response: &str) -> Vec<(usize, char)> {
 
         let after_agent = &single_line[after_key_pos..];
 
-        // Get the agent letter
-        if let Some(agent_char) = after_agent.chars().next() {
-            if agent_char.is_ascii_uppercase() {
-                // Look for "line": in the surrounding context (within 100 bytes)
-                let context_start = floor_char_boundary(&single_line, abs_pos.saturating_sub(50));
-                let context_end =
-                    ceil_char_boundary(&single_line, (abs_pos + 100).min(single_line.len()));
-                let context = &single_line[context_start..context_end];
-
-                if let Some(line_pos) = context.find(r#""line":"#) {
-                    let line_value_start = context_start + line_pos + r#""line":"#.len();
-                    let line_value_start = ceil_char_boundary(&single_line, line_value_start);
-                    if line_value_start < single_line.len() {
-                        if let Some(line_num) = parse_number_at(&single_line[line_value_start..]) {
-                            assignments.push((line_num, agent_char));
-                        }
+        // Get the agent id (A, B, ..., AA, AB, ...)
+        if let Some(agent_char) = parse_agent_id_at(after_agent) {
+            // Look for "line": in the surrounding context (within 100 bytes)
+            let context_start = floor_char_boundary(&single_line, abs_pos.saturating_sub(50));
+            let context_end =
+                ceil_char_boundary(&single_line, (abs_pos + 100).min(single_line.len()));
+            let context = &single_line[context_start..context_end];
+
+            if let Some(line_pos) = context.find(r#""line":"#) {
+                let line_value_start = context_start + line_pos + r#""line":"#.len();
+                let line_value_start = ceil_char_boundary(&single_line, line_value_start);
+                if line_value_start < single_line.len() {
+                    if let Some(line_num) = parse_number_at(&single_line[line_value_start..]) {
+                        assignments.push((line_num, agent_char));
                     }
                 }
             }
@@ -250,16 +258,9 @@ fn stub_assignment(
     tasks_per_agent: usize,
 ) -> PlanningResult {
     let unassigned: Vec<usize> = task_list
-        .tasks
-        .iter()
-        .enumerate()
-        .filter_map(|(idx, _t)| {
-            if task_list.is_task_assignable(idx) {
-                Some(idx + 1) // 1-indexed
-            } else {
-                None
-            }
-        })
+        .assignable_indices_by_priority()
+        .into_iter()
+        .map(|idx| idx + 1) // 1-indexed
         .collect();
 
     let mut assignments = Vec::new();
@@ -278,7 +279,7 @@ fn stub_assignment(
         r#"{{"assignments":[{}]}}"#,
         assignments
             .iter()
-            .map(|(l, a)| format!(r#"{{"agent":"{}","line":{}}}"#, a, l))
+            .map(|(l, a)| format!(r#"{{"agent":"{}","line":{}}}"#, agent::id(*a), l))
             .collect::<Vec<_>>()
             .join(",")
     );
@@ -313,6 +314,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_scrum_master_prompt_lists_urgent_tasks_first() {
+        let content = "# Tasks\n- [ ] Untagged task\n- [ ] (P1) Urgent task\n";
+        let task_list = TaskList::parse(content);
+        let result = generate_scrum_master_prompt(&task_list, &['A'], 2);
+        if let Ok(Some(prompt)) = result {
+            let urgent = prompt.find("Line 2: (P1) Urgent task").unwrap();
+            let untagged = prompt.find("Line 1: Untagged task").unwrap();
+            assert!(urgent < untagged);
+        }
+    }
+
+    #[test]
+    fn test_stub_assignment_prefers_urgent_tasks() {
+        let content = "- [ ] Untagged task\n- [ ] (P1) Urgent task\n";
+        let task_list = TaskList::parse(content);
+        let result = stub_assignment(&task_list, &['A'], 1);
+        assert_eq!(result.assignments, vec![(2, 'A')]);
+    }
+
     #[test]
     fn test_generate_scrum_master_prompt_skips_blocked() {
         // Task #2 is blocked by incomplete #1
@@ -340,6 +361,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_agent_list_includes_skills() {
+        let roster = agent::Roster::parse(
+            "B = \"Backend\"\nF = \"Frontend\"\n[skills]\nF = [\"frontend\", \"ui\"]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            format_agent_list(&['B', 'F'], &roster),
+            "  - B (Backend)\n  - F (Frontend) - skills: frontend, ui\n"
+        );
+    }
+
     #[test]
     fn test_parse_llm_assignments_clean_json() {
         let response = r#"{"assignments":[{"agent":"A","line":1,"reason":"first"},{"agent":"B","line":2,"reason":"second"}]}"#;
@@ -349,6 +382,16 @@ mod tests {
         assert_eq!(assignments[1], (2, 'B'));
     }
 
+    #[test]
+    fn test_parse_llm_assignments_multi_letter_ids() {
+        let response = r#"{"assignments":[{"agent":"Z","line":1},{"agent":"AB","line":2}]}"#;
+        let assignments = parse_llm_assignments(response);
+        assert_eq!(
+            assignments,
+            vec![(1, 'Z'), (2, agent::initial_from_id("AB").unwrap())]
+        );
+    }
+
     #[test]
     fn test_parse_llm_assignments_with_markdown() {
         let response = r#"```json
@@ -47,6 +47,18 @@ pub(super) fn parse_number_at(s: &str) -> Option<usize> {
 }
 
 /// Parse assignments from a JSON string.
+/// Parse the agent id (`A`, `AB`, ...) at the start of `s`.
+///
+/// An all-uppercase value up to the closing quote is read as an id; anything
+/// else falls back to its leading uppercase letter.
+pub(super) fn parse_agent_id_at(s: &str) -> Option<char> {
+    let id = s.find('"').map_or("", |end| &s[..end]);
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_uppercase()) {
+        return crate::agent::initial_from_id(id);
+    }
+    s.chars().next().filter(|c| c.is_ascii_uppercase())
+}
+
 pub(super) fn parse_assignments_json(json: &str) -> Option<Vec<(usize, char)>> {
     // Simple manual JSON parsing since we don't want to add serde
     let mut assignments = Vec::new();
@@ -63,12 +75,9 @@ pub(super) fn parse_assignments_json(json: &str) -> Option<Vec<(usize, char)>> {
         let obj = obj.trim_matches(|c| c == '{' || c == '}' || c == ' ');
 
         // Extract agent - look for "agent":"X"
-        let agent = if let Some(pos) = obj.find(r#""agent":"#) {
-            let start = pos + 9; // skip "agent":"
-            obj.chars().nth(start).filter(|c| c.is_ascii_uppercase())
-        } else {
-            None
-        };
+        let agent = obj
+            .find(r#""agent":"#)
+            .and_then(|pos| parse_agent_id_at(&obj[pos + 9..])); // skip "agent":"
 
         // Extract line number - look for "line": followed by a number
         // The JSON can have "line":N or "line": N (whitespace collapsed)
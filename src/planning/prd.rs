@@ -5,6 +5,7 @@ use std::path::Path;
 use crate::config::EngineType;
 use crate::engine::Engine;
 use crate::prompt;
+use crate::prompt_dump;
 
 /// Result of PRD to tasks conversion.
 #[derive(Debug)]
@@ -46,37 +47,96 @@ impl PrdConversionResult {
 /// # Errors
 /// Returns an error if the prd_to_tasks.md prompt file is missing.
 pub fn generate_prd_prompt(prd_content: &str) -> Result<String, String> {
+    let epics = prd_epics(prd_content);
+    let epic_list = if epics.is_empty() {
+        "(none)".to_string()
+    } else {
+        epics
+            .iter()
+            .map(|epic| format!("- {}", epic))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
     let mut vars = HashMap::new();
     vars.insert("prd_content", prd_content.to_string());
+    vars.insert("epics", epic_list);
+
+    let rendered = prompt::load_and_render("prd_to_tasks", &vars)?;
+    prompt_dump::dump("prd_to_tasks", &rendered);
+    Ok(rendered)
+}
+
+/// Epic names from the PRD's `## ` headings, in document order.
+///
+/// Headings inside code fences are ignored.
+fn prd_epics(prd_content: &str) -> Vec<String> {
+    let mut epics = Vec::new();
+    let mut in_fence = false;
+    for line in prd_content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if let Some(title) = trimmed.strip_prefix("## ") {
+            let title = title.trim();
+            if !title.is_empty() && !epics.iter().any(|e| e == title) {
+                epics.push(title.to_string());
+            }
+        }
+    }
+    epics
+}
 
-    prompt::load_and_render("prd_to_tasks", &vars)
+/// Title of a group heading line: any markdown heading level, or a line that
+/// is entirely bold (`**Epic**`).
+fn group_heading(line: &str) -> Option<&str> {
+    let title = if line.starts_with('#') {
+        line.trim_start_matches('#').strip_prefix(' ')?
+    } else {
+        line.strip_prefix("**")?.strip_suffix("**")?
+    };
+    let title = title.trim();
+    (!title.is_empty()).then_some(title)
 }
 
 /// Parse the response from PRD conversion to extract the tasks markdown.
 ///
 /// The response should already be in markdown format with sections and tasks.
-/// We clean it up by removing any markdown code fences if present.
+/// We clean it up by removing code fences (including one per group), dropping
+/// any preamble before the first heading or task, and normalizing group
+/// headings to `## <Group>` so `TaskList` keeps each group's tasks together.
 pub fn parse_prd_response(response: &str) -> String {
-    let mut result = response.to_string();
-
-    // Remove markdown code fences if present
-    if ["```markdown", "```md", "```"]
-        .iter()
-        .any(|prefix| result.starts_with(prefix))
-    {
-        if let Some(first_newline) = result.find('\n') {
-            result = result[first_newline + 1..].to_string();
-        }
-    }
+    let mut lines: Vec<String> = Vec::new();
+    let mut started = false;
 
-    // Remove trailing code fence
-    if result.trim_end().ends_with("```") {
-        if let Some(last_fence) = result.rfind("```") {
-            result = result[..last_fence].to_string();
+    for line in response.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            continue;
+        }
+        if let Some(title) = group_heading(trimmed) {
+            if lines.last().is_some_and(|last| !last.is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(format!("## {}", title));
+            started = true;
+            continue;
+        }
+        if !started {
+            if !trimmed.starts_with("- [") {
+                continue;
+            }
+            started = true;
         }
+        lines.push(line.trim_end().to_string());
     }
 
-    result.trim().to_string()
+    lines.join("\n").trim().to_string()
 }
 
 /// Convert a PRD document to a task list using LLM.
@@ -129,8 +189,29 @@ pub fn convert_prd_to_tasks(
 
 /// Generate stub PRD conversion (deterministic for testing).
 fn stub_prd_conversion(prd_content: &str) -> PrdConversionResult {
-    // Generate a simple task list based on the PRD content
-    let lines: Vec<&str> = prd_content.lines().collect();
+    let epics = prd_epics(prd_content);
+    let tasks = if epics.is_empty() {
+        stub_work_area_tasks(prd_content)
+    } else {
+        stub_epic_tasks(&epics)
+    };
+
+    // Include first non-empty line from PRD as context in response
+    let first_line = prd_content
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("(empty PRD)");
+
+    let response = format!(
+        "Generated tasks from PRD starting with: {}\n\n{}",
+        first_line, tasks
+    );
+
+    PrdConversionResult::success(tasks, response)
+}
+
+/// Stub tasks grouped into Implementation and Testing work areas.
+fn stub_work_area_tasks(prd_content: &str) -> String {
     let word_count = prd_content.split_whitespace().count();
 
     // Generate a number of tasks proportional to PRD length
@@ -157,19 +238,31 @@ fn stub_prd_conversion(prd_content: &str) -> PrdConversionResult {
         task_num - 1
     ));
 
-    // Include first non-empty line from PRD as context in response
-    let first_line = lines
-        .iter()
-        .find(|l| !l.trim().is_empty())
-        .copied()
-        .unwrap_or("(empty PRD)");
-
-    let response = format!(
-        "Generated tasks from PRD starting with: {}\n\n{}",
-        first_line, tasks
-    );
+    tasks
+}
 
-    PrdConversionResult::success(tasks, response)
+/// Stub tasks with an implementation/test pair under each epic's heading.
+fn stub_epic_tasks(epics: &[String]) -> String {
+    let mut tasks = String::new();
+    let mut task_num = 1;
+    for epic in epics {
+        if !tasks.is_empty() {
+            tasks.push('\n');
+        }
+        tasks.push_str(&format!("## {}\n\n", epic));
+        tasks.push_str(&format!(
+            "- [ ] (#{}) Implement {} from PRD\n",
+            task_num, epic
+        ));
+        tasks.push_str(&format!(
+            "- [ ] (#{}) Write tests for {} (blocked by #{})\n",
+            task_num + 1,
+            epic,
+            task_num
+        ));
+        task_num += 2;
+    }
+    tasks
 }
 
 #[cfg(test)]
@@ -216,6 +309,78 @@ mod tests {
         assert_eq!(result, "## Backend\n\n- [ ] Task one");
     }
 
+    #[test]
+    fn test_parse_prd_response_normalizes_grouped_output() {
+        let response = "Here are the tasks:\n\n\
+                        ### Epic: Accounts\n\
+                        ```markdown\n\
+                        - [ ] (#1) Add signup [5 pts]\n\
+                        ```\n\
+                        **Epic: Billing**\n\
+                        ```\n\
+                        - [ ] (#2) Add invoices [5 pts] (blocked by #1)\n\
+                        ```";
+        let result = parse_prd_response(response);
+        assert_eq!(
+            result,
+            "## Epic: Accounts\n- [ ] (#1) Add signup [5 pts]\n\n\
+             ## Epic: Billing\n- [ ] (#2) Add invoices [5 pts] (blocked by #1)"
+        );
+    }
+
+    #[test]
+    fn test_prd_epics_ignore_title_and_fenced_headings() {
+        let prd =
+            "# Product\n\n## Accounts\nText\n```\n## not an epic\n```\n## Billing\n### Details\n";
+        assert_eq!(prd_epics(prd), vec!["Accounts", "Billing"]);
+        assert!(prd_epics("# Just a title\n\nBody").is_empty());
+    }
+
+    #[test]
+    fn test_two_epic_prd_produces_two_task_groups() {
+        use crate::task::TaskList;
+
+        let prd = "# Store\n\n## Accounts\nUsers sign up and log in.\n\n## Billing\nUsers pay invoices.\n";
+        let result = stub_prd_conversion(prd);
+        assert!(result.success);
+
+        let content = format!(
+            "# Tasks\n\n{}\n",
+            parse_prd_response(&result.tasks_markdown)
+        );
+        let list = TaskList::parse(&content);
+
+        // Group each task under the most recent `## ` heading before it; lines
+        // before the first task live in the list header
+        let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+        for (i, task) in list.tasks.iter().enumerate() {
+            let before = if i == 0 { &list.header } else { &task.prefix };
+            if let Some(heading) = before.iter().rev().find_map(|l| l.strip_prefix("## ")) {
+                groups.push((heading.to_string(), Vec::new()));
+            }
+            groups.last_mut().unwrap().1.push(task.description.clone());
+        }
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "Accounts");
+        assert_eq!(
+            groups[0].1,
+            vec![
+                "(#1) Implement Accounts from PRD",
+                "(#2) Write tests for Accounts (blocked by #1)"
+            ]
+        );
+        assert_eq!(groups[1].0, "Billing");
+        assert_eq!(
+            groups[1].1,
+            vec![
+                "(#3) Implement Billing from PRD",
+                "(#4) Write tests for Billing (blocked by #3)"
+            ]
+        );
+        assert_eq!(list.to_string(), content);
+    }
+
     #[test]
     fn test_stub_prd_conversion() {
         let prd = "# Feature X\n\nThis is a long description of the feature that spans multiple words and lines.\nIt should generate several tasks based on the content length.";
@@ -4,6 +4,7 @@ use std::path::Path;
 use crate::config::EngineType;
 use crate::engine::Engine;
 use crate::prompt;
+use crate::prompt_dump;
 
 /// Generate the post-sprint review prompt.
 ///
@@ -14,26 +15,54 @@ pub fn generate_review_prompt(tasks_content: &str, git_log: &str) -> Result<Stri
     vars.insert("git_log", git_log.to_string());
     vars.insert("tasks_content", tasks_content.to_string());
 
-    prompt::load_and_render("review", &vars)
+    let rendered = prompt::load_and_render("review", &vars)?;
+    prompt_dump::dump("review", &rendered);
+    Ok(rendered)
 }
 
-/// Parse review response to extract follow-up tasks.
-pub fn parse_review_response(response: &str) -> Vec<String> {
-    if response.contains("NO_FOLLOWUPS_NEEDED") {
-        return vec![];
+/// Changes to the task list requested by a post-sprint review.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewResult {
+    /// Descriptions of new follow-up tasks.
+    pub follow_ups: Vec<String>,
+    /// Numbers of existing tasks the review marked obsolete via `CLOSE: #N`.
+    pub closed: Vec<usize>,
+}
+
+impl ReviewResult {
+    /// Whether the review requested no changes.
+    pub fn is_empty(&self) -> bool {
+        self.follow_ups.is_empty() && self.closed.is_empty()
     }
+}
 
-    response
-        .lines()
-        .filter_map(|line| {
-            let trimmed = line.trim();
-            if trimmed.starts_with("- [ ]") {
-                normalize_follow_up_description(trimmed)
-            } else {
-                None
+/// Parse review response to extract follow-up tasks and close directives.
+///
+/// `CLOSE: <task-number>` lines (with or without `#`) are honored even when
+/// the response also says `NO_FOLLOWUPS_NEEDED`.
+pub fn parse_review_response(response: &str) -> ReviewResult {
+    let mut result = ReviewResult::default();
+    let no_follow_ups = response.contains("NO_FOLLOWUPS_NEEDED");
+
+    for line in response.lines() {
+        let trimmed = line.trim();
+        if let Some(number) = parse_close_directive(trimmed) {
+            if !result.closed.contains(&number) {
+                result.closed.push(number);
+            }
+        } else if !no_follow_ups && trimmed.starts_with("- [ ]") {
+            if let Some(desc) = normalize_follow_up_description(trimmed) {
+                result.follow_ups.push(desc);
             }
-        })
-        .collect()
+        }
+    }
+
+    result
+}
+
+fn parse_close_directive(line: &str) -> Option<usize> {
+    let rest = line.strip_prefix("CLOSE:")?.trim();
+    rest.strip_prefix('#').unwrap_or(rest).trim().parse().ok()
 }
 
 /// Format follow-up tasks in PRD-to-task format with sequential numbering.
@@ -100,10 +129,10 @@ pub fn run_sprint_review(
     tasks_content: &str,
     git_log: &str,
     log_dir: &Path,
-) -> Result<Vec<String>, String> {
+) -> Result<ReviewResult, String> {
     // For stub engine, return no follow-ups (deterministic)
     if engine.engine_type() == EngineType::Stub {
-        return Ok(vec![]);
+        return Ok(ReviewResult::default());
     }
 
     let prompt = generate_review_prompt(tasks_content, git_log)?;
@@ -130,18 +159,43 @@ mod tests {
     #[test]
     fn test_parse_review_response_no_followups() {
         let response = "NO_FOLLOWUPS_NEEDED";
-        let tasks = parse_review_response(response);
-        assert!(tasks.is_empty());
+        let review = parse_review_response(response);
+        assert!(review.is_empty());
     }
 
     #[test]
     fn test_parse_review_response_with_tasks() {
         let response =
             "Found some issues:\n- [ ] Fix the bug\n- [ ] (#9) Add tests (blocked by #2)\nDone.";
-        let tasks = parse_review_response(response);
-        assert_eq!(tasks.len(), 2);
-        assert_eq!(tasks[0], "Fix the bug");
-        assert_eq!(tasks[1], "Add tests (blocked by #2)");
+        let review = parse_review_response(response);
+        assert_eq!(review.follow_ups.len(), 2);
+        assert_eq!(review.follow_ups[0], "Fix the bug");
+        assert_eq!(review.follow_ups[1], "Add tests (blocked by #2)");
+        assert!(review.closed.is_empty());
+    }
+
+    #[test]
+    fn test_parse_review_response_mixes_tasks_and_close_directives() {
+        let response = "Review notes:\n\
+                        - [ ] Add retry test\n\
+                        CLOSE: #4\n\
+                        - [ ] (#9) Document flags (blocked by #2)\n\
+                        CLOSE: 7\n\
+                        CLOSE: #4\n\
+                        CLOSE: soon\n";
+        let review = parse_review_response(response);
+        assert_eq!(
+            review.follow_ups,
+            vec!["Add retry test", "Document flags (blocked by #2)"]
+        );
+        assert_eq!(review.closed, vec![4, 7]);
+    }
+
+    #[test]
+    fn test_parse_review_response_closes_without_followups() {
+        let review = parse_review_response("CLOSE: #3\nNO_FOLLOWUPS_NEEDED");
+        assert!(review.follow_ups.is_empty());
+        assert_eq!(review.closed, vec![3]);
     }
 
     #[test]
@@ -159,8 +213,8 @@ mod tests {
     #[test]
     fn test_follow_up_tasks_use_prd_format_and_sequential_numbers() {
         let response = "- [ ] Investigate timeouts (blocked by #2, #3)\n- [ ] (#9) Write docs";
-        let follow_ups = parse_review_response(response);
-        let formatted = format_follow_up_tasks(12, &follow_ups);
+        let review = parse_review_response(response);
+        let formatted = format_follow_up_tasks(12, &review.follow_ups);
         assert_eq!(formatted.len(), 2);
         assert_eq!(
             formatted[0],
@@ -1,33 +1,106 @@
-use std::collections::HashSet;
+//! Subprocesses owned by this swarm instance.
+//!
+//! Registered PIDs can also be persisted to a `pids.json` state file, so a
+//! later run (or `swarm kill`) can reap engine processes left behind when
+//! swarm itself was killed hard, and `swarm ps` can list them. Each entry
+//! records the process name, and a recorded PID is only treated as ours while
+//! the running process still has that name, so a reused PID is never killed.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use once_cell::sync::Lazy;
 
+use crate::json::{escape_json, json_string_field, json_u64_field};
+
 /// Thread-safe registry of subprocess PIDs owned by this swarm instance.
 pub struct ProcessRegistry {
-    pids: Mutex<HashSet<u32>>,
+    /// Registered processes by PID.
+    pids: Mutex<BTreeMap<u32, RecordedProcess>>,
+    /// Where the registered PIDs are persisted, if anywhere.
+    state_file: Mutex<Option<PathBuf>>,
+}
+
+/// A process recorded in a PID state file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedProcess {
+    /// Process ID.
+    pub pid: u32,
+    /// Process name when it was registered ("" when unknown).
+    pub name: String,
+    /// Agent the process runs for ("" when unknown).
+    pub agent: String,
+    /// Command line the process was spawned with ("" when unknown).
+    pub command: String,
+    /// Registration time in seconds since the Unix epoch.
+    pub started: u64,
+}
+
+impl RecordedProcess {
+    /// Seconds since the process was registered.
+    pub fn elapsed_secs(&self) -> u64 {
+        unix_now().saturating_sub(self.started)
+    }
 }
 
 impl ProcessRegistry {
     pub fn new() -> Self {
         Self {
-            pids: Mutex::new(HashSet::new()),
+            pids: Mutex::new(BTreeMap::new()),
+            state_file: Mutex::new(None),
         }
     }
 
     /// Register a spawned subprocess.
     pub fn register(&self, pid: u32) {
-        self.pids.lock().unwrap().insert(pid);
+        self.register_agent(pid, "", "");
+    }
+
+    /// Register a subprocess spawned for `agent` with the given command line.
+    pub fn register_agent(&self, pid: u32, agent: &str, command: &str) {
+        let process = RecordedProcess {
+            pid,
+            name: process_name(pid).unwrap_or_default(),
+            agent: agent.to_string(),
+            command: command.to_string(),
+            started: unix_now(),
+        };
+        let mut pids = self.pids.lock().unwrap();
+        pids.insert(pid, process);
+        self.persist(&pids);
     }
 
     /// Unregister a subprocess (after wait/reap).
     pub fn unregister(&self, pid: u32) {
-        self.pids.lock().unwrap().remove(&pid);
+        let mut pids = self.pids.lock().unwrap();
+        if pids.remove(&pid).is_some() {
+            self.persist(&pids);
+        }
     }
 
     /// Get all registered PIDs (for shutdown).
     pub fn all_pids(&self) -> Vec<u32> {
-        self.pids.lock().unwrap().iter().copied().collect()
+        self.pids.lock().unwrap().keys().copied().collect()
+    }
+
+    /// Persist registered PIDs to `path` from now on (`None` stops persisting).
+    pub fn set_state_file(&self, path: Option<PathBuf>) {
+        *self.state_file.lock().unwrap() = path;
+        self.persist(&self.pids.lock().unwrap());
+    }
+
+    /// Best-effort write of the state file; a failure only loses crash recovery.
+    fn persist(&self, pids: &BTreeMap<u32, RecordedProcess>) {
+        let Some(path) = self.state_file.lock().unwrap().clone() else {
+            return;
+        };
+        let processes: Vec<RecordedProcess> = pids.values().cloned().collect();
+        if let Err(e) = save(&path, &processes) {
+            eprintln!("warning: {}", e);
+        }
     }
 
     /// Kill all registered subprocesses (graceful then forced).
@@ -47,6 +120,140 @@ impl Default for ProcessRegistry {
 /// Global registry for the current swarm run.
 pub static PROCESS_REGISTRY: Lazy<ProcessRegistry> = Lazy::new(ProcessRegistry::new);
 
+/// Program and arguments of `cmd` as one display string.
+pub fn command_line(cmd: &std::process::Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Write `processes` to a PID state file, one object per line.
+pub fn save(path: &Path, processes: &[RecordedProcess]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("failed to create directory: {}", e))?;
+    }
+    let entries: Vec<String> = processes
+        .iter()
+        .map(|p| {
+            format!(
+                "    {{\"pid\": {}, \"started\": {}, \"name\": \"{}\", \"agent\": \"{}\", \"command\": \"{}\"}}",
+                p.pid,
+                p.started,
+                escape_json(&p.name),
+                escape_json(&p.agent),
+                escape_json(&p.command)
+            )
+        })
+        .collect();
+    let content = if entries.is_empty() {
+        "{\n  \"pids\": []\n}\n".to_string()
+    } else {
+        format!("{{\n  \"pids\": [\n{}\n  ]\n}}\n", entries.join(",\n"))
+    };
+    fs::write(path, content).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+/// Load a PID state file written by [`save`]. A missing file has no processes.
+pub fn load(path: &Path) -> Result<Vec<RecordedProcess>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("failed to read {}: {}", path.display(), e)),
+    };
+    Ok(content
+        .lines()
+        .filter_map(|line| {
+            Some(RecordedProcess {
+                pid: json_u64_field(line, "pid")?.try_into().ok()?,
+                name: json_string_field(line, "name").unwrap_or_default(),
+                agent: json_string_field(line, "agent").unwrap_or_default(),
+                command: json_string_field(line, "command").unwrap_or_default(),
+                started: json_u64_field(line, "started").unwrap_or(0),
+            })
+        })
+        .collect())
+}
+
+/// Whether a recorded process is still running as the same program.
+///
+/// An entry without a recorded name cannot be told apart from a reused PID,
+/// so it is never treated as running.
+pub fn is_running(process: &RecordedProcess) -> bool {
+    !process.name.is_empty()
+        && is_alive(process.pid)
+        && process_name(process.pid).as_deref() == Some(process.name.as_str())
+}
+
+/// The still-running processes recorded in `path`, oldest first.
+pub fn running(path: &Path) -> Result<Vec<RecordedProcess>, String> {
+    let mut processes: Vec<RecordedProcess> = load(path)?.into_iter().filter(is_running).collect();
+    processes.sort_by_key(|p| (p.started, p.pid));
+    Ok(processes)
+}
+
+/// Kill the still-running processes recorded in `path`, then remove the file.
+///
+/// Dead PIDs (and PIDs since reused by another program) are dropped without
+/// being signalled. Returns the processes that were killed.
+pub fn reap_recorded(path: &Path) -> Result<Vec<RecordedProcess>, String> {
+    let running = running(path)?;
+    for process in &running {
+        kill_pid_gracefully(process.pid);
+    }
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("failed to remove {}: {}", path.display(), e))
+        }
+        _ => Ok(running),
+    }
+}
+
+#[cfg(unix)]
+fn is_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks that the process exists (EPERM: exists, not ours)
+    let signalled = unsafe { libc::kill(pid, 0) } == 0;
+    signalled || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(windows)]
+fn is_alive(pid: u32) -> bool {
+    process_name(pid).is_some()
+}
+
+/// Name of the program running as `pid`, if it is running.
+#[cfg(unix)]
+fn process_name(pid: u32) -> Option<String> {
+    let output = std::process::Command::new("ps")
+        .args(["-o", "comm=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !name.is_empty()).then_some(name)
+}
+
+#[cfg(windows)]
+fn process_name(pid: u32) -> Option<String> {
+    let output = std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/FO", "CSV", "/NH"])
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let name = stdout.split(',').next()?.trim().trim_matches('"');
+    (stdout.contains(&format!("\"{}\"", pid)) && !name.is_empty()).then(|| name.to_string())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 #[cfg(unix)]
 fn kill_pid_gracefully(pid: u32) {
     crate::process::kill_process_tree(pid);
@@ -63,7 +270,7 @@ fn kill_pid_gracefully(pid: u32) {
 
 #[cfg(test)]
 mod tests {
-    use super::ProcessRegistry;
+    use super::{is_running, load, reap_recorded, running, save, ProcessRegistry, RecordedProcess};
 
     #[test]
     fn register_unregister_tracks_pids() {
@@ -83,6 +290,115 @@ mod tests {
         assert_eq!(pids, vec![200]);
     }
 
+    #[test]
+    fn state_file_round_trips_registered_pids() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("runs").join("main").join("pids.json");
+        let registry = ProcessRegistry::new();
+        registry.set_state_file(Some(path.clone()));
+
+        registry.register(100);
+        registry.register_agent(200, "Aaron", "claude --print \"quoted\" -");
+        registry.unregister(100);
+
+        let recorded = load(&path).unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].pid, 200);
+        assert_eq!(recorded[0].agent, "Aaron");
+        assert_eq!(recorded[0].command, "claude --print \"quoted\" -");
+        assert!(recorded[0].started > 0);
+        assert!(recorded[0].elapsed_secs() < 60);
+        assert!(load(&dir.path().join("missing.json")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn state_file_decodes_json_escapes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pids.json");
+        let process = RecordedProcess {
+            pid: 300,
+            name: "claude".to_string(),
+            agent: "Aaron".to_string(),
+            command: "sh -c 'printf \"a\\tb\\\\\"'\t\u{1}é".to_string(),
+            started: 7,
+        };
+        save(&path, std::slice::from_ref(&process)).unwrap();
+
+        assert_eq!(load(&path).unwrap(), vec![process]);
+    }
+
+    #[test]
+    fn nameless_entry_is_never_running() {
+        let process = RecordedProcess {
+            pid: std::process::id(),
+            name: String::new(),
+            agent: String::new(),
+            command: String::new(),
+            started: 0,
+        };
+        assert!(!is_running(&process));
+    }
+
+    #[test]
+    fn exited_pid_is_dead_and_reaped_from_state_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pids.json");
+        let registry = ProcessRegistry::new();
+        registry.set_state_file(Some(path.clone()));
+
+        let mut child = std::process::Command::new(if cfg!(windows) { "cmd" } else { "true" })
+            .args(if cfg!(windows) {
+                &["/C", "exit"][..]
+            } else {
+                &[][..]
+            })
+            .spawn()
+            .expect("spawn child");
+        let pid = child.id();
+        registry.register(pid);
+        child.wait().expect("wait child");
+
+        let recorded = load(&path).unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].pid, pid);
+        assert!(!is_running(&recorded[0]));
+
+        assert!(reap_recorded(&path).unwrap().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn running_lists_live_processes_and_filters_exited() {
+        use std::process::{Command, Stdio};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pids.json");
+        let registry = ProcessRegistry::new();
+        registry.set_state_file(Some(path.clone()));
+
+        let mut sleeper = Command::new("sleep")
+            .arg("10")
+            .stdout(Stdio::null())
+            .spawn()
+            .expect("spawn sleep");
+        registry.register_agent(sleeper.id(), "Aaron", "sleep 10");
+
+        let mut exited = Command::new("true").spawn().expect("spawn true");
+        registry.register_agent(exited.id(), "Betty", "true");
+        exited.wait().expect("wait true");
+
+        let listed = running(&path).unwrap();
+        let _ = sleeper.kill();
+        let _ = sleeper.wait();
+
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].pid, sleeper.id());
+        assert_eq!(listed[0].agent, "Aaron");
+        assert_eq!(listed[0].command, "sleep 10");
+        assert!(running(&path).unwrap().is_empty());
+    }
+
     #[test]
     fn kill_all_empty_no_panic() {
         let registry = ProcessRegistry::new();
@@ -0,0 +1,116 @@
+//! Diagnostic dumps of generated engine prompts (`--dump-prompts <dir>`).
+//!
+//! Prompt generators call [`dump`] right before a prompt is handed to an
+//! engine. When no dump directory is set this is a no-op; failures only print
+//! a warning so dumping never changes control flow.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use chrono::Local;
+
+/// Replacement text for redacted secret values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Secrets shorter than this are left alone to avoid masking common words.
+const MIN_SECRET_LEN: usize = 8;
+
+/// Environment variable name fragments treated as secrets.
+const SECRET_NAME_MARKERS: &[&str] = &["KEY", "TOKEN", "SECRET", "PASSWORD"];
+
+static DUMP_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Per-process sequence number so same-millisecond dumps never collide.
+static SEQUENCE: AtomicUsize = AtomicUsize::new(0);
+
+/// Set (or clear) the directory prompts are dumped to.
+pub fn set_dir(dir: Option<PathBuf>) {
+    *DUMP_DIR.lock().unwrap_or_else(|e| e.into_inner()) = dir;
+}
+
+/// Current dump directory, if prompt dumping is enabled.
+pub fn dir() -> Option<PathBuf> {
+    DUMP_DIR.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Write `prompt` to the dump directory as `<timestamp>-<seq>-<kind>.md`.
+///
+/// Secrets from the environment are redacted first.
+pub fn dump(kind: &str, prompt: &str) {
+    let Some(dir) = dir() else {
+        return;
+    };
+    if let Err(e) = write_dump(&dir, kind, prompt) {
+        eprintln!("warning: failed to dump {} prompt: {}", kind, e);
+    }
+}
+
+fn write_dump(dir: &Path, kind: &str, prompt: &str) -> Result<PathBuf, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    let seq = SEQUENCE.fetch_add(1, Ordering::SeqCst);
+    let path = dir.join(format!(
+        "{}-{:03}-{}.md",
+        Local::now().format("%Y%m%d-%H%M%S%.3f"),
+        seq,
+        kind
+    ));
+    fs::write(&path, redact_secrets(prompt, &secret_env_values()))
+        .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Values of environment variables whose names look like credentials.
+fn secret_env_values() -> Vec<String> {
+    env::vars()
+        .filter(|(name, value)| {
+            let name = name.to_ascii_uppercase();
+            value.len() >= MIN_SECRET_LEN
+                && SECRET_NAME_MARKERS
+                    .iter()
+                    .any(|marker| name.contains(marker))
+        })
+        .map(|(_, value)| value)
+        .collect()
+}
+
+/// Replace every occurrence of each secret in `text` with [`REDACTED`].
+pub fn redact_secrets(text: &str, secrets: &[String]) -> String {
+    let mut redacted = text.to_string();
+    for secret in secrets {
+        if secret.len() >= MIN_SECRET_LEN {
+            redacted = redacted.replace(secret.as_str(), REDACTED);
+        }
+    }
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_redact_secrets_masks_long_values_only() {
+        let secrets = vec!["sk-or-1234567890".to_string(), "short".to_string()];
+        let text = "key=sk-or-1234567890 again sk-or-1234567890, short stays";
+        assert_eq!(
+            redact_secrets(text, &secrets),
+            "key=[REDACTED] again [REDACTED], short stays"
+        );
+    }
+
+    #[test]
+    fn test_write_dump_uses_unique_kind_suffixed_names() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("prompts");
+        let first = write_dump(&dir, "review", "prompt one").unwrap();
+        let second = write_dump(&dir, "review", "prompt two").unwrap();
+
+        assert_ne!(first, second);
+        assert!(first.to_string_lossy().ends_with("-review.md"));
+        assert_eq!(fs::read_to_string(&second).unwrap(), "prompt two");
+    }
+}
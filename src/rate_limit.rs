@@ -0,0 +1,359 @@
+//! Rate limiting for engine executions.
+//!
+//! Provides a token-bucket limiter shared by all agent threads in a sprint so
+//! engine calls can be paced to stay under provider request-per-minute limits,
+//! and a semaphore capping how many engine executions run at the same time.
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::shutdown;
+
+/// How often a rate-limited wait checks for a shutdown request.
+const SHUTDOWN_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Source of time for the rate limiter (mockable in tests).
+pub trait Clock: Send + Sync {
+    /// Time elapsed since an arbitrary, fixed starting point.
+    fn now(&self) -> Duration;
+    /// Block the current thread for the given duration.
+    fn sleep(&self, duration: Duration);
+}
+
+/// Wall-clock implementation backed by `Instant` and `thread::sleep`.
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    /// Create a new system clock anchored at the current instant.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Tokens go negative while callers hold reservations for future slots.
+struct Bucket {
+    tokens: f64,
+    last_refill: Duration,
+}
+
+/// Token-bucket limiter with a capacity of one token.
+///
+/// Tokens refill at `max_rpm / 60` per second, so successive `acquire` calls
+/// are spaced at least `60 / max_rpm` seconds apart. A rate of 0 disables
+/// throttling entirely.
+pub struct RateLimiter {
+    interval: Option<Duration>,
+    clock: Arc<dyn Clock>,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `max_rpm` acquisitions per minute.
+    pub fn per_minute(max_rpm: u32) -> Self {
+        Self::with_clock(max_rpm, Arc::new(SystemClock::new()))
+    }
+
+    /// Create a limiter using a custom clock.
+    pub fn with_clock(max_rpm: u32, clock: Arc<dyn Clock>) -> Self {
+        let interval = if max_rpm == 0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(60.0 / max_rpm as f64))
+        };
+        let last_refill = clock.now();
+        Self {
+            interval,
+            clock,
+            bucket: Mutex::new(Bucket {
+                tokens: 1.0,
+                last_refill,
+            }),
+        }
+    }
+
+    /// Whether this limiter throttles at all.
+    pub fn is_enabled(&self) -> bool {
+        self.interval.is_some()
+    }
+
+    /// Block until a token is available, then consume it.
+    ///
+    /// The next free slot is reserved under the bucket lock, so callers are
+    /// served in turn, and then waited for with the lock released, checking
+    /// for shutdown as it goes. Returns `false` if shutdown was requested
+    /// before the slot came up.
+    pub fn acquire(&self) -> bool {
+        let interval = match self.interval {
+            Some(interval) => interval,
+            None => return true,
+        };
+
+        let deadline = {
+            let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+            let now = self.clock.now();
+            let elapsed = now.saturating_sub(bucket.last_refill);
+            bucket.tokens =
+                (bucket.tokens + elapsed.as_secs_f64() / interval.as_secs_f64()).min(1.0);
+            bucket.last_refill = now;
+            bucket.tokens -= 1.0;
+            now + interval.mul_f64((-bucket.tokens).max(0.0))
+        };
+
+        loop {
+            let now = self.clock.now();
+            if now >= deadline {
+                return true;
+            }
+            if shutdown::requested() {
+                return false;
+            }
+            self.clock
+                .sleep((deadline - now).min(SHUTDOWN_CHECK_INTERVAL));
+        }
+    }
+}
+
+/// Counting semaphore limiting simultaneous engine executions.
+///
+/// Agents beyond the limit block in `acquire` until a running execution drops
+/// its permit. A limit of 0 disables the cap.
+pub struct ConcurrencyLimiter {
+    max: usize,
+    running: Mutex<usize>,
+    released: Condvar,
+}
+
+impl ConcurrencyLimiter {
+    /// Create a limiter allowing `max` executions at once.
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            running: Mutex::new(0),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Whether this limiter caps concurrency at all.
+    pub fn is_enabled(&self) -> bool {
+        self.max > 0
+    }
+
+    /// Block until a slot is free, then hold it until the permit is dropped.
+    pub fn acquire(&self) -> ConcurrencyPermit<'_> {
+        if !self.is_enabled() {
+            return ConcurrencyPermit { limiter: None };
+        }
+
+        let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        while *running >= self.max {
+            running = self
+                .released
+                .wait(running)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        *running += 1;
+        ConcurrencyPermit {
+            limiter: Some(self),
+        }
+    }
+}
+
+/// Slot held for the duration of one engine execution; released on drop.
+pub struct ConcurrencyPermit<'a> {
+    limiter: Option<&'a ConcurrencyLimiter>,
+}
+
+impl Drop for ConcurrencyPermit<'_> {
+    fn drop(&mut self) {
+        if let Some(limiter) = self.limiter {
+            let mut running = limiter.running.lock().unwrap_or_else(|e| e.into_inner());
+            *running -= 1;
+            limiter.released.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{Engine, StubEngine};
+    use tempfile::TempDir;
+
+    /// Manual clock where `sleep` advances time instantly.
+    struct MockClock {
+        now: Mutex<Duration>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            Self {
+                now: Mutex::new(Duration::ZERO),
+            }
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Duration {
+            *self.now.lock().unwrap()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+        }
+    }
+
+    #[test]
+    fn test_zero_rpm_disables_throttling() {
+        let clock = Arc::new(MockClock::new());
+        let limiter = RateLimiter::with_clock(0, clock.clone());
+        assert!(!limiter.is_enabled());
+        for _ in 0..10 {
+            limiter.acquire();
+        }
+        assert_eq!(clock.now(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_first_acquire_does_not_wait() {
+        let clock = Arc::new(MockClock::new());
+        let limiter = RateLimiter::with_clock(6, clock.clone());
+        limiter.acquire();
+        assert_eq!(clock.now(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_acquire_refills_after_idle_period() {
+        let clock = Arc::new(MockClock::new());
+        let limiter = RateLimiter::with_clock(60, clock.clone());
+        limiter.acquire();
+        clock.sleep(Duration::from_secs(5));
+        limiter.acquire();
+        // Bucket capacity is one token, so idle time does not allow a burst.
+        assert_eq!(clock.now(), Duration::from_secs(5));
+        limiter.acquire();
+        assert_eq!(clock.now(), Duration::from_secs(6));
+    }
+
+    #[test]
+    fn test_agents_start_spaced_by_rpm() {
+        let tmp = TempDir::new().unwrap();
+        let created = Instant::now();
+        // 1200 rpm: one engine start every 50ms
+        let interval = Duration::from_millis(50);
+        let limiter = Arc::new(RateLimiter::per_minute(1200));
+        let starts = Arc::new(Mutex::new(Vec::new()));
+        let log_dir = tmp.path().join("loop").to_string_lossy().to_string();
+
+        let mut handles = Vec::new();
+        for (turn, agent) in ["Aaron", "Betty", "Carlos", "Diana"].iter().enumerate() {
+            let limiter = Arc::clone(&limiter);
+            let starts = Arc::clone(&starts);
+            let log_dir = log_dir.clone();
+            let working_dir = tmp.path().to_path_buf();
+            handles.push(thread::spawn(move || {
+                let engine = StubEngine::new(log_dir);
+                assert!(limiter.acquire());
+                starts.lock().unwrap().push(created.elapsed());
+                let result = engine.execute(agent, "Do work", &working_dir, turn + 1, None);
+                assert!(result.success);
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut starts = starts.lock().unwrap().clone();
+        starts.sort();
+        for (i, start) in starts.iter().enumerate() {
+            assert!(*start >= interval * i as u32, "start {} at {:?}", i, start);
+        }
+    }
+
+    #[test]
+    fn test_waiting_acquire_returns_on_shutdown_without_holding_lock() {
+        let _guard = shutdown::test_lock();
+        shutdown::reset();
+        // 1 rpm: the second caller would wait a full minute
+        let limiter = Arc::new(RateLimiter::per_minute(1));
+        assert!(limiter.acquire());
+
+        let waiter = {
+            let limiter = Arc::clone(&limiter);
+            thread::spawn(move || limiter.acquire())
+        };
+        thread::sleep(Duration::from_millis(50));
+        // The bucket is free while the waiter sleeps
+        assert!(limiter.bucket.try_lock().is_ok());
+
+        let requested = Instant::now();
+        shutdown::request();
+        let acquired = waiter.join().unwrap();
+        shutdown::reset();
+
+        assert!(!acquired);
+        assert!(requested.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_zero_concurrency_disables_cap() {
+        let limiter = ConcurrencyLimiter::new(0);
+        assert!(!limiter.is_enabled());
+        let _permits: Vec<_> = (0..10).map(|_| limiter.acquire()).collect();
+    }
+
+    #[test]
+    fn test_max_concurrency_one_serializes_executions() {
+        let tmp = TempDir::new().unwrap();
+        let limiter = Arc::new(ConcurrencyLimiter::new(1));
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        let log_dir = tmp.path().join("loop").to_string_lossy().to_string();
+
+        let mut handles = Vec::new();
+        for (turn, agent) in ["Aaron", "Betty", "Carlos", "Diana"].iter().enumerate() {
+            let limiter = Arc::clone(&limiter);
+            let spans = Arc::clone(&spans);
+            let log_dir = log_dir.clone();
+            let working_dir = tmp.path().to_path_buf();
+            handles.push(thread::spawn(move || {
+                let engine = StubEngine::new(log_dir);
+                let _permit = limiter.acquire();
+                let start = Instant::now();
+                let result = engine.execute(agent, "Do work", &working_dir, turn + 1, None);
+                thread::sleep(Duration::from_millis(20));
+                spans.lock().unwrap().push((start, Instant::now()));
+                assert!(result.success);
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut spans = spans.lock().unwrap().clone();
+        spans.sort();
+        assert_eq!(spans.len(), 4);
+        for pair in spans.windows(2) {
+            assert!(pair[1].0 >= pair[0].1, "executions overlapped: {:?}", pair);
+        }
+    }
+}
@@ -4,8 +4,14 @@
 //! All artifacts (worktrees, branches) for the run share the same hash,
 //! ensuring isolation between runs and projects.
 
+use std::path::Path;
+use std::process::Command;
+
 use crate::agent;
-use crate::run_hash::generate_run_hash;
+use crate::json::escape_json;
+use crate::run_hash::{
+    generate_run_hash, generate_run_hash_with_len, sanitize_run_id, DEFAULT_HASH_LEN,
+};
 
 /// Context for a single sprint run.
 ///
@@ -34,8 +40,14 @@ pub struct RunContext {
     pub runtime_id: String,
     /// Sprint number within the project.
     pub sprint_number: u32,
-    /// Unique hash for this run (6 alphanumeric characters).
+    /// Unique hash for this run (6 alphanumeric characters by default).
     pub run_hash: String,
+    /// Prepended to sprint and agent branch names (empty by default).
+    pub branch_prefix: String,
+    /// Directories agent worktrees check out via sparse-checkout (empty = all).
+    pub sparse_paths: Vec<String>,
+    /// Short SHA of the commit the sprint branch was created from, once known.
+    pub base_sha: Option<String>,
 }
 
 impl RunContext {
@@ -69,17 +81,128 @@ impl RunContext {
         target_branch: &str,
         run_instance: &str,
         sprint_number: u32,
+    ) -> Self {
+        Self::new_for_run_with_hash_len(
+            project,
+            target_branch,
+            run_instance,
+            sprint_number,
+            DEFAULT_HASH_LEN,
+        )
+    }
+
+    /// Creates a run context whose run hash has a custom length.
+    ///
+    /// Longer hashes reduce branch-name collisions across many concurrent
+    /// runs. Changing the length only affects branches created by new runs;
+    /// existing branches keep their original names.
+    ///
+    /// # Examples
+    /// ```
+    /// use swarm::run_context::RunContext;
+    ///
+    /// let ctx = RunContext::new_for_run_with_hash_len("greenfield", "main", "run42", 1, 10);
+    /// assert_eq!(ctx.hash().len(), 10);
+    /// ```
+    pub fn new_for_run_with_hash_len(
+        project: &str,
+        target_branch: &str,
+        run_instance: &str,
+        sprint_number: u32,
+        hash_len: usize,
     ) -> Self {
         Self {
             project: project.to_string(),
             target_branch: target_branch.to_string(),
             runtime_id: compose_runtime_id(project, target_branch, run_instance),
             sprint_number,
-            run_hash: generate_run_hash(),
+            run_hash: generate_run_hash_with_len(hash_len),
+            branch_prefix: String::new(),
+            sparse_paths: Vec::new(),
+            base_sha: None,
         }
     }
 
-    /// Returns the sprint branch name: `{project}-sprint-{n}-{hash}`.
+    /// Prepend `prefix` to every sprint and agent branch name, e.g. `swarm/`
+    /// to match CODEOWNERS or branch-protection rules.
+    ///
+    /// # Examples
+    /// ```
+    /// use swarm::run_context::RunContext;
+    ///
+    /// let ctx = RunContext::new("greenfield", 1).with_branch_prefix("swarm/");
+    /// assert!(ctx.sprint_branch().starts_with("swarm/greenfield-sprint-1-"));
+    /// assert!(ctx.agent_branch('A').starts_with("swarm/greenfield-agent-aaron-"));
+    /// ```
+    pub fn with_branch_prefix(mut self, prefix: &str) -> Self {
+        self.branch_prefix = prefix.to_string();
+        self
+    }
+
+    /// Use a fixed run ID (`--run-id`) as the run hash instead of the generated
+    /// one, so reruns produce the same sprint and agent branch names. The ID is
+    /// sanitized for branch names; an ID with nothing usable left is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use swarm::run_context::RunContext;
+    ///
+    /// let ctx = RunContext::new("greenfield", 1).with_run_id("Nightly 42");
+    /// assert_eq!(ctx.sprint_branch(), "greenfield-sprint-1-nightly-42");
+    /// ```
+    pub fn with_run_id(mut self, run_id: &str) -> Self {
+        if let Some(hash) = sanitize_run_id(run_id) {
+            self.run_hash = hash;
+        }
+        self
+    }
+
+    /// Limit agent worktrees to these directories with a cone-mode
+    /// sparse-checkout. An empty list checks out the whole tree.
+    pub fn with_sparse_paths(mut self, paths: &[String]) -> Self {
+        self.sparse_paths = paths.to_vec();
+        self
+    }
+
+    /// Record the short SHA `base_ref` resolves to in `repo_root` as the
+    /// sprint's base commit. Leaves it unset if the ref cannot be resolved.
+    pub fn with_base_commit(mut self, repo_root: &Path, base_ref: &str) -> Self {
+        self.base_sha = Command::new("git")
+            .arg("-C")
+            .arg(repo_root)
+            .args(["rev-parse", "--short", base_ref])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .filter(|sha| !sha.is_empty());
+        self
+    }
+
+    /// Short SHA of the sprint's base commit, if it was recorded.
+    pub fn base_sha(&self) -> Option<&str> {
+        self.base_sha.as_deref()
+    }
+
+    /// Run metadata as a JSON object, for the runtime namespace's `run.json`.
+    pub fn to_json(&self) -> String {
+        let base_sha = match self.base_sha() {
+            Some(sha) => format!("\"{}\"", escape_json(sha)),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\n  \"project\": \"{}\",\n  \"target_branch\": \"{}\",\n  \"runtime_id\": \"{}\",\n  \"sprint\": {},\n  \"run_hash\": \"{}\",\n  \"sprint_branch\": \"{}\",\n  \"base_sha\": {}\n}}\n",
+            escape_json(&self.project),
+            escape_json(&self.target_branch),
+            escape_json(&self.runtime_id),
+            self.sprint_number,
+            escape_json(&self.run_hash),
+            escape_json(&self.sprint_branch()),
+            base_sha
+        )
+    }
+
+    /// Returns the sprint branch name: `{prefix}{project}-sprint-{n}-{hash}`.
     ///
     /// # Examples
     /// ```
@@ -92,12 +215,12 @@ impl RunContext {
     /// ```
     pub fn sprint_branch(&self) -> String {
         format!(
-            "{}-sprint-{}-{}",
-            self.project, self.sprint_number, self.run_hash
+            "{}{}-sprint-{}-{}",
+            self.branch_prefix, self.project, self.sprint_number, self.run_hash
         )
     }
 
-    /// Returns the agent branch name: `{project}-agent-{name}-{hash}`.
+    /// Returns the agent branch name: `{prefix}{project}-agent-{name}-{hash}`.
     ///
     /// # Arguments
     /// * `initial` - The agent's initial (A-Z)
@@ -111,9 +234,10 @@ impl RunContext {
     /// assert!(branch.starts_with("greenfield-agent-aaron-"));
     /// ```
     pub fn agent_branch(&self, initial: char) -> String {
-        let name = agent::name_from_initial(initial).unwrap_or("unknown");
+        let name = agent::name_from_initial(initial).unwrap_or_else(|| "unknown".to_string());
         format!(
-            "{}-agent-{}-{}",
+            "{}{}-agent-{}-{}",
+            self.branch_prefix,
             self.project,
             name.to_lowercase(),
             self.run_hash
@@ -225,6 +349,63 @@ mod tests {
         assert_eq!(branch.len(), "greenfield-sprint-1-".len() + 6);
     }
 
+    #[test]
+    fn test_same_run_id_gives_identical_branches() {
+        let first = RunContext::new_for_run("greenfield", "main", "run1", 3).with_run_id("ci-7");
+        let second = RunContext::new_for_run("greenfield", "main", "run2", 3).with_run_id("ci-7");
+        assert_eq!(first.hash(), "ci-7");
+        assert_eq!(first.sprint_branch(), "greenfield-sprint-3-ci-7");
+        assert_eq!(first.sprint_branch(), second.sprint_branch());
+        assert_eq!(first.agent_branch('A'), second.agent_branch('A'));
+        assert_eq!(first.agent_branch('A'), "greenfield-agent-aaron-ci-7");
+    }
+
+    #[test]
+    fn test_unusable_run_id_keeps_generated_hash() {
+        let ctx = RunContext::new("greenfield", 1);
+        let hash = ctx.hash().to_string();
+        assert_eq!(ctx.with_run_id("//").hash(), hash);
+    }
+
+    fn run_git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(args)
+            .output()
+            .expect("failed to run git");
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    #[test]
+    fn test_with_base_commit_records_short_sha() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path();
+        run_git(repo, &["init"]);
+        run_git(repo, &["config", "user.name", "Swarm Test"]);
+        run_git(repo, &["config", "user.email", "swarm-test@example.com"]);
+        std::fs::write(repo.join("README.md"), "base").unwrap();
+        run_git(repo, &["add", "."]);
+        run_git(repo, &["commit", "-m", "base"]);
+
+        let ctx = RunContext::new("greenfield", 1).with_base_commit(repo, "HEAD");
+        let expected = run_git(repo, &["rev-parse", "--short", "HEAD"]);
+        assert_eq!(ctx.base_sha(), Some(expected.as_str()));
+        assert!(ctx
+            .to_json()
+            .contains(&format!("\"base_sha\": \"{}\"", expected)));
+
+        let missing = RunContext::new("greenfield", 1).with_base_commit(repo, "no-such-branch");
+        assert_eq!(missing.base_sha(), None);
+        assert!(missing.to_json().contains("\"base_sha\": null"));
+    }
+
     #[test]
     fn test_sprint_branch_includes_hash() {
         let ctx = RunContext::new("greenfield", 1);
@@ -279,6 +460,36 @@ mod tests {
         assert_eq!(sprint_hash, agent_hash);
     }
 
+    #[test]
+    fn test_new_for_run_with_hash_len_sets_hash_length() {
+        let ctx = RunContext::new_for_run_with_hash_len("greenfield", "main", "run42", 1, 12);
+        assert_eq!(ctx.hash().len(), 12);
+        assert_eq!(
+            ctx.agent_branch('A').len(),
+            "greenfield-agent-aaron-".len() + 12
+        );
+    }
+
+    #[test]
+    fn test_branch_prefix_applies_to_sprint_and_agent_branches() {
+        let ctx = RunContext::new("greenfield", 2).with_branch_prefix("team/swarm-");
+        assert_eq!(
+            ctx.sprint_branch(),
+            format!("team/swarm-greenfield-sprint-2-{}", ctx.hash())
+        );
+        assert_eq!(
+            ctx.agent_branch('B'),
+            format!("team/swarm-greenfield-agent-betty-{}", ctx.hash())
+        );
+    }
+
+    #[test]
+    fn test_branch_prefix_defaults_to_empty() {
+        let ctx = RunContext::new("greenfield", 1);
+        assert!(ctx.branch_prefix.is_empty());
+        assert!(ctx.sprint_branch().starts_with("greenfield-"));
+    }
+
     #[test]
     fn test_hash_returns_run_hash() {
         let ctx = RunContext::new("greenfield", 1);
@@ -2,7 +2,8 @@
 //!
 //! Generates unique 6-character alphanumeric hashes to identify sprint runs.
 //! Each sprint run gets a unique hash, ensuring worktrees and branches from
-//! different runs don't conflict.
+//! different runs don't conflict. A `--run-id` replaces the generated hash so
+//! reruns produce the same branch names.
 
 use rand::Rng;
 
@@ -10,8 +11,14 @@ use rand::Rng;
 /// This ensures git branch name compatibility.
 const CHARSET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
 
-/// Length of generated run hashes.
-const HASH_LEN: usize = 6;
+/// Default length of generated run hashes.
+pub const DEFAULT_HASH_LEN: usize = 6;
+
+/// Minimum configurable run hash length.
+pub const MIN_HASH_LEN: usize = 4;
+
+/// Maximum configurable run hash length.
+pub const MAX_HASH_LEN: usize = 20;
 
 /// Generates a 6-character alphanumeric hash unique to this run.
 ///
@@ -27,8 +34,24 @@ const HASH_LEN: usize = 6;
 /// assert!(hash.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
 /// ```
 pub fn generate_run_hash() -> String {
+    generate_run_hash_with_len(DEFAULT_HASH_LEN)
+}
+
+/// Generates an alphanumeric hash of the given length.
+///
+/// The length is clamped to `MIN_HASH_LEN..=MAX_HASH_LEN`.
+///
+/// # Examples
+/// ```
+/// use swarm::run_hash::generate_run_hash_with_len;
+///
+/// assert_eq!(generate_run_hash_with_len(10).len(), 10);
+/// assert_eq!(generate_run_hash_with_len(1).len(), 4);
+/// ```
+pub fn generate_run_hash_with_len(len: usize) -> String {
+    let len = len.clamp(MIN_HASH_LEN, MAX_HASH_LEN);
     let mut rng = rand::thread_rng();
-    (0..HASH_LEN)
+    (0..len)
         .map(|_| {
             let idx = rng.gen_range(0..CHARSET.len());
             CHARSET[idx] as char
@@ -36,6 +59,32 @@ pub fn generate_run_hash() -> String {
         .collect()
 }
 
+/// Turns a user-supplied run ID into a branch-safe run hash.
+///
+/// Letters are lowercased, every run of other characters becomes a single
+/// `-`, and leading/trailing dashes are dropped. Returns `None` when nothing
+/// usable is left.
+///
+/// # Examples
+/// ```
+/// use swarm::run_hash::sanitize_run_id;
+///
+/// assert_eq!(sanitize_run_id("Nightly Build #42").as_deref(), Some("nightly-build-42"));
+/// assert_eq!(sanitize_run_id("~~"), None);
+/// ```
+pub fn sanitize_run_id(run_id: &str) -> Option<String> {
+    let mut sanitized = String::with_capacity(run_id.len());
+    for ch in run_id.chars() {
+        if ch.is_ascii_alphanumeric() {
+            sanitized.push(ch.to_ascii_lowercase());
+        } else if !sanitized.is_empty() && !sanitized.ends_with('-') {
+            sanitized.push('-');
+        }
+    }
+    let sanitized = sanitized.trim_end_matches('-');
+    (!sanitized.is_empty()).then(|| sanitized.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,6 +153,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hash_with_len_respects_length() {
+        for len in MIN_HASH_LEN..=MAX_HASH_LEN {
+            assert_eq!(generate_run_hash_with_len(len).len(), len);
+        }
+    }
+
+    #[test]
+    fn test_hash_with_len_clamps_out_of_range() {
+        assert_eq!(generate_run_hash_with_len(0).len(), MIN_HASH_LEN);
+        assert_eq!(generate_run_hash_with_len(64).len(), MAX_HASH_LEN);
+    }
+
+    #[test]
+    fn test_sanitize_run_id() {
+        assert_eq!(sanitize_run_id("release-7").as_deref(), Some("release-7"));
+        assert_eq!(sanitize_run_id("CI_Run 12").as_deref(), Some("ci-run-12"));
+        assert_eq!(sanitize_run_id("--a..b//").as_deref(), Some("a-b"));
+        assert_eq!(sanitize_run_id(""), None);
+        assert_eq!(sanitize_run_id(" / "), None);
+    }
+
     #[test]
     fn test_hash_not_empty() {
         let hash = generate_run_hash();
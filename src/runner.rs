@@ -1,36 +1,51 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use swarm::agent;
-use swarm::agent::INITIALS;
+use swarm::audit;
 use swarm::chat;
 use swarm::color::{self, emoji};
-use swarm::config::{Config, EngineType};
+use swarm::config::{AgentTaskOrder, Config, EngineType, NoAgentsBehavior};
 use swarm::engine;
+use swarm::events;
 use swarm::heartbeat;
-use swarm::lifecycle::LifecycleTracker;
+use swarm::json::{escape_json, json_string_field};
+use swarm::lifecycle::{AgentState, LifecycleTracker};
 use swarm::log::{self, AgentLogger, NamedLogger};
 use swarm::merge_agent;
+use swarm::pause;
 use swarm::planning;
+use swarm::process_registry::{self, PROCESS_REGISTRY};
+use swarm::prompt_dump;
+use swarm::rate_limit::{ConcurrencyLimiter, RateLimiter};
 use swarm::run_context::RunContext;
-use swarm::shutdown;
-use swarm::task::TaskList;
+use swarm::shutdown::{self, ShutdownSignal};
+use swarm::slack;
+use swarm::task::{insert_after_last_task, merge_prerequisites, Task, TaskList};
 use swarm::team;
+use swarm::verbosity::{self, Verbosity};
+use swarm::warnings::WarningCollector;
+use swarm::webhook;
 use swarm::worktree::{self, Worktree};
+use swarm::{info, verbose};
 
 use crate::git::{
     commit_files_in_worktree_on_branch, commit_sprint_completion, commit_task_assignments,
-    create_pull_request, get_commit_log_between, get_current_commit_in, get_git_log_range_in,
-    get_short_commit_for_ref_in, git_repo_root, push_branch_to_remote, PullRequestCreateResult,
+    create_pull_request, detect_forge, fetch_and_fast_forward_target, get_commit_log_between,
+    get_current_commit_in, get_git_log_range_in, get_short_commit_for_ref_in, git_repo_root,
+    is_ancestor_in, push_branch_to_remote, FetchTargetResult, PullRequestCreateResult,
 };
 use crate::output::{print_sprint_start_banner, print_team_status_banner};
 use crate::project::project_name_for_config;
 
 type TaskResult = (char, String, bool, Option<String>, Option<Duration>);
+/// Task results plus the token usage reported by one agent thread.
+type AgentOutcome = (Vec<TaskResult>, Option<engine::TokenUsage>);
 
 #[derive(Debug, Clone)]
 struct MergeFailureInfo {
@@ -40,9 +55,93 @@ struct MergeFailureInfo {
     worktree_path: String,
     log_path: String,
     detail: String,
+    /// Files git reported as conflicting (empty for non-conflict failures).
+    files: Vec<String>,
     skip_cleanup: bool,
 }
 
+/// Write the sprint's merge failures to `conflicts.json`, replacing the last sprint's.
+///
+/// One failure object per line, like `warnings.json`, so tooling can read it
+/// without a full JSON parser.
+fn write_conflict_report(
+    path: &Path,
+    sprint: usize,
+    failures: &[MergeFailureInfo],
+) -> Result<(), String> {
+    let entries: Vec<String> = failures
+        .iter()
+        .map(|f| {
+            let files: Vec<String> = f
+                .files
+                .iter()
+                .map(|file| format!("\"{}\"", escape_json(file)))
+                .collect();
+            format!(
+                "    {{\"agent\": \"{}\", \"initial\": \"{}\", \"branch\": \"{}\", \"files\": [{}], \"worktree_path\": \"{}\", \"log_path\": \"{}\", \"detail\": \"{}\"}}",
+                escape_json(&f.agent_name),
+                agent::id(f.initial),
+                escape_json(&f.branch),
+                files.join(", "),
+                escape_json(&f.worktree_path),
+                escape_json(&f.log_path),
+                escape_json(&f.detail)
+            )
+        })
+        .collect();
+    let json = if entries.is_empty() {
+        format!("{{\n  \"sprint\": {},\n  \"conflicts\": []\n}}\n", sprint)
+    } else {
+        format!(
+            "{{\n  \"sprint\": {},\n  \"conflicts\": [\n{}\n  ]\n}}\n",
+            sprint,
+            entries.join(",\n")
+        )
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("failed to create directory: {}", e))?;
+    }
+    fs::write(path, json).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+/// Average time per task spent Working, and spent waiting: Assigned before
+/// starting, plus Done until merged and terminated. `None` if no task started.
+fn average_state_times(tracker: &LifecycleTracker) -> Option<(Duration, Duration)> {
+    let mut tasks = 0u32;
+    let mut working = Duration::ZERO;
+    let mut waiting = Duration::ZERO;
+    for ctx in tracker.all() {
+        tasks += ctx
+            .history
+            .iter()
+            .filter(|t| t.state == AgentState::Working)
+            .count() as u32;
+        let durations = ctx.state_durations();
+        let time_in = |state| durations.get(&state).copied().unwrap_or_default();
+        working += time_in(AgentState::Working);
+        waiting += time_in(AgentState::Assigned) + time_in(AgentState::Done);
+    }
+    (tasks > 0).then(|| (working / tasks, waiting / tasks))
+}
+
+/// Write the sprint's agent transition history (see [`LifecycleTracker::to_json`]).
+fn write_run_metadata(path: &Path, run_ctx: &RunContext) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("failed to create directory: {}", e))?;
+    }
+    fs::write(path, run_ctx.to_json())
+        .map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+fn write_lifecycle_report(path: &Path, tracker: &LifecycleTracker) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("failed to create directory: {}", e))?;
+    }
+    fs::write(path, tracker.to_json())
+        .map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
 fn split_cleanup_initials(
     initials: &[char],
     merge_failures: &[MergeFailureInfo],
@@ -64,6 +163,75 @@ fn split_cleanup_initials(
     (cleanup, skipped)
 }
 
+/// Number of agents needed to take every assignable task.
+///
+/// With per-priority limits, focused tiers need more agents than a plain
+/// division suggests, so the assignment is simulated on a copy. When tasks
+/// carry `(<n>pts)` estimates, enough agents are added to keep each one
+/// within `points_per_agent` (0 disables the points budget).
+fn agents_needed_for(
+    task_list: &TaskList,
+    tasks_per_agent: usize,
+    tier_limits: &BTreeMap<u8, usize>,
+    points_per_agent: u32,
+) -> usize {
+    let by_count = agents_needed_by_count(task_list, tasks_per_agent, tier_limits);
+    match task_list.assignable_points() {
+        Some(points) if points_per_agent > 0 => {
+            by_count.max(points.div_ceil(points_per_agent) as usize)
+        }
+        _ => by_count,
+    }
+}
+
+fn agents_needed_by_count(
+    task_list: &TaskList,
+    tasks_per_agent: usize,
+    tier_limits: &BTreeMap<u8, usize>,
+) -> usize {
+    if tier_limits.is_empty() {
+        return task_list.assignable_count().div_ceil(tasks_per_agent);
+    }
+    let mut preview = task_list.clone();
+    preview.assign_sprint_with_tiers(&agent::initials(), tasks_per_agent, tier_limits);
+    preview
+        .tasks
+        .iter()
+        .filter_map(|t| match t.status {
+            swarm::task::TaskStatus::Assigned(initial) => Some(initial),
+            _ => None,
+        })
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Maximum candidate names tried before giving up on preserving a worktree.
+const MAX_PRESERVE_PATH_ATTEMPTS: usize = 100;
+
+/// Pick an unused path under `preserved_root` for a preserved worktree.
+///
+/// Tries `<base_name>` first, then `<base_name>-<pid>-<n>` with an increasing
+/// counter so repeated failures within the same millisecond never collide.
+fn unique_preserved_path(preserved_root: &Path, base_name: &str) -> Result<PathBuf, String> {
+    let candidate = preserved_root.join(base_name);
+    if !candidate.exists() {
+        return Ok(candidate);
+    }
+    let pid = process::id();
+    for attempt in 1..MAX_PRESERVE_PATH_ATTEMPTS {
+        let candidate = preserved_root.join(format!("{}-{}-{}", base_name, pid, attempt));
+        if !candidate.exists() {
+            return Ok(candidate);
+        }
+    }
+    Err(format!(
+        "no unused preserved worktree path for '{}' under {} after {} attempts",
+        base_name,
+        preserved_root.display(),
+        MAX_PRESERVE_PATH_ATTEMPTS
+    ))
+}
+
 struct PreserveOutcome {
     path: PathBuf,
     allow_recreate: bool,
@@ -96,7 +264,7 @@ fn preserve_failed_worktree(
     } else {
         repo_root.join(worktrees_dir)
     };
-    let preserved_root = worktrees_dir.join("preserved");
+    let preserved_root = worktrees_dir.join(worktree::PRESERVED_DIR);
     if let Err(e) = fs::create_dir_all(&preserved_root) {
         outcome.error = Some(format!(
             "failed to create preserved worktrees dir {}: {}",
@@ -110,17 +278,14 @@ fn preserve_failed_worktree(
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
         .as_millis();
-    let mut preserved_path =
-        preserved_root.join(format!("{}-preserved-{}-{}", branch, task_index + 1, ts));
-    if preserved_path.exists() {
-        preserved_path = preserved_root.join(format!(
-            "{}-preserved-{}-{}-{}",
-            branch,
-            task_index + 1,
-            ts,
-            process::id()
-        ));
-    }
+    let base_name = format!("{}-preserved-{}-{}", branch, task_index + 1, ts);
+    let preserved_path = match unique_preserved_path(&preserved_root, &base_name) {
+        Ok(path) => path,
+        Err(e) => {
+            outcome.error = Some(e);
+            return outcome;
+        }
+    };
 
     let worktree_path_str = worktree_path.to_string_lossy().to_string();
     let preserved_path_str = preserved_path.to_string_lossy().to_string();
@@ -287,6 +452,8 @@ pub(crate) struct SprintResult {
     pub(crate) tasks_completed: usize,
     /// Number of tasks that failed.
     pub(crate) tasks_failed: usize,
+    /// Number of agents whose work failed to merge into the sprint branch.
+    pub(crate) merge_failures: usize,
 }
 
 impl SprintResult {
@@ -296,97 +463,6 @@ impl SprintResult {
     }
 }
 
-/// Retry the merge agent once after an initial `ensure_feature_merged` failure.
-///
-/// Re-prepares the workspace, re-runs the merge agent, and re-checks merge status.
-/// Returns `Ok(())` if the retry succeeds, or an error combining both attempts' context.
-#[cfg(test)]
-fn retry_merge_agent(
-    engine: &dyn swarm::engine::Engine,
-    sprint_branch: &str,
-    target_branch: &str,
-    feature_worktree_path: &Path,
-    merge_cleanup_paths: &[PathBuf],
-    first_err: &str,
-    merge_logger: &log::NamedLogger,
-) -> Result<(), String> {
-    // Re-prepare workspace for the retry attempt.
-    if let Err(e) = merge_agent::prepare_merge_workspace(feature_worktree_path, merge_cleanup_paths)
-    {
-        let _ = merge_logger.log(&format!("Retry prepare workspace failed: {}", e));
-        return Err(format!(
-            "merge agent failed: attempt 1: {}; retry prepare failed: {}",
-            first_err, e
-        ));
-    }
-    let _ = merge_logger.log("Retry: workspace re-prepared");
-
-    let retry_result =
-        merge_agent::run_merge_agent(engine, sprint_branch, target_branch, feature_worktree_path)
-            .map_err(|e| {
-            let _ = merge_logger.log(&format!("Retry merge agent execution failed: {}", e));
-            format!(
-                "merge agent failed: attempt 1: {}; retry execution failed: {}",
-                first_err, e
-            )
-        })?;
-
-    if !retry_result.output.is_empty() {
-        let output_preview = if retry_result.output.len() > 1000 {
-            format!(
-                "{}... [truncated, {} bytes total]",
-                &retry_result.output[..1000],
-                retry_result.output.len()
-            )
-        } else {
-            retry_result.output.clone()
-        };
-        let _ = merge_logger.log(&format!("Retry engine output:\n{}", output_preview));
-    }
-    let _ = merge_logger.log(&format!(
-        "Retry engine result: {} (exit_code={})",
-        if retry_result.success {
-            "success"
-        } else {
-            "failure"
-        },
-        retry_result.exit_code
-    ));
-    if let Some(err) = retry_result.error.as_deref() {
-        let _ = merge_logger.log(&format!("Retry engine error: {}", err));
-    }
-
-    if !retry_result.success {
-        let detail = retry_result
-            .error
-            .unwrap_or_else(|| "unknown error".to_string());
-        let _ = merge_logger.log(&format!("Retry merge agent not successful: {}", detail));
-        return Err(format!(
-            "merge agent failed: attempt 1: {}; retry failed: {}",
-            first_err, detail
-        ));
-    }
-
-    // Re-check merge status after retry.
-    if let Err(retry_err) = merge_agent::ensure_feature_merged(
-        engine,
-        sprint_branch,
-        target_branch,
-        feature_worktree_path,
-    ) {
-        let _ = merge_logger.log(&format!(
-            "Merge verification failed (attempt 2): {}",
-            retry_err
-        ));
-        return Err(format!(
-            "merge agent failed after retry: attempt 1: {}; attempt 2: {}",
-            first_err, retry_err
-        ));
-    }
-    let _ = merge_logger.log("Merge verification succeeded on retry (attempt 2)");
-    Ok(())
-}
-
 const DEFAULT_PR_BODY: &str = "Auto-generated by swarm sprint.";
 
 fn default_pr_title(target_branch: &str) -> String {
@@ -411,96 +487,6 @@ Commit log (`git log --oneline {}..{}`):\n{}\n",
     )
 }
 
-fn skip_json_whitespace(input: &str, mut index: usize) -> usize {
-    while index < input.len() {
-        let Some(ch) = input[index..].chars().next() else {
-            break;
-        };
-        if ch.is_whitespace() {
-            index += ch.len_utf8();
-        } else {
-            break;
-        }
-    }
-    index
-}
-
-fn parse_json_string_at(input: &str, start_index: usize) -> Option<(String, usize)> {
-    if input.as_bytes().get(start_index) != Some(&b'"') {
-        return None;
-    }
-
-    let mut decoded = String::new();
-    let mut index = start_index + 1;
-
-    while index < input.len() {
-        let ch = input[index..].chars().next()?;
-        match ch {
-            '"' => return Some((decoded, index + 1)),
-            '\\' => {
-                index += 1;
-                let escaped = input[index..].chars().next()?;
-                match escaped {
-                    '"' => decoded.push('"'),
-                    '\\' => decoded.push('\\'),
-                    '/' => decoded.push('/'),
-                    'b' => decoded.push('\u{0008}'),
-                    'f' => decoded.push('\u{000C}'),
-                    'n' => decoded.push('\n'),
-                    'r' => decoded.push('\r'),
-                    't' => decoded.push('\t'),
-                    'u' => {
-                        let hex_start = index + 1;
-                        let hex_end = hex_start + 4;
-                        if hex_end > input.len() {
-                            return None;
-                        }
-                        let codepoint = u32::from_str_radix(&input[hex_start..hex_end], 16).ok()?;
-                        let value = char::from_u32(codepoint)?;
-                        decoded.push(value);
-                        index = hex_end;
-                        continue;
-                    }
-                    _ => return None,
-                }
-                index += escaped.len_utf8();
-            }
-            _ => {
-                decoded.push(ch);
-                index += ch.len_utf8();
-            }
-        }
-    }
-
-    None
-}
-
-fn parse_json_string_field(json: &str, key: &str) -> Option<String> {
-    let key_pattern = format!("\"{}\"", key);
-    let mut search_start = 0;
-
-    while search_start < json.len() {
-        let relative = json[search_start..].find(&key_pattern)?;
-        let key_start = search_start + relative;
-        let mut value_start = key_start + key_pattern.len();
-        value_start = skip_json_whitespace(json, value_start);
-        if json.as_bytes().get(value_start) != Some(&b':') {
-            search_start = key_start + key_pattern.len();
-            continue;
-        }
-
-        value_start += 1;
-        value_start = skip_json_whitespace(json, value_start);
-        if let Some((value, _)) = parse_json_string_at(json, value_start) {
-            return Some(value);
-        }
-
-        search_start = key_start + key_pattern.len();
-    }
-
-    None
-}
-
 fn find_matching_json_object_end(output: &str, start_index: usize) -> Option<usize> {
     if output[start_index..].chars().next()? != '{' {
         return None;
@@ -551,10 +537,10 @@ fn parse_pr_metadata_from_engine_output(output: &str) -> Option<(String, String)
             continue;
         };
         let candidate = &output[start_index..=end_index];
-        let Some(title) = parse_json_string_field(candidate, "title") else {
+        let Some(title) = json_string_field(candidate, "title") else {
             continue;
         };
-        let Some(body) = parse_json_string_field(candidate, "body") else {
+        let Some(body) = json_string_field(candidate, "body") else {
             continue;
         };
         if !title.trim().is_empty() && !body.trim().is_empty() {
@@ -565,6 +551,27 @@ fn parse_pr_metadata_from_engine_output(output: &str) -> Option<(String, String)
     None
 }
 
+/// Default log preview length for agent engine output.
+const AGENT_OUTPUT_LOG_BYTES: usize = 500;
+/// Default log preview length for merge-agent engine output.
+const MERGE_OUTPUT_LOG_BYTES: usize = 1000;
+
+/// Truncate engine output for a log entry (`max_bytes == 0` keeps everything).
+fn truncate_for_log_bytes(output: &str, max_bytes: usize) -> String {
+    if max_bytes == 0 || output.len() <= max_bytes {
+        return output.to_string();
+    }
+    let mut end = max_bytes;
+    while !output.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}... [truncated, {} bytes total]",
+        &output[..end],
+        output.len()
+    )
+}
+
 fn truncate_for_log_chars(input: &str, max_chars: usize) -> String {
     let mut preview: String = input.chars().take(max_chars).collect();
     let total_chars = input.chars().count();
@@ -593,6 +600,7 @@ fn generate_pr_title_and_body(
         }
     };
     let prompt = build_pr_metadata_prompt(source_branch, target_branch, &commit_log);
+    prompt_dump::dump("pr_metadata", &prompt);
     let pr_result = engine.execute(
         "ScrumMaster",
         &prompt,
@@ -624,19 +632,59 @@ fn generate_pr_title_and_body(
     }
 }
 
+/// Append the sprint's base commit to a PR body so the run can be traced.
+fn with_base_commit_line(body: &str, base_sha: Option<&str>) -> String {
+    match base_sha {
+        Some(sha) => format!("{}\n\nBase commit: {}", body.trim_end(), sha),
+        None => body.to_string(),
+    }
+}
+
+/// Open the sprint pull request via `create` unless `sprints.create_pr` is off,
+/// returning its URL when one was created.
+fn open_pull_request_if_enabled<F>(
+    create_pr: bool,
+    merge_logger: &NamedLogger,
+    chat_file: &str,
+    warnings: &WarningCollector,
+    create: F,
+) -> Option<String>
+where
+    F: FnOnce() -> PullRequestCreateResult,
+{
+    if create_pr {
+        return report_pull_request_creation(create(), merge_logger, chat_file, warnings);
+    }
+    info!("  PR: disabled by config");
+    let _ = merge_logger.log("PR: disabled by config");
+    if let Err(e) = chat::write_message(chat_file, "ScrumMaster", "PR: disabled by config") {
+        warnings.warn(
+            "chat",
+            format!("  warning: failed to write PR status to chat: {}", e),
+        );
+    }
+    None
+}
+
+/// Log and announce a PR creation outcome, returning the URL of a created PR.
 fn report_pull_request_creation(
     result: PullRequestCreateResult,
     merge_logger: &NamedLogger,
     chat_file: &str,
-) {
+    warnings: &WarningCollector,
+) -> Option<String> {
     match result {
         PullRequestCreateResult::Created {
-            url,
+            url: created_url,
             stdout,
             stderr,
         } => {
-            let url = url.unwrap_or_else(|| "(no URL returned)".to_string());
-            println!("  PR: created {}", url);
+            let url = created_url
+                .clone()
+                .unwrap_or_else(|| "(no URL returned)".to_string());
+            info!("  PR: created {}", url);
+            audit::event("pr_created", &[("url", &url)]);
+            events::emit("pr_created", &[("url", &url)]);
             let _ = merge_logger.log(&format!("PR created: {}", url));
             if !stdout.trim().is_empty() {
                 let _ = merge_logger.log(&format!("PR create stdout: {}", stdout.trim()));
@@ -647,45 +695,67 @@ fn report_pull_request_creation(
             if let Err(e) =
                 chat::write_message(chat_file, "ScrumMaster", &format!("PR: created {}", url))
             {
-                eprintln!("  warning: failed to write PR creation to chat: {}", e);
+                warnings.warn(
+                    "chat",
+                    format!("  warning: failed to write PR creation to chat: {}", e),
+                );
             }
+            created_url
         }
         PullRequestCreateResult::Skipped { reason } => {
-            eprintln!(
-                "  warning: failed to create pull request (continuing): {}",
-                reason
+            audit::failure("pr_skipped", &[("reason", &reason)]);
+            warnings.warn(
+                "pr",
+                format!(
+                    "  warning: failed to create pull request (continuing): {}",
+                    reason
+                ),
             );
             let _ = merge_logger.log(&format!("PR creation skipped: {}", reason));
-            if let Err(e) = chat::write_message(
+            if let Err(e) = chat::write_message_level(
                 chat_file,
                 "ScrumMaster",
+                chat::Level::Warn,
                 &format!("PR: skipped ({})", reason),
             ) {
-                eprintln!("  warning: failed to write PR skip to chat: {}", e);
+                warnings.warn(
+                    "chat",
+                    format!("  warning: failed to write PR skip to chat: {}", e),
+                );
             }
+            None
         }
         PullRequestCreateResult::Failed {
             stdout,
             stderr,
             exit_code,
         } => {
-            eprintln!("  warning: failed to create pull request (continuing)");
+            warnings.warn(
+                "pr",
+                "  warning: failed to create pull request (continuing)",
+            );
             let exit = exit_code
                 .map(|code| code.to_string())
                 .unwrap_or_else(|| "unknown".to_string());
+            audit::failure("pr_failed", &[("exit_code", &exit)]);
             let _ = merge_logger.log(&format!(
                 "PR creation failed: exit_code={} stdout='{}' stderr='{}'",
                 exit,
                 stdout.trim(),
                 stderr.trim()
             ));
-            if let Err(e) = chat::write_message(
+            if let Err(e) = chat::write_message_level(
                 chat_file,
                 "ScrumMaster",
+                chat::Level::Error,
                 "PR: failed to create (continuing)",
             ) {
-                eprintln!("  warning: failed to write PR failure to chat: {}", e);
+                warnings.warn(
+                    "chat",
+                    format!("  warning: failed to write PR failure to chat: {}", e),
+                );
             }
+            None
         }
     }
 }
@@ -705,6 +775,283 @@ fn should_push_target_branch(
     .is_none()
 }
 
+/// How often to re-check for free agents when `on_no_agents = "wait"`.
+const NO_AGENTS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often a wait for free agents checks for a shutdown request.
+const NO_AGENTS_SHUTDOWN_CHECK: Duration = Duration::from_millis(200);
+
+/// Agents with a live engine process recorded by another run of `team_name`.
+///
+/// `own_pids` (this run's PID file) is ignored, and dead or reused PIDs are
+/// filtered out by the registry, so a crashed run never holds agents.
+fn busy_agents(team_name: &str, own_pids: &Path) -> HashSet<char> {
+    team::run_pids_files(team_name)
+        .iter()
+        .filter(|path| path.as_path() != own_pids)
+        .filter_map(|path| process_registry::running(path).ok())
+        .flatten()
+        .filter_map(|process| agent::initial_from_name(&process.agent))
+        .collect()
+}
+
+/// Up to `cap` agents from the roster that are not in `busy`.
+///
+/// The default roster grows past busy agents; a custom roster is fixed, so
+/// it can run out when other runs hold its agents.
+fn available_agents(cap: usize, busy: &HashSet<char>) -> Vec<char> {
+    agent::get_initials(cap.saturating_add(busy.len()))
+        .into_iter()
+        .filter(|initial| !busy.contains(initial))
+        .take(cap)
+        .collect()
+}
+
+/// Resolve the agents available for a sprint, applying `on_no_agents`.
+///
+/// With `wait`, `available` is re-polled every `poll_interval` until it
+/// returns agents or `wait_timeout` runs out. Returns `Ok(None)` when the
+/// sprint should be skipped, including on shutdown during the wait.
+fn wait_for_available_agents<F>(
+    behavior: NoAgentsBehavior,
+    wait_timeout: Duration,
+    poll_interval: Duration,
+    mut available: F,
+) -> Result<Option<Vec<char>>, String>
+where
+    F: FnMut() -> Vec<char>,
+{
+    let initials = available();
+    if !initials.is_empty() {
+        return Ok(Some(initials));
+    }
+
+    match behavior {
+        NoAgentsBehavior::Skip => Ok(None),
+        NoAgentsBehavior::Error => Err(
+            "no agents available for this sprint (check agents.max_count, or agents busy in \
+             other runs; on_no_agents = \"error\")"
+                .to_string(),
+        ),
+        NoAgentsBehavior::Wait => {
+            let start = Instant::now();
+            while start.elapsed() < wait_timeout {
+                let next_poll = Instant::now() + poll_interval;
+                while Instant::now() < next_poll && start.elapsed() < wait_timeout {
+                    if shutdown::requested() {
+                        return Ok(None);
+                    }
+                    thread::sleep(
+                        NO_AGENTS_SHUTDOWN_CHECK
+                            .min(next_poll.saturating_duration_since(Instant::now())),
+                    );
+                }
+                let initials = available();
+                if !initials.is_empty() {
+                    return Ok(Some(initials));
+                }
+            }
+            Err(format!(
+                "no agents became available within {}s (on_no_agents = \"wait\")",
+                wait_timeout.as_secs()
+            ))
+        }
+    }
+}
+
+/// How often agent threads are polled while the sprint waits on them.
+const AGENT_JOIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long agent threads get to return after their processes are force-killed.
+const FORCE_KILL_WAIT: Duration = Duration::from_secs(5);
+
+/// Join agent threads, returning `(initial, result)` in spawn order.
+///
+/// Once `shutdown` is signalled, agents get `grace` to finish their current
+/// task (zero waits indefinitely), with the remaining seconds printed as it
+/// counts down. When it runs out, agent processes are force-killed; threads
+/// that still have not returned shortly after are abandoned and left out of
+/// the result.
+fn join_agents<T>(
+    handles: Vec<(char, thread::JoinHandle<T>)>,
+    grace: Duration,
+    shutdown: &ShutdownSignal,
+    warnings: &WarningCollector,
+) -> Vec<(char, thread::Result<T>)> {
+    let total = handles.len();
+    let mut pending: Vec<(usize, char, thread::JoinHandle<T>)> = handles
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (initial, handle))| (idx, initial, handle))
+        .collect();
+    let mut joined = Vec::with_capacity(total);
+    let mut deadline: Option<Instant> = None;
+    let mut killed_at: Option<Instant> = None;
+    let mut last_shown: Option<u64> = None;
+
+    while !pending.is_empty() {
+        let (finished, running): (Vec<_>, Vec<_>) = pending
+            .into_iter()
+            .partition(|(_, _, handle)| handle.is_finished());
+        pending = running;
+        for (idx, initial, handle) in finished {
+            joined.push((idx, initial, handle.join()));
+            if deadline.is_some() && !pending.is_empty() {
+                info!("  {} agent(s) remaining...", pending.len());
+            }
+        }
+        if pending.is_empty() {
+            break;
+        }
+
+        if shutdown.is_shutdown() {
+            let deadline = *deadline.get_or_insert_with(|| {
+                info!(
+                    "Waiting for {} agent(s) to finish current work...",
+                    pending.len()
+                );
+                Instant::now() + grace
+            });
+            if grace.is_zero() {
+                // Wait indefinitely
+            } else if let Some(killed_at) = killed_at {
+                if killed_at.elapsed() >= FORCE_KILL_WAIT {
+                    for (_, initial, _) in &pending {
+                        warnings.warn(
+                            "agent",
+                            format!(
+                                "warning: agent {} did not stop after being killed; abandoning it",
+                                agent::name_from_initial(*initial)
+                                    .unwrap_or_else(|| "?".to_string())
+                            ),
+                        );
+                    }
+                    break;
+                }
+            } else {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    warnings.warn(
+                        "agent",
+                        format!(
+                            "warning: shutdown grace period ({}s) expired; force-killing {} agent(s)",
+                            grace.as_secs(),
+                            pending.len()
+                        ),
+                    );
+                    PROCESS_REGISTRY.kill_all();
+                    killed_at = Some(Instant::now());
+                } else {
+                    let secs = remaining.as_secs_f64().ceil() as u64;
+                    if last_shown != Some(secs) && (secs.is_multiple_of(10) || secs <= 5) {
+                        info!("  Shutdown grace: {}s remaining...", secs);
+                    }
+                    last_shown = Some(secs);
+                }
+            }
+        }
+        thread::sleep(AGENT_JOIN_POLL_INTERVAL);
+    }
+
+    joined.sort_by_key(|(idx, _, _)| *idx);
+    joined
+        .into_iter()
+        .map(|(_, initial, result)| (initial, result))
+        .collect()
+}
+
+/// Reorder one agent's tasks according to the configured task order.
+///
+/// Priority ordering is a stable sort, so tasks within the same tier (and all
+/// untagged tasks) keep their assignment order.
+fn order_agent_tasks(tasks: &mut [String], order: AgentTaskOrder) {
+    if order == AgentTaskOrder::Priority {
+        tasks.sort_by_key(|description| {
+            Task::new(description.as_str())
+                .priority()
+                .unwrap_or(u8::MAX)
+        });
+    }
+}
+
+/// How often a task waiting on the merge gate re-checks for shutdown.
+const MERGE_GATE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Orders merges within a sprint (`--ordered-merge`): a task merges only once
+/// the sprint tasks it is blocked by have finished, whether they merged or failed.
+struct MergeGate {
+    prerequisites: HashMap<String, Vec<String>>,
+    finished: Mutex<HashSet<String>>,
+    changed: Condvar,
+}
+
+impl MergeGate {
+    fn new(prerequisites: HashMap<String, Vec<String>>) -> Self {
+        Self {
+            prerequisites,
+            finished: Mutex::new(HashSet::new()),
+            changed: Condvar::new(),
+        }
+    }
+
+    fn has_prerequisites(&self, description: &str) -> bool {
+        self.prerequisites.contains_key(description)
+    }
+
+    /// Block until every prerequisite of `description` has finished, or shutdown.
+    fn wait_for_prerequisites(&self, description: &str) {
+        let Some(deps) = self.prerequisites.get(description) else {
+            return;
+        };
+        let mut finished = self.finished.lock().unwrap();
+        while !deps.iter().all(|dep| finished.contains(dep)) && !shutdown::requested() {
+            finished = self
+                .changed
+                .wait_timeout(finished, MERGE_GATE_POLL_INTERVAL)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// Record that `description` is done merging, releasing tasks that wait on it.
+    fn finish(&self, description: &str) {
+        self.finished
+            .lock()
+            .unwrap()
+            .insert(description.to_string());
+        self.changed.notify_all();
+    }
+}
+
+/// Finishes an agent's tasks on the merge gate when its thread exits.
+struct MergeGateRelease {
+    gate: Arc<MergeGate>,
+    tasks: Vec<String>,
+}
+
+impl Drop for MergeGateRelease {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            self.gate.finish(task);
+        }
+    }
+}
+
+/// Chat message for a completed task, preferring the agent's `SWARM_SUMMARY:` note.
+fn completion_message(description: &str, result: &engine::EngineResult) -> String {
+    match result.summary() {
+        Some(summary) => format!("Completed: {}", summary),
+        None => format!("Completed: {}", description),
+    }
+}
+
+/// Agent timeout for one task: its `{timeout: N}` annotation, else `default_secs`.
+fn task_timeout_secs(description: &str, default_secs: u64) -> Result<u64, String> {
+    Ok(Task::new(description)
+        .timeout_secs()?
+        .unwrap_or(default_secs))
+}
+
 /// Run a single sprint.
 ///
 /// The `session_sprint_number` is the sprint number within this run session (1, 2, 3...).
@@ -713,6 +1060,48 @@ pub(crate) fn run_sprint(
     config: &Config,
     session_sprint_number: usize,
     run_instance: &str,
+) -> Result<SprintResult, String> {
+    let warnings = WarningCollector::new();
+    let result = run_sprint_collecting(config, session_sprint_number, run_instance, &warnings);
+    if let Err(ref e) = result {
+        audit::failure(
+            "sprint_failed",
+            &[
+                ("team", &project_name_for_config(config)),
+                ("session_sprint", &session_sprint_number.to_string()),
+                ("error", e),
+            ],
+        );
+    }
+
+    // Only sprints that did work (or hit problems) replace the last warnings file.
+    let ran = matches!(result, Ok(ref r) if r.tasks_assigned > 0);
+    if ran || !warnings.is_empty() {
+        if let Some(path) = warnings_path_for_config(config) {
+            if let Err(e) = warnings.save(&path) {
+                eprintln!("warning: failed to write {}: {}", path.display(), e);
+            }
+        }
+    }
+    result
+}
+
+/// Location of `warnings.json` for the configured team and target branch.
+fn warnings_path_for_config(config: &Config) -> Option<PathBuf> {
+    let source_branch = config.source_branch.as_deref()?;
+    let target_branch = config.target_branch.as_deref()?;
+    let team_name = project_name_for_config(config);
+    Some(
+        team::RuntimeStatePaths::for_branches(&team_name, source_branch, target_branch)
+            .warnings_path(),
+    )
+}
+
+fn run_sprint_collecting(
+    config: &Config,
+    session_sprint_number: usize,
+    run_instance: &str,
+    warnings: &WarningCollector,
 ) -> Result<SprintResult, String> {
     // Resolve runtime state namespace and determine sprint number (peek, don't write yet).
     let team_name = project_name_for_config(config);
@@ -728,9 +1117,25 @@ pub(crate) fn run_sprint(
     let runtime_paths =
         team::RuntimeStatePaths::for_branches(&team_name, source_branch, target_branch);
 
+    // `swarm resume` continues an interrupted sprint instead of starting over.
+    let resume = if config.resume && session_sprint_number == 1 {
+        let point = find_resume_point(&repo_root, &runtime_paths)?;
+        match &point {
+            Some(point) => info!(
+                "{} Resuming interrupted sprint {}",
+                emoji::SPRINT,
+                point.sprint_branch
+            ),
+            None => info!("No interrupted sprint to resume; starting a new sprint."),
+        }
+        point
+    } else {
+        None
+    };
+
     // Start each `swarm run` invocation with a fresh runtime namespace for the
     // target branch to avoid stale cache/state artifacts across reruns.
-    if session_sprint_number == 1 && runtime_paths.is_namespaced() {
+    if session_sprint_number == 1 && runtime_paths.is_namespaced() && resume.is_none() {
         reset_runtime_namespace_for_new_run(&repo_root, &runtime_paths)?;
     }
 
@@ -748,13 +1153,12 @@ pub(crate) fn run_sprint(
     )?;
 
     // Load tasks from runtime-scoped state.
-    let runtime_tasks_path = runtime_paths.tasks_path();
+    let runtime_task_files = runtime_task_file_paths(&runtime_paths, config);
     let runtime_history_path = runtime_paths.sprint_history_path();
     let runtime_state_path = runtime_paths.team_state_path();
 
-    let content = fs::read_to_string(&runtime_tasks_path)
-        .map_err(|e| format!("failed to read {}: {}", runtime_tasks_path.display(), e))?;
-    let mut task_list = TaskList::parse(&content);
+    let mut task_list = TaskList::load_files(&runtime_task_files)?;
+    task_list.completion_style = config.files_tasks_completion_style;
 
     let mut sprint_history = team::SprintHistory::load_from(&runtime_history_path)?;
     if sprint_history.team_name == "unknown" {
@@ -763,75 +1167,35 @@ pub(crate) fn run_sprint(
     let historical_sprint = sprint_history.peek_next_sprint();
     let formatted_team = sprint_history.formatted_team_name();
 
-    // Unassign any incomplete tasks from previous sprints so they can be reassigned fresh.
-    // Keep this in-memory to avoid dirtying the target branch worktree.
-    task_list.unassign_all();
-
-    // Determine how many agents to spawn
-    let assignable = task_list.assignable_count();
-    if assignable == 0 {
-        return Ok(SprintResult {
-            tasks_assigned: 0,
-            tasks_completed: 0,
-            tasks_failed: 0,
-        });
-    }
-
-    let tasks_per_agent = config.agents_tasks_per_agent;
-    let agents_needed = assignable.div_ceil(tasks_per_agent);
-    let agent_cap = agents_needed.min(config.agents_max_count);
-    // With project-namespaced worktrees, all agents are available for any project
-    let initials: Vec<char> = INITIALS.iter().take(agent_cap).copied().collect();
-    if initials.is_empty() {
-        println!("No agents available.");
-        return Ok(SprintResult {
-            tasks_assigned: 0,
-            tasks_completed: 0,
-            tasks_failed: 0,
-        });
-    }
-    let agent_count = initials.len();
-
-    // Assign tasks via LLM planning (with fallback to algorithmic)
+    // Planning engine, also used for the post-sprint review and PR metadata
     let engine = engine::create_engine(
         config.effective_engine(),
         &config.files_log_dir,
-        config.agent_timeout_secs,
-    );
-    let log_dir = Path::new(&config.files_log_dir);
-
-    if let Err(e) =
-        chat::write_message(&config.files_chat, "ScrumMaster", "Sprint planning started")
-    {
-        eprintln!("warning: failed to write chat: {}", e);
-    }
-
-    let plan_result = planning::run_llm_assignment(
-        engine.as_ref(),
-        &task_list,
-        &initials,
-        tasks_per_agent,
-        log_dir,
+        config.planning_timeout_secs,
     );
-
-    let assigned = if !plan_result.success {
-        eprintln!(
-            "LLM planning failed: {}, falling back to algorithmic assignment",
-            plan_result.error.unwrap_or_default()
-        );
-        task_list.assign_sprint(&initials, tasks_per_agent)
+    let (assigned, agent_count) = if resume.is_some() {
+        // Keep the interrupted sprint's assignments instead of re-planning.
+        resumed_assignment_counts(&task_list)
     } else {
-        // Apply LLM assignments (line numbers are 1-indexed in the response)
-        let mut count = 0;
-        for (line_num, initial) in &plan_result.assignments {
-            // Convert line number to task index (0-indexed)
-            let task_idx = line_num.saturating_sub(1);
-            if task_idx < task_list.tasks.len() {
-                task_list.tasks[task_idx].assign(*initial);
-                count += 1;
+        match plan_sprint_assignments(
+            config,
+            engine.as_ref(),
+            &mut task_list,
+            &team_name,
+            &runtime_paths.pids_path(),
+            Some(&config.files_chat),
+            warnings,
+        )? {
+            Some(counts) => counts,
+            None => {
+                return Ok(SprintResult {
+                    tasks_assigned: 0,
+                    tasks_completed: 0,
+                    tasks_failed: 0,
+                    merge_failures: 0,
+                })
             }
         }
-        count
     };
 
     if assigned == 0 {
@@ -839,20 +1203,29 @@ pub(crate) fn run_sprint(
             tasks_assigned: 0,
             tasks_completed: 0,
             tasks_failed: 0,
+            merge_failures: 0,
         });
     }
 
     // Create run context for namespaced artifacts (worktrees, branches)
     // This is created early so the sprint branch uses the run hash
-    let run_ctx = RunContext::new_for_run(
+    let run_ctx = RunContext::new_for_run_with_hash_len(
         &team_name,
         target_branch,
         run_instance,
         historical_sprint as u32,
-    );
+        config.run_hash_len,
+    )
+    .with_branch_prefix(&config.branch_prefix)
+    .with_sparse_paths(&config.worktree_sparse_paths);
+    let run_ctx = match (&resume, config.run_id.as_deref()) {
+        (Some(point), _) => run_ctx.with_run_id(&point.run_hash),
+        (None, Some(run_id)) => run_ctx.with_run_id(run_id),
+        (None, None) => run_ctx,
+    };
 
     // Log run hash at sprint start for visibility
-    println!(
+    info!(
         "{} {} Sprint {} (runtime {}, run {}): starting",
         emoji::SPRINT,
         color::info(&formatted_team),
@@ -860,13 +1233,25 @@ pub(crate) fn run_sprint(
         color::info(run_ctx.runtime_id()),
         color::info(run_ctx.hash())
     );
+    let sprint_label = historical_sprint.to_string();
+    let sprint_fields = [
+        ("team", team_name.as_str()),
+        ("sprint", sprint_label.as_str()),
+        ("run", run_ctx.hash()),
+        ("target", target_branch),
+    ];
+    audit::event("sprint_start", &sprint_fields);
+    events::emit("sprint_started", &sprint_fields);
 
     // Compute sprint branch name using run context (includes run hash)
     let sprint_branch = run_ctx.sprint_branch();
     let sprint_base_branch = resolve_sprint_base_branch(&repo_root, source_branch, target_branch)?;
     let worktrees_dir = Path::new(&config.files_worktrees_dir);
 
-    let base_commit = get_short_commit_for_ref_in(&repo_root, &sprint_base_branch)
+    let run_ctx = run_ctx.with_base_commit(&repo_root, &sprint_base_branch);
+    let base_commit = run_ctx
+        .base_sha()
+        .map(str::to_string)
         .or_else(|| get_short_commit_for_ref_in(&repo_root, "HEAD"))
         .unwrap_or_else(|| "unknown".to_string());
     if let Err(e) = chat::write_message(
@@ -877,7 +1262,7 @@ pub(crate) fn run_sprint(
             sprint_branch, sprint_base_branch, base_commit
         ),
     ) {
-        eprintln!("warning: failed to write chat: {}", e);
+        warnings.warn("chat", format!("warning: failed to write chat: {}", e));
     }
 
     // Create sprint branch/worktree FIRST, before any file writes
@@ -885,17 +1270,28 @@ pub(crate) fn run_sprint(
     // not the target branch (main/master)
 
     // Clean up any existing feature worktree from a failed previous sprint.
-    // This ensures we start fresh from the source branch for this run.
-    if let Err(e) = worktree::cleanup_feature_worktree(worktrees_dir, &sprint_branch, true) {
-        // Log but don't fail - the worktree might not exist
-        eprintln!("  note: pre-sprint feature worktree cleanup: {}", e);
+    // This ensures we start fresh from the source branch for this run. A
+    // resumed sprint keeps its branch and the work already merged into it.
+    if resume.is_none() {
+        if let Err(e) = worktree::cleanup_feature_worktree(worktrees_dir, &sprint_branch, true) {
+            // Log but don't fail - the worktree might not exist
+            warnings.warn(
+                "cleanup",
+                format!("  note: pre-sprint feature worktree cleanup: {}", e),
+            );
+        }
     }
 
     let feature_worktree_path =
         create_sprint_worktree_in(worktrees_dir, &sprint_branch, &sprint_base_branch)?;
 
     // Print sprint start banner (after worktree creation to ensure we have a valid sprint)
-    print_sprint_start_banner(&formatted_team, historical_sprint);
+    if verbosity::enabled(Verbosity::Normal) {
+        print_sprint_start_banner(&formatted_team, historical_sprint, &base_commit);
+    }
+    if let Err(e) = write_run_metadata(&runtime_paths.run_path(), &run_ctx) {
+        warnings.warn("state", format!("warning: {}", e));
+    }
 
     // Construct the sprint worktree swarm directory path.
     let worktree_swarm_dir = feature_worktree_path
@@ -904,6 +1300,7 @@ pub(crate) fn run_sprint(
 
     // Ensure worktree swarm dir exists.
     let worktree_tasks_path = worktree_swarm_dir.join("tasks.md");
+    let worktree_task_files = task_file_paths(&worktree_tasks_path, &feature_worktree_path, config);
     fs::create_dir_all(&worktree_swarm_dir)
         .map_err(|e| format!("failed to create worktree swarm dir: {}", e))?;
     update_runtime_feature_branch(&runtime_state_path, &team_name, Some(&sprint_branch))?;
@@ -911,9 +1308,8 @@ pub(crate) fn run_sprint(
     // Re-read task list from worktree to get any completions from previous sprints
     // that may have been committed to the sprint branch but not yet merged to main
     if worktree_tasks_path.exists() {
-        let worktree_content = fs::read_to_string(&worktree_tasks_path)
-            .map_err(|e| format!("failed to read {}: {}", worktree_tasks_path.display(), e))?;
-        let worktree_task_list = TaskList::parse(&worktree_content);
+        let existing: Vec<&PathBuf> = worktree_task_files.iter().filter(|p| p.exists()).collect();
+        let worktree_task_list = TaskList::load_files(&existing)?;
 
         // Merge: keep completed tasks from worktree, apply new assignments from task_list
         for worktree_task in &worktree_task_list.tasks {
@@ -929,13 +1325,13 @@ pub(crate) fn run_sprint(
         }
     }
 
-    // Write merged task list to worktree
-    fs::write(&worktree_tasks_path, task_list.to_string())
-        .map_err(|e| format!("failed to write {}: {}", worktree_tasks_path.display(), e))?;
+    // Write merged task list to worktree, each task back to its own file
+    task_list.relocate_sources(&worktree_task_files);
+    task_list.save_sources()?;
 
     // Persist runtime-scoped planning tasks for this target branch.
     if runtime_paths.is_namespaced() {
-        persist_runtime_tasks_file(&worktree_tasks_path, &runtime_tasks_path)?;
+        persist_runtime_task_files(&worktree_task_files, &runtime_task_files)?;
     }
 
     // Collect assignments
@@ -958,6 +1354,32 @@ pub(crate) fn run_sprint(
         }
     }
 
+    // Group assignments by agent (each agent processes their tasks sequentially)
+    let mut agent_tasks: std::collections::HashMap<char, Vec<String>> =
+        std::collections::HashMap::new();
+    for (initial, description) in &assignments {
+        agent_tasks
+            .entry(*initial)
+            .or_default()
+            .push(description.clone());
+    }
+    for tasks in agent_tasks.values_mut() {
+        order_agent_tasks(tasks, config.agent_task_order);
+    }
+
+    // With --ordered-merge, each task's merge waits for the sprint tasks it is
+    // blocked by. A dependency cycle would stall the sprint, so reject it here.
+    let merge_gate = if config.sprints_ordered_merge {
+        let mut ordered: Vec<(char, Vec<String>)> = agent_tasks
+            .iter()
+            .map(|(initial, tasks)| (*initial, tasks.clone()))
+            .collect();
+        ordered.sort_by_key(|(initial, _)| *initial);
+        Some(Arc::new(MergeGate::new(merge_prerequisites(&ordered)?)))
+    } else {
+        None
+    };
+
     // Write sprint plan to chat
     let assignments_ref: Vec<(char, &str)> =
         assignments.iter().map(|(i, d)| (*i, d.as_str())).collect();
@@ -968,7 +1390,7 @@ pub(crate) fn run_sprint(
     commit_task_assignments(
         &feature_worktree_path,
         &sprint_branch,
-        worktree_tasks_path.to_str().unwrap_or(""),
+        &path_strs(&worktree_task_files)?,
         &formatted_team,
         historical_sprint,
     )?;
@@ -978,7 +1400,7 @@ pub(crate) fn run_sprint(
     let sprint_start_commit =
         get_current_commit_in(&feature_worktree_path).unwrap_or_else(|| "HEAD".to_string());
 
-    println!(
+    info!(
         "{} {} Sprint {}: assigned {} task(s) to {} agent(s)",
         emoji::SPRINT,
         color::info(&formatted_team),
@@ -997,16 +1419,19 @@ pub(crate) fn run_sprint(
         &run_ctx,
     );
     if cleanup_summary.cleaned_count() > 0 {
-        println!(
+        info!(
             "  Pre-sprint cleanup: removed {} worktree(s)",
             cleanup_summary.cleaned_count()
         );
     }
     for (initial, err) in &cleanup_summary.errors {
-        let name = agent::name_from_initial(*initial).unwrap_or("?");
-        eprintln!(
-            "  warning: pre-sprint cleanup failed for {} ({}): {}",
-            name, initial, err
+        let name = agent::name_from_initial(*initial).unwrap_or_else(|| "?".to_string());
+        warnings.warn(
+            "cleanup",
+            format!(
+                "  warning: pre-sprint cleanup failed for {} ({}): {}",
+                name, initial, err
+            ),
         );
     }
 
@@ -1023,10 +1448,29 @@ pub(crate) fn run_sprint(
 
     let worktrees_dir_buf = PathBuf::from(&config.files_worktrees_dir);
 
-    // Initialize lifecycle tracker (wrapped for thread-safe access)
-    let tracker = Arc::new(Mutex::new(LifecycleTracker::new()));
+    // Initialize lifecycle tracker (wrapped for thread-safe access), forwarding
+    // each state change to event subscribers
+    let mut lifecycle = LifecycleTracker::new();
+    lifecycle.on_transition(Box::new(|initial, from, to| {
+        events::emit(
+            "agent_state",
+            &[
+                (
+                    "agent",
+                    agent::name_from_initial(initial)
+                        .as_deref()
+                        .unwrap_or("Unknown"),
+                ),
+                ("initial", &agent::id(initial)),
+                ("from", &from.to_string()),
+                ("to", &to.to_string()),
+            ],
+        );
+    }));
+    let tracker = Arc::new(Mutex::new(lifecycle));
     for (initial, description) in &assignments {
-        let agent_name = agent::name_from_initial(*initial).unwrap_or("Unknown");
+        let agent_name = agent::name_from_initial(*initial);
+        let agent_name = agent_name.as_deref().unwrap_or("Unknown");
         let wt_path = worktree_map
             .get(initial)
             .map(|p| p.to_string_lossy().to_string())
@@ -1045,25 +1489,24 @@ pub(crate) fn run_sprint(
     let engine_stub_mode = config.engine_stub_mode;
     let agent_timeout_secs = config.agent_timeout_secs;
 
+    // Shared limiter paces engine executions across all agent threads
+    let rate_limiter = Arc::new(RateLimiter::per_minute(config.engine_max_rpm));
+    // Caps simultaneous engine executions; extra agents queue for a slot
+    let concurrency_limiter = Arc::new(ConcurrencyLimiter::new(config.engine_max_concurrency));
+
     // Rotate any large logs before starting
     let log_dir_path = config.files_log_dir.clone();
-    if let Err(e) = log::rotate_logs_in_dir(Path::new(&log_dir_path), log::DEFAULT_MAX_LINES) {
-        eprintln!("warning: failed to rotate logs: {}", e);
-    }
-
-    // Group assignments by agent (each agent processes their tasks sequentially)
-    let mut agent_tasks: std::collections::HashMap<char, Vec<String>> =
-        std::collections::HashMap::new();
-    for (initial, description) in &assignments {
-        agent_tasks
-            .entry(*initial)
-            .or_default()
-            .push(description.clone());
+    if let Err(e) = log::rotate_logs_in_dir(
+        Path::new(&log_dir_path),
+        log::DEFAULT_MAX_LINES,
+        config.files_log_max_rotations,
+    ) {
+        warnings.warn("log", format!("warning: failed to rotate logs: {}", e));
     }
 
     // Execute agents in parallel, each agent processes their tasks sequentially
     // Return type includes: (initial, description, success, error, duration)
-    let mut handles: Vec<thread::JoinHandle<Vec<TaskResult>>> = Vec::new();
+    let mut handles: Vec<(char, thread::JoinHandle<AgentOutcome>)> = Vec::new();
 
     // Always pass canonical team directory to engines. Runtime tasks may be
     // namespaced under runs/<target>, but prompt-derived
@@ -1090,39 +1533,81 @@ pub(crate) fn run_sprint(
         let thread_engine_types = engine_types.clone();
         let thread_engine_stub_mode = engine_stub_mode;
         let thread_agent_timeout = agent_timeout_secs;
+        let thread_heartbeat_interval = Duration::from_secs(config.heartbeat_interval_secs);
+        let webhook = webhook::Webhook::new(config.webhook_url.as_deref(), &run_ctx.project);
+        let thread_merge_timeout = config.merge_timeout_secs;
+        let thread_stub_fail_rate = config.engine_stub_fail_rate;
+        let thread_seed = config.seed;
+        let thread_engine_retries = config.engine_retries;
+        let thread_retry_delay = Duration::from_secs(config.engine_retry_delay_secs);
+        let thread_log_bytes = config
+            .files_log_output_max_bytes
+            .unwrap_or(AGENT_OUTPUT_LOG_BYTES);
+        let rate_limiter = Arc::clone(&rate_limiter);
+        let concurrency_limiter = Arc::clone(&concurrency_limiter);
+        let merge_gate = merge_gate.clone();
+        let warnings = warnings.clone();
 
         let handle = thread::spawn(move || {
-            let agent_name = agent::name_from_initial(initial).unwrap_or("Unknown");
+            let agent_name = agent::name_from_initial(initial);
+            let agent_name = agent_name.as_deref().unwrap_or("Unknown");
+            // Tasks this agent never merges (skipped, or a panic) must not hold up dependents
+            let _merge_gate_release = merge_gate.clone().map(|gate| MergeGateRelease {
+                gate,
+                tasks: tasks.clone(),
+            });
             let mut task_results: Vec<TaskResult> = Vec::new();
+            let mut agent_usage: Option<engine::TokenUsage> = None;
 
             // Create agent logger
             let logger = AgentLogger::new(Path::new(&log_dir), initial, agent_name);
 
             // Log session start
             if let Err(e) = logger.log_session_start() {
-                eprintln!("warning: failed to write log: {}", e);
+                warnings.warn("log", format!("warning: failed to write log: {}", e));
             }
             if let Err(e) = logger.log(&format!("Working directory: {}", working_dir.display())) {
-                eprintln!("warning: failed to write log: {}", e);
+                warnings.warn("log", format!("warning: failed to write log: {}", e));
             }
 
             let total_tasks = tasks.len();
 
+            // Seeded runs repeat the same engine picks for this agent and sprint
+            let mut engine_rng = engine::selection_rng(thread_seed, initial, session_sprint_number);
+
             // Process each task sequentially for this agent
             for (task_index, description) in tasks.iter().enumerate() {
                 let description = description.clone();
+                let task_timeout = task_timeout_secs(&description, thread_agent_timeout)
+                    .unwrap_or_else(|e| {
+                        warnings.warn("agent", format!("warning: {} (using global timeout)", e));
+                        thread_agent_timeout
+                    });
                 // Select and create random engine for this task (per-task engine selection)
-                let (engine, selected_engine_type) = engine::create_random_engine(
+                let selected_engine_type = engine::select_engine_type_with_rng(
                     &thread_engine_types,
                     thread_engine_stub_mode,
-                    &log_dir,
-                    thread_agent_timeout,
+                    &mut engine_rng,
                 );
+                let engine: Arc<dyn engine::Engine> = match selected_engine_type {
+                    EngineType::Stub => Arc::new(
+                        engine::StubEngine::new(log_dir.as_str())
+                            .with_fail_rate(thread_stub_fail_rate, thread_seed),
+                    ),
+                    ref selected => engine::create_engine(selected.clone(), &log_dir, task_timeout),
+                };
+                let engine: Arc<dyn engine::Engine> = Arc::new(engine::RetryingEngine::new(
+                    engine,
+                    thread_engine_retries,
+                    thread_retry_delay,
+                ));
                 let engine_type_str = selected_engine_type.as_str();
+                // Hold the next task while the TUI has the run paused
+                wait_while_paused(&logger, &chat_path, agent_name, &warnings);
                 // Check for shutdown before starting a new task
                 if shutdown::requested() {
                     if let Err(e) = logger.log("Shutdown requested, skipping remaining tasks") {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
                     // Mark remaining tasks as not completed (they stay assigned)
                     task_results.push((
@@ -1140,16 +1625,30 @@ pub(crate) fn run_sprint(
                     "Assigned task: {} [engine: {}]",
                     description, engine_type_str
                 )) {
-                    eprintln!("warning: failed to write log: {}", e);
+                    warnings.warn("log", format!("warning: failed to write log: {}", e));
                 }
 
                 // Transition: Assigned -> Working
                 {
                     let mut t = tracker.lock().unwrap();
+                    t.register(
+                        initial,
+                        agent_name,
+                        &description,
+                        &working_dir.to_string_lossy(),
+                    );
                     t.start(initial);
                 }
+                events::emit(
+                    "task_started",
+                    &[
+                        ("agent", agent_name),
+                        ("initial", &agent::id(initial)),
+                        ("task", &description),
+                    ],
+                );
                 if let Err(e) = logger.log("State: ASSIGNED -> WORKING") {
-                    eprintln!("warning: failed to write log: {}", e);
+                    warnings.warn("log", format!("warning: failed to write log: {}", e));
                 }
 
                 // Write agent start to chat (including engine name for visibility)
@@ -1158,12 +1657,42 @@ pub(crate) fn run_sprint(
                     agent_name,
                     &format!("Starting: {} [engine: {}]", description, engine_type_str),
                 ) {
-                    eprintln!("warning: failed to write chat: {}", e);
+                    warnings.warn("chat", format!("warning: failed to write chat: {}", e));
+                }
+                if let Some(webhook) = &webhook {
+                    webhook.notify(agent_name, "started", &description);
+                }
+
+                // Wait for a free execution slot, held until the engine returns
+                if concurrency_limiter.is_enabled() {
+                    if let Err(e) = logger.log("Waiting for an engine execution slot") {
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
+                    }
+                }
+                let permit = concurrency_limiter.acquire();
+
+                // Wait for the rate limiter before calling the engine
+                if rate_limiter.is_enabled() {
+                    if let Err(e) = logger.log("Waiting for engine rate limiter") {
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
+                    }
+                    if !rate_limiter.acquire() {
+                        // Shutdown arrived while waiting; the task stays assigned
+                        tracker.lock().unwrap().fail(initial, "Shutdown requested");
+                        task_results.push((
+                            initial,
+                            description.clone(),
+                            false,
+                            Some("Shutdown requested".to_string()),
+                            None,
+                        ));
+                        continue;
+                    }
                 }
 
                 // Execute via engine in the agent's worktree
                 if let Err(e) = logger.log(&format!("Executing with engine: {}", engine_type_str)) {
-                    eprintln!("warning: failed to write log: {}", e);
+                    warnings.warn("log", format!("warning: failed to write log: {}", e));
                 }
 
                 let task_start = Instant::now();
@@ -1171,39 +1700,49 @@ pub(crate) fn run_sprint(
                     chat_path.as_str(),
                     agent_name,
                     &description,
-                    heartbeat::default_interval(),
+                    thread_heartbeat_interval,
+                    webhook.clone(),
                 );
-                let result = engine.execute(
+                // Mirror engine output into the agent log as it arrives
+                let result = engine.execute_streaming(
                     agent_name,
                     &description,
                     &working_dir,
                     session_sprint_number,
                     team_dir.as_deref(),
+                    &mut |line| {
+                        if let Err(e) = logger.log(&format!("> {}", line)) {
+                            warnings.warn("log", format!("warning: failed to write log: {}", e));
+                        }
+                    },
                 );
                 drop(heartbeat_guard);
+                drop(permit);
                 let task_duration = task_start.elapsed();
+                if let Some(usage) = result.usage {
+                    *agent_usage.get_or_insert_default() += usage;
+                }
 
                 // Log engine output for debugging (truncated if very long)
-                let output_preview = if result.output.len() > 500 {
-                    format!(
-                        "{}... [truncated, {} bytes total]",
-                        &result.output[..500],
-                        result.output.len()
-                    )
-                } else {
-                    result.output.clone()
-                };
+                let output_preview = truncate_for_log_bytes(&result.output, thread_log_bytes);
                 if !output_preview.is_empty() {
                     if let Err(e) = logger.log(&format!("Engine output:\n{}", output_preview)) {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
                 }
+                if !result.output.is_empty() {
+                    verbose!(
+                        "  {} output:\n{}",
+                        agent_name,
+                        verbosity::console_preview(&result.output, 500)
+                    );
+                }
                 if let Some(ref err) = result.error {
                     if let Err(e) = logger.log(&format!(
                         "Engine error: {} (exit code: {})",
                         err, result.exit_code
                     )) {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
                 }
 
@@ -1214,34 +1753,45 @@ pub(crate) fn run_sprint(
                         let mut t = tracker.lock().unwrap();
                         t.complete(initial);
                     }
+                    events::emit(
+                        "task_completed",
+                        &[
+                            ("agent", agent_name),
+                            ("initial", &agent::id(initial)),
+                            ("task", &description),
+                        ],
+                    );
                     if let Err(e) = logger.log("State: WORKING -> DONE (success)") {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
 
                     if let Err(e) = logger.log(&format!(
                         "Task completed: {} [engine: {}]",
                         description, engine_type_str
                     )) {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
 
                     if let Err(e) = chat::write_message(
                         &chat_path,
                         agent_name,
-                        &format!("Completed: {}", description),
+                        &completion_message(&description, &result),
                     ) {
-                        eprintln!("warning: failed to write chat: {}", e);
+                        warnings.warn("chat", format!("warning: failed to write chat: {}", e));
+                    }
+                    if let Some(webhook) = &webhook {
+                        webhook.notify(agent_name, "completed", &description);
                     }
 
                     // Commit the agent's work in their worktree (one commit per task)
                     if let Err(e) = logger.log("Committing changes...") {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
                     if let Err(e) = commit_agent_work(&working_dir, agent_name, &description) {
-                        eprintln!("warning: failed to commit: {}", e);
+                        warnings.warn("git", format!("warning: failed to commit: {}", e));
                     }
                     if let Err(e) = logger.log("Commit successful") {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
 
                     (true, None)
@@ -1253,25 +1803,48 @@ pub(crate) fn run_sprint(
                         let mut t = tracker.lock().unwrap();
                         t.fail(initial, &err);
                     }
+                    events::emit(
+                        "task_failed",
+                        &[
+                            ("agent", agent_name),
+                            ("initial", &agent::id(initial)),
+                            ("task", &description),
+                            ("error", &err),
+                        ],
+                    );
                     if let Err(e) = logger.log(&format!("State: WORKING -> DONE (failed: {})", err))
                     {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
 
-                    if let Err(e) = chat::write_message(
+                    if let Err(e) = chat::write_message_level(
                         &chat_path,
                         agent_name,
+                        chat::Level::Error,
                         &format!("Failed: {} - {}", description, err),
                     ) {
-                        eprintln!("warning: failed to write chat: {}", e);
+                        warnings.warn("chat", format!("warning: failed to write chat: {}", e));
+                    }
+                    if let Some(webhook) = &webhook {
+                        webhook.notify(agent_name, "failed", &description);
                     }
 
                     (false, Some(err))
                 };
 
                 if success {
+                    if let Some(gate) = merge_gate.as_ref() {
+                        if gate.has_prerequisites(&description) {
+                            if let Err(e) = logger.log("Waiting for prerequisite tasks to merge...")
+                            {
+                                warnings
+                                    .warn("log", format!("warning: failed to write log: {}", e));
+                            }
+                        }
+                        gate.wait_for_prerequisites(&description);
+                    }
                     if let Err(e) = logger.log("Merging agent branch into sprint branch...") {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
                     let mut merge_result = {
                         let _guard = worktree_lock.lock().unwrap();
@@ -1294,7 +1867,8 @@ pub(crate) fn run_sprint(
                                 "Missing branch {}. Recreating from HEAD {}...",
                                 expected_branch, head_short
                             )) {
-                                eprintln!("warning: failed to write log: {}", e);
+                                warnings
+                                    .warn("log", format!("warning: failed to write log: {}", e));
                             }
                             let recreate_result = {
                                 let _guard = worktree_lock.lock().unwrap();
@@ -1341,6 +1915,11 @@ pub(crate) fn run_sprint(
                             branch, head_short
                         ));
                     }
+                    // Kept for the conflict report if the merge agent can't resolve them
+                    let conflict_files = match &merge_result {
+                        worktree::MergeResult::Conflict(files) => files.clone(),
+                        _ => Vec::new(),
+                    };
 
                     if matches!(merge_result, worktree::MergeResult::Conflict(_))
                         && engine.engine_type() != EngineType::Stub
@@ -1358,22 +1937,30 @@ pub(crate) fn run_sprint(
                         let agent_branch = run_ctx.agent_branch(initial);
                         if let Err(e) = logger.log("Merge conflict detected; invoking merge agent")
                         {
-                            eprintln!("warning: failed to write log: {}", e);
+                            warnings.warn("log", format!("warning: failed to write log: {}", e));
                         }
                         let conflict_msg = format!(
                             "Merge conflict for {} detected. Invoking merge agent.",
                             agent_name
                         );
-                        if let Err(e) =
-                            chat::write_message(&chat_path, "ScrumMaster", &conflict_msg)
-                        {
-                            eprintln!("warning: failed to write chat: {}", e);
+                        if let Err(e) = chat::write_message_level(
+                            &chat_path,
+                            "ScrumMaster",
+                            chat::Level::Warn,
+                            &conflict_msg,
+                        ) {
+                            warnings.warn("chat", format!("warning: failed to write chat: {}", e));
                         }
 
+                        let merge_engine = engine::create_engine(
+                            engine.engine_type(),
+                            &log_dir,
+                            thread_merge_timeout,
+                        );
                         let merge_attempt = {
                             let _guard = worktree_lock.lock().unwrap();
                             merge_agent::run_merge_agent_in_worktree(
-                                engine.as_ref(),
+                                merge_engine.as_ref(),
                                 &agent_branch,
                                 &sprint_branch,
                                 &feature_worktree_path,
@@ -1382,33 +1969,32 @@ pub(crate) fn run_sprint(
 
                         match merge_attempt {
                             Ok(result) => {
-                                let output_preview = if result.output.len() > 500 {
-                                    format!(
-                                        "{}... [truncated, {} bytes total]",
-                                        &result.output[..500],
-                                        result.output.len()
-                                    )
-                                } else {
-                                    result.output.clone()
-                                };
+                                let output_preview =
+                                    truncate_for_log_bytes(&result.output, thread_log_bytes);
                                 if !output_preview.is_empty() {
                                     if let Err(e) = logger
                                         .log(&format!("Merge agent output:\n{}", output_preview))
                                     {
-                                        eprintln!("warning: failed to write log: {}", e);
+                                        warnings.warn(
+                                            "log",
+                                            format!("warning: failed to write log: {}", e),
+                                        );
                                     }
                                 }
                                 if let Some(err) = result.error.as_deref() {
                                     if let Err(e) =
                                         logger.log(&format!("Merge agent error: {}", err))
                                     {
-                                        eprintln!("warning: failed to write log: {}", e);
+                                        warnings.warn(
+                                            "log",
+                                            format!("warning: failed to write log: {}", e),
+                                        );
                                     }
                                 }
 
                                 if result.success {
                                     match merge_agent::ensure_feature_merged(
-                                        engine.as_ref(),
+                                        merge_engine.as_ref(),
                                         &agent_branch,
                                         &sprint_branch,
                                         &feature_worktree_path,
@@ -1418,7 +2004,10 @@ pub(crate) fn run_sprint(
                                             if let Err(e) =
                                                 logger.log("Merge agent resolved conflicts")
                                             {
-                                                eprintln!("warning: failed to write log: {}", e);
+                                                warnings.warn(
+                                                    "log",
+                                                    format!("warning: failed to write log: {}", e),
+                                                );
                                             }
                                             let resolved_msg = format!(
                                                 "Merge conflicts resolved for {}.",
@@ -1429,7 +2018,10 @@ pub(crate) fn run_sprint(
                                                 "ScrumMaster",
                                                 &resolved_msg,
                                             ) {
-                                                eprintln!("warning: failed to write chat: {}", e);
+                                                warnings.warn(
+                                                    "chat",
+                                                    format!("warning: failed to write chat: {}", e),
+                                                );
                                             }
                                         }
                                         Err(e) => {
@@ -1464,13 +2056,15 @@ pub(crate) fn run_sprint(
                     match merge_result {
                         worktree::MergeResult::Success => {
                             if let Err(e) = logger.log("Merge successful") {
-                                eprintln!("warning: failed to write log: {}", e);
+                                warnings
+                                    .warn("log", format!("warning: failed to write log: {}", e));
                             }
                             should_cleanup = true;
                         }
                         worktree::MergeResult::NoChanges => {
                             if let Err(e) = logger.log("Merge skipped: no changes detected") {
-                                eprintln!("warning: failed to write log: {}", e);
+                                warnings
+                                    .warn("log", format!("warning: failed to write log: {}", e));
                             }
                             should_cleanup = true;
                         }
@@ -1494,7 +2088,7 @@ pub(crate) fn run_sprint(
 
                     if should_cleanup {
                         if let Err(e) = logger.log("Cleaning up agent worktree after merge...") {
-                            eprintln!("warning: failed to write log: {}", e);
+                            warnings.warn("log", format!("warning: failed to write log: {}", e));
                         }
                         let cleanup_result = {
                             let _guard = worktree_lock.lock().unwrap();
@@ -1508,10 +2102,11 @@ pub(crate) fn run_sprint(
                         if let Err(e) = cleanup_result {
                             let msg = format!("Worktree cleanup failed: {}", e);
                             if let Err(e) = logger.log(&msg) {
-                                eprintln!("warning: failed to write log: {}", e);
+                                warnings
+                                    .warn("log", format!("warning: failed to write log: {}", e));
                             }
                         } else if let Err(e) = logger.log("Worktree cleanup complete") {
-                            eprintln!("warning: failed to write log: {}", e);
+                            warnings.warn("log", format!("warning: failed to write log: {}", e));
                         }
                     }
 
@@ -1527,10 +2122,10 @@ pub(crate) fn run_sprint(
 
                     if let Some(detail) = merge_error_detail.as_ref() {
                         if let Err(e) = logger.log(&format!("Merge failed: {}", detail)) {
-                            eprintln!("warning: failed to write log: {}", e);
+                            warnings.warn("log", format!("warning: failed to write log: {}", e));
                         }
                         if let Err(e) = write_merge_failure_chat(&chat_path, agent_name, detail) {
-                            eprintln!("warning: failed to write chat: {}", e);
+                            warnings.warn("chat", format!("warning: failed to write chat: {}", e));
                         }
                         let branch = run_ctx.agent_branch(initial);
                         let log_path = log::log_file_path(Path::new(&log_dir), initial)
@@ -1550,7 +2145,8 @@ pub(crate) fn run_sprint(
 
                         if let Some(err) = preserve_outcome.error.as_ref() {
                             if let Err(e) = logger.log(&format!("Preserve failed: {}", err)) {
-                                eprintln!("warning: failed to write log: {}", e);
+                                warnings
+                                    .warn("log", format!("warning: failed to write log: {}", e));
                             }
                         }
 
@@ -1573,12 +2169,15 @@ pub(crate) fn run_sprint(
                             )
                         };
                         if let Err(e) = logger.log(&preserve_msg) {
-                            eprintln!("warning: failed to write log: {}", e);
+                            warnings.warn("log", format!("warning: failed to write log: {}", e));
                         }
-                        if let Err(e) =
-                            chat::write_message(&chat_path, "ScrumMaster", &preserve_msg)
-                        {
-                            eprintln!("warning: failed to write chat: {}", e);
+                        if let Err(e) = chat::write_message_level(
+                            &chat_path,
+                            "ScrumMaster",
+                            chat::Level::Warn,
+                            &preserve_msg,
+                        ) {
+                            warnings.warn("chat", format!("warning: failed to write chat: {}", e));
                         }
                         if let Ok(mut failures) = merge_failures.lock() {
                             failures.push(MergeFailureInfo {
@@ -1588,6 +2187,7 @@ pub(crate) fn run_sprint(
                                 worktree_path: preserve_outcome.path.display().to_string(),
                                 log_path,
                                 detail: detail.clone(),
+                                files: conflict_files,
                                 skip_cleanup: preserve_outcome.error.is_some(),
                             });
                         }
@@ -1600,13 +2200,17 @@ pub(crate) fn run_sprint(
                     }
                 }
 
+                if let Some(gate) = merge_gate.as_ref() {
+                    gate.finish(&description);
+                }
+
                 // Transition: Done -> Terminated
                 {
                     let mut t = tracker.lock().unwrap();
                     t.terminate(initial);
                 }
                 if let Err(e) = logger.log("State: DONE -> TERMINATED") {
-                    eprintln!("warning: failed to write log: {}", e);
+                    warnings.warn("log", format!("warning: failed to write log: {}", e));
                 }
 
                 task_results.push((
@@ -1634,7 +2238,7 @@ pub(crate) fn run_sprint(
                         break;
                     }
                     if let Err(e) = logger.log("Recreating worktree for next task...") {
-                        eprintln!("warning: failed to write log: {}", e);
+                        warnings.warn("log", format!("warning: failed to write log: {}", e));
                     }
                     let recreate_assignments = vec![(initial, description.clone())];
                     let recreate_result = {
@@ -1654,12 +2258,18 @@ pub(crate) fn run_sprint(
                                     "Worktree recreated at {}",
                                     working_dir.display()
                                 )) {
-                                    eprintln!("warning: failed to write log: {}", e);
+                                    warnings.warn(
+                                        "log",
+                                        format!("warning: failed to write log: {}", e),
+                                    );
                                 }
                             } else {
                                 let msg = "worktree recreation returned no worktree".to_string();
                                 if let Err(e) = logger.log(&msg) {
-                                    eprintln!("warning: failed to write log: {}", e);
+                                    warnings.warn(
+                                        "log",
+                                        format!("warning: failed to write log: {}", e),
+                                    );
                                 }
                                 for remaining in tasks.iter().skip(task_index + 1) {
                                     task_results.push((
@@ -1676,7 +2286,8 @@ pub(crate) fn run_sprint(
                         Err(e) => {
                             let msg = format!("worktree recreation failed: {}", e);
                             if let Err(e) = logger.log(&msg) {
-                                eprintln!("warning: failed to write log: {}", e);
+                                warnings
+                                    .warn("log", format!("warning: failed to write log: {}", e));
                             }
                             for remaining in tasks.iter().skip(task_index + 1) {
                                 task_results.push((
@@ -1693,34 +2304,29 @@ pub(crate) fn run_sprint(
                 }
             }
 
-            task_results
+            (task_results, agent_usage)
         });
 
-        handles.push(handle);
+        handles.push((initial, handle));
     }
 
     // Wait for all agents to complete and collect results
     let mut results: Vec<TaskResult> = Vec::new();
-    let shutdown_in_progress = shutdown::requested();
-    let total_agents = handles.len();
-    if shutdown_in_progress {
-        println!(
-            "Waiting for {} agent(s) to finish current work...",
-            total_agents
-        );
-    }
-    for (idx, handle) in handles.into_iter().enumerate() {
-        if shutdown_in_progress && idx > 0 {
-            // Provide periodic status during shutdown
-            println!("  {} agent(s) remaining...", total_agents - idx);
-        }
-        match handle.join() {
-            Ok(agent_results) => results.extend(agent_results),
-            Err(_) => eprintln!("warning: agent thread panicked"),
+    let mut sprint_usage = team::SprintUsage::default();
+    let grace = Duration::from_secs(config.shutdown_grace_secs);
+    for (initial, outcome) in join_agents(handles, grace, &ShutdownSignal::global(), warnings) {
+        match outcome {
+            Ok((agent_results, agent_usage)) => {
+                results.extend(agent_results);
+                if let Some(usage) = agent_usage {
+                    sprint_usage.record(initial, usage);
+                }
+            }
+            Err(_) => warnings.warn("agent", "warning: agent thread panicked"),
         }
     }
-    if shutdown_in_progress {
-        println!("All agents finished. Cleaning up sprint...");
+    if shutdown::requested() {
+        info!("All agents finished. Cleaning up sprint...");
     }
 
     // Collect task durations for successful tasks
@@ -1749,7 +2355,7 @@ pub(crate) fn run_sprint(
     // Log lifecycle summary
     let tracker_guard = tracker.lock().unwrap();
     let (_, _, _, terminated) = tracker_guard.counts();
-    println!(
+    info!(
         "  {} Lifecycle: {} agents terminated ({} {}, {} {})",
         emoji::ROBOT,
         color::number(terminated),
@@ -1758,11 +2364,15 @@ pub(crate) fn run_sprint(
         color::failed(&tracker_guard.failure_count().to_string()),
         color::error("failed")
     );
+    let state_times = average_state_times(&tracker_guard);
+    let lifecycle_path = runtime_paths.lifecycle_path(historical_sprint);
+    if let Err(e) = write_lifecycle_report(&lifecycle_path, &tracker_guard) {
+        warnings.warn("lifecycle", format!("warning: {}", e));
+    }
     drop(tracker_guard);
 
     // Write final task state to worktree
-    fs::write(&worktree_tasks_path, task_list.to_string())
-        .map_err(|e| format!("failed to write {}: {}", worktree_tasks_path.display(), e))?;
+    task_list.save_sources()?;
 
     let merge_failures_snapshot = merge_failures
         .lock()
@@ -1773,42 +2383,68 @@ pub(crate) fn run_sprint(
 
     if !merge_failures_snapshot.is_empty() {
         if !skipped_initials.is_empty() {
-            println!(
+            info!(
                 "  Post-sprint cleanup: skipping {} agent worktree(s) due to merge failures",
                 skipped_initials.len()
             );
         }
         for failure in &merge_failures_snapshot {
-            println!(
+            info!(
                 "  Merge failure preserved: {} ({}) branch {} at {}",
                 failure.agent_name, failure.initial, failure.branch, failure.worktree_path
             );
-            println!(
+            info!(
                 "  Merge failure detail: {} (log: {})",
                 failure.detail, failure.log_path
             );
         }
     }
 
+    let conflicts_path = runtime_paths.conflicts_path();
+    if let Err(e) =
+        write_conflict_report(&conflicts_path, historical_sprint, &merge_failures_snapshot)
+    {
+        warnings.warn("conflicts", format!("warning: {}", e));
+    }
+
     // Clean up worktrees after sprint completes
     // This ensures worktrees are recreated fresh from the feature branch on the next sprint
     let cleanup_summary = worktree::cleanup_agent_worktrees(
         worktrees_dir,
         &cleanup_initials,
-        true, // Also delete branches
+        !config.agents_keep_branches, // Delete branches unless kept for audit
         &run_ctx,
     );
+    if config.agents_keep_branches {
+        for initial in &cleanup_summary.cleaned {
+            match worktree::archive_agent_branch(*initial, historical_sprint, &run_ctx) {
+                Ok(Some(archived)) => verbose!("  Kept agent branch: {}", archived),
+                Ok(None) => {}
+                Err(e) => warnings.warn(
+                    "cleanup",
+                    format!(
+                        "  warning: failed to keep branch for {}: {}",
+                        agent::name_from_initial(*initial).unwrap_or_else(|| "?".to_string()),
+                        e
+                    ),
+                ),
+            }
+        }
+    }
     if cleanup_summary.cleaned_count() > 0 {
-        println!(
+        info!(
             "  Post-sprint cleanup: removed {} worktree(s)",
             cleanup_summary.cleaned_count()
         );
     }
     for (initial, err) in &cleanup_summary.errors {
-        let name = agent::name_from_initial(*initial).unwrap_or("?");
-        eprintln!(
-            "  warning: post-sprint cleanup failed for {} ({}): {}",
-            name, initial, err
+        let name = agent::name_from_initial(*initial).unwrap_or_else(|| "?".to_string());
+        warnings.warn(
+            "cleanup",
+            format!(
+                "  warning: post-sprint cleanup failed for {} ({}): {}",
+                name, initial, err
+            ),
         );
     }
 
@@ -1816,14 +2452,14 @@ pub(crate) fn run_sprint(
     commit_sprint_completion(
         &feature_worktree_path,
         &sprint_branch,
-        worktree_tasks_path.to_str().unwrap_or(""),
+        &path_strs(&worktree_task_files)?,
         &formatted_team,
         historical_sprint,
     )?;
 
     // Run post-sprint review to identify follow-up tasks (skip if shutting down)
     if shutdown::requested() {
-        println!("  Skipping post-sprint review due to shutdown.");
+        info!("  Skipping post-sprint review due to shutdown.");
     } else {
         run_post_sprint_review(
             config,
@@ -1832,35 +2468,47 @@ pub(crate) fn run_sprint(
             &sprint_branch,
             &sprint_start_commit,
             &task_list,
+            &assignments,
             &formatted_team,
             historical_sprint,
-            &worktree_tasks_path,
+            &worktree_task_files,
+            warnings,
         )?;
     }
 
     // Reload task list to get latest counts (post-sprint review may have added tasks)
-    let final_content = fs::read_to_string(&worktree_tasks_path)
-        .map_err(|e| format!("failed to read {}: {}", worktree_tasks_path.display(), e))?;
-    let final_task_list = TaskList::parse(&final_content);
+    let final_task_list = TaskList::load_files(&worktree_task_files)?;
 
     // Persist final runtime-scoped planning tasks (including follow-up tasks if added).
     if runtime_paths.is_namespaced() {
-        persist_runtime_tasks_file(&worktree_tasks_path, &runtime_tasks_path)?;
+        persist_runtime_task_files(&worktree_task_files, &runtime_task_files)?;
     }
 
     let remaining_tasks = final_task_list.unassigned_count() + final_task_list.assigned_count();
     let total_tasks = final_task_list.tasks.len();
 
+    let sprint_range = team::SprintRange {
+        start_commit: sprint_start_commit.clone(),
+        end_commit: get_current_commit_in(&feature_worktree_path)
+            .unwrap_or_else(|| sprint_start_commit.clone()),
+        source_branch: source_branch.to_string(),
+        target_branch: target_branch.to_string(),
+    };
+    if let Err(e) = sprint_range.save(&runtime_paths.sprint_range_path(historical_sprint)) {
+        warnings.warn("state", format!("warning: {}", e));
+    }
+
     if let Err(e) = chat::write_sprint_status(
         &config.files_chat,
         &formatted_team,
         historical_sprint,
         completed_this_sprint,
         failed_this_sprint,
+        merge_failures_snapshot.len(),
         remaining_tasks,
         total_tasks,
     ) {
-        eprintln!("warning: failed to write chat: {}", e);
+        warnings.warn("chat", format!("warning: failed to write chat: {}", e));
     }
 
     // Print team status banner
@@ -1869,20 +2517,64 @@ pub(crate) fn run_sprint(
         historical_sprint,
         completed_this_sprint,
         failed_this_sprint,
+        merge_failures_snapshot.len(),
         remaining_tasks,
         total_tasks,
         &task_durations,
+        state_times,
+        sprint_usage.total(),
         config.sprints_max,
         agent_count,
     );
+    if sprint_usage.total().is_some() && !team_name.trim().is_empty() {
+        let recorded = team::TeamUsage::load(&team_name).and_then(|mut usage| {
+            usage.record_sprint(historical_sprint, &sprint_usage);
+            usage.save()
+        });
+        if let Err(e) = recorded {
+            warnings.warn(
+                "usage",
+                format!("warning: failed to record token usage: {}", e),
+            );
+        }
+    }
+    for line in sprint_range_lines(&feature_worktree_path, &sprint_range) {
+        println!("{}", line);
+    }
+    let (completed_label, failed_label) = (
+        completed_this_sprint.to_string(),
+        failed_this_sprint.to_string(),
+    );
+    audit::event(
+        "sprint_end",
+        &[
+            ("team", &team_name),
+            ("sprint", &sprint_label),
+            ("completed", &completed_label),
+            ("failed", &failed_label),
+            ("merge_failures", &merge_failures_snapshot.len().to_string()),
+            ("remaining", &remaining_tasks.to_string()),
+        ],
+    );
+    events::emit(
+        "sprint_completed",
+        &[
+            ("team", &team_name),
+            ("sprint", &sprint_label),
+            ("completed", &completed_label),
+            ("failed", &failed_label),
+        ],
+    );
 
     let mut sprint_state_committed = false;
+    let mut pr_url = None;
+    let mut merge_status = "skipped";
 
     // Merge sprint branch into target branch via merge agent.
     if shutdown::requested() {
-        println!("  Skipping merge agent due to shutdown.");
+        info!("  Skipping merge agent due to shutdown.");
     } else if sprint_branch == target_branch {
-        println!("  Skipping merge agent: feature branch matches target branch.");
+        info!("  Skipping merge agent: feature branch matches target branch.");
         sprint_state_committed = true;
     } else {
         let merge_logger = NamedLogger::new(
@@ -1890,191 +2582,163 @@ pub(crate) fn run_sprint(
             "MergeAgent",
             "merge-agent.log",
         );
-        println!(
-            "  Merge agent: starting ({} -> {})",
-            sprint_branch, target_branch
+        if config.sprints_fetch_before_merge {
+            let fetch_msg = match fetch_and_fast_forward_target(&repo_root, target_branch) {
+                Ok(FetchTargetResult::UpToDate) => {
+                    format!("Fetch: {} is up to date with origin", target_branch)
+                }
+                Ok(FetchTargetResult::FastForwarded { from, to }) => format!(
+                    "Fetch: fast-forwarded {} {}..{} from origin",
+                    target_branch, from, to
+                ),
+                Err(e) => {
+                    let _ = merge_logger.log(&format!("Fetch before merge failed: {}", e));
+                    return Err(format!("fetch before merge failed: {}", e));
+                }
+            };
+            info!("  {}", fetch_msg);
+            if let Err(e) = merge_logger.log(&fetch_msg) {
+                warnings.warn(
+                    "log",
+                    format!("  warning: failed to write merge log: {}", e),
+                );
+            }
+        }
+        let fast_path = fast_path_merge_eligible(
+            config.sprints_fast_path_merge,
+            assigned_initials.len(),
+            merge_failures_snapshot.len(),
+            &repo_root,
+            &sprint_branch,
+            target_branch,
         );
-        let merge_msg = format!(
-            "Merge agent: starting ({} -> {})",
-            sprint_branch, target_branch
+        let merge_fields = [
+            ("source", sprint_branch.as_str()),
+            ("target", target_branch),
+            ("mode", if fast_path { "fast_path" } else { "agent" }),
+        ];
+        events::emit("merge_started", &merge_fields);
+        let merge_engine = engine::create_engine(
+            config.effective_engine(),
+            &config.files_log_dir,
+            config.merge_timeout_secs,
         );
-        if let Err(e) = chat::write_message(&config.files_chat, "ScrumMaster", &merge_msg) {
-            eprintln!("  warning: failed to write merge start to chat: {}", e);
-        }
-        if let Err(e) = merge_logger.log(&format!(
-            "Starting merge: {} -> {}",
-            sprint_branch, target_branch
-        )) {
-            eprintln!("  warning: failed to write merge log: {}", e);
-        }
-        let merge_engine = engine.engine_type().as_str();
-        if let Err(e) = merge_logger.log(&format!("Engine: {}", merge_engine)) {
-            eprintln!("  warning: failed to write merge log: {}", e);
-        }
-        let merge_cleanup_paths = vec![worktree_tasks_path.clone()];
-        if let Err(e) =
-            merge_agent::prepare_merge_workspace(&feature_worktree_path, &merge_cleanup_paths)
-        {
-            let _ = merge_logger.log(&format!("Prepare workspace failed: {}", e));
-            return Err(format!("merge agent failed: {}", e));
-        }
-        if let Err(e) = merge_logger.log("Workspace prepared") {
-            eprintln!("  warning: failed to write merge log: {}", e);
-        }
-        let merge_result = merge_agent::run_merge_agent(
-            engine.as_ref(),
+        let merge_result = merge_sprint_into_target(
+            config,
+            merge_engine.as_ref(),
+            fast_path,
             &sprint_branch,
             target_branch,
             &feature_worktree_path,
-        )
-        .map_err(|e| {
-            let _ = merge_logger.log(&format!("Merge agent execution failed: {}", e));
-            format!("merge agent failed: {}", e)
-        })?;
-        if !merge_result.output.is_empty() {
-            let output_preview = if merge_result.output.len() > 1000 {
-                format!(
-                    "{}... [truncated, {} bytes total]",
-                    &merge_result.output[..1000],
-                    merge_result.output.len()
-                )
-            } else {
-                merge_result.output.clone()
-            };
-            if let Err(e) = merge_logger.log(&format!("Engine output:\n{}", output_preview)) {
-                eprintln!("  warning: failed to write merge log: {}", e);
+            &worktree_task_files,
+            &merge_logger,
+            warnings,
+        );
+        match merge_result {
+            Ok(()) => {
+                audit::event("merge", &merge_fields);
+                events::emit("merge_completed", &merge_fields);
             }
-        }
-        if let Err(e) = merge_logger.log(&format!(
-            "Engine result: {} (exit_code={})",
-            if merge_result.success {
-                "success"
-            } else {
-                "failure"
-            },
-            merge_result.exit_code
-        )) {
-            eprintln!("  warning: failed to write merge log: {}", e);
-        }
-        if let Some(err) = merge_result.error.as_deref() {
-            if let Err(e) = merge_logger.log(&format!("Engine error: {}", err)) {
-                eprintln!("  warning: failed to write merge log: {}", e);
+            Err(ref e) => {
+                let [source, target, mode] = merge_fields;
+                let fields = [source, target, mode, ("error", e.as_str())];
+                audit::failure("merge_failed", &fields);
+                events::emit("merge_failed", &fields);
             }
         }
-        if merge_result.success {
-            if let Err(e) = merge_agent::run_merge_agent_with_retry(
-                engine.as_ref(),
-                &sprint_branch,
-                target_branch,
-                &feature_worktree_path,
-            ) {
-                let _ = merge_logger.log(&format!("Merge verification failed (with retry): {}", e));
-                return Err(format!("merge agent failed: {}", e));
-            }
-            println!("  Merge agent: completed");
+        merge_result?;
+        merge_status = "merged";
+
+        let mut push_succeeded = false;
+        let skip_reason = push_skip_reason(
+            config.target_branch_explicit,
+            &sprint_branch,
+            target_branch,
+            shutdown::requested(),
+        );
+        if let Some(reason) = skip_reason {
+            let push_msg = format!("Push: skipped ({})", reason);
+            info!("  {}", push_msg);
+            let _ = merge_logger.log(&push_msg);
             if let Err(e) =
-                chat::write_message(&config.files_chat, "ScrumMaster", "Merge agent: completed")
+                write_push_outcome_chat(&config.files_chat, chat::Level::Warn, &push_msg)
             {
-                eprintln!("  warning: failed to write merge complete to chat: {}", e);
-            }
-            if let Err(e) = merge_logger.log("Merge completed") {
-                eprintln!("  warning: failed to write merge log: {}", e);
+                warnings.warn(
+                    "chat",
+                    format!("  warning: failed to write push status to chat: {}", e),
+                );
             }
-            let merged = worktree::branch_is_merged(&sprint_branch, target_branch)
-                .map_err(|e| format!("merge verification failed: {}", e))?;
-            let mut merged_ok = merged;
-            if !merged {
-                if engine.engine_type() == EngineType::Stub {
-                    let merge_result =
-                        worktree::merge_feature_branch(&sprint_branch, target_branch);
-                    match merge_result {
-                        worktree::MergeResult::Success | worktree::MergeResult::NoChanges => {
-                            println!("  Merge agent: merged feature branch (stub)");
-                            merged_ok = true;
-                        }
-                        worktree::MergeResult::NoBranch => {
-                            let _ = merge_logger.log("Stub merge failed: feature branch not found");
-                            return Err(format!(
-                                "merge agent failed: feature branch '{}' not found",
-                                sprint_branch
-                            ));
-                        }
-                        worktree::MergeResult::Conflict(files) => {
-                            let detail = if files.is_empty() {
-                                "conflicts detected".to_string()
-                            } else {
-                                format!("conflicts in {}", files.join(", "))
-                            };
-                            let _ = merge_logger.log(&format!("Stub merge conflict: {}", detail));
-                            return Err(format!("merge agent failed: {}", detail));
-                        }
-                        worktree::MergeResult::Error(e) => {
-                            let _ = merge_logger.log(&format!("Stub merge error: {}", e));
-                            return Err(format!("merge agent failed: {}", e));
-                        }
-                    }
-                } else {
-                    let _ = merge_logger.log("Merge agent did not merge feature into target");
-                    return Err(format!(
-                        "merge agent did not merge '{}' into '{}'",
-                        sprint_branch, target_branch
-                    ));
+        } else if should_push_target_branch(
+            config.target_branch_explicit,
+            &sprint_branch,
+            target_branch,
+            shutdown::requested(),
+        ) {
+            let push_result = push_branch_to_remote(&repo_root, target_branch);
+            if push_result.success {
+                push_succeeded = true;
+                let push_msg = format!("Push: pushed '{}' to origin", target_branch);
+                info!("  {}", push_msg);
+                let _ = merge_logger.log(&format!("Push succeeded: {}", target_branch));
+                audit::event("push", &[("target", target_branch), ("remote", "origin")]);
+                events::emit("push_completed", &[("target", target_branch)]);
+                if let Err(e) =
+                    write_push_outcome_chat(&config.files_chat, chat::Level::Info, &push_msg)
+                {
+                    warnings.warn(
+                        "chat",
+                        format!("  warning: failed to write push status to chat: {}", e),
+                    );
                 }
-            }
-
-            if merged_ok {
-                let mut push_succeeded = false;
-                let skip_reason = push_skip_reason(
-                    config.target_branch_explicit,
-                    &sprint_branch,
-                    target_branch,
-                    shutdown::requested(),
+            } else {
+                warnings.warn(
+                    "push",
+                    format!(
+                        "  warning: failed to push '{}' to origin (continuing)",
+                        target_branch
+                    ),
                 );
-                if let Some(reason) = skip_reason {
-                    let push_msg = format!("Push: skipped ({})", reason);
-                    println!("  {}", push_msg);
-                    let _ = merge_logger.log(&push_msg);
-                    if let Err(e) = write_push_outcome_chat(&config.files_chat, &push_msg) {
-                        eprintln!("  warning: failed to write push status to chat: {}", e);
-                    }
-                } else if should_push_target_branch(
-                    config.target_branch_explicit,
-                    &sprint_branch,
-                    target_branch,
-                    shutdown::requested(),
-                ) {
-                    let push_result = push_branch_to_remote(&repo_root, target_branch);
-                    if push_result.success {
-                        push_succeeded = true;
-                        let push_msg = format!("Push: pushed '{}' to origin", target_branch);
-                        println!("  {}", push_msg);
-                        let _ = merge_logger.log(&format!("Push succeeded: {}", target_branch));
-                        if let Err(e) = write_push_outcome_chat(&config.files_chat, &push_msg) {
-                            eprintln!("  warning: failed to write push status to chat: {}", e);
-                        }
-                    } else {
-                        eprintln!(
-                            "  warning: failed to push '{}' to origin (continuing)",
-                            target_branch
-                        );
-                        let push_msg = format!(
-                            "Push: failed to push '{}' to origin (continuing)",
-                            target_branch
-                        );
-                        let error = push_result.error.as_deref().unwrap_or("unknown error");
-                        let stdout = push_result.stdout.trim();
-                        let stderr = push_result.stderr.trim();
-                        let _ = merge_logger.log(&format!(
-                            "Push failed for '{}': error='{}' exit_code={:?} stdout='{}' stderr='{}'",
-                            target_branch, error, push_result.exit_code, stdout, stderr
-                        ));
-                        if let Err(e) = write_push_outcome_chat(&config.files_chat, &push_msg) {
-                            eprintln!("  warning: failed to write push status to chat: {}", e);
-                        }
-                    }
+                let push_msg = format!(
+                    "Push: failed to push '{}' to origin (continuing)",
+                    target_branch
+                );
+                let error = push_result.error.as_deref().unwrap_or("unknown error");
+                audit::failure(
+                    "push_failed",
+                    &[
+                        ("target", target_branch),
+                        ("remote", "origin"),
+                        ("error", error),
+                    ],
+                );
+                events::emit(
+                    "push_failed",
+                    &[("target", target_branch), ("error", error)],
+                );
+                let stdout = push_result.stdout.trim();
+                let stderr = push_result.stderr.trim();
+                let _ = merge_logger.log(&format!(
+                    "Push failed for '{}': error='{}' exit_code={:?} stdout='{}' stderr='{}'",
+                    target_branch, error, push_result.exit_code, stdout, stderr
+                ));
+                if let Err(e) =
+                    write_push_outcome_chat(&config.files_chat, chat::Level::Error, &push_msg)
+                {
+                    warnings.warn(
+                        "chat",
+                        format!("  warning: failed to write push status to chat: {}", e),
+                    );
                 }
+            }
+        }
 
-                if push_succeeded {
+        if push_succeeded {
+            pr_url = open_pull_request_if_enabled(
+                config.sprints_create_pr,
+                &merge_logger,
+                &config.files_chat,
+                warnings,
+                || {
                     let pr_team_dir = engine_team_dir(&team_name, &config.files_tasks);
                     let (pr_title, pr_body) = generate_pr_title_and_body(
                         engine.as_ref(),
@@ -2086,58 +2750,661 @@ pub(crate) fn run_sprint(
                         target_branch,
                         &merge_logger,
                     );
+                    let pr_body = with_base_commit_line(&pr_body, run_ctx.base_sha());
                     let _ = merge_logger.log(&format!(
                         "PR metadata prepared: title='{}' body_chars={}",
                         pr_title,
                         pr_body.len()
                     ));
-                    let pr_result =
-                        create_pull_request(&pr_title, &pr_body, source_branch, target_branch);
-                    report_pull_request_creation(pr_result, &merge_logger, &config.files_chat);
+                    let forge = config
+                        .sprints_forge
+                        .unwrap_or_else(|| detect_forge(&repo_root));
+                    create_pull_request(forge, &pr_title, &pr_body, source_branch, target_branch)
+                },
+            );
+        }
+
+        if let Err(e) = worktree::cleanup_feature_worktree(worktrees_dir, &sprint_branch, true) {
+            warnings.warn(
+                "cleanup",
+                format!("  warning: feature worktree cleanup failed: {}", e),
+            );
+            let _ = merge_logger.log(&format!("Feature cleanup failed: {}", e));
+        } else {
+            info!("  Feature cleanup: removed '{}'", sprint_branch);
+            let _ = merge_logger.log(&format!("Feature cleanup: removed '{}'", sprint_branch));
+        }
+        sprint_state_committed = true;
+    }
+
+    if sprint_state_committed {
+        finalize_runtime_state_after_sprint(
+            &runtime_history_path,
+            &runtime_state_path,
+            &team_name,
+            sprint_range,
+        )?;
+    }
+
+    if let Some(url) = config.sprint_webhook_url.as_deref() {
+        let summary = webhook::SprintSummary {
+            team: &team_name,
+            sprint: historical_sprint,
+            tasks_assigned: assigned,
+            tasks_completed: completed_this_sprint,
+            tasks_failed: failed_this_sprint,
+            merge_failures: merge_failures_snapshot.len(),
+            merge_status,
+            pr_url: pr_url.as_deref(),
+        };
+        if let Err(e) = webhook::post(url, &webhook::format_sprint_payload(&summary)) {
+            warnings.warn(
+                "webhook",
+                format!("  warning: sprint webhook {} failed: {}", url, e),
+            );
+        }
+    }
+    if let Some(url) = config.slack_webhook_url.as_deref() {
+        if let Err(e) = slack::notify_sprint_completed(
+            url,
+            &team_name,
+            historical_sprint,
+            completed_this_sprint,
+            failed_this_sprint,
+            pr_url.as_deref(),
+        ) {
+            warnings.warn(
+                "slack",
+                format!("  warning: failed to post sprint summary to Slack: {}", e),
+            );
+        }
+    }
+
+    Ok(SprintResult {
+        tasks_assigned: assigned,
+        tasks_completed: completed_this_sprint,
+        tasks_failed: failed_this_sprint,
+        merge_failures: merge_failures_snapshot.len(),
+    })
+}
+
+/// Unassign leftover tasks and assign this sprint's work, by LLM planning with
+/// an algorithmic fallback.
+///
+/// Planning is announced in `chat_path` unless it is `None` (a dry run).
+/// Returns the number of tasks assigned and agents considered, or `None` when
+/// nothing is assignable or no agents are available.
+fn plan_sprint_assignments(
+    config: &Config,
+    engine: &dyn engine::Engine,
+    task_list: &mut TaskList,
+    team_name: &str,
+    own_pids: &Path,
+    chat_path: Option<&str>,
+    warnings: &WarningCollector,
+) -> Result<Option<(usize, usize)>, String> {
+    // Unassign any incomplete tasks from previous sprints so they can be reassigned fresh.
+    // Keep this in-memory to avoid dirtying the target branch worktree.
+    if config.sprints_track_interrupted {
+        task_list.mark_interrupted_all();
+    } else {
+        task_list.unassign_all();
+    }
+
+    // Determine how many agents to spawn
+    let assignable = task_list.assignable_count();
+    if assignable == 0 {
+        return Ok(None);
+    }
+
+    let tasks_per_agent = config.agents_tasks_per_agent;
+    let tier_limits = &config.agents_tasks_per_priority;
+    let agents_needed = agents_needed_for(
+        task_list,
+        tasks_per_agent,
+        tier_limits,
+        config.agents_points_per_agent,
+    );
+    let agent_cap = agents_needed.min(config.agents_max_count);
+    // Agents still running an engine for another run of this project are busy.
+    let initials = match wait_for_available_agents(
+        config.agents_on_no_agents,
+        Duration::from_secs(config.agents_no_agents_wait_secs),
+        NO_AGENTS_POLL_INTERVAL,
+        || available_agents(agent_cap, &busy_agents(team_name, own_pids)),
+    )? {
+        Some(initials) => initials,
+        None => {
+            info!("No agents available.");
+            return Ok(None);
+        }
+    };
+
+    // Assign tasks via LLM planning (with fallback to algorithmic)
+    let log_dir = Path::new(&config.files_log_dir);
+
+    if let Some(chat_path) = chat_path {
+        if let Err(e) = chat::write_message(chat_path, "ScrumMaster", "Sprint planning started") {
+            warnings.warn("chat", format!("warning: failed to write chat: {}", e));
+        }
+    }
+
+    let plan_result =
+        planning::run_llm_assignment(engine, task_list, &initials, tasks_per_agent, log_dir);
+
+    let assigned = if !plan_result.success {
+        warnings.warn(
+            "planning",
+            format!(
+                "LLM planning failed: {}, falling back to algorithmic assignment",
+                plan_result.error.unwrap_or_default()
+            ),
+        );
+        task_list.assign_sprint_with_tiers(&initials, tasks_per_agent, tier_limits)
+    } else {
+        // Apply LLM assignments (line numbers are 1-indexed in the response)
+        let mut count = 0;
+        for (line_num, initial) in &plan_result.assignments {
+            // Convert line number to task index (0-indexed)
+            let task_idx = line_num.saturating_sub(1);
+            if task_idx < task_list.tasks.len() {
+                task_list.tasks[task_idx].assign(*initial);
+                count += 1;
+            }
+        }
+        if !tier_limits.is_empty() {
+            count -= task_list.enforce_tier_limits(tasks_per_agent, tier_limits);
+        }
+        count
+    };
+    Ok(Some((assigned, initials.len())))
+}
+
+/// Tasks and distinct agents already assigned in a resumed sprint's task list.
+fn resumed_assignment_counts(task_list: &TaskList) -> (usize, usize) {
+    let mut initials = Vec::new();
+    for task in &task_list.tasks {
+        if let swarm::task::TaskStatus::Assigned(initial) = task.status {
+            initials.push(initial);
+        }
+    }
+    let assigned = initials.len();
+    initials.sort_unstable();
+    initials.dedup();
+    (assigned, initials.len())
+}
+
+/// An interrupted sprint that `swarm resume` can pick back up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ResumePoint {
+    sprint_branch: String,
+    run_hash: String,
+}
+
+/// Find an interrupted sprint in the runtime namespace: team state still names
+/// its sprint branch, the runtime tasks file still has assigned tasks, and
+/// `run.json` records the run hash that created the branch.
+fn find_resume_point(
+    repo_root: &Path,
+    runtime_paths: &team::RuntimeStatePaths,
+) -> Result<Option<ResumePoint>, String> {
+    let state_path = repo_root.join(runtime_paths.team_state_path());
+    if !state_path.exists() {
+        return Ok(None);
+    }
+    let Some(sprint_branch) = team::TeamState::load_from(&state_path)?.feature_branch else {
+        return Ok(None);
+    };
+    let Ok(tasks) = fs::read_to_string(repo_root.join(runtime_paths.tasks_path())) else {
+        return Ok(None);
+    };
+    if resumed_assignment_counts(&TaskList::parse(&tasks)).0 == 0 {
+        return Ok(None);
+    }
+    let Ok(run_json) = fs::read_to_string(repo_root.join(runtime_paths.run_path())) else {
+        return Ok(None);
+    };
+    if json_string_field(&run_json, "sprint_branch").as_deref() != Some(&sprint_branch) {
+        return Ok(None);
+    }
+    let Some(run_hash) = json_string_field(&run_json, "run_hash") else {
+        return Ok(None);
+    };
+    if ensure_branch_exists(repo_root, &sprint_branch).is_err() {
+        return Ok(None);
+    }
+    Ok(Some(ResumePoint {
+        sprint_branch,
+        run_hash,
+    }))
+}
+
+/// One agent's share of a [`DryRunPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DryRunAgent {
+    pub(crate) initial: char,
+    pub(crate) branch: String,
+    pub(crate) worktree: PathBuf,
+    pub(crate) tasks: Vec<String>,
+}
+
+/// What the next sprint would do, for `swarm run --dry-run`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DryRunPlan {
+    pub(crate) team: String,
+    pub(crate) sprint: usize,
+    pub(crate) sprint_branch: String,
+    pub(crate) sprint_worktree: PathBuf,
+    pub(crate) agents: Vec<DryRunAgent>,
+}
+
+/// Plan the next sprint without side effects: tasks are read from the target
+/// branch and assigned by the same planning as a real sprint, with the stub
+/// engine standing in for the LLM, and nothing is written to git, chat, or
+/// `.swarm-hug/`.
+pub(crate) fn plan_dry_run(config: &Config, run_instance: &str) -> Result<DryRunPlan, String> {
+    let team_name = project_name_for_config(config);
+    let source_branch = config
+        .source_branch
+        .as_deref()
+        .ok_or_else(|| "source branch not configured".to_string())?;
+    let target_branch = config
+        .target_branch
+        .as_deref()
+        .ok_or_else(|| "target branch not configured".to_string())?;
+    let repo_root = git_repo_root()?;
+    ensure_branch_exists(&repo_root, source_branch)?;
+    let runtime_paths =
+        team::RuntimeStatePaths::for_branches(&team_name, source_branch, target_branch);
+
+    let mut files = vec![(
+        runtime_paths.tasks_path(),
+        read_target_tasks(&repo_root, target_branch, config, &runtime_paths)?,
+    )];
+    for extra in config.files_tasks_extra.iter().map(PathBuf::from) {
+        let candidates = [runtime_paths.root().join(&extra), extra.clone()];
+        files.push((
+            extra,
+            read_target_file(&repo_root, target_branch, &candidates)?,
+        ));
+    }
+    let mut task_list = TaskList::parse_sources(&files);
+    task_list.completion_style = config.files_tasks_completion_style;
+
+    // A new run resets the runtime namespace, so its sprint history starts empty.
+    let sprint = if runtime_paths.is_namespaced() {
+        1
+    } else {
+        team::SprintHistory::load_from(&repo_root.join(runtime_paths.sprint_history_path()))?
+            .peek_next_sprint()
+    };
+
+    let stub = engine::create_engine(
+        EngineType::Stub,
+        &config.files_log_dir,
+        config.planning_timeout_secs,
+    );
+    plan_sprint_assignments(
+        config,
+        stub.as_ref(),
+        &mut task_list,
+        &team_name,
+        &runtime_paths.pids_path(),
+        None,
+        &WarningCollector::new(),
+    )?;
+    let mut assigned: BTreeMap<char, Vec<String>> = BTreeMap::new();
+    for task in &task_list.tasks {
+        if let swarm::task::TaskStatus::Assigned(initial) = task.status {
+            assigned
+                .entry(initial)
+                .or_default()
+                .push(task.description.clone());
+        }
+    }
+
+    let run_ctx = RunContext::new_for_run_with_hash_len(
+        &team_name,
+        target_branch,
+        run_instance,
+        sprint as u32,
+        config.run_hash_len,
+    )
+    .with_branch_prefix(&config.branch_prefix);
+    let run_ctx = match config.run_id.as_deref() {
+        Some(run_id) => run_ctx.with_run_id(run_id),
+        None => run_ctx,
+    };
+
+    let worktrees_dir = Path::new(&config.files_worktrees_dir);
+    let agents = assigned
+        .into_iter()
+        .map(|(initial, tasks)| {
+            let branch = run_ctx.agent_branch(initial);
+            DryRunAgent {
+                initial,
+                worktree: worktrees_dir.join(&branch),
+                branch,
+                tasks,
+            }
+        })
+        .collect();
+
+    let sprint_branch = run_ctx.sprint_branch();
+    Ok(DryRunPlan {
+        team: team_name,
+        sprint,
+        sprint_worktree: worktrees_dir.join(&sprint_branch),
+        sprint_branch,
+        agents,
+    })
+}
+
+/// Read the tasks file a new run would bootstrap from, without creating the
+/// target branch worktree that `sync_target_branch_state` uses.
+fn read_target_tasks(
+    repo_root: &Path,
+    target_branch: &str,
+    config: &Config,
+    runtime_paths: &team::RuntimeStatePaths,
+) -> Result<String, String> {
+    let mut candidates = vec![runtime_paths.branch_tasks_path()];
+    let configured = PathBuf::from(&config.files_tasks);
+    if configured.is_relative() {
+        candidates.push(configured);
+    }
+    read_target_file(repo_root, target_branch, &candidates)
+}
+
+/// Read the first of `candidates` present on the target branch, from its
+/// checkout when it has one, otherwise from git.
+fn read_target_file(
+    repo_root: &Path,
+    target_branch: &str,
+    candidates: &[PathBuf],
+) -> Result<String, String> {
+    let checked_out = branch_is_checked_out(repo_root, target_branch)?;
+    for rel in candidates {
+        let content = if checked_out {
+            fs::read_to_string(repo_root.join(rel)).ok()
+        } else {
+            let spec = format!(
+                "{}:{}",
+                target_branch,
+                rel.to_string_lossy().replace('\\', "/")
+            );
+            process::Command::new("git")
+                .arg("-C")
+                .arg(repo_root)
+                .args(["show", &spec])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+        if let Some(content) = content {
+            return Ok(content);
+        }
+    }
+    Err(format!(
+        "no tasks file found on {} (looked for {})",
+        target_branch,
+        candidates
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+/// Whether a sprint can merge into the target without the LLM merge agent.
+///
+/// Requires `sprints.fast_path_merge`, a single agent whose work merged cleanly
+/// into the sprint branch, and a target that is an ancestor of the sprint
+/// branch, so the merge cannot conflict.
+fn fast_path_merge_eligible(
+    enabled: bool,
+    agents_run: usize,
+    merge_failures: usize,
+    repo_root: &Path,
+    sprint_branch: &str,
+    target_branch: &str,
+) -> bool {
+    enabled
+        && agents_run == 1
+        && merge_failures == 0
+        && is_ancestor_in(repo_root, target_branch, sprint_branch).unwrap_or(false)
+}
+
+/// Merge the sprint branch into the target, via the merge agent unless `fast_path`.
+#[allow(clippy::too_many_arguments)]
+fn merge_sprint_into_target(
+    config: &Config,
+    engine: &dyn engine::Engine,
+    fast_path: bool,
+    sprint_branch: &str,
+    target_branch: &str,
+    feature_worktree_path: &Path,
+    worktree_task_files: &[PathBuf],
+    merge_logger: &NamedLogger,
+    warnings: &WarningCollector,
+) -> Result<(), String> {
+    if fast_path {
+        return fast_path_merge(
+            &config.files_chat,
+            sprint_branch,
+            target_branch,
+            merge_logger,
+            warnings,
+        );
+    }
+
+    info!(
+        "  Merge agent: starting ({} -> {})",
+        sprint_branch, target_branch
+    );
+    let merge_msg = format!(
+        "Merge agent: starting ({} -> {})",
+        sprint_branch, target_branch
+    );
+    if let Err(e) = chat::write_message(&config.files_chat, "ScrumMaster", &merge_msg) {
+        warnings.warn(
+            "chat",
+            format!("  warning: failed to write merge start to chat: {}", e),
+        );
+    }
+    if let Err(e) = merge_logger.log(&format!(
+        "Starting merge: {} -> {}",
+        sprint_branch, target_branch
+    )) {
+        warnings.warn(
+            "log",
+            format!("  warning: failed to write merge log: {}", e),
+        );
+    }
+    let merge_engine = engine.engine_type().as_str();
+    if let Err(e) = merge_logger.log(&format!("Engine: {}", merge_engine)) {
+        warnings.warn(
+            "log",
+            format!("  warning: failed to write merge log: {}", e),
+        );
+    }
+    let merge_cleanup_paths = worktree_task_files.to_vec();
+    if let Err(e) =
+        merge_agent::prepare_merge_workspace(feature_worktree_path, &merge_cleanup_paths)
+    {
+        let _ = merge_logger.log(&format!("Prepare workspace failed: {}", e));
+        return Err(format!("merge agent failed: {}", e));
+    }
+    if let Err(e) = merge_logger.log("Workspace prepared") {
+        warnings.warn(
+            "log",
+            format!("  warning: failed to write merge log: {}", e),
+        );
+    }
+    let merge_result =
+        merge_agent::run_merge_agent(engine, sprint_branch, target_branch, feature_worktree_path)
+            .map_err(|e| {
+            let _ = merge_logger.log(&format!("Merge agent execution failed: {}", e));
+            format!("merge agent failed: {}", e)
+        })?;
+    if !merge_result.output.is_empty() {
+        let output_preview = truncate_for_log_bytes(
+            &merge_result.output,
+            config
+                .files_log_output_max_bytes
+                .unwrap_or(MERGE_OUTPUT_LOG_BYTES),
+        );
+        if let Err(e) = merge_logger.log(&format!("Engine output:\n{}", output_preview)) {
+            warnings.warn(
+                "log",
+                format!("  warning: failed to write merge log: {}", e),
+            );
+        }
+    }
+    if let Err(e) = merge_logger.log(&format!(
+        "Engine result: {} (exit_code={})",
+        if merge_result.success {
+            "success"
+        } else {
+            "failure"
+        },
+        merge_result.exit_code
+    )) {
+        warnings.warn(
+            "log",
+            format!("  warning: failed to write merge log: {}", e),
+        );
+    }
+    if let Some(err) = merge_result.error.as_deref() {
+        if let Err(e) = merge_logger.log(&format!("Engine error: {}", err)) {
+            warnings.warn(
+                "log",
+                format!("  warning: failed to write merge log: {}", e),
+            );
+        }
+    }
+    if !merge_result.success {
+        let detail = merge_result
+            .error
+            .unwrap_or_else(|| "unknown error".to_string());
+        println!("  Merge agent: failed");
+        if let Err(e) = chat::write_message_level(
+            &config.files_chat,
+            "ScrumMaster",
+            chat::Level::Error,
+            &format!("Merge agent: failed ({})", detail),
+        ) {
+            warnings.warn(
+                "chat",
+                format!("  warning: failed to write merge failure to chat: {}", e),
+            );
+        }
+        let _ = merge_logger.log(&format!("Merge failed: {}", detail));
+        return Err(format!("merge agent failed: {}", detail));
+    }
+    if let Err(e) = merge_agent::run_merge_agent_with_retry(
+        engine,
+        sprint_branch,
+        target_branch,
+        feature_worktree_path,
+        &merge_cleanup_paths,
+        config.merge_retries,
+    ) {
+        let _ = merge_logger.log(&format!("Merge verification failed (with retry): {}", e));
+        return Err(format!("merge agent failed: {}", e));
+    }
+    info!("  Merge agent: completed");
+    if let Err(e) = chat::write_message(&config.files_chat, "ScrumMaster", "Merge agent: completed")
+    {
+        warnings.warn(
+            "chat",
+            format!("  warning: failed to write merge complete to chat: {}", e),
+        );
+    }
+    if let Err(e) = merge_logger.log("Merge completed") {
+        warnings.warn(
+            "log",
+            format!("  warning: failed to write merge log: {}", e),
+        );
+    }
+    let merged = worktree::branch_is_merged(sprint_branch, target_branch)
+        .map_err(|e| format!("merge verification failed: {}", e))?;
+    if !merged {
+        if engine.engine_type() == EngineType::Stub {
+            let merge_result = worktree::merge_feature_branch(sprint_branch, target_branch);
+            match merge_result {
+                worktree::MergeResult::Success | worktree::MergeResult::NoChanges => {
+                    info!("  Merge agent: merged feature branch (stub)");
+                }
+                worktree::MergeResult::NoBranch => {
+                    let _ = merge_logger.log("Stub merge failed: feature branch not found");
+                    return Err(format!(
+                        "merge agent failed: feature branch '{}' not found",
+                        sprint_branch
+                    ));
                 }
-
-                if let Err(e) =
-                    worktree::cleanup_feature_worktree(worktrees_dir, &sprint_branch, true)
-                {
-                    eprintln!("  warning: feature worktree cleanup failed: {}", e);
-                    let _ = merge_logger.log(&format!("Feature cleanup failed: {}", e));
-                } else {
-                    println!("  Feature cleanup: removed '{}'", sprint_branch);
-                    let _ =
-                        merge_logger.log(&format!("Feature cleanup: removed '{}'", sprint_branch));
+                worktree::MergeResult::Conflict(files) => {
+                    let detail = if files.is_empty() {
+                        "conflicts detected".to_string()
+                    } else {
+                        format!("conflicts in {}", files.join(", "))
+                    };
+                    let _ = merge_logger.log(&format!("Stub merge conflict: {}", detail));
+                    return Err(format!("merge agent failed: {}", detail));
+                }
+                worktree::MergeResult::Error(e) => {
+                    let _ = merge_logger.log(&format!("Stub merge error: {}", e));
+                    return Err(format!("merge agent failed: {}", e));
                 }
-                sprint_state_committed = true;
             }
         } else {
-            let detail = merge_result
-                .error
-                .unwrap_or_else(|| "unknown error".to_string());
-            println!("  Merge agent: failed");
-            if let Err(e) = chat::write_message(
-                &config.files_chat,
-                "ScrumMaster",
-                &format!("Merge agent: failed ({})", detail),
-            ) {
-                eprintln!("  warning: failed to write merge failure to chat: {}", e);
-            }
-            let _ = merge_logger.log(&format!("Merge failed: {}", detail));
-            return Err(format!("merge agent failed: {}", detail));
+            let _ = merge_logger.log("Merge agent did not merge feature into target");
+            return Err(format!(
+                "merge agent did not merge '{}' into '{}'",
+                sprint_branch, target_branch
+            ));
         }
     }
 
-    if sprint_state_committed {
-        finalize_runtime_state_after_sprint(
-            &runtime_history_path,
-            &runtime_state_path,
-            &team_name,
-        )?;
-    }
+    Ok(())
+}
 
-    Ok(SprintResult {
-        tasks_assigned: assigned,
-        tasks_completed: completed_this_sprint,
-        tasks_failed: failed_this_sprint,
-    })
+/// Merge a clean single-agent sprint with git alone (no merge agent call).
+fn fast_path_merge(
+    chat_file: &str,
+    sprint_branch: &str,
+    target_branch: &str,
+    merge_logger: &NamedLogger,
+    warnings: &WarningCollector,
+) -> Result<(), String> {
+    let message = format!(
+        "Merge: fast path ({} -> {}), skipping merge agent",
+        sprint_branch, target_branch
+    );
+    info!("  {}", message);
+    let _ = merge_logger.log(&message);
+    if let Err(e) = chat::write_message(chat_file, "ScrumMaster", &message) {
+        warnings.warn(
+            "chat",
+            format!("  warning: failed to write merge start to chat: {}", e),
+        );
+    }
+    match worktree::merge_feature_branch(sprint_branch, target_branch) {
+        worktree::MergeResult::Success | worktree::MergeResult::NoChanges => {
+            let _ = merge_logger.log("Merge completed (fast path)");
+            Ok(())
+        }
+        worktree::MergeResult::NoBranch => Err(format!(
+            "fast-path merge failed: feature branch '{}' not found",
+            sprint_branch
+        )),
+        worktree::MergeResult::Conflict(files) => Err(format!(
+            "fast-path merge failed: conflicts in {}",
+            files.join(", ")
+        )),
+        worktree::MergeResult::Error(e) => Err(format!("fast-path merge failed: {}", e)),
+    }
 }
 
 fn reset_runtime_namespace_for_new_run(
@@ -2174,6 +3441,7 @@ fn sync_target_branch_state(
     // Bootstrap tasks from target branch once; keep history/state local to
     // runtime namespace to avoid branch-tracked state conflicts.
     if runtime_paths.is_namespaced() {
+        let runtime_root = repo_root.join(runtime_paths.root());
         let runtime_tasks = repo_root.join(runtime_paths.tasks_path());
         let runtime_history = repo_root.join(runtime_paths.sprint_history_path());
         let runtime_state = repo_root.join(runtime_paths.team_state_path());
@@ -2188,6 +3456,7 @@ fn sync_target_branch_state(
                 if !runtime_tasks.exists() && configured_tasks_rel.is_relative() {
                     copy_if_missing(&repo_root.join(configured_tasks_rel), &runtime_tasks)?;
                 }
+                copy_extra_task_files(repo_root, &runtime_root, config, copy_if_missing)?;
             } else {
                 let target_worktree_preexisting =
                     worktree::find_target_branch_worktree_in(repo_root, target_branch)?;
@@ -2198,6 +3467,7 @@ fn sync_target_branch_state(
                 if !runtime_tasks.exists() && configured_tasks_rel.is_relative() {
                     copy_if_missing(&target_worktree.join(configured_tasks_rel), &runtime_tasks)?;
                 }
+                copy_extra_task_files(&target_worktree, &runtime_root, config, copy_if_missing)?;
                 if target_worktree_preexisting.is_none() {
                     remove_worktree_path(repo_root, &target_worktree)?;
                 }
@@ -2235,6 +3505,7 @@ fn sync_target_branch_state(
         let dst = repo_root.join(tasks_path);
         copy_if_exists(&src, &dst)?;
     }
+    copy_extra_task_files(&source_worktree, repo_root, config, copy_if_exists)?;
 
     let sprint_rel = Path::new(team::SWARM_HUG_DIR)
         .join(team_name)
@@ -2315,6 +3586,21 @@ fn copy_if_missing(src: &Path, dst: &Path) -> Result<(), String> {
     copy_if_exists(src, dst)
 }
 
+/// Copy the relative extra `--tasks-file` paths from `src_root` to `dst_root`.
+fn copy_extra_task_files(
+    src_root: &Path,
+    dst_root: &Path,
+    config: &Config,
+    copy: fn(&Path, &Path) -> Result<(), String>,
+) -> Result<(), String> {
+    for extra in config.files_tasks_extra.iter().map(Path::new) {
+        if extra.is_relative() {
+            copy(&src_root.join(extra), &dst_root.join(extra))?;
+        }
+    }
+    Ok(())
+}
+
 fn remove_worktree_path(repo_root: &Path, worktree_path: &Path) -> Result<(), String> {
     let path_str = worktree_path.to_string_lossy().to_string();
     let output = process::Command::new("git")
@@ -2336,11 +3622,52 @@ fn remove_worktree_path(repo_root: &Path, worktree_path: &Path) -> Result<(), St
     }
 }
 
-fn persist_runtime_tasks_file(
-    worktree_tasks_path: &Path,
-    runtime_tasks_path: &Path,
+/// Every tasks file a sprint reads and writes: `primary` followed by the
+/// extra `--tasks-file` paths resolved under `root`.
+pub(crate) fn task_file_paths(primary: &Path, root: &Path, config: &Config) -> Vec<PathBuf> {
+    std::iter::once(primary.to_path_buf())
+        .chain(
+            config
+                .files_tasks_extra
+                .iter()
+                .map(|extra| root.join(extra)),
+        )
+        .collect()
+}
+
+/// Runtime copies of the tasks files. Extra files are kept inside the runtime
+/// namespace alongside `tasks.md`; legacy runs use them in place.
+pub(crate) fn runtime_task_file_paths(
+    runtime_paths: &team::RuntimeStatePaths,
+    config: &Config,
+) -> Vec<PathBuf> {
+    let root = if runtime_paths.is_namespaced() {
+        runtime_paths.root()
+    } else {
+        Path::new("")
+    };
+    task_file_paths(&runtime_paths.tasks_path(), root, config)
+}
+
+/// `paths` as strings for git; a non-UTF-8 path is an error rather than
+/// being silently dropped from the commit.
+fn path_strs(paths: &[PathBuf]) -> Result<Vec<&str>, String> {
+    paths
+        .iter()
+        .map(|p| {
+            p.to_str()
+                .ok_or_else(|| format!("tasks file path is not valid UTF-8: {}", p.display()))
+        })
+        .collect()
+}
+
+fn persist_runtime_task_files(
+    worktree_task_files: &[PathBuf],
+    runtime_task_files: &[PathBuf],
 ) -> Result<(), String> {
-    copy_if_exists(worktree_tasks_path, runtime_tasks_path)?;
+    for (worktree, runtime) in worktree_task_files.iter().zip(runtime_task_files) {
+        copy_if_exists(worktree, runtime)?;
+    }
     Ok(())
 }
 
@@ -2362,16 +3689,7 @@ fn parse_agent_initial_from_email(author_email: &str) -> Option<char> {
     let normalized = author_email.trim().to_ascii_lowercase();
     let local = normalized.strip_suffix("@swarm.local")?;
     let suffix = local.strip_prefix("agent-")?;
-    if suffix.len() != 1 {
-        return None;
-    }
-
-    let initial = suffix.chars().next()?.to_ascii_uppercase();
-    if agent::is_valid_initial(initial) {
-        Some(initial)
-    } else {
-        None
-    }
+    agent::initial_from_id(suffix)
 }
 
 fn collect_sprint_commit_evidence_in_range(
@@ -2489,7 +3807,8 @@ fn reconcile_sprint_tasks_from_git(
     let mut exact_match_counts_by_initial: std::collections::HashMap<char, usize> =
         std::collections::HashMap::new();
     for (initial, description) in assignments {
-        let agent_name = agent::name_from_initial(*initial).unwrap_or("Unknown");
+        let agent_name = agent::name_from_initial(*initial);
+        let agent_name = agent_name.as_deref().unwrap_or("Unknown");
         let expected_subject = format!("{}: {}", agent_name, description);
         if let Some(count) = evidence.subject_counts.get_mut(&expected_subject) {
             if *count > 0 {
@@ -2601,6 +3920,7 @@ fn reconcile_sprint_tasks_from_git(
             }
         }
     }
+    task_list.complete_finished_parents();
 
     Ok(SprintCompletionSummary {
         completed,
@@ -2624,22 +3944,47 @@ fn update_runtime_feature_branch(
     state.save()
 }
 
+/// Stable, greppable lines describing the git range a sprint produced.
+///
+/// `SPRINT_RANGE` spans the sprint branch from the assignment commit to its
+/// final HEAD; `SPRINT_PR_RANGE` is the `source..target` pair used for the PR.
+fn sprint_range_lines(repo_dir: &Path, range: &team::SprintRange) -> Vec<String> {
+    let short = |commit: &str| {
+        get_short_commit_for_ref_in(repo_dir, commit).unwrap_or_else(|| commit.to_string())
+    };
+    vec![
+        format!(
+            "SPRINT_RANGE={}..{}",
+            short(&range.start_commit),
+            short(&range.end_commit)
+        ),
+        format!(
+            "SPRINT_PR_RANGE={}..{}",
+            range.source_branch, range.target_branch
+        ),
+    ]
+}
+
 fn finalize_runtime_state_after_sprint(
     runtime_history_path: &Path,
     runtime_state_path: &Path,
     team_name: &str,
+    sprint_range: team::SprintRange,
 ) -> Result<(), String> {
     let mut history = team::SprintHistory::load_from(runtime_history_path)?;
     if history.team_name == "unknown" {
         history.team_name = team_name.to_string();
     }
     history.increment();
+    history.last_range = Some(sprint_range);
     history.save()?;
     update_runtime_feature_branch(runtime_state_path, team_name, None)?;
     Ok(())
 }
 
-/// Run post-sprint review to identify follow-up tasks.
+/// Run post-sprint review to identify follow-up tasks and close obsolete ones.
+///
+/// Tasks in `sprint_assignments` are never closed, even if the review asks.
 #[allow(clippy::too_many_arguments)]
 fn run_post_sprint_review(
     config: &Config,
@@ -2648,16 +3993,18 @@ fn run_post_sprint_review(
     sprint_branch: &str,
     sprint_start_commit: &str,
     task_list: &TaskList,
+    sprint_assignments: &[(char, String)],
     team_name: &str,
     sprint_number: usize,
-    worktree_tasks_path: &Path,
+    worktree_task_files: &[PathBuf],
+    warnings: &WarningCollector,
 ) -> Result<(), String> {
     // Get git log from sprint start to now
     let git_log = get_git_log_range_in(feature_worktree, sprint_start_commit, "HEAD")?;
 
     // If no changes, skip review
     if git_log.trim().is_empty() {
-        println!("  Post-sprint review: skipped (no git changes detected)");
+        info!("  Post-sprint review: skipped (no git changes detected)");
         return Ok(());
     }
 
@@ -2672,71 +4019,118 @@ fn run_post_sprint_review(
     let tasks_content = task_list.to_string();
 
     if let Err(e) = chat::write_message(&config.files_chat, "ScrumMaster", "Post-mortem started") {
-        eprintln!("warning: failed to write chat: {}", e);
+        warnings.warn("chat", format!("warning: failed to write chat: {}", e));
     }
 
     // Run the review
     let log_dir = Path::new(&config.files_log_dir);
     match planning::run_sprint_review(engine, &tasks_content, &git_log, log_dir) {
-        Ok(follow_ups) => {
+        Ok(review) => {
             let start_number = task_list.max_task_number().saturating_add(1);
-            let formatted_follow_ups = planning::format_follow_up_tasks(start_number, &follow_ups);
+            let formatted_follow_ups =
+                planning::format_follow_up_tasks(start_number, &review.follow_ups);
+            let mut worktree_tasks = TaskList::load_files(worktree_task_files)?;
+            worktree_tasks.completion_style = config.files_tasks_completion_style;
+
+            // Close tasks the review found obsolete, except this sprint's work
+            let mut closed = Vec::new();
+            if !review.closed.is_empty() {
+                let protected: Vec<&str> = sprint_assignments
+                    .iter()
+                    .map(|(_, description)| description.as_str())
+                    .collect();
+                closed = worktree_tasks.cancel_by_numbers(&review.closed, &protected);
+                for number in review.closed.iter().filter(|n| !closed.contains(n)) {
+                    warnings.warn(
+                        "review",
+                        format!(
+                            "  warning: review asked to close #{}, which is not an open task from before this sprint",
+                            number
+                        ),
+                    );
+                }
+            }
 
-            if formatted_follow_ups.is_empty() {
-                println!("  Post-sprint review: no follow-up tasks needed");
+            if formatted_follow_ups.is_empty() && closed.is_empty() {
+                info!("  Post-sprint review: no follow-up tasks needed");
             } else {
-                println!(
-                    "  Post-sprint review: {} follow-up task(s) identified",
-                    formatted_follow_ups.len()
-                );
-
-                // Append follow-up tasks to TASKS.md in worktree
-                let mut current_content =
-                    fs::read_to_string(worktree_tasks_path).unwrap_or_default();
-
-                // Ensure newline before appending
-                if !current_content.ends_with('\n') {
-                    current_content.push('\n');
+                let mut files = worktree_tasks.render_sources();
+                if !closed.is_empty() {
+                    let numbers: Vec<String> = closed.iter().map(|n| format!("#{}", n)).collect();
+                    info!(
+                        "  Post-sprint review: closed obsolete task(s) {}",
+                        numbers.join(", ")
+                    );
                 }
+                if !formatted_follow_ups.is_empty() {
+                    info!(
+                        "  Post-sprint review: {} follow-up task(s) identified",
+                        formatted_follow_ups.len()
+                    );
 
-                // Add follow-up tasks
-                current_content.push_str("\n## Follow-up tasks (from sprint review)\n");
-                for task in &formatted_follow_ups {
-                    current_content.push_str(task);
-                    current_content.push('\n');
-                    println!("    {}", task);
+                    // Add follow-up tasks to TASKS.md in worktree, after the last
+                    // task so trailing notes stay at the end of the file.
+                    let mut section = String::from("## Follow-up tasks (from sprint review)\n");
+                    for task in &formatted_follow_ups {
+                        section.push_str(task);
+                        section.push('\n');
+                        info!("    {}", task);
+                    }
+                    if let Some((_, content)) = files.first_mut() {
+                        *content = insert_after_last_task(content, &section);
+                    }
                 }
 
-                fs::write(worktree_tasks_path, current_content)
-                    .map_err(|e| format!("failed to write follow-up tasks: {}", e))?;
+                for (path, content) in &files {
+                    fs::write(path, content)
+                        .map_err(|e| format!("failed to write follow-up tasks: {}", e))?;
+                }
 
                 // Write to chat
-                let msg = format!(
-                    "Sprint review added {} follow-up task(s)",
-                    formatted_follow_ups.len()
-                );
+                let mut changes = Vec::new();
+                if !formatted_follow_ups.is_empty() {
+                    changes.push(format!(
+                        "added {} follow-up task(s)",
+                        formatted_follow_ups.len()
+                    ));
+                }
+                if !closed.is_empty() {
+                    changes.push(format!("closed {} obsolete task(s)", closed.len()));
+                }
+                let msg = format!("Sprint review {}", changes.join(" and "));
                 if let Err(e) = chat::write_message(worktree_chat_str, "ScrumMaster", &msg) {
-                    eprintln!("  warning: failed to write chat: {}", e);
+                    warnings.warn("chat", format!("  warning: failed to write chat: {}", e));
                 }
 
-                // Commit follow-up tasks so next planning phase sees them
-                let commit_msg = format!(
-                    "{} Sprint {}: follow-up tasks from review",
-                    team_name, sprint_number
-                );
-                let tasks_path_str = worktree_tasks_path.to_str().unwrap_or("");
+                // Commit task updates so next planning phase sees them
+                let commit_msg = if formatted_follow_ups.is_empty() {
+                    format!(
+                        "{} Sprint {}: closed obsolete tasks from review",
+                        team_name, sprint_number
+                    )
+                } else {
+                    format!(
+                        "{} Sprint {}: follow-up tasks from review",
+                        team_name, sprint_number
+                    )
+                };
+                let mut commit_paths = path_strs(worktree_task_files)?;
+                commit_paths.push(worktree_chat_str);
                 if let Ok(true) = commit_files_in_worktree_on_branch(
                     feature_worktree,
                     sprint_branch,
-                    &[tasks_path_str, worktree_chat_str],
+                    &commit_paths,
                     &commit_msg,
                 ) {
-                    println!("  Committed follow-up tasks to git.");
+                    info!("  Committed review task updates to git.");
                 }
             }
         }
         Err(e) => {
-            eprintln!("  warning: post-sprint review failed: {}", e);
+            warnings.warn(
+                "review",
+                format!("  warning: post-sprint review failed: {}", e),
+            );
         }
     }
 
@@ -2749,11 +4143,41 @@ fn write_merge_failure_chat(
     detail: &str,
 ) -> std::io::Result<()> {
     let msg = format!("Merge failed for {}: {}", agent_name, detail);
-    chat::write_message(chat_path, "ScrumMaster", &msg)
+    chat::write_message_level(chat_path, "ScrumMaster", chat::Level::Error, &msg)
+}
+
+fn write_push_outcome_chat(
+    chat_path: &str,
+    level: chat::Level,
+    detail: &str,
+) -> std::io::Result<()> {
+    chat::write_message_level(chat_path, "ScrumMaster", level, detail)
 }
 
-fn write_push_outcome_chat(chat_path: &str, detail: &str) -> std::io::Result<()> {
-    chat::write_message(chat_path, "ScrumMaster", detail)
+/// Hold an agent's next task while the run is paused, noting the wait in its
+/// log and in chat. Returns once resumed or shutdown is requested.
+fn wait_while_paused(
+    logger: &AgentLogger,
+    chat_path: &str,
+    agent_name: &str,
+    warnings: &WarningCollector,
+) {
+    if !pause::requested() {
+        return;
+    }
+    let note = |msg: &str| {
+        if let Err(e) = logger.log(msg) {
+            warnings.warn("log", format!("warning: failed to write log: {}", e));
+        }
+        if let Err(e) = chat::write_message(chat_path, agent_name, msg) {
+            warnings.warn("chat", format!("warning: failed to write chat: {}", e));
+        }
+    };
+    note("Paused: waiting to start next task");
+    pause::wait_until_resumed();
+    if !shutdown::requested() {
+        note("Resumed");
+    }
 }
 
 fn push_skip_reason(
@@ -2823,17 +4247,20 @@ fn commit_agent_work(
         .arg(worktree_path)
         .args(["commit", "-m", &commit_msg])
         .env("GIT_AUTHOR_NAME", format!("Agent {}", agent_name))
-        .env("GIT_AUTHOR_EMAIL", format!("agent-{}@swarm.local", initial))
+        .env(
+            "GIT_AUTHOR_EMAIL",
+            format!("agent-{}@swarm.local", agent::id(initial)),
+        )
         .env("GIT_COMMITTER_NAME", format!("Agent {}", agent_name))
         .env(
             "GIT_COMMITTER_EMAIL",
-            format!("agent-{}@swarm.local", initial),
+            format!("agent-{}@swarm.local", agent::id(initial)),
         )
         .output();
 
     match commit_result {
         Ok(output) if output.status.success() => {
-            println!("  {} committed: {}", agent_name, task_description);
+            info!("  {} committed: {}", agent_name, task_description);
             Ok(())
         }
         Ok(output) => {
@@ -2852,25 +4279,35 @@ fn commit_agent_work(
 #[cfg(test)]
 mod tests {
     use super::{
-        build_pr_metadata_prompt, chat, create_branch_at_commit, create_sprint_worktree_in,
-        default_pr_title, engine_team_dir, ensure_branch_exists, generate_pr_title_and_body,
-        parse_pr_metadata_from_engine_output, preserve_failed_worktree, push_skip_reason,
-        reconcile_sprint_tasks_from_git, report_pull_request_creation,
-        reset_runtime_namespace_for_new_run, resolve_sprint_base_branch, retry_merge_agent,
-        should_push_target_branch, split_cleanup_initials, sync_target_branch_state,
-        write_merge_failure_chat, write_push_outcome_chat, MergeFailureInfo, SprintResult,
-        TaskResult, DEFAULT_PR_BODY,
+        agents_needed_for, available_agents, average_state_times, build_pr_metadata_prompt,
+        busy_agents, chat, completion_message, create_branch_at_commit, create_sprint_worktree_in,
+        default_pr_title, engine_team_dir, ensure_branch_exists, fast_path_merge_eligible,
+        find_resume_point, generate_pr_title_and_body, join_agents, merge_sprint_into_target,
+        open_pull_request_if_enabled, order_agent_tasks, parse_pr_metadata_from_engine_output,
+        path_strs, preserve_failed_worktree, push_skip_reason, reconcile_sprint_tasks_from_git,
+        report_pull_request_creation, reset_runtime_namespace_for_new_run,
+        resolve_sprint_base_branch, resumed_assignment_counts, should_push_target_branch,
+        split_cleanup_initials, sprint_range_lines, sync_target_branch_state, task_timeout_secs,
+        truncate_for_log_bytes, unique_preserved_path, wait_for_available_agents,
+        wait_while_paused, with_base_commit_line, write_conflict_report, write_merge_failure_chat,
+        write_push_outcome_chat, MergeFailureInfo, MergeGate, MergeGateRelease, SprintResult,
+        TaskResult, DEFAULT_PR_BODY, MAX_PRESERVE_PATH_ATTEMPTS,
     };
+    use std::collections::HashSet;
     use std::fs;
     use std::path::Path;
     use std::process::Command;
     use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
     use tempfile::NamedTempFile;
 
     use crate::git::PullRequestCreateResult;
     use crate::testutil::with_temp_cwd;
-    use swarm::config::Config;
-    use swarm::engine::{Engine, EngineResult};
+    use swarm::config::EngineType;
+    use swarm::config::{AgentTaskOrder, Config, NoAgentsBehavior};
+    use swarm::engine::{self as engine, Engine, EngineResult};
+    use swarm::warnings::WarningCollector;
     use swarm::{team, worktree};
 
     fn run_git_in(dir: &Path, args: &[&str]) {
@@ -2909,10 +4346,283 @@ mod tests {
             tasks_assigned: 3,
             tasks_completed: 0,
             tasks_failed: 3,
+            merge_failures: 0,
         };
         assert!(result.all_failed());
     }
 
+    #[test]
+    fn test_wait_for_available_agents_error_is_descriptive() {
+        let err = wait_for_available_agents(
+            NoAgentsBehavior::Error,
+            Duration::ZERO,
+            Duration::ZERO,
+            Vec::new,
+        )
+        .expect_err("no agents should fail");
+        assert!(err.contains("no agents available"), "{}", err);
+        assert!(err.contains("on_no_agents"), "{}", err);
+    }
+
+    #[test]
+    fn test_wait_for_available_agents_skip_returns_none() {
+        let result = wait_for_available_agents(
+            NoAgentsBehavior::Skip,
+            Duration::ZERO,
+            Duration::ZERO,
+            Vec::new,
+        );
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn test_wait_for_available_agents_wait_polls_until_free() {
+        let mut polls = 0;
+        let result = wait_for_available_agents(
+            NoAgentsBehavior::Wait,
+            Duration::from_secs(5),
+            Duration::from_millis(1),
+            || {
+                polls += 1;
+                if polls < 3 {
+                    Vec::new()
+                } else {
+                    vec!['A']
+                }
+            },
+        );
+        assert_eq!(result, Ok(Some(vec!['A'])));
+        assert_eq!(polls, 3);
+    }
+
+    #[test]
+    fn test_wait_for_available_agents_wait_times_out() {
+        let result = wait_for_available_agents(
+            NoAgentsBehavior::Wait,
+            Duration::from_millis(5),
+            Duration::from_millis(1),
+            Vec::new,
+        );
+        assert!(result.unwrap_err().contains("within"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_path_strs_rejects_non_utf8_paths() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::PathBuf;
+
+        let paths = vec![PathBuf::from("tasks.md"), PathBuf::from("bugs.md")];
+        assert_eq!(path_strs(&paths), Ok(vec!["tasks.md", "bugs.md"]));
+        let paths = vec![PathBuf::from(OsStr::from_bytes(b"bad\xff.md"))];
+        assert!(path_strs(&paths).unwrap_err().contains("not valid UTF-8"));
+    }
+
+    #[test]
+    fn test_available_agents_skips_busy_agents() {
+        let busy: HashSet<char> = ['A', 'C'].into_iter().collect();
+        assert_eq!(available_agents(3, &busy), vec!['B', 'D', 'E']);
+        assert_eq!(available_agents(2, &HashSet::new()), vec!['A', 'B']);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_busy_agents_reads_live_processes_of_other_runs() {
+        use swarm::process_registry::{self, RecordedProcess};
+
+        with_temp_cwd(|| {
+            let mut child = Command::new("sleep")
+                .arg("30")
+                .spawn()
+                .expect("spawn sleep");
+            let record = |agent: &str, pid: u32| RecordedProcess {
+                pid,
+                name: "sleep".to_string(),
+                agent: agent.to_string(),
+                command: "sleep 30".to_string(),
+                started: 0,
+            };
+            let other = team::RuntimeStatePaths::for_branches("proj", "main", "other").pids_path();
+            let own = team::RuntimeStatePaths::for_branches("proj", "main", "mine").pids_path();
+            fs::create_dir_all(other.parent().unwrap()).unwrap();
+            fs::create_dir_all(own.parent().unwrap()).unwrap();
+            process_registry::save(
+                &other,
+                &[record("Betty", child.id()), record("Carlos", u32::MAX)],
+            )
+            .unwrap();
+            process_registry::save(&own, &[record("Aaron", child.id())]).unwrap();
+
+            let busy = busy_agents("proj", &own);
+
+            child.kill().ok();
+            child.wait().ok();
+            assert_eq!(busy, ['B'].into_iter().collect());
+        });
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_join_agents_force_kills_after_shutdown_grace() {
+        use swarm::engine::CommandEngine;
+        use swarm::shutdown::ShutdownSignal;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let workdir = dir.path().to_path_buf();
+        // An agent whose engine sleeps far longer than the grace period
+        let stuck = thread::spawn(move || {
+            let engine = CommandEngine::new("sh", vec!["-c".to_string(), "sleep 30".to_string()]);
+            engine.execute("Aaron", "Stuck task", &workdir, 1, None)
+        });
+        let finished = thread::spawn(|| EngineResult::success("done"));
+
+        let signal = ShutdownSignal::new();
+        signal.trigger();
+        let warnings = WarningCollector::new();
+        let start = Instant::now();
+        let joined = join_agents(
+            vec![('A', stuck), ('B', finished)],
+            Duration::from_secs(1),
+            &signal,
+            &warnings,
+        );
+
+        assert!(start.elapsed() < Duration::from_secs(10));
+        let initials: Vec<char> = joined.iter().map(|(initial, _)| *initial).collect();
+        assert_eq!(initials, vec!['A', 'B']);
+        let stuck = joined[0].1.as_ref().unwrap();
+        assert!(!stuck.success, "stuck engine should have been killed");
+        assert!(joined[1].1.as_ref().unwrap().success);
+        assert!(warnings
+            .warnings()
+            .iter()
+            .any(|w| w.message.contains("grace period (1s) expired")));
+    }
+
+    #[test]
+    fn test_order_agent_tasks_runs_p0_before_p3() {
+        let mut tasks = vec![
+            "(P3) Polish docs".to_string(),
+            "Untagged cleanup".to_string(),
+            "(P0) Fix auth outage".to_string(),
+            "(P3) Rename helper".to_string(),
+        ];
+        order_agent_tasks(&mut tasks, AgentTaskOrder::Priority);
+        assert_eq!(
+            tasks,
+            vec![
+                "(P0) Fix auth outage",
+                "(P3) Polish docs",
+                "(P3) Rename helper",
+                "Untagged cleanup",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_agent_tasks_assignment_order_is_unchanged() {
+        let mut tasks = vec!["(P3) Later".to_string(), "(P0) Sooner".to_string()];
+        order_agent_tasks(&mut tasks, AgentTaskOrder::Assignment);
+        assert_eq!(tasks, vec!["(P3) Later", "(P0) Sooner"]);
+    }
+
+    #[test]
+    fn test_merge_gate_merges_prerequisite_branch_first() {
+        let task_a = "(#1) Add auth API".to_string();
+        let task_b = "(#2) Add login form (blocked by #1)".to_string();
+        let agent_tasks = vec![('A', vec![task_a.clone()]), ('B', vec![task_b.clone()])];
+        let gate = Arc::new(MergeGate::new(
+            swarm::task::merge_prerequisites(&agent_tasks).unwrap(),
+        ));
+        let merged = Arc::new(Mutex::new(Vec::new()));
+
+        // B finishes its work first and reaches the merge step while A is still running
+        let b = {
+            let gate = Arc::clone(&gate);
+            let merged = Arc::clone(&merged);
+            let task_b = task_b.clone();
+            std::thread::spawn(move || {
+                gate.wait_for_prerequisites(&task_b);
+                merged.lock().unwrap().push("agent-b");
+                gate.finish(&task_b);
+            })
+        };
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(merged.lock().unwrap().is_empty(), "B merged before A");
+
+        gate.wait_for_prerequisites(&task_a);
+        merged.lock().unwrap().push("agent-a");
+        gate.finish(&task_a);
+        b.join().unwrap();
+
+        assert_eq!(*merged.lock().unwrap(), vec!["agent-a", "agent-b"]);
+    }
+
+    #[test]
+    fn test_merge_gate_release_unblocks_skipped_prerequisites() {
+        let task_a = "(#1) Add auth API".to_string();
+        let task_b = "(#2) Add login form (blocked by #1)".to_string();
+        let agent_tasks = vec![('A', vec![task_a.clone()]), ('B', vec![task_b.clone()])];
+        let gate = Arc::new(MergeGate::new(
+            swarm::task::merge_prerequisites(&agent_tasks).unwrap(),
+        ));
+
+        // Agent A's thread exits without merging (e.g. shutdown skipped its task)
+        drop(MergeGateRelease {
+            gate: Arc::clone(&gate),
+            tasks: vec![task_a],
+        });
+        gate.wait_for_prerequisites(&task_b);
+    }
+
+    #[test]
+    fn test_truncate_for_log_bytes_respects_limit_and_zero() {
+        let output = "é".repeat(10); // 20 bytes
+        assert_eq!(
+            truncate_for_log_bytes(&output, 5),
+            "éé... [truncated, 20 bytes total]"
+        );
+        assert_eq!(truncate_for_log_bytes(&output, 20), output);
+        assert_eq!(truncate_for_log_bytes(&output, 0), output);
+    }
+
+    #[test]
+    fn test_completion_message_uses_agent_summary() {
+        let captured = Arc::new(Mutex::new(None));
+        let engine = CapturingEngine::success(
+            "Edited src/lib.rs\nSWARM_SUMMARY: Added retry with backoff to the fetcher\n",
+            Arc::clone(&captured),
+        );
+        let result = engine.execute("Aaron", "Add retries", Path::new("."), 1, None);
+        assert_eq!(
+            completion_message("Add retries", &result),
+            "Completed: Added retry with backoff to the fetcher"
+        );
+
+        let engine = CapturingEngine::success("done", captured);
+        let result = engine.execute("Aaron", "Add retries", Path::new("."), 1, None);
+        assert_eq!(
+            completion_message("Add retries", &result),
+            "Completed: Add retries"
+        );
+    }
+
+    #[test]
+    fn test_task_timeout_annotation_builds_engine_with_task_timeout() {
+        let timeout = task_timeout_secs("(#2) Big refactor {timeout: 7200}", 3600).unwrap();
+        let (engine, _) =
+            engine::create_random_engine(&[EngineType::Claude], false, "loop", timeout);
+        assert_eq!(engine.timeout_secs(), 7200);
+
+        let timeout = task_timeout_secs("(#3) Small fix", 3600).unwrap();
+        let (engine, _) =
+            engine::create_random_engine(&[EngineType::Claude], false, "loop", timeout);
+        assert_eq!(engine.timeout_secs(), 3600);
+
+        assert!(task_timeout_secs("Broken {timeout: 0}", 3600).is_err());
+    }
+
     #[test]
     fn test_engine_team_dir_uses_canonical_team_root() {
         let path = engine_team_dir("greenfield", ".swarm-hug/greenfield/runs/main/tasks.md");
@@ -2925,12 +4635,32 @@ mod tests {
         assert_eq!(path, ".swarm-hug/greenfield/runs/main");
     }
 
+    #[test]
+    fn test_sprint_result_counts_merge_failures_separately() {
+        let result = SprintResult {
+            tasks_assigned: 3,
+            tasks_completed: 1,
+            tasks_failed: 2,
+            merge_failures: 2,
+        };
+        assert_eq!(result.merge_failures, 2);
+        assert!(!result.all_failed());
+
+        let result = SprintResult {
+            merge_failures: 3,
+            tasks_completed: 0,
+            ..result
+        };
+        assert!(result.all_failed());
+    }
+
     #[test]
     fn test_sprint_result_all_failed_false_with_success() {
         let result = SprintResult {
             tasks_assigned: 3,
             tasks_completed: 1,
             tasks_failed: 2,
+            merge_failures: 0,
         };
         assert!(!result.all_failed());
     }
@@ -2941,6 +4671,7 @@ mod tests {
             tasks_assigned: 0,
             tasks_completed: 0,
             tasks_failed: 0,
+            merge_failures: 0,
         };
         assert!(!result.all_failed());
     }
@@ -2951,6 +4682,7 @@ mod tests {
             tasks_assigned: 2,
             tasks_completed: 2,
             tasks_failed: 0,
+            merge_failures: 0,
         };
         assert!(!result.all_failed());
     }
@@ -3213,13 +4945,72 @@ mod tests {
             },
             &merge_logger,
             chat_file.to_str().expect("chat path"),
+            &WarningCollector::new(),
+        );
+
+        let log_content = fs::read_to_string(merge_logger.path).expect("read merge log");
+        assert!(log_content.contains("PR created: https://github.com/example/repo/pull/42"));
+
+        let chat_content = fs::read_to_string(&chat_file).expect("read chat file");
+        assert!(chat_content.contains("PR: created https://github.com/example/repo/pull/42"));
+    }
+
+    #[test]
+    fn test_open_pull_request_if_enabled_skips_pr_path_when_disabled() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let log_dir = temp.path().join("logs");
+        fs::create_dir_all(&log_dir).expect("create log dir");
+        let merge_logger = swarm::log::NamedLogger::new(&log_dir, "MergeAgent", "merge-agent.log");
+        let chat_file = temp.path().join("chat.md");
+
+        let mut invoked = false;
+        open_pull_request_if_enabled(
+            false,
+            &merge_logger,
+            chat_file.to_str().expect("chat path"),
+            &WarningCollector::new(),
+            || {
+                invoked = true;
+                PullRequestCreateResult::Skipped {
+                    reason: "unexpected".to_string(),
+                }
+            },
+        );
+
+        assert!(!invoked, "PR creation must not run when disabled");
+        let log_content = fs::read_to_string(merge_logger.path).expect("read merge log");
+        assert!(log_content.contains("PR: disabled by config"));
+        let chat_content = fs::read_to_string(&chat_file).expect("read chat file");
+        assert!(chat_content.contains("PR: disabled by config"));
+    }
+
+    #[test]
+    fn test_open_pull_request_if_enabled_runs_pr_path_by_default() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let log_dir = temp.path().join("logs");
+        fs::create_dir_all(&log_dir).expect("create log dir");
+        let merge_logger = swarm::log::NamedLogger::new(&log_dir, "MergeAgent", "merge-agent.log");
+        let chat_file = temp.path().join("chat.md");
+
+        let mut invoked = false;
+        open_pull_request_if_enabled(
+            Config::default().sprints_create_pr,
+            &merge_logger,
+            chat_file.to_str().expect("chat path"),
+            &WarningCollector::new(),
+            || {
+                invoked = true;
+                PullRequestCreateResult::Created {
+                    url: Some("https://github.com/example/repo/pull/7".to_string()),
+                    stdout: String::new(),
+                    stderr: String::new(),
+                }
+            },
         );
 
-        let log_content = fs::read_to_string(merge_logger.path).expect("read merge log");
-        assert!(log_content.contains("PR created: https://github.com/example/repo/pull/42"));
-
+        assert!(invoked);
         let chat_content = fs::read_to_string(&chat_file).expect("read chat file");
-        assert!(chat_content.contains("PR: created https://github.com/example/repo/pull/42"));
+        assert!(chat_content.contains("PR: created https://github.com/example/repo/pull/7"));
     }
 
     #[test]
@@ -3236,6 +5027,7 @@ mod tests {
             },
             &merge_logger,
             chat_file.to_str().expect("chat path"),
+            &WarningCollector::new(),
         );
 
         let log_content = fs::read_to_string(merge_logger.path).expect("read merge log");
@@ -3253,6 +5045,7 @@ mod tests {
         fs::create_dir_all(&log_dir).expect("create log dir");
         let merge_logger = swarm::log::NamedLogger::new(&log_dir, "MergeAgent", "merge-agent.log");
         let chat_file = temp.path().join("chat.md");
+        let warnings = WarningCollector::new();
 
         report_pull_request_creation(
             PullRequestCreateResult::Failed {
@@ -3262,6 +5055,15 @@ mod tests {
             },
             &merge_logger,
             chat_file.to_str().expect("chat path"),
+            &warnings,
+        );
+
+        let recorded = warnings.warnings();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].category, "pr");
+        assert_eq!(
+            recorded[0].message,
+            "failed to create pull request (continuing)"
         );
 
         let log_content = fs::read_to_string(merge_logger.path).expect("read merge log");
@@ -3286,7 +5088,13 @@ mod tests {
         let line = content.lines().next().expect("chat line");
         let (_, agent, message) = chat::parse_line(line).expect("parse chat line");
         assert_eq!(agent, "ScrumMaster");
-        assert_eq!(message, "Merge failed for Aaron: conflicts in file.txt");
+        assert_eq!(
+            chat::Level::of_message(message),
+            (
+                chat::Level::Error,
+                "Merge failed for Aaron: conflicts in file.txt"
+            )
+        );
     }
 
     #[test]
@@ -3294,6 +5102,7 @@ mod tests {
         let temp = NamedTempFile::new().expect("temp chat file");
         write_push_outcome_chat(
             temp.path().to_str().expect("temp path"),
+            chat::Level::Info,
             "Push: pushed 'release' to origin",
         )
         .expect("write push chat");
@@ -3338,6 +5147,7 @@ mod tests {
             worktree_path: "/tmp/wt-a".to_string(),
             log_path: "/tmp/agent-A.log".to_string(),
             detail: "conflict".to_string(),
+            files: vec!["src/lib.rs".to_string()],
             skip_cleanup: true,
         }];
         let (cleanup, skipped) = split_cleanup_initials(&['A', 'B', 'C'], &failures);
@@ -3345,6 +5155,41 @@ mod tests {
         assert_eq!(skipped, vec!['A']);
     }
 
+    #[test]
+    fn test_write_conflict_report_records_conflicting_files() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let path = temp.path().join("runs/main/conflicts.json");
+        let merge_result = worktree::MergeResult::Conflict(vec![
+            "src/lib.rs".to_string(),
+            "README.md".to_string(),
+        ]);
+        let failures = vec![MergeFailureInfo {
+            initial: 'A',
+            agent_name: "Aaron".to_string(),
+            branch: "alpha-agent-aaron-abc123".to_string(),
+            worktree_path: "/repo/worktrees/preserved/alpha-agent-aaron-abc123-preserved-1-100"
+                .to_string(),
+            log_path: "/repo/loop/agent-A.log".to_string(),
+            detail: "conflicts in src/lib.rs, README.md".to_string(),
+            files: merge_result.conflict_files().unwrap().to_vec(),
+            skip_cleanup: false,
+        }];
+
+        write_conflict_report(&path, 3, &failures).expect("write report");
+
+        let json = fs::read_to_string(&path).expect("read report");
+        assert_eq!(
+            json,
+            "{\n  \"sprint\": 3,\n  \"conflicts\": [\n    {\"agent\": \"Aaron\", \"initial\": \"A\", \"branch\": \"alpha-agent-aaron-abc123\", \"files\": [\"src/lib.rs\", \"README.md\"], \"worktree_path\": \"/repo/worktrees/preserved/alpha-agent-aaron-abc123-preserved-1-100\", \"log_path\": \"/repo/loop/agent-A.log\", \"detail\": \"conflicts in src/lib.rs, README.md\"}\n  ]\n}\n"
+        );
+
+        write_conflict_report(&path, 4, &[]).expect("write empty report");
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "{\n  \"sprint\": 4,\n  \"conflicts\": []\n}\n"
+        );
+    }
+
     #[test]
     fn test_split_cleanup_initials_allows_cleanup_when_skip_false() {
         let failures = vec![MergeFailureInfo {
@@ -3354,6 +5199,7 @@ mod tests {
             worktree_path: "/tmp/wt-a".to_string(),
             log_path: "/tmp/agent-A.log".to_string(),
             detail: "conflict".to_string(),
+            files: vec!["src/lib.rs".to_string()],
             skip_cleanup: false,
         }];
         let (cleanup, skipped) = split_cleanup_initials(&['A', 'B'], &failures);
@@ -3420,6 +5266,40 @@ mod tests {
         assert_eq!(head_ref, "HEAD", "preserved worktree should be detached");
     }
 
+    #[test]
+    fn test_unique_preserved_path_skips_existing_candidates() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let root = temp.path();
+
+        let first = unique_preserved_path(root, "agent-branch-preserved-1-42").expect("first");
+        assert_eq!(first, root.join("agent-branch-preserved-1-42"));
+        fs::create_dir_all(&first).expect("create first candidate");
+
+        let second = unique_preserved_path(root, "agent-branch-preserved-1-42").expect("second");
+        assert_ne!(second, first);
+        assert!(!second.exists());
+        fs::create_dir_all(&second).expect("create second candidate");
+
+        let third = unique_preserved_path(root, "agent-branch-preserved-1-42").expect("third");
+        assert_ne!(third, first);
+        assert_ne!(third, second);
+    }
+
+    #[test]
+    fn test_unique_preserved_path_errors_when_exhausted() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let root = temp.path();
+        let base = "agent-branch-preserved-1-42";
+        fs::create_dir_all(root.join(base)).expect("create base");
+        for attempt in 1..MAX_PRESERVE_PATH_ATTEMPTS {
+            fs::create_dir_all(root.join(format!("{}-{}-{}", base, std::process::id(), attempt)))
+                .expect("create candidate");
+        }
+
+        let err = unique_preserved_path(root, base).expect_err("should exhaust candidates");
+        assert!(err.contains("no unused preserved worktree path"));
+    }
+
     #[test]
     fn test_preserve_failed_worktree_missing_path() {
         let temp = tempfile::TempDir::new().expect("temp repo");
@@ -4037,6 +5917,114 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_agent_initial_from_email_round_trips_ids_past_z() {
+        use swarm::agent;
+
+        for initial in (0..30).filter_map(agent::initial_at) {
+            let email = format!("agent-{}@swarm.local", agent::id(initial));
+            assert_eq!(super::parse_agent_initial_from_email(&email), Some(initial));
+            assert_eq!(
+                super::parse_agent_initial_from_email(&email.to_ascii_uppercase()),
+                Some(initial)
+            );
+        }
+        assert_eq!(
+            super::parse_agent_initial_from_email("agent-a1@swarm.local"),
+            None
+        );
+        assert_eq!(
+            super::parse_agent_initial_from_email("agent-@swarm.local"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_thirty_agent_sprint_has_distinct_branches_and_reconciles() {
+        use swarm::agent;
+
+        let initials = agent::get_initials(30);
+        assert_eq!(initials.len(), 30);
+        let ctx = swarm::run_context::RunContext::new("greenfield", 1);
+        let branches: std::collections::HashSet<String> = initials
+            .iter()
+            .map(|&initial| swarm::worktree::agent_branch_name(&ctx, initial))
+            .collect();
+        assert_eq!(branches.len(), 30, "branches: {:?}", branches);
+
+        let temp = tempfile::TempDir::new().expect("temp repo");
+        let repo_root = temp.path().to_path_buf();
+        init_repo(&repo_root);
+
+        let mut content = String::from("# Tasks\n\n");
+        let mut assignments = Vec::new();
+        for (n, &initial) in initials.iter().enumerate() {
+            let description = format!("(#{}) Task {}", n + 1, n + 1);
+            content.push_str(&format!("- [ ] {}\n", description));
+            assignments.push((initial, description));
+        }
+        let mut task_list = swarm::task::TaskList::parse(&content);
+        for (task, &initial) in task_list.tasks.iter_mut().zip(&initials) {
+            task.assign(initial);
+        }
+        // Multi-letter assignments survive a TASKS.md round trip
+        let task_list_text = task_list.to_string();
+        assert!(task_list_text.contains("- [AD] (#30) Task 30"));
+        let reparsed = swarm::task::TaskList::parse(&task_list_text);
+        assert_eq!(
+            reparsed.tasks[29].status,
+            swarm::task::TaskStatus::Assigned(initials[29])
+        );
+
+        let sprint_start = String::from_utf8_lossy(
+            &Command::new("git")
+                .arg("-C")
+                .arg(&repo_root)
+                .args(["rev-parse", "HEAD"])
+                .output()
+                .expect("rev-parse")
+                .stdout,
+        )
+        .trim()
+        .to_string();
+
+        for &initial in &initials {
+            let id = agent::id(initial);
+            let email = format!("agent-{}@swarm.local", id);
+            fs::write(repo_root.join(format!("work-{}.txt", id)), "done").expect("write change");
+            run_git_in(&repo_root, &["add", "."]);
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(&repo_root)
+                .args(["commit", "-m", &format!("Merge work from agent {}", id)])
+                .env("GIT_AUTHOR_NAME", "Agent")
+                .env("GIT_AUTHOR_EMAIL", &email)
+                .env("GIT_COMMITTER_NAME", "Agent")
+                .env("GIT_COMMITTER_EMAIL", &email)
+                .output()
+                .expect("commit as agent");
+            assert!(output.status.success(), "{:?}", output);
+        }
+
+        let summary = reconcile_sprint_tasks_from_git(
+            &repo_root,
+            &sprint_start,
+            &assignments,
+            &[],
+            false,
+            &mut task_list,
+        )
+        .expect("reconcile thirty agents");
+        assert_eq!(summary.completed, 30);
+        assert_eq!(summary.failed, 0);
+        for (task, &initial) in task_list.tasks.iter().zip(&initials) {
+            assert_eq!(task.status, swarm::task::TaskStatus::Completed(initial));
+        }
+        let aa = agent::initial_from_id("AA").unwrap();
+        assert!(task_list.to_string().contains("- [x] (#27) Task 27 (AA)"));
+        assert_eq!(agent::name_from_initial(aa).as_deref(), Some("Aaron2"));
+    }
+
     #[test]
     fn test_reconcile_sprint_tasks_from_git_falls_back_to_success_when_diff_exists() {
         let temp = tempfile::TempDir::new().expect("temp repo");
@@ -4129,6 +6117,94 @@ mod tests {
         );
     }
 
+    /// Leave runtime state as a sprint interrupted after assignment would.
+    fn write_interrupted_sprint(
+        repo_root: &Path,
+        runtime_paths: &team::RuntimeStatePaths,
+        sprint_branch: &str,
+    ) {
+        let runtime_root = repo_root.join(runtime_paths.root());
+        fs::create_dir_all(&runtime_root).expect("create runtime root");
+        fs::write(
+            repo_root.join(runtime_paths.tasks_path()),
+            "# Tasks\n\n- [x] Done already (A)\n- [C] Task two\n- [C] Task three\n- [D] Task four\n- [ ] Task five\n",
+        )
+        .expect("write runtime tasks");
+        fs::write(
+            repo_root.join(runtime_paths.team_state_path()),
+            format!(
+                r#"{{"team": "greenfield", "feature_branch": "{}"}}"#,
+                sprint_branch
+            ),
+        )
+        .expect("write team state");
+        let run_ctx = swarm::run_context::RunContext::new("greenfield", 2).with_run_id("abc123");
+        assert_eq!(run_ctx.sprint_branch(), sprint_branch);
+        fs::write(repo_root.join(runtime_paths.run_path()), run_ctx.to_json())
+            .expect("write run.json");
+    }
+
+    #[test]
+    fn test_find_resume_point_picks_up_runtime_tasks_without_replanning() {
+        let temp = tempfile::TempDir::new().expect("temp repo");
+        let repo_root = temp.path().to_path_buf();
+        init_repo(&repo_root);
+        let runtime_paths = team::RuntimeStatePaths::for_branches("greenfield", "main", "main");
+        write_interrupted_sprint(&repo_root, &runtime_paths, "greenfield-sprint-2-abc123");
+        run_git_in(&repo_root, &["branch", "greenfield-sprint-2-abc123"]);
+
+        let point = find_resume_point(&repo_root, &runtime_paths)
+            .expect("find resume point")
+            .expect("interrupted sprint");
+        assert_eq!(point.sprint_branch, "greenfield-sprint-2-abc123");
+        assert_eq!(point.run_hash, "abc123");
+
+        // The runtime assignments are used as-is rather than re-planned.
+        let content =
+            fs::read_to_string(repo_root.join(runtime_paths.tasks_path())).expect("read tasks");
+        let task_list = swarm::task::TaskList::parse(&content);
+        assert_eq!(resumed_assignment_counts(&task_list), (3, 2));
+        assert_eq!(
+            task_list.tasks[1].status,
+            swarm::task::TaskStatus::Assigned('C')
+        );
+        assert_eq!(
+            task_list.tasks[4].status,
+            swarm::task::TaskStatus::Unassigned
+        );
+    }
+
+    #[test]
+    fn test_find_resume_point_is_none_without_interrupted_sprint() {
+        let temp = tempfile::TempDir::new().expect("temp repo");
+        let repo_root = temp.path().to_path_buf();
+        init_repo(&repo_root);
+        let runtime_paths = team::RuntimeStatePaths::for_branches("greenfield", "main", "main");
+        assert_eq!(find_resume_point(&repo_root, &runtime_paths), Ok(None));
+
+        // The sprint branch is gone, so there is nothing to continue.
+        write_interrupted_sprint(&repo_root, &runtime_paths, "greenfield-sprint-2-abc123");
+        assert_eq!(find_resume_point(&repo_root, &runtime_paths), Ok(None));
+
+        // A finished sprint clears the feature branch.
+        run_git_in(&repo_root, &["branch", "greenfield-sprint-2-abc123"]);
+        fs::write(
+            repo_root.join(runtime_paths.team_state_path()),
+            r#"{"team": "greenfield"}"#,
+        )
+        .expect("clear team state");
+        assert_eq!(find_resume_point(&repo_root, &runtime_paths), Ok(None));
+    }
+
+    #[test]
+    fn test_with_base_commit_line_appends_sha() {
+        assert_eq!(
+            with_base_commit_line("Adds auth.\n", Some("abc1234")),
+            "Adds auth.\n\nBase commit: abc1234"
+        );
+        assert_eq!(with_base_commit_line("Adds auth.", None), "Adds auth.");
+    }
+
     #[test]
     fn test_ensure_branch_exists_succeeds_for_existing_branch() {
         let temp = tempfile::TempDir::new().expect("temp repo");
@@ -4153,6 +6229,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fast_path_merge_eligible_requires_single_clean_agent() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let repo_root = temp.path();
+        init_repo(repo_root);
+        run_git_in(repo_root, &["checkout", "-b", "alpha-sprint-1"]);
+        fs::write(repo_root.join("work.txt"), "agent work").expect("write work");
+        run_git_in(repo_root, &["add", "."]);
+        run_git_in(repo_root, &["commit", "-m", "agent work"]);
+        run_git_in(repo_root, &["checkout", "main"]);
+
+        let eligible = |enabled, agents, failures| {
+            fast_path_merge_eligible(
+                enabled,
+                agents,
+                failures,
+                repo_root,
+                "alpha-sprint-1",
+                "main",
+            )
+        };
+        assert!(eligible(true, 1, 0));
+        assert!(!eligible(false, 1, 0), "disabled by default config");
+        assert!(
+            !eligible(true, 2, 0),
+            "multiple agents need the merge agent"
+        );
+        assert!(!eligible(true, 1, 1), "merge failures need the merge agent");
+
+        // Once the target moves on, merging is no longer a fast-forward.
+        fs::write(repo_root.join("other.txt"), "target work").expect("write other");
+        run_git_in(repo_root, &["add", "."]);
+        run_git_in(repo_root, &["commit", "-m", "target work"]);
+        assert!(!eligible(true, 1, 0));
+    }
+
+    #[test]
+    fn test_merge_sprint_into_target_fast_path_skips_merge_agent() {
+        use swarm::log::NamedLogger;
+
+        with_temp_cwd(|| {
+            let repo_root = std::env::current_dir().expect("current dir");
+            init_repo(&repo_root);
+            run_git_in(&repo_root, &["checkout", "-b", "alpha-sprint-1"]);
+            fs::write(repo_root.join("work.txt"), "agent work").expect("write work");
+            run_git_in(&repo_root, &["add", "."]);
+            run_git_in(&repo_root, &["commit", "-m", "agent work"]);
+            run_git_in(&repo_root, &["checkout", "main"]);
+
+            let log_dir = repo_root.join("logs");
+            fs::create_dir_all(&log_dir).expect("create log dir");
+            let merge_logger = NamedLogger::new(&log_dir, "MergeAgent", "merge-agent.log");
+            let mut config = Config::default();
+            config.files_chat = repo_root.join("chat.md").to_string_lossy().to_string();
+            let captured = Arc::new(Mutex::new(None));
+            let engine = CapturingEngine::success("merged", Arc::clone(&captured));
+
+            assert!(fast_path_merge_eligible(
+                true,
+                1,
+                0,
+                &repo_root,
+                "alpha-sprint-1",
+                "main"
+            ));
+            merge_sprint_into_target(
+                &config,
+                &engine,
+                true,
+                "alpha-sprint-1",
+                "main",
+                &repo_root,
+                &[repo_root.join("tasks.md")],
+                &merge_logger,
+                &WarningCollector::new(),
+            )
+            .expect("fast-path merge");
+
+            assert!(
+                captured.lock().unwrap().is_none(),
+                "merge agent engine must not be called on the fast path"
+            );
+            assert!(swarm::worktree::branch_is_merged("alpha-sprint-1", "main").unwrap());
+            let chat_content = fs::read_to_string(&config.files_chat).expect("read chat");
+            assert!(chat_content.contains("skipping merge agent"));
+        });
+    }
+
     /// A no-op engine that claims to be Claude but does nothing.
     /// `ensure_feature_merged` with this engine skips the stub merge path,
     /// so the branch must be actually merged for verification to pass.
@@ -4175,38 +6339,34 @@ mod tests {
         }
     }
 
+    /// Repo on `main` with an unmerged `branch` adding `file`.
+    fn init_repo_with_feature_branch(repo_root: &Path, branch: &str, file: &str) {
+        init_repo(repo_root);
+        run_git_in(repo_root, &["checkout", "-b", branch]);
+        fs::write(repo_root.join(file), "feature content").expect("write feature file");
+        run_git_in(repo_root, &["add", "."]);
+        run_git_in(repo_root, &["commit", "-m", "feature commit"]);
+        run_git_in(repo_root, &["checkout", "main"]);
+    }
+
     #[test]
-    fn test_retry_merge_agent_succeeds_on_retry_with_stub() {
+    fn test_merge_retry_succeeds_with_stub() {
         use swarm::engine::StubEngine;
-        use swarm::log::NamedLogger;
 
         with_temp_cwd(|| {
             let repo_root = std::env::current_dir().expect("current dir");
-            init_repo(&repo_root);
-
-            // Create a feature branch with a diverging commit.
-            run_git_in(&repo_root, &["checkout", "-b", "feature-retry"]);
-            fs::write(repo_root.join("feature.txt"), "feature content")
-                .expect("write feature file");
-            run_git_in(&repo_root, &["add", "."]);
-            run_git_in(&repo_root, &["commit", "-m", "feature commit"]);
-            run_git_in(&repo_root, &["checkout", "main"]);
-
-            let log_dir = repo_root.join("logs");
-            fs::create_dir_all(&log_dir).expect("create log dir");
-            let merge_logger = NamedLogger::new(&log_dir, "MergeAgent", "merge-agent.log");
+            init_repo_with_feature_branch(&repo_root, "feature-retry", "feature.txt");
 
             // StubEngine ensure_feature_merged will perform a real git merge.
             let engine = StubEngine::new(repo_root.join("loop").to_string_lossy().to_string());
 
-            let result = retry_merge_agent(
+            let result = swarm::merge_agent::run_merge_agent_with_retry(
                 &engine,
                 "feature-retry",
                 "main",
                 &repo_root,
                 &[],
-                "first attempt failed: not merged",
-                &merge_logger,
+                1,
             );
 
             assert!(
@@ -4214,47 +6374,23 @@ mod tests {
                 "retry should succeed with stub engine, got: {:?}",
                 result
             );
-
-            // Verify the log contains attempt 2 success.
-            let log_content = fs::read_to_string(merge_logger.path).unwrap_or_default();
-            assert!(
-                log_content.contains("succeeded on retry (attempt 2)"),
-                "log should record retry success, got: {}",
-                log_content
-            );
         });
     }
 
     #[test]
-    fn test_retry_merge_agent_fails_on_both_attempts() {
-        use swarm::log::NamedLogger;
-
+    fn test_merge_retry_fails_on_both_attempts() {
         with_temp_cwd(|| {
             let repo_root = std::env::current_dir().expect("current dir");
-            init_repo(&repo_root);
-
-            // Create a feature branch with a diverging commit.
-            run_git_in(&repo_root, &["checkout", "-b", "feature-fail"]);
-            fs::write(repo_root.join("fail.txt"), "fail content").expect("write fail file");
-            run_git_in(&repo_root, &["add", "."]);
-            run_git_in(&repo_root, &["commit", "-m", "feature-fail commit"]);
-            run_git_in(&repo_root, &["checkout", "main"]);
-
-            let log_dir = repo_root.join("logs");
-            fs::create_dir_all(&log_dir).expect("create log dir");
-            let merge_logger = NamedLogger::new(&log_dir, "MergeAgent", "merge-agent.log");
+            init_repo_with_feature_branch(&repo_root, "feature-fail", "fail.txt");
 
             // NoopEngine does not actually merge, so ensure_feature_merged fails.
-            let engine = NoopEngine;
-
-            let result = retry_merge_agent(
-                &engine,
+            let result = swarm::merge_agent::run_merge_agent_with_retry(
+                &NoopEngine,
                 "feature-fail",
                 "main",
                 &repo_root,
                 &[],
-                "first attempt: branch not merged",
-                &merge_logger,
+                1,
             );
 
             assert!(result.is_err(), "retry should fail with noop engine");
@@ -4269,55 +6405,178 @@ mod tests {
                 "error should contain attempt 2 context, got: {}",
                 err
             );
-
-            // Verify log contains both attempt failures.
-            let log_content = fs::read_to_string(merge_logger.path).unwrap_or_default();
             assert!(
-                log_content.contains("Merge verification failed (attempt 2)"),
-                "log should record attempt 2 failure, got: {}",
-                log_content
+                !err.contains("attempt 3"),
+                "one retry should allow exactly two attempts, got: {}",
+                err
             );
         });
     }
 
     #[test]
-    fn test_retry_merge_agent_preserves_first_error_context() {
-        use swarm::log::NamedLogger;
-
+    fn test_merge_retry_preserves_first_error_context() {
         with_temp_cwd(|| {
             let repo_root = std::env::current_dir().expect("current dir");
-            init_repo(&repo_root);
-
-            run_git_in(&repo_root, &["checkout", "-b", "feature-ctx"]);
-            fs::write(repo_root.join("ctx.txt"), "ctx").expect("write ctx file");
-            run_git_in(&repo_root, &["add", "."]);
-            run_git_in(&repo_root, &["commit", "-m", "ctx commit"]);
-            run_git_in(&repo_root, &["checkout", "main"]);
-
-            let log_dir = repo_root.join("logs");
-            fs::create_dir_all(&log_dir).expect("create log dir");
-            let merge_logger = NamedLogger::new(&log_dir, "MergeAgent", "merge-agent.log");
+            init_repo_with_feature_branch(&repo_root, "feature-ctx", "ctx.txt");
 
-            let engine = NoopEngine;
-            let first_err_msg = "squash-merge detected: single-parent commit";
-
-            let result = retry_merge_agent(
-                &engine,
+            let result = swarm::merge_agent::run_merge_agent_with_retry(
+                &NoopEngine,
                 "feature-ctx",
                 "main",
                 &repo_root,
                 &[],
-                first_err_msg,
-                &merge_logger,
+                2,
             );
 
-            assert!(result.is_err());
             let err = result.unwrap_err();
             assert!(
-                err.contains(first_err_msg),
-                "error should preserve the original first_err message, got: {}",
+                err.contains("attempt 1: feature branch 'feature-ctx' is not merged into 'main'"),
+                "error should preserve the original verification failure, got: {}",
                 err
             );
+            assert!(err.contains("attempt 3"), "got: {}", err);
         });
     }
+
+    #[test]
+    fn test_sprint_range_lines_use_short_hashes() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let repo = temp.path();
+        init_repo(repo);
+        let start = crate::git::get_current_commit_in(repo).expect("start commit");
+        fs::write(repo.join("work.txt"), "agent work").expect("write work");
+        run_git_in(repo, &["add", "."]);
+        run_git_in(repo, &["commit", "-m", "agent work"]);
+        let end = crate::git::get_current_commit_in(repo).expect("end commit");
+
+        let range = team::SprintRange {
+            start_commit: start.clone(),
+            end_commit: end.clone(),
+            source_branch: "main".to_string(),
+            target_branch: "feature-1".to_string(),
+        };
+        let lines = sprint_range_lines(repo, &range);
+
+        let (from, to) = lines[0]
+            .strip_prefix("SPRINT_RANGE=")
+            .and_then(|r| r.split_once(".."))
+            .expect("SPRINT_RANGE line");
+        for (short, full) in [(from, &start), (to, &end)] {
+            assert!(short.len() >= 4 && short.len() < full.len());
+            assert!(short.chars().all(|c| c.is_ascii_hexdigit()));
+            assert!(full.starts_with(short));
+        }
+        assert_eq!(lines[1], "SPRINT_PR_RANGE=main..feature-1");
+    }
+
+    #[test]
+    fn test_agents_needed_for_accounts_for_priority_tiers() {
+        let list = swarm::task::TaskList::parse(
+            "- [ ] (P0) Fix 1\n- [ ] (P0) Fix 2\n- [ ] (P3) Chore 1\n- [ ] (P3) Chore 2\n- [ ] (P3) Chore 3\n",
+        );
+        assert_eq!(
+            agents_needed_for(&list, 3, &std::collections::BTreeMap::new(), 8),
+            2
+        );
+        let tiers = std::collections::BTreeMap::from([(0, 1), (3, 3)]);
+        assert_eq!(agents_needed_for(&list, 3, &tiers, 8), 3);
+    }
+
+    #[test]
+    fn test_agents_needed_for_uses_points_budget() {
+        let list = swarm::task::TaskList::parse(
+            "- [ ] Auth (8pts)
+- [ ] Billing (8pts)
+- [ ] Search (8pts)
+",
+        );
+        let no_tiers = std::collections::BTreeMap::new();
+        assert_eq!(agents_needed_for(&list, 3, &no_tiers, 8), 3);
+        assert_eq!(agents_needed_for(&list, 3, &no_tiers, 16), 2);
+        assert_eq!(agents_needed_for(&list, 3, &no_tiers, 0), 1);
+
+        // Without estimates the count-based need applies
+        let plain = swarm::task::TaskList::parse(
+            "- [ ] One
+- [ ] Two
+- [ ] Three
+",
+        );
+        assert_eq!(agents_needed_for(&plain, 3, &no_tiers, 1), 1);
+    }
+
+    #[test]
+    fn test_paused_agent_does_not_start_pending_task() {
+        let temp = tempfile::TempDir::new().expect("temp dir");
+        let pause_file = temp.path().join("pause");
+        let chat_path = temp.path().join("chat.md");
+        let chat_path = chat_path.to_str().unwrap().to_string();
+        swarm::pause::set_file(Some(pause_file.clone()));
+
+        let started = Arc::new(Mutex::new(Vec::new()));
+        let worker = {
+            let started = Arc::clone(&started);
+            let logger = swarm::log::AgentLogger::new(temp.path(), 'A', "Aaron");
+            let pause_file = pause_file.clone();
+            let chat_path = chat_path.clone();
+            std::thread::spawn(move || {
+                let warnings = WarningCollector::new();
+                for task in ["Task one", "Task two"] {
+                    wait_while_paused(&logger, &chat_path, "Aaron", &warnings);
+                    started.lock().unwrap().push(task);
+                    // The user pauses while the first task runs
+                    if task == "Task one" {
+                        swarm::pause::set_paused(&pause_file, true).unwrap();
+                    }
+                }
+            })
+        };
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        while !fs::read_to_string(&chat_path)
+            .unwrap_or_default()
+            .contains("Paused: waiting to start next task")
+        {
+            assert!(std::time::Instant::now() < deadline, "agent never paused");
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        std::thread::sleep(Duration::from_millis(500));
+        assert_eq!(*started.lock().unwrap(), vec!["Task one"]);
+
+        swarm::pause::set_paused(&pause_file, false).unwrap();
+        worker.join().unwrap();
+        assert_eq!(*started.lock().unwrap(), vec!["Task one", "Task two"]);
+        assert!(fs::read_to_string(&chat_path).unwrap().contains("Resumed"));
+        swarm::pause::set_file(None);
+    }
+
+    #[test]
+    fn test_average_state_times_split_working_from_waiting() {
+        use swarm::lifecycle::LifecycleTracker;
+
+        let mut tracker = LifecycleTracker::new();
+        assert_eq!(average_state_times(&tracker), None);
+
+        for initial in ['A', 'B'] {
+            tracker.register(initial, "Agent", "Task", "/wt");
+            tracker.start(initial);
+            tracker.complete(initial);
+            tracker.terminate(initial);
+        }
+        // Seconds spent assigned, working, and done for each agent
+        let base = chrono::Utc::now();
+        for (initial, steps) in [('A', [1, 10, 3]), ('B', [3, 20, 1])] {
+            let ctx = tracker.get_mut(initial).unwrap();
+            let mut at = base;
+            for (t, secs) in ctx.history.iter_mut().zip(steps.iter().chain([&0])) {
+                t.at = at;
+                at += chrono::Duration::seconds(*secs);
+            }
+        }
+
+        assert_eq!(
+            average_state_times(&tracker),
+            Some((Duration::from_secs(15), Duration::from_secs(4)))
+        );
+    }
 }
@@ -1,19 +1,22 @@
 //! Graceful shutdown handling for sprint interruption.
 //!
-//! Provides a way to handle Ctrl+C (SIGINT) gracefully during sprint execution,
-//! allowing the system to:
+//! Provides a way to handle Ctrl+C (SIGINT), and on unix SIGTERM and SIGHUP,
+//! gracefully during sprint execution, allowing the system to:
 //! - Stop spawning new agent tasks
 //! - Wait for currently running agents to complete (with timeout)
 //! - Update the task list properly
 //! - Release agent assignments
 //! - Commit the sprint state
 //!
+//! Ctrl+C force-quits after three presses; a second SIGTERM/SIGHUP aborts
+//! immediately.
+//!
 //! # Example
 //!
 //! ```ignore
 //! use swarm::shutdown;
 //!
-//! // Register the Ctrl+C handler at startup
+//! // Register the signal handlers at startup
 //! shutdown::register_handler();
 //!
 //! // Check if shutdown was requested
@@ -36,6 +39,10 @@ static INTERRUPT_COUNT: AtomicUsize = AtomicUsize::new(0);
 /// Maximum number of interrupts before force-quitting.
 const MAX_INTERRUPTS: usize = 3;
 
+/// Exit code when a second termination signal aborts the run (128 + SIGTERM).
+#[cfg(unix)]
+const TERMINATE_EXIT_CODE: i32 = 143;
+
 #[cfg(test)]
 static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
 
@@ -44,15 +51,19 @@ pub(crate) fn test_lock() -> std::sync::MutexGuard<'static, ()> {
     TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner())
 }
 
-/// Register the Ctrl+C handler.
+/// Register the Ctrl+C handler, and on unix the SIGTERM/SIGHUP handler.
 ///
-/// Should be called once at program startup. Sets up the signal handler
-/// to set the shutdown flag when Ctrl+C is pressed.
+/// Should be called once at program startup. Sets up the signal handlers
+/// to set the shutdown flag when Ctrl+C is pressed or the process is asked
+/// to terminate (e.g., by a CI runner or container stop).
 ///
 /// # Panics
 ///
 /// Panics if the handler cannot be registered (rare, usually only in tests).
 pub fn register_handler() -> Result<(), String> {
+    #[cfg(unix)]
+    register_terminate_handler()?;
+
     ctrlc::set_handler(move || {
         let count = INTERRUPT_COUNT.fetch_add(1, Ordering::SeqCst) + 1;
 
@@ -68,8 +79,7 @@ pub fn register_handler() -> Result<(), String> {
                 "(Press Ctrl+C {} more time(s) to force quit)",
                 MAX_INTERRUPTS - count
             );
-            SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
-            PROCESS_REGISTRY.kill_all();
+            begin_shutdown();
         } else {
             eprintln!(
                 "(Press Ctrl+C {} more time(s) to force quit)",
@@ -80,9 +90,48 @@ pub fn register_handler() -> Result<(), String> {
     .map_err(|e| format!("failed to register Ctrl+C handler: {}", e))
 }
 
+/// Handle SIGTERM and SIGHUP: the first begins a graceful shutdown, the
+/// second exits immediately.
+#[cfg(unix)]
+fn register_terminate_handler() -> Result<(), String> {
+    use signal_hook::consts::{SIGHUP, SIGTERM};
+    use signal_hook::flag;
+    use signal_hook::iterator::Signals;
+
+    let signals = [SIGTERM, SIGHUP];
+    let err = |e: std::io::Error| format!("failed to register SIGTERM handler: {}", e);
+
+    // Set inside the signal handler, so a quick second signal still aborts
+    let terminating = Arc::new(AtomicBool::new(false));
+    for signal in signals {
+        // Checked before the flag is set: exits only on the second signal
+        flag::register_conditional_shutdown(signal, TERMINATE_EXIT_CODE, Arc::clone(&terminating))
+            .map_err(err)?;
+        flag::register(signal, Arc::clone(&terminating)).map_err(err)?;
+    }
+
+    let mut signals = Signals::new(signals).map_err(err)?;
+    std::thread::spawn(move || {
+        if signals.forever().next().is_some() {
+            eprintln!("\n");
+            eprintln!("Termination signal received. Gracefully ending sprint...");
+            eprintln!("(Send it again to abort immediately)");
+            begin_shutdown();
+        }
+    });
+    Ok(())
+}
+
+/// Stop new work and kill running agent processes.
+fn begin_shutdown() {
+    SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
+    PROCESS_REGISTRY.kill_all();
+}
+
 /// Check if shutdown has been requested.
 ///
-/// Returns `true` if the user pressed Ctrl+C or if `request()` was called.
+/// Returns `true` if the user pressed Ctrl+C, a termination signal was
+/// received, or `request()` was called.
 pub fn requested() -> bool {
     SHUTDOWN_REQUESTED.load(Ordering::SeqCst)
 }
@@ -176,6 +225,28 @@ mod tests {
         assert!(!requested());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_sigterm_requests_shutdown() {
+        let _guard = test_lock();
+        reset();
+        register_terminate_handler().unwrap();
+
+        unsafe {
+            libc::kill(libc::getpid(), libc::SIGTERM);
+        }
+        // Handled on the signal thread
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while !requested() && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(requested());
+        // A SIGTERM is not counted as a Ctrl+C press
+        assert_eq!(interrupt_count(), 0);
+
+        reset();
+    }
+
     #[test]
     fn test_interrupt_count() {
         let _guard = test_lock();
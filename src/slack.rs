@@ -0,0 +1,100 @@
+//! Sprint summaries posted to a Slack incoming webhook (`slack.webhook_url`).
+//!
+//! The runner calls [`notify_sprint_completed`] once a sprint has finished.
+//! The message names the team and sprint, the completed and failed task
+//! counts, and the pull request URL when one was opened:
+//!
+//! ```text
+//! {"text": "greenfield sprint 3 finished: 4 completed, 1 failed\nPR: https://..."}
+//! ```
+//!
+//! The message is sent with [`webhook::post`], so Slack's `https://` URLs go
+//! through `curl`. Callers treat failures as warnings only.
+
+use crate::json::escape_json;
+use crate::webhook;
+
+/// Format a finished sprint as the Slack message body.
+pub fn format_sprint_message(
+    team: &str,
+    sprint: usize,
+    completed: usize,
+    failed: usize,
+    pr_url: Option<&str>,
+) -> String {
+    let mut text = format!(
+        "{} sprint {} finished: {} completed, {} failed",
+        team, sprint, completed, failed
+    );
+    if let Some(url) = pr_url {
+        text.push_str(&format!("\nPR: {}", url));
+    }
+    format!("{{\"text\": \"{}\"}}", escape_json(&text))
+}
+
+/// POST a finished sprint's summary to the Slack webhook at `url`.
+pub fn notify_sprint_completed(
+    url: &str,
+    team: &str,
+    sprint: usize,
+    completed: usize,
+    failed: usize,
+    pr_url: Option<&str>,
+) -> Result<(), String> {
+    let body = format_sprint_message(team, sprint, completed, failed, pr_url);
+    webhook::post(url, &body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn test_notify_posts_counts_and_pr_url() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/services/T0/B0/x", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.ends_with(b"}") {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+                .unwrap();
+            String::from_utf8_lossy(&request).to_string()
+        });
+
+        notify_sprint_completed(
+            &url,
+            "greenfield",
+            3,
+            4,
+            1,
+            Some("https://github.com/example/repo/pull/7"),
+        )
+        .unwrap();
+        let request = server.join().unwrap();
+
+        assert!(request.starts_with("POST /services/T0/B0/x HTTP/1.1\r\n"));
+        assert!(request.ends_with(
+            "\r\n\r\n{\"text\": \"greenfield sprint 3 finished: 4 completed, 1 failed\\nPR: https://github.com/example/repo/pull/7\"}"
+        ), "{}", request);
+    }
+
+    #[test]
+    fn test_format_sprint_message_without_pr() {
+        assert_eq!(
+            format_sprint_message("greenfield", 1, 0, 2, None),
+            "{\"text\": \"greenfield sprint 1 finished: 0 completed, 2 failed\"}"
+        );
+    }
+}
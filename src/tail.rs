@@ -8,16 +8,27 @@ use std::sync::{
 use std::thread;
 use std::time::Duration;
 
-use swarm::color;
+use swarm::{chat, color};
 
-/// Tail a file and stream appended content.
+/// Tail a chat file and stream appended content.
 pub(crate) fn tail_follow(
     path: &str,
     allow_missing: bool,
     stop: Option<Arc<AtomicBool>>,
 ) -> Result<(), String> {
-    let mut offset: u64 = 0;
+    tail_follow_from(path, allow_missing, stop, 0, |line| {
+        color::chat_line(&chat::display_line(line))
+    })
+}
 
+/// Tail a file from `offset` and stream appended lines through `render`.
+pub(crate) fn tail_follow_from(
+    path: &str,
+    allow_missing: bool,
+    stop: Option<Arc<AtomicBool>>,
+    mut offset: u64,
+    render: impl Fn(&str) -> String,
+) -> Result<(), String> {
     loop {
         if let Some(flag) = stop.as_ref() {
             if flag.load(Ordering::SeqCst) {
@@ -55,9 +66,8 @@ pub(crate) fn tail_follow(
             .map_err(|e| format!("failed to read {}: {}", path, e))?;
 
         if bytes > 0 {
-            // Colorize each line of the chat output
             for line in buffer.lines() {
-                println!("{}", color::chat_line(line));
+                println!("{}", render(line));
             }
             let _ = io::stdout().flush();
             offset += bytes as u64;
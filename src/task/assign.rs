@@ -1,4 +1,10 @@
+use std::collections::{BTreeMap, HashMap};
+
 use super::{Task, TaskList, TaskStatus};
+use crate::agent;
+
+/// Prefix of the per-task timeout annotation, e.g. `{timeout: 7200}`.
+const TIMEOUT_ANNOTATION_PREFIX: &str = "{timeout:";
 
 impl Task {
     /// Extract the task number from a leading "(#N)" prefix.
@@ -27,9 +33,87 @@ impl Task {
         after_prefix[..digits_len].parse::<usize>().ok()
     }
 
+    /// The priority tier from a `(P<n>)` marker (e.g. `(P0)`).
+    ///
+    /// Lower numbers are more urgent. Returns None for untagged tasks.
+    pub fn priority(&self) -> Option<u8> {
+        self.priority
+    }
+
+    /// Extract a story-point estimate from a `(<n>pts)` marker (e.g. `(3pts)`, `(1pt)`).
+    ///
+    /// Returns None for unestimated tasks.
+    pub fn estimate(&self) -> Option<u32> {
+        let desc = &self.description;
+        let mut search_from = 0;
+        while let Some(offset) = desc[search_from..].find('(') {
+            let start = search_from + offset + 1;
+            let rest = &desc[start..];
+            let digits_len = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+            let unit = rest[digits_len..].trim_start();
+            if digits_len > 0 && (unit.starts_with("pts)") || unit.starts_with("pt)")) {
+                if let Ok(points) = rest[..digits_len].parse::<u32>() {
+                    return Some(points);
+                }
+            }
+            search_from = start;
+        }
+        None
+    }
+
+    /// Extract lowercase skill tags from `[tag]` markers (e.g. `[frontend]`).
+    ///
+    /// Markdown links (`[text](url)`) are not tags.
+    pub fn tags(&self) -> Vec<String> {
+        let desc = &self.description;
+        let mut tags = Vec::new();
+        let mut search_from = 0;
+        while let Some(offset) = desc[search_from..].find('[') {
+            let start = search_from + offset + 1;
+            search_from = start;
+            let Some(len) = desc[start..].find(']') else {
+                break;
+            };
+            let tag = &desc[start..start + len];
+            let is_link = desc[start + len + 1..].starts_with('(');
+            if !tag.is_empty()
+                && !is_link
+                && tag
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                tags.push(tag.to_lowercase());
+            }
+        }
+        tags
+    }
+
+    /// Extract a per-task agent timeout from a `{timeout: <secs>}` annotation.
+    ///
+    /// Returns `Ok(None)` when the task has no annotation, and an error when
+    /// the value is not a positive number of seconds.
+    pub fn timeout_secs(&self) -> Result<Option<u64>, String> {
+        let desc = &self.description;
+        let Some(start) = desc.find(TIMEOUT_ANNOTATION_PREFIX) else {
+            return Ok(None);
+        };
+        let rest = &desc[start + TIMEOUT_ANNOTATION_PREFIX.len()..];
+        let end = rest
+            .find('}')
+            .ok_or_else(|| "unterminated {timeout: ...} annotation".to_string())?;
+        let value = rest[..end].trim();
+        match value.parse::<u64>() {
+            Ok(secs) if secs > 0 => Ok(Some(secs)),
+            _ => Err(format!(
+                "invalid task timeout '{}': expected a positive number of seconds",
+                value
+            )),
+        }
+    }
+
     /// Check if this task has blocking references.
     ///
-    /// Returns true if the task has `(blocked by #N)` in its description.
+    /// Returns true if the task has `(blocked by #N)` or `(needs: N)` in its description.
     /// Use `TaskList::is_task_blocked()` to check if blockers are actually incomplete.
     pub fn has_blockers(&self) -> bool {
         !self.blocking_task_numbers().is_empty()
@@ -37,26 +121,34 @@ impl Task {
 
     /// Extract blocking task numbers from the description.
     ///
-    /// Parses patterns like `(blocked by #1)` or `(blocked by #1, #2, #3)`.
+    /// Parses patterns like `(blocked by #1)` or `(blocked by #1, #2, #3)`,
+    /// and the shorter `(needs: 3, 5)` form where the `#` is optional.
     /// Returns a vector of task numbers that this task depends on.
     pub fn blocking_task_numbers(&self) -> Vec<usize> {
         let desc = &self.description;
 
         // Look for "(blocked by #N)" or "(blocked by #N, #M, ...)" pattern
-        if let Some(start) = desc.find("(blocked by ") {
-            let after_prefix = &desc[start + 12..]; // skip "(blocked by "
-            if let Some(end) = after_prefix.find(')') {
-                let refs = &after_prefix[..end];
-                // Parse comma-separated #N references
-                return refs
-                    .split(',')
-                    .filter_map(|part| {
-                        part.trim()
-                            .strip_prefix('#')
-                            .and_then(|num| num.parse::<usize>().ok())
-                    })
-                    .collect();
-            }
+        if let Some(refs) = annotation_body(desc, "(blocked by ") {
+            // Parse comma-separated #N references
+            return refs
+                .split(',')
+                .filter_map(|part| {
+                    part.trim()
+                        .strip_prefix('#')
+                        .and_then(|num| num.parse::<usize>().ok())
+                })
+                .collect();
+        }
+
+        // Look for "(needs: N)" or "(needs: N, M, ...)" pattern
+        if let Some(refs) = annotation_body(desc, "(needs:") {
+            return refs
+                .split(',')
+                .filter_map(|part| {
+                    let part = part.trim();
+                    part.strip_prefix('#').unwrap_or(part).parse::<usize>().ok()
+                })
+                .collect();
         }
 
         Vec::new()
@@ -78,10 +170,33 @@ impl Task {
     }
 
     /// Mark this task as completed.
+    ///
+    /// Any interrupted mark is dropped once the task is done.
     pub fn complete(&mut self, initial: char) {
+        self.clear_interrupted();
         self.status = TaskStatus::Completed(initial.to_ascii_uppercase());
     }
 
+    /// Check if this task was left unfinished by an interrupted sprint.
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted
+    }
+
+    /// Unassign this task and mark it interrupted.
+    /// Only affects tasks that are currently Assigned.
+    pub fn mark_interrupted(&mut self) {
+        if !matches!(self.status, TaskStatus::Assigned(_)) {
+            return;
+        }
+        self.status = TaskStatus::Unassigned;
+        self.interrupted = true;
+    }
+
+    /// Clear the interrupted mark.
+    pub fn clear_interrupted(&mut self) {
+        self.interrupted = false;
+    }
+
     /// Check if this task is assignable based on status alone.
     ///
     /// Note: For full blocking checks, use `TaskList::is_task_assignable()` which
@@ -115,6 +230,55 @@ impl TaskList {
         count
     }
 
+    /// Cancel the task at `index`, whatever its status.
+    ///
+    /// Returns false if there is no task at that index.
+    pub fn cancel(&mut self, index: usize) -> bool {
+        match self.tasks.get_mut(index) {
+            Some(task) => {
+                task.status = TaskStatus::Cancelled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cancel unassigned tasks by `(#N)` number.
+    ///
+    /// Tasks whose description is in `protected` (e.g. tasks assigned this
+    /// sprint) are left alone, as are assigned, completed, and already
+    /// cancelled tasks. Returns the numbers that were cancelled.
+    pub fn cancel_by_numbers(&mut self, numbers: &[usize], protected: &[&str]) -> Vec<usize> {
+        let mut cancelled = Vec::new();
+        for task in &mut self.tasks {
+            let Some(number) = task.task_number() else {
+                continue;
+            };
+            if numbers.contains(&number)
+                && task.status == TaskStatus::Unassigned
+                && !protected.contains(&task.description.as_str())
+            {
+                task.status = TaskStatus::Cancelled;
+                cancelled.push(number);
+            }
+        }
+        cancelled
+    }
+
+    /// Unassign all currently assigned tasks, marking each interrupted.
+    /// Used instead of `unassign_all` when `track_interrupted` is enabled.
+    /// Returns the number of tasks that were marked.
+    pub fn mark_interrupted_all(&mut self) -> usize {
+        let mut count = 0;
+        for task in &mut self.tasks {
+            if matches!(task.status, TaskStatus::Assigned(_)) {
+                task.mark_interrupted();
+                count += 1;
+            }
+        }
+        count
+    }
+
     /// Get count of assignable tasks (unassigned and not blocked).
     pub fn assignable_count(&self) -> usize {
         (0..self.tasks.len())
@@ -122,9 +286,38 @@ impl TaskList {
             .count()
     }
 
+    /// Indices of assignable tasks, most urgent `(P<n>)` tier first.
+    ///
+    /// Untagged tasks sort after every tier; ties keep backlog order.
+    pub fn assignable_indices_by_priority(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.tasks.len())
+            .filter(|&i| self.is_task_assignable(i))
+            .collect();
+        indices.sort_by_key(|&i| self.tasks[i].priority.unwrap_or(u8::MAX));
+        indices
+    }
+
+    /// Total story points of assignable tasks, counting unestimated ones as 1.
+    ///
+    /// Returns None when no assignable task carries a `(<n>pts)` estimate.
+    pub fn assignable_points(&self) -> Option<u32> {
+        let indices: Vec<usize> = (0..self.tasks.len())
+            .filter(|&i| self.is_task_assignable(i))
+            .collect();
+        if !indices.iter().any(|&i| self.tasks[i].estimate().is_some()) {
+            return None;
+        }
+        Some(
+            indices
+                .iter()
+                .map(|&i| self.tasks[i].estimate().unwrap_or(1))
+                .sum(),
+        )
+    }
+
     /// Check if a task at the given index is blocked.
     ///
-    /// A task is blocked if it has `(blocked by #N)` references where any
+    /// A task is blocked if it has `(blocked by #N)` or `(needs: N)` references where any
     /// referenced task is not yet completed.
     pub fn is_task_blocked(&self, task_index: usize) -> bool {
         let task = match self.tasks.get(task_index) {
@@ -160,7 +353,56 @@ impl TaskList {
             None => return false,
         };
 
-        matches!(task.status, TaskStatus::Unassigned) && !self.is_task_blocked(task_index)
+        matches!(task.status, TaskStatus::Unassigned)
+            && !self.is_task_blocked(task_index)
+            && !self.has_open_subtasks(task_index)
+    }
+
+    /// Indices of the subtasks nested under the task at `task_index`.
+    pub fn subtask_indices(&self, task_index: usize) -> Vec<usize> {
+        (0..self.tasks.len())
+            .filter(|&i| self.tasks[i].parent == Some(task_index))
+            .collect()
+    }
+
+    /// Whether the task at `task_index` has a subtask not yet completed or cancelled.
+    pub fn has_open_subtasks(&self, task_index: usize) -> bool {
+        self.subtask_indices(task_index).into_iter().any(|i| {
+            !matches!(
+                self.tasks[i].status,
+                TaskStatus::Completed(_) | TaskStatus::Cancelled
+            )
+        })
+    }
+
+    /// Complete every open parent task whose subtasks are all finished.
+    ///
+    /// The parent is credited to the agent that completed its last subtask.
+    /// Returns the number of parents completed.
+    pub fn complete_finished_parents(&mut self) -> usize {
+        let mut completed = 0;
+        for index in 0..self.tasks.len() {
+            if matches!(
+                self.tasks[index].status,
+                TaskStatus::Completed(_) | TaskStatus::Cancelled
+            ) || self.has_open_subtasks(index)
+            {
+                continue;
+            }
+            let last_initial = self
+                .subtask_indices(index)
+                .into_iter()
+                .filter_map(|i| match self.tasks[i].status {
+                    TaskStatus::Completed(initial) => Some(initial),
+                    _ => None,
+                })
+                .next_back();
+            if let Some(initial) = last_initial {
+                self.tasks[index].complete(initial);
+                completed += 1;
+            }
+        }
+        completed
     }
 
     /// Get tasks assigned to a specific agent.
@@ -176,30 +418,146 @@ impl TaskList {
     ///
     /// Returns the number of tasks assigned.
     pub fn assign_sprint(&mut self, agent_initials: &[char], tasks_per_agent: usize) -> usize {
+        self.assign_sprint_with_tiers(agent_initials, tasks_per_agent, &BTreeMap::new())
+    }
+
+    /// Assign tasks to agents, capping each agent by the priority tiers it holds.
+    ///
+    /// Tasks are taken most urgent tier first (see `assignable_indices_by_priority`).
+    /// `tier_limits` maps a `(P<n>)` tier to its tasks-per-agent value; untagged
+    /// or unlisted tiers use `tasks_per_agent`. An agent's capacity is the
+    /// smallest limit among the tasks assigned to it, so an agent holding a P0
+    /// task stays focused while P3 batches can share an agent.
+    ///
+    /// When any task carries a `(<n>pts)` estimate, each task goes to the agent
+    /// with capacity holding the fewest points (unestimated tasks count as 1).
+    /// Otherwise agents are filled in order.
+    ///
+    /// Agents whose roster skills match a task's `[tag]` are preferred; see
+    /// `assign_sprint_with_skills`.
+    ///
+    /// Returns the number of tasks assigned.
+    pub fn assign_sprint_with_tiers(
+        &mut self,
+        agent_initials: &[char],
+        tasks_per_agent: usize,
+        tier_limits: &BTreeMap<u8, usize>,
+    ) -> usize {
+        let roster = agent::roster();
+        self.assign_sprint_with_skills(
+            agent_initials,
+            tasks_per_agent,
+            tier_limits,
+            roster.skill_map(),
+        )
+    }
+
+    /// `assign_sprint_with_tiers` with an explicit skills map.
+    ///
+    /// A task tagged `[skill]` goes to an agent with that skill and capacity
+    /// when there is one; otherwise, like untagged tasks, it goes to any agent
+    /// with capacity.
+    pub fn assign_sprint_with_skills(
+        &mut self,
+        agent_initials: &[char],
+        tasks_per_agent: usize,
+        tier_limits: &BTreeMap<u8, usize>,
+        skills: &BTreeMap<char, Vec<String>>,
+    ) -> usize {
         let mut assigned = 0;
-        let mut agent_task_count: std::collections::HashMap<char, usize> =
-            std::collections::HashMap::new();
+        // (tasks assigned, capacity) per agent
+        let mut agent_load: HashMap<char, (usize, usize)> = HashMap::new();
+        let mut agent_points: HashMap<char, u32> = HashMap::new();
+        let indices = self.assignable_indices_by_priority();
+        let balance_points = indices.iter().any(|&i| self.tasks[i].estimate().is_some());
 
-        for task_idx in 0..self.tasks.len() {
-            if !self.is_task_assignable(task_idx) {
-                continue;
-            }
+        for task_idx in indices {
+            let limit = tier_limit(
+                self.tasks[task_idx].priority(),
+                tasks_per_agent,
+                tier_limits,
+            );
+
+            let tags = self.tasks[task_idx].tags();
+            let skilled: Vec<char> = agent_initials
+                .iter()
+                .copied()
+                .filter(|initial| {
+                    skills
+                        .get(initial)
+                        .is_some_and(|s| s.iter().any(|skill| tags.contains(skill)))
+                })
+                .collect();
 
-            // Find an agent with capacity
-            for &initial in agent_initials {
-                let count = agent_task_count.entry(initial).or_insert(0);
-                if *count < tasks_per_agent {
-                    self.tasks[task_idx].assign(initial);
-                    *count += 1;
-                    assigned += 1;
+            // Find an agent with capacity, the least loaded one when balancing
+            // points, trying agents skilled for the task first
+            let mut chosen: Option<(char, u32)> = None;
+            for candidates in [skilled.as_slice(), agent_initials] {
+                for &initial in candidates {
+                    let (count, capacity) =
+                        agent_load.get(&initial).copied().unwrap_or((0, usize::MAX));
+                    if count >= capacity.min(limit) {
+                        continue;
+                    }
+                    let points = agent_points.get(&initial).copied().unwrap_or(0);
+                    if chosen.is_none_or(|(_, best)| points < best) {
+                        chosen = Some((initial, points));
+                    }
+                    if !balance_points {
+                        break;
+                    }
+                }
+                if chosen.is_some() {
                     break;
                 }
             }
+
+            if let Some((initial, _)) = chosen {
+                self.tasks[task_idx].assign(initial);
+                let (count, capacity) = agent_load.entry(initial).or_insert((0, usize::MAX));
+                *count += 1;
+                *capacity = (*capacity).min(limit);
+                *agent_points.entry(initial).or_insert(0) +=
+                    self.tasks[task_idx].estimate().unwrap_or(1);
+                assigned += 1;
+            }
         }
 
         assigned
     }
 
+    /// Unassign tasks that exceed an agent's priority-tier capacity.
+    ///
+    /// Applies the same capacity rule as `assign_sprint_with_tiers` to
+    /// assignments made elsewhere (e.g. LLM planning), keeping each agent's
+    /// earliest tasks. Returns the number of tasks unassigned.
+    pub fn enforce_tier_limits(
+        &mut self,
+        tasks_per_agent: usize,
+        tier_limits: &BTreeMap<u8, usize>,
+    ) -> usize {
+        let mut agent_load: HashMap<char, (usize, usize)> = HashMap::new();
+        let mut removed = 0;
+
+        for task in &mut self.tasks {
+            let TaskStatus::Assigned(initial) = task.status else {
+                continue;
+            };
+            let limit = tier_limit(task.priority(), tasks_per_agent, tier_limits);
+            let (count, capacity) = agent_load.entry(initial).or_insert((0, usize::MAX));
+            let effective = (*capacity).min(limit);
+            if *count < effective {
+                *count += 1;
+                *capacity = effective;
+            } else {
+                task.unassign();
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
     /// Check if a task with the given number (from #N format) is completed.
     ///
     /// Looks for tasks with `(#N)` in their description.
@@ -214,3 +572,23 @@ impl TaskList {
         false
     }
 }
+
+/// Text between `prefix` and the next `)` in `desc`, if the annotation is present.
+fn annotation_body<'a>(desc: &'a str, prefix: &str) -> Option<&'a str> {
+    let start = desc.find(prefix)? + prefix.len();
+    let after_prefix = &desc[start..];
+    let end = after_prefix.find(')')?;
+    Some(&after_prefix[..end])
+}
+
+/// Tasks-per-agent for a task's priority tier (never less than one).
+fn tier_limit(
+    priority: Option<u8>,
+    tasks_per_agent: usize,
+    tier_limits: &BTreeMap<u8, usize>,
+) -> usize {
+    priority
+        .and_then(|p| tier_limits.get(&p).copied())
+        .unwrap_or(tasks_per_agent)
+        .max(1)
+}
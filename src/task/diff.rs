@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use super::{Task, TaskList, TaskStatus};
+
+/// Changes between two versions of a task list, e.g. before and after a sprint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskDiff {
+    /// Tasks only in the newer list.
+    pub added: Vec<Task>,
+    /// Tasks that became completed.
+    pub completed: Vec<Task>,
+    /// Tasks that became assigned to an agent.
+    pub assigned: Vec<Task>,
+    /// Tasks only in the older list.
+    pub removed: Vec<Task>,
+}
+
+impl TaskDiff {
+    /// Whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.completed.is_empty()
+            && self.assigned.is_empty()
+            && self.removed.is_empty()
+    }
+}
+
+/// Identity used to match a task across versions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum TaskKey {
+    /// `(#N)` task number, stable even if the description is edited.
+    Number(usize),
+    /// Trimmed description for unnumbered tasks.
+    Description(String),
+}
+
+fn task_key(task: &Task) -> TaskKey {
+    match task.task_number() {
+        Some(number) => TaskKey::Number(number),
+        None => TaskKey::Description(task.description.trim().to_string()),
+    }
+}
+
+impl TaskList {
+    /// Compare this (older) list with `other` (newer).
+    ///
+    /// Tasks are matched by `(#N)` number when present, otherwise by
+    /// description. Tasks in the returned diff are taken from `other`, except
+    /// `removed`, which come from `self`.
+    pub fn diff(&self, other: &TaskList) -> TaskDiff {
+        let old: HashMap<TaskKey, &Task> = self.tasks.iter().map(|t| (task_key(t), t)).collect();
+        let new_keys: HashMap<TaskKey, &Task> =
+            other.tasks.iter().map(|t| (task_key(t), t)).collect();
+
+        let mut diff = TaskDiff::default();
+        for task in &other.tasks {
+            let Some(before) = old.get(&task_key(task)) else {
+                diff.added.push(task.clone());
+                continue;
+            };
+            match (before.status, task.status) {
+                (TaskStatus::Completed(_), TaskStatus::Completed(_)) => {}
+                (_, TaskStatus::Completed(_)) => diff.completed.push(task.clone()),
+                (TaskStatus::Assigned(_), TaskStatus::Assigned(_)) => {}
+                (_, TaskStatus::Assigned(_)) => diff.assigned.push(task.clone()),
+                _ => {}
+            }
+        }
+        diff.removed = self
+            .tasks
+            .iter()
+            .filter(|t| !new_keys.contains_key(&task_key(t)))
+            .cloned()
+            .collect();
+        diff
+    }
+}
@@ -4,12 +4,35 @@
 //! - `- [ ] Task description` (unassigned)
 //! - `- [A] Task description` (assigned to Aaron)
 //! - `- [x] Task description (A)` (completed by Aaron)
+//! - `- [x] (A) Task description` (completed by Aaron, prefix style)
+//! - `- [-] Task description` (cancelled)
+//! - `- [ ] Task description {interrupted}` (cut off mid-sprint, still assignable)
+//! - `- [ ] Task description {timeout: 7200}` (per-task agent timeout in seconds)
+//!
+//! Headings, prose, and fenced code blocks between tasks are kept verbatim;
+//! checklist lines inside a code fence are not tasks.
+//!
+//! Indented checklist lines are subtasks of the preceding top-level task. A
+//! parent only becomes assignable once its subtasks are done, and is completed
+//! automatically when the last one is.
+//!
+//! `merge_prerequisites` maps the `(blocked by #N)` links between tasks in
+//! the same sprint, so their branches can be merged in dependency order.
+//!
+//! Several files can be merged into one pool with `TaskList::parse_sources`;
+//! each task keeps its source so changes are written back to the right file.
 
 mod assign;
+mod diff;
 mod model;
+mod order;
 mod parse;
+mod sources;
 
 #[cfg(test)]
 mod tests;
 
-pub use model::{Task, TaskList, TaskStatus};
+pub use diff::TaskDiff;
+pub use model::{CompletionStyle, Task, TaskList, TaskSource, TaskStatus, INTERRUPTED_ANNOTATION};
+pub use order::merge_prerequisites;
+pub use parse::insert_after_last_task;
@@ -1,3 +1,11 @@
+use std::path::PathBuf;
+
+use super::parse::parse_priority;
+use crate::agent;
+
+/// Marker written after tasks that were in flight when a sprint was cut off.
+pub const INTERRUPTED_ANNOTATION: &str = "{interrupted}";
+
 /// Task status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TaskStatus {
@@ -7,6 +15,39 @@ pub enum TaskStatus {
     Assigned(char),
     /// Completed by an agent: `- [x] ... (A)`
     Completed(char),
+    /// No longer needed: `- [-] ...`
+    Cancelled,
+}
+
+/// How completed tasks are written back to TASKS.md.
+///
+/// Parsing accepts every style regardless of this setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompletionStyle {
+    /// `- [x] description (A)`
+    #[default]
+    Suffix,
+    /// `- [x] (A) description`
+    Prefix,
+}
+
+impl CompletionStyle {
+    /// Parse completion style from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "suffix" => Some(Self::Suffix),
+            "prefix" => Some(Self::Prefix),
+            _ => None,
+        }
+    }
+
+    /// Convert to string representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Suffix => "suffix",
+            Self::Prefix => "prefix",
+        }
+    }
 }
 
 /// A single task parsed from TASKS.md.
@@ -21,26 +62,62 @@ pub struct Task {
     /// Lines that appeared before this task (section headings, blank lines, etc.).
     /// This preserves document structure when writing back.
     pub prefix: Vec<String>,
+    /// Index into `TaskList::sources` for lists merged from several files.
+    pub source: Option<usize>,
+    /// Leading whitespace of the task line, preserved on write.
+    pub indent: String,
+    /// For an indented subtask, the index of its parent in `TaskList::tasks`.
+    pub parent: Option<usize>,
+    /// Left unfinished by an interrupted sprint (`track_interrupted`); written
+    /// as a trailing `{interrupted}` marker, not part of `description`.
+    pub interrupted: bool,
+    /// Tier of the `(P<n>)` marker in `description`, parsed once on load.
+    pub priority: Option<u8>,
 }
 
 impl Task {
     /// Create a new unassigned task.
     pub fn new(description: impl Into<String>) -> Self {
+        let description = description.into();
         Self {
-            description: description.into(),
+            priority: parse_priority(&description),
+            description,
             status: TaskStatus::Unassigned,
             line_number: 0,
             prefix: Vec::new(),
+            source: None,
+            indent: String::new(),
+            parent: None,
+            interrupted: false,
         }
     }
 
     /// Format this task as a TASKS.md line.
     pub fn to_line(&self) -> String {
-        match self.status {
-            TaskStatus::Unassigned => format!("- [ ] {}", self.description),
-            TaskStatus::Assigned(initial) => format!("- [{}] {}", initial, self.description),
-            TaskStatus::Completed(initial) => format!("- [x] {} ({})", self.description, initial),
-        }
+        self.to_line_with(CompletionStyle::default())
+    }
+
+    /// Format this task as a TASKS.md line using the given completion style.
+    pub fn to_line_with(&self, style: CompletionStyle) -> String {
+        let description = if self.interrupted {
+            format!("{} {}", self.description, INTERRUPTED_ANNOTATION)
+        } else {
+            self.description.clone()
+        };
+        let line = match (self.status, style) {
+            (TaskStatus::Unassigned, _) => format!("- [ ] {}", description),
+            (TaskStatus::Assigned(initial), _) => {
+                format!("- [{}] {}", agent::id(initial), description)
+            }
+            (TaskStatus::Completed(initial), CompletionStyle::Suffix) => {
+                format!("- [x] {} ({})", description, agent::id(initial))
+            }
+            (TaskStatus::Completed(initial), CompletionStyle::Prefix) => {
+                format!("- [x] ({}) {}", agent::id(initial), description)
+            }
+            (TaskStatus::Cancelled, _) => format!("- [-] {}", description),
+        };
+        format!("{}{}", self.indent, line)
     }
 }
 
@@ -53,6 +130,21 @@ pub struct TaskList {
     pub tasks: Vec<Task>,
     /// Footer lines after the last task (preserved on write).
     pub footer: Vec<String>,
+    /// Originating files when merged via `parse_sources` (empty for a single file).
+    pub sources: Vec<TaskSource>,
+    /// How completed tasks are serialized by `to_string`.
+    pub completion_style: CompletionStyle,
+}
+
+/// A task file contributing to a merged `TaskList`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskSource {
+    /// Path the file was read from (and is written back to).
+    pub path: PathBuf,
+    /// Header lines before the file's first task.
+    pub header: Vec<String>,
+    /// Footer lines after the file's last task.
+    pub footer: Vec<String>,
 }
 
 impl TaskList {
@@ -79,4 +171,17 @@ impl TaskList {
             .filter(|t| matches!(t.status, TaskStatus::Completed(_)))
             .count()
     }
+
+    /// Get count of tasks left unfinished by an interrupted sprint.
+    pub fn interrupted_count(&self) -> usize {
+        self.tasks.iter().filter(|t| t.interrupted).count()
+    }
+
+    /// Get count of cancelled tasks.
+    pub fn cancelled_count(&self) -> usize {
+        self.tasks
+            .iter()
+            .filter(|t| matches!(t.status, TaskStatus::Cancelled))
+            .count()
+    }
 }
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use super::Task;
+
+/// Prerequisites of each sprint task among the other tasks in the same sprint.
+///
+/// `agent_tasks` holds each agent's tasks in the order the agent works them.
+/// A task's prerequisites are the sprint tasks its `(blocked by #N)` or
+/// `(needs: N)` annotation names; blockers outside the sprint are ignored.
+///
+/// Merging in this order can only make progress if no task ends up waiting on
+/// itself, so a cycle through the dependencies (and each agent's own task
+/// order, since an agent merges one task before starting the next) is an error.
+pub fn merge_prerequisites(
+    agent_tasks: &[(char, Vec<String>)],
+) -> Result<HashMap<String, Vec<String>>, String> {
+    let by_number: HashMap<usize, &String> = agent_tasks
+        .iter()
+        .flat_map(|(_, tasks)| tasks)
+        .filter_map(|desc| Task::new(desc.as_str()).task_number().map(|n| (n, desc)))
+        .collect();
+
+    let mut prerequisites: HashMap<String, Vec<String>> = HashMap::new();
+    for desc in agent_tasks.iter().flat_map(|(_, tasks)| tasks) {
+        let deps: Vec<String> = Task::new(desc.as_str())
+            .blocking_task_numbers()
+            .into_iter()
+            .filter_map(|n| by_number.get(&n).copied())
+            .filter(|dep| *dep != desc)
+            .cloned()
+            .collect();
+        if !deps.is_empty() {
+            prerequisites.insert(desc.clone(), deps);
+        }
+    }
+
+    // Everything a task waits on before it can merge: its prerequisites, and
+    // the agent's previous task.
+    let mut waits_on: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (_, tasks) in agent_tasks {
+        for (i, desc) in tasks.iter().enumerate() {
+            let entry = waits_on.entry(desc.as_str()).or_default();
+            if let Some(deps) = prerequisites.get(desc) {
+                entry.extend(deps.iter().map(String::as_str));
+            }
+            if i > 0 {
+                entry.push(tasks[i - 1].as_str());
+            }
+        }
+    }
+
+    let mut visited: HashMap<&str, bool> = HashMap::new(); // true once fully explored
+    for (_, tasks) in agent_tasks {
+        for desc in tasks {
+            let mut path = Vec::new();
+            if let Some(cycle) = find_cycle(desc, &waits_on, &mut visited, &mut path) {
+                return Err(format!(
+                    "task dependency cycle prevents ordered merge: {}",
+                    cycle.join(" -> ")
+                ));
+            }
+        }
+    }
+
+    Ok(prerequisites)
+}
+
+/// Depth-first search from `node`, returning the tasks on a cycle if one is reachable.
+fn find_cycle<'a>(
+    node: &'a str,
+    waits_on: &HashMap<&'a str, Vec<&'a str>>,
+    visited: &mut HashMap<&'a str, bool>,
+    path: &mut Vec<&'a str>,
+) -> Option<Vec<&'a str>> {
+    match visited.get(node) {
+        Some(true) => return None,
+        Some(false) => {
+            let start = path.iter().position(|n| *n == node).unwrap_or(0);
+            let mut cycle = path[start..].to_vec();
+            cycle.push(node);
+            return Some(cycle);
+        }
+        None => {}
+    }
+
+    visited.insert(node, false);
+    path.push(node);
+    for next in waits_on.get(node).into_iter().flatten() {
+        if let Some(cycle) = find_cycle(next, waits_on, visited, path) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+    visited.insert(node, true);
+    None
+}
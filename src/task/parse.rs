@@ -2,22 +2,43 @@ use std::fmt;
 
 use crate::agent;
 
-use super::{Task, TaskList, TaskStatus};
+use super::{CompletionStyle, Task, TaskList, TaskStatus, INTERRUPTED_ANNOTATION};
 
 impl TaskList {
     /// Parse a TASKS.md file content.
     ///
     /// Preserves document structure by storing non-task lines (section headings,
     /// blank lines) as prefixes on the following task. This ensures roundtrip
-    /// fidelity when writing back.
+    /// fidelity when writing back. The completion style is taken from the first
+    /// attributed completed task so files keep their layout when rewritten.
     pub fn parse(content: &str) -> Self {
         let mut header = Vec::new();
         let mut tasks = Vec::new();
         let mut seen_task = false;
         let mut pending_prefix: Vec<String> = Vec::new();
+        let mut completion_style = None;
+        let mut in_fence = false;
+        let mut last_top_level: Option<usize> = None;
 
         for (line_num, line) in content.lines().enumerate() {
-            if let Some(mut task) = parse_task_line(line, line_num + 1) {
+            let task = if in_fence {
+                None
+            } else {
+                parse_task_line(line, line_num + 1)
+            };
+            if is_fence_line(line) {
+                in_fence = !in_fence;
+            }
+            if let Some(mut task) = task {
+                if completion_style.is_none() {
+                    completion_style = detect_completion_style(&task, line);
+                }
+                // Indented tasks are subtasks of the preceding top-level task
+                if task.indent.is_empty() {
+                    last_top_level = Some(tasks.len());
+                } else {
+                    task.parent = last_top_level;
+                }
                 // Attach any pending prefix lines to this task
                 task.prefix = std::mem::take(&mut pending_prefix);
                 tasks.push(task);
@@ -39,6 +60,8 @@ impl TaskList {
             header,
             tasks,
             footer,
+            sources: Vec::new(),
+            completion_style: completion_style.unwrap_or_default(),
         }
     }
 }
@@ -56,7 +79,7 @@ impl fmt::Display for TaskList {
             for prefix_line in &task.prefix {
                 lines.push(prefix_line.clone());
             }
-            lines.push(task.to_line());
+            lines.push(task.to_line_with(self.completion_style));
         }
 
         for footer_line in &self.footer {
@@ -71,9 +94,63 @@ impl fmt::Display for TaskList {
     }
 }
 
+/// Whether `line` opens or closes a fenced code block.
+fn is_fence_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+/// Insert `block` right after the last task line in `content`.
+///
+/// Trailing notes or prose after the task list stay at the end of the file.
+/// When nothing but blank lines follows the last task (or there are no tasks),
+/// `block` is appended at the end, separated by a blank line.
+pub fn insert_after_last_task(content: &str, block: &str) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut in_fence = false;
+    let mut last_task = None;
+    for (idx, line) in lines.iter().enumerate() {
+        if !in_fence && parse_task_line(line, idx + 1).is_some() {
+            last_task = Some(idx);
+        }
+        if is_fence_line(line) {
+            in_fence = !in_fence;
+        }
+    }
+
+    let block = block.trim_end_matches('\n');
+    let trailing = match last_task {
+        Some(idx) => &lines[idx + 1..],
+        None => &lines[..],
+    };
+    if trailing.iter().all(|line| line.trim().is_empty()) {
+        let mut result = content.to_string();
+        if !result.is_empty() && !result.ends_with('\n') {
+            result.push('\n');
+        }
+        result.push('\n');
+        result.push_str(block);
+        result.push('\n');
+        return result;
+    }
+
+    let split = last_task.map_or(0, |idx| idx + 1);
+    let mut out: Vec<&str> = lines[..split].to_vec();
+    out.push("");
+    out.push(block);
+    out.extend_from_slice(&lines[split..]);
+    let mut result = out.join("\n");
+    result.push('\n');
+    result
+}
+
 /// Parse a single task line.
+///
+/// Leading whitespace is kept as the task's indent so nested subtasks
+/// round-trip unchanged.
 pub(super) fn parse_task_line(line: &str, line_number: usize) -> Option<Task> {
     let trimmed = line.trim();
+    let indent = &line[..line.len() - line.trim_start().len()];
 
     // Must start with "- ["
     if !trimmed.starts_with("- [") {
@@ -93,47 +170,105 @@ pub(super) fn parse_task_line(line: &str, line_number: usize) -> Option<Task> {
     let (status, description) = if marker == " " {
         // Unassigned: - [ ] description
         (TaskStatus::Unassigned, rest.to_string())
+    } else if marker == "-" {
+        // Cancelled: - [-] description
+        (TaskStatus::Cancelled, rest.to_string())
     } else if marker == "x" || marker == "X" {
-        // Completed: - [x] description (A)
-        // Extract the agent initial from the end
-        if let Some(agent_start) = rest.rfind(" (") {
-            if rest.ends_with(')') {
-                let agent_part = &rest[agent_start + 2..rest.len() - 1];
-                if agent_part.len() == 1 {
-                    let initial = agent_part.chars().next()?;
-                    if agent::is_valid_initial(initial) {
-                        let desc = rest[..agent_start].to_string();
-                        return Some(Task {
-                            description: desc,
-                            status: TaskStatus::Completed(initial.to_ascii_uppercase()),
-                            line_number,
-                            prefix: Vec::new(),
-                        });
-                    }
-                }
-            }
-        }
-        // Completed but no agent attribution (treat as completed by unknown)
-        (TaskStatus::Completed('?'), rest.to_string())
-    } else if marker.len() == 1 {
-        // Assigned: - [A] description
-        let initial = marker.chars().next()?;
-        if agent::is_valid_initial(initial) {
+        // Completed: - [x] description (A)  or  - [x] (A) description
+        if let Some((initial, desc)) = parse_suffix_initial(rest) {
+            (TaskStatus::Completed(initial), desc.to_string())
+        } else if let Some((initial, desc)) = parse_prefix_initial(rest) {
             (
-                TaskStatus::Assigned(initial.to_ascii_uppercase()),
-                rest.to_string(),
+                TaskStatus::Completed(initial.to_ascii_uppercase()),
+                desc.to_string(),
             )
         } else {
-            return None;
+            // Completed but no agent attribution (treat as completed by unknown)
+            (TaskStatus::Completed('?'), rest.to_string())
         }
+    } else if let Some(initial) = parse_agent_id(marker) {
+        // Assigned: - [A] description  or  - [AA] description
+        (TaskStatus::Assigned(initial), rest.to_string())
     } else {
         return None;
     };
 
+    let (description, interrupted) = match description.strip_suffix(INTERRUPTED_ANNOTATION) {
+        Some(desc) => (desc.trim_end().to_string(), true),
+        None => (description, false),
+    };
+
     Some(Task {
+        priority: parse_priority(&description),
         description,
         status,
         line_number,
         prefix: Vec::new(),
+        source: None,
+        indent: indent.to_string(),
+        parent: None,
+        interrupted,
     })
 }
+
+/// The tier of the first `(P<n>)` marker in a task description.
+pub(super) fn parse_priority(description: &str) -> Option<u8> {
+    let mut search_from = 0;
+    while let Some(offset) = description[search_from..].find("(P") {
+        let start = search_from + offset + 2;
+        let rest = &description[start..];
+        let digits_len = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits_len > 0 && rest[digits_len..].starts_with(')') {
+            if let Ok(priority) = rest[..digits_len].parse::<u8>() {
+                return Some(priority);
+            }
+        }
+        search_from = start;
+    }
+    None
+}
+
+/// Split a trailing ` (A)` agent attribution off a completed task description.
+fn parse_suffix_initial(rest: &str) -> Option<(char, &str)> {
+    let agent_start = rest.rfind(" (")?;
+    let agent_part = rest[agent_start + 2..].strip_suffix(')')?;
+    Some((parse_agent_id(agent_part)?, &rest[..agent_start]))
+}
+
+/// Parse an agent id from a task marker or attribution.
+///
+/// Any single letter is accepted; longer ids (`AA`, `AB`, ...) only when the
+/// roster has that agent, so descriptions ending in e.g. `(UI)` stay intact.
+fn parse_agent_id(text: &str) -> Option<char> {
+    let initial = agent::initial_from_id(text)?;
+    if text.len() == 1 || agent::name_from_initial(initial).is_some() {
+        Some(initial)
+    } else {
+        None
+    }
+}
+
+/// Split a leading `(A) ` agent attribution off a completed task description.
+fn parse_prefix_initial(rest: &str) -> Option<(char, &str)> {
+    let inner = rest.strip_prefix('(')?;
+    let (id, desc) = inner.split_once(") ")?;
+    let initial = parse_agent_id(id)?;
+    if !desc.trim().is_empty() {
+        Some((initial, desc.trim_start()))
+    } else {
+        None
+    }
+}
+
+/// Completion style used by an attributed completed task's source line.
+fn detect_completion_style(task: &Task, line: &str) -> Option<CompletionStyle> {
+    if !matches!(task.status, TaskStatus::Completed(initial) if initial != '?') {
+        return None;
+    }
+    let prefix_line = task.to_line_with(CompletionStyle::Prefix);
+    if line.trim().eq_ignore_ascii_case(prefix_line.trim()) {
+        Some(CompletionStyle::Prefix)
+    } else {
+        Some(CompletionStyle::Suffix)
+    }
+}
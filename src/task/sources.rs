@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{TaskList, TaskSource};
+
+impl TaskList {
+    /// Merge several task files into one pool.
+    ///
+    /// Each task remembers which file it came from (`Task::source`), so
+    /// `render_sources` can write changes back to the originating file.
+    /// The merged `header`/`footer` come from the first/last file.
+    pub fn parse_sources(files: &[(PathBuf, String)]) -> Self {
+        let mut merged = TaskList::default();
+
+        for (index, (path, content)) in files.iter().enumerate() {
+            let parsed = TaskList::parse(content);
+            merged.sources.push(TaskSource {
+                path: path.clone(),
+                header: parsed.header,
+                footer: parsed.footer,
+            });
+            let base = merged.tasks.len();
+            merged
+                .tasks
+                .extend(parsed.tasks.into_iter().map(|mut task| {
+                    task.source = Some(index);
+                    task.parent = task.parent.map(|parent| parent + base);
+                    task
+                }));
+        }
+
+        if let Some(first) = merged.sources.first() {
+            merged.header = first.header.clone();
+        }
+        if let Some(last) = merged.sources.last() {
+            merged.footer = last.footer.clone();
+        }
+        merged
+    }
+
+    /// Read and merge task files from disk.
+    pub fn load_files<P: AsRef<Path>>(paths: &[P]) -> Result<Self, String> {
+        let mut files = Vec::with_capacity(paths.len());
+        for path in paths {
+            let path = path.as_ref();
+            let content = fs::read_to_string(path)
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+            files.push((path.to_path_buf(), content));
+        }
+        Ok(Self::parse_sources(&files))
+    }
+
+    /// Render each source file's content with only the tasks that came from it.
+    ///
+    /// Tasks without a source (e.g. added after merging) are written to the
+    /// first file.
+    pub fn render_sources(&self) -> Vec<(PathBuf, String)> {
+        self.sources
+            .iter()
+            .enumerate()
+            .map(|(index, source)| {
+                let tasks = self
+                    .tasks
+                    .iter()
+                    .filter(|t| t.source.unwrap_or(0) == index)
+                    .cloned()
+                    .collect();
+                let file = TaskList {
+                    header: source.header.clone(),
+                    tasks,
+                    footer: source.footer.clone(),
+                    sources: Vec::new(),
+                    completion_style: self.completion_style,
+                };
+                (source.path.clone(), file.to_string())
+            })
+            .collect()
+    }
+
+    /// Point each source at a new path, in order, e.g. the same files in
+    /// another worktree. Extra paths are ignored.
+    pub fn relocate_sources<P: AsRef<Path>>(&mut self, paths: &[P]) {
+        for (source, path) in self.sources.iter_mut().zip(paths) {
+            source.path = path.as_ref().to_path_buf();
+        }
+    }
+
+    /// Write every source file back to disk.
+    pub fn save_sources(&self) -> Result<(), String> {
+        for (path, content) in self.render_sources() {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+            }
+            fs::write(&path, content)
+                .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+}
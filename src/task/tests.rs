@@ -135,6 +135,57 @@ fn test_task_to_line() {
     assert_eq!(task.to_line(), "- [x] Write tests (A)");
 }
 
+#[test]
+fn test_task_to_line_with_prefix_style() {
+    let mut task = Task::new("Write tests");
+    task.complete('A');
+    assert_eq!(
+        task.to_line_with(CompletionStyle::Prefix),
+        "- [x] (A) Write tests"
+    );
+    assert_eq!(
+        task.to_line_with(CompletionStyle::Suffix),
+        "- [x] Write tests (A)"
+    );
+}
+
+#[test]
+fn test_parse_completed_prefix_style() {
+    let task = parse_task_line("- [x] (b) Write tests", 1).unwrap();
+    assert_eq!(task.description, "Write tests");
+    assert_eq!(task.status, TaskStatus::Completed('B'));
+}
+
+#[test]
+fn test_completion_style_parse() {
+    assert_eq!(
+        CompletionStyle::parse("prefix"),
+        Some(CompletionStyle::Prefix)
+    );
+    assert_eq!(
+        CompletionStyle::parse(" Suffix "),
+        Some(CompletionStyle::Suffix)
+    );
+    assert_eq!(CompletionStyle::parse("middle"), None);
+}
+
+#[test]
+fn test_tasklist_round_trips_each_completion_style() {
+    let suffix = "# Tasks\n- [x] Task 1 (A)\n- [ ] Task 2\n";
+    let prefix = "# Tasks\n- [x] (A) Task 1\n- [ ] Task 2\n";
+
+    let list = TaskList::parse(suffix);
+    assert_eq!(list.completion_style, CompletionStyle::Suffix);
+    assert_eq!(list.to_string(), suffix);
+
+    let mut list = TaskList::parse(prefix);
+    assert_eq!(list.completion_style, CompletionStyle::Prefix);
+    assert_eq!(list.to_string(), prefix);
+
+    list.completion_style = CompletionStyle::Suffix;
+    assert_eq!(list.to_string(), suffix);
+}
+
 #[test]
 fn test_tasklist_parse() {
     let content = "# Tasks\n\n- [ ] Task 1\n- [A] Task 2\n- [x] Task 3 (B)\n";
@@ -193,6 +244,41 @@ fn test_tasklist_assign_sprint() {
     assert_eq!(list.tasks[4].status, TaskStatus::Unassigned);
 }
 
+#[test]
+fn test_tasklist_assign_sprint_with_tiers() {
+    let content = "- [ ] (P0) Hotfix 1\n- [ ] (P0) Hotfix 2\n\
+- [ ] (P3) Chore 1\n- [ ] (P3) Chore 2\n- [ ] (P3) Chore 3\n\
+- [ ] (P3) Chore 4\n- [ ] (P3) Chore 5\n- [ ] (P3) Chore 6\n- [ ] Untagged\n";
+    let mut list = TaskList::parse(content);
+    let tiers = std::collections::BTreeMap::from([(0, 1), (3, 3)]);
+
+    let assigned = list.assign_sprint_with_tiers(&['A', 'B', 'C', 'D', 'E'], 2, &tiers);
+    assert_eq!(assigned, 9);
+
+    // P0 tasks fill one per agent.
+    assert_eq!(list.tasks_for_agent('A').len(), 1);
+    assert_eq!(list.tasks_for_agent('B').len(), 1);
+    // P3 tasks fill three per agent in the same sprint.
+    assert_eq!(list.tasks_for_agent('C').len(), 3);
+    assert_eq!(list.tasks_for_agent('D').len(), 3);
+    // Untagged tasks fall back to the global value.
+    assert_eq!(list.tasks[8].status, TaskStatus::Assigned('E'));
+}
+
+#[test]
+fn test_tasklist_enforce_tier_limits_trims_overflow() {
+    let content = "- [A] (P3) Chore 1\n- [A] (P0) Hotfix\n- [A] (P3) Chore 2\n- [B] (P3) Chore 3\n";
+    let mut list = TaskList::parse(content);
+    let tiers = std::collections::BTreeMap::from([(0, 1), (3, 3)]);
+
+    // A already batches P3 work, so the P0 task is released for a focused agent.
+    assert_eq!(list.enforce_tier_limits(2, &tiers), 1);
+    assert_eq!(list.tasks[0].status, TaskStatus::Assigned('A'));
+    assert_eq!(list.tasks[1].status, TaskStatus::Unassigned);
+    assert_eq!(list.tasks[2].status, TaskStatus::Assigned('A'));
+    assert_eq!(list.tasks[3].status, TaskStatus::Assigned('B'));
+}
+
 #[test]
 fn test_tasklist_assign_sprint_skips_blocked() {
     // Task 1 is blocked by incomplete task 3
@@ -207,6 +293,47 @@ fn test_tasklist_assign_sprint_skips_blocked() {
     assert_eq!(list.tasks[2].status, TaskStatus::Assigned('A'));
 }
 
+#[test]
+fn test_task_needs_annotation() {
+    let task = Task::new("(#6) Ship release (needs: 3, #5)");
+    assert_eq!(task.blocking_task_numbers(), vec![3, 5]);
+    assert!(task.has_blockers());
+    assert!(Task::new("(#1) Needs review")
+        .blocking_task_numbers()
+        .is_empty());
+}
+
+#[test]
+fn test_tasklist_needs_chain_unblocks_across_sprints() {
+    let content = "- [ ] (#1) Schema
+- [ ] (#2) API (needs: 1)
+- [ ] (#3) UI (needs: 2)
+";
+    let mut list = TaskList::parse(content);
+    assert_eq!(list.to_string(), content);
+
+    // Sprint 1: only the head of the chain is assignable.
+    assert_eq!(list.assignable_count(), 1);
+    assert_eq!(list.assign_sprint(&['A', 'B'], 2), 1);
+    assert_eq!(list.tasks[0].status, TaskStatus::Assigned('A'));
+    list.tasks[0].complete('A');
+
+    // Sprint 2: #2 unblocks; #3 still waits on it.
+    assert_eq!(list.assignable_count(), 1);
+    assert_eq!(list.assign_sprint(&['A', 'B'], 2), 1);
+    assert_eq!(list.tasks[1].status, TaskStatus::Assigned('A'));
+    assert_eq!(list.tasks[2].status, TaskStatus::Unassigned);
+    list.tasks[1].complete('A');
+
+    // Sprint 3: the tail of the chain unblocks.
+    assert_eq!(list.assign_sprint(&['A', 'B'], 2), 1);
+    assert_eq!(list.tasks[2].status, TaskStatus::Assigned('A'));
+    assert_eq!(
+        list.to_string(),
+        "- [x] (#1) Schema (A)\n- [x] (#2) API (needs: 1) (A)\n- [A] (#3) UI (needs: 2)\n"
+    );
+}
+
 #[test]
 fn test_tasklist_is_task_blocked_dynamic() {
     // Task #2 is blocked by #1, which is not completed
@@ -357,6 +484,107 @@ fn test_tasklist_unassign_all() {
     assert_eq!(list.completed_count(), 1); // Task 4 still completed
 }
 
+#[test]
+fn test_task_priority_marker() {
+    assert_eq!(Task::new("(P0) Fix outage").priority(), Some(0));
+    assert_eq!(Task::new("(#4) (P2) Add cache").priority(), Some(2));
+    assert_eq!(Task::new("Open (PR) for review").priority(), None);
+    assert_eq!(Task::new("No tag").priority(), None);
+}
+
+#[test]
+fn test_parse_mixed_priority_list_round_trips() {
+    let content = "# Tasks
+- [ ] Untagged chore
+- [ ] (P2) Add cache
+- [x] (P1) Fix auth bug (A)
+- [ ] (P1) Rotate keys
+- [B] (P0) Outage
+";
+    let list = TaskList::parse(content);
+
+    let priorities: Vec<Option<u8>> = list.tasks.iter().map(Task::priority).collect();
+    assert_eq!(priorities, vec![None, Some(2), Some(1), Some(1), Some(0)]);
+    assert_eq!(list.to_string(), content);
+    assert_eq!(list.assignable_count(), 3);
+}
+
+#[test]
+fn test_assignable_indices_by_priority_sorts_untagged_last() {
+    let content = "- [ ] Untagged
+- [ ] (P2) Cache
+- [x] (P0) Done (A)
+- [ ] (P1) Auth
+- [ ] (P1) Keys
+- [ ] Also untagged
+";
+    let list = TaskList::parse(content);
+    assert_eq!(list.assignable_indices_by_priority(), vec![3, 4, 1, 0, 5]);
+}
+
+#[test]
+fn test_tasklist_assign_sprint_prefers_urgent_tiers() {
+    let content = "- [ ] Untagged
+- [ ] (P2) Cache
+- [ ] (P1) Auth
+";
+    let mut list = TaskList::parse(content);
+
+    assert_eq!(list.assign_sprint(&['A', 'B'], 1), 2);
+    assert_eq!(list.tasks[0].status, TaskStatus::Unassigned);
+    assert_eq!(list.tasks[1].status, TaskStatus::Assigned('B'));
+    assert_eq!(list.tasks[2].status, TaskStatus::Assigned('A'));
+}
+
+#[test]
+fn test_task_timeout_annotation() {
+    assert_eq!(
+        Task::new("(#3) Big refactor {timeout: 7200}").timeout_secs(),
+        Ok(Some(7200))
+    );
+    assert_eq!(
+        Task::new("{timeout:60} Quick fix").timeout_secs(),
+        Ok(Some(60))
+    );
+    assert_eq!(Task::new("No annotation").timeout_secs(), Ok(None));
+    assert!(Task::new("Task {timeout: 0}").timeout_secs().is_err());
+    assert!(Task::new("Task {timeout: -5}").timeout_secs().is_err());
+    assert!(Task::new("Task {timeout: soon}").timeout_secs().is_err());
+    assert!(Task::new("Task {timeout: 10").timeout_secs().is_err());
+}
+
+#[test]
+fn test_tasklist_mark_interrupted_all() {
+    let content = "- [ ] Task 1\n- [A] Task 2\n- [x] Task 3 (C)\n";
+    let mut list = TaskList::parse(content);
+
+    let marked = list.mark_interrupted_all();
+    assert_eq!(marked, 1);
+    assert_eq!(list.assigned_count(), 0);
+    assert_eq!(list.interrupted_count(), 1);
+    assert_eq!(list.tasks[1].to_line(), "- [ ] Task 2 {interrupted}");
+    assert_eq!(list.tasks[1].description, "Task 2");
+    assert!(list.is_task_assignable(1));
+
+    // Re-marking after reassignment does not duplicate the annotation
+    list.tasks[1].assign('B');
+    list.mark_interrupted_all();
+    assert_eq!(list.tasks[1].to_line(), "- [ ] Task 2 {interrupted}");
+}
+
+#[test]
+fn test_task_complete_clears_interrupted() {
+    let mut list = TaskList::parse("- [A] Task 1 {interrupted}\n");
+    assert!(list.tasks[0].is_interrupted());
+    assert_eq!(list.tasks[0].description, "Task 1");
+    assert_eq!(list.to_string(), "- [A] Task 1 {interrupted}\n");
+
+    list.tasks[0].complete('A');
+    assert!(!list.tasks[0].is_interrupted());
+    assert_eq!(list.tasks[0].to_line(), "- [x] Task 1 (A)");
+    assert_eq!(list.interrupted_count(), 0);
+}
+
 #[test]
 fn test_tasklist_preserves_section_headings() {
     // Test that section headings between tasks are preserved
@@ -522,3 +750,381 @@ fn test_tasklist_complex_structure_roundtrip() {
         "jobs table should be under Schema"
     );
 }
+
+#[test]
+fn test_parse_sources_merges_files_with_provenance() {
+    use std::path::PathBuf;
+
+    let files = vec![
+        (
+            PathBuf::from("features.md"),
+            "# Features\n\n- [ ] Add login\n- [x] Add signup (A)\n".to_string(),
+        ),
+        (
+            PathBuf::from("bugs.md"),
+            "# Bugs\n\n- [ ] Fix crash\n\nNotes at the end\n".to_string(),
+        ),
+    ];
+    let list = TaskList::parse_sources(&files);
+
+    assert_eq!(list.tasks.len(), 3);
+    assert_eq!(list.sources.len(), 2);
+    assert_eq!(list.tasks[0].source, Some(0));
+    assert_eq!(list.tasks[2].source, Some(1));
+    assert_eq!(list.assignable_count(), 2);
+
+    // Unchanged lists render back to the original contents.
+    let rendered = list.render_sources();
+    assert_eq!(rendered[0].1, files[0].1);
+    assert_eq!(rendered[1].1, files[1].1);
+}
+
+#[test]
+fn test_render_sources_writes_completion_to_originating_file() {
+    use std::path::PathBuf;
+
+    let files = vec![
+        (
+            PathBuf::from("features.md"),
+            "- [ ] Add login\n".to_string(),
+        ),
+        (
+            PathBuf::from("bugs.md"),
+            "# Bugs\n- [B] Fix crash\n".to_string(),
+        ),
+    ];
+    let mut list = TaskList::parse_sources(&files);
+    let crash = list
+        .tasks
+        .iter()
+        .position(|t| t.description == "Fix crash")
+        .unwrap();
+    list.tasks[crash].complete('B');
+
+    let rendered = list.render_sources();
+    assert_eq!(rendered[0].0, PathBuf::from("features.md"));
+    assert_eq!(rendered[0].1, "- [ ] Add login\n");
+    assert_eq!(rendered[1].0, PathBuf::from("bugs.md"));
+    assert_eq!(rendered[1].1, "# Bugs\n- [x] Fix crash (B)\n");
+}
+
+#[test]
+fn test_relocate_sources_saves_each_file_under_new_root() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let src = temp.path().join("src");
+    let dst = temp.path().join("worktree");
+    std::fs::create_dir_all(&src).unwrap();
+    let paths = [src.join("tasks.md"), src.join("bugs.md")];
+    std::fs::write(&paths[0], "- [ ] Add login\n").unwrap();
+    std::fs::write(&paths[1], "- [A] Fix crash\n").unwrap();
+
+    let mut list = TaskList::load_files(&paths).unwrap();
+    list.tasks[1].complete('A');
+    let moved = [dst.join("tasks.md"), dst.join("docs").join("bugs.md")];
+    list.relocate_sources(&moved);
+    list.save_sources().unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(&moved[0]).unwrap(),
+        "- [ ] Add login\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&moved[1]).unwrap(),
+        "- [x] Fix crash (A)\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&paths[1]).unwrap(),
+        "- [A] Fix crash\n"
+    );
+}
+
+#[test]
+fn test_cancelled_task_round_trip() {
+    let content = "# Tasks\n- [-] Obsolete migration\n- [ ] Keep going\n";
+    let list = TaskList::parse(content);
+    assert_eq!(list.tasks[0].status, TaskStatus::Cancelled);
+    assert_eq!(list.tasks[0].description, "Obsolete migration");
+    assert_eq!(list.to_string(), content);
+}
+
+#[test]
+fn test_cancel_by_numbers_skips_protected_and_non_open_tasks() {
+    let content = "- [ ] (#1) Old idea\n- [ ] (#2) Retried this sprint\n- [A] (#3) In progress\n- [x] (#4) Done (B)\n- [ ] (#5) Still needed\n";
+    let mut list = TaskList::parse(content);
+    let cancelled = list.cancel_by_numbers(&[1, 2, 3, 4, 9], &["(#2) Retried this sprint"]);
+    assert_eq!(cancelled, vec![1]);
+    assert_eq!(list.tasks[0].status, TaskStatus::Cancelled);
+    assert_eq!(list.tasks[1].status, TaskStatus::Unassigned);
+    assert_eq!(list.tasks[2].status, TaskStatus::Assigned('A'));
+    assert_eq!(list.tasks[3].status, TaskStatus::Completed('B'));
+    assert_eq!(list.tasks[4].status, TaskStatus::Unassigned);
+}
+
+#[test]
+fn test_cancelled_tasks_excluded_from_counts_and_assignment() {
+    let content = "- [ ] First\n- [A] Second\n- [ ] Third\n";
+    let mut list = TaskList::parse(content);
+    assert!(list.cancel(0));
+    assert!(list.cancel(1));
+    assert!(!list.cancel(9));
+
+    assert_eq!(list.tasks.len(), 3);
+    assert_eq!(list.cancelled_count(), 2);
+    assert_eq!(list.assignable_count(), 1);
+    assert_eq!(list.unassigned_count(), 1);
+    assert_eq!(list.assigned_count(), 0);
+
+    assert_eq!(list.unassign_all(), 0);
+    assert_eq!(list.mark_interrupted_all(), 0);
+    assert_eq!(list.assign_sprint(&['A', 'B'], 2), 1);
+    assert_eq!(list.tasks[0].status, TaskStatus::Cancelled);
+    assert_eq!(list.tasks[1].status, TaskStatus::Cancelled);
+    assert_eq!(list.tasks[2].status, TaskStatus::Assigned('A'));
+    assert_eq!(list.to_string(), "- [-] First\n- [-] Second\n- [A] Third\n");
+}
+
+#[test]
+fn test_parse_preserves_interstitial_markdown() {
+    let content = "# Backlog\n\nIntro prose.\n\n## Auth\n- [ ] Fix login\n\n\
+Notes about auth:\n\n```sh\n- [ ] not a task, just shell output\ncargo test\n```\n\n\
+## UI\n- [A] Polish buttons\n- [x] Ship icons (B)\n\nTrailing thoughts.\n";
+    let list = TaskList::parse(content);
+
+    assert_eq!(list.tasks.len(), 3);
+    assert_eq!(list.assignable_count(), 1);
+    assert_eq!(list.to_string(), content);
+}
+
+#[test]
+fn test_insert_after_last_task_keeps_trailing_prose_last() {
+    let content = "# Tasks\n- [ ] One\n- [x] Two (A)\n\n## Notes\nKeep me at the end.\n";
+    let updated = insert_after_last_task(content, "## Follow-up\n- [ ] (#3) Three\n");
+    assert_eq!(
+        updated,
+        "# Tasks\n- [ ] One\n- [x] Two (A)\n\n## Follow-up\n- [ ] (#3) Three\n\n## Notes\nKeep me at the end.\n"
+    );
+    assert_eq!(TaskList::parse(&updated).tasks.len(), 3);
+}
+
+#[test]
+fn test_insert_after_last_task_appends_without_trailing_prose() {
+    let content = "# Tasks\n- [ ] One\n";
+    assert_eq!(
+        insert_after_last_task(content, "## Follow-up\n- [ ] Two\n"),
+        "# Tasks\n- [ ] One\n\n## Follow-up\n- [ ] Two\n"
+    );
+}
+
+#[test]
+fn test_insert_after_last_task_ignores_fenced_checklists() {
+    let content = "- [ ] One\n\n```\n- [ ] example\n```\n";
+    assert_eq!(
+        insert_after_last_task(content, "- [ ] Two"),
+        "- [ ] One\n\n- [ ] Two\n\n```\n- [ ] example\n```\n"
+    );
+}
+
+#[test]
+fn test_diff_reports_each_change_category() {
+    let before = TaskList::parse(
+        "- [ ] (#1) Build API\n- [ ] (#2) Write docs\n- [ ] Drop legacy flag\n- [x] (#4) Setup CI (A)\n",
+    );
+    let after = TaskList::parse(
+        "- [x] (#1) Build API (A)\n- [B] (#2) Write docs\n- [x] (#4) Setup CI (A)\n- [ ] (#5) Add metrics\n",
+    );
+    let diff = before.diff(&after);
+
+    let descriptions =
+        |tasks: &[Task]| -> Vec<String> { tasks.iter().map(|t| t.description.clone()).collect() };
+    assert_eq!(descriptions(&diff.added), vec!["(#5) Add metrics"]);
+    assert_eq!(descriptions(&diff.completed), vec!["(#1) Build API"]);
+    assert_eq!(descriptions(&diff.assigned), vec!["(#2) Write docs"]);
+    assert_eq!(descriptions(&diff.removed), vec!["Drop legacy flag"]);
+}
+
+#[test]
+fn test_diff_assigned_to_completed_counts_as_completed() {
+    let before = TaskList::parse("- [A] Fix auth\n");
+    let after = TaskList::parse("- [x] Fix auth (A)\n");
+    let diff = before.diff(&after);
+
+    assert_eq!(diff.completed.len(), 1);
+    assert_eq!(diff.completed[0].status, TaskStatus::Completed('A'));
+    assert!(diff.assigned.is_empty());
+    assert!(diff.added.is_empty() && diff.removed.is_empty());
+}
+
+#[test]
+fn test_diff_matches_numbered_tasks_by_id() {
+    let before = TaskList::parse("- [ ] (#7) Old wording\n");
+    let after = TaskList::parse("- [C] (#7) New wording\n");
+    let diff = before.diff(&after);
+
+    assert_eq!(diff.assigned.len(), 1);
+    assert!(diff.added.is_empty() && diff.removed.is_empty());
+    assert!(before.diff(&before).is_empty());
+}
+
+#[test]
+fn test_indented_tasks_are_subtasks_of_preceding_task() {
+    let content = "- [ ] Build auth\n  - [x] Add login (A)\n  - [x] Add logout (B)\n  - [ ] Add reset\n- [ ] Write docs\n";
+    let mut list = TaskList::parse(content);
+
+    assert_eq!(list.tasks.len(), 5);
+    assert_eq!(list.subtask_indices(0), vec![1, 2, 3]);
+    assert_eq!(list.tasks[4].parent, None);
+    assert!(list.has_open_subtasks(0));
+    assert!(!list.is_task_assignable(0));
+    assert!(list.is_task_assignable(3));
+    assert_eq!(list.complete_finished_parents(), 0);
+
+    list.tasks[3].complete('C');
+    assert!(list.is_task_assignable(0));
+    assert_eq!(list.complete_finished_parents(), 1);
+    assert_eq!(list.tasks[0].status, TaskStatus::Completed('C'));
+    assert_eq!(list.tasks[4].status, TaskStatus::Unassigned);
+}
+
+#[test]
+fn test_indented_tasks_round_trip_exactly() {
+    let content = "# Tasks\n\n- [ ] Build auth\n  - [ ] Add login\n    - [A] Hash passwords\n\t- [x] Add logout (B)\n- [ ] Write docs\n";
+    let list = TaskList::parse(content);
+    assert_eq!(list.tasks[2].parent, Some(0));
+    assert_eq!(list.to_string(), content);
+}
+
+#[test]
+fn test_task_estimate_marker() {
+    assert_eq!(Task::new("Build auth (3pts)").estimate(), Some(3));
+    assert_eq!(Task::new("(#2) (1pt) Fix typo").estimate(), Some(1));
+    assert_eq!(Task::new("Migrate (13 pts) (P1)").estimate(), Some(13));
+    assert_eq!(Task::new("Support (3) points").estimate(), None);
+    assert_eq!(Task::new("No estimate").estimate(), None);
+}
+
+#[test]
+fn test_assign_sprint_balances_points_instead_of_count() {
+    let content = "- [ ] Auth (8pts)
+- [ ] Billing (8pts)
+- [ ] Search (8pts)
+- [ ] Typo (1pt)
+- [ ] Lint (1pt)
+- [ ] Readme (1pt)
+";
+    let points_for = |list: &TaskList, initial: char| -> u32 {
+        list.tasks_for_agent(initial)
+            .iter()
+            .map(|t| t.estimate().unwrap_or(1))
+            .sum()
+    };
+
+    // Count-based balancing fills agents in order: A takes all the big tasks.
+    let plain = content.replace("(8pts)", "").replace("(1pt)", "");
+    let mut by_count = TaskList::parse(&plain);
+    assert_eq!(by_count.assign_sprint(&['A', 'B'], 3), 6);
+    assert_eq!(by_count.tasks_for_agent('A').len(), 3);
+    assert!(by_count
+        .tasks_for_agent('A')
+        .iter()
+        .all(|t| ["Auth", "Billing", "Search"].contains(&t.description.trim())));
+
+    // Point-based balancing spreads the big tasks across both agents.
+    let mut by_points = TaskList::parse(content);
+    assert_eq!(by_points.assignable_points(), Some(27));
+    assert_eq!(by_points.assign_sprint(&['A', 'B'], 3), 6);
+    assert_eq!(points_for(&by_points, 'A'), 17);
+    assert_eq!(points_for(&by_points, 'B'), 10);
+    assert_eq!(by_points.tasks_for_agent('A').len(), 3);
+    assert_eq!(by_points.tasks_for_agent('B').len(), 3);
+}
+
+#[test]
+fn test_assignable_points_counts_unestimated_tasks_as_one() {
+    let list = TaskList::parse("- [ ] Auth (5pts)\n- [ ] Docs\n- [x] Done (3pts) (A)\n");
+    assert_eq!(list.assignable_points(), Some(6));
+    assert_eq!(TaskList::parse("- [ ] Docs\n").assignable_points(), None);
+}
+
+#[test]
+fn test_merge_prerequisites_links_sprint_dependencies() {
+    let agent_tasks = vec![
+        (
+            'A',
+            vec!["(#2) Add login form (blocked by #1, #9)".to_string()],
+        ),
+        ('B', vec!["(#1) Add auth API".to_string()]),
+    ];
+
+    let prerequisites = merge_prerequisites(&agent_tasks).unwrap();
+    // #9 is not in the sprint, so only #1 gates the merge
+    assert_eq!(
+        prerequisites.get("(#2) Add login form (blocked by #1, #9)"),
+        Some(&vec!["(#1) Add auth API".to_string()])
+    );
+    assert!(!prerequisites.contains_key("(#1) Add auth API"));
+}
+
+#[test]
+fn test_merge_prerequisites_rejects_cycles() {
+    let agent_tasks = vec![
+        ('A', vec!["(#1) First (blocked by #2)".to_string()]),
+        ('B', vec!["(#2) Second (needs: 1)".to_string()]),
+    ];
+    let err = merge_prerequisites(&agent_tasks).unwrap_err();
+    assert!(err.contains("cycle"), "{}", err);
+    assert!(err.contains("(#1) First"), "{}", err);
+    assert!(err.contains("(#2) Second"), "{}", err);
+
+    // Aaron merges #2 before starting #1, but #2 waits for #1
+    let agent_tasks = vec![(
+        'A',
+        vec![
+            "(#2) Second (blocked by #1)".to_string(),
+            "(#1) First".to_string(),
+        ],
+    )];
+    assert!(merge_prerequisites(&agent_tasks).is_err());
+}
+
+#[test]
+fn test_task_tags() {
+    assert_eq!(
+        Task::new("[Frontend] Fix nav [ui] (#3)").tags(),
+        vec!["frontend", "ui"]
+    );
+    assert!(Task::new("See [docs](https://example.com)")
+        .tags()
+        .is_empty());
+    assert!(Task::new("Handle [two words] and []").tags().is_empty());
+}
+
+#[test]
+fn test_assign_sprint_prefers_skilled_agent() {
+    let content = "- [ ] Backend API\n- [ ] [frontend] Login page\n- [ ] Database index\n";
+    let skills = std::collections::BTreeMap::from([('C', vec!["frontend".to_string()])]);
+    let mut list = TaskList::parse(content);
+
+    let assigned =
+        list.assign_sprint_with_skills(&['A', 'B', 'C'], 1, &Default::default(), &skills);
+    assert_eq!(assigned, 3);
+    // The tagged task goes to the frontend agent even though A and B come first.
+    assert_eq!(list.tasks[1].status, TaskStatus::Assigned('C'));
+    // Untagged tasks distribute normally among the rest.
+    assert_eq!(list.tasks[0].status, TaskStatus::Assigned('A'));
+    assert_eq!(list.tasks[2].status, TaskStatus::Assigned('B'));
+}
+
+#[test]
+fn test_assign_sprint_skill_falls_back_when_skilled_agent_is_full() {
+    let content =
+        "- [ ] [frontend] Login page\n- [ ] [frontend] Signup page\n- [ ] [design] Logo\n";
+    let skills = std::collections::BTreeMap::from([('B', vec!["frontend".to_string()])]);
+    let mut list = TaskList::parse(content);
+
+    list.assign_sprint_with_skills(&['A', 'B'], 1, &Default::default(), &skills);
+    assert_eq!(list.tasks[0].status, TaskStatus::Assigned('B'));
+    // B is at capacity, so the second frontend task goes to whoever has room.
+    assert_eq!(list.tasks[1].status, TaskStatus::Assigned('A'));
+    // No agent has the tag, and both are full.
+    assert_eq!(list.tasks[2].status, TaskStatus::Unassigned);
+}
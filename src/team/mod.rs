@@ -4,7 +4,9 @@
 //! - Its own specs.md, prompt.md, tasks.md
 //! - Its own loop/, worktrees/ directories
 //! - Its own chat.md
+//! - Optional config.toml overriding swarm.toml settings for that team
 //! - Optional legacy sprint-history.json / team-state.json files
+//! - Its own usage.json token totals
 //! - Runtime-local state under `.swarm-hug/<team>/runs/<target>/`
 
 mod runtime_state;
@@ -12,11 +14,13 @@ mod sprint_history;
 mod state;
 #[allow(clippy::module_inception)]
 mod team;
+mod usage;
 
 pub use runtime_state::RuntimeStatePaths;
-pub use sprint_history::SprintHistory;
+pub use sprint_history::{SprintHistory, SprintRange};
 pub use state::TeamState;
 pub use team::Team;
+pub use usage::{SprintUsage, TeamUsage};
 
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -24,10 +28,47 @@ use std::path::{Path, PathBuf};
 /// Root directory for all swarm-hug configuration and artifacts.
 pub const SWARM_HUG_DIR: &str = ".swarm-hug";
 
+/// Filename for the custom agent roster within the .swarm-hug directory.
+pub const ROSTER_FILE: &str = "agents.toml";
+/// Directory within .swarm-hug that holds archived teams.
+pub const ARCHIVE_DIR: &str = ".archive";
+/// Filename for per-team config overrides within each team directory.
+pub const TEAM_CONFIG_FILE: &str = "config.toml";
 /// Filename for sprint history within each team directory.
 pub const SPRINT_HISTORY_FILE: &str = "sprint-history.json";
 /// Filename for team state within each team directory.
 pub const TEAM_STATE_FILE: &str = "team-state.json";
+/// Filename for token usage totals within each team directory.
+pub const USAGE_FILE: &str = "usage.json";
+/// Filename for the last sprint's warnings within the runtime state directory.
+pub const WARNINGS_FILE: &str = "warnings.json";
+/// Filename for the last sprint's merge failures within the runtime state directory.
+pub const CONFLICTS_FILE: &str = "conflicts.json";
+/// Filename for the engine processes of the current run within the runtime state directory.
+pub const PIDS_FILE: &str = "pids.json";
+/// Filename for the current sprint's run metadata within the runtime state directory.
+pub const RUN_FILE: &str = "run.json";
+
+/// Filename of the git range a sprint produced, kept per sprint in each run.
+pub fn sprint_range_file(sprint: usize) -> String {
+    format!("range-{}.json", sprint)
+}
+
+/// PID state files of every run of `team_name`, including the legacy team root.
+pub fn run_pids_files(team_name: &str) -> Vec<PathBuf> {
+    let team_root = PathBuf::from(SWARM_HUG_DIR).join(team_name);
+    let mut files = vec![team_root.join(PIDS_FILE)];
+    if let Ok(entries) = fs::read_dir(team_root.join("runs")) {
+        let mut runs: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path().join(PIDS_FILE))
+            .collect();
+        runs.sort();
+        files.extend(runs);
+    }
+    files.retain(|path| path.is_file());
+    files
+}
 
 /// List all teams in the .swarm-hug directory.
 pub fn list_teams() -> Result<Vec<Team>, String> {
@@ -44,8 +85,8 @@ pub fn list_teams() -> Result<Vec<Team>, String> {
         let entry = entry.map_err(|e| format!("failed to read entry: {}", e))?;
         let path = entry.path();
 
-        // Skip non-directories
-        if !path.is_dir() {
+        // Skip non-directories and archived teams
+        if !path.is_dir() || entry.file_name() == ARCHIVE_DIR {
             continue;
         }
 
@@ -86,13 +127,45 @@ pub fn init_root() -> Result<(), String> {
         */loop/\n\
         \n\
         # Chat logs (local coordination)\n\
-        */chat.md\n";
+        */chat.md\n\
+        \n\
+        # Token usage totals (local cost tracking)\n\
+        */usage.json\n";
     fs::write(&gitignore_path, gitignore_content)
         .map_err(|e| format!("failed to create .gitignore: {}", e))?;
 
     Ok(())
 }
 
+/// Validate a project name for use as a directory and branch component.
+pub fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(
+            "Project name must contain only letters, numbers, hyphens, and underscores".to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Map a team's agent or sprint branch onto the same branch for a renamed team.
+///
+/// Returns `None` for branches that don't belong to `old_team`.
+pub fn renamed_branch(
+    branch: &str,
+    branch_prefix: &str,
+    old_team: &str,
+    new_team: &str,
+) -> Option<String> {
+    ["-agent-", "-sprint-"].iter().find_map(|kind| {
+        let rest = branch.strip_prefix(&format!("{}{}{}", branch_prefix, old_team, kind))?;
+        Some(format!("{}{}{}{}", branch_prefix, new_team, kind, rest))
+    })
+}
+
 /// Check if the .swarm-hug directory exists.
 pub fn root_exists() -> bool {
     Path::new(SWARM_HUG_DIR).exists()
@@ -118,6 +191,46 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_archived_team_is_not_listed() {
+        with_temp_cwd(|| {
+            init_root().unwrap();
+            let team = Team::new("payments");
+            team.init().unwrap();
+            Team::new("search").init().unwrap();
+
+            let archived = team.archive().unwrap();
+            assert_eq!(
+                archived,
+                PathBuf::from(SWARM_HUG_DIR)
+                    .join(ARCHIVE_DIR)
+                    .join("payments")
+            );
+            assert!(!team.exists());
+            assert!(archived.join("tasks.md").exists());
+
+            let names: Vec<String> = list_teams().unwrap().into_iter().map(|t| t.name).collect();
+            assert_eq!(names, vec!["search"]);
+        });
+    }
+
+    #[test]
+    fn test_renamed_branch() {
+        assert_eq!(
+            renamed_branch("swarm/pay-agent-aaron-abc123", "swarm/", "pay", "billing"),
+            Some("swarm/billing-agent-aaron-abc123".to_string())
+        );
+        assert_eq!(
+            renamed_branch("pay-sprint-3-abc123", "", "pay", "billing"),
+            Some("billing-sprint-3-abc123".to_string())
+        );
+        assert_eq!(
+            renamed_branch("payments-agent-aaron-abc123", "", "pay", "billing"),
+            None
+        );
+        assert_eq!(renamed_branch("main", "", "pay", "billing"), None);
+    }
+
     #[test]
     fn test_init_root_creates_gitignore() {
         with_temp_cwd(|| {
@@ -143,6 +256,10 @@ mod tests {
                 content.contains("*/chat.md"),
                 ".gitignore should ignore chat.md"
             );
+            assert!(
+                content.contains("*/usage.json"),
+                ".gitignore should ignore usage.json"
+            );
             assert!(
                 content.contains("Do not edit"),
                 ".gitignore should warn against edits"
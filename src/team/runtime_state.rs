@@ -1,6 +1,9 @@
 use std::path::{Path, PathBuf};
 
-use super::{SPRINT_HISTORY_FILE, SWARM_HUG_DIR, TEAM_STATE_FILE};
+use super::{
+    sprint_range_file, CONFLICTS_FILE, PIDS_FILE, RUN_FILE, SPRINT_HISTORY_FILE, SWARM_HUG_DIR,
+    TEAM_STATE_FILE, WARNINGS_FILE,
+};
 
 /// Runtime state paths for a swarm run.
 ///
@@ -62,6 +65,36 @@ impl RuntimeStatePaths {
         self.root.join(TEAM_STATE_FILE)
     }
 
+    /// Warnings recorded during the most recent sprint.
+    pub fn warnings_path(&self) -> PathBuf {
+        self.root.join(WARNINGS_FILE)
+    }
+
+    /// Merge failures recorded during the most recent sprint.
+    pub fn conflicts_path(&self) -> PathBuf {
+        self.root.join(CONFLICTS_FILE)
+    }
+
+    /// Engine processes spawned by the current run (reaped after a crash).
+    pub fn pids_path(&self) -> PathBuf {
+        self.root.join(PIDS_FILE)
+    }
+
+    /// Run hash, sprint branch, and base commit of the current sprint.
+    pub fn run_path(&self) -> PathBuf {
+        self.root.join(RUN_FILE)
+    }
+
+    /// Agent lifecycle history of sprint `sprint`.
+    pub fn lifecycle_path(&self, sprint: usize) -> PathBuf {
+        self.root.join(format!("lifecycle-{}.json", sprint))
+    }
+
+    /// Git range produced by sprint `sprint`.
+    pub fn sprint_range_path(&self, sprint: usize) -> PathBuf {
+        self.root.join(sprint_range_file(sprint))
+    }
+
     /// Canonical team root in branch state (`.swarm-hug/<team>`).
     pub fn branch_root(&self) -> PathBuf {
         PathBuf::from(SWARM_HUG_DIR).join(&self.team_name)
@@ -131,6 +164,18 @@ mod tests {
             paths.team_state_path(),
             PathBuf::from(".swarm-hug/alpha/runs/main/team-state.json")
         );
+        assert_eq!(
+            paths.lifecycle_path(3),
+            PathBuf::from(".swarm-hug/alpha/runs/main/lifecycle-3.json")
+        );
+        assert_eq!(
+            paths.pids_path(),
+            PathBuf::from(".swarm-hug/alpha/runs/main/pids.json")
+        );
+        assert_eq!(
+            paths.run_path(),
+            PathBuf::from(".swarm-hug/alpha/runs/main/run.json")
+        );
     }
 
     #[test]
@@ -2,6 +2,59 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::{SPRINT_HISTORY_FILE, SWARM_HUG_DIR};
+use crate::json::escape_json;
+
+/// Commit and branch range produced by a single sprint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SprintRange {
+    /// Sprint branch commit after task assignment, before any agent work.
+    pub start_commit: String,
+    /// Sprint branch HEAD once agents and the post-sprint review finished.
+    pub end_commit: String,
+    /// Branch the pull request is opened from.
+    pub source_branch: String,
+    /// Branch the pull request targets.
+    pub target_branch: String,
+}
+
+impl SprintRange {
+    /// Parse the range fields from sprint history JSON, if all are present.
+    fn parse_json(content: &str) -> Option<Self> {
+        Some(Self {
+            start_commit: parse_string_field(content, "sprint_start_commit")?,
+            end_commit: parse_string_field(content, "sprint_end_commit")?,
+            source_branch: parse_string_field(content, "source_branch")?,
+            target_branch: parse_string_field(content, "target_branch")?,
+        })
+    }
+
+    /// Load a range saved with [`SprintRange::save`]; `None` if the file is missing.
+    pub fn load(path: &Path) -> Result<Option<Self>, String> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        Self::parse_json(&content)
+            .map(Some)
+            .ok_or_else(|| format!("invalid sprint range in {}", path.display()))
+    }
+
+    /// Save this range as a standalone JSON object.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("failed to create directory: {}", e))?;
+        }
+        let content = format!(
+            "{{\n  \"sprint_start_commit\": \"{}\",\n  \"sprint_end_commit\": \"{}\",\n  \"source_branch\": \"{}\",\n  \"target_branch\": \"{}\"\n}}\n",
+            self.start_commit,
+            self.end_commit,
+            escape_json(&self.source_branch),
+            escape_json(&self.target_branch)
+        );
+        fs::write(path, content).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+    }
+}
 
 /// Sprint history tracking for a team.
 ///
@@ -14,6 +67,8 @@ pub struct SprintHistory {
     pub team_name: String,
     /// Total sprints completed (ever) for this team.
     pub total_sprints: usize,
+    /// Git range produced by the most recently completed sprint.
+    pub last_range: Option<SprintRange>,
     /// Path to the sprint history file.
     path: PathBuf,
 }
@@ -27,17 +82,21 @@ impl SprintHistory {
             .join(team_name)
             .join(SPRINT_HISTORY_FILE);
 
-        let total_sprints = if path.exists() {
+        let (total_sprints, last_range) = if path.exists() {
             let content = fs::read_to_string(&path)
                 .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
-            Self::parse_json(&content)?
+            (
+                Self::parse_json(&content)?,
+                SprintRange::parse_json(&content),
+            )
         } else {
-            0
+            (0, None)
         };
 
         Ok(Self {
             team_name: team_name.to_string(),
             total_sprints,
+            last_range,
             path,
         })
     }
@@ -55,19 +114,20 @@ impl SprintHistory {
     /// otherwise it defaults to "unknown" (callers should set it if needed
     /// before saving).
     pub fn load_from(path: &Path) -> Result<Self, String> {
-        let (total_sprints, team_name) = if path.exists() {
+        let (total_sprints, team_name, last_range) = if path.exists() {
             let content = fs::read_to_string(path)
                 .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
             let sprints = Self::parse_json(&content)?;
             let team = Self::parse_team_name(&content).unwrap_or_else(|| "unknown".to_string());
-            (sprints, team)
+            (sprints, team, SprintRange::parse_json(&content))
         } else {
-            (0, "unknown".to_string())
+            (0, "unknown".to_string(), None)
         };
 
         Ok(Self {
             team_name,
             total_sprints,
+            last_range,
             path: path.to_path_buf(),
         })
     }
@@ -109,46 +169,7 @@ impl SprintHistory {
     ///
     /// Returns None if the team field is not found or cannot be parsed.
     fn parse_team_name(content: &str) -> Option<String> {
-        let content = content.trim();
-
-        // Find "team": "value"
-        if let Some(idx) = content.find("\"team\"") {
-            let after_key = &content[idx + 6..]; // Skip past "team"
-            if let Some(colon_idx) = after_key.find(':') {
-                let after_colon = after_key[colon_idx + 1..].trim_start();
-                // Parse the string value
-                if let Some(stripped) = after_colon.strip_prefix('"') {
-                    let mut chars = stripped.chars();
-                    let mut result = String::new();
-                    let mut escaped = false;
-                    for ch in chars.by_ref() {
-                        if escaped {
-                            let decoded = match ch {
-                                'n' => '\n',
-                                'r' => '\r',
-                                't' => '\t',
-                                '\\' => '\\',
-                                '"' => '"',
-                                other => other,
-                            };
-                            result.push(decoded);
-                            escaped = false;
-                            continue;
-                        }
-                        if ch == '\\' {
-                            escaped = true;
-                            continue;
-                        }
-                        if ch == '"' {
-                            return Some(result);
-                        }
-                        result.push(ch);
-                    }
-                }
-            }
-        }
-
-        None
+        parse_string_field(content, "team")
     }
 
     /// Peek at the next sprint number without mutating state.
@@ -198,10 +219,21 @@ impl SprintHistory {
 
     /// Convert to JSON string.
     fn to_json(&self) -> String {
-        format!(
-            "{{\n  \"team\": \"{}\",\n  \"total_sprints\": {}\n}}\n",
+        let mut json = format!(
+            "{{\n  \"team\": \"{}\",\n  \"total_sprints\": {}",
             self.team_name, self.total_sprints
-        )
+        );
+        if let Some(ref range) = self.last_range {
+            json.push_str(&format!(
+                ",\n  \"sprint_start_commit\": \"{}\",\n  \"sprint_end_commit\": \"{}\",\n  \"source_branch\": \"{}\",\n  \"target_branch\": \"{}\"",
+                range.start_commit,
+                range.end_commit,
+                escape_json(&range.source_branch),
+                escape_json(&range.target_branch)
+            ));
+        }
+        json.push_str("\n}\n");
+        json
     }
 
     /// Get the formatted team name for commit messages.
@@ -222,6 +254,43 @@ impl SprintHistory {
     }
 }
 
+/// Parse a top-level string field (`"key": "value"`) from JSON content.
+fn parse_string_field(content: &str, key: &str) -> Option<String> {
+    let pattern = format!("\"{}\"", key);
+    let idx = content.find(&pattern)?;
+    let after_key = &content[idx + pattern.len()..];
+    let colon_idx = after_key.find(':')?;
+    let stripped = after_key[colon_idx + 1..].trim_start().strip_prefix('"')?;
+
+    let mut result = String::new();
+    let mut escaped = false;
+    for ch in stripped.chars() {
+        if escaped {
+            let decoded = match ch {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '\\' => '\\',
+                '"' => '"',
+                other => other,
+            };
+            result.push(decoded);
+            escaped = false;
+            continue;
+        }
+        if ch == '\\' {
+            escaped = true;
+            continue;
+        }
+        if ch == '"' {
+            return Some(result);
+        }
+        result.push(ch);
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::Team;
@@ -512,6 +581,34 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_last_range_round_trips_through_json() {
+        with_temp_cwd(|| {
+            let path = PathBuf::from("range/sprint-history.json");
+            let mut history = SprintHistory::load_from(&path).unwrap();
+            assert_eq!(history.last_range, None);
+
+            let range = SprintRange {
+                start_commit: "1111111aaaaaaa".to_string(),
+                end_commit: "2222222bbbbbbb".to_string(),
+                source_branch: "main".to_string(),
+                target_branch: "feature/\"x\"".to_string(),
+            };
+            history.team_name = "range-team".to_string();
+            history.increment();
+            history.last_range = Some(range.clone());
+            history.save().unwrap();
+
+            let json = fs::read_to_string(&path).unwrap();
+            assert!(json.contains("\"sprint_start_commit\": \"1111111aaaaaaa\""));
+
+            let reloaded = SprintHistory::load_from(&path).unwrap();
+            assert_eq!(reloaded.team_name, "range-team");
+            assert_eq!(reloaded.total_sprints, 1);
+            assert_eq!(reloaded.last_range, Some(range));
+        });
+    }
+
     #[test]
     fn test_load_from_preserves_team_name_from_file() {
         with_temp_cwd(|| {
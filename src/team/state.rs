@@ -2,6 +2,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::{SWARM_HUG_DIR, TEAM_STATE_FILE};
+use crate::json::{escape_json, parse_json_string};
 
 /// Persisted team state for merge operations.
 #[derive(Debug, Clone)]
@@ -122,7 +123,8 @@ impl TeamState {
                         return Ok(None);
                     }
                     if after_colon.starts_with('"') {
-                        let value = parse_json_string(after_colon)?;
+                        let (value, _) =
+                            parse_json_string(after_colon).ok_or("unterminated JSON string")?;
                         return Ok(Some(value));
                     }
                 }
@@ -147,7 +149,9 @@ impl TeamState {
             if let Some(colon_idx) = after_key.find(':') {
                 let after_colon = after_key[colon_idx + 1..].trim_start();
                 if after_colon.starts_with('"') {
-                    parse_json_string(after_colon)?
+                    parse_json_string(after_colon)
+                        .map(|(value, _)| value)
+                        .ok_or("unterminated JSON string")?
                 } else {
                     return Err("invalid team value".to_string());
                 }
@@ -165,9 +169,9 @@ impl TeamState {
     }
 
     fn to_json(&self) -> String {
-        let team = escape_json_string(&self.team_name);
+        let team = escape_json(&self.team_name);
         let feature = match &self.feature_branch {
-            Some(branch) => format!("\"{}\"", escape_json_string(branch)),
+            Some(branch) => format!("\"{}\"", escape_json(branch)),
             None => "null".to_string(),
         };
         format!(
@@ -185,59 +189,6 @@ fn derive_team_name_from_path(path: &Path) -> Result<String, String> {
         .ok_or_else(|| format!("cannot derive team name from path: {}", path.display()))
 }
 
-fn escape_json_string(value: &str) -> String {
-    let mut escaped = String::new();
-    for ch in value.chars() {
-        match ch {
-            '\\' => escaped.push_str("\\\\"),
-            '"' => escaped.push_str("\\\""),
-            '\n' => escaped.push_str("\\n"),
-            '\r' => escaped.push_str("\\r"),
-            '\t' => escaped.push_str("\\t"),
-            _ => escaped.push(ch),
-        }
-    }
-    escaped
-}
-
-fn parse_json_string(input: &str) -> Result<String, String> {
-    let mut chars = input.chars();
-    if chars.next() != Some('"') {
-        return Err("expected JSON string".to_string());
-    }
-
-    let mut out = String::new();
-    let mut escaped = false;
-    for ch in chars {
-        if escaped {
-            let decoded = match ch {
-                'n' => '\n',
-                'r' => '\r',
-                't' => '\t',
-                '\\' => '\\',
-                '"' => '"',
-                other => other,
-            };
-            out.push(decoded);
-            escaped = false;
-            continue;
-        }
-
-        if ch == '\\' {
-            escaped = true;
-            continue;
-        }
-
-        if ch == '"' {
-            return Ok(out);
-        }
-
-        out.push(ch);
-    }
-
-    Err("unterminated JSON string".to_string())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,10 @@
 use std::fs;
 use std::path::PathBuf;
 
-use super::{SPRINT_HISTORY_FILE, SWARM_HUG_DIR, TEAM_STATE_FILE};
+use super::{
+    renamed_branch, validate_name, SprintHistory, TeamState, ARCHIVE_DIR, SPRINT_HISTORY_FILE,
+    SWARM_HUG_DIR, TEAM_CONFIG_FILE, TEAM_STATE_FILE,
+};
 
 /// A team's configuration and paths.
 #[derive(Debug, Clone)]
@@ -51,6 +54,11 @@ impl Team {
         self.root.join("worktrees")
     }
 
+    /// Path to team's config.toml overrides.
+    pub fn config_path(&self) -> PathBuf {
+        self.root.join(TEAM_CONFIG_FILE)
+    }
+
     /// Path to team's sprint-history.json file.
     pub fn sprint_history_path(&self) -> PathBuf {
         self.root.join(SPRINT_HISTORY_FILE)
@@ -66,6 +74,104 @@ impl Team {
         self.root.exists()
     }
 
+    /// Path this team's directory moves to when archived.
+    pub fn archive_path(&self) -> PathBuf {
+        PathBuf::from(SWARM_HUG_DIR)
+            .join(ARCHIVE_DIR)
+            .join(&self.name)
+    }
+
+    /// Move this team's directory to `.swarm-hug/.archive/<name>/`.
+    ///
+    /// Fails if the team does not exist or an archive of the same name does.
+    pub fn archive(&self) -> Result<PathBuf, String> {
+        if !self.exists() {
+            return Err(format!("project '{}' not found", self.name));
+        }
+        let dest = self.archive_path();
+        if dest.exists() {
+            return Err(format!(
+                "{} already exists; remove it before archiving '{}' again",
+                dest.display(),
+                self.name
+            ));
+        }
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::rename(&self.root, &dest).map_err(|e| {
+            format!(
+                "failed to move {} to {}: {}",
+                self.root.display(),
+                dest.display(),
+                e
+            )
+        })?;
+        Ok(dest)
+    }
+
+    /// Remove this team's directory entirely.
+    pub fn delete(&self) -> Result<(), String> {
+        if !self.exists() {
+            return Err(format!("project '{}' not found", self.name));
+        }
+        fs::remove_dir_all(&self.root)
+            .map_err(|e| format!("failed to remove {}: {}", self.root.display(), e))
+    }
+
+    /// Move this team's directory to `.swarm-hug/<new_name>/` and rewrite the
+    /// team name recorded in sprint history and team state (including the
+    /// per-target copies under `runs/`). A recorded feature branch owned by
+    /// the team is renamed to match.
+    ///
+    /// Git branches and worktrees are left to the caller.
+    pub fn rename(&self, new_name: &str, branch_prefix: &str) -> Result<Team, String> {
+        validate_name(new_name)?;
+        if !self.exists() {
+            return Err(format!("project '{}' not found", self.name));
+        }
+        let renamed = Team::new(new_name);
+        if renamed.root.exists() {
+            return Err(format!("project '{}' already exists", new_name));
+        }
+        fs::rename(&self.root, &renamed.root).map_err(|e| {
+            format!(
+                "failed to move {} to {}: {}",
+                self.root.display(),
+                renamed.root.display(),
+                e
+            )
+        })?;
+
+        let mut state_dirs = vec![renamed.root.clone()];
+        if let Ok(entries) = fs::read_dir(renamed.root.join("runs")) {
+            state_dirs.extend(entries.flatten().map(|e| e.path()));
+        }
+        for dir in state_dirs {
+            let history_path = dir.join(SPRINT_HISTORY_FILE);
+            if history_path.exists() {
+                let mut history = SprintHistory::load_from(&history_path)?;
+                history.team_name = new_name.to_string();
+                history.save()?;
+            }
+            let state_path = dir.join(TEAM_STATE_FILE);
+            if state_path.exists() {
+                let mut state = TeamState::load_from(&state_path)?;
+                state.team_name = new_name.to_string();
+                if let Some(branch) = state
+                    .feature_branch
+                    .as_deref()
+                    .and_then(|b| renamed_branch(b, branch_prefix, &self.name, new_name))
+                {
+                    state.set_feature_branch(&branch)?;
+                }
+                state.save()?;
+            }
+        }
+        Ok(renamed)
+    }
+
     /// Initialize this team's directory structure.
     pub fn init(&self) -> Result<(), String> {
         // Create root directory
@@ -173,4 +279,52 @@ mod tests {
             PathBuf::from(".swarm-hug/myteam/sprint-history.json")
         );
     }
+
+    #[test]
+    fn test_team_rename_preserves_sprint_history() {
+        with_temp_cwd(|| {
+            let team = Team::new("payments");
+            team.init().unwrap();
+            let mut history = SprintHistory::load_from(&team.sprint_history_path()).unwrap();
+            history.team_name = "payments".to_string();
+            history.increment();
+            history.increment();
+            history.save().unwrap();
+            let runs = team.root.join("runs").join("main");
+            let mut state = TeamState::load_from(&runs.join(TEAM_STATE_FILE)).unwrap();
+            state
+                .set_feature_branch("payments-sprint-2-abc123")
+                .unwrap();
+            state.save().unwrap();
+
+            let renamed = team.rename("billing", "").unwrap();
+
+            assert!(!team.exists());
+            assert_eq!(renamed.root, PathBuf::from(".swarm-hug/billing"));
+            let history = SprintHistory::load_from(&renamed.sprint_history_path()).unwrap();
+            assert_eq!(history.team_name, "billing");
+            assert_eq!(history.total_sprints, 2);
+            let runs = renamed.root.join("runs").join("main");
+            let state = TeamState::load_from(&runs.join(TEAM_STATE_FILE)).unwrap();
+            assert_eq!(state.team_name, "billing");
+            assert_eq!(
+                state.feature_branch.as_deref(),
+                Some("billing-sprint-2-abc123")
+            );
+        });
+    }
+
+    #[test]
+    fn test_team_rename_refuses_existing_or_invalid_name() {
+        with_temp_cwd(|| {
+            let team = Team::new("payments");
+            team.init().unwrap();
+            Team::new("billing").init().unwrap();
+
+            let err = team.rename("billing", "").unwrap_err();
+            assert!(err.contains("already exists"), "{}", err);
+            assert!(team.rename("bad name", "").is_err());
+            assert!(team.exists());
+        });
+    }
 }
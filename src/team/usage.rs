@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::engine::TokenUsage;
+
+use super::{SWARM_HUG_DIR, USAGE_FILE};
+
+/// Token usage of one sprint, broken down by agent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SprintUsage {
+    /// Usage per agent initial, for agents whose engines reported usage.
+    pub by_agent: BTreeMap<char, TokenUsage>,
+}
+
+impl SprintUsage {
+    /// Add usage reported by an agent's engine execution.
+    pub fn record(&mut self, initial: char, usage: TokenUsage) {
+        *self.by_agent.entry(initial).or_default() += usage;
+    }
+
+    /// Usage summed across agents, or None if no engine reported any.
+    pub fn total(&self) -> Option<TokenUsage> {
+        self.by_agent.values().copied().reduce(|mut total, usage| {
+            total += usage;
+            total
+        })
+    }
+}
+
+/// Cumulative token usage for a team.
+///
+/// Persisted to `.swarm-hug/<team>/usage.json` with the team's running
+/// totals and the per-agent breakdown of the most recent sprint.
+#[derive(Debug, Clone)]
+pub struct TeamUsage {
+    /// Team name.
+    pub team_name: String,
+    /// Usage across all recorded sprints.
+    pub total: TokenUsage,
+    /// Sprint number and usage of the most recently recorded sprint.
+    pub last_sprint: Option<(usize, SprintUsage)>,
+    /// Path to the usage file.
+    path: PathBuf,
+}
+
+impl TeamUsage {
+    /// Load usage for a team, starting from zero if no file exists yet.
+    pub fn load(team_name: &str) -> Result<Self, String> {
+        let path = PathBuf::from(SWARM_HUG_DIR)
+            .join(team_name)
+            .join(USAGE_FILE);
+        Self::load_from(team_name, &path)
+    }
+
+    /// Load usage from an explicit path.
+    pub fn load_from(team_name: &str, path: &Path) -> Result<Self, String> {
+        let total = if path.exists() {
+            let content = fs::read_to_string(path)
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+            Self::parse_json(&content)?
+        } else {
+            TokenUsage::default()
+        };
+
+        Ok(Self {
+            team_name: team_name.to_string(),
+            total,
+            last_sprint: None,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Add a sprint's usage to the running totals.
+    pub fn record_sprint(&mut self, sprint: usize, usage: &SprintUsage) {
+        if let Some(total) = usage.total() {
+            self.total += total;
+        }
+        self.last_sprint = Some((sprint, usage.clone()));
+    }
+
+    /// Save usage to disk.
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("failed to create directory: {}", e))?;
+        }
+        fs::write(&self.path, self.to_json())
+            .map_err(|e| format!("failed to write {}: {}", self.path.display(), e))
+    }
+
+    /// Parse the running totals, which precede the last-sprint breakdown.
+    fn parse_json(content: &str) -> Result<TokenUsage, String> {
+        let content = content.trim();
+        if !content.starts_with('{') || !content.ends_with('}') {
+            return Err("invalid usage JSON".to_string());
+        }
+        let field = |key: &str| {
+            parse_u64_field(content, key).ok_or_else(|| format!("missing {} in usage", key))
+        };
+        Ok(TokenUsage::new(
+            field("prompt_tokens")?,
+            field("completion_tokens")?,
+        ))
+    }
+
+    fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\n  \"team\": \"{}\",\n  {}",
+            self.team_name.replace('\\', "\\\\").replace('"', "\\\""),
+            usage_fields(&self.total)
+        );
+        if let Some((sprint, ref usage)) = self.last_sprint {
+            let agents: Vec<String> = usage
+                .by_agent
+                .iter()
+                .map(|(initial, usage)| {
+                    format!(
+                        "\"{}\": {{ {} }}",
+                        crate::agent::id(*initial),
+                        usage_fields(usage)
+                    )
+                })
+                .collect();
+            json.push_str(&format!(
+                ",\n  \"last_sprint\": {{\n    \"sprint\": {},\n    {},\n    \"agents\": {{ {} }}\n  }}",
+                sprint,
+                usage_fields(&usage.total().unwrap_or_default()),
+                agents.join(", ")
+            ));
+        }
+        json.push_str("\n}\n");
+        json
+    }
+}
+
+fn usage_fields(usage: &TokenUsage) -> String {
+    format!(
+        "\"prompt_tokens\": {}, \"completion_tokens\": {}, \"total_tokens\": {}",
+        usage.prompt_tokens,
+        usage.completion_tokens,
+        usage.total()
+    )
+}
+
+/// Parse the first `"key": N` number field from JSON content.
+fn parse_u64_field(content: &str, key: &str) -> Option<u64> {
+    let pattern = format!("\"{}\"", key);
+    let idx = content.find(&pattern)?;
+    let after_key = &content[idx + pattern.len()..];
+    let after_colon = after_key.trim_start().strip_prefix(':')?.trim_start();
+    let digits: String = after_colon
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::with_temp_cwd;
+
+    #[test]
+    fn test_sprint_usage_aggregates_across_agents() {
+        let mut usage = SprintUsage::default();
+        assert_eq!(usage.total(), None);
+
+        usage.record('A', TokenUsage::new(1000, 200));
+        usage.record('B', TokenUsage::new(500, 50));
+        usage.record('A', TokenUsage::new(300, 30));
+
+        assert_eq!(usage.by_agent[&'A'], TokenUsage::new(1300, 230));
+        assert_eq!(usage.by_agent[&'B'], TokenUsage::new(500, 50));
+        assert_eq!(usage.total(), Some(TokenUsage::new(1800, 280)));
+    }
+
+    #[test]
+    fn test_team_usage_accumulates_across_sprints() {
+        with_temp_cwd(|| {
+            let mut sprint = SprintUsage::default();
+            sprint.record('A', TokenUsage::new(100, 10));
+            sprint.record('B', TokenUsage::new(200, 20));
+
+            let mut usage = TeamUsage::load("alpha").unwrap();
+            assert_eq!(usage.total, TokenUsage::default());
+            usage.record_sprint(1, &sprint);
+            usage.save().unwrap();
+
+            let content = fs::read_to_string(".swarm-hug/alpha/usage.json").unwrap();
+            assert!(content.contains("\"total_tokens\": 330"));
+            assert!(content.contains(
+                "\"B\": { \"prompt_tokens\": 200, \"completion_tokens\": 20, \"total_tokens\": 220 }"
+            ));
+
+            let mut usage = TeamUsage::load("alpha").unwrap();
+            assert_eq!(usage.total, TokenUsage::new(300, 30));
+            usage.record_sprint(2, &sprint);
+            usage.save().unwrap();
+
+            let usage = TeamUsage::load("alpha").unwrap();
+            assert_eq!(usage.total, TokenUsage::new(600, 60));
+        });
+    }
+
+    #[test]
+    fn test_team_usage_rejects_invalid_json() {
+        assert!(TeamUsage::parse_json("not json").is_err());
+        assert!(TeamUsage::parse_json("{\"team\": \"alpha\"}").is_err());
+    }
+}
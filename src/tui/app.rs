@@ -1,9 +1,14 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::sync::mpsc::Receiver;
 
 use crossterm::event::{KeyCode, KeyModifiers};
 
 use super::ansi::strip_ansi;
 use super::message::TuiMessage;
+use crate::chat;
+use crate::lifecycle::LifecycleTracker;
+use crate::pause;
 
 /// Number of lines to scroll with mouse wheel
 const MOUSE_SCROLL_LINES: usize = 3;
@@ -17,6 +22,20 @@ pub(super) enum InputMode {
     Search,
 }
 
+/// Indices of `lines` containing `query`, ignoring case and ANSI color codes.
+pub(super) fn search_matches(lines: &[String], query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let query_lower = query.to_lowercase();
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| strip_ansi(line).to_lowercase().contains(&query_lower))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
 /// TUI application state
 pub struct TuiApp {
     /// Lines of output to display (raw with ANSI codes)
@@ -39,6 +58,16 @@ pub struct TuiApp {
     pub(super) search_matches: Vec<usize>,
     /// Current match index (for n/N navigation)
     pub(super) current_match: usize,
+    /// Log lines per agent initial, from `agent-<initial>.log`
+    pub(super) agent_lines: BTreeMap<char, Vec<String>>,
+    /// Agent states followed from the agent logs
+    pub(super) lifecycle: LifecycleTracker,
+    /// Agent whose pane is shown (`None` shows the merged output)
+    pub(super) selected_agent: Option<char>,
+    /// Pause file shared with the sprint subprocess (`None`: pausing unavailable)
+    pause_file: Option<PathBuf>,
+    /// Whether agents are paused before their next task
+    pub(super) paused: bool,
 }
 
 impl TuiApp {
@@ -55,9 +84,64 @@ impl TuiApp {
             search_query: String::new(),
             search_matches: Vec::new(),
             current_match: 0,
+            agent_lines: BTreeMap::new(),
+            lifecycle: LifecycleTracker::new(),
+            selected_agent: None,
+            pause_file: None,
+            paused: false,
+        }
+    }
+
+    /// Let `p` pause and resume agents through `path` (see [`crate::pause`]).
+    pub fn with_pause_file(mut self, path: PathBuf) -> Self {
+        self.pause_file = Some(path);
+        self
+    }
+
+    /// Pause agents before their next task, or resume them.
+    fn toggle_pause(&mut self) {
+        let Some(path) = &self.pause_file else {
+            return;
+        };
+        let paused = !self.paused;
+        match pause::set_paused(path, paused) {
+            Ok(()) => self.paused = paused,
+            Err(e) => self.lines.push(format!(
+                "\u{274c} Failed to {}: {}",
+                if paused { "pause" } else { "resume" },
+                e
+            )),
         }
     }
 
+    /// Lines of the pane being shown: the selected agent's log, or the merged output.
+    pub(super) fn pane_lines(&self) -> &[String] {
+        match self.selected_agent {
+            Some(initial) => self.agent_lines.get(&initial).map_or(&[], Vec::as_slice),
+            None => &self.lines,
+        }
+    }
+
+    /// Select the next (`forward`) or previous agent pane; the merged output
+    /// sits before the first agent.
+    fn cycle_agent(&mut self, forward: bool) {
+        let mut panes: Vec<Option<char>> = vec![None];
+        panes.extend(self.agent_lines.keys().copied().map(Some));
+        let current = panes
+            .iter()
+            .position(|pane| *pane == self.selected_agent)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % panes.len()
+        } else {
+            (current + panes.len() - 1) % panes.len()
+        };
+        self.selected_agent = panes[next];
+        self.scroll_offset = 0;
+        self.current_match = 0;
+        self.update_search_matches();
+    }
+
     /// Process any pending messages from the channel.
     pub(super) fn process_messages(&mut self) {
         while let Ok(msg) = self.rx.try_recv() {
@@ -72,7 +156,16 @@ impl TuiApp {
                         self.lines.push(l.to_string());
                     }
                     // Update search matches if we have an active search
-                    if !self.search_query.is_empty() {
+                    if self.selected_agent.is_none() && !self.search_query.is_empty() {
+                        self.update_search_matches();
+                    }
+                }
+                TuiMessage::AgentLogLine(initial, line) => {
+                    if let Some((_, name, message)) = chat::parse_line(&line) {
+                        self.lifecycle.observe_log(initial, name, message);
+                    }
+                    self.agent_lines.entry(initial).or_default().push(line);
+                    if self.selected_agent == Some(initial) && !self.search_query.is_empty() {
                         self.update_search_matches();
                     }
                 }
@@ -88,31 +181,27 @@ impl TuiApp {
 
     /// Update search matches based on current query.
     fn update_search_matches(&mut self) {
-        self.search_matches.clear();
-        if self.search_query.is_empty() {
-            return;
-        }
-        let query_lower = self.search_query.to_lowercase();
-        for (idx, line) in self.lines.iter().enumerate() {
-            // Strip ANSI codes for search matching
-            let plain = strip_ansi(line);
-            if plain.to_lowercase().contains(&query_lower) {
-                self.search_matches.push(idx);
-            }
-        }
+        self.search_matches = search_matches(self.pane_lines(), &self.search_query);
         // Reset current match if out of bounds
         if self.current_match >= self.search_matches.len() {
             self.current_match = 0;
         }
     }
 
+    /// Drop the search query and its highlights.
+    fn clear_search(&mut self) {
+        self.search_query.clear();
+        self.search_matches.clear();
+        self.current_match = 0;
+    }
+
     /// Jump to the current search match.
     fn jump_to_current_match(&mut self, inner_height: usize) {
         if self.search_matches.is_empty() {
             return;
         }
         let match_idx = self.search_matches[self.current_match];
-        let total = self.lines.len();
+        let total = self.pane_lines().len();
 
         // Calculate scroll offset to show the matched line
         // We want the matched line to be visible in the viewport
@@ -153,8 +242,9 @@ impl TuiApp {
         match self.input_mode {
             InputMode::Search => match key {
                 KeyCode::Esc => {
-                    // Exit search mode
+                    // Cancel: exit search mode and clear the search
                     self.input_mode = InputMode::Normal;
+                    self.clear_search();
                 }
                 KeyCode::Enter => {
                     // Confirm search and jump to first match
@@ -215,30 +305,29 @@ impl TuiApp {
                     }
                 }
                 KeyCode::Up | KeyCode::Char('k') => {
-                    let max_scroll = self.lines.len().saturating_sub(1);
+                    let max_scroll = self.pane_lines().len().saturating_sub(1);
                     self.scroll_offset = (self.scroll_offset + 1).min(max_scroll);
                 }
                 KeyCode::Down | KeyCode::Char('j') => {
                     self.scroll_offset = self.scroll_offset.saturating_sub(1);
                 }
                 KeyCode::PageUp => {
-                    let max_scroll = self.lines.len().saturating_sub(1);
+                    let max_scroll = self.pane_lines().len().saturating_sub(1);
                     self.scroll_offset = (self.scroll_offset + 10).min(max_scroll);
                 }
                 KeyCode::PageDown => {
                     self.scroll_offset = self.scroll_offset.saturating_sub(10);
                 }
                 KeyCode::Home => {
-                    self.scroll_offset = self.lines.len().saturating_sub(1);
+                    self.scroll_offset = self.pane_lines().len().saturating_sub(1);
                 }
                 KeyCode::End => {
                     self.scroll_offset = 0;
                 }
-                KeyCode::Esc => {
-                    // Clear search
-                    self.search_query.clear();
-                    self.search_matches.clear();
-                }
+                KeyCode::Char('p') if !self.work_complete => self.toggle_pause(),
+                KeyCode::Left => self.cycle_agent(false),
+                KeyCode::Right => self.cycle_agent(true),
+                KeyCode::Esc => self.clear_search(),
                 _ => {}
             },
         }
@@ -250,7 +339,7 @@ impl TuiApp {
             return;
         }
 
-        let max_scroll = self.lines.len().saturating_sub(1);
+        let max_scroll = self.pane_lines().len().saturating_sub(1);
         if up {
             self.scroll_offset = (self.scroll_offset + MOUSE_SCROLL_LINES).min(max_scroll);
         } else {
@@ -258,3 +347,88 @@ impl TuiApp {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    fn buffer() -> Vec<String> {
+        [
+            "2026-01-26 00:01:26 | \x1b[1;36mAaron\x1b[0m | Starting: Task one",
+            "2026-01-26 00:01:27 | \x1b[1;35mBetty\x1b[0m | Starting: Task two",
+            "2026-01-26 00:02:10 | \x1b[1;36mAaron\x1b[0m | \x1b[1;32mCompleted:\x1b[0m Task one",
+            "2026-01-26 00:03:00 | ScrumMaster | [ERROR] Merge failed for Betty",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+    }
+
+    fn app_with_buffer() -> TuiApp {
+        let (tx, rx) = mpsc::channel();
+        let mut app = TuiApp::new(rx);
+        for line in buffer() {
+            tx.send(TuiMessage::AppendLine(line)).unwrap();
+        }
+        app.process_messages();
+        app
+    }
+
+    fn type_keys(app: &mut TuiApp, keys: &str) {
+        for c in keys.chars() {
+            app.handle_key(KeyCode::Char(c), KeyModifiers::NONE, 2);
+        }
+    }
+
+    #[test]
+    fn test_search_matches_over_fixed_buffer() {
+        let lines = buffer();
+        assert_eq!(search_matches(&lines, "aaron"), vec![0, 2]);
+        assert_eq!(search_matches(&lines, "BETTY"), vec![1, 3]);
+        // Matches plain text, not the ANSI codes around it
+        assert_eq!(search_matches(&lines, "Completed: Task"), vec![2]);
+        assert!(search_matches(&lines, "1;36m").is_empty());
+        assert!(search_matches(&lines, "").is_empty());
+        assert!(search_matches(&lines, "Zane").is_empty());
+    }
+
+    #[test]
+    fn test_search_keys_jump_between_matches() {
+        let mut app = app_with_buffer();
+
+        type_keys(&mut app, "/betty");
+        assert!(app.input_mode == InputMode::Search);
+        assert_eq!(app.search_matches, vec![1, 3]);
+
+        app.handle_key(KeyCode::Enter, KeyModifiers::NONE, 2);
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(app.current_match, 0);
+        assert_eq!(app.scroll_offset, 2);
+
+        type_keys(&mut app, "n");
+        assert_eq!((app.current_match, app.scroll_offset), (1, 0));
+        type_keys(&mut app, "n");
+        assert_eq!(app.current_match, 0);
+        type_keys(&mut app, "N");
+        assert_eq!(app.current_match, 1);
+
+        app.handle_key(KeyCode::Esc, KeyModifiers::NONE, 2);
+        assert!(app.search_query.is_empty());
+        assert!(app.search_matches.is_empty());
+    }
+
+    #[test]
+    fn test_escape_in_search_mode_clears_search() {
+        let mut app = app_with_buffer();
+
+        type_keys(&mut app, "/aaron");
+        assert_eq!(app.search_matches, vec![0, 2]);
+        app.handle_key(KeyCode::Esc, KeyModifiers::NONE, 2);
+
+        assert!(app.input_mode == InputMode::Normal);
+        assert!(app.search_query.is_empty());
+        assert!(app.search_matches.is_empty());
+    }
+}
@@ -3,6 +3,8 @@
 pub enum TuiMessage {
     /// Append a line to the output
     AppendLine(String),
+    /// Append a line from an agent's log (`agent-<initial>.log`)
+    AgentLogLine(char, String),
     /// Signal that the work is complete
     WorkComplete,
     /// Request to quit (user pressed q)
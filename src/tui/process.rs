@@ -6,11 +6,12 @@ use std::time::Duration;
 #[cfg(unix)]
 use std::time::Instant;
 
+use crate::pause;
 use crate::process::kill_process_tree;
 
 use super::message::TuiMessage;
 use super::run::run_tui;
-use super::tail::tail_chat_to_tui;
+use super::tail::{tail_agent_logs_to_tui, tail_chat_to_tui};
 
 #[cfg(unix)]
 const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
@@ -56,9 +57,12 @@ fn graceful_stop_child(child_pid: u32, child: &mut std::process::Child) {
 /// Run the TUI with a subprocess that does the actual work.
 ///
 /// This spawns the swarm command as a subprocess to avoid stdout corruption.
-/// The TUI only shows the chat file content (which the subprocess writes to).
+/// The TUI shows the chat file content (which the subprocess writes to), plus
+/// a pane per agent following `agent-<initial>.log` in `log_dir`. `p` pauses
+/// agents through a pause file passed to the subprocess in `SWARM_PAUSE_FILE`.
 pub fn run_tui_with_subprocess(
     chat_path: &str,
+    log_dir: &str,
     args: Vec<String>,
     skip_chat_reset: bool,
 ) -> io::Result<()> {
@@ -67,9 +71,13 @@ pub fn run_tui_with_subprocess(
     let (tx, rx) = mpsc::channel();
     let tx_clone = tx.clone();
     let chat_path = chat_path.to_string();
+    let log_dir = log_dir.to_string();
+    let pause_file = std::env::temp_dir().join(format!("swarm-pause-{}", std::process::id()));
+    let pause_for_proc = pause_file.clone();
 
     let stop_flag = Arc::new(AtomicBool::new(false));
     let stop_for_tail = Arc::clone(&stop_flag);
+    let stop_for_logs = Arc::clone(&stop_flag);
     let stop_for_proc = Arc::clone(&stop_flag);
 
     let exe_path = std::env::current_exe()
@@ -86,7 +94,8 @@ pub fn run_tui_with_subprocess(
             cmd.args(&args)
                 .stdout(Stdio::null())
                 .stderr(Stdio::null())
-                .env("SWARM_NO_TAIL", "1"); // TUI handles display, subprocess shouldn't tail
+                .env("SWARM_NO_TAIL", "1") // TUI handles display, subprocess shouldn't tail
+                .env(pause::PAUSE_FILE_ENV, &pause_for_proc);
             if skip_chat_reset {
                 cmd.env("SWARM_SKIP_CHAT_RESET", "1");
             }
@@ -106,7 +115,8 @@ pub fn run_tui_with_subprocess(
             cmd.args(&args)
                 .stdout(Stdio::null())
                 .stderr(Stdio::null())
-                .env("SWARM_NO_TAIL", "1"); // TUI handles display, subprocess shouldn't tail
+                .env("SWARM_NO_TAIL", "1") // TUI handles display, subprocess shouldn't tail
+                .env(pause::PAUSE_FILE_ENV, &pause_for_proc);
             if skip_chat_reset {
                 cmd.env("SWARM_SKIP_CHAT_RESET", "1");
             }
@@ -168,12 +178,20 @@ pub fn run_tui_with_subprocess(
         tail_chat_to_tui(&chat_path, tx_for_tail, stop_for_tail);
     });
 
-    let result = run_tui(rx);
+    let tx_for_logs = tx.clone();
+    let logs_handle = thread::spawn(move || {
+        tail_agent_logs_to_tui(&log_dir, tx_for_logs, stop_for_logs);
+    });
+
+    let result = run_tui(rx, Some(pause_file.clone()));
 
+    // Let paused agents wind down with the subprocess
+    let _ = pause::set_paused(&pause_file, false);
     stop_flag.store(true, Ordering::SeqCst);
 
     let _ = proc_handle.join();
     let _ = tail_handle.join();
+    let _ = logs_handle.join();
 
     result
 }
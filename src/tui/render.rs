@@ -8,6 +8,8 @@ use ratatui::{
 
 use super::ansi::truncate_and_parse_ansi_with_highlight;
 use super::app::{InputMode, TuiApp};
+use crate::agent;
+use crate::lifecycle::AgentState;
 
 /// ASCII art header for SWARM HUG
 const HEADER: &str = "\u{250f}\u{2501}\u{2513}\u{257b} \u{257b}\u{250f}\u{2501}\u{2513}\u{250f}\u{2501}\u{2513}\u{250f}\u{2533}\u{2513}   \u{257b} \u{257b}\u{257b} \u{257b}\u{250f}\u{2501}\u{2578}\n\u{2517}\u{2501}\u{2513}\u{2503}\u{257b}\u{2503}\u{2523}\u{2501}\u{252b}\u{2523}\u{2533}\u{251b}\u{2503}\u{2503}\u{2503}   \u{2523}\u{2501}\u{252b}\u{2503} \u{2503}\u{2503}\u{257a}\u{2513}\n\u{2517}\u{2501}\u{251b}\u{2517}\u{253b}\u{251b}\u{2579} \u{2579}\u{2579}\u{2517}\u{2578}\u{2579} \u{2579}   \u{2579} \u{2579}\u{2517}\u{2501}\u{251b}\u{2517}\u{2501}\u{251b}";
@@ -15,6 +17,9 @@ const HEADER: &str = "\u{250f}\u{2501}\u{2513}\u{257b} \u{257b}\u{250f}\u{2501}\
 /// Padding inside content area (1 cell on each side)
 const CONTENT_PADDING: u16 = 1;
 
+/// Width of the agent list beside the output pane
+const AGENT_LIST_WIDTH: u16 = 32;
+
 /// Draw the UI. Returns the inner content height for search navigation.
 pub(super) fn draw_ui(f: &mut Frame, app: &TuiApp) -> usize {
     let size = f.area();
@@ -45,8 +50,20 @@ pub(super) fn draw_ui(f: &mut Frame, app: &TuiApp) -> usize {
     // Draw header
     draw_header(f, chunks[0]);
 
+    // Draw the agent list once agents have logged, then the selected pane
+    let content_area = if app.agent_lines.is_empty() {
+        chunks[1]
+    } else {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(AGENT_LIST_WIDTH), Constraint::Min(0)])
+            .split(chunks[1]);
+        draw_agent_list(f, columns[0], app);
+        columns[1]
+    };
+
     // Draw content and get inner height
-    let inner_height = draw_content(f, chunks[1], app);
+    let inner_height = draw_content(f, content_area, app);
 
     // Draw search bar if active
     if has_search {
@@ -86,6 +103,58 @@ fn draw_header(f: &mut Frame, area: Rect) {
     f.render_widget(header, area);
 }
 
+/// Lifecycle state label for an agent, e.g. `working` or `done (failed)`.
+fn agent_state_label(app: &TuiApp, initial: char) -> String {
+    match app.lifecycle.get(initial) {
+        Some(ctx) if ctx.is_finished() && !ctx.succeeded() => format!("{} (failed)", ctx.state),
+        Some(ctx) => ctx.state.to_string(),
+        None => "idle".to_string(),
+    }
+}
+
+/// Draw the selectable agent list with each agent's lifecycle state.
+fn draw_agent_list(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let selected = Style::default().add_modifier(Modifier::REVERSED);
+    let mut items = vec![Line::from(Span::styled(
+        " All output",
+        if app.selected_agent.is_none() {
+            selected
+        } else {
+            Style::default()
+        },
+    ))];
+    for &initial in app.agent_lines.keys() {
+        let name = agent::name_from_initial(initial).unwrap_or_else(|| "Unknown".to_string());
+        let state_color = match app.lifecycle.get(initial) {
+            Some(ctx) if ctx.state == AgentState::Working => Color::Yellow,
+            Some(ctx) if ctx.is_finished() && ctx.succeeded() => Color::Green,
+            Some(ctx) if ctx.is_finished() => Color::Red,
+            _ => Color::DarkGray,
+        };
+        let style = if app.selected_agent == Some(initial) {
+            selected
+        } else {
+            Style::default()
+        };
+        items.push(Line::from(vec![
+            Span::styled(format!(" {} {:<7}", agent::id(initial), name), style),
+            Span::styled(
+                format!(" {}", agent_state_label(app, initial)),
+                style.fg(state_color),
+            ),
+        ]));
+    }
+
+    let list = Paragraph::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .title(" Agents [\u{2190}\u{2192}] ")
+            .title_style(Style::default().fg(Color::White)),
+    );
+    f.render_widget(list, area);
+}
+
 /// Draw the scrollable content area. Returns inner height.
 fn draw_content(f: &mut Frame, area: Rect, app: &TuiApp) -> usize {
     // Calculate inner area accounting for borders (1 cell each side) and padding
@@ -107,7 +176,8 @@ fn draw_content(f: &mut Frame, area: Rect, app: &TuiApp) -> usize {
     }
 
     // Calculate which lines to show based on scroll offset
-    let total_lines = app.lines.len();
+    let lines = app.pane_lines();
+    let total_lines = lines.len();
     let start_idx = if total_lines <= inner_height {
         0
     } else {
@@ -119,7 +189,7 @@ fn draw_content(f: &mut Frame, area: Rect, app: &TuiApp) -> usize {
 
     // Convert lines to styled Lines, parsing ANSI colors and truncating to fit width
     // Also highlight search matches
-    let visible_lines: Vec<Line> = app.lines[start_idx..end_idx]
+    let visible_lines: Vec<Line> = lines[start_idx..end_idx]
         .iter()
         .enumerate()
         .map(|(visible_idx, line)| {
@@ -139,10 +209,29 @@ fn draw_content(f: &mut Frame, area: Rect, app: &TuiApp) -> usize {
         })
         .collect();
 
+    // Name the pane: merged output, or the selected agent with its state and task
+    let pane = match app.selected_agent {
+        Some(initial) => {
+            let name = agent::name_from_initial(initial).unwrap_or_else(|| "Unknown".to_string());
+            let mut pane = format!("{} - {}", name, agent_state_label(app, initial));
+            if let Some(ctx) = app.lifecycle.get(initial) {
+                pane.push_str(&format!(": {}", ctx.task));
+            }
+            pane
+        }
+        None => "Output".to_string(),
+    };
+    let pane = if app.paused {
+        format!("PAUSED - {}", pane)
+    } else {
+        pane
+    };
+
     // Build title with search info
     let title = if !app.search_matches.is_empty() {
         format!(
-            " Output ({}/{}) [match {}/{}] [\u{2191}\u{2193} scroll, / search, n/N next/prev, q quit] ",
+            " {} ({}/{}) [match {}/{}] [\u{2191}\u{2193} scroll, / search, n/N next/prev, p pause, q quit] ",
+            pane,
             if total_lines > 0 {
                 total_lines.saturating_sub(app.scroll_offset)
             } else {
@@ -154,7 +243,8 @@ fn draw_content(f: &mut Frame, area: Rect, app: &TuiApp) -> usize {
         )
     } else {
         format!(
-            " Output ({}/{}) [\u{2191}\u{2193} scroll, / search, q quit] ",
+            " {} ({}/{}) [\u{2191}\u{2193} scroll, / search, p pause, q quit] ",
+            pane,
             if total_lines > 0 {
                 total_lines.saturating_sub(app.scroll_offset)
             } else {
@@ -169,7 +259,11 @@ fn draw_content(f: &mut Frame, area: Rect, app: &TuiApp) -> usize {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::DarkGray))
         .title(title)
-        .title_style(Style::default().fg(Color::White));
+        .title_style(Style::default().fg(if app.paused {
+            Color::Yellow
+        } else {
+            Color::White
+        }));
 
     // Render the block first
     f.render_widget(block, area);
@@ -190,7 +284,7 @@ fn draw_content(f: &mut Frame, area: Rect, app: &TuiApp) -> usize {
 /// Draw the search bar.
 fn draw_search_bar(f: &mut Frame, area: Rect, app: &TuiApp) {
     let (border_color, title) = if app.input_mode == InputMode::Search {
-        (Color::Yellow, " Search (Enter to confirm, Esc to cancel) ")
+        (Color::Yellow, " Search (Enter to confirm, Esc to clear) ")
     } else {
         (Color::DarkGray, " Search (Esc to clear) ")
     };
@@ -270,3 +364,137 @@ fn draw_quit_modal(f: &mut Frame, area: Rect) {
 
     f.render_widget(modal, modal_area);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+    use crate::tui::message::TuiMessage;
+
+    /// Render `app` and return the screen as text rows.
+    fn render(app: &TuiApp) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(120, 24)).unwrap();
+        terminal
+            .draw(|f| {
+                draw_ui(f, app);
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    fn app_with_agents() -> TuiApp {
+        let (tx, rx) = mpsc::channel();
+        let mut app = TuiApp::new(rx);
+        let messages = [
+            TuiMessage::AppendLine("12:00:00 | ScrumMaster | Sprint 1 plan".to_string()),
+            TuiMessage::AgentLogLine(
+                'A',
+                "12:00:01 | Aaron | Assigned task: Write docs [engine: stub]".to_string(),
+            ),
+            TuiMessage::AgentLogLine(
+                'A',
+                "12:00:01 | Aaron | State: ASSIGNED -> WORKING".to_string(),
+            ),
+            TuiMessage::AgentLogLine(
+                'B',
+                "12:00:02 | Betty | Assigned task: Fix tests [engine: stub]".to_string(),
+            ),
+            TuiMessage::AgentLogLine(
+                'B',
+                "12:00:02 | Betty | State: ASSIGNED -> WORKING".to_string(),
+            ),
+            TuiMessage::AgentLogLine(
+                'B',
+                "12:00:09 | Betty | State: WORKING -> DONE (failed: timed out)".to_string(),
+            ),
+            TuiMessage::AgentLogLine('A', "12:00:10 | Aaron | Aaron output line".to_string()),
+        ];
+        for message in messages {
+            tx.send(message).unwrap();
+        }
+        app.process_messages();
+        app
+    }
+
+    #[test]
+    fn test_selected_agent_pane_shows_only_its_lines() {
+        let mut app = app_with_agents();
+
+        // Merged output with the agent list beside it
+        let screen = render(&app).join("\n");
+        assert!(screen.contains("Sprint 1 plan"), "{}", screen);
+        assert!(screen.contains("A Aaron   working"), "{}", screen);
+        assert!(screen.contains("B Betty   done (failed)"), "{}", screen);
+        assert!(!screen.contains("Aaron output line"), "{}", screen);
+
+        app.handle_key(KeyCode::Right, KeyModifiers::NONE, 10);
+        assert_eq!(app.selected_agent, Some('A'));
+        let screen = render(&app).join("\n");
+        assert!(screen.contains("Aaron - working: Write docs"), "{}", screen);
+        assert!(screen.contains("Aaron output line"), "{}", screen);
+        assert!(!screen.contains("Fix tests [engine"), "{}", screen);
+        assert!(!screen.contains("Sprint 1 plan"), "{}", screen);
+
+        app.handle_key(KeyCode::Right, KeyModifiers::NONE, 10);
+        let screen = render(&app).join("\n");
+        assert!(
+            screen.contains("Betty - done (failed): Fix tests"),
+            "{}",
+            screen
+        );
+        assert!(
+            screen.contains("State: WORKING -> DONE (failed: timed out)"),
+            "{}",
+            screen
+        );
+        assert!(!screen.contains("Aaron output line"), "{}", screen);
+
+        // Wraps back around to the merged output
+        app.handle_key(KeyCode::Right, KeyModifiers::NONE, 10);
+        assert_eq!(app.selected_agent, None);
+        app.handle_key(KeyCode::Left, KeyModifiers::NONE, 10);
+        assert_eq!(app.selected_agent, Some('B'));
+    }
+
+    #[test]
+    fn test_no_agent_list_before_agents_log() {
+        let (tx, rx) = mpsc::channel();
+        let mut app = TuiApp::new(rx);
+        tx.send(TuiMessage::AppendLine("hello".to_string()))
+            .unwrap();
+        app.process_messages();
+
+        let screen = render(&app).join("\n");
+        assert!(screen.contains("hello"));
+        assert!(!screen.contains("Agents"));
+    }
+
+    #[test]
+    fn test_pause_key_toggles_pause_file_and_title() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let pause_file = temp.path().join("pause");
+        let (_tx, rx) = mpsc::channel();
+        let mut app = TuiApp::new(rx).with_pause_file(pause_file.clone());
+
+        app.handle_key(KeyCode::Char('p'), KeyModifiers::NONE, 10);
+        assert!(pause_file.exists());
+        let screen = render(&app).join("\n");
+        assert!(screen.contains("PAUSED - Output"), "{}", screen);
+
+        app.handle_key(KeyCode::Char('p'), KeyModifiers::NONE, 10);
+        assert!(!pause_file.exists());
+        let screen = render(&app).join("\n");
+        assert!(!screen.contains("PAUSED"), "{}", screen);
+    }
+}
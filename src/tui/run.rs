@@ -1,4 +1,5 @@
 use std::io;
+use std::path::PathBuf;
 use std::sync::mpsc::Receiver;
 use std::time::Duration;
 
@@ -14,7 +15,9 @@ use super::message::TuiMessage;
 use super::render::draw_ui;
 
 /// Run the TUI application.
-pub fn run_tui(rx: Receiver<TuiMessage>) -> io::Result<()> {
+///
+/// With a `pause_file`, `p` pauses and resumes agents (see [`crate::pause`]).
+pub fn run_tui(rx: Receiver<TuiMessage>, pause_file: Option<PathBuf>) -> io::Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -26,6 +29,9 @@ pub fn run_tui(rx: Receiver<TuiMessage>) -> io::Result<()> {
     terminal.clear()?;
 
     let mut app = TuiApp::new(rx);
+    if let Some(path) = pause_file {
+        app = app.with_pause_file(path);
+    }
 
     // Track inner height for search navigation
     let mut last_inner_height: usize = 20;
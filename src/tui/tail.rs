@@ -1,64 +1,87 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc::Sender, Arc};
 use std::thread;
 use std::time::Duration;
 
 use super::message::TuiMessage;
-use crate::chat;
+use crate::{agent, chat, log};
 
-/// Tail a chat file and send lines to the TUI.
-pub(super) fn tail_chat_to_tui(path: &str, tx: Sender<TuiMessage>, stop: Arc<AtomicBool>) {
-    use std::fs::File;
-    use std::io::{BufReader, Read, Seek, SeekFrom};
-
-    let mut offset: u64 = 0;
+/// Poll interval for new file content.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
 
-    loop {
-        if stop.load(Ordering::SeqCst) {
-            break;
-        }
-
-        let file = match File::open(path) {
-            Ok(f) => f,
-            Err(_) => {
-                thread::sleep(Duration::from_millis(100));
-                continue;
-            }
-        };
-
-        let len = match file.metadata() {
-            Ok(m) => m.len(),
-            Err(_) => {
-                thread::sleep(Duration::from_millis(100));
-                continue;
-            }
-        };
-
-        if len < offset {
-            offset = 0;
-        }
+/// Read content appended to `path` since `offset`, advancing `offset`.
+///
+/// A file shorter than `offset` was truncated or rotated, so it is re-read from the start.
+fn read_new_content(path: &Path, offset: &mut u64) -> Option<String> {
+    let file = File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    if len < *offset {
+        *offset = 0;
+    }
 
-        let mut reader = BufReader::new(file);
-        if reader.seek(SeekFrom::Start(offset)).is_err() {
-            thread::sleep(Duration::from_millis(100));
-            continue;
-        }
+    let mut reader = BufReader::new(file);
+    reader.seek(SeekFrom::Start(*offset)).ok()?;
+    let mut new_content = String::new();
+    let bytes_read = reader.read_to_string(&mut new_content).unwrap_or(0);
+    *offset += bytes_read as u64;
+    (bytes_read > 0).then_some(new_content)
+}
 
-        let mut new_content = String::new();
-        let bytes_read = reader.read_to_string(&mut new_content).unwrap_or(0);
+/// Tail a chat file and send lines to the TUI.
+pub(super) fn tail_chat_to_tui(path: &str, tx: Sender<TuiMessage>, stop: Arc<AtomicBool>) {
+    let path = Path::new(path);
+    let mut offset: u64 = 0;
 
-        if bytes_read > 0 {
+    while !stop.load(Ordering::SeqCst) {
+        if let Some(new_content) = read_new_content(path, &mut offset) {
             for line in new_content.lines() {
                 if !line.is_empty() && !chat::is_heartbeat_line(line) {
-                    let colored_line = crate::color::chat_line(line);
+                    let colored_line = crate::color::chat_line(&chat::display_line(line));
                     if tx.send(TuiMessage::AppendLine(colored_line)).is_err() {
                         return;
                     }
                 }
             }
-            offset += bytes_read as u64;
         }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Tail every agent's log in `log_dir` and send lines to the TUI.
+///
+/// Logs that already exist are followed from their current end, so lines
+/// from earlier runs are not shown.
+pub(super) fn tail_agent_logs_to_tui(log_dir: &str, tx: Sender<TuiMessage>, stop: Arc<AtomicBool>) {
+    let log_dir = Path::new(log_dir);
+    let mut offsets: HashMap<char, u64> = agent::initials()
+        .iter()
+        .filter_map(|&initial| {
+            let len = log::log_file_path(log_dir, initial).metadata().ok()?.len();
+            Some((initial, len))
+        })
+        .collect();
 
-        thread::sleep(Duration::from_millis(100));
+    while !stop.load(Ordering::SeqCst) {
+        // Re-read each pass: the roster grows when a sprint needs more than 26 agents
+        for initial in agent::initials() {
+            let offset = offsets.entry(initial).or_insert(0);
+            let path = log::log_file_path(log_dir, initial);
+            let Some(new_content) = read_new_content(&path, offset) else {
+                continue;
+            };
+            for line in new_content.lines().filter(|line| !line.trim().is_empty()) {
+                if tx
+                    .send(TuiMessage::AgentLogLine(initial, log::display_line(line)))
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
     }
 }
@@ -0,0 +1,156 @@
+//! Console verbosity control.
+//!
+//! Commands print progress through the [`info!`](crate::info) and
+//! [`verbose!`](crate::verbose) macros instead of calling `println!` directly,
+//! so `-q` and `-v` can tune how chatty a run is. Errors and final summaries
+//! keep printing unconditionally.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much console output to produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// Only errors and the final summary.
+    Quiet,
+    /// Default progress output.
+    #[default]
+    Normal,
+    /// Extra detail, including engine output previews (`-v`).
+    Verbose,
+    /// Everything, with engine output left untruncated (`-vv`).
+    Debug,
+}
+
+impl Verbosity {
+    /// Verbosity for a number of `-v` flags (0 = normal, 2+ = debug).
+    pub fn from_count(count: u8) -> Self {
+        match count {
+            0 => Verbosity::Normal,
+            1 => Verbosity::Verbose,
+            _ => Verbosity::Debug,
+        }
+    }
+
+    /// Repeated `-v` flags needed to reproduce this level (quiet maps to 0).
+    pub fn count(self) -> u8 {
+        match self {
+            Verbosity::Quiet | Verbosity::Normal => 0,
+            Verbosity::Verbose => 1,
+            Verbosity::Debug => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Verbosity::Quiet,
+            1 => Verbosity::Normal,
+            2 => Verbosity::Verbose,
+            _ => Verbosity::Debug,
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// Set the process-wide verbosity.
+pub fn set(level: Verbosity) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Current process-wide verbosity.
+pub fn current() -> Verbosity {
+    Verbosity::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+/// Whether output at `level` should be printed.
+pub fn enabled(level: Verbosity) -> bool {
+    current() >= level
+}
+
+/// Truncate engine output for console display.
+///
+/// Output is returned whole at [`Verbosity::Debug`]; otherwise it is cut to
+/// `max_chars` characters with a note about the total length.
+pub fn console_preview(output: &str, max_chars: usize) -> String {
+    if enabled(Verbosity::Debug) {
+        return output.to_string();
+    }
+    let total_chars = output.chars().count();
+    if total_chars <= max_chars {
+        return output.to_string();
+    }
+    let mut preview: String = output.chars().take(max_chars).collect();
+    preview.push_str(&format!("... [truncated, {} chars total]", total_chars));
+    preview
+}
+
+/// Print a line at normal verbosity (suppressed by `-q`).
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::verbosity::enabled($crate::verbosity::Verbosity::Normal) {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Print a line only when `-v` or higher is set.
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::verbosity::enabled($crate::verbosity::Verbosity::Verbose) {
+            println!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_count_and_count_round_trip() {
+        assert_eq!(Verbosity::from_count(0), Verbosity::Normal);
+        assert_eq!(Verbosity::from_count(1), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_count(2), Verbosity::Debug);
+        assert_eq!(Verbosity::from_count(5), Verbosity::Debug);
+        for level in [Verbosity::Normal, Verbosity::Verbose, Verbosity::Debug] {
+            assert_eq!(Verbosity::from_count(level.count()), level);
+        }
+    }
+
+    #[test]
+    fn test_levels_are_ordered() {
+        assert!(Verbosity::Quiet < Verbosity::Normal);
+        assert!(Verbosity::Normal < Verbosity::Verbose);
+        assert!(Verbosity::Verbose < Verbosity::Debug);
+        assert_eq!(Verbosity::default(), Verbosity::Normal);
+        for level in [
+            Verbosity::Quiet,
+            Verbosity::Normal,
+            Verbosity::Verbose,
+            Verbosity::Debug,
+        ] {
+            assert_eq!(Verbosity::from_u8(level as u8), level);
+        }
+    }
+
+    #[test]
+    fn test_console_preview_truncates_below_debug() {
+        // Global state is only touched in this test to avoid races between tests.
+        set(Verbosity::Verbose);
+        let output = "x".repeat(20);
+        assert_eq!(
+            console_preview(&output, 5),
+            "xxxxx... [truncated, 20 chars total]"
+        );
+        assert_eq!(console_preview("short", 10), "short");
+
+        set(Verbosity::Debug);
+        assert_eq!(console_preview(&output, 5), output);
+
+        set(Verbosity::Normal);
+        assert!(enabled(Verbosity::Normal));
+        assert!(!enabled(Verbosity::Verbose));
+    }
+}
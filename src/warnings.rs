@@ -0,0 +1,181 @@
+//! Per-sprint warning collection.
+//!
+//! Non-fatal problems during a sprint (failed log writes, cleanup failures,
+//! push failures, ...) are printed to stderr as they happen. The collector
+//! also records them so they can be saved to `warnings.json` in the runtime
+//! state directory and summarized after the fact.
+
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::json::{escape_json, json_string_field};
+
+/// A single recorded warning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// Short category such as `log`, `chat`, `cleanup`, or `push`.
+    pub category: String,
+    /// Warning text without the `warning:` prefix.
+    pub message: String,
+}
+
+/// Thread-safe warning sink shared by the sprint and its agent threads.
+#[derive(Debug, Clone, Default)]
+pub struct WarningCollector {
+    warnings: Arc<Mutex<Vec<Warning>>>,
+}
+
+impl WarningCollector {
+    /// Create an empty collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Print `line` to stderr unchanged and record it under `category`.
+    pub fn warn(&self, category: &str, line: impl Into<String>) {
+        let line = line.into();
+        eprintln!("{}", line);
+        self.record(category, &line);
+    }
+
+    /// Record a warning without printing it.
+    pub fn record(&self, category: &str, line: &str) {
+        let trimmed = line.trim();
+        let message = trimmed.strip_prefix("warning:").unwrap_or(trimmed).trim();
+        self.warnings
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Warning {
+                category: category.to_string(),
+                message: message.to_string(),
+            });
+    }
+
+    /// Snapshot of the warnings recorded so far.
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.warnings
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Number of warnings recorded so far.
+    pub fn len(&self) -> usize {
+        self.warnings
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// Whether no warnings have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write the recorded warnings to `path`, replacing any previous sprint's file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("failed to create directory: {}", e))?;
+        }
+        fs::write(path, to_json(&self.warnings()))
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))
+    }
+}
+
+/// Load warnings previously written by [`WarningCollector::save`].
+pub fn load(path: &Path) -> Result<Vec<Warning>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    Ok(content
+        .lines()
+        .filter_map(|line| {
+            Some(Warning {
+                category: json_string_field(line, "category")?,
+                message: json_string_field(line, "message")?,
+            })
+        })
+        .collect())
+}
+
+/// One warning object per line so the file stays readable and easy to parse.
+fn to_json(warnings: &[Warning]) -> String {
+    let entries: Vec<String> = warnings
+        .iter()
+        .map(|w| {
+            format!(
+                "    {{\"category\": \"{}\", \"message\": \"{}\"}}",
+                escape_json(&w.category),
+                escape_json(&w.message)
+            )
+        })
+        .collect();
+    if entries.is_empty() {
+        "{\n  \"warnings\": []\n}\n".to_string()
+    } else {
+        format!("{{\n  \"warnings\": [\n{}\n  ]\n}}\n", entries.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_strips_warning_prefix() {
+        let collector = WarningCollector::new();
+        collector.record("log", "  warning: failed to write log: disk full");
+        collector.record("git", "LLM planning failed");
+        assert_eq!(
+            collector.warnings(),
+            vec![
+                Warning {
+                    category: "log".to_string(),
+                    message: "failed to write log: disk full".to_string(),
+                },
+                Warning {
+                    category: "git".to_string(),
+                    message: "LLM planning failed".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clones_share_warnings_across_threads() {
+        let collector = WarningCollector::new();
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let c = collector.clone();
+                thread::spawn(move || c.record("agent", &format!("warning: agent {}", i)))
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(collector.len(), 4);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("runs/main/warnings.json");
+
+        let collector = WarningCollector::new();
+        collector.save(&path).unwrap();
+        assert!(load(&path).unwrap().is_empty());
+
+        collector.record(
+            "push",
+            "warning: push failed: \"remote\" rejected\nretry later",
+        );
+        collector.record("cleanup", "warning: cleanup failed: C:\\tmp");
+        collector.record("engine", "warning: stray \u{1b}[0m escape\u{7}");
+        collector.save(&path).unwrap();
+
+        let loaded = load(&path).unwrap();
+        assert_eq!(loaded, collector.warnings());
+    }
+}
@@ -0,0 +1,206 @@
+//! Agent activity POSTed to an external webhook (`webhook.url`).
+//!
+//! The runner and heartbeat call [`Webhook::notify`] when an agent starts a
+//! task, finishes it, or is still working on it. Each call sends one small
+//! JSON body:
+//!
+//! ```text
+//! {"team": "greenfield", "agent": "Aaron", "event": "heartbeat", "task": "Fix auth"}
+//! ```
+//!
+//! Events are `started`, `heartbeat`, `completed`, and `failed`. Requests are
+//! sent on a background thread (`https://` URLs via `curl`); a failed request is logged and
+//! never affects the sprint. When no webhook is configured there is no
+//! [`Webhook`] and nothing is sent.
+//!
+//! Separately, `webhook.sprint_url` receives one [`SprintSummary`] per finished
+//! sprint (see [`format_sprint_payload`]); the runner warns when that POST fails.
+
+use std::thread;
+use std::time::Duration;
+
+use crate::http;
+use crate::json::escape_json;
+
+/// Connect, write, and read timeout for a webhook request.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Webhook URL (`webhook.url`) and the team reported in every payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Webhook {
+    pub url: String,
+    pub team: String,
+}
+
+impl Webhook {
+    /// `team`'s webhook, or `None` when no URL is configured.
+    pub fn new(url: Option<&str>, team: &str) -> Option<Self> {
+        url.map(|url| Self {
+            url: url.to_string(),
+            team: team.to_string(),
+        })
+    }
+
+    /// POST an agent event without waiting for it.
+    pub fn notify(&self, agent: &str, event: &str, task: &str) {
+        let url = self.url.clone();
+        let body = format_payload(&self.team, agent, event, task);
+        thread::spawn(move || {
+            if let Err(e) = post(&url, &body) {
+                eprintln!("warning: webhook {} failed: {}", url, e);
+            }
+        });
+    }
+}
+
+/// Format an event as the JSON request body.
+pub fn format_payload(team: &str, agent: &str, event: &str, task: &str) -> String {
+    format!(
+        "{{\"team\": \"{}\", \"agent\": \"{}\", \"event\": \"{}\", \"task\": \"{}\"}}",
+        escape_json(team),
+        escape_json(agent),
+        escape_json(event),
+        escape_json(task)
+    )
+}
+
+/// Outcome of a finished sprint, POSTed to `webhook.sprint_url`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SprintSummary<'a> {
+    pub team: &'a str,
+    /// Historical sprint number (as in sprint-history.json).
+    pub sprint: usize,
+    pub tasks_assigned: usize,
+    pub tasks_completed: usize,
+    pub tasks_failed: usize,
+    /// Agent branches that failed to merge into the sprint branch.
+    pub merge_failures: usize,
+    /// `merged` when the sprint branch was merged into the target, `skipped`
+    /// when no merge ran (shutdown, or the sprint branch is the target).
+    pub merge_status: &'a str,
+    /// URL of the pull request opened for the sprint, if any.
+    pub pr_url: Option<&'a str>,
+}
+
+/// Format a finished sprint as the JSON request body.
+pub fn format_sprint_payload(summary: &SprintSummary) -> String {
+    let pr_url = summary
+        .pr_url
+        .map(|url| format!("\"{}\"", escape_json(url)))
+        .unwrap_or_else(|| "null".to_string());
+    format!(
+        "{{\"event\": \"sprint_completed\", \"team\": \"{}\", \"sprint\": {}, \"tasks_assigned\": {}, \"tasks_completed\": {}, \"tasks_failed\": {}, \"merge_failures\": {}, \"merge_status\": \"{}\", \"pr_url\": {}}}",
+        escape_json(summary.team),
+        summary.sprint,
+        summary.tasks_assigned,
+        summary.tasks_completed,
+        summary.tasks_failed,
+        summary.merge_failures,
+        escape_json(summary.merge_status),
+        pr_url
+    )
+}
+
+/// POST `body` as JSON to `url`, failing on connection errors and non-2xx replies.
+pub fn post(url: &str, body: &str) -> Result<(), String> {
+    let response = http::post_json(url, body, TIMEOUT)?;
+    if response.is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}", response.status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Accept one request, reply with `status`, and return the raw request.
+    fn mock_server(status: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hooks/swarm", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.ends_with(b"}") {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let reply = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+            stream.write_all(reply.as_bytes()).unwrap();
+            String::from_utf8_lossy(&request).to_string()
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_heartbeat_posts_to_webhook() {
+        let (url, server) = mock_server("200 OK");
+        let chat = tempfile::NamedTempFile::new().unwrap();
+        let webhook = Webhook::new(Some(&url), "greenfield");
+
+        let guard = crate::heartbeat::HeartbeatGuard::start(
+            chat.path(),
+            "Aaron",
+            "Fix \"auth\"",
+            Duration::from_millis(50),
+            webhook,
+        );
+        let request = server.join().unwrap();
+        guard.stop();
+
+        assert!(
+            request.starts_with("POST /hooks/swarm HTTP/1.1\r\n"),
+            "{}",
+            request
+        );
+        assert!(request.contains("Content-Type: application/json\r\n"));
+        assert!(request.ends_with(
+            "\r\n\r\n{\"team\": \"greenfield\", \"agent\": \"Aaron\", \"event\": \"heartbeat\", \"task\": \"Fix \\\"auth\\\"\"}"
+        ));
+    }
+
+    #[test]
+    fn test_sprint_payload_posted_as_json() {
+        let (url, server) = mock_server("204 No Content");
+        let summary = SprintSummary {
+            team: "greenfield",
+            sprint: 3,
+            tasks_assigned: 5,
+            tasks_completed: 4,
+            tasks_failed: 1,
+            merge_failures: 0,
+            merge_status: "merged",
+            pr_url: Some("https://github.com/example/repo/pull/7"),
+        };
+        post(&url, &format_sprint_payload(&summary)).unwrap();
+        let request = server.join().unwrap();
+
+        assert!(request.ends_with(
+            "\r\n\r\n{\"event\": \"sprint_completed\", \"team\": \"greenfield\", \"sprint\": 3, \"tasks_assigned\": 5, \"tasks_completed\": 4, \"tasks_failed\": 1, \"merge_failures\": 0, \"merge_status\": \"merged\", \"pr_url\": \"https://github.com/example/repo/pull/7\"}"
+        ), "{}", request);
+
+        let no_pr = SprintSummary {
+            merge_status: "skipped",
+            pr_url: None,
+            ..summary
+        };
+        assert!(format_sprint_payload(&no_pr)
+            .ends_with("\"merge_status\": \"skipped\", \"pr_url\": null}"));
+    }
+
+    #[test]
+    fn test_post_reports_http_errors() {
+        let (url, server) = mock_server("500 Internal Server Error");
+        assert_eq!(post(&url, "{}").unwrap_err(), "HTTP 500");
+        server.join().unwrap();
+
+        assert!(post("ftp://example.com", "{}").is_err());
+    }
+}
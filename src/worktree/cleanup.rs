@@ -5,7 +5,10 @@ use std::process::Command;
 use super::create::{
     worktree_is_registered, worktree_path, worktree_path_with_context, worktrees_dir_abs,
 };
-use super::git::{agent_branch_name_legacy, find_worktrees_with_branch, git_repo_root};
+use super::git::{
+    agent_branch_name_legacy, find_worktrees_with_branch, git_repo_root,
+    prune_stale_worktree_registrations,
+};
 use super::list::list_worktrees;
 use crate::run_context::RunContext;
 
@@ -26,6 +29,17 @@ pub(super) fn remove_worktree_by_path(repo_root: &Path, worktree_path: &str) ->
     }
 }
 
+/// Force-remove the worktree at `path` from the repository at `repo_root`.
+pub fn remove_worktree_in(repo_root: &Path, path: &Path) -> Result<(), String> {
+    remove_worktree_by_path(repo_root, &path.to_string_lossy())
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Drop registrations for worktrees whose directories no longer exist.
+pub fn prune_worktrees_in(repo_root: &Path) -> Result<(), String> {
+    prune_stale_worktree_registrations(repo_root)
+}
+
 /// Clean up worktrees in the specified directory.
 pub fn cleanup_worktrees_in(worktrees_dir: &Path) -> Result<(), String> {
     if !worktrees_dir.exists() {
@@ -114,7 +128,10 @@ pub fn cleanup_worktrees(base: &Path) -> Result<(), String> {
     cleanup_worktrees_in(&base.join("worktrees"))
 }
 
-fn delete_branch_in(repo_root: &Path, branch_name: &str) -> Result<bool, String> {
+/// Delete a branch by full name in the repository at `repo_root`.
+///
+/// Returns `Ok(false)` when the branch does not exist.
+pub fn delete_branch_in(repo_root: &Path, branch_name: &str) -> Result<bool, String> {
     let output = Command::new("git")
         .arg("-C")
         .arg(repo_root)
@@ -172,7 +189,7 @@ pub fn cleanup_agent_worktree(
 
     // Backward compatibility: also reconcile legacy agent naming conventions
     // (agent-A-Aaron worktree path and agent-aaron branch).
-    let legacy_path = worktree_path(&worktrees_dir, upper, agent_name);
+    let legacy_path = worktree_path(&worktrees_dir, upper, &agent_name);
     if let Some(legacy_branch) = agent_branch_name_legacy(upper) {
         cleanup_agent_artifacts(&repo_root, &legacy_path, &legacy_branch, delete_branch)?;
     }
@@ -318,6 +335,53 @@ pub fn cleanup_agent_worktrees(
     summary
 }
 
+/// Rename an agent's branch to `{branch}-sprint-{n}` for audit.
+///
+/// Agent branch names are reused across sprints of a run, so keeping a branch
+/// after cleanup means moving it out of the way before the next sprint
+/// recreates it. Returns the archived branch name, or `None` if the agent had
+/// no branch.
+pub fn archive_agent_branch(
+    initial: char,
+    sprint_number: usize,
+    ctx: &RunContext,
+) -> Result<Option<String>, String> {
+    let repo_root = git_repo_root()?;
+    let branch = ctx.agent_branch(initial.to_ascii_uppercase());
+
+    let exists = Command::new("git")
+        .arg("-C")
+        .arg(&repo_root)
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{}", branch),
+        ])
+        .output()
+        .map_err(|e| format!("failed to run git rev-parse: {}", e))?
+        .status
+        .success();
+    if !exists {
+        return Ok(None);
+    }
+
+    let archived = format!("{}-sprint-{}", branch, sprint_number);
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(&repo_root)
+        .args(["branch", "-M", &branch, &archived])
+        .output()
+        .map_err(|e| format!("failed to run git branch -M: {}", e))?;
+
+    if output.status.success() {
+        Ok(Some(archived))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("git branch -M failed: {}", stderr.trim()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -328,7 +392,7 @@ mod tests {
     use crate::testutil::with_temp_cwd;
 
     use super::super::create::create_worktrees_in;
-    use super::{cleanup_agent_worktree, cleanup_agent_worktrees};
+    use super::{archive_agent_branch, cleanup_agent_worktree, cleanup_agent_worktrees};
 
     fn run_git(args: &[&str]) -> Output {
         let output = Command::new("git")
@@ -420,6 +484,34 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_cleanup_agent_worktree_with_branch_prefix() {
+        with_temp_cwd(|| {
+            init_repo();
+            run_git(&["checkout", "-b", "base-branch"]);
+
+            let ctx = RunContext::new("greenfield", 1).with_branch_prefix("swarm/");
+            let worktrees_dir = Path::new(".swarm-hug/greenfield/worktrees");
+            let assignments = vec![('A', "Task one".to_string())];
+
+            let worktrees = create_worktrees_in(worktrees_dir, &assignments, "base-branch", &ctx)
+                .expect("create worktrees");
+            let wt_path = &worktrees[0].path;
+            let branch = ctx.agent_branch('A');
+            assert!(branch.starts_with("swarm/greenfield-agent-aaron-"));
+            assert!(wt_path.exists(), "worktree should exist before cleanup");
+            assert!(branch_exists(&branch), "prefixed branch should exist");
+
+            cleanup_agent_worktree(worktrees_dir, 'A', true, &ctx).expect("cleanup should succeed");
+
+            assert!(!wt_path.exists(), "worktree should not exist after cleanup");
+            assert!(
+                !branch_exists(&branch),
+                "prefixed branch should not exist after cleanup"
+            );
+        });
+    }
+
     #[test]
     fn test_cleanup_agent_worktree_only_affects_matching_hash() {
         with_temp_cwd(|| {
@@ -506,6 +598,36 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_cleanup_keeping_branches_then_archive() {
+        with_temp_cwd(|| {
+            init_repo();
+            run_git(&["checkout", "-b", "base-branch"]);
+
+            let ctx = RunContext::new("greenfield", 1);
+            let worktrees_dir = Path::new(".swarm-hug/greenfield/worktrees");
+            let assignments = vec![('A', "Task one".to_string())];
+            let worktrees = create_worktrees_in(worktrees_dir, &assignments, "base-branch", &ctx)
+                .expect("create worktrees");
+
+            let summary = cleanup_agent_worktrees(worktrees_dir, &['A'], false, &ctx);
+            assert!(!summary.has_errors());
+            assert!(!worktrees[0].path.exists());
+
+            let archived = archive_agent_branch('A', 7, &ctx)
+                .expect("archive")
+                .expect("branch should exist");
+            assert_eq!(archived, format!("{}-sprint-7", ctx.agent_branch('A')));
+            let heads = run_git(&["branch", "--format=%(refname:short)"]);
+            let heads = String::from_utf8_lossy(&heads.stdout);
+            assert!(heads.lines().any(|b| b == archived));
+            assert!(!heads.lines().any(|b| b == ctx.agent_branch('A')));
+
+            // Nothing left to archive once the branch has moved.
+            assert_eq!(archive_agent_branch('A', 8, &ctx).unwrap(), None);
+        });
+    }
+
     #[test]
     fn test_cleanup_agent_worktree_invalid_initial() {
         with_temp_cwd(|| {
@@ -0,0 +1,189 @@
+//! Conflict marker extraction for failed merges.
+//!
+//! `MergeResult::Conflict` only lists the conflicted files. The `_detailed`
+//! merge functions read the conflict regions here, before aborting the
+//! merge, and return them alongside the result so tooling can act on
+//! specifics.
+
+use std::fs;
+use std::path::Path;
+
+use super::git::get_merge_conflicts_in;
+
+/// A single `<<<<<<<` / `=======` / `>>>>>>>` region within a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictHunk {
+    /// 1-based line number of the `<<<<<<<` marker.
+    pub start_line: usize,
+    /// 1-based line number of the `>>>>>>>` marker.
+    pub end_line: usize,
+    /// Lines from the current branch side.
+    pub ours: Vec<String>,
+    /// Lines from the common ancestor (only present with diff3-style markers).
+    pub base: Vec<String>,
+    /// Lines from the incoming branch side.
+    pub theirs: Vec<String>,
+}
+
+/// Conflict regions for one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileConflict {
+    /// Path relative to the repository root.
+    pub path: String,
+    /// Conflict regions in file order (empty for binary or delete/modify conflicts).
+    pub hunks: Vec<ConflictHunk>,
+}
+
+#[derive(PartialEq)]
+enum Section {
+    Outside,
+    Ours,
+    Base,
+    Theirs,
+}
+
+/// Parse conflict marker regions from file content.
+///
+/// Unterminated regions are ignored.
+pub fn parse_conflict_hunks(content: &str) -> Vec<ConflictHunk> {
+    let mut hunks = Vec::new();
+    let mut section = Section::Outside;
+    let mut current: Option<ConflictHunk> = None;
+
+    for (idx, line) in content.lines().enumerate() {
+        let line_number = idx + 1;
+        if line.starts_with("<<<<<<<") {
+            section = Section::Ours;
+            current = Some(ConflictHunk {
+                start_line: line_number,
+                end_line: line_number,
+                ours: Vec::new(),
+                base: Vec::new(),
+                theirs: Vec::new(),
+            });
+            continue;
+        }
+
+        let hunk = match current.as_mut() {
+            Some(hunk) => hunk,
+            None => continue,
+        };
+
+        if line.starts_with("|||||||") && section == Section::Ours {
+            section = Section::Base;
+        } else if line.starts_with("=======")
+            && (section == Section::Ours || section == Section::Base)
+        {
+            section = Section::Theirs;
+        } else if line.starts_with(">>>>>>>") && section == Section::Theirs {
+            hunk.end_line = line_number;
+            if let Some(done) = current.take() {
+                hunks.push(done);
+            }
+            section = Section::Outside;
+        } else {
+            match section {
+                Section::Ours => hunk.ours.push(line.to_string()),
+                Section::Base => hunk.base.push(line.to_string()),
+                Section::Theirs => hunk.theirs.push(line.to_string()),
+                Section::Outside => {}
+            }
+        }
+    }
+
+    hunks
+}
+
+/// Collect per-file conflict regions from a worktree with an unresolved merge.
+///
+/// Must be called before the merge is aborted; afterwards the markers are gone.
+pub(super) fn collect_conflict_details_in(repo_root: &Path) -> Vec<FileConflict> {
+    get_merge_conflicts_in(repo_root)
+        .into_iter()
+        .map(|path| {
+            let hunks = fs::read_to_string(repo_root.join(&path))
+                .map(|content| parse_conflict_hunks(&content))
+                .unwrap_or_default();
+            FileConflict { path, hunks }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+
+    use tempfile::TempDir;
+
+    use super::{collect_conflict_details_in, parse_conflict_hunks};
+
+    fn run_git(repo: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(args)
+            .output()
+            .expect("failed to run git command")
+            .status
+            .success()
+    }
+
+    #[test]
+    fn test_parse_conflict_hunks_two_way() {
+        let content = "a\n<<<<<<< HEAD\nours\n=======\ntheirs 1\ntheirs 2\n>>>>>>> branch\nz\n";
+        let hunks = parse_conflict_hunks(content);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].start_line, 2);
+        assert_eq!(hunks[0].end_line, 7);
+        assert_eq!(hunks[0].ours, vec!["ours"]);
+        assert!(hunks[0].base.is_empty());
+        assert_eq!(hunks[0].theirs, vec!["theirs 1", "theirs 2"]);
+    }
+
+    #[test]
+    fn test_parse_conflict_hunks_diff3_and_unterminated() {
+        let content =
+            "<<<<<<< HEAD\nx\n||||||| base\nb\n=======\ny\n>>>>>>> other\n<<<<<<< HEAD\nlost\n";
+        let hunks = parse_conflict_hunks(content);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0].ours, vec!["x"]);
+        assert_eq!(hunks[0].base, vec!["b"]);
+        assert_eq!(hunks[0].theirs, vec!["y"]);
+    }
+
+    #[test]
+    fn test_collect_conflict_details_in_after_failed_merge() {
+        let temp = TempDir::new().expect("temp dir");
+        let repo = temp.path();
+        assert!(run_git(repo, &["init", "-b", "main"]));
+        assert!(run_git(repo, &["config", "user.name", "Swarm Test"]));
+        assert!(run_git(
+            repo,
+            &["config", "user.email", "swarm-test@example.com"]
+        ));
+        fs::write(repo.join("file.txt"), "line 1\nshared\nline 3\n").unwrap();
+        assert!(run_git(repo, &["add", "."]));
+        assert!(run_git(repo, &["commit", "-m", "init"]));
+
+        assert!(run_git(repo, &["checkout", "-b", "agent-aaron"]));
+        fs::write(repo.join("file.txt"), "line 1\nfrom agent\nline 3\n").unwrap();
+        assert!(run_git(repo, &["commit", "-am", "agent change"]));
+
+        assert!(run_git(repo, &["checkout", "main"]));
+        fs::write(repo.join("file.txt"), "line 1\nfrom main\nline 3\n").unwrap();
+        assert!(run_git(repo, &["commit", "-am", "main change"]));
+
+        assert!(!run_git(repo, &["merge", "--no-ff", "agent-aaron"]));
+
+        let details = collect_conflict_details_in(repo);
+        assert_eq!(details.len(), 1);
+        assert_eq!(details[0].path, "file.txt");
+        assert_eq!(details[0].hunks.len(), 1);
+        let hunk = &details[0].hunks[0];
+        assert_eq!(hunk.start_line, 2);
+        assert_eq!(hunk.ours, vec!["from main"]);
+        assert_eq!(hunk.theirs, vec!["from agent"]);
+    }
+}
@@ -2,6 +2,9 @@ use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
 
 use super::cleanup::remove_worktree_by_path;
 use super::git::{
@@ -11,6 +14,9 @@ use super::git::{
 use super::Worktree;
 use crate::run_context::RunContext;
 
+/// Upper bound on agent worktrees created at the same time.
+const MAX_PARALLEL_WORKTREE_CREATES: usize = 4;
+
 pub(super) fn worktrees_dir_abs(worktrees_dir: &Path, repo_root: &Path) -> PathBuf {
     if worktrees_dir.is_absolute() {
         worktrees_dir.to_path_buf()
@@ -79,7 +85,7 @@ pub(super) fn worktree_is_registered(repo_root: &Path, path: &Path) -> Result<bo
 /// Legacy worktree path without namespacing.
 /// Format: agent-{INITIAL}-{name} (e.g., agent-A-Aaron)
 pub(super) fn worktree_path(root: &Path, initial: char, name: &str) -> PathBuf {
-    root.join(format!("agent-{}-{}", initial, name))
+    root.join(format!("agent-{}-{}", crate::agent::id(initial), name))
 }
 
 /// Worktree path using the namespaced branch name from RunContext.
@@ -98,6 +104,10 @@ pub(super) fn worktree_path_with_context(root: &Path, ctx: &RunContext, initial:
 /// namespaced branch and worktree names, ensuring isolation between projects
 /// and sprint runs.
 ///
+/// Worktrees are created concurrently on a few threads; the result keeps the
+/// order of first appearance in `assignments`. If any agent fails, the first
+/// failure in that order is returned.
+///
 /// # Arguments
 /// * `worktrees_dir` - Directory where worktrees will be created
 /// * `assignments` - List of (agent_initial, task_description) tuples
@@ -141,62 +151,117 @@ pub fn create_worktrees_in(
     fs::create_dir_all(&worktrees_dir)
         .map_err(|e| format!("failed to create worktrees dir: {}", e))?;
 
-    let mut registered = registered_worktrees(&repo_root)?;
+    let registered = registered_worktrees(&repo_root)?;
 
+    let mut initials = Vec::new();
     for (initial, _task) in assignments {
         let upper = initial.to_ascii_uppercase();
-        if !seen.insert(upper) {
-            continue;
+        if seen.insert(upper) {
+            initials.push(upper);
         }
-        let name = crate::agent::name_from_initial(upper).unwrap_or("Unknown");
-
-        // Use namespaced branch and path from RunContext
-        let branch = agent_branch_name(ctx, upper);
-        let path = worktree_path_with_context(&worktrees_dir, ctx, upper);
-        let path_str = path.to_string_lossy().to_string();
+    }
 
-        // If worktree already exists, remove it first to ensure a fresh start
-        if is_registered_path(&registered, &path) {
-            let _ = Command::new("git")
-                .arg("-C")
-                .arg(&repo_root)
-                .args(["worktree", "remove", "--force", &path_str])
-                .output();
-            registered.remove(&path_str);
+    // Workers pull agents off a shared queue; results keep assignment order
+    let git_lock = Mutex::new(());
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<Result<Worktree, String>>>> =
+        initials.iter().map(|_| Mutex::new(None)).collect();
+    let workers = initials.len().min(MAX_PARALLEL_WORKTREE_CREATES);
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(&initial) = initials.get(index) else {
+                    break;
+                };
+                let result = create_agent_worktree(
+                    &repo_root,
+                    &worktrees_dir,
+                    &registered,
+                    base,
+                    ctx,
+                    initial,
+                    &git_lock,
+                );
+                *results[index].lock().unwrap_or_else(|e| e.into_inner()) = Some(result);
+            });
         }
+    });
 
-        // If path exists but not registered, remove the directory
-        if path.exists() {
-            fs::remove_dir_all(&path).map_err(|e| {
-                format!(
-                    "failed to remove stale worktree dir {}: {}",
-                    path.display(),
-                    e
-                )
-            })?;
-        }
+    for result in results {
+        let result = result.into_inner().unwrap_or_else(|e| e.into_inner());
+        created.push(result.ok_or("worktree creation thread did not finish")??);
+    }
+
+    Ok(created)
+}
+
+/// Create one agent's worktree.
+///
+/// Steps that touch the shared `.git` directory run under `git_lock`; the
+/// checkout into the new worktree runs unlocked so agents overlap there.
+fn create_agent_worktree(
+    repo_root: &Path,
+    worktrees_dir: &Path,
+    registered: &HashSet<String>,
+    base: &str,
+    ctx: &RunContext,
+    initial: char,
+    git_lock: &Mutex<()>,
+) -> Result<Worktree, String> {
+    let name = crate::agent::name_from_initial(initial).unwrap_or_else(|| "Unknown".to_string());
+
+    // Use namespaced branch and path from RunContext
+    let branch = agent_branch_name(ctx, initial);
+    let path = worktree_path_with_context(worktrees_dir, ctx, initial);
+    let path_str = path.to_string_lossy().to_string();
+
+    // If worktree already exists, remove it first to ensure a fresh start
+    if is_registered_path(registered, &path) {
+        let _guard = git_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = Command::new("git")
+            .arg("-C")
+            .arg(repo_root)
+            .args(["worktree", "remove", "--force", &path_str])
+            .output();
+    }
+
+    // If path exists but not registered, remove the directory
+    if path.exists() {
+        fs::remove_dir_all(&path).map_err(|e| {
+            format!(
+                "failed to remove stale worktree dir {}: {}",
+                path.display(),
+                e
+            )
+        })?;
+    }
+
+    {
+        let _guard = git_lock.lock().unwrap_or_else(|e| e.into_inner());
 
         // Before deleting the branch, remove any worktrees that have it checked out
         // (this handles multi-team scenarios where another team's worktree uses this branch)
-        if let Ok(worktrees_with_branch) = find_worktrees_with_branch(&repo_root, &branch) {
+        if let Ok(worktrees_with_branch) = find_worktrees_with_branch(repo_root, &branch) {
             for wt_path in worktrees_with_branch {
                 // Don't fail if removal fails - we'll get the error on branch delete or worktree add
-                let _ = remove_worktree_by_path(&repo_root, &wt_path);
+                let _ = remove_worktree_by_path(repo_root, &wt_path);
             }
         }
 
         // Delete the branch if it exists (to ensure fresh start from HEAD)
         let _ = Command::new("git")
             .arg("-C")
-            .arg(&repo_root)
+            .arg(repo_root)
             .args(["branch", "-D", &branch])
             .output();
 
-        // Create fresh worktree with new branch from the base branch
+        // Register a fresh worktree with a new branch from the base branch;
+        // files are checked out below, outside the lock
         let mut cmd = Command::new("git");
         cmd.arg("-C")
-            .arg(&repo_root)
-            .args(["worktree", "add", "--relative-paths"]);
+            .arg(repo_root)
+            .args(["worktree", "add", "--relative-paths", "--no-checkout"]);
         let output = cmd
             .args(["-B", &branch, &path_str, base])
             .output()
@@ -211,18 +276,51 @@ pub fn create_worktrees_in(
             ));
         }
 
-        repair_worktree_links(&repo_root, &path)
+        repair_worktree_links(repo_root, &path)
             .map_err(|e| format!("git worktree repair failed for {}: {}", path.display(), e))?;
 
-        registered.insert(path_str);
-        created.push(Worktree {
-            path,
-            initial: upper,
-            name: name.to_string(),
-        });
+        // Sparse-checkout enables per-worktree config in the shared repo config
+        if !ctx.sparse_paths.is_empty() {
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(&path)
+                .args(["sparse-checkout", "set", "--cone", "--"])
+                .args(&ctx.sparse_paths)
+                .output()
+                .map_err(|e| format!("failed to run git sparse-checkout: {}", e))?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!(
+                    "git sparse-checkout set failed for {}: {}",
+                    path.display(),
+                    stderr.trim()
+                ));
+            }
+        }
     }
 
-    Ok(created)
+    // Populate the index and working tree (honoring any sparse paths); only
+    // this worktree's files change
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(&path)
+        .args(["reset", "--hard", "--quiet"])
+        .output()
+        .map_err(|e| format!("failed to run git reset: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "git checkout failed for {}: {}",
+            path.display(),
+            stderr.trim()
+        ));
+    }
+
+    Ok(Worktree {
+        path,
+        initial,
+        name: name.to_string(),
+    })
 }
 
 /// Create a feature/sprint worktree under the specified worktrees directory.
@@ -489,6 +587,76 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_create_worktrees_in_creates_many_concurrently() {
+        with_temp_cwd(|| {
+            init_repo();
+            run_git(&["checkout", "-b", "alpha-sprint-1"]);
+
+            let ctx = RunContext::new("alpha", 1);
+            let worktrees_dir = Path::new(".swarm-hug/alpha/worktrees");
+            let initials = ['E', 'B', 'D', 'A', 'C'];
+            let assignments: Vec<(char, String)> = initials
+                .iter()
+                .map(|&initial| (initial, format!("Task for {}", initial)))
+                .collect();
+            let worktrees =
+                create_worktrees_in(worktrees_dir, &assignments, "alpha-sprint-1", &ctx)
+                    .expect("create worktrees");
+
+            let created: Vec<char> = worktrees.iter().map(|wt| wt.initial).collect();
+            assert_eq!(created, initials);
+            for wt in &worktrees {
+                assert!(wt.path.join("README.md").exists(), "{:?}", wt.path);
+                let branch = run_git_in(&wt.path, &["rev-parse", "--abbrev-ref", "HEAD"]);
+                assert_eq!(
+                    String::from_utf8_lossy(&branch.stdout).trim(),
+                    ctx.agent_branch(wt.initial)
+                );
+                let status = run_git_in(&wt.path, &["status", "--porcelain"]);
+                assert!(status.stdout.is_empty(), "{:?} is not clean", wt.path);
+            }
+
+            let list = run_git(&["worktree", "list", "--porcelain"]);
+            let listed = String::from_utf8_lossy(&list.stdout)
+                .lines()
+                .filter(|line| line.starts_with("worktree "))
+                .count();
+            assert_eq!(listed, 1 + initials.len());
+        });
+    }
+
+    #[test]
+    fn test_create_worktrees_in_sparse_paths_limit_checkout() {
+        with_temp_cwd(|| {
+            init_repo();
+            fs::create_dir_all("services/auth").expect("create auth dir");
+            fs::create_dir_all("services/billing").expect("create billing dir");
+            fs::write("services/auth/login.rs", "auth").expect("write auth file");
+            fs::write("services/billing/invoice.rs", "billing").expect("write billing file");
+            run_git(&["add", "."]);
+            run_git(&["commit", "-m", "services"]);
+            run_git(&["checkout", "-b", "alpha-sprint-1"]);
+
+            let ctx = RunContext::new("alpha", 1).with_sparse_paths(&["services/auth".to_string()]);
+            let worktrees_dir = Path::new(".swarm-hug/alpha/worktrees");
+            let assignments = vec![('A', "Task one".to_string())];
+            let worktrees =
+                create_worktrees_in(worktrees_dir, &assignments, "alpha-sprint-1", &ctx)
+                    .expect("create worktrees");
+            let wt_path = &worktrees[0].path;
+
+            assert!(wt_path.join("services/auth/login.rs").exists());
+            assert!(wt_path.join("README.md").exists());
+            assert!(!wt_path.join("services/billing").exists());
+            let status = run_git_in(wt_path, &["status", "--porcelain"]);
+            assert!(status.stdout.is_empty(), "sparse worktree is not clean");
+
+            // The main checkout keeps every file
+            assert!(Path::new("services/billing/invoice.rs").exists());
+        });
+    }
+
     #[test]
     fn test_create_worktrees_in_recreates_existing_worktree() {
         with_temp_cwd(|| {
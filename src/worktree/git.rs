@@ -3,6 +3,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use super::conflict::{collect_conflict_details_in, FileConflict};
 use crate::run_context::RunContext;
 
 pub(super) fn git_repo_root() -> Result<PathBuf, String> {
@@ -378,7 +379,7 @@ pub(super) fn find_worktrees_with_branch(
 }
 
 /// Get the branch name for an agent with project namespace and run hash.
-/// Format: {project}-agent-{name}-{hash} (e.g., greenfield-agent-aaron-a3f8k2)
+/// Format: {prefix}{project}-agent-{name}-{hash} (e.g., greenfield-agent-aaron-a3f8k2)
 ///
 /// This is the preferred function for creating agent branch names when you have
 /// a `RunContext`. For legacy code paths that don't yet have context, use
@@ -534,6 +535,19 @@ pub enum MergeResult {
     Error(String),
 }
 
+impl MergeResult {
+    /// Conflicted file paths, if this result is a conflict.
+    ///
+    /// For the conflict regions themselves, use one of the `_detailed` merge
+    /// functions, which read them before the merge is aborted.
+    pub fn conflict_files(&self) -> Option<&[String]> {
+        match self {
+            Self::Conflict(files) => Some(files),
+            _ => None,
+        }
+    }
+}
+
 /// Check if an agent branch has changes relative to a target branch.
 pub fn agent_branch_has_changes(initial: char, target: &str) -> Result<bool, String> {
     let repo_root = git_repo_root()?;
@@ -636,6 +650,27 @@ pub fn merge_agent_branch_in(
     repo_root: &Path,
     initial: char,
     target_branch: Option<&str>,
+) -> MergeResult {
+    merge_agent_branch_in_detailed(repo_root, initial, target_branch).0
+}
+
+/// Like [`merge_agent_branch_in`], also returning the conflict regions read
+/// before the merge was aborted (empty unless the result is a conflict).
+pub fn merge_agent_branch_in_detailed(
+    repo_root: &Path,
+    initial: char,
+    target_branch: Option<&str>,
+) -> (MergeResult, Vec<FileConflict>) {
+    let mut conflicts = Vec::new();
+    let result = merge_agent_branch_capturing(repo_root, initial, target_branch, &mut conflicts);
+    (result, conflicts)
+}
+
+fn merge_agent_branch_capturing(
+    repo_root: &Path,
+    initial: char,
+    target_branch: Option<&str>,
+    conflicts: &mut Vec<FileConflict>,
 ) -> MergeResult {
     let branch = match agent_branch_name_legacy(initial) {
         Some(b) => b,
@@ -665,7 +700,8 @@ pub fn merge_agent_branch_in(
     }
 
     // Get agent name for commit message
-    let agent_name = crate::agent::name_from_initial(initial).unwrap_or("Unknown");
+    let agent_name =
+        crate::agent::name_from_initial(initial).unwrap_or_else(|| "Unknown".to_string());
 
     // Attempt merge with --no-ff
     let merge = Command::new("git")
@@ -679,32 +715,24 @@ pub fn merge_agent_branch_in(
             &branch,
         ])
         .env("GIT_AUTHOR_NAME", format!("Agent {}", agent_name))
-        .env("GIT_AUTHOR_EMAIL", format!("agent-{}@swarm.local", initial))
+        .env(
+            "GIT_AUTHOR_EMAIL",
+            format!("agent-{}@swarm.local", crate::agent::id(initial)),
+        )
         .env("GIT_COMMITTER_NAME", format!("Agent {}", agent_name))
         .env(
             "GIT_COMMITTER_EMAIL",
-            format!("agent-{}@swarm.local", initial),
+            format!("agent-{}@swarm.local", crate::agent::id(initial)),
         )
         .output();
 
     match merge {
         Err(e) => MergeResult::Error(format!("merge command failed: {}", e)),
         Ok(output) if output.status.success() => MergeResult::Success,
-        Ok(_) => {
-            // Check for conflicts
-            let conflicts = get_merge_conflicts_in(repo_root);
-            if !conflicts.is_empty() {
-                // Abort the merge
-                let _ = Command::new("git")
-                    .arg("-C")
-                    .arg(repo_root)
-                    .args(["merge", "--abort"])
-                    .output();
-                MergeResult::Conflict(conflicts)
-            } else {
-                MergeResult::Error("merge failed".to_string())
-            }
-        }
+        Ok(_) => match capture_conflicts_and_abort(repo_root, conflicts) {
+            Some(files) => MergeResult::Conflict(files),
+            None => MergeResult::Error("merge failed".to_string()),
+        },
     }
 }
 
@@ -715,6 +743,35 @@ pub fn merge_agent_branch_in_with_ctx(
     ctx: &RunContext,
     initial: char,
     target_branch: Option<&str>,
+) -> MergeResult {
+    merge_agent_branch_in_with_ctx_detailed(repo_root, ctx, initial, target_branch).0
+}
+
+/// Like [`merge_agent_branch_in_with_ctx`], also returning the conflict
+/// regions read before the merge was aborted.
+pub fn merge_agent_branch_in_with_ctx_detailed(
+    repo_root: &Path,
+    ctx: &RunContext,
+    initial: char,
+    target_branch: Option<&str>,
+) -> (MergeResult, Vec<FileConflict>) {
+    let mut conflicts = Vec::new();
+    let result = merge_agent_branch_with_ctx_capturing(
+        repo_root,
+        ctx,
+        initial,
+        target_branch,
+        &mut conflicts,
+    );
+    (result, conflicts)
+}
+
+fn merge_agent_branch_with_ctx_capturing(
+    repo_root: &Path,
+    ctx: &RunContext,
+    initial: char,
+    target_branch: Option<&str>,
+    conflicts: &mut Vec<FileConflict>,
 ) -> MergeResult {
     let branch = agent_branch_name(ctx, initial);
 
@@ -741,7 +798,8 @@ pub fn merge_agent_branch_in_with_ctx(
     }
 
     // Get agent name for commit message
-    let agent_name = crate::agent::name_from_initial(initial).unwrap_or("Unknown");
+    let agent_name =
+        crate::agent::name_from_initial(initial).unwrap_or_else(|| "Unknown".to_string());
 
     // Attempt merge with --no-ff
     let merge = Command::new("git")
@@ -755,35 +813,50 @@ pub fn merge_agent_branch_in_with_ctx(
             &branch,
         ])
         .env("GIT_AUTHOR_NAME", format!("Agent {}", agent_name))
-        .env("GIT_AUTHOR_EMAIL", format!("agent-{}@swarm.local", initial))
+        .env(
+            "GIT_AUTHOR_EMAIL",
+            format!("agent-{}@swarm.local", crate::agent::id(initial)),
+        )
         .env("GIT_COMMITTER_NAME", format!("Agent {}", agent_name))
         .env(
             "GIT_COMMITTER_EMAIL",
-            format!("agent-{}@swarm.local", initial),
+            format!("agent-{}@swarm.local", crate::agent::id(initial)),
         )
         .output();
 
     match merge {
         Err(e) => MergeResult::Error(format!("merge command failed: {}", e)),
         Ok(output) if output.status.success() => MergeResult::Success,
-        Ok(_) => {
-            // Check for conflicts
-            let conflicts = get_merge_conflicts_in(repo_root);
-            if !conflicts.is_empty() {
-                // Abort the merge
-                let _ = Command::new("git")
-                    .arg("-C")
-                    .arg(repo_root)
-                    .args(["merge", "--abort"])
-                    .output();
-                MergeResult::Conflict(conflicts)
-            } else {
-                MergeResult::Error("merge failed".to_string())
-            }
-        }
+        Ok(_) => match capture_conflicts_and_abort(repo_root, conflicts) {
+            Some(files) => MergeResult::Conflict(files),
+            None => MergeResult::Error("merge failed".to_string()),
+        },
     }
 }
 
+/// Read the conflict regions of a failed merge into `conflicts`, then abort
+/// it and return the conflicted file paths.
+///
+/// Returns `None` when the failure left no conflicted files (the merge is
+/// left as is).
+fn capture_conflicts_and_abort(
+    repo_root: &Path,
+    conflicts: &mut Vec<FileConflict>,
+) -> Option<Vec<String>> {
+    let details = collect_conflict_details_in(repo_root);
+    if details.is_empty() {
+        return None;
+    }
+    let _ = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["merge", "--abort"])
+        .output();
+    let files = details.iter().map(|c| c.path.clone()).collect();
+    *conflicts = details;
+    Some(files)
+}
+
 /// Check whether a source branch has been merged into a target branch.
 pub fn branch_is_merged(source_branch: &str, target_branch: &str) -> Result<bool, String> {
     let repo_root = git_repo_root()?;
@@ -832,17 +905,29 @@ fn branch_is_merged_in(
 
 /// Merge a feature branch into a target branch in the main repo.
 pub fn merge_feature_branch(feature_branch: &str, target_branch: &str) -> MergeResult {
+    merge_feature_branch_detailed(feature_branch, target_branch).0
+}
+
+/// Like [`merge_feature_branch`], also returning the conflict regions read
+/// before the merge was aborted.
+pub fn merge_feature_branch_detailed(
+    feature_branch: &str,
+    target_branch: &str,
+) -> (MergeResult, Vec<FileConflict>) {
     let repo_root = match git_repo_root() {
         Ok(root) => root,
-        Err(e) => return MergeResult::Error(e),
+        Err(e) => return (MergeResult::Error(e), Vec::new()),
     };
-    merge_feature_branch_in(&repo_root, feature_branch, target_branch)
+    let mut conflicts = Vec::new();
+    let result = merge_feature_branch_in(&repo_root, feature_branch, target_branch, &mut conflicts);
+    (result, conflicts)
 }
 
 fn merge_feature_branch_in(
     repo_root: &Path,
     feature_branch: &str,
     target_branch: &str,
+    conflicts: &mut Vec<FileConflict>,
 ) -> MergeResult {
     let feature = feature_branch.trim();
     if feature.is_empty() {
@@ -916,14 +1001,8 @@ fn merge_feature_branch_in(
         Err(e) => MergeResult::Error(format!("merge command failed: {}", e)),
         Ok(output) if output.status.success() => MergeResult::Success,
         Ok(output) => {
-            let conflicts = get_merge_conflicts_in(repo_root);
-            if !conflicts.is_empty() {
-                let _ = Command::new("git")
-                    .arg("-C")
-                    .arg(repo_root)
-                    .args(["merge", "--abort"])
-                    .output();
-                MergeResult::Conflict(conflicts)
+            if let Some(files) = capture_conflicts_and_abort(repo_root, conflicts) {
+                MergeResult::Conflict(files)
             } else {
                 let stderr = String::from_utf8_lossy(&output.stderr);
                 let detail = stderr.trim();
@@ -979,7 +1058,7 @@ fn cleanup_untracked_swarm_hug_files(repo_root: &Path) -> Result<(), String> {
 }
 
 /// Get list of files with merge conflicts in the specified repo/worktree.
-fn get_merge_conflicts_in(repo_root: &Path) -> Vec<String> {
+pub(super) fn get_merge_conflicts_in(repo_root: &Path) -> Vec<String> {
     let output = Command::new("git")
         .arg("-C")
         .arg(repo_root)
@@ -1070,8 +1149,8 @@ mod tests {
 
     use super::{
         branch_is_merged_in, create_feature_branch_in, merge_agent_branch_in,
-        merge_agent_branch_in_with_ctx, merge_feature_branch_in, parse_worktrees_with_branch,
-        MergeResult,
+        merge_agent_branch_in_detailed, merge_agent_branch_in_with_ctx, merge_feature_branch_in,
+        parse_worktrees_with_branch, MergeResult,
     };
 
     fn run_git(repo: &Path, args: &[&str]) -> Output {
@@ -1329,6 +1408,46 @@ branch refs/heads/agent-aaron
         assert_eq!(content, "change");
     }
 
+    #[test]
+    fn test_merge_agent_branch_in_conflict_carries_hunks() {
+        let temp = TempDir::new().expect("temp dir");
+        let repo = temp.path();
+        init_repo(repo);
+
+        run_git(repo, &["checkout", "-b", "alpha-sprint-1"]);
+        fs::write(repo.join("file.txt"), "line 1\nshared\nline 3\n").expect("write base");
+        run_git(repo, &["add", "."]);
+        run_git(repo, &["commit", "-m", "base"]);
+
+        run_git(repo, &["checkout", "-b", "agent-aaron"]);
+        fs::write(repo.join("file.txt"), "line 1\nfrom agent\nline 3\n").expect("write agent");
+        run_git(repo, &["commit", "-am", "agent change"]);
+
+        run_git(repo, &["checkout", "alpha-sprint-1"]);
+        fs::write(repo.join("file.txt"), "line 1\nfrom sprint\nline 3\n").expect("write sprint");
+        run_git(repo, &["commit", "-am", "sprint change"]);
+
+        let (result, conflicts) = merge_agent_branch_in_detailed(repo, 'A', Some("alpha-sprint-1"));
+        assert!(
+            matches!(&result, MergeResult::Conflict(files) if files == &["file.txt"]),
+            "expected conflict, got {:?}",
+            result
+        );
+        assert_eq!(result.conflict_files(), Some(&["file.txt".to_string()][..]));
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, "file.txt");
+        assert_eq!(conflicts[0].hunks.len(), 1);
+        let hunk = &conflicts[0].hunks[0];
+        assert_eq!(hunk.start_line, 2);
+        assert_eq!(hunk.ours, vec!["from sprint"]);
+        assert_eq!(hunk.theirs, vec!["from agent"]);
+
+        // The merge was aborted after the hunks were read.
+        assert!(super::get_merge_conflicts_in(repo).is_empty());
+        let content = fs::read_to_string(repo.join("file.txt")).expect("read file");
+        assert_eq!(content, "line 1\nfrom sprint\nline 3\n");
+    }
+
     #[test]
     fn test_merge_agent_branch_in_with_ctx_recovers_from_unresolved_index() {
         let temp = TempDir::new().expect("temp dir");
@@ -1407,7 +1526,7 @@ branch refs/heads/agent-aaron
             branch_is_merged_in(repo, "feature-branch", "main").expect("merge check before");
         assert!(!merged_before);
 
-        let merge_result = merge_feature_branch_in(repo, "feature-branch", "main");
+        let merge_result = merge_feature_branch_in(repo, "feature-branch", "main", &mut Vec::new());
         assert!(matches!(merge_result, MergeResult::Success));
 
         let merged_after =
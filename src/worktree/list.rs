@@ -1,5 +1,5 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use super::Worktree;
@@ -89,3 +89,77 @@ pub fn list_agent_branches() -> Result<Vec<AgentBranch>, String> {
     branches.sort_by(|a, b| a.initial.cmp(&b.initial));
     Ok(branches)
 }
+
+/// Every worktree registered with git and the branch it has checked out
+/// (`None` when detached), from one `git worktree list --porcelain`.
+///
+/// Relative paths are resolved against `repo_root`.
+pub fn registered_worktrees_in(repo_root: &Path) -> Result<Vec<(PathBuf, Option<String>)>, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .map_err(|e| format!("failed to run git worktree list: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git worktree list failed: {}", stderr.trim()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_worktree_list(&stdout, repo_root))
+}
+
+fn parse_worktree_list(porcelain_output: &str, repo_root: &Path) -> Vec<(PathBuf, Option<String>)> {
+    let mut worktrees: Vec<(PathBuf, Option<String>)> = Vec::new();
+    for line in porcelain_output.lines() {
+        if let Some(path) = line.strip_prefix("worktree ") {
+            let path = PathBuf::from(path.trim());
+            let path = if path.is_absolute() {
+                path
+            } else {
+                repo_root.join(path)
+            };
+            worktrees.push((path, None));
+        } else if let Some(branch) = line.strip_prefix("branch refs/heads/") {
+            if let Some((_, current)) = worktrees.last_mut() {
+                *current = Some(branch.trim().to_string());
+            }
+        }
+    }
+    worktrees
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_worktree_list_pairs_paths_with_branches() {
+        let porcelain = "\
+worktree /repo
+HEAD abc123
+branch refs/heads/main
+
+worktree /repo/.swarm-hug/alpha/worktrees/alpha-agent-aaron-abc123
+HEAD def456
+branch refs/heads/alpha-agent-aaron-abc123
+
+worktree wt/detached
+HEAD 789abc
+detached
+";
+        assert_eq!(
+            parse_worktree_list(porcelain, Path::new("/repo")),
+            vec![
+                (PathBuf::from("/repo"), Some("main".to_string())),
+                (
+                    PathBuf::from("/repo/.swarm-hug/alpha/worktrees/alpha-agent-aaron-abc123"),
+                    Some("alpha-agent-aaron-abc123".to_string())
+                ),
+                (PathBuf::from("/repo/wt/detached"), None),
+            ]
+        );
+    }
+}
@@ -10,10 +10,13 @@
 //! In multi-team mode, worktrees are created under `.swarm-hug/<team>/worktrees/`.
 
 mod cleanup;
+mod conflict;
 mod create;
 mod git;
 mod list;
+mod prune;
 mod target;
+mod usage;
 
 #[derive(Debug, Clone)]
 pub struct Worktree {
@@ -23,22 +26,27 @@ pub struct Worktree {
 }
 
 pub use cleanup::{
-    cleanup_agent_worktree, cleanup_agent_worktrees, cleanup_feature_worktree, cleanup_worktrees,
-    cleanup_worktrees_in, delete_branch, CleanupSummary,
+    archive_agent_branch, cleanup_agent_worktree, cleanup_agent_worktrees,
+    cleanup_feature_worktree, cleanup_worktrees, cleanup_worktrees_in, delete_branch,
+    delete_branch_in, prune_worktrees_in, remove_worktree_in, CleanupSummary,
 };
+pub use conflict::{parse_conflict_hunks, ConflictHunk, FileConflict};
 pub use create::{create_feature_worktree_in, create_worktrees_in};
 pub use git::{
     agent_branch_exists, agent_branch_has_changes, agent_branch_name, branch_is_merged,
     create_feature_branch, create_feature_branch_in, delete_agent_branch, merge_agent_branch,
-    merge_agent_branch_in, merge_agent_branch_in_with_ctx, merge_all_agent_branches,
-    merge_feature_branch, MergeResult, MergeSummary,
+    merge_agent_branch_in, merge_agent_branch_in_detailed, merge_agent_branch_in_with_ctx,
+    merge_agent_branch_in_with_ctx_detailed, merge_all_agent_branches, merge_feature_branch,
+    merge_feature_branch_detailed, MergeResult, MergeSummary,
 };
-pub use list::{list_agent_branches, list_worktrees, AgentBranch};
+pub use list::{list_agent_branches, list_worktrees, registered_worktrees_in, AgentBranch};
+pub use prune::{prune_preserved, PruneSummary, PRESERVED_DIR};
 pub use target::{
     create_target_branch_worktree, create_target_branch_worktree_in, ensure_shared_worktrees_root,
     find_target_branch_worktree, find_target_branch_worktree_in, shared_worktrees_root,
     validate_target_branch_worktree, validate_target_branch_worktree_in,
 };
+pub use usage::{disk_usage, worktree_dirs};
 
 #[cfg(test)]
 mod tests {
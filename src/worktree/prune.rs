@@ -0,0 +1,142 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use super::cleanup::remove_worktree_by_path;
+use super::create::{worktree_is_registered, worktrees_dir_abs};
+use super::git::{git_repo_root, prune_stale_worktree_registrations};
+use super::usage::dir_size;
+
+/// Subdirectory of a worktrees dir where worktrees are kept after merge failures.
+pub const PRESERVED_DIR: &str = "preserved";
+
+/// Result of pruning preserved worktrees.
+#[derive(Debug, Default)]
+pub struct PruneSummary {
+    /// Preserved worktrees that were removed.
+    pub removed: Vec<PathBuf>,
+    /// Disk space freed by the removed worktrees, in bytes.
+    pub bytes_reclaimed: u64,
+    /// Failures for individual worktrees (pruning continues past them).
+    pub errors: Vec<String>,
+}
+
+/// Remove preserved worktrees under `worktrees_dir/preserved/` whose
+/// directory was last modified at least `older_than` ago, then prune stale
+/// git worktree registrations.
+///
+/// Registered worktrees are removed with `git worktree remove`; leftover
+/// directories git no longer knows about are deleted directly.
+pub fn prune_preserved(worktrees_dir: &Path, older_than: Duration) -> Result<PruneSummary, String> {
+    let repo_root = git_repo_root().ok();
+    let worktrees_dir = match &repo_root {
+        Some(root) => worktrees_dir_abs(worktrees_dir, root),
+        None => worktrees_dir.to_path_buf(),
+    };
+    let preserved_root = worktrees_dir.join(PRESERVED_DIR);
+
+    let mut summary = PruneSummary::default();
+    if preserved_root.exists() {
+        let entries = fs::read_dir(&preserved_root)
+            .map_err(|e| format!("failed to read {}: {}", preserved_root.display(), e))?;
+        let now = SystemTime::now();
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(meta) = fs::symlink_metadata(&path) else {
+                continue;
+            };
+            if !meta.is_dir() {
+                continue;
+            }
+            let age = meta
+                .modified()
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok())
+                .unwrap_or_default();
+            if age < older_than {
+                continue;
+            }
+
+            let size = dir_size(&path);
+            let registered = repo_root
+                .as_deref()
+                .is_some_and(|root| worktree_is_registered(root, &path).unwrap_or(false));
+            let result = match &repo_root {
+                Some(root) if registered => remove_worktree_by_path(root, &path.to_string_lossy()),
+                _ => fs::remove_dir_all(&path).map_err(|e| format!("failed to remove: {}", e)),
+            };
+            match result {
+                Ok(()) => {
+                    summary.bytes_reclaimed += size;
+                    summary.removed.push(path);
+                }
+                Err(e) => summary.errors.push(format!("{}: {}", path.display(), e)),
+            }
+        }
+    }
+
+    if let Some(root) = &repo_root {
+        prune_stale_worktree_registrations(root)?;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::process::Command;
+
+    use super::*;
+    use crate::testutil::with_temp_cwd;
+
+    fn set_dir_mtime(path: &Path, age: Duration) {
+        let dir = File::open(path).expect("open dir");
+        dir.set_modified(SystemTime::now() - age)
+            .expect("set dir mtime");
+    }
+
+    #[test]
+    fn test_prune_preserved_removes_only_old_worktrees() {
+        with_temp_cwd(|| {
+            let output = Command::new("git")
+                .args(["init", "-q"])
+                .output()
+                .expect("git init");
+            assert!(output.status.success());
+
+            let worktrees_dir = Path::new(".swarm-hug/alpha/worktrees");
+            let preserved = worktrees_dir.join(PRESERVED_DIR);
+            let old = preserved.join("alpha-agent-aaron-abc123-preserved-1-100");
+            let recent = preserved.join("alpha-agent-betty-abc123-preserved-1-200");
+            for dir in [&old, &recent] {
+                fs::create_dir_all(dir.join("src")).expect("create preserved dir");
+                fs::write(dir.join("src/lib.rs"), "fn main() {}\n").expect("write file");
+                fs::write(dir.join("notes.txt"), "x".repeat(1000)).expect("write file");
+            }
+            set_dir_mtime(&old, Duration::from_secs(10 * 24 * 60 * 60));
+            set_dir_mtime(&recent, Duration::from_secs(60));
+
+            let summary = prune_preserved(worktrees_dir, Duration::from_secs(7 * 24 * 60 * 60))
+                .expect("prune preserved");
+
+            assert!(summary.errors.is_empty(), "{:?}", summary.errors);
+            assert_eq!(summary.removed.len(), 1);
+            assert!(summary.removed[0].ends_with(old.file_name().unwrap()));
+            assert_eq!(summary.bytes_reclaimed, 13 + 1000);
+            assert!(!old.exists());
+            assert!(recent.join("notes.txt").exists());
+        });
+    }
+
+    #[test]
+    fn test_prune_preserved_without_preserved_dir() {
+        with_temp_cwd(|| {
+            let summary =
+                prune_preserved(Path::new("worktrees"), Duration::ZERO).expect("prune preserved");
+            assert!(summary.removed.is_empty());
+            assert_eq!(summary.bytes_reclaimed, 0);
+        });
+    }
+}
@@ -0,0 +1,125 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::prune::PRESERVED_DIR;
+
+/// Worktree directories under `worktrees_dir`, sorted by name.
+///
+/// Worktrees kept after merge failures are listed as `preserved/<name>`.
+pub fn worktree_dirs(worktrees_dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut dirs = Vec::new();
+    for (name, path) in subdirs(worktrees_dir) {
+        if name == PRESERVED_DIR {
+            for (preserved, path) in subdirs(&path) {
+                dirs.push((format!("{}/{}", PRESERVED_DIR, preserved), path));
+            }
+        } else {
+            dirs.push((name, path));
+        }
+    }
+    dirs.sort();
+    dirs
+}
+
+/// Bytes used by each worktree under `worktrees_dir`, named as in
+/// [`worktree_dirs`]. Symlinks count as links and are never followed.
+pub fn disk_usage(worktrees_dir: &Path) -> Vec<(String, u64)> {
+    worktree_dirs(worktrees_dir)
+        .into_iter()
+        .map(|(name, path)| (name, dir_size(&path)))
+        .collect()
+}
+
+/// Total size of the files under `path`, without following symlinks.
+pub(super) fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match fs::symlink_metadata(entry.path()) {
+            Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Real (non-symlink) subdirectories of `dir` with their names.
+fn subdirs(dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().to_string(),
+                entry.path(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_disk_usage_reports_known_file_size() {
+        let temp = TempDir::new().unwrap();
+        let worktrees_dir = temp.path().join("worktrees");
+        let agent = worktrees_dir.join("alpha-agent-aaron-abc123");
+        fs::create_dir_all(agent.join("src")).unwrap();
+        fs::write(agent.join("src/data.bin"), vec![0u8; 64 * 1024]).unwrap();
+        let preserved = worktrees_dir
+            .join(PRESERVED_DIR)
+            .join("alpha-agent-betty-abc123-preserved-1-100");
+        fs::create_dir_all(&preserved).unwrap();
+        fs::write(preserved.join("notes.txt"), "kept").unwrap();
+
+        let usage = disk_usage(&worktrees_dir);
+        assert_eq!(
+            usage,
+            vec![
+                ("alpha-agent-aaron-abc123".to_string(), 64 * 1024),
+                (
+                    "preserved/alpha-agent-betty-abc123-preserved-1-100".to_string(),
+                    4
+                ),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_disk_usage_does_not_follow_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let temp = TempDir::new().unwrap();
+        let outside = temp.path().join("outside");
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("big.bin"), vec![0u8; 256 * 1024]).unwrap();
+
+        let worktrees_dir = temp.path().join("worktrees");
+        let agent = worktrees_dir.join("alpha-agent-aaron-abc123");
+        fs::create_dir_all(&agent).unwrap();
+        fs::write(agent.join("file.txt"), vec![b'x'; 4096]).unwrap();
+        symlink(&outside, agent.join("linked-dir")).unwrap();
+        symlink(outside.join("big.bin"), agent.join("linked-file")).unwrap();
+        symlink(&outside, worktrees_dir.join("linked-worktree")).unwrap();
+
+        let usage = disk_usage(&worktrees_dir);
+        assert_eq!(usage.len(), 1);
+        assert!(usage[0].1 >= 4096);
+        assert!(usage[0].1 < 256 * 1024, "followed a symlink: {:?}", usage);
+    }
+
+    #[test]
+    fn test_disk_usage_missing_dir_is_empty() {
+        let temp = TempDir::new().unwrap();
+        assert!(disk_usage(&temp.path().join("missing")).is_empty());
+    }
+}
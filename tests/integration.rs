@@ -469,14 +469,7 @@ fn test_removed_commands_return_error() {
     let temp = TempDir::new().expect("temp dir");
     let repo_path = temp.path();
     let swarm_bin = env!("CARGO_BIN_EXE_swarm");
-    let removed_commands = [
-        "sprint",
-        "plan",
-        "status",
-        "worktrees",
-        "worktrees-branch",
-        "cleanup",
-    ];
+    let removed_commands = ["sprint", "plan", "worktrees-branch"];
 
     for command in removed_commands {
         let mut cmd = Command::new(swarm_bin);
@@ -500,6 +493,52 @@ fn test_removed_commands_return_error() {
     }
 }
 
+#[test]
+fn test_config_validate_exit_status() {
+    let temp = TempDir::new().expect("temp dir");
+    let repo_path = temp.path();
+    let swarm_bin = env!("CARGO_BIN_EXE_swarm");
+    fs::write(
+        repo_path.join("swarm.toml"),
+        "[agents]\nmax_count = -1\n\n[engine]\ntype = \"bogus\"\n",
+    )
+    .expect("write swarm.toml");
+
+    let output = Command::new(swarm_bin)
+        .args(["config", "validate"])
+        .current_dir(repo_path)
+        .env_remove("SWARM_ENGINE_TYPE")
+        .output()
+        .expect("failed to run swarm config validate");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success(), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("FAIL agents.max_count (file swarm.toml): invalid agents.max_count: -1"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("FAIL engine.type (file swarm.toml): invalid engine.type: bogus"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("2 problem(s)"));
+
+    fs::write(
+        repo_path.join("swarm.toml"),
+        "[agents]\nmax_count = 4  # fine\n",
+    )
+    .expect("write swarm.toml");
+    let output = Command::new(swarm_bin)
+        .args(["config", "validate"])
+        .current_dir(repo_path)
+        .env_remove("SWARM_ENGINE_TYPE")
+        .output()
+        .expect("failed to run swarm config validate");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Config OK"));
+}
+
 #[test]
 fn test_swarm_run_stub_integration() {
     let temp = TempDir::new().expect("temp dir");
@@ -674,6 +713,60 @@ fn test_swarm_run_stub_integration() {
     );
 }
 
+#[test]
+fn test_swarm_run_stub_dumps_prompts() {
+    let temp = TempDir::new().expect("temp dir");
+    let repo_path = temp.path();
+    let team_name = "alpha";
+
+    init_git_repo(repo_path);
+    let swarm_bin = env!("CARGO_BIN_EXE_swarm");
+
+    let mut team_init_cmd = Command::new(swarm_bin);
+    team_init_cmd
+        .args(["project", "init", team_name])
+        .current_dir(repo_path);
+    run_success(&mut team_init_cmd);
+
+    let team_root = repo_path.join(".swarm-hug").join(team_name);
+    write_team_tasks(&team_root);
+    commit_all(repo_path, "init");
+    let default_branch = git_stdout(repo_path, &["rev-parse", "--abbrev-ref", "HEAD"]);
+    let dump_dir = temp.path().join("prompt-dumps");
+
+    let mut run_cmd = Command::new(swarm_bin);
+    run_cmd
+        .args([
+            "--project",
+            team_name,
+            "--source-branch",
+            default_branch.as_str(),
+            "--target-branch",
+            default_branch.as_str(),
+            "--stub",
+            "--max-sprints",
+            "1",
+            "--no-tui",
+            "--dump-prompts",
+            dump_dir.to_str().expect("dump dir"),
+            "run",
+        ])
+        .current_dir(repo_path);
+    run_success(&mut run_cmd);
+
+    let dumped: Vec<String> = fs::read_dir(&dump_dir)
+        .expect("prompt dump dir should exist")
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    let planning_prompt = dumped
+        .iter()
+        .find(|name| name.ends_with("-scrum_master.md"))
+        .unwrap_or_else(|| panic!("expected a scrum master prompt dump, got {:?}", dumped));
+    let content = fs::read_to_string(dump_dir.join(planning_prompt)).expect("read dumped prompt");
+    assert!(content.contains("Task one"));
+}
+
 #[test]
 fn test_merge_agent_conflict_surfaces_files() {
     let temp = TempDir::new().expect("temp dir");
@@ -1263,7 +1356,51 @@ fn test_single_variation_run_succeeds_with_shared_mismatch_present() {
     assert_eq!(head, "other");
 }
 
-// test_swarm_status_shows_counts_and_recent_chat removed: status command was deprecated
+#[test]
+fn test_swarm_status_json_shows_counts_and_recent_chat() {
+    let temp = TempDir::new().expect("temp dir");
+    let repo_path = temp.path();
+    let team_name = "alpha";
+
+    init_git_repo(repo_path);
+    let swarm_bin = env!("CARGO_BIN_EXE_swarm");
+
+    let mut team_init_cmd = Command::new(swarm_bin);
+    team_init_cmd
+        .args(["project", "init", team_name])
+        .current_dir(repo_path);
+    run_success(&mut team_init_cmd);
+
+    let team_root = repo_path.join(".swarm-hug").join(team_name);
+    write_team_tasks_with_assignments(&team_root);
+    fs::write(
+        team_root.join("chat.md"),
+        "2026-01-01 10:00:00 | ScrumMaster | Sprint 1 plan: 2 task(s)\n",
+    )
+    .expect("write chat.md");
+
+    let mut status_cmd = Command::new(swarm_bin);
+    status_cmd
+        .args(["--project", team_name, "status", "--json"])
+        .current_dir(repo_path);
+    let output = run_success(&mut status_cmd);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.trim().starts_with(
+            "{\"unassigned\":1,\"assigned\":2,\"completed\":0,\"cancelled\":0,\"interrupted\":0,\
+             \"assignable\":1,\"total\":3,\"recent_chat\":["
+        ),
+        "stdout:\n{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Sprint 1 plan: 2 task(s)"),
+        "stdout:\n{}",
+        stdout
+    );
+    assert_eq!(stdout.trim().lines().count(), 1, "stdout:\n{}", stdout);
+}
 
 /// Test that multiple consecutive sprints correctly reassign agents.
 /// This verifies agents are released after each sprint and picked up again for the next.
@@ -1369,6 +1506,79 @@ fn test_swarm_run_multiple_sprints_reassigns_agents() {
     );
 }
 
+/// Test that a repeated --tasks-file pools tasks from every file and writes
+/// each completion back to the file the task came from.
+#[test]
+fn test_swarm_run_multiple_tasks_files_writes_completions_back() {
+    let temp = TempDir::new().expect("temp dir");
+    let repo_path = temp.path();
+    let team_name = "alpha";
+
+    init_git_repo(repo_path);
+    let swarm_bin = env!("CARGO_BIN_EXE_swarm");
+
+    let mut team_init_cmd = Command::new(swarm_bin);
+    team_init_cmd
+        .args(["project", "init", team_name])
+        .current_dir(repo_path);
+    run_success(&mut team_init_cmd);
+
+    let team_root = repo_path.join(".swarm-hug").join(team_name);
+    let tasks_path = team_root.join("tasks.md");
+    fs::write(&tasks_path, "# Tasks\n\n- [ ] Task one\n").expect("write tasks.md");
+    let bugs_path = repo_path.join("bugs.md");
+    fs::write(&bugs_path, "# Bugs\n\n- [ ] Fix crash\n").expect("write bugs.md");
+    commit_all(repo_path, "init");
+    let default_branch = git_stdout(repo_path, &["rev-parse", "--abbrev-ref", "HEAD"]);
+
+    let mut run_cmd = Command::new(swarm_bin);
+    run_cmd
+        .args([
+            "--project",
+            team_name,
+            "--source-branch",
+            default_branch.as_str(),
+            "--target-branch",
+            default_branch.as_str(),
+            "--tasks-file",
+            ".swarm-hug/alpha/tasks.md",
+            "--tasks-file",
+            "bugs.md",
+            "--stub",
+            "--max-sprints",
+            "1",
+            "--tasks-per-agent",
+            "1",
+            "--max-agents",
+            "2",
+            "--no-tui",
+            "run",
+        ])
+        .current_dir(repo_path);
+    let output = run_success(&mut run_cmd);
+    let stdout = strip_ansi(&String::from_utf8_lossy(&output.stdout));
+    assert!(
+        stdout.contains("Sprint 1: assigned 2 task(s)"),
+        "Both files' tasks should be pooled. Output: {}",
+        stdout
+    );
+
+    let tasks_content = fs::read_to_string(&tasks_path).expect("read tasks.md");
+    let bugs_content = fs::read_to_string(&bugs_path).expect("read bugs.md");
+    assert_eq!(TaskList::parse(&tasks_content).completed_count(), 1);
+    assert_eq!(TaskList::parse(&bugs_content).completed_count(), 1);
+    assert!(
+        tasks_content.contains("Task one") && !tasks_content.contains("Fix crash"),
+        "tasks.md:\n{}",
+        tasks_content
+    );
+    assert!(
+        bugs_content.starts_with("# Bugs") && !bugs_content.contains("Task one"),
+        "bugs.md:\n{}",
+        bugs_content
+    );
+}
+
 /// Test that per-task engine selection mechanism works correctly.
 /// Verifies that when an agent has multiple tasks, the engine selection/creation
 /// happens for each task individually (not once per agent).
@@ -1475,6 +1685,76 @@ fn test_per_task_engine_selection_mechanism() {
     );
 }
 
+/// `engine.max_rpm` paces every engine execution of a sprint through the
+/// shared rate limiter.
+#[test]
+fn test_swarm_run_max_rpm_spaces_engine_executions() {
+    let temp = TempDir::new().expect("temp dir");
+    let repo_path = temp.path();
+    let team_name = "alpha";
+
+    init_git_repo(repo_path);
+    let swarm_bin = env!("CARGO_BIN_EXE_swarm");
+
+    let mut team_init_cmd = Command::new(swarm_bin);
+    team_init_cmd
+        .args(["project", "init", team_name])
+        .current_dir(repo_path);
+    run_success(&mut team_init_cmd);
+
+    let team_root = repo_path.join(".swarm-hug").join(team_name);
+    let tasks_path = team_root.join("tasks.md");
+    let tasks_content = "# Tasks\n\n- [ ] Task one\n- [ ] Task two\n- [ ] Task three\n";
+    fs::write(&tasks_path, tasks_content).expect("write TASKS.md");
+    commit_all(repo_path, "init");
+    let default_branch = git_stdout(repo_path, &["rev-parse", "--abbrev-ref", "HEAD"]);
+
+    // 60 rpm: one engine start per second, so three tasks take at least 2s
+    let mut run_cmd = Command::new(swarm_bin);
+    run_cmd
+        .args([
+            "--project",
+            team_name,
+            "--source-branch",
+            default_branch.as_str(),
+            "--target-branch",
+            default_branch.as_str(),
+            "--stub",
+            "--max-sprints",
+            "1",
+            "--tasks-per-agent",
+            "3",
+            "--max-agents",
+            "1",
+            "--no-tui",
+            "run",
+        ])
+        .env("SWARM_ENGINE_MAX_RPM", "60")
+        .current_dir(repo_path);
+    let started = std::time::Instant::now();
+    run_success(&mut run_cmd);
+    let elapsed = started.elapsed();
+
+    let task_list = TaskList::parse(&fs::read_to_string(&tasks_path).expect("read TASKS.md"));
+    assert_eq!(task_list.completed_count(), 3);
+
+    let log_content =
+        fs::read_to_string(team_root.join("loop").join("agent-A.log")).expect("read agent log");
+    assert_eq!(
+        log_content
+            .matches("Waiting for engine rate limiter")
+            .count(),
+        3,
+        "{}",
+        log_content
+    );
+    assert!(
+        elapsed >= std::time::Duration::from_secs(2),
+        "three rate-limited executions finished in {:?}",
+        elapsed
+    );
+}
+
 /// Test multi-engine configuration parsing and selection.
 /// Verifies that --engine flag with multiple engines is parsed correctly
 /// and that the engine selection mechanism handles multiple engine types.
@@ -2464,6 +2744,14 @@ fn test_first_sprint_creates_files_in_sprint_branch() {
         runtime_root.join("team-state.json").exists(),
         "runtime team-state.json should exist under runs/main"
     );
+    let lifecycle = fs::read_to_string(runtime_root.join("lifecycle-1.json"))
+        .expect("runtime lifecycle-1.json should exist under runs/main");
+    assert!(
+        lifecycle.contains("\"state\": \"terminated\"")
+            && lifecycle.contains("\"status\": \"success\""),
+        "lifecycle history should record terminated, successful agents:\n{}",
+        lifecycle
+    );
 
     // KEY ASSERTION 6: Verify the git log shows the sprint was properly created and merged
     let main_log = git_stdout(repo_path, &["log", "--oneline", "-10"]);